@@ -83,10 +83,40 @@ describe("Ntt Layout Tests", function () {
       const deserialized = deserializeLayout(transceiverInfo, data);
       expect(deserialized.decimals).toEqual(16);
       expect(deserialized.mode).toEqual(0);
+      expect(deserialized.tokenMetadataHash).toBeNull();
       expect(serializeLayout(transceiverInfo, deserialized)).toEqual(data);
     }
   );
 
+  test("Test Transceiver info metadata hash extension", () => {
+    const raw = fs
+      .readFileSync(path.join(filePath, payloads.transceiver.info[0]!), "utf-8")
+      .trim();
+    const legacy = encoding.hex.decode(raw);
+    const hash = new Uint8Array(32).fill(0x99);
+    const extended = new Uint8Array([...legacy, 1, ...hash]);
+
+    const deserialized = deserializeLayout(transceiverInfo, extended);
+    expect(deserialized.decimals).toEqual(16);
+    expect(deserialized.tokenMetadataHash).toEqual(hash);
+    expect(serializeLayout(transceiverInfo, deserialized)).toEqual(extended);
+
+    // old payloads still decode, and the extension doesn't change them
+    const old = deserializeLayout(transceiverInfo, legacy);
+    expect({ ...old, tokenMetadataHash: hash }).toEqual(deserialized);
+
+    // unknown extension versions and truncated hashes are rejected
+    expect(() =>
+      deserializeLayout(
+        transceiverInfo,
+        new Uint8Array([...legacy, 2, ...hash])
+      )
+    ).toThrow();
+    expect(() =>
+      deserializeLayout(transceiverInfo, extended.slice(0, -1))
+    ).toThrow();
+  });
+
   test.each(payloads.transceiver.registration)(
     "Test Transceiver %s messages",
     async (filename) => {
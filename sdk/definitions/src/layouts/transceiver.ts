@@ -1,5 +1,6 @@
 import { layoutItems } from "@wormhole-foundation/sdk-definitions";
 import {
+  CustomConversion,
   CustomizableBytes,
   Layout,
   LayoutToType,
@@ -34,6 +35,35 @@ export const transceiverMessageLayout = <
     ),
   ] as const satisfies Layout;
 
+/** Version byte that precedes the token metadata hash extension */
+export const METADATA_HASH_EXTENSION_VERSION = 1;
+
+// The token metadata hash is an extension of the original layout, appended at
+// the end behind a version byte. Payloads without it decode to `null`.
+const optionalMetadataHashItem = {
+  binary: "bytes",
+  custom: {
+    to: (val: Uint8Array) => {
+      if (val.byteLength === 0) return null;
+      if (val[0] !== METADATA_HASH_EXTENSION_VERSION)
+        throw new Error(`Unknown extension version ${val[0]}`);
+      if (val.byteLength !== 33)
+        throw new Error(
+          `Invalid metadata hash length ${val.byteLength - 1}`
+        );
+      return val.slice(1);
+    },
+    from: (val: Uint8Array | null) => {
+      if (val === null) return new Uint8Array();
+      if (val.byteLength !== 32)
+        throw new Error(
+          `Invalid metadata hash length ${val.byteLength}`
+        );
+      return new Uint8Array([METADATA_HASH_EXTENSION_VERSION, ...val]);
+    },
+  } satisfies CustomConversion<Uint8Array, Uint8Array | null>,
+} as const satisfies Layout;
+
 export type TransceiverInfo = LayoutToType<typeof transceiverInfo>;
 export const transceiverInfo = [
   prefixItem([0x9c, 0x23, 0xbd, 0x3b]),
@@ -41,6 +71,7 @@ export const transceiverInfo = [
   { name: "mode", binary: "uint", size: 1 },
   { name: "token", ...layoutItems.universalAddressItem },
   { name: "decimals", binary: "uint", size: 1 },
+  { name: "tokenMetadataHash", ...optionalMetadataHashItem },
 ] as const satisfies Layout;
 
 //
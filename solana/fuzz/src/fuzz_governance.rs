@@ -0,0 +1,13 @@
+use anchor_lang::AnchorDeserialize;
+use honggfuzz::fuzz;
+use wormhole_governance::instructions::GovernanceMessage;
+
+/// Parses the input as a governance VAA payload. This may not panic, only fail
+/// to parse.
+fn main() {
+    loop {
+        fuzz!(|data: &[u8]| {
+            let _ = GovernanceMessage::deserialize(&mut &data[..]);
+        });
+    }
+}
@@ -0,0 +1,35 @@
+use honggfuzz::fuzz;
+use ntt_messages::{
+    ntt::{EmptyPayload, NativeTokenTransfer},
+    transceiver::{TransceiverMessage, TransceiverMessageDataBytes},
+    transceivers::wormhole::{
+        WormholeTransceiver, WormholeTransceiverHeartbeat, WormholeTransceiverInfo,
+        WormholeTransceiverRegistration,
+    },
+};
+use wormhole_io::TypePrefixedPayload;
+
+type Message = TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<EmptyPayload>>;
+
+/// Parses the input as each of the messages a transceiver receives. None of
+/// them may panic, only fail to parse.
+fn main() {
+    loop {
+        fuzz!(|data: &[u8]| {
+            let _ = Message::read_payload(&mut &data[..]);
+            let _ = WormholeTransceiverInfo::read_payload(&mut &data[..]);
+            let _ = WormholeTransceiverRegistration::read_payload(&mut &data[..]);
+            let _ = WormholeTransceiverHeartbeat::read_payload(&mut &data[..]);
+
+            // the borsh encoded message stored by the transceivers, as read in
+            // the redeem instruction
+            if let Ok(bytes) =
+                TransceiverMessageDataBytes::<NativeTokenTransfer<EmptyPayload>>::parse(data)
+            {
+                let _ = bytes.source_ntt_manager();
+                let _ = bytes.recipient_ntt_manager();
+                let _ = bytes.ntt_manager_payload();
+            }
+        });
+    }
+}
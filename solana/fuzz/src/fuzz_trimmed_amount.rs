@@ -5,9 +5,17 @@ use ntt_messages::trimmed_amount::TrimmedAmount;
 
 fn main() {
     loop {
-         fuzz!(|input: (u64, u8, u8)| {
+        fuzz!(|input: (u64, u8, u8)| {
+            let (amount, from_decimals, to_decimals) = input;
 
-            let _ = TrimmedAmount::trim(input.0, input.1, input.2);
+            if let Ok(trimmed) = TrimmedAmount::trim(amount, from_decimals, to_decimals) {
+                let _ = trimmed.untrim(from_decimals);
+            }
+            let _ = TrimmedAmount::new(amount, from_decimals).untrim(to_decimals);
+            let _ = TrimmedAmount::new(amount, from_decimals).change_decimals(to_decimals);
+
+            let mut amount = amount;
+            let _ = TrimmedAmount::remove_dust(&mut amount, from_decimals, to_decimals);
         });
     }
 }
@@ -0,0 +1,22 @@
+use example_native_token_transfers::transfer::Payload;
+use honggfuzz::fuzz;
+use ntt_messages::{ntt::NativeTokenTransfer, transceivers::wormhole::WormholeTransceiver};
+use ntt_transceiver::vaa_body::VaaBodyBytes;
+
+type Transfer = NativeTokenTransfer<Payload>;
+
+/// Reads every field of the input as a VAA body, as the receive instructions
+/// do. None of them may panic, only fail to parse.
+fn main() {
+    loop {
+        fuzz!(|span: &[u8]| {
+            let vaa_body = VaaBodyBytes { span };
+            let _ = vaa_body.emitter_chain();
+            let _ = vaa_body.emitter_address();
+            let _ = vaa_body.source_ntt_manager();
+            let _ = vaa_body.id();
+            let _ = vaa_body.to_chain();
+            let _ = vaa_body.validated_transceiver_message::<WormholeTransceiver, Transfer>(0);
+        });
+    }
+}
@@ -0,0 +1,35 @@
+#!/usr/bin/env bash
+# Writes the seed corpus of each fuzz target from the golden layout vectors in
+# modules/ntt-messages/layout-vectors. Rerun after adding vectors.
+set -euo pipefail
+
+SCRIPT_DIR=$( cd -- "$( dirname -- "${BASH_SOURCE[0]}" )" &> /dev/null && pwd )
+VECTORS=${SCRIPT_DIR}/../../modules/ntt-messages/layout-vectors
+CORPUS=${SCRIPT_DIR}/corpus
+
+# The VAA body fields that precede the payload: timestamp, nonce, emitter chain,
+# emitter address, sequence and consistency level.
+VAA_BODY_HEADER="00000001000000020001$(printf '06%.0s' {1..32})000000000000000301"
+
+# The governance message parsed in the wormhole-governance tests.
+GOVERNANCE_MESSAGE="000000000000000047656e6572616c507572706f7365476f7665726e616e63650200010e027fbc6b1e61365d4b0680a3179f791b15796f93e24e9b441e3fa04ccda4a000000000000000010000000000000000000000000000000000000000000000000002000000000000000200000000000000000000000000000000000000000000000001010000000000000003000000000000000000000000000000000000000000000000000100050102030405"
+
+# write_seeds <target> <vectors file> [hex prefix]
+write_seeds() {
+	local target=$1 file=$2 prefix=${3:-}
+	mkdir -p "${CORPUS}/${target}"
+	jq -r '.[] | "\(.name) \(.hex)"' "${VECTORS}/${file}.json" | while read -r name hex; do
+		echo -n "${prefix}${hex}" | xxd -r -p > "${CORPUS}/${target}/${file}-${name}"
+	done
+}
+
+rm -rf "${CORPUS}"
+
+for file in transceiver_message wormhole_transceiver_info wormhole_transceiver_registration wormhole_transceiver_heartbeat; do
+	write_seeds transceiver-message "${file}"
+done
+
+write_seeds vaa-body transceiver_message "${VAA_BODY_HEADER}"
+
+mkdir -p "${CORPUS}/governance"
+echo -n "${GOVERNANCE_MESSAGE}" | xxd -r -p > "${CORPUS}/governance/guardian"
@@ -10,10 +10,142 @@ use wormhole_io::{Readable, Writeable};
     feature = "anchor",
     derive(AnchorSerialize, AnchorDeserialize, InitSpace)
 )]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct ChainId {
     pub id: u16,
 }
 
+/// Wormhole chain ids, as listed in the Wormhole SDK.
+/// New chains need to be added here before peers can be registered for them
+/// (without explicitly overriding the check, see [`known_chain`]).
+pub const KNOWN_CHAINS: &[(u16, &str)] = &[
+    (1, "solana"),
+    (2, "ethereum"),
+    (3, "terra"),
+    (4, "bsc"),
+    (5, "polygon"),
+    (6, "avalanche"),
+    (7, "oasis"),
+    (8, "algorand"),
+    (9, "aurora"),
+    (10, "fantom"),
+    (11, "karura"),
+    (12, "acala"),
+    (13, "klaytn"),
+    (14, "celo"),
+    (15, "near"),
+    (16, "moonbeam"),
+    (17, "neon"),
+    (18, "terra2"),
+    (19, "injective"),
+    (20, "osmosis"),
+    (21, "sui"),
+    (22, "aptos"),
+    (23, "arbitrum"),
+    (24, "optimism"),
+    (25, "gnosis"),
+    (26, "pythnet"),
+    (28, "xpla"),
+    (29, "btc"),
+    (30, "base"),
+    (31, "filecoin"),
+    (32, "sei"),
+    (33, "rootstock"),
+    (34, "scroll"),
+    (35, "mantle"),
+    (36, "blast"),
+    (37, "xlayer"),
+    (38, "linea"),
+    (39, "berachain"),
+    (40, "seievm"),
+    (41, "eclipse"),
+    (42, "bob"),
+    (43, "snaxchain"),
+    (44, "unichain"),
+    (45, "worldchain"),
+    (46, "ink"),
+    (47, "hyperevm"),
+    (48, "monad"),
+    (49, "movement"),
+    (50, "mezo"),
+    (51, "fogo"),
+    (52, "sonic"),
+    (3104, "wormchain"),
+    (4000, "cosmoshub"),
+    (4001, "evmos"),
+    (4002, "kujira"),
+    (4003, "neutron"),
+    (4004, "celestia"),
+    (4005, "stargaze"),
+    (4006, "seda"),
+    (4007, "dymension"),
+    (4008, "provenance"),
+    (4009, "noble"),
+    (10002, "sepolia"),
+    (10003, "arbitrum_sepolia"),
+    (10004, "base_sepolia"),
+    (10005, "optimism_sepolia"),
+    (10006, "holesky"),
+    (10007, "polygon_sepolia"),
+];
+
+/// Whether `id` is a Wormhole chain id listed in [`KNOWN_CHAINS`].
+pub fn known_chain(id: u16) -> bool {
+    KNOWN_CHAINS
+        .binary_search_by_key(&id, |(chain_id, _)| *chain_id)
+        .is_ok()
+}
+
+/// How native addresses of a chain are laid out in 32 byte universal
+/// addresses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressFormat {
+    /// 20 byte addresses, left-padded with 12 zero bytes.
+    Evm,
+    /// 32 byte public keys.
+    Solana,
+    /// Anything else, including chains not in [`KNOWN_CHAINS`].
+    Other,
+}
+
+/// EVM chains (mainnets and testnets) in [`KNOWN_CHAINS`].
+const EVM_CHAINS: &[u16] = &[
+    2, 4, 5, 6, 7, 9, 10, 11, 12, 13, 14, 16, 17, 23, 24, 25, 30, 33, 34, 35, 36, 37, 38, 39, 40,
+    42, 43, 44, 45, 46, 47, 48, 50, 52, 10002, 10003, 10004, 10005, 10006, 10007,
+];
+
+/// SVM chains in [`KNOWN_CHAINS`].
+const SOLANA_CHAINS: &[u16] = &[1, 26, 41, 51];
+
+impl AddressFormat {
+    /// The address format of chain `id`.
+    pub fn of(id: u16) -> Self {
+        if EVM_CHAINS.binary_search(&id).is_ok() {
+            AddressFormat::Evm
+        } else if SOLANA_CHAINS.binary_search(&id).is_ok() {
+            AddressFormat::Solana
+        } else {
+            AddressFormat::Other
+        }
+    }
+
+    /// Whether `address` is a well-formed universal address in this format.
+    /// No address is all zeroes, and EVM addresses have to be left-padded, so
+    /// that a right-padded (or otherwise truncated) address is caught.
+    pub fn is_valid(&self, address: &[u8; 32]) -> bool {
+        match self {
+            AddressFormat::Evm => {
+                address[..12].iter().all(|b| *b == 0) && address[12..].iter().any(|b| *b != 0)
+            }
+            AddressFormat::Solana | AddressFormat::Other => address.iter().any(|b| *b != 0),
+        }
+    }
+}
+
 impl Readable for ChainId {
     const SIZE: Option<usize> = u16::SIZE;
 
@@ -41,3 +173,62 @@ impl Writeable for ChainId {
         id.write(writer)
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_known_chains_sorted() {
+        // [`known_chain`] relies on the table being sorted (and deduplicated)
+        assert!(KNOWN_CHAINS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_known_chain() {
+        assert!(known_chain(1));
+        assert!(known_chain(2));
+        assert!(known_chain(42));
+        assert!(known_chain(10002));
+
+        assert!(!known_chain(0));
+        assert!(!known_chain(27));
+        assert!(!known_chain(420));
+        assert!(!known_chain(u16::MAX));
+    }
+    #[test]
+    fn test_address_format_chains() {
+        // the lookups rely on the tables being sorted, and every chain in
+        // them should be known
+        for chains in [EVM_CHAINS, SOLANA_CHAINS] {
+            assert!(chains.windows(2).all(|w| w[0] < w[1]));
+            assert!(chains.iter().all(|id| known_chain(*id)));
+        }
+
+        assert_eq!(AddressFormat::of(1), AddressFormat::Solana);
+        assert_eq!(AddressFormat::of(2), AddressFormat::Evm);
+        assert_eq!(AddressFormat::of(10002), AddressFormat::Evm);
+        assert_eq!(AddressFormat::of(21), AddressFormat::Other);
+        assert_eq!(AddressFormat::of(u16::MAX), AddressFormat::Other);
+    }
+
+    #[test]
+    fn test_address_format_is_valid() {
+        let mut left_padded = [0u8; 32];
+        left_padded[12..].copy_from_slice(&[0xab; 20]);
+        let mut right_padded = [0u8; 32];
+        right_padded[..20].copy_from_slice(&[0xab; 20]);
+
+        assert!(AddressFormat::Evm.is_valid(&left_padded));
+        assert!(!AddressFormat::Evm.is_valid(&right_padded));
+        assert!(!AddressFormat::Evm.is_valid(&[0xab; 32]));
+        assert!(!AddressFormat::Evm.is_valid(&[0; 32]));
+
+        for format in [AddressFormat::Solana, AddressFormat::Other] {
+            assert!(format.is_valid(&left_padded));
+            assert!(format.is_valid(&right_padded));
+            assert!(!format.is_valid(&[0; 32]));
+        }
+    }
+}
@@ -0,0 +1,480 @@
+//! Golden vectors for the binary layouts of the messages, shared with the
+//! TypeScript SDK. Each file in `layout-vectors/` holds a list of messages of
+//! one type, as the hex encoded payload and the decoded fields.
+//!
+//! [`test_layout_vectors_are_up_to_date`] checks that the committed files
+//! match the messages below. To regenerate them after adding a vector, run
+//!
+//! ```sh
+//! UPDATE_LAYOUT_VECTORS=1 cargo test -p ntt-messages --features serde layout_vectors
+//! ```
+//!
+//! Changes to existing vectors mean that the wire format changed, which breaks
+//! compatibility with every other implementation.
+//!
+//! The vectors also seed the fuzzer corpus (see `solana/fuzz/src`).
+
+use std::{fs, io, path::PathBuf};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use wormhole_io::{Readable, TypePrefixedPayload, Writeable};
+
+use crate::{
+    chain_id::ChainId,
+    mode::Mode,
+    ntt::{EmptyPayload, NativeTokenTransfer},
+    ntt_manager::NttManagerMessage,
+    transceiver::TransceiverMessage,
+    transceivers::wormhole::{
+        WormholeTransceiver, WormholeTransceiverHeartbeat, WormholeTransceiverInfo,
+        WormholeTransceiverRegistration,
+    },
+    trimmed_amount::TrimmedAmount,
+    utils::maybe_space::MaybeSpace,
+};
+
+/// Largest additional payload that fits in a [`TransceiverMessage`], whose
+/// manager payload is length prefixed with a `u16`.
+const MAX_ADDITIONAL_PAYLOAD_LEN: usize = u16::MAX as usize
+    - (32 + 32 + 2) // manager message, up to the transfer
+    - (3 + 1 + 9 + 32 + 32 + 2 + 2); // transfer, up to the additional payload
+
+/// An opaque additional payload of `N` bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct FixedPayload<const N: usize>(#[serde(with = "crate::utils::hex_bytes")] [u8; N]);
+
+#[cfg(feature = "anchor")]
+impl<const N: usize> anchor_lang::Space for FixedPayload<N> {
+    const INIT_SPACE: usize = N;
+}
+
+impl<const N: usize> TypePrefixedPayload for FixedPayload<N> {
+    const TYPE: Option<u8> = None;
+}
+
+impl<const N: usize> Readable for FixedPayload<N> {
+    const SIZE: Option<usize> = Some(N);
+
+    fn read<R>(reader: &mut R) -> io::Result<Self>
+    where
+        Self: Sized,
+        R: io::Read,
+    {
+        let mut bytes = [0u8; N];
+        reader.read_exact(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+}
+
+impl<const N: usize> Writeable for FixedPayload<N> {
+    fn written_size(&self) -> usize {
+        N
+    }
+
+    fn write<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        writer.write_all(&self.0)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Vector<T> {
+    name: String,
+    hex: String,
+    decoded: T,
+}
+
+fn vector<T: TypePrefixedPayload>(name: &str, decoded: T) -> Vector<T> {
+    Vector {
+        name: name.to_string(),
+        hex: hex::encode(TypePrefixedPayload::to_vec_payload(&decoded)),
+        decoded,
+    }
+}
+
+fn path(file: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("layout-vectors")
+        .join(file)
+}
+
+// * Messages
+
+fn transfer<A: TypePrefixedPayload + MaybeSpace>(
+    amount: TrimmedAmount,
+    to_chain: u16,
+    additional_payload: A,
+) -> NativeTokenTransfer<A> {
+    NativeTokenTransfer {
+        version: NativeTokenTransfer::<A>::CURRENT_VERSION,
+        amount,
+        source_token: [0x55; 32],
+        to_chain: ChainId { id: to_chain },
+        to: [0x66; 32],
+        additional_payload,
+    }
+}
+
+fn manager_message<A: TypePrefixedPayload + MaybeSpace>(payload: A) -> NttManagerMessage<A> {
+    NttManagerMessage {
+        id: [0x33; 32],
+        sender: [0x44; 32],
+        payload,
+    }
+}
+
+fn transceiver_message<A: TypePrefixedPayload + MaybeSpace>(
+    payload: A,
+    transceiver_payload: Vec<u8>,
+) -> TransceiverMessage<WormholeTransceiver, A> {
+    TransceiverMessage::new(
+        [0x11; 32],
+        [0x22; 32],
+        manager_message(payload),
+        transceiver_payload,
+    )
+}
+
+fn native_token_transfer_vectors() -> Vec<Vector<NativeTokenTransfer<EmptyPayload>>> {
+    vec![
+        vector(
+            "basic",
+            transfer(TrimmedAmount::new(1234567, 7), 2, EmptyPayload {}),
+        ),
+        vector(
+            "zero_amount",
+            transfer(TrimmedAmount::new(0, 0), 2, EmptyPayload {}),
+        ),
+        vector(
+            "max_amount",
+            transfer(TrimmedAmount::new(u64::MAX, 8), 2, EmptyPayload {}),
+        ),
+        vector(
+            "max_decimals",
+            transfer(TrimmedAmount::new(1, u8::MAX), 2, EmptyPayload {}),
+        ),
+        vector(
+            "max_chain_id",
+            transfer(TrimmedAmount::new(1234567, 7), u16::MAX, EmptyPayload {}),
+        ),
+        vector(
+            "unknown_version",
+            NativeTokenTransfer {
+                version: 0x55,
+                ..transfer(TrimmedAmount::new(1234567, 7), 2, EmptyPayload {})
+            },
+        ),
+    ]
+}
+
+fn native_token_transfer_with_payload_vectors() -> Vec<Vector<NativeTokenTransfer<FixedPayload<32>>>>
+{
+    vec![vector(
+        "basic",
+        transfer(TrimmedAmount::new(1234567, 7), 2, FixedPayload([0xAB; 32])),
+    )]
+}
+
+fn ntt_manager_message_vectors() -> Vec<Vector<NttManagerMessage<NativeTokenTransfer<EmptyPayload>>>>
+{
+    vec![
+        vector(
+            "basic",
+            manager_message(transfer(TrimmedAmount::new(1234567, 7), 2, EmptyPayload {})),
+        ),
+        vector(
+            "max_amount",
+            manager_message(transfer(
+                TrimmedAmount::new(u64::MAX, 8),
+                2,
+                EmptyPayload {},
+            )),
+        ),
+    ]
+}
+
+fn transceiver_message_vectors(
+) -> Vec<Vector<TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<EmptyPayload>>>> {
+    vec![
+        vector(
+            "empty_transceiver_payload",
+            transceiver_message(
+                transfer(TrimmedAmount::new(1234567, 7), 2, EmptyPayload {}),
+                vec![],
+            ),
+        ),
+        vector(
+            "with_transceiver_payload",
+            transceiver_message(
+                transfer(TrimmedAmount::new(1234567, 7), 2, EmptyPayload {}),
+                vec![0x01, 0x02, 0x03, 0x04],
+            ),
+        ),
+    ]
+}
+
+fn transceiver_message_max_payload_vectors() -> Vec<
+    Vector<
+        TransceiverMessage<
+            WormholeTransceiver,
+            NativeTokenTransfer<FixedPayload<MAX_ADDITIONAL_PAYLOAD_LEN>>,
+        >,
+    >,
+> {
+    vec![vector(
+        "max_payload",
+        transceiver_message(
+            transfer(
+                TrimmedAmount::new(1234567, 7),
+                2,
+                FixedPayload(std::array::from_fn(|i| u8::try_from(i % 256).unwrap())),
+            ),
+            vec![],
+        ),
+    )]
+}
+
+fn wormhole_transceiver_info_vectors() -> Vec<Vector<WormholeTransceiverInfo>> {
+    vec![
+        vector(
+            "locking",
+            WormholeTransceiverInfo {
+                manager_address: [0x77; 32],
+                manager_mode: Mode::Locking,
+                token_address: [0x88; 32],
+                token_decimals: 9,
+                token_metadata_hash: None,
+            },
+        ),
+        vector(
+            "burning",
+            WormholeTransceiverInfo {
+                manager_address: [0x77; 32],
+                manager_mode: Mode::Burning,
+                token_address: [0x88; 32],
+                token_decimals: 18,
+                token_metadata_hash: None,
+            },
+        ),
+        vector(
+            "max_decimals",
+            WormholeTransceiverInfo {
+                manager_address: [0x77; 32],
+                manager_mode: Mode::Locking,
+                token_address: [0x88; 32],
+                token_decimals: u8::MAX,
+                token_metadata_hash: None,
+            },
+        ),
+        vector(
+            "metadata_hash",
+            WormholeTransceiverInfo {
+                manager_address: [0x77; 32],
+                manager_mode: Mode::Locking,
+                token_address: [0x88; 32],
+                token_decimals: 9,
+                token_metadata_hash: Some([0x99; 32]),
+            },
+        ),
+        vector(
+            "no_metadata",
+            WormholeTransceiverInfo {
+                manager_address: [0x77; 32],
+                manager_mode: Mode::Locking,
+                token_address: [0x88; 32],
+                token_decimals: 9,
+                token_metadata_hash: Some([0; 32]),
+            },
+        ),
+    ]
+}
+
+fn wormhole_transceiver_registration_vectors() -> Vec<Vector<WormholeTransceiverRegistration>> {
+    vec![
+        vector(
+            "basic",
+            WormholeTransceiverRegistration {
+                chain_id: ChainId { id: 2 },
+                transceiver_address: [0x99; 32],
+            },
+        ),
+        vector(
+            "max_chain_id",
+            WormholeTransceiverRegistration {
+                chain_id: ChainId { id: u16::MAX },
+                transceiver_address: [0x99; 32],
+            },
+        ),
+    ]
+}
+
+fn wormhole_transceiver_heartbeat_vectors() -> Vec<Vector<WormholeTransceiverHeartbeat>> {
+    vec![
+        vector(
+            "basic",
+            WormholeTransceiverHeartbeat {
+                manager_address: [0x77; 32],
+                timestamp: 1_700_000_000,
+            },
+        ),
+        vector(
+            "max_timestamp",
+            WormholeTransceiverHeartbeat {
+                manager_address: [0x77; 32],
+                timestamp: u64::MAX,
+            },
+        ),
+    ]
+}
+
+// * Checks
+
+/// Checks that `file` holds exactly `vectors`, or overwrites it if
+/// `UPDATE_LAYOUT_VECTORS` is set.
+fn check_up_to_date<T: Serialize>(file: &str, vectors: Vec<Vector<T>>) {
+    let json = serde_json::to_string_pretty(&vectors).unwrap() + "\n";
+    if std::env::var_os("UPDATE_LAYOUT_VECTORS").is_some() {
+        fs::write(path(file), json).unwrap();
+    } else {
+        let committed = fs::read_to_string(path(file)).unwrap();
+        assert!(
+            committed == json,
+            "{file} is out of date, see the `layout_vectors` module docs"
+        );
+    }
+}
+
+/// Decodes every vector in `file`, and checks that it re-encodes to the same
+/// bytes.
+fn check_roundtrip<T>(file: &str)
+where
+    T: TypePrefixedPayload + DeserializeOwned + PartialEq + std::fmt::Debug,
+{
+    let vectors: Vec<Vector<T>> =
+        serde_json::from_str(&fs::read_to_string(path(file)).unwrap()).unwrap();
+    assert!(!vectors.is_empty());
+
+    for Vector { name, hex, decoded } in vectors {
+        let bytes = hex::decode(hex).unwrap();
+
+        let mut reader = &bytes[..];
+        let parsed: T = TypePrefixedPayload::read_payload(&mut reader).unwrap();
+        assert_eq!(parsed, decoded, "{file}: {name}");
+        assert!(reader.is_empty(), "{file}: {name} has trailing bytes");
+
+        assert_eq!(
+            TypePrefixedPayload::to_vec_payload(&decoded),
+            bytes,
+            "{file}: {name}"
+        );
+    }
+}
+
+/// Decodes truncations and single byte mutations of every vector in `file`,
+/// none of which may panic (but they may well decode).
+///
+/// Long vectors are only truncated and mutated at up to [`MAX_CHECKED_OFFSETS`]
+/// evenly spaced offsets, to keep the test fast.
+fn check_no_panic<T>(file: &str)
+where
+    T: TypePrefixedPayload + DeserializeOwned + std::fmt::Debug,
+{
+    let vectors: Vec<Vector<T>> =
+        serde_json::from_str(&fs::read_to_string(path(file)).unwrap()).unwrap();
+
+    for Vector { hex, .. } in vectors {
+        let bytes = hex::decode(hex).unwrap();
+        let step = bytes.len().div_ceil(MAX_CHECKED_OFFSETS).max(1);
+
+        for offset in (0..bytes.len()).step_by(step) {
+            let _ = T::read_payload(&mut &bytes[..offset]);
+
+            for byte in [0x00, 0x01, 0x7f, 0x80, 0xff] {
+                let mut mutated = bytes.clone();
+                mutated[offset] = byte;
+                let _ = T::read_payload(&mut &mutated[..]);
+            }
+        }
+    }
+}
+
+/// See [`check_no_panic`].
+const MAX_CHECKED_OFFSETS: usize = 1024;
+
+#[test]
+fn test_layout_vectors_are_up_to_date() {
+    check_up_to_date(
+        "native_token_transfer.json",
+        native_token_transfer_vectors(),
+    );
+    check_up_to_date(
+        "native_token_transfer_with_payload.json",
+        native_token_transfer_with_payload_vectors(),
+    );
+    check_up_to_date("ntt_manager_message.json", ntt_manager_message_vectors());
+    check_up_to_date("transceiver_message.json", transceiver_message_vectors());
+    check_up_to_date(
+        "transceiver_message_max_payload.json",
+        transceiver_message_max_payload_vectors(),
+    );
+    check_up_to_date(
+        "wormhole_transceiver_info.json",
+        wormhole_transceiver_info_vectors(),
+    );
+    check_up_to_date(
+        "wormhole_transceiver_registration.json",
+        wormhole_transceiver_registration_vectors(),
+    );
+    check_up_to_date(
+        "wormhole_transceiver_heartbeat.json",
+        wormhole_transceiver_heartbeat_vectors(),
+    );
+}
+
+#[test]
+fn test_layout_vectors_roundtrip() {
+    check_roundtrip::<NativeTokenTransfer<EmptyPayload>>("native_token_transfer.json");
+    check_roundtrip::<NativeTokenTransfer<FixedPayload<32>>>(
+        "native_token_transfer_with_payload.json",
+    );
+    check_roundtrip::<NttManagerMessage<NativeTokenTransfer<EmptyPayload>>>(
+        "ntt_manager_message.json",
+    );
+    check_roundtrip::<TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<EmptyPayload>>>(
+        "transceiver_message.json",
+    );
+    check_roundtrip::<
+        TransceiverMessage<
+            WormholeTransceiver,
+            NativeTokenTransfer<FixedPayload<MAX_ADDITIONAL_PAYLOAD_LEN>>,
+        >,
+    >("transceiver_message_max_payload.json");
+    check_roundtrip::<WormholeTransceiverInfo>("wormhole_transceiver_info.json");
+    check_roundtrip::<WormholeTransceiverRegistration>("wormhole_transceiver_registration.json");
+    check_roundtrip::<WormholeTransceiverHeartbeat>("wormhole_transceiver_heartbeat.json");
+}
+
+#[test]
+fn test_layout_vectors_malformed_do_not_panic() {
+    check_no_panic::<NativeTokenTransfer<EmptyPayload>>("native_token_transfer.json");
+    check_no_panic::<NativeTokenTransfer<FixedPayload<32>>>(
+        "native_token_transfer_with_payload.json",
+    );
+    check_no_panic::<NttManagerMessage<NativeTokenTransfer<EmptyPayload>>>(
+        "ntt_manager_message.json",
+    );
+    check_no_panic::<TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<EmptyPayload>>>(
+        "transceiver_message.json",
+    );
+    check_no_panic::<
+        TransceiverMessage<
+            WormholeTransceiver,
+            NativeTokenTransfer<FixedPayload<MAX_ADDITIONAL_PAYLOAD_LEN>>,
+        >,
+    >("transceiver_message_max_payload.json");
+    check_no_panic::<WormholeTransceiverInfo>("wormhole_transceiver_info.json");
+    check_no_panic::<WormholeTransceiverRegistration>("wormhole_transceiver_registration.json");
+    check_no_panic::<WormholeTransceiverHeartbeat>("wormhole_transceiver_heartbeat.json");
+}
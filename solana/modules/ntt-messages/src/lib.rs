@@ -1,5 +1,7 @@
 pub mod chain_id;
 pub mod errors;
+#[cfg(all(test, feature = "serde"))]
+mod layout_vectors;
 pub mod mode;
 pub mod ntt;
 pub mod ntt_manager;
@@ -8,6 +8,7 @@ use wormhole_io::{Readable, Writeable};
     feature = "anchor",
     derive(AnchorSerialize, AnchorDeserialize, InitSpace)
 )]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Mode {
     Locking,
     Burning,
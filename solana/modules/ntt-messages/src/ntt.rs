@@ -7,23 +7,84 @@ use wormhole_io::{Readable, TypePrefixedPayload, Writeable};
 
 use crate::{chain_id::ChainId, trimmed_amount::TrimmedAmount, utils::maybe_space::MaybeSpace};
 
+/// NOTE: the borsh encoding (with the `anchor` feature) is implemented by
+/// hand, as it doesn't include [`Self::version`], see the impls below.
 #[derive(Debug, Clone, PartialEq, Eq)]
-#[cfg_attr(
-    feature = "anchor",
-    derive(AnchorSerialize, AnchorDeserialize, InitSpace)
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NativeTokenTransfer<A: MaybeSpace> {
+    /// Payload format version. Messages are always decoded, so that the
+    /// version can be checked (and rejected) by the receiving program.
+    pub version: u8,
     pub amount: TrimmedAmount,
     // TODO: is this needed?
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::hex_bytes"))]
     pub source_token: [u8; 32],
     // TODO: shouldn't we put this in the outer message?
     pub to_chain: ChainId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::hex_bytes"))]
     pub to: [u8; 32],
     pub additional_payload: A,
 }
 
 impl<A: MaybeSpace> NativeTokenTransfer<A> {
-    const PREFIX: [u8; 4] = [0x99, 0x4E, 0x54, 0x54];
+    /// The payload starts with `0x994E54` followed by the version byte.
+    /// NOTE: the version byte of the current format is `0x54`, which keeps
+    /// the encoding identical to the original `0x994E5454` prefix.
+    const PREFIX: [u8; 3] = [0x99, 0x4E, 0x54];
+
+    pub const CURRENT_VERSION: u8 = 0x54;
+}
+
+/// The borsh encoding is the layout messages are stored in on chain (see
+/// `ValidatedTransceiverMessage`), which predates the version byte. It's left
+/// out so that messages stored before it was introduced still decode: only
+/// messages of the [`NativeTokenTransfer::CURRENT_VERSION`] are ever stored, as
+/// the others are rejected on receive.
+#[cfg(feature = "anchor")]
+impl<A: MaybeSpace + AnchorSerialize> AnchorSerialize for NativeTokenTransfer<A> {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        let NativeTokenTransfer {
+            version,
+            amount,
+            source_token,
+            to_chain,
+            to,
+            additional_payload,
+        } = self;
+
+        if *version != Self::CURRENT_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Only the current NativeTokenTransfer version can be serialized",
+            ));
+        }
+
+        amount.serialize(writer)?;
+        source_token.serialize(writer)?;
+        to_chain.serialize(writer)?;
+        to.serialize(writer)?;
+        additional_payload.serialize(writer)
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl<A: MaybeSpace + AnchorDeserialize> AnchorDeserialize for NativeTokenTransfer<A> {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        Ok(Self {
+            version: Self::CURRENT_VERSION,
+            amount: AnchorDeserialize::deserialize_reader(reader)?,
+            source_token: AnchorDeserialize::deserialize_reader(reader)?,
+            to_chain: AnchorDeserialize::deserialize_reader(reader)?,
+            to: AnchorDeserialize::deserialize_reader(reader)?,
+            additional_payload: AnchorDeserialize::deserialize_reader(reader)?,
+        })
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl<A: MaybeSpace> Space for NativeTokenTransfer<A> {
+    const INIT_SPACE: usize =
+        TrimmedAmount::INIT_SPACE + 32 + ChainId::INIT_SPACE + 32 + A::INIT_SPACE;
 }
 
 impl<A: TypePrefixedPayload + MaybeSpace> TypePrefixedPayload for NativeTokenTransfer<A> {
@@ -38,7 +99,7 @@ impl<A: TypePrefixedPayload + MaybeSpace> Readable for NativeTokenTransfer<A> {
         Self: Sized,
         R: io::Read,
     {
-        let prefix: [u8; 4] = Readable::read(reader)?;
+        let prefix: [u8; 3] = Readable::read(reader)?;
         if prefix != Self::PREFIX {
             return Err(io::Error::new(
                 io::ErrorKind::InvalidData,
@@ -46,6 +107,7 @@ impl<A: TypePrefixedPayload + MaybeSpace> Readable for NativeTokenTransfer<A> {
             ));
         }
 
+        let version = Readable::read(reader)?;
         let amount = Readable::read(reader)?;
         let source_token = Readable::read(reader)?;
         let to = Readable::read(reader)?;
@@ -60,6 +122,7 @@ impl<A: TypePrefixedPayload + MaybeSpace> Readable for NativeTokenTransfer<A> {
         let additional_payload = A::read_payload(reader)?;
 
         Ok(Self {
+            version,
             amount,
             source_token,
             to,
@@ -72,6 +135,7 @@ impl<A: TypePrefixedPayload + MaybeSpace> Readable for NativeTokenTransfer<A> {
 impl<A: TypePrefixedPayload + MaybeSpace> Writeable for NativeTokenTransfer<A> {
     fn written_size(&self) -> usize {
         Self::PREFIX.len()
+            + u8::SIZE.unwrap()
             + TrimmedAmount::SIZE.unwrap()
             + self.source_token.len()
             + self.to.len()
@@ -88,6 +152,7 @@ impl<A: TypePrefixedPayload + MaybeSpace> Writeable for NativeTokenTransfer<A> {
         W: io::Write,
     {
         let NativeTokenTransfer {
+            version,
             amount,
             source_token,
             to,
@@ -96,6 +161,7 @@ impl<A: TypePrefixedPayload + MaybeSpace> Writeable for NativeTokenTransfer<A> {
         } = self;
 
         Self::PREFIX.write(writer)?;
+        version.write(writer)?;
         amount.write(writer)?;
         source_token.write(writer)?;
         to.write(writer)?;
@@ -117,6 +183,7 @@ impl<A: TypePrefixedPayload + MaybeSpace> Writeable for NativeTokenTransfer<A> {
     feature = "anchor",
     derive(AnchorSerialize, AnchorDeserialize, InitSpace)
 )]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EmptyPayload {}
 
 impl EmptyPayload {
@@ -151,3 +218,235 @@ impl Writeable for EmptyPayload {
         Ok(())
     }
 }
+
+/// Application data that can be carried in
+/// [`NativeTokenTransfer::additional_payload`] (wrapped in an
+/// [`ExtendedPayload`]), e.g. an instruction for the destination chain.
+///
+/// Each extension is identified on the wire by its [`Self::PREFIX`], so that a
+/// receiver expecting a different extension rejects the message instead of
+/// misinterpreting its bytes.
+#[cfg(feature = "anchor")]
+pub trait PayloadExtension: AnchorSerialize + AnchorDeserialize + Space {
+    const PREFIX: [u8; 4];
+}
+
+/// An additional payload made of a [`PayloadExtension`], encoded as its
+/// prefix followed by its borsh encoding.
+#[cfg(feature = "anchor")]
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct ExtendedPayload<T>(pub T);
+
+#[cfg(feature = "anchor")]
+impl<T: Space> Space for ExtendedPayload<T> {
+    const INIT_SPACE: usize = T::INIT_SPACE;
+}
+
+#[cfg(feature = "anchor")]
+impl<T: PayloadExtension> TypePrefixedPayload for ExtendedPayload<T> {
+    const TYPE: Option<u8> = None;
+}
+
+#[cfg(feature = "anchor")]
+impl<T: PayloadExtension> Readable for ExtendedPayload<T> {
+    const SIZE: Option<usize> = None;
+
+    fn read<R>(reader: &mut R) -> io::Result<Self>
+    where
+        Self: Sized,
+        R: io::Read,
+    {
+        let prefix: [u8; 4] = Readable::read(reader)?;
+        if prefix != T::PREFIX {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Invalid prefix for ExtendedPayload: expected {:02x?}, got {:02x?}",
+                    T::PREFIX,
+                    prefix
+                ),
+            ));
+        }
+
+        Ok(Self(T::deserialize_reader(reader)?))
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl<T: PayloadExtension> Writeable for ExtendedPayload<T> {
+    fn written_size(&self) -> usize {
+        T::PREFIX.len()
+            + self
+                .0
+                .try_to_vec()
+                .expect("serializing into a vector can't fail")
+                .len()
+    }
+
+    fn write<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        T::PREFIX.write(writer)?;
+        self.0.serialize(writer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_current_version_prefix() {
+        let transfer = NativeTokenTransfer {
+            version: NativeTokenTransfer::<EmptyPayload>::CURRENT_VERSION,
+            amount: TrimmedAmount::new(1, 8),
+            source_token: [1u8; 32],
+            to_chain: ChainId { id: 2 },
+            to: [3u8; 32],
+            additional_payload: EmptyPayload {},
+        };
+
+        let encoded = TypePrefixedPayload::to_vec_payload(&transfer);
+        assert_eq!(encoded[..4], [0x99, 0x4E, 0x54, 0x54]);
+    }
+
+    #[test]
+    fn test_unknown_version_is_decoded() {
+        let transfer = NativeTokenTransfer {
+            version: 0x55,
+            amount: TrimmedAmount::new(1, 8),
+            source_token: [1u8; 32],
+            to_chain: ChainId { id: 2 },
+            to: [3u8; 32],
+            additional_payload: EmptyPayload {},
+        };
+
+        let encoded = TypePrefixedPayload::to_vec_payload(&transfer);
+        let decoded: NativeTokenTransfer<EmptyPayload> =
+            TypePrefixedPayload::read_payload(&mut &encoded[..]).unwrap();
+        assert_eq!(decoded, transfer);
+    }
+
+    #[cfg(feature = "anchor")]
+    #[test]
+    fn test_borsh_layout_has_no_version() {
+        let transfer = NativeTokenTransfer {
+            version: NativeTokenTransfer::<EmptyPayload>::CURRENT_VERSION,
+            amount: TrimmedAmount::new(1234567, 7),
+            source_token: [1u8; 32],
+            to_chain: ChainId { id: 2 },
+            to: [3u8; 32],
+            additional_payload: EmptyPayload {},
+        };
+
+        // the layout of messages stored before the version was introduced
+        let mut expected = Vec::new();
+        expected.extend_from_slice(&1234567u64.to_le_bytes());
+        expected.push(7);
+        expected.extend_from_slice(&[1u8; 32]);
+        expected.extend_from_slice(&2u16.to_le_bytes());
+        expected.extend_from_slice(&[3u8; 32]);
+
+        let encoded = transfer.try_to_vec().unwrap();
+        assert_eq!(encoded, expected);
+        assert_eq!(
+            encoded.len(),
+            NativeTokenTransfer::<EmptyPayload>::INIT_SPACE
+        );
+
+        let decoded = NativeTokenTransfer::<EmptyPayload>::deserialize(&mut &expected[..]).unwrap();
+        assert_eq!(decoded, transfer);
+    }
+
+    #[cfg(feature = "anchor")]
+    #[test]
+    fn test_borsh_rejects_unknown_version() {
+        let transfer = NativeTokenTransfer {
+            version: 0x55,
+            amount: TrimmedAmount::new(1, 8),
+            source_token: [1u8; 32],
+            to_chain: ChainId { id: 2 },
+            to: [3u8; 32],
+            additional_payload: EmptyPayload {},
+        };
+
+        assert_eq!(
+            transfer.try_to_vec().unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[cfg(feature = "anchor")]
+    #[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+    struct Swap {
+        min_amount_out: u64,
+        #[max_len(8)]
+        route: Vec<u8>,
+    }
+
+    #[cfg(feature = "anchor")]
+    impl PayloadExtension for Swap {
+        const PREFIX: [u8; 4] = *b"SWAP";
+    }
+
+    #[cfg(feature = "anchor")]
+    #[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+    struct Memo {
+        id: u64,
+    }
+
+    #[cfg(feature = "anchor")]
+    impl PayloadExtension for Memo {
+        const PREFIX: [u8; 4] = *b"MEMO";
+    }
+
+    #[cfg(feature = "anchor")]
+    fn swap_transfer() -> NativeTokenTransfer<ExtendedPayload<Swap>> {
+        NativeTokenTransfer {
+            version: NativeTokenTransfer::<EmptyPayload>::CURRENT_VERSION,
+            amount: TrimmedAmount::new(1, 8),
+            source_token: [1u8; 32],
+            to_chain: ChainId { id: 2 },
+            to: [3u8; 32],
+            additional_payload: ExtendedPayload(Swap {
+                min_amount_out: 42,
+                route: vec![4, 5, 6],
+            }),
+        }
+    }
+
+    #[cfg(feature = "anchor")]
+    #[test]
+    fn test_extended_payload_round_trip() {
+        let transfer = swap_transfer();
+
+        let encoded = TypePrefixedPayload::to_vec_payload(&transfer);
+        assert_eq!(encoded.len(), transfer.written_size());
+
+        // the extension follows the length of the additional payload
+        let payload_len = usize::from(u16::from_be_bytes([encoded[79], encoded[80]]));
+        assert_eq!(payload_len, encoded.len() - 81);
+        assert_eq!(encoded[81..85], *b"SWAP");
+
+        let decoded: NativeTokenTransfer<ExtendedPayload<Swap>> =
+            TypePrefixedPayload::read_payload(&mut &encoded[..]).unwrap();
+        assert_eq!(decoded, transfer);
+    }
+
+    #[cfg(feature = "anchor")]
+    #[test]
+    fn test_extended_payload_prefix_mismatch() {
+        let encoded = TypePrefixedPayload::to_vec_payload(&swap_transfer());
+
+        let err =
+            <NativeTokenTransfer<ExtendedPayload<Memo>> as TypePrefixedPayload>::read_payload(
+                &mut &encoded[..],
+            )
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err
+            .to_string()
+            .contains("Invalid prefix for ExtendedPayload"));
+    }
+}
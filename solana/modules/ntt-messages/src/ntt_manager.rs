@@ -12,8 +12,11 @@ use crate::utils::maybe_space::MaybeSpace;
     feature = "anchor",
     derive(AnchorSerialize, AnchorDeserialize, InitSpace)
 )]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NttManagerMessage<A: MaybeSpace> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::hex_bytes"))]
     pub id: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::hex_bytes"))]
     pub sender: [u8; 32],
     pub payload: A,
 }
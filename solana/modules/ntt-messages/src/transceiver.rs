@@ -17,14 +17,20 @@ pub trait Transceiver {
     feature = "anchor",
     derive(AnchorSerialize, AnchorDeserialize, InitSpace)
 )]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TransceiverMessageData<A: MaybeSpace> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::hex_bytes"))]
     pub source_ntt_manager: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::hex_bytes"))]
     pub recipient_ntt_manager: [u8; 32],
     pub ntt_manager_payload: NttManagerMessage<A>,
 }
 
 /// This struct is for zero-copy deserialization of
 /// `ValidatedTransceiverMessage::message()` in the redeem ix
+///
+/// The span is checked to be long enough for the fixed-size fields in
+/// [`Self::parse`], so the accessors below never read out of bounds.
 pub struct TransceiverMessageDataBytes<'a, A: MaybeSpace> {
     _phantom: PhantomData<A>,
     span: &'a [u8],
@@ -37,34 +43,49 @@ impl<A: MaybeSpace> AsRef<[u8]> for TransceiverMessageDataBytes<'_, A> {
 }
 
 impl<'a, A: MaybeSpace> TransceiverMessageDataBytes<'a, A> {
+    /// The length of the source and recipient manager addresses that precede
+    /// the manager payload.
+    const HEADER_LEN: usize = 64;
+
     pub fn source_ntt_manager(&self) -> [u8; 32] {
         self.span[..32].try_into().unwrap()
     }
 
     pub fn recipient_ntt_manager(&self) -> [u8; 32] {
-        self.span[32..64].try_into().unwrap()
+        self.span[32..Self::HEADER_LEN].try_into().unwrap()
     }
 
-    pub fn ntt_manager_payload(&self) -> NttManagerMessage<A>
+    #[cfg(feature = "anchor")]
+    pub fn ntt_manager_payload(&self) -> io::Result<NttManagerMessage<A>>
     where
         A: AnchorDeserialize,
     {
-        NttManagerMessage::deserialize(&mut &self.span[64..]).unwrap()
+        NttManagerMessage::deserialize(&mut &self.span[Self::HEADER_LEN..])
     }
 
-    pub fn parse(span: &'a [u8]) -> TransceiverMessageDataBytes<'a, A> {
-        TransceiverMessageDataBytes {
+    pub fn parse(span: &'a [u8]) -> io::Result<TransceiverMessageDataBytes<'a, A>> {
+        if span.len() < Self::HEADER_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "TransceiverMessageData too short",
+            ));
+        }
+        Ok(TransceiverMessageDataBytes {
             _phantom: PhantomData,
             span,
-        }
+        })
     }
 }
 
 #[derive(Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TransceiverMessage<E: Transceiver, A: MaybeSpace> {
+    #[cfg_attr(feature = "serde", serde(skip))]
     _phantom: PhantomData<E>,
     // TODO: check peer registration at the ntt_manager level
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub message_data: TransceiverMessageData<A>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::hex_bytes"))]
     pub transceiver_payload: Vec<u8>,
 }
 
@@ -153,10 +174,7 @@ where
 
         let source_ntt_manager = Readable::read(reader)?;
         let recipient_ntt_manager = Readable::read(reader)?;
-        // TODO: we need a way to easily check that decoding the payload
-        // consumes the expected amount of bytes
-        let _ntt_manager_payload_len: u16 = Readable::read(reader)?;
-        let ntt_manager_payload = NttManagerMessage::read(reader)?;
+        let (ntt_manager_payload, _unconsumed) = Self::read_ntt_manager_payload(reader)?;
         let transceiver_payload_len: u16 = Readable::read(reader)?;
         let mut transceiver_payload = vec![0; transceiver_payload_len as usize];
         reader.read_exact(&mut transceiver_payload)?;
@@ -170,6 +188,47 @@ where
     }
 }
 
+impl<E: Transceiver, A: TypePrefixedPayload> TransceiverMessage<E, A>
+where
+    A: MaybeSpace,
+{
+    /// Reads the length-prefixed manager payload, and returns it along with
+    /// the number of its bytes that decoding it as `A` left unread (e.g. an
+    /// additional payload that `A` has no room for). These bytes are skipped,
+    /// so that the transceiver payload is read from the right place.
+    fn read_ntt_manager_payload<R>(reader: &mut R) -> io::Result<(NttManagerMessage<A>, usize)>
+    where
+        R: io::Read,
+    {
+        let ntt_manager_payload_len: u16 = Readable::read(reader)?;
+        let mut ntt_manager_payload = vec![0; ntt_manager_payload_len as usize];
+        reader.read_exact(&mut ntt_manager_payload)?;
+
+        let mut span = &ntt_manager_payload[..];
+        let message = NttManagerMessage::read(&mut span)?;
+        Ok((message, span.len()))
+    }
+
+    /// The number of bytes of the manager payload in `data` (an encoded
+    /// [`TransceiverMessage`]) that decoding it as `A` leaves unread. These
+    /// are dropped by [`Readable::read`].
+    pub fn unconsumed_payload_len(mut data: &[u8]) -> io::Result<usize> {
+        let reader = &mut data;
+        let prefix: [u8; 4] = Readable::read(reader)?;
+        if prefix != E::PREFIX {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Invalid prefix for TransceiverMessage",
+            ));
+        }
+
+        let _source_ntt_manager: [u8; 32] = Readable::read(reader)?;
+        let _recipient_ntt_manager: [u8; 32] = Readable::read(reader)?;
+        let (_, unconsumed) = Self::read_ntt_manager_payload(reader)?;
+        Ok(unconsumed)
+    }
+}
+
 impl<E: Transceiver, A: TypePrefixedPayload> Writeable for TransceiverMessage<E, A>
 where
     A: MaybeSpace,
@@ -224,6 +283,21 @@ mod test {
     };
 
     use super::*;
+
+    #[test]
+    fn test_transceiver_message_data_bytes_too_short() {
+        let span = [0xAB; 64];
+        for len in 0..span.len() {
+            assert!(TransceiverMessageDataBytes::<EmptyPayload>::parse(&span[..len]).is_err());
+        }
+
+        let bytes = TransceiverMessageDataBytes::<EmptyPayload>::parse(&span).unwrap();
+        assert_eq!(bytes.source_ntt_manager(), [0xAB; 32]);
+        assert_eq!(bytes.recipient_ntt_manager(), [0xAB; 32]);
+        // the manager payload is missing
+        #[cfg(feature = "anchor")]
+        assert!(bytes.ntt_manager_payload().is_err());
+    }
     //
     #[test]
     fn test_deserialize_transceiver_message() {
@@ -256,6 +330,7 @@ mod test {
                         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
                     ],
                     payload: NativeTokenTransfer {
+                        version: NativeTokenTransfer::<EmptyPayload>::CURRENT_VERSION,
                         amount: TrimmedAmount {
                             amount: 1234567,
                             decimals: 7,
@@ -359,6 +434,7 @@ mod test {
                         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
                     ],
                     payload: NativeTokenTransfer {
+                        version: NativeTokenTransfer::<EmptyPayload>::CURRENT_VERSION,
                         amount: TrimmedAmount {
                             amount: 1234567,
                             decimals: 7,
@@ -466,6 +542,7 @@ mod test {
                         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
                     ],
                     payload: NativeTokenTransfer {
+                        version: NativeTokenTransfer::<EmptyPayload>::CURRENT_VERSION,
                         amount: TrimmedAmount {
                             amount: 1234567,
                             decimals: 7,
@@ -496,4 +573,32 @@ mod test {
         let encoded = TypePrefixedPayload::to_vec_payload(&expected);
         assert_eq!(encoded, data);
     }
+
+    #[test]
+    fn test_unconsumed_payload_is_skipped() {
+        let data = hex::decode(
+            include_str!(
+                "../../../../evm/test/payloads/transceiver_message_with_32byte_payload.txt"
+            )
+            .trim_end(),
+        )
+        .unwrap();
+
+        // the additional payload (with its length prefix) is left unread...
+        assert_eq!(
+            TransceiverMessage::<WormholeTransceiver, NativeTokenTransfer<EmptyPayload>>::unconsumed_payload_len(&data).unwrap(),
+            2 + 32
+        );
+        assert_eq!(
+            TransceiverMessage::<WormholeTransceiver, NativeTokenTransfer<MockPayload>>::unconsumed_payload_len(&data).unwrap(),
+            0
+        );
+
+        // ...but skipped, so the rest of the message still decodes
+        let mut vec = &data[..];
+        let message: TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<EmptyPayload>> =
+            TypePrefixedPayload::read_payload(&mut vec).unwrap();
+        assert_eq!(message.transceiver_payload, Vec::<u8>::new());
+        assert_eq!(vec.len(), 0);
+    }
 }
@@ -19,16 +19,50 @@ impl WormholeTransceiver {
     pub const INFO_PREFIX: [u8; 4] = [0x9c, 0x23, 0xbd, 0x3b];
 
     pub const PEER_INFO_PREFIX: [u8; 4] = [0x18, 0xfc, 0x67, 0xc2];
+
+    /// bytes4(keccak256("WormholeTransceiverHeartbeat"))
+    pub const HEARTBEAT_PREFIX: [u8; 4] = [0xa3, 0x5a, 0xc9, 0xfa];
 }
 
 // * Transceiver info
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WormholeTransceiverInfo {
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::hex_bytes"))]
     pub manager_address: [u8; 32],
     pub manager_mode: Mode,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::hex_bytes"))]
     pub token_address: [u8; 32],
     pub token_decimals: u8,
+    /// Hash of the token's name, symbol and URI, so that the destination chain
+    /// can tie the token address to its human-readable identity. All zeroes if
+    /// the token has no metadata.
+    ///
+    /// NOTE: this is an extension of the original layout, appended at the end
+    /// behind a version byte ([`Self::METADATA_HASH_EXTENSION_VERSION`]).
+    /// Payloads without it (e.g. from older deployments) decode to `None`, and
+    /// unknown extension versions are rejected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "crate::utils::hex_bytes::option"
+        )
+    )]
+    pub token_metadata_hash: Option<[u8; 32]>,
+}
+
+impl WormholeTransceiverInfo {
+    /// Size of the original layout, without the metadata hash extension.
+    pub const BASE_SIZE: usize = 32 + 1 + 32 + 1;
+
+    /// Version byte that precedes the metadata hash.
+    pub const METADATA_HASH_EXTENSION_VERSION: u8 = 1;
+
+    /// Size of the metadata hash extension, including its version byte.
+    pub const METADATA_HASH_EXTENSION_SIZE: usize = 1 + 32;
 }
 
 #[cfg(feature = "anchor")]
@@ -46,7 +80,7 @@ impl AnchorSerialize for WormholeTransceiverInfo {
 }
 
 impl Readable for WormholeTransceiverInfo {
-    const SIZE: Option<usize> = Some(32 + 1 + 32 + 1);
+    const SIZE: Option<usize> = None;
 
     fn read<R>(reader: &mut R) -> std::io::Result<Self>
     where
@@ -66,18 +100,36 @@ impl Readable for WormholeTransceiverInfo {
         let token_address = <[u8; 32]>::read(reader)?;
         let token_decimals = u8::read(reader)?;
 
+        // the extension is only present if there are bytes left
+        let mut version = [0u8; 1];
+        let token_metadata_hash = if reader.read(&mut version)? == 0 {
+            None
+        } else if version[0] == WormholeTransceiverInfo::METADATA_HASH_EXTENSION_VERSION {
+            Some(<[u8; 32]>::read(reader)?)
+        } else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Unknown extension version",
+            ));
+        };
+
         Ok(WormholeTransceiverInfo {
             manager_address,
             manager_mode,
             token_address,
             token_decimals,
+            token_metadata_hash,
         })
     }
 }
 
 impl Writeable for WormholeTransceiverInfo {
     fn written_size(&self) -> usize {
-        WormholeTransceiver::INFO_PREFIX.len() + WormholeTransceiverInfo::SIZE.unwrap()
+        WormholeTransceiver::INFO_PREFIX.len()
+            + WormholeTransceiverInfo::BASE_SIZE
+            + self
+                .token_metadata_hash
+                .map_or(0, |_| WormholeTransceiverInfo::METADATA_HASH_EXTENSION_SIZE)
     }
 
     fn write<W>(&self, writer: &mut W) -> std::io::Result<()>
@@ -88,7 +140,12 @@ impl Writeable for WormholeTransceiverInfo {
         self.manager_address.write(writer)?;
         self.manager_mode.write(writer)?;
         self.token_address.write(writer)?;
-        self.token_decimals.write(writer)
+        self.token_decimals.write(writer)?;
+        if let Some(hash) = self.token_metadata_hash {
+            WormholeTransceiverInfo::METADATA_HASH_EXTENSION_VERSION.write(writer)?;
+            hash.write(writer)?;
+        }
+        Ok(())
     }
 }
 
@@ -99,8 +156,10 @@ impl TypePrefixedPayload for WormholeTransceiverInfo {
 // * Transceiver registration
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WormholeTransceiverRegistration {
     pub chain_id: ChainId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::hex_bytes"))]
     pub transceiver_address: [u8; 32],
 }
 
@@ -163,6 +222,79 @@ impl TypePrefixedPayload for WormholeTransceiverRegistration {
     const TYPE: Option<u8> = None;
 }
 
+// * Transceiver heartbeat
+
+/// Liveness signal, so watchers can tell that a deployment is still able to
+/// publish messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WormholeTransceiverHeartbeat {
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::hex_bytes"))]
+    pub manager_address: [u8; 32],
+    /// Unix timestamp (in seconds) at which the heartbeat was sent.
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::u64_string"))]
+    pub timestamp: u64,
+}
+
+#[cfg(feature = "anchor")]
+impl AnchorDeserialize for WormholeTransceiverHeartbeat {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        Readable::read(reader)
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl AnchorSerialize for WormholeTransceiverHeartbeat {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Writeable::write(self, writer)
+    }
+}
+
+impl Readable for WormholeTransceiverHeartbeat {
+    const SIZE: Option<usize> = Some(32 + 8);
+
+    fn read<R>(reader: &mut R) -> std::io::Result<Self>
+    where
+        Self: Sized,
+        R: std::io::Read,
+    {
+        let prefix = <[u8; 4]>::read(reader)?;
+        if prefix != WormholeTransceiver::HEARTBEAT_PREFIX {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Invalid prefix",
+            ));
+        }
+
+        let manager_address = <[u8; 32]>::read(reader)?;
+        let timestamp = u64::read(reader)?;
+
+        Ok(WormholeTransceiverHeartbeat {
+            manager_address,
+            timestamp,
+        })
+    }
+}
+
+impl Writeable for WormholeTransceiverHeartbeat {
+    fn written_size(&self) -> usize {
+        WormholeTransceiver::HEARTBEAT_PREFIX.len() + WormholeTransceiverHeartbeat::SIZE.unwrap()
+    }
+
+    fn write<W>(&self, writer: &mut W) -> std::io::Result<()>
+    where
+        W: std::io::Write,
+    {
+        WormholeTransceiver::HEARTBEAT_PREFIX.write(writer)?;
+        self.manager_address.write(writer)?;
+        self.timestamp.write(writer)
+    }
+}
+
+impl TypePrefixedPayload for WormholeTransceiverHeartbeat {
+    const TYPE: Option<u8> = None;
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -189,6 +321,7 @@ mod test {
                 0x00, 0x00, 0x00, 0x00,
             ],
             token_decimals: 16,
+            token_metadata_hash: None,
         };
         assert_eq!(message, expected);
         assert_eq!(vec.len(), 0);
@@ -197,6 +330,65 @@ mod test {
         assert_eq!(encoded, data);
     }
 
+    #[test]
+    fn test_transceiver_info_metadata_hash_extension() {
+        let legacy = WormholeTransceiverInfo {
+            manager_address: [0x77; 32],
+            manager_mode: Mode::Locking,
+            token_address: [0x88; 32],
+            token_decimals: 9,
+            token_metadata_hash: None,
+        };
+        let extended = WormholeTransceiverInfo {
+            token_metadata_hash: Some([0x99; 32]),
+            ..legacy.clone()
+        };
+
+        let legacy_encoded = TypePrefixedPayload::to_vec_payload(&legacy);
+        let extended_encoded = TypePrefixedPayload::to_vec_payload(&extended);
+
+        // the hash is appended to the original layout, behind its version
+        assert_eq!(legacy_encoded.len(), 4 + 66);
+        assert_eq!(legacy_encoded.len(), legacy.written_size());
+        assert_eq!(extended_encoded.len(), extended.written_size());
+        assert_eq!(extended_encoded[..legacy_encoded.len()], legacy_encoded);
+        assert_eq!(extended_encoded[legacy_encoded.len()], 1);
+        assert_eq!(extended_encoded[legacy_encoded.len() + 1..], [0x99; 32]);
+
+        // old payloads decode with the new layout
+        let mut vec = &legacy_encoded[..];
+        let decoded: WormholeTransceiverInfo = TypePrefixedPayload::read_payload(&mut vec).unwrap();
+        assert_eq!(decoded, legacy);
+        assert_eq!(vec.len(), 0);
+
+        let mut vec = &extended_encoded[..];
+        let decoded: WormholeTransceiverInfo = TypePrefixedPayload::read_payload(&mut vec).unwrap();
+        assert_eq!(decoded, extended);
+        assert_eq!(vec.len(), 0);
+
+        // an old decoder (which reads the original fields only) sees the same
+        // info in new payloads, and leaves the extension unread
+        let mut vec = &extended_encoded[4..];
+        assert_eq!(<[u8; 32]>::read(&mut vec).unwrap(), legacy.manager_address);
+        assert_eq!(Mode::read(&mut vec).unwrap(), legacy.manager_mode);
+        assert_eq!(<[u8; 32]>::read(&mut vec).unwrap(), legacy.token_address);
+        assert_eq!(u8::read(&mut vec).unwrap(), legacy.token_decimals);
+        assert_eq!(
+            vec.len(),
+            WormholeTransceiverInfo::METADATA_HASH_EXTENSION_SIZE
+        );
+
+        // a truncated hash is an error, rather than silently dropped
+        let mut vec = &extended_encoded[..extended_encoded.len() - 1];
+        assert!(<WormholeTransceiverInfo as TypePrefixedPayload>::read_payload(&mut vec).is_err());
+
+        // so is an unknown extension version
+        let mut unknown = extended_encoded.clone();
+        unknown[legacy_encoded.len()] = 2;
+        let mut vec = &unknown[..];
+        assert!(<WormholeTransceiverInfo as TypePrefixedPayload>::read_payload(&mut vec).is_err());
+    }
+
     #[test]
     fn test_deserialize_transceiver_registration() {
         let data = hex::decode(
@@ -222,4 +414,23 @@ mod test {
         let encoded = TypePrefixedPayload::to_vec_payload(&expected);
         assert_eq!(encoded, data);
     }
+
+    #[test]
+    fn test_transceiver_heartbeat_roundtrip() {
+        let heartbeat = WormholeTransceiverHeartbeat {
+            manager_address: [0xBA; 32],
+            timestamp: 1_700_000_000,
+        };
+
+        let encoded = TypePrefixedPayload::to_vec_payload(&heartbeat);
+        assert_eq!(encoded.len(), heartbeat.written_size());
+        assert_eq!(encoded[..4], WormholeTransceiver::HEARTBEAT_PREFIX);
+        assert_eq!(encoded[36..], 1_700_000_000u64.to_be_bytes());
+
+        let mut vec = &encoded[..];
+        let message: WormholeTransceiverHeartbeat =
+            TypePrefixedPayload::read_payload(&mut vec).unwrap();
+        assert_eq!(message, heartbeat);
+        assert_eq!(vec.len(), 0);
+    }
 }
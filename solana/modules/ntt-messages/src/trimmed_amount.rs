@@ -23,7 +23,9 @@ pub const TRIMMED_DECIMALS: u8 = 8;
     feature = "anchor",
     derive(AnchorSerialize, AnchorDeserialize, InitSpace)
 )]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrimmedAmount {
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::u64_string"))]
     pub amount: u64,
     pub decimals: u8,
 }
@@ -82,12 +84,18 @@ impl TrimmedAmount {
         }
     }
 
+    /// The decimals amounts are trimmed to when sent from a token with
+    /// `from_decimals` to one with `to_decimals`.
+    pub fn trimmed_decimals(from_decimals: u8, to_decimals: u8) -> u8 {
+        TRIMMED_DECIMALS.min(from_decimals).min(to_decimals)
+    }
+
     pub fn trim(
         amount: u64,
         from_decimals: u8,
         to_decimals: u8,
     ) -> Result<TrimmedAmount, ScalingError> {
-        let to_decimals = TRIMMED_DECIMALS.min(from_decimals).min(to_decimals);
+        let to_decimals = Self::trimmed_decimals(from_decimals, to_decimals);
         let amount = Self::scale(amount, from_decimals, to_decimals)?;
         Ok(Self {
             amount,
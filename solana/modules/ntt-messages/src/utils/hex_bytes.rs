@@ -0,0 +1,51 @@
+//! `#[serde(with = "crate::utils::hex_bytes")]`: byte strings as hex strings.
+
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+pub fn serialize<S, T>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: AsRef<[u8]>,
+{
+    serializer.serialize_str(&hex::encode(bytes))
+}
+
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: TryFrom<Vec<u8>>,
+{
+    let s = String::deserialize(deserializer)?;
+    let bytes = hex::decode(s).map_err(D::Error::custom)?;
+    T::try_from(bytes).map_err(|_| D::Error::custom("invalid length"))
+}
+
+/// `#[serde(with = "crate::utils::hex_bytes::option")]`: optional byte
+/// strings as hex strings (or `null`).
+pub mod option {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S, T>(bytes: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: AsRef<[u8]>,
+    {
+        match bytes {
+            Some(bytes) => super::serialize(bytes, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: TryFrom<Vec<u8>>,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|s| {
+                let bytes = hex::decode(s).map_err(D::Error::custom)?;
+                T::try_from(bytes).map_err(|_| D::Error::custom("invalid length"))
+            })
+            .transpose()
+    }
+}
@@ -1 +1,5 @@
+#[cfg(feature = "serde")]
+pub mod hex_bytes;
 pub mod maybe_space;
+#[cfg(feature = "serde")]
+pub mod u64_string;
@@ -0,0 +1,20 @@
+//! `#[serde(with = "crate::utils::u64_string")]`: `u64`s as decimal strings,
+//! as they don't fit in a JavaScript number.
+
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+pub fn serialize<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&value.to_string())
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(D::Error::custom)
+}
@@ -4,9 +4,13 @@ use std::result::Result as StdResult;
 
 use crate::error::NTTError;
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug, AnchorDeserialize, AnchorSerialize, InitSpace)]
+/// Stored as little-endian bytes rather than a `u128` so that it can be embedded
+/// in zero-copy accounts without imposing an alignment requirement. The borsh
+/// encoding is the same either way.
+#[zero_copy]
+#[derive(PartialEq, Eq, Debug, AnchorDeserialize, AnchorSerialize, InitSpace)]
 pub struct Bitmap {
-    map: u128,
+    map: [u8; 16],
 }
 
 impl Default for Bitmap {
@@ -19,20 +23,26 @@ impl Bitmap {
     pub const BITS: u8 = 128;
 
     pub fn new() -> Self {
-        Bitmap { map: 0 }
+        Self::from_value(0)
     }
 
     pub fn from_value(value: u128) -> Self {
-        Bitmap { map: value }
+        Bitmap {
+            map: value.to_le_bytes(),
+        }
+    }
+
+    pub fn value(&self) -> u128 {
+        u128::from_le_bytes(self.map)
     }
 
     pub fn set(&mut self, index: u8, value: bool) -> StdResult<(), NTTError> {
         if index >= Self::BITS {
             return Err(NTTError::BitmapIndexOutOfBounds);
         }
-        let mut bm = BM::<128>::from_value(self.map);
+        let mut bm = BM::<128>::from_value(self.value());
         bm.set(usize::from(index), value);
-        self.map = *bm.as_value();
+        self.map = bm.as_value().to_le_bytes();
         Ok(())
     }
 
@@ -40,25 +50,25 @@ impl Bitmap {
         if index >= Self::BITS {
             return Err(NTTError::BitmapIndexOutOfBounds);
         }
-        Ok(BM::<128>::from_value(self.map).get(usize::from(index)))
+        Ok(BM::<128>::from_value(self.value()).get(usize::from(index)))
     }
 
     pub fn count_enabled_votes(&self, enabled: Bitmap) -> u8 {
-        let bm = BM::<128>::from_value(self.map) & BM::<128>::from_value(enabled.map);
+        let bm = BM::<128>::from_value(self.value()) & BM::<128>::from_value(enabled.value());
         bm.len()
             .try_into()
             .expect("Bitmap length must not exceed the bounds of u8")
     }
 
     pub fn len(self) -> u8 {
-        BM::<128>::from_value(self.map)
+        BM::<128>::from_value(self.value())
             .len()
             .try_into()
             .expect("Bitmap length must not exceed the bounds of u8")
     }
 
     pub fn is_empty(self) -> bool {
-        BM::<128>::from_value(self.map).is_empty()
+        BM::<128>::from_value(self.value()).is_empty()
     }
 }
 
@@ -96,6 +106,18 @@ mod tests {
         assert_eq!(128, max_bitmap.count_enabled_votes(max_bitmap));
     }
 
+    #[test]
+    fn test_bitmap_borsh_layout() {
+        // the byte representation must match the `u128` it replaced
+        let bm = Bitmap::from_value(0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10);
+        assert_eq!(
+            bm.try_to_vec().unwrap(),
+            0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10u128
+                .try_to_vec()
+                .unwrap()
+        );
+    }
+
     #[test]
     fn test_bitmap_get_out_of_bounds() {
         let bm = Bitmap::new();
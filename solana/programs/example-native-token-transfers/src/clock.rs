@@ -4,11 +4,12 @@
 //! This makes it easy to unit test functions that depend on the current time
 //! without having to instantiate a Solana runtime.
 
-#[cfg(not(test))]
 use anchor_lang::prelude::*;
 
 use anchor_lang::solana_program::clock::UnixTimestamp;
 
+use crate::error::NTTError;
+
 #[cfg(test)]
 static TEST_TIMESTAMP: std::sync::Mutex<i64> = std::sync::Mutex::new(0);
 
@@ -21,7 +22,44 @@ pub fn current_timestamp() -> UnixTimestamp {
     return *TEST_TIMESTAMP.lock().unwrap();
 }
 
+/// The current timestamp, for instructions that derive release timestamps
+/// from it. They read it once, so that every decision in the instruction is
+/// made at the same time.
+///
+/// Fails with [`NTTError::InvalidClock`] if the clock is not positive (as on
+/// misconfigured local validators), as release timestamps computed from it
+/// would be in the past, making queued transfers releasable straight away.
+pub fn current_time() -> Result<UnixTimestamp> {
+    check_timestamp(current_timestamp())
+}
+
+fn check_timestamp(timestamp: UnixTimestamp) -> Result<UnixTimestamp> {
+    if timestamp <= 0 {
+        msg!("Invalid clock: {}", timestamp);
+        return Err(NTTError::InvalidClock.into());
+    }
+    Ok(timestamp)
+}
+
 #[cfg(test)]
 pub fn set_test_timestamp(timestamp: UnixTimestamp) {
     *TEST_TIMESTAMP.lock().unwrap() = timestamp;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_timestamp() {
+        assert_eq!(check_timestamp(1).unwrap(), 1);
+        assert_eq!(check_timestamp(i64::MAX).unwrap(), i64::MAX);
+
+        for timestamp in [0, -1, i64::MIN] {
+            assert_eq!(
+                check_timestamp(timestamp).unwrap_err(),
+                Error::from(NTTError::InvalidClock)
+            );
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Guard rails for mints with the Token-2022 confidential transfer extension.
+//!
+//! NTT only deals in public balances: inbound transfers are credited to the
+//! recipient's public balance. Confidential transfers are not supported (yet),
+//! so inbound transfers to recipients that don't accept non-confidential
+//! credits are rejected instead.
+//! Token-2022 configures non-confidential credits per token account rather
+//! than on the mint, which is why the check happens at release time, on the
+//! recipient. It only applies to mints with the extension (see
+//! [`crate::config::Config::confidential_transfers`]).
+
+use anchor_lang::prelude::*;
+use spl_token_2022::{
+    extension::{
+        confidential_transfer::{ConfidentialTransferAccount, ConfidentialTransferMint},
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+    state::{Account, Mint},
+};
+
+/// Whether `mint` has the confidential transfer extension.
+pub fn has_confidential_transfers(mint: &AccountInfo) -> Result<bool> {
+    // classic token mints don't have extensions
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(false);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+    Ok(mint.get_extension::<ConfidentialTransferMint>().is_ok())
+}
+
+/// Whether `token_account` can be credited with a public balance.
+pub fn accepts_non_confidential_credits(token_account: &AccountInfo) -> Result<bool> {
+    if *token_account.owner != spl_token_2022::ID {
+        return Ok(true);
+    }
+
+    let data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<Account>::unpack(&data)?;
+    Ok(account
+        .get_extension::<ConfidentialTransferAccount>()
+        .map_or(true, |extension| {
+            extension.allow_non_confidential_credits.into()
+        }))
+}
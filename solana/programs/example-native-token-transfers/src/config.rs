@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use ntt_messages::{chain_id::ChainId, mode::Mode};
 use std::ops::{Deref, DerefMut};
 
-use crate::bitmap::Bitmap;
+use crate::{bitmap::Bitmap, clock::current_timestamp, error::NTTError};
 
 /// This is a hack to re-export some modules that anchor generates as
 /// pub(crate), as it's not possible to directly re-export a module with a
@@ -12,12 +12,20 @@ use crate::bitmap::Bitmap;
 /// Within this crate, this module should not be used. Outside of this crate,
 /// importing `anchor_reexports::*` achieves what we want.
 pub mod anchor_reexports {
-    pub mod __cpi_client_accounts_not_paused_config {
-        pub use super::super::__cpi_client_accounts_not_paused_config::*;
+    pub mod __cpi_client_accounts_not_paused_outbound_config {
+        pub use super::super::__cpi_client_accounts_not_paused_outbound_config::*;
     }
 
-    pub mod __client_accounts_not_paused_config {
-        pub use super::super::__client_accounts_not_paused_config::*;
+    pub mod __client_accounts_not_paused_outbound_config {
+        pub use super::super::__client_accounts_not_paused_outbound_config::*;
+    }
+
+    pub mod __cpi_client_accounts_not_paused_inbound_config {
+        pub use super::super::__cpi_client_accounts_not_paused_inbound_config::*;
+    }
+
+    pub mod __client_accounts_not_paused_inbound_config {
+        pub use super::super::__client_accounts_not_paused_inbound_config::*;
     }
 }
 
@@ -50,25 +58,245 @@ pub struct Config {
     /// Bitmap of enabled transceivers.
     /// The maximum number of transceivers is equal to [`Bitmap::BITS`].
     pub enabled_transceivers: Bitmap,
-    /// Pause the program. This is useful for upgrades and other maintenance.
-    pub paused: bool,
+    /// Pause outbound transfers (sending and releasing them). This is useful
+    /// for upgrades and other maintenance, or to halt outflows during an
+    /// incident without stranding inbound transfers (see [`paused_inbound`]).
+    pub paused_outbound: bool,
     /// The custody account that holds tokens in locking mode.
     pub custody: Pubkey,
+    /// Whether routes to newly registered peers are enabled straight away.
+    /// When false, the owner has to enable each route explicitly (via
+    /// `enable_route`) before any transfers to or from that chain are accepted.
+    pub routes_default_enabled: bool,
+    /// Decimals of [`mint`], cached here so that inbound amounts can be
+    /// untrimmed without loading the mint account.
+    pub mint_decimals: u8,
+    /// Program notified whenever a transceiver receives a message (see
+    /// [`crate::receive_hook`]).
+    pub receive_hook: Option<Pubkey>,
+    /// Wormhole core bridge fee collector that message fees are paid into.
+    /// Defaults to the account derived from the core bridge program, and can
+    /// be updated (via `set_fee_collector`) if the core bridge changes it.
+    pub fee_collector: Pubkey,
+    /// Whether [`mint`] has the Token-2022 confidential transfer extension, in
+    /// which case inbound transfers are only released to recipients that
+    /// accept non-confidential credits (see [`crate::confidential_transfer`]).
+    pub confidential_transfers: bool,
+    /// Token account that dust trimmed off outbound transfers is collected
+    /// into (set via `set_dust_account`). When unset, the dust is left in the
+    /// sender's account.
+    pub dust_account: Option<Pubkey>,
+    /// Account that can tune rate limits on behalf of the owner (see
+    /// [`Role::LimitsManager`]). Set and revoked via `set_limits_manager`.
+    pub limits_manager: Option<Pubkey>,
+    /// How old (in seconds) a VAA may be when it's received, measured from
+    /// the timestamp in its body. Older VAAs are rejected by the transceivers.
+    /// Set via `set_max_vaa_age`, unlimited when unset.
+    pub max_vaa_age: Option<u32>,
+    /// What happens to messages whose additional payload this program doesn't
+    /// decode (see [`crate::transfer::Payload`]) when there's no recipient
+    /// callback to pass it on to. When false, the payload is dropped and the
+    /// transfer goes through. When true, redeeming the message fails. Set via
+    /// `set_reject_unconsumed_payload`.
+    pub reject_unconsumed_payload: bool,
+    /// When the deployment is being decommissioned (see
+    /// `begin_decommission`), the time from which `finalize_decommission`
+    /// can be called. Outbound transfers are rejected in the meantime, while
+    /// inbound ones can still be redeemed and released.
+    pub decommission_timestamp: Option<i64>,
+    /// Whether the deployment has been decommissioned for good (see
+    /// `finalize_decommission`).
+    pub terminated: bool,
+    /// Consistency levels outbound transfers may be released with, as a
+    /// bitmap indexed by the level's VAA encoding (see
+    /// [`CONSISTENCY_LEVEL_FINALIZED`] and [`CONSISTENCY_LEVEL_CONFIRMED`]).
+    /// Only finalized by default. Set via `set_allowed_consistency_levels`.
+    pub allowed_consistency_levels: Bitmap,
+    /// Pause inbound transfers (receiving, redeeming and releasing them).
+    /// Set together with [`paused_outbound`] via `set_paused`, or on its own
+    /// via `set_inbound_paused`.
+    pub paused_inbound: bool,
+    /// How much a single sender may transfer out per day (see
+    /// [`crate::queue::outbox::SenderRateLimit`]), unless it has an override.
+    /// Set via `set_sender_limit`, unlimited when unset.
+    pub sender_limit: Option<u64>,
+    /// Whether transfers to a chain with its own
+    /// [`crate::queue::outbox::ChainOutboxRateLimit`] consume from it rather
+    /// than the global [`crate::queue::outbox::OutboxRateLimit`]. Set via
+    /// `set_per_chain_outbound_limits`.
+    pub per_chain_outbound_limits: bool,
+    /// Whether an override has been set for any sender with
+    /// `set_sender_limit_override`, which limits that sender even while
+    /// [`Self::sender_limit`] is unset. Never cleared, see
+    /// [`Self::limits_senders`].
+    pub sender_limit_overrides: bool,
+    /// Whether the tokens locked in custody are tracked in the
+    /// [`crate::custody_ledger::CustodyLedger`], which is created along with
+    /// the deployment. Deployments migrated from before the ledger existed
+    /// don't have one, so transfers don't require it there.
+    pub tracks_custody: bool,
+}
+
+/// VAA encoding of the finalized consistency level.
+pub const CONSISTENCY_LEVEL_FINALIZED: u8 = 32;
+
+/// VAA encoding of the confirmed consistency level.
+pub const CONSISTENCY_LEVEL_CONFIRMED: u8 = 1;
+
+/// How far (in seconds) the timestamp in a VAA body may be ahead of the
+/// clock. Guardians timestamp a message with its block on the source chain,
+/// whose clock can run a little ahead of the Solana one, but a VAA from
+/// further in the future is malformed.
+pub const MAX_VAA_FUTURE_SKEW: i64 = 10 * 60;
+
+/// The [`Config::allowed_consistency_levels`] of new (and migrated)
+/// deployments: finalized only.
+pub fn default_allowed_consistency_levels() -> Bitmap {
+    Bitmap::from_value(1 << CONSISTENCY_LEVEL_FINALIZED)
 }
 
 impl Config {
     pub const SEED_PREFIX: &'static [u8] = b"config";
+
+    /// Whether transfers are paused in both directions, as `set_paused`
+    /// leaves them.
+    pub fn is_paused(&self) -> bool {
+        self.paused_outbound && self.paused_inbound
+    }
+
+    /// Whether transfers have to pass the sender's
+    /// [`crate::queue::outbox::SenderRateLimit`], which is only the case once
+    /// a sender limit or an override has been set.
+    pub fn limits_senders(&self) -> bool {
+        self.sender_limit.is_some() || self.sender_limit_overrides
+    }
+
+    /// Whether `signer` holds `role`. The owner holds every role.
+    pub fn has_role(&self, signer: &Pubkey, role: Role) -> bool {
+        if *signer == self.owner {
+            return true;
+        }
+        match role {
+            Role::Owner => false,
+            Role::LimitsManager => self.limits_manager == Some(*signer),
+        }
+    }
+
+    /// Fails with [`NTTError::VaaTooOld`] if a VAA with body timestamp
+    /// `timestamp` is older than [`Config::max_vaa_age`], and with
+    /// [`NTTError::VaaTimestampInFuture`] if it is more than
+    /// [`MAX_VAA_FUTURE_SKEW`] seconds ahead of the clock.
+    pub fn check_vaa_age(&self, timestamp: u32) -> Result<()> {
+        let age = current_timestamp().saturating_sub(i64::from(timestamp));
+        if age < -MAX_VAA_FUTURE_SKEW {
+            msg!(
+                "VAA timestamp: {}, {}s ahead of the clock",
+                timestamp,
+                age.saturating_neg()
+            );
+            return Err(NTTError::VaaTimestampInFuture.into());
+        }
+
+        let Some(max_vaa_age) = self.max_vaa_age else {
+            return Ok(());
+        };
+        if age > i64::from(max_vaa_age) {
+            msg!(
+                "VAA timestamp: {}, maximum age: {}s",
+                timestamp,
+                max_vaa_age
+            );
+            return Err(NTTError::VaaTooOld.into());
+        }
+        Ok(())
+    }
+
+    /// Fails with [`NTTError::UnconsumedPayload`] if a message being redeemed
+    /// has `unconsumed_payload_len` bytes of additional payload, and
+    /// [`Config::reject_unconsumed_payload`] is set.
+    ///
+    /// NOTE: redeeming never passes the additional payload on to the
+    /// recipient (there is no recipient callback), so any such payload goes
+    /// unconsumed. In particular, the receive hook doesn't consume it: it's
+    /// only notified about the transfer.
+    pub fn check_unconsumed_payload(&self, unconsumed_payload_len: usize) -> Result<()> {
+        if unconsumed_payload_len == 0 {
+            return Ok(());
+        }
+        if self.reject_unconsumed_payload {
+            msg!(
+                "Additional payload of {} bytes can't be consumed",
+                unconsumed_payload_len
+            );
+            return Err(NTTError::UnconsumedPayload.into());
+        }
+        msg!(
+            "Dropping additional payload of {} bytes",
+            unconsumed_payload_len
+        );
+        Ok(())
+    }
+
+    /// Fails with [`NTTError::ConsistencyLevelNotAllowed`] if outbound
+    /// transfers can't be released with `consistency_level` (see
+    /// [`Config::allowed_consistency_levels`]).
+    pub fn check_consistency_level(&self, consistency_level: u8) -> Result<()> {
+        if !self
+            .allowed_consistency_levels
+            .get(consistency_level)
+            .unwrap_or(false)
+        {
+            msg!("Consistency level {} is not allowed", consistency_level);
+            return Err(NTTError::ConsistencyLevelNotAllowed.into());
+        }
+        Ok(())
+    }
+}
+
+/// Roles an admin instruction can require of its signer (see
+/// [`Config::has_role`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// Only the owner.
+    Owner,
+    /// The owner or the [`Config::limits_manager`]. Only required by the rate
+    /// limit instructions.
+    LimitsManager,
+}
+
+#[derive(Accounts)]
+pub struct NotPausedOutboundConfig<'info> {
+    #[account(
+        constraint = !config.terminated @ crate::error::NTTError::DeploymentTerminated,
+        constraint = !config.paused_outbound @ crate::error::NTTError::Paused,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> Deref for NotPausedOutboundConfig<'info> {
+    type Target = Config;
+
+    fn deref(&self) -> &Self::Target {
+        &self.config
+    }
+}
+
+impl<'info> DerefMut for NotPausedOutboundConfig<'info> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.config
+    }
 }
 
 #[derive(Accounts)]
-pub struct NotPausedConfig<'info> {
+pub struct NotPausedInboundConfig<'info> {
     #[account(
-        constraint = !config.paused @ crate::error::NTTError::Paused,
+        constraint = !config.terminated @ crate::error::NTTError::DeploymentTerminated,
+        constraint = !config.paused_inbound @ crate::error::NTTError::Paused,
     )]
     pub config: Account<'info, Config>,
 }
 
-impl<'info> Deref for NotPausedConfig<'info> {
+impl<'info> Deref for NotPausedInboundConfig<'info> {
     type Target = Config;
 
     fn deref(&self) -> &Self::Target {
@@ -76,7 +304,7 @@ impl<'info> Deref for NotPausedConfig<'info> {
     }
 }
 
-impl<'info> DerefMut for NotPausedConfig<'info> {
+impl<'info> DerefMut for NotPausedInboundConfig<'info> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.config
     }
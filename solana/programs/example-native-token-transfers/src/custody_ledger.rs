@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::config::Config;
+
+#[account]
+#[derive(InitSpace)]
+/// The amount of tokens the program believes are held in custody, so that it
+/// can be reconciled against the actual balance of the custody account (see
+/// [`crate::instructions::reconcile_custody`]).
+/// Singleton PDA, created in [`crate::instructions::initialize`].
+pub struct CustodyLedger {
+    pub bump: u8,
+    /// Tokens locked by outbound transfers, minus the tokens unlocked by
+    /// inbound transfers. Only ever non-zero in locking mode.
+    pub locked_amount: u64,
+}
+
+impl CustodyLedger {
+    pub const SEED_PREFIX: &'static [u8] = b"custody_ledger";
+
+    /// Runs `f` on `custody_ledger`, which is required unless the deployment
+    /// doesn't track custody (see [`Config::tracks_custody`]).
+    pub fn update(
+        config: &Config,
+        custody_ledger: Option<&mut Self>,
+        f: impl FnOnce(&mut Self),
+    ) -> Result<()> {
+        match custody_ledger {
+            Some(custody_ledger) => {
+                f(custody_ledger);
+                Ok(())
+            }
+            None if config.tracks_custody => Err(ErrorCode::AccountNotEnoughKeys.into()),
+            None => Ok(()),
+        }
+    }
+
+    pub fn lock(&mut self, amount: u64) {
+        self.locked_amount += amount;
+    }
+
+    /// NOTE: tokens can end up in custody without being locked by a transfer
+    /// (e.g. a deployer seeding liquidity), and releasing them is not an error.
+    /// The ledger bottoms out at zero instead, and the excess shows up as a
+    /// discrepancy when reconciling.
+    pub fn unlock(&mut self, amount: u64) {
+        self.locked_amount = self.locked_amount.saturating_sub(amount);
+    }
+}
@@ -65,6 +65,118 @@ pub enum NTTError {
     ThresholdTooHigh,
     #[msg("InvalidTransceiverProgram")]
     InvalidTransceiverProgram,
+    #[msg("InvalidAddressEncoding")]
+    InvalidAddressEncoding,
+    #[msg("RouteDisabled")]
+    RouteDisabled,
+    #[msg("TokenProgramMismatch")]
+    TokenProgramMismatch,
+    #[msg("UnknownChainId")]
+    UnknownChainId,
+    #[msg("SourceMintMismatch")]
+    SourceMintMismatch,
+    #[msg("RecipientMintMismatch")]
+    RecipientMintMismatch,
+    #[msg("UnsupportedPayloadVersion")]
+    UnsupportedPayloadVersion,
+    #[msg("InvalidFeeCollector")]
+    InvalidFeeCollector,
+    #[msg("InvalidGuardianSignaturesAccount")]
+    InvalidGuardianSignaturesAccount,
+    #[msg("ConfidentialTransfersUnsupported")]
+    ConfidentialTransfersUnsupported,
+    #[msg("TooManyOutboundInFlight")]
+    TooManyOutboundInFlight,
+    #[msg("DigestMismatch")]
+    DigestMismatch,
+    #[msg("HeartbeatTooSoon")]
+    HeartbeatTooSoon,
+    #[msg("ForceReleaseNotConfirmed")]
+    ForceReleaseNotConfirmed,
+    #[msg("UnregisteredTransceiver")]
+    UnregisteredTransceiver,
+    #[msg("InvalidDustAccount")]
+    InvalidDustAccount,
+    #[msg("Unauthorized")]
+    Unauthorized,
+    #[msg("InsufficientRentForMessage")]
+    InsufficientRentForMessage,
+    #[msg("QueueItemNotMigrated")]
+    QueueItemNotMigrated,
+    #[msg("QueueItemAlreadyMigrated")]
+    QueueItemAlreadyMigrated,
+    #[msg("WormholeFeeTooHigh")]
+    WormholeFeeTooHigh,
+    #[msg("MalformedMessage")]
+    MalformedMessage,
+    #[msg("SlippageExceeded")]
+    SlippageExceeded,
+    #[msg("InvalidDestinationFeeBps")]
+    InvalidDestinationFeeBps,
+    #[msg("TransceiverNotRegistered")]
+    TransceiverNotRegistered,
+    #[msg("BroadcastSuppressed")]
+    BroadcastSuppressed,
+    #[msg("InvalidFallbackRecipient")]
+    InvalidFallbackRecipient,
+    #[msg("TooManyInitialPeers")]
+    TooManyInitialPeers,
+    #[msg("MintAuthorityRotatedAway")]
+    MintAuthorityRotatedAway,
+    #[msg("VaaTooOld")]
+    VaaTooOld,
+    #[msg("InvalidClock")]
+    InvalidClock,
+    #[msg("PeerIndexFull")]
+    PeerIndexFull,
+    #[msg("PeerHasOutboundInFlight")]
+    PeerHasOutboundInFlight,
+    #[msg("RateLimitNotInitialized")]
+    RateLimitNotInitialized,
+    #[msg("InvalidPeerAddress")]
+    InvalidPeerAddress,
+    #[msg("TooManyEnabledTransceivers")]
+    TooManyEnabledTransceivers,
+    #[msg("UnconsumedPayload")]
+    UnconsumedPayload,
+    #[msg("MintDecimalsMismatch")]
+    MintDecimalsMismatch,
+    #[msg("CustodyNotEmpty")]
+    CustodyNotEmpty,
+    #[msg("TransferExpired")]
+    TransferExpired,
+    #[msg("InvalidExpiryTimestamp")]
+    InvalidExpiryTimestamp,
+    #[msg("TransferNotExpired")]
+    TransferNotExpired,
+    #[msg("InboxItemNotReleasable")]
+    InboxItemNotReleasable,
+    #[msg("DeploymentDecommissioning")]
+    DeploymentDecommissioning,
+    #[msg("DeploymentTerminated")]
+    DeploymentTerminated,
+    #[msg("NotDecommissioning")]
+    NotDecommissioning,
+    #[msg("DecommissionGracePeriodActive")]
+    DecommissionGracePeriodActive,
+    #[msg("PeersRemaining")]
+    PeersRemaining,
+    #[msg("PeerHasInboundInFlight")]
+    PeerHasInboundInFlight,
+    #[msg("TransferAlreadyCancelled")]
+    TransferAlreadyCancelled,
+    #[msg("ConsistencyLevelNotAllowed")]
+    ConsistencyLevelNotAllowed,
+    #[msg("UnsupportedConsistencyLevel")]
+    UnsupportedConsistencyLevel,
+    #[msg("VaaBodyNotExpired")]
+    VaaBodyNotExpired,
+    #[msg("VaaTimestampInFuture")]
+    VaaTimestampInFuture,
+    #[msg("TransferExceedsSenderRateLimit")]
+    TransferExceedsSenderRateLimit,
+    #[msg("PeerAlreadyMigrated")]
+    PeerAlreadyMigrated,
 }
 
 impl From<ScalingError> for NTTError {
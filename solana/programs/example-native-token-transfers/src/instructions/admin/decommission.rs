@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    clock::{current_time, current_timestamp},
+    config::Config,
+    error::NTTError,
+    peer::PeerIndex,
+    queue::outbox::OutboxRateLimit,
+};
+
+// * Begin decommission
+
+/// Starts winding down the deployment for good. Outbound transfers are
+/// rejected from now on, while inbound transfers can still be redeemed and
+/// released (and queued outbound transfers still go out), until
+/// [`finalize_decommission`] is called after the grace period.
+///
+/// This can't be undone.
+#[derive(Accounts)]
+pub struct BeginDecommission<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = config.decommission_timestamp.is_none() @ NTTError::DeploymentDecommissioning,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn begin_decommission(
+    ctx: Context<BeginDecommission>,
+    grace_period_seconds: u64,
+) -> Result<()> {
+    let grace_period = i64::try_from(grace_period_seconds).unwrap_or(i64::MAX);
+    let decommission_timestamp = current_time()?.saturating_add(grace_period);
+
+    msg!(
+        "Decommissioning deployment, can be finalized from {}",
+        decommission_timestamp
+    );
+    ctx.accounts.config.decommission_timestamp = Some(decommission_timestamp);
+
+    Ok(())
+}
+
+// * Finalize decommission
+
+/// Terminates a deployment that is being decommissioned, once the grace
+/// period is over. Every peer has to be deleted with
+/// [`crate::instructions::delete_peer`] first, which requires all of their
+/// transfers (inbound and outbound) to have been released, and reclaims the
+/// rent of their accounts. The outbound rate limit is closed here.
+///
+/// After this, transfers can no longer be sent, received, redeemed or
+/// released. The owner keeps ownership, so that the program can still be
+/// upgraded or closed, and anything else it needs (such as handing the mint
+/// authority back with [`crate::instructions::set_token_authority`]) is still
+/// possible.
+///
+/// NOTE: peers set before the [`PeerIndex`] was introduced have to be set
+/// again (and then deleted), as they are not in it.
+#[derive(Accounts)]
+pub struct FinalizeDecommission<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = !config.terminated @ NTTError::DeploymentTerminated,
+        constraint = config.decommission_timestamp.is_some() @ NTTError::NotDecommissioning,
+        constraint = config.decommission_timestamp <= Some(current_timestamp()) @ NTTError::DecommissionGracePeriodActive,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [PeerIndex::SEED_PREFIX],
+        bump = peer_index.bump,
+        constraint = peer_index.chain_ids.is_empty() @ NTTError::PeersRemaining,
+    )]
+    pub peer_index: Account<'info, PeerIndex>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [OutboxRateLimit::SEED_PREFIX],
+        bump,
+    )]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+}
+
+pub fn finalize_decommission(ctx: Context<FinalizeDecommission>) -> Result<()> {
+    msg!("Deployment decommissioned");
+    ctx.accounts.config.terminated = true;
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{config::Config, error::NTTError, peer::NttManagerPeer, queue::outbox::OutboxItem};
+
+// * Force mark released
+
+/// Marks an outbox item as released by a transceiver, without the transceiver
+/// being involved.
+///
+/// This is intended as a last resort for outbox items where the transceiver
+/// did send its message, but failed to record the release (for example because
+/// of a bug in the transceiver). Nothing is sent by this instruction: the owner
+/// must make sure that the transceiver's message really went out, otherwise
+/// the transfer is lost on that transceiver's path.
+///
+/// To guard against passing the wrong account, the outbox item's address has
+/// to be repeated in the instruction arguments.
+#[derive(Accounts)]
+#[instruction(args: ForceMarkReleasedArgs)]
+pub struct ForceMarkReleased<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner,
+        constraint = args.transceiver_id < config.next_transceiver_id @ NTTError::UnregisteredTransceiver,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = outbox_item.key() == args.confirm_outbox_item @ NTTError::ForceReleaseNotConfirmed,
+        constraint = OutboxItem::is_current_layout(&outbox_item.to_account_info()) @ NTTError::QueueItemNotMigrated,
+        constraint = !outbox_item.load()?.released.get(args.transceiver_id)? @ NTTError::MessageAlreadySent,
+        constraint = !outbox_item.load()?.is_cancelled() @ NTTError::TransferAlreadyCancelled,
+    )]
+    pub outbox_item: AccountLoader<'info, OutboxItem>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, outbox_item.load()?.recipient_chain.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ForceMarkReleasedArgs {
+    pub transceiver_id: u8,
+    /// Must be the address of the outbox item.
+    pub confirm_outbox_item: Pubkey,
+}
+
+pub fn force_mark_released(
+    ctx: Context<ForceMarkReleased>,
+    args: ForceMarkReleasedArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
+
+    msg!(
+        "WARNING: owner {} force marking outbox item {} as released by transceiver {}",
+        accs.owner.key(),
+        accs.outbox_item.key(),
+        args.transceiver_id
+    );
+
+    accs.outbox_item
+        .load_mut()?
+        .mark_released(args.transceiver_id, &mut accs.peer)
+}
@@ -0,0 +1,150 @@
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::token_interface;
+use ntt_messages::{chain_id::ChainId, mode::Mode};
+
+use crate::{
+    bitmap::Bitmap,
+    confidential_transfer::has_confidential_transfers,
+    config::{default_allowed_consistency_levels, Config},
+    transceivers::wormhole::accounts::default_fee_collector,
+};
+
+// * Migrate config
+
+/// Layout of [`Config`] before the fields following `custody` were appended
+/// to it.
+#[derive(AnchorDeserialize)]
+struct LegacyConfig {
+    bump: u8,
+    owner: Pubkey,
+    pending_owner: Option<Pubkey>,
+    mint: Pubkey,
+    token_program: Pubkey,
+    mode: Mode,
+    chain_id: ChainId,
+    next_transceiver_id: u8,
+    threshold: u8,
+    enabled_transceivers: Bitmap,
+    paused: bool,
+    custody: Pubkey,
+}
+
+/// Brings a [`Config`] account created by an earlier version of the program
+/// up to the current layout, resizing it if necessary.
+///
+/// The config can't be loaded as an `Account<Config>` before it's migrated, so
+/// the owner and mint checks happen in the handler instead.
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+        owner = crate::ID,
+    )]
+    /// CHECK: deserialized manually in [`migrate_config`], as it might still
+    /// be in the legacy layout.
+    pub config: UncheckedAccount<'info>,
+
+    /// The config's mint. Its decimals (and whether it has confidential
+    /// transfers) are cached in the config.
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let config_info = ctx.accounts.config.to_account_info();
+    let new_len = 8 + Config::INIT_SPACE;
+
+    require!(
+        config_info
+            .try_borrow_data()?
+            .starts_with(&Config::DISCRIMINATOR),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    // NOTE: the trailing bytes of the account are not necessarily zero (as
+    // `pending_owner` is variable length), so the legacy layout can't just be
+    // read as the current one after resizing.
+    let mut config = if config_info.data_len() < new_len {
+        let legacy = LegacyConfig::deserialize(&mut &config_info.try_borrow_data()?[8..])?;
+
+        let rent = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(config_info.lamports());
+        if rent > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: config_info.clone(),
+                    },
+                ),
+                rent,
+            )?;
+        }
+        config_info.realloc(new_len, false)?;
+
+        Config {
+            bump: legacy.bump,
+            owner: legacy.owner,
+            pending_owner: legacy.pending_owner,
+            mint: legacy.mint,
+            token_program: legacy.token_program,
+            mode: legacy.mode,
+            chain_id: legacy.chain_id,
+            next_transceiver_id: legacy.next_transceiver_id,
+            threshold: legacy.threshold,
+            enabled_transceivers: legacy.enabled_transceivers,
+            paused_outbound: legacy.paused,
+            custody: legacy.custody,
+            // all routes were enabled before this setting existed
+            routes_default_enabled: true,
+            mint_decimals: 0,
+            receive_hook: None,
+            fee_collector: default_fee_collector(),
+            confidential_transfers: false,
+            dust_account: None,
+            limits_manager: None,
+            max_vaa_age: None,
+            reject_unconsumed_payload: false,
+            decommission_timestamp: None,
+            terminated: false,
+            allowed_consistency_levels: default_allowed_consistency_levels(),
+            paused_inbound: legacy.paused,
+            sender_limit: None,
+            per_chain_outbound_limits: false,
+            sender_limit_overrides: false,
+            // the ledger is only created along with the deployment
+            tracks_custody: false,
+        }
+    } else {
+        Config::try_deserialize(&mut &config_info.try_borrow_data()?[..])?
+    };
+
+    require_keys_eq!(
+        config.owner,
+        ctx.accounts.owner.key(),
+        ErrorCode::ConstraintHasOne
+    );
+    require_keys_eq!(
+        config.mint,
+        ctx.accounts.mint.key(),
+        ErrorCode::ConstraintRaw
+    );
+
+    config.mint_decimals = ctx.accounts.mint.decimals;
+    config.confidential_transfers =
+        has_confidential_transfers(&ctx.accounts.mint.to_account_info())?;
+
+    config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface};
+use ntt_messages::mode::Mode;
+
+use crate::{
+    confidential_transfer::has_confidential_transfers, config::Config,
+    custody_ledger::CustodyLedger, error::NTTError, pending_mint_migration::PendingMintMigration,
+};
+
+// * Begin mint migration
+
+#[derive(Accounts)]
+pub struct BeginMintMigration<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = mint,
+        // NOTE: in burning mode, the supply on other chains isn't backed by
+        // custody, so there's nothing that says it's safe to switch mints.
+        constraint = config.mode == Mode::Locking @ NTTError::InvalidMode,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        constraint = new_mint.key() != mint.key() @ ErrorCode::ConstraintRaw,
+        constraint = new_mint.decimals == mint.decimals @ NTTError::MintDecimalsMismatch,
+        constraint = *new_mint.to_account_info().owner == new_token_program.key() @ NTTError::TokenProgramMismatch,
+    )]
+    pub new_mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    pub new_token_program: Interface<'info, token_interface::TokenInterface>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + PendingMintMigration::INIT_SPACE,
+        payer = payer,
+        seeds = [PendingMintMigration::SEED_PREFIX],
+        bump,
+    )]
+    /// Created if needed, so that a migration that hasn't been completed can
+    /// be restarted with a different mint.
+    pub pending_mint_migration: Account<'info, PendingMintMigration>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Starts migrating the program to `new_mint`, e.g. a Token-2022 mint
+/// replacing a legacy SPL token mint. Transfers are paused until
+/// [`complete_mint_migration`], which requires the custody to be wound down
+/// first.
+pub fn begin_mint_migration(ctx: Context<BeginMintMigration>) -> Result<()> {
+    ctx.accounts.config.paused_outbound = true;
+    ctx.accounts.config.paused_inbound = true;
+    ctx.accounts
+        .pending_mint_migration
+        .set_inner(PendingMintMigration {
+            bump: ctx.bumps.pending_mint_migration,
+            mint: ctx.accounts.new_mint.key(),
+            token_program: ctx.accounts.new_token_program.key(),
+            rent_payer: ctx.accounts.payer.key(),
+        });
+    Ok(())
+}
+
+// * Complete mint migration
+
+#[derive(Accounts)]
+pub struct CompleteMintMigration<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = custody,
+        constraint = config.is_paused() @ NTTError::NotPaused,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [PendingMintMigration::SEED_PREFIX],
+        bump = pending_mint_migration.bump,
+        has_one = rent_payer,
+        constraint = pending_mint_migration.mint == new_mint.key() @ ErrorCode::ConstraintRaw,
+        constraint = pending_mint_migration.token_program == new_token_program.key() @ NTTError::TokenProgramMismatch,
+        close = rent_payer,
+    )]
+    pub pending_mint_migration: Account<'info, PendingMintMigration>,
+
+    #[account(mut)]
+    /// CHECK: checked to be the rent payer of [`pending_mint_migration`].
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = custody.amount == 0 @ NTTError::CustodyNotEmpty,
+    )]
+    pub custody: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        seeds = [CustodyLedger::SEED_PREFIX],
+        bump = custody_ledger.bump,
+        constraint = custody_ledger.locked_amount == 0 @ NTTError::CustodyNotEmpty,
+    )]
+    pub custody_ledger: Account<'info, CustodyLedger>,
+
+    pub new_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint ensures that this is the correct address
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = new_mint,
+        associated_token::authority = token_authority,
+        associated_token::token_program = new_token_program,
+    )]
+    /// CHECK: Use init_if_needed in case someone created the account ahead of
+    /// time, same as in [`crate::instructions::initialize`].
+    pub new_custody: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    pub new_token_program: Interface<'info, token_interface::TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Retargets the program to the mint of the pending migration, and unpauses
+/// it. This only goes through once the deployment has been fully wound down,
+/// i.e. nothing is locked and the custody account is empty, as there is no
+/// way to swap the tokens in custody for the new ones.
+///
+/// NOTE: inbound transfers still in the inbox are released in the new mint,
+/// so they have to be released (or the custody topped up) separately.
+pub fn complete_mint_migration(ctx: Context<CompleteMintMigration>) -> Result<()> {
+    let accs = ctx.accounts;
+    let config = &mut accs.config;
+
+    config.mint = accs.new_mint.key();
+    config.token_program = accs.new_token_program.key();
+    config.custody = accs.new_custody.key();
+    config.mint_decimals = accs.new_mint.decimals;
+    config.confidential_transfers = has_confidential_transfers(&accs.new_mint.to_account_info())?;
+    config.paused_outbound = false;
+    config.paused_inbound = false;
+
+    Ok(())
+}
@@ -1,17 +1,33 @@
 use anchor_lang::prelude::*;
-use ntt_messages::chain_id::ChainId;
+use anchor_spl::token_interface;
+use ntt_messages::chain_id::{known_chain, ChainId};
 
 use crate::{
-    config::Config,
+    bitmap::Bitmap,
+    config::{Config, Role, CONSISTENCY_LEVEL_CONFIRMED, CONSISTENCY_LEVEL_FINALIZED},
     error::NTTError,
-    peer::NttManagerPeer,
-    queue::{inbox::InboxRateLimit, outbox::OutboxRateLimit, rate_limit::RateLimitState},
-    registered_transceiver::RegisteredTransceiver,
+    peer::{check_peer_address, NttManagerPeer, PeerIndex},
+    queue::{
+        inbox::InboxRateLimit,
+        outbox::{ChainOutboxRateLimit, OutboxRateLimit, SenderRateLimit},
+        rate_limit::RateLimitState,
+    },
+    registered_transceiver::{RegisteredTransceiver, MAX_ENABLED_TRANSCEIVERS},
 };
 
+pub mod decommission;
+pub mod force_mark_released;
+pub mod migrate_config;
+pub mod migrate_mint;
+pub mod purge_inbox_item;
 pub mod transfer_ownership;
 pub mod transfer_token_authority;
 
+pub use decommission::*;
+pub use force_mark_released::*;
+pub use migrate_config::*;
+pub use migrate_mint::*;
+pub use purge_inbox_item::*;
 pub use transfer_ownership::*;
 pub use transfer_token_authority::*;
 
@@ -27,7 +43,7 @@ pub struct SetPeer<'info> {
 
     #[account(
         has_one = owner,
-        constraint = args.chain_id != config.chain_id @ NTTError::InvalidChainId
+        constraint = args.chain_id != config.chain_id @ NTTError::InvalidChainId,
     )]
     pub config: Account<'info, Config>,
 
@@ -52,6 +68,15 @@ pub struct SetPeer<'info> {
     )]
     pub inbox_rate_limit: Account<'info, InboxRateLimit>,
 
+    #[account(
+        init_if_needed,
+        space = 8 + PeerIndex::INIT_SPACE,
+        payer = payer,
+        seeds = [PeerIndex::SEED_PREFIX],
+        bump,
+    )]
+    pub peer_index: Account<'info, PeerIndex>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -64,11 +89,92 @@ pub struct SetPeerArgs {
     pub token_decimals: u8,
 }
 
+/// Checks of [`set_peer`] that can be skipped, through
+/// [`set_peer_with_options`]. They're not part of [`SetPeerArgs`], so that
+/// its encoding stays the same for existing clients.
+#[derive(AnchorDeserialize, AnchorSerialize, Default)]
+pub struct SetPeerOptions {
+    /// Skip the check that `chain_id` is a known Wormhole chain id.
+    /// Only needed for chains added to Wormhole after this program was built.
+    pub allow_unknown_chain: bool,
+    /// Skip the check that `address` is well-formed for the chain, see
+    /// [`check_peer_address`].
+    pub allow_nonstandard_address: bool,
+}
+
 pub fn set_peer(ctx: Context<SetPeer>, args: SetPeerArgs) -> Result<()> {
+    set_peer_with_options(ctx, args, SetPeerOptions::default())
+}
+
+pub fn set_peer_with_options(
+    ctx: Context<SetPeer>,
+    args: SetPeerArgs,
+    options: SetPeerOptions,
+) -> Result<()> {
+    require!(
+        options.allow_unknown_chain || known_chain(args.chain_id.id),
+        NTTError::UnknownChainId
+    );
+    if !known_chain(args.chain_id.id) {
+        msg!(
+            "WARNING: registering peer for unknown chain id {}",
+            args.chain_id.id
+        );
+    }
+    check_peer_address(
+        args.chain_id.id,
+        &args.address,
+        options.allow_nonstandard_address,
+    )?;
+
+    // new routes start out in the configured default state, existing ones
+    // are left as they are (and so are their in-flight transfers)
+    let (
+        enabled,
+        outbound_in_flight,
+        max_outbound_in_flight,
+        allow_queue,
+        destination_fee_bps,
+        fallback_recipient,
+        inbound_in_flight,
+        allow_inbound_queue,
+    ) = if NttManagerPeer::is_initialized(&ctx.accounts.peer.to_account_info())? {
+        let peer = &ctx.accounts.peer;
+        (
+            peer.enabled,
+            peer.outbound_in_flight,
+            peer.max_outbound_in_flight,
+            peer.allow_queue,
+            peer.destination_fee_bps,
+            peer.fallback_recipient,
+            peer.inbound_in_flight,
+            peer.allow_inbound_queue,
+        )
+    } else {
+        (
+            ctx.accounts.config.routes_default_enabled,
+            0,
+            None,
+            true,
+            0,
+            None,
+            0,
+            true,
+        )
+    };
+
     ctx.accounts.peer.set_inner(NttManagerPeer {
         bump: ctx.bumps.peer,
         address: args.address,
         token_decimals: args.token_decimals,
+        enabled,
+        outbound_in_flight,
+        max_outbound_in_flight,
+        allow_queue,
+        destination_fee_bps,
+        fallback_recipient,
+        inbound_in_flight,
+        allow_inbound_queue,
     });
 
     // if rate limit is uninitialized/unused, set new rate limit
@@ -83,6 +189,55 @@ pub fn set_peer(ctx: Context<SetPeer>, args: SetPeerArgs) -> Result<()> {
         ctx.accounts.inbox_rate_limit.set_limit(args.limit);
     }
 
+    ctx.accounts.peer_index.bump = ctx.bumps.peer_index;
+    ctx.accounts.peer_index.insert(args.chain_id.id)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct DeletePeer<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(has_one = owner)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [NttManagerPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+        // outbox items to this chain can't be released without the peer
+        constraint = peer.outbound_in_flight == 0 @ NTTError::PeerHasOutboundInFlight,
+        // neither can inbox items from this chain
+        constraint = peer.inbound_in_flight == 0 @ NTTError::PeerHasInboundInFlight,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [InboxRateLimit::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [PeerIndex::SEED_PREFIX],
+        bump = peer_index.bump,
+    )]
+    pub peer_index: Account<'info, PeerIndex>,
+}
+
+/// Removes the peer on `chain_id`, refunding the rent of its accounts to the
+/// owner. Setting it again later starts it out afresh, as [`set_peer`] does
+/// for a new chain.
+pub fn delete_peer(ctx: Context<DeletePeer>, chain_id: u16) -> Result<()> {
+    ctx.accounts.peer_index.remove(chain_id);
+
     Ok(())
 }
 
@@ -121,24 +276,51 @@ pub struct RegisterTransceiver<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Registers and enables `transceiver`, failing with
+/// [`NTTError::TooManyEnabledTransceivers`] if that would exceed
+/// [`MAX_ENABLED_TRANSCEIVERS`]. Re-enabling a disabled transceiver keeps its id.
 pub fn register_transceiver(ctx: Context<RegisterTransceiver>) -> Result<()> {
+    enable_transceiver(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.registered_transceiver,
+        ctx.bumps.registered_transceiver,
+        ctx.accounts.transceiver.key(),
+    )?;
+
+    // re-registering an enabled transceiver doesn't change the count, and
+    // [`swap_transceivers`] disables one for every one it enables
+    let num_enabled_transceivers = ctx.accounts.config.enabled_transceivers.len();
+    if num_enabled_transceivers > MAX_ENABLED_TRANSCEIVERS {
+        msg!(
+            "{} transceivers are enabled already, deregister one first",
+            MAX_ENABLED_TRANSCEIVERS
+        );
+        return err!(NTTError::TooManyEnabledTransceivers);
+    }
+
+    Ok(())
+}
+
+fn enable_transceiver(
+    config: &mut Config,
+    registered_transceiver: &mut Account<RegisteredTransceiver>,
+    bump: u8,
+    transceiver: Pubkey,
+) -> Result<()> {
     // initialize registered transceiver with new id on init
-    if ctx.accounts.registered_transceiver.transceiver_address == Pubkey::default() {
-        let id = ctx.accounts.config.next_transceiver_id;
-        ctx.accounts.config.next_transceiver_id += 1;
-        ctx.accounts
-            .registered_transceiver
-            .set_inner(RegisteredTransceiver {
-                bump: ctx.bumps.registered_transceiver,
-                id,
-                transceiver_address: ctx.accounts.transceiver.key(),
-            });
+    if registered_transceiver.transceiver_address == Pubkey::default() {
+        let id = config.next_transceiver_id;
+        config.next_transceiver_id += 1;
+        registered_transceiver.set_inner(RegisteredTransceiver {
+            bump,
+            id,
+            transceiver_address: transceiver,
+        });
     }
 
-    ctx.accounts
-        .config
+    config
         .enabled_transceivers
-        .set(ctx.accounts.registered_transceiver.id, true)?;
+        .set(registered_transceiver.id, true)?;
     Ok(())
 }
 
@@ -162,33 +344,119 @@ pub struct DeregisterTransceiver<'info> {
 }
 
 pub fn deregister_transceiver(ctx: Context<DeregisterTransceiver>) -> Result<()> {
-    ctx.accounts
-        .config
-        .enabled_transceivers
-        .set(ctx.accounts.registered_transceiver.id, false)?;
+    disable_transceiver(
+        &mut ctx.accounts.config,
+        ctx.accounts.registered_transceiver.id,
+    )
+}
 
-    let num_enabled_transceivers = ctx.accounts.config.enabled_transceivers.len();
+fn disable_transceiver(config: &mut Config, id: u8) -> Result<()> {
+    config.enabled_transceivers.set(id, false)?;
+
+    let num_enabled_transceivers = config.enabled_transceivers.len();
     // at least one transceiver should be enabled
     if num_enabled_transceivers == 0 {
         return Err(NTTError::ZeroThreshold.into());
     }
     // decrement threshold if too high
-    if num_enabled_transceivers < ctx.accounts.config.threshold {
-        ctx.accounts.config.threshold = num_enabled_transceivers;
+    if num_enabled_transceivers < config.threshold {
+        config.threshold = num_enabled_transceivers;
     }
     Ok(())
 }
 
+/// Replaces an enabled transceiver with another one in a single instruction,
+/// so that there's no window in which the enabled transceivers can't meet the
+/// threshold (as there would be when deregistering and registering in
+/// separate transactions).
+#[derive(Accounts)]
+pub struct SwapTransceivers<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [RegisteredTransceiver::SEED_PREFIX, old_registered_transceiver.transceiver_address.as_ref()],
+        bump,
+        constraint = config.enabled_transceivers.get(old_registered_transceiver.id)? @ NTTError::DisabledTransceiver,
+    )]
+    pub old_registered_transceiver: Account<'info, RegisteredTransceiver>,
+
+    #[account(
+        executable,
+        constraint = new_transceiver.key() != Pubkey::default() @ NTTError::InvalidTransceiverProgram,
+        constraint = new_transceiver.key() != old_registered_transceiver.transceiver_address @ NTTError::InvalidTransceiverProgram,
+    )]
+    /// CHECK: transceiver is meant to be a transceiver program (see
+    /// [`RegisterTransceiver::transceiver`]).
+    pub new_transceiver: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + RegisteredTransceiver::INIT_SPACE,
+        payer = payer,
+        seeds = [RegisteredTransceiver::SEED_PREFIX, new_transceiver.key().as_ref()],
+        bump
+    )]
+    pub new_registered_transceiver: Account<'info, RegisteredTransceiver>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn swap_transceivers(ctx: Context<SwapTransceivers>) -> Result<()> {
+    // enable the new transceiver first, so that the old one is never the last
+    // one standing
+    enable_transceiver(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.new_registered_transceiver,
+        ctx.bumps.new_registered_transceiver,
+        ctx.accounts.new_transceiver.key(),
+    )?;
+    disable_transceiver(
+        &mut ctx.accounts.config,
+        ctx.accounts.old_registered_transceiver.id,
+    )
+}
+
 // * Limit rate adjustment
 
 #[derive(Accounts)]
-pub struct SetOutboundLimit<'info> {
+pub struct SetLimitsManager<'info> {
+    pub owner: Signer<'info>,
+
     #[account(
+        mut,
         has_one = owner,
     )]
     pub config: Account<'info, Config>,
+}
 
-    pub owner: Signer<'info>,
+/// Delegates rate limit tuning to `limits_manager`, or revokes the delegation
+/// when `None`.
+pub fn set_limits_manager(
+    ctx: Context<SetLimitsManager>,
+    limits_manager: Option<Pubkey>,
+) -> Result<()> {
+    ctx.accounts.config.limits_manager = limits_manager;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetOutboundLimit<'info> {
+    #[account(
+        constraint = config.has_role(&authority.key(), Role::LimitsManager) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The owner or the limits manager.
+    pub authority: Signer<'info>,
 
     #[account(mut)]
     pub rate_limit: Account<'info, OutboxRateLimit>,
@@ -211,11 +479,12 @@ pub fn set_outbound_limit(
 #[instruction(args: SetInboundLimitArgs)]
 pub struct SetInboundLimit<'info> {
     #[account(
-        has_one = owner,
+        constraint = config.has_role(&authority.key(), Role::LimitsManager) @ NTTError::Unauthorized,
     )]
     pub config: Account<'info, Config>,
 
-    pub owner: Signer<'info>,
+    /// The owner or the limits manager.
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
@@ -239,6 +508,157 @@ pub fn set_inbound_limit(ctx: Context<SetInboundLimit>, args: SetInboundLimitArg
     Ok(())
 }
 
+// * Sender limits
+
+#[derive(Accounts)]
+pub struct SetSenderLimit<'info> {
+    #[account(
+        mut,
+        constraint = config.has_role(&authority.key(), Role::LimitsManager) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The owner or the limits manager.
+    pub authority: Signer<'info>,
+}
+
+/// Sets [`Config::sender_limit`], or lifts the limit when `None`. Senders
+/// without an override move over to the new limit on their next transfer.
+pub fn set_sender_limit(ctx: Context<SetSenderLimit>, limit: Option<u64>) -> Result<()> {
+    ctx.accounts.config.sender_limit = limit;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetSenderLimitOverrideArgs)]
+pub struct SetSenderLimitOverride<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = config.has_role(&authority.key(), Role::LimitsManager) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The owner or the limits manager.
+    pub authority: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + SenderRateLimit::INIT_SPACE,
+        payer = payer,
+        seeds = [SenderRateLimit::SEED_PREFIX, args.sender.as_ref()],
+        bump,
+    )]
+    pub sender_rate_limit: Account<'info, SenderRateLimit>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetSenderLimitOverrideArgs {
+    pub sender: Pubkey,
+    /// Falls back to [`Config::sender_limit`] when `None`.
+    pub limit: Option<u64>,
+}
+
+/// Sets [`SenderRateLimit::limit_override`] for a single sender. From then on
+/// transfers have to pass the sender's rate limit, see
+/// [`Config::limits_senders`].
+pub fn set_sender_limit_override(
+    ctx: Context<SetSenderLimitOverride>,
+    args: SetSenderLimitOverrideArgs,
+) -> Result<()> {
+    ctx.accounts.config.sender_limit_overrides = true;
+    let sender_rate_limit = &mut ctx.accounts.sender_rate_limit;
+    sender_rate_limit.bump = ctx.bumps.sender_rate_limit;
+    sender_rate_limit.limit_override = args.limit;
+    if let Some(limit) = sender_rate_limit.limit(ctx.accounts.config.sender_limit) {
+        sender_rate_limit.sync_limit(limit);
+    }
+    Ok(())
+}
+
+// * Per-chain outbound limits
+
+#[derive(Accounts)]
+pub struct SetPerChainOutboundLimits<'info> {
+    #[account(
+        mut,
+        constraint = config.has_role(&authority.key(), Role::LimitsManager) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The owner or the limits manager.
+    pub authority: Signer<'info>,
+}
+
+/// Sets [`Config::per_chain_outbound_limits`].
+pub fn set_per_chain_outbound_limits(
+    ctx: Context<SetPerChainOutboundLimits>,
+    enabled: bool,
+) -> Result<()> {
+    ctx.accounts.config.per_chain_outbound_limits = enabled;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetChainOutboundLimitArgs)]
+pub struct SetChainOutboundLimit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = config.has_role(&authority.key(), Role::LimitsManager) @ NTTError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The owner or the limits manager.
+    pub authority: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + ChainOutboxRateLimit::INIT_SPACE,
+        payer = payer,
+        seeds = [
+            ChainOutboxRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub rate_limit: Account<'info, ChainOutboxRateLimit>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetChainOutboundLimitArgs {
+    pub limit: u64,
+    pub chain_id: ChainId,
+}
+
+/// Sets the outbound limit for transfers to a single chain, creating its
+/// [`ChainOutboxRateLimit`] at full capacity if it doesn't exist yet. Only
+/// used while [`Config::per_chain_outbound_limits`] is set.
+pub fn set_chain_outbound_limit(
+    ctx: Context<SetChainOutboundLimit>,
+    args: SetChainOutboundLimitArgs,
+) -> Result<()> {
+    // if rate limit is uninitialized/unused, set new rate limit
+    if ctx.accounts.rate_limit.rate_limit.last_tx_timestamp == 0 {
+        ctx.accounts.rate_limit.set_inner(ChainOutboxRateLimit {
+            bump: ctx.bumps.rate_limit,
+            rate_limit: RateLimitState::new(args.limit),
+        });
+    }
+    // else update rate limit
+    else {
+        ctx.accounts.rate_limit.set_limit(args.limit);
+    }
+    Ok(())
+}
+
 // * Pausing
 
 #[derive(Accounts)]
@@ -252,8 +672,343 @@ pub struct SetPaused<'info> {
     pub config: Account<'info, Config>,
 }
 
+/// Pauses (or unpauses) transfers in both directions.
 pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
-    ctx.accounts.config.paused = paused;
+    ctx.accounts.config.paused_outbound = paused;
+    ctx.accounts.config.paused_inbound = paused;
+    Ok(())
+}
+
+/// Sets [`Config::paused_outbound`].
+pub fn set_outbound_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.config.paused_outbound = paused;
+    Ok(())
+}
+
+/// Sets [`Config::paused_inbound`].
+pub fn set_inbound_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.config.paused_inbound = paused;
+    Ok(())
+}
+
+// * Receive hook
+
+#[derive(Accounts)]
+pub struct SetReceiveHook<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn set_receive_hook(ctx: Context<SetReceiveHook>, receive_hook: Option<Pubkey>) -> Result<()> {
+    ctx.accounts.config.receive_hook = receive_hook;
+    Ok(())
+}
+
+// * Dust account
+
+#[derive(Accounts)]
+pub struct SetDustAccount<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        token::mint = config.mint,
+    )]
+    /// The account to collect dust into. When omitted, dust collection is
+    /// turned off.
+    pub dust_account: Option<InterfaceAccount<'info, token_interface::TokenAccount>>,
+}
+
+pub fn set_dust_account(ctx: Context<SetDustAccount>) -> Result<()> {
+    ctx.accounts.config.dust_account = ctx.accounts.dust_account.as_ref().map(|a| a.key());
+    Ok(())
+}
+
+// * Maximum VAA age
+
+#[derive(Accounts)]
+pub struct SetMaxVaaAge<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Sets [`Config::max_vaa_age`], or lifts the limit when `None`.
+pub fn set_max_vaa_age(ctx: Context<SetMaxVaaAge>, max_vaa_age: Option<u32>) -> Result<()> {
+    ctx.accounts.config.max_vaa_age = max_vaa_age;
+    Ok(())
+}
+
+// * Unconsumed payloads
+
+#[derive(Accounts)]
+pub struct SetRejectUnconsumedPayload<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Sets [`Config::reject_unconsumed_payload`].
+pub fn set_reject_unconsumed_payload(
+    ctx: Context<SetRejectUnconsumedPayload>,
+    reject: bool,
+) -> Result<()> {
+    ctx.accounts.config.reject_unconsumed_payload = reject;
+    Ok(())
+}
+
+// * Consistency levels
+
+#[derive(Accounts)]
+pub struct SetAllowedConsistencyLevels<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Sets [`Config::allowed_consistency_levels`] to exactly
+/// `consistency_levels`, each of which has to be
+/// [`CONSISTENCY_LEVEL_FINALIZED`] or [`CONSISTENCY_LEVEL_CONFIRMED`].
+pub fn set_allowed_consistency_levels(
+    ctx: Context<SetAllowedConsistencyLevels>,
+    consistency_levels: Vec<u8>,
+) -> Result<()> {
+    let mut allowed = Bitmap::new();
+    for consistency_level in consistency_levels {
+        require!(
+            consistency_level == CONSISTENCY_LEVEL_FINALIZED
+                || consistency_level == CONSISTENCY_LEVEL_CONFIRMED,
+            NTTError::UnsupportedConsistencyLevel
+        );
+        allowed.set(consistency_level, true)?;
+    }
+    ctx.accounts.config.allowed_consistency_levels = allowed;
+    Ok(())
+}
+
+// * Fee collector
+
+#[derive(Accounts)]
+pub struct SetFeeCollector<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn set_fee_collector(ctx: Context<SetFeeCollector>, fee_collector: Pubkey) -> Result<()> {
+    ctx.accounts.config.fee_collector = fee_collector;
+    Ok(())
+}
+
+// * Routes
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct SetRouteEnabled<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+}
+
+pub fn set_route_enabled(ctx: Context<SetRouteEnabled>, enabled: bool) -> Result<()> {
+    ctx.accounts.peer.enabled = enabled;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRoutesDefaultEnabled<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Sets whether routes to newly registered peers are enabled by default.
+/// This does not affect peers that have already been registered.
+pub fn set_routes_default_enabled(
+    ctx: Context<SetRoutesDefaultEnabled>,
+    enabled: bool,
+) -> Result<()> {
+    ctx.accounts.config.routes_default_enabled = enabled;
+    Ok(())
+}
+
+// * Outbound in-flight limit
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct SetMaxOutboundInFlight<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+}
+
+/// Caps the number of unreleased outbound transfers to the peer, or removes
+/// the cap if `max` is `None`.
+/// Lowering the cap below the current number of transfers in flight doesn't
+/// affect those, but blocks new transfers until enough have been released.
+pub fn set_max_outbound_in_flight(
+    ctx: Context<SetMaxOutboundInFlight>,
+    max: Option<u64>,
+) -> Result<()> {
+    ctx.accounts.peer.max_outbound_in_flight = max;
+    Ok(())
+}
+
+// * Queueing
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct SetAllowQueue<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+}
+
+/// Sets whether outbound transfers to the peer that exceed the rate limit may
+/// be queued. Transfers that are already queued are not affected.
+pub fn set_allow_queue(ctx: Context<SetAllowQueue>, allow_queue: bool) -> Result<()> {
+    ctx.accounts.peer.allow_queue = allow_queue;
+    Ok(())
+}
+
+/// Sets whether inbound transfers from the peer that exceed the inbound rate
+/// limit may be queued, see [`NttManagerPeer::allow_inbound_queue`].
+/// Transfers that are already queued are not affected.
+pub fn set_allow_inbound_queue(
+    ctx: Context<SetAllowQueue>,
+    allow_inbound_queue: bool,
+) -> Result<()> {
+    ctx.accounts.peer.allow_inbound_queue = allow_inbound_queue;
+    Ok(())
+}
+
+// * Destination fee
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct SetDestinationFeeBps<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+}
+
+/// Records the execution fee the peer deducts from delivered transfers, as
+/// published in the peer chain's configuration. This only affects the
+/// `min_amount_out` check of outbound transfers.
+pub fn set_destination_fee_bps(ctx: Context<SetDestinationFeeBps>, fee_bps: u16) -> Result<()> {
+    require!(
+        fee_bps <= NttManagerPeer::MAX_FEE_BPS,
+        NTTError::InvalidDestinationFeeBps
+    );
+    ctx.accounts.peer.destination_fee_bps = fee_bps;
+    Ok(())
+}
+
+// * Fallback recipient
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct SetFallbackRecipient<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        constraint = fallback_recipient.mint == config.mint @ NTTError::RecipientMintMismatch,
+    )]
+    /// The token account to deliver to, or `None` to remove the fallback.
+    pub fallback_recipient: Option<InterfaceAccount<'info, token_interface::TokenAccount>>,
+}
+
+/// Sets the token account that inbound transfers from the peer are delivered
+/// to when the recipient's token account is frozen (see
+/// [`crate::instructions::release_inbound_unlock`]).
+pub fn set_fallback_recipient(ctx: Context<SetFallbackRecipient>) -> Result<()> {
+    ctx.accounts.peer.fallback_recipient = ctx
+        .accounts
+        .fallback_recipient
+        .as_ref()
+        .map(|fallback_recipient| fallback_recipient.key());
     Ok(())
 }
 
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use ntt_messages::{chain_id::ChainId, ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage};
+
+use crate::{
+    config::Config,
+    error::NTTError,
+    peer::NttManagerPeer,
+    queue::inbox::{InboxItem, ReleaseStatus},
+    transfer::Payload,
+};
+
+// * Purge inbox item
+
+/// Closes an inbox item that has not been released yet, refunding its rent to the owner.
+///
+/// This is intended as a last resort for unsticking inbound transfers that can never be
+/// released (for example because the recipient token account is permanently invalid).
+///
+/// WARNING: purging an inbox item discards its votes, but does NOT invalidate the
+/// underlying transceiver messages. As long as those are around, the transfer can be
+/// redeemed again, which re-creates the inbox item from scratch. The owner must make
+/// sure that the transfer is being purged for good (or is meant to be retried) before
+/// calling this instruction.
+#[derive(Accounts)]
+#[instruction(args: PurgeInboxItemArgs)]
+pub struct PurgeInboxItem<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxItem::SEED_PREFIX,
+            args.ntt_manager_message.keccak256(args.chain_id).as_ref(),
+        ],
+        bump = inbox_item.load()?.bump,
+        constraint = InboxItem::is_current_layout(&inbox_item.to_account_info()) @ NTTError::QueueItemNotMigrated,
+        constraint = inbox_item.load()?.release_status() != ReleaseStatus::Released @ NTTError::TransferAlreadyRedeemed,
+        close = owner,
+    )]
+    pub inbox_item: AccountLoader<'info, InboxItem>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    /// The peer the transfer came from. Only needed when the transfer has
+    /// been approved, to decrement its [`NttManagerPeer::inbound_in_flight`].
+    pub peer: Option<Account<'info, NttManagerPeer>>,
+}
+
+impl<'info> PurgeInboxItem<'info> {
+    /// Records the purge of an approved transfer in [`PurgeInboxItem::peer`],
+    /// as it will never be released. Inbox items that predate
+    /// [`InboxItem::from_chain`] were never counted.
+    fn finish_inbound(&mut self) -> Result<()> {
+        let inbox_item = self.inbox_item.load()?;
+        if inbox_item.from_chain == 0
+            || !matches!(inbox_item.release_status(), ReleaseStatus::ReleaseAfter(_))
+        {
+            return Ok(());
+        }
+        self.peer
+            .as_mut()
+            .ok_or(ErrorCode::AccountNotEnoughKeys)?
+            .finish_inbound();
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct PurgeInboxItemArgs {
+    pub chain_id: ChainId,
+    pub ntt_manager_message: NttManagerMessage<NativeTokenTransfer<Payload>>,
+}
+
+#[event]
+pub struct InboxItemPurged {
+    pub chain_id: u16,
+    pub inbox_item: Pubkey,
+    pub amount: u64,
+    pub recipient_address: Pubkey,
+    pub release_status: ReleaseStatus,
+}
+
+/// Same as [`PurgeInboxItem`], plus the accounts needed to emit events via
+/// self-CPI (`event_authority` and `program`, added by `#[event_cpi]`).
+/// Events emitted this way can't be truncated from the logs.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: PurgeInboxItemArgs)]
+pub struct PurgeInboxItemV2<'info> {
+    pub common: PurgeInboxItem<'info>,
+}
+
+/// DEPRECATED: use [`purge_inbox_item_v2`]. This variant only logs the event,
+/// and will be removed once integrators have moved to the new account layout.
+pub fn purge_inbox_item(ctx: Context<PurgeInboxItem>, args: PurgeInboxItemArgs) -> Result<()> {
+    msg!("DEPRECATED: purge_inbox_item will be removed, use purge_inbox_item_v2 instead");
+
+    emit!(purge_event(&ctx.accounts.inbox_item, &args)?);
+
+    ctx.accounts.finish_inbound()
+}
+
+pub fn purge_inbox_item_v2(ctx: Context<PurgeInboxItemV2>, args: PurgeInboxItemArgs) -> Result<()> {
+    let event = purge_event(&ctx.accounts.common.inbox_item, &args)?;
+
+    emit_cpi!(event);
+
+    ctx.accounts.common.finish_inbound()
+}
+
+/// Logs the purge, and returns the event describing it.
+fn purge_event(
+    inbox_item: &AccountLoader<InboxItem>,
+    args: &PurgeInboxItemArgs,
+) -> Result<InboxItemPurged> {
+    msg!(
+        "WARNING: purging unreleased inbox item {}",
+        inbox_item.key()
+    );
+
+    let data = inbox_item.load()?;
+    Ok(InboxItemPurged {
+        chain_id: args.chain_id.id,
+        inbox_item: inbox_item.key(),
+        amount: data.amount,
+        recipient_address: data.recipient_address,
+        release_status: data.release_status(),
+    })
+}
@@ -153,3 +153,16 @@ pub fn claim_ownership(ctx: Context<ClaimOwnership>) -> Result<()> {
         &crate::ID,
     )
 }
+
+// * Get pending owner
+
+#[derive(Accounts)]
+pub struct GetPendingOwner<'info> {
+    pub config: Account<'info, Config>,
+}
+
+/// Returns the owner set by [`transfer_ownership`] that has not claimed the
+/// ownership yet, if any.
+pub fn get_pending_owner(ctx: Context<GetPendingOwner>) -> Result<Option<Pubkey>> {
+    Ok(ctx.accounts.config.pending_owner)
+}
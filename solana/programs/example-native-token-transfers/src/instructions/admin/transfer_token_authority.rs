@@ -12,7 +12,7 @@ use crate::{
 pub struct AcceptTokenAuthorityBase<'info> {
     #[account(
         has_one = mint,
-        constraint = config.paused @ NTTError::NotPaused,
+        constraint = config.is_paused() @ NTTError::NotPaused,
     )]
     pub config: Account<'info, Config>,
 
@@ -110,7 +110,7 @@ pub struct SetTokenAuthority<'info> {
     #[account(
         has_one = owner,
         has_one = mint,
-        constraint = config.paused @ NTTError::NotPaused,
+        constraint = config.is_paused() @ NTTError::NotPaused,
     )]
     pub config: Account<'info, Config>,
 
@@ -215,7 +215,7 @@ pub fn set_token_authority(ctx: Context<SetTokenAuthorityChecked>) -> Result<()>
 pub struct ClaimTokenAuthorityBase<'info> {
     #[account(
         has_one = mint,
-        constraint = config.paused @ NTTError::NotPaused,
+        constraint = config.is_paused() @ NTTError::NotPaused,
     )]
     pub config: Account<'info, Config>,
 
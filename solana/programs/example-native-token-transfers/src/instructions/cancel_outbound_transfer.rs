@@ -0,0 +1,309 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use ntt_messages::mode::Mode;
+use spl_token_2022::onchain;
+
+use crate::{
+    clock::current_timestamp,
+    config::*,
+    custody_ledger::CustodyLedger,
+    error::NTTError,
+    instructions::{
+        check_mint_authority,
+        release_inbound::{
+            mint_to_custody_from_multisig_token_authority, mint_to_custody_from_token_authority,
+        },
+    },
+    peer::NttManagerPeer,
+    queue::{
+        inbox::InboxRateLimit,
+        outbox::{ChainOutboxRateLimit, OutboxItem, OutboxRateLimit, SenderRateLimit},
+    },
+    spl_multisig::SplMultisig,
+};
+
+/// Lets the sender take back an outbound transfer that no transceiver has
+/// released yet. The tokens are returned to `to` (unlocked from custody in
+/// locking mode, minted back in burning mode), and the outbox item is closed,
+/// returning the rent to whoever paid for it.
+///
+/// The outbound rate limit capacity the transfer consumed is given back, and
+/// the inbound capacity it refilled for the recipient chain is taken back, so
+/// that the rate limits end up as if it had never been sent. Queued transfers
+/// consumed none, so there is nothing to restore for them.
+///
+/// Outbox items created before [`OutboxItem::rent_payer`] was recorded have
+/// nowhere to return the rent to, so they are only marked as cancelled, which
+/// keeps them from being released afterwards.
+#[derive(Accounts)]
+pub struct CancelOutbound<'info> {
+    pub sender: Signer<'info>,
+
+    /// Not gated on [`Config::paused_outbound`], so that a pause doesn't keep
+    /// senders from taking back transfers it holds up.
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        has_one = sender,
+        constraint = OutboxItem::is_current_layout(&outbox_item.to_account_info()) @ NTTError::QueueItemNotMigrated,
+        constraint = !outbox_item.load()?.is_cancelled() @ NTTError::TransferAlreadyCancelled,
+        // once a transceiver sent it, the transfer can be redeemed on the peer
+        constraint = outbox_item.load()?.released.is_empty() @ NTTError::MessageAlreadySent,
+    )]
+    pub outbox_item: AccountLoader<'info, OutboxItem>,
+
+    #[account(
+        mut,
+        address = outbox_item.load()?.rent_payer,
+    )]
+    /// CHECK: the address is checked against the one recorded in the outbox item.
+    /// Required unless the outbox item predates [`OutboxItem::rent_payer`].
+    pub rent_payer: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, outbox_item.load()?.recipient_chain.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        mut,
+        address = config.mint @ NTTError::SourceMintMismatch,
+    )]
+    /// CHECK: the mint address matches the config
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        mut,
+        // NOTE: implied by the `token::mint` check below, but this way a
+        // wrong mint surfaces as a specific error
+        constraint = to.mint == config.mint @ NTTError::SourceMintMismatch,
+        token::mint = mint,
+        token::authority = sender,
+    )]
+    /// The sender's token account the tokens are returned to.
+    pub to: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint ensures that this is the correct address
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = config.custody
+    )]
+    pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+
+    #[account(
+        mut,
+        seeds = [CustodyLedger::SEED_PREFIX],
+        bump = custody_ledger.bump,
+    )]
+    /// Only needed in locking mode.
+    pub custody_ledger: Option<Account<'info, CustodyLedger>>,
+
+    #[account(
+        constraint = multisig_token_authority.m == 1
+            && multisig_token_authority.signers.contains(&token_authority.key())
+            @ NTTError::InvalidMultisig,
+    )]
+    /// Only needed in burning mode, when the mint authority is a multisig.
+    pub multisig_token_authority: Option<InterfaceAccount<'info, SplMultisig>>,
+
+    #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX],
+        bump,
+    )]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [ChainOutboxRateLimit::SEED_PREFIX, outbox_item.load()?.recipient_chain.to_be_bytes().as_ref()],
+        bump = chain_outbox_rate_limit.bump,
+    )]
+    /// Only needed when the transfer consumed from the recipient chain's own
+    /// limit (see [`OutboxItem::consumed_chain_outbound_limit`]).
+    pub chain_outbox_rate_limit: Option<Account<'info, ChainOutboxRateLimit>>,
+
+    #[account(
+        mut,
+        seeds = [SenderRateLimit::SEED_PREFIX, sender.key().as_ref()],
+        bump = sender_rate_limit.bump,
+    )]
+    /// Only needed when the transfer consumed from the sender's limit (see
+    /// [`OutboxItem::consumed_sender_limit`]).
+    pub sender_rate_limit: Option<Account<'info, SenderRateLimit>>,
+
+    #[account(
+        mut,
+        seeds = [InboxRateLimit::SEED_PREFIX, outbox_item.load()?.recipient_chain.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+}
+
+pub fn cancel_outbound_transfer<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelOutbound<'info>>,
+) -> Result<()> {
+    let accs = ctx.accounts;
+
+    let (amount, rent_payer) = {
+        let mut outbox_item = accs.outbox_item.load_mut()?;
+        outbox_item.cancel();
+        let amount = outbox_item
+            .trimmed_amount()
+            .untrim(accs.mint.decimals)
+            .map_err(NTTError::from)?;
+        restore_rate_limits(
+            &outbox_item,
+            amount,
+            &mut accs.outbox_rate_limit,
+            accs.chain_outbox_rate_limit.as_deref_mut(),
+            accs.sender_rate_limit.as_deref_mut(),
+            &mut accs.inbox_rate_limit,
+        )?;
+        (amount, outbox_item.rent_payer)
+    };
+    accs.peer.finish_outbound();
+
+    if rent_payer != Pubkey::default() {
+        let rent_payer = accs
+            .rent_payer
+            .as_ref()
+            .ok_or(ErrorCode::AccountNotEnoughKeys)?;
+        accs.outbox_item.close(rent_payer.to_account_info())?;
+    }
+
+    return_tokens(
+        &accs.config,
+        &accs.mint,
+        &accs.to,
+        &accs.token_authority,
+        ctx.bumps.token_authority,
+        &accs.custody,
+        &accs.token_program,
+        accs.custody_ledger.as_mut(),
+        accs.multisig_token_authority.as_ref(),
+        ctx.remaining_accounts,
+        amount,
+    )
+}
+
+/// Gives back the outbound rate limit capacity the transfer in `outbox_item`
+/// consumed, and takes back the inbound capacity it refilled (see
+/// [`OutboxItem::inbound_backflow`]). There is nothing to restore for queued
+/// transfers.
+pub(crate) fn restore_rate_limits(
+    outbox_item: &OutboxItem,
+    amount: u64,
+    outbox_rate_limit: &mut OutboxRateLimit,
+    chain_outbox_rate_limit: Option<&mut ChainOutboxRateLimit>,
+    sender_rate_limit: Option<&mut SenderRateLimit>,
+    inbox_rate_limit: &mut InboxRateLimit,
+) -> Result<()> {
+    if !outbox_item.consumed_outbound_limit() {
+        return Ok(());
+    }
+
+    let now = current_timestamp();
+    if outbox_item.consumed_chain_outbound_limit() {
+        chain_outbox_rate_limit
+            .ok_or(ErrorCode::AccountNotEnoughKeys)?
+            .refill(now, amount);
+    } else {
+        outbox_rate_limit.refill(now, amount);
+    }
+    if outbox_item.consumed_sender_limit() {
+        sender_rate_limit
+            .ok_or(ErrorCode::AccountNotEnoughKeys)?
+            .refill(now, amount);
+    }
+    inbox_rate_limit
+        .rate_limit
+        .drain(now, outbox_item.inbound_backflow);
+
+    Ok(())
+}
+
+/// Returns `amount` from custody to `to`, unlocking it in locking mode, or
+/// minting it back in burning mode.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn return_tokens<'info>(
+    config: &Config,
+    mint: &InterfaceAccount<'info, token_interface::Mint>,
+    to: &InterfaceAccount<'info, token_interface::TokenAccount>,
+    token_authority: &UncheckedAccount<'info>,
+    token_authority_bump: u8,
+    custody: &InterfaceAccount<'info, token_interface::TokenAccount>,
+    token_program: &Interface<'info, token_interface::TokenInterface>,
+    custody_ledger: Option<&mut Account<'info, CustodyLedger>>,
+    multisig_token_authority: Option<&InterfaceAccount<'info, SplMultisig>>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
+    let token_authority_sig: &[&[&[u8]]] =
+        &[&[crate::TOKEN_AUTHORITY_SEED, &[token_authority_bump]]];
+
+    match config.mode {
+        Mode::Locking => {
+            CustodyLedger::update(
+                config,
+                custody_ledger.map(|custody_ledger| &mut **custody_ledger),
+                |custody_ledger| custody_ledger.unlock(amount),
+            )?;
+        }
+        Mode::Burning => {
+            require!(
+                check_mint_authority(
+                    mint,
+                    &token_authority.key(),
+                    multisig_token_authority.map(|multisig| multisig.key()),
+                ),
+                NTTError::MintAuthorityRotatedAway
+            );
+
+            // minted to custody first, as in
+            // [`crate::instructions::release_inbound_mint`], so that transfer
+            // hooks run on the way out
+            match multisig_token_authority {
+                Some(multisig_token_authority) => mint_to_custody_from_multisig_token_authority(
+                    token_program.to_account_info(),
+                    mint.to_account_info(),
+                    custody.to_account_info(),
+                    multisig_token_authority.to_account_info(),
+                    token_authority.to_account_info(),
+                    token_authority_sig,
+                    amount,
+                )?,
+                None => mint_to_custody_from_token_authority(
+                    token_program.to_account_info(),
+                    mint.to_account_info(),
+                    custody.to_account_info(),
+                    token_authority.to_account_info(),
+                    token_authority_sig,
+                    amount,
+                )?,
+            };
+        }
+    }
+
+    onchain::invoke_transfer_checked(
+        &token_program.key(),
+        custody.to_account_info(),
+        mint.to_account_info(),
+        to.to_account_info(),
+        token_authority.to_account_info(),
+        remaining_accounts,
+        amount,
+        mint.decimals,
+        token_authority_sig,
+    )
+}
@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+
+use crate::{
+    clock::current_timestamp,
+    config::*,
+    custody_ledger::CustodyLedger,
+    error::NTTError,
+    instructions::cancel_outbound_transfer::{restore_rate_limits, return_tokens},
+    peer::NttManagerPeer,
+    queue::{
+        inbox::InboxRateLimit,
+        outbox::{ChainOutboxRateLimit, OutboxItem, OutboxRateLimit, SenderRateLimit},
+    },
+    spl_multisig::SplMultisig,
+};
+
+/// Closes an [`OutboxItem`] whose transfer expired before it was released,
+/// returning the rent to whoever paid for it.
+///
+/// This is permissionless, as expired transfers can't be released anyway.
+/// Unless a transceiver released the transfer before it expired, the tokens
+/// go back to the sender, and the rate limits are restored, as in
+/// [`crate::instructions::cancel_outbound_transfer`].
+#[derive(Accounts)]
+pub struct CloseExpiredOutboxItem<'info> {
+    #[account(
+        mut,
+        constraint = OutboxItem::is_current_layout(&outbox_item.to_account_info()) @ NTTError::QueueItemNotMigrated,
+        constraint = outbox_item.load()?.is_expired(current_timestamp()) @ NTTError::TransferNotExpired,
+        close = rent_payer,
+    )]
+    pub outbox_item: AccountLoader<'info, OutboxItem>,
+
+    #[account(
+        mut,
+        address = outbox_item.load()?.rent_payer,
+    )]
+    /// CHECK: the address is checked against the one recorded in the outbox item.
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, outbox_item.load()?.recipient_chain.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        address = config.mint @ NTTError::SourceMintMismatch,
+    )]
+    /// CHECK: the mint address matches the config
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        mut,
+        // NOTE: implied by the `token::mint` check below, but this way a
+        // wrong mint surfaces as a specific error
+        constraint = to.mint == config.mint @ NTTError::SourceMintMismatch,
+        constraint = to.owner == outbox_item.load()?.sender @ ErrorCode::ConstraintTokenOwner,
+        token::mint = mint,
+    )]
+    /// The sender's token account the tokens are returned to.
+    pub to: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint ensures that this is the correct address
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = config.custody
+    )]
+    pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+
+    #[account(
+        mut,
+        seeds = [CustodyLedger::SEED_PREFIX],
+        bump = custody_ledger.bump,
+    )]
+    /// Only needed in locking mode.
+    pub custody_ledger: Option<Account<'info, CustodyLedger>>,
+
+    #[account(
+        constraint = multisig_token_authority.m == 1
+            && multisig_token_authority.signers.contains(&token_authority.key())
+            @ NTTError::InvalidMultisig,
+    )]
+    /// Only needed in burning mode, when the mint authority is a multisig.
+    pub multisig_token_authority: Option<InterfaceAccount<'info, SplMultisig>>,
+
+    #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX],
+        bump,
+    )]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [ChainOutboxRateLimit::SEED_PREFIX, outbox_item.load()?.recipient_chain.to_be_bytes().as_ref()],
+        bump = chain_outbox_rate_limit.bump,
+    )]
+    /// Only needed when the transfer consumed from the recipient chain's own
+    /// limit (see [`OutboxItem::consumed_chain_outbound_limit`]).
+    pub chain_outbox_rate_limit: Option<Account<'info, ChainOutboxRateLimit>>,
+
+    #[account(
+        mut,
+        seeds = [SenderRateLimit::SEED_PREFIX, outbox_item.load()?.sender.as_ref()],
+        bump = sender_rate_limit.bump,
+    )]
+    /// Only needed when the transfer consumed from the sender's limit (see
+    /// [`OutboxItem::consumed_sender_limit`]).
+    pub sender_rate_limit: Option<Account<'info, SenderRateLimit>>,
+
+    #[account(
+        mut,
+        seeds = [InboxRateLimit::SEED_PREFIX, outbox_item.load()?.recipient_chain.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+}
+
+pub fn close_expired_outbox_item<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseExpiredOutboxItem<'info>>,
+) -> Result<()> {
+    let accs = ctx.accounts;
+
+    // a transfer that a transceiver released before it expired can still be
+    // redeemed on the peer, and one that was cancelled has been refunded
+    // already. Either way it's no longer in flight
+    let amount = {
+        let outbox_item = accs.outbox_item.load()?;
+        if !outbox_item.released.is_empty() || outbox_item.is_cancelled() {
+            return Ok(());
+        }
+        let amount = outbox_item
+            .trimmed_amount()
+            .untrim(accs.mint.decimals)
+            .map_err(NTTError::from)?;
+        restore_rate_limits(
+            &outbox_item,
+            amount,
+            &mut accs.outbox_rate_limit,
+            accs.chain_outbox_rate_limit.as_deref_mut(),
+            accs.sender_rate_limit.as_deref_mut(),
+            &mut accs.inbox_rate_limit,
+        )?;
+        amount
+    };
+    accs.peer.finish_outbound();
+
+    return_tokens(
+        &accs.config,
+        &accs.mint,
+        &accs.to,
+        &accs.token_authority,
+        ctx.bumps.token_authority,
+        &accs.custody,
+        &accs.token_program,
+        accs.custody_ledger.as_mut(),
+        accs.multisig_token_authority.as_ref(),
+        ctx.remaining_accounts,
+        amount,
+    )
+}
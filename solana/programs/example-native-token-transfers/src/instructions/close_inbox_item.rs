@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::NTTError,
+    queue::inbox::{InboxItem, ReleaseStatus},
+};
+
+/// Reclaims the rent of an inbox item whose transfer has been released,
+/// paying it to `rent_recipient`.
+///
+/// NOTE: the account can't be closed outright. We don't replay protect
+/// transceiver messages (see [`crate::instructions::Redeem`]), so redeeming
+/// the transfer again would re-create the inbox item from scratch and release
+/// it a second time. Instead, the account is shrunk down to its discriminator,
+/// which fails the `space` check of `init_if_needed` on redeem just like
+/// legacy inbox items do. Only the rent of the data is reclaimed.
+///
+/// This is permissionless, so whoever closes the item first gets the rent.
+#[derive(Accounts)]
+pub struct CloseInboxItem<'info> {
+    #[account(
+        mut,
+        constraint = InboxItem::is_current_layout(&inbox_item.to_account_info()) @ NTTError::QueueItemNotMigrated,
+        constraint = inbox_item.load()?.release_status() == ReleaseStatus::Released @ NTTError::InboxItemNotReleasable,
+    )]
+    pub inbox_item: AccountLoader<'info, InboxItem>,
+
+    #[account(mut)]
+    /// CHECK: any account can receive the rent.
+    pub rent_recipient: UncheckedAccount<'info>,
+}
+
+pub fn close_inbox_item(ctx: Context<CloseInboxItem>) -> Result<()> {
+    let info = ctx.accounts.inbox_item.to_account_info();
+
+    info.realloc(8, false)?;
+
+    let rent = info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(info.data_len()));
+    **info.try_borrow_mut_lamports()? -= rent;
+    **ctx.accounts.rent_recipient.try_borrow_mut_lamports()? += rent;
+
+    Ok(())
+}
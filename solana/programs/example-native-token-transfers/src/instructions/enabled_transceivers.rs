@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{config::Config, registered_transceiver::RegisteredTransceiver};
+
+#[derive(Accounts)]
+pub struct GetEnabledTransceivers<'info> {
+    pub config: Account<'info, Config>,
+}
+
+/// Returns the programs of the enabled transceivers.
+///
+/// The [`RegisteredTransceiver`] accounts to look up are passed in as remaining
+/// accounts. Transceivers whose bit is not set in
+/// [`Config::enabled_transceivers`] are skipped.
+pub fn get_enabled_transceivers<'info>(
+    ctx: Context<'_, '_, '_, 'info, GetEnabledTransceivers<'info>>,
+) -> Result<Vec<Pubkey>> {
+    let mut transceivers = Vec::new();
+
+    for info in ctx.remaining_accounts {
+        // registered transceiver accounts can only be created by this program,
+        // so checking the owner (and discriminator) is sufficient
+        require_keys_eq!(
+            *info.owner,
+            crate::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        let registered_transceiver =
+            RegisteredTransceiver::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+
+        if ctx
+            .accounts
+            .config
+            .enabled_transceivers
+            .get(registered_transceiver.id)?
+            && !transceivers.contains(&registered_transceiver.transceiver_address)
+        {
+            transceivers.push(registered_transceiver.transceiver_address);
+        }
+    }
+
+    Ok(transceivers)
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    bitmap::Bitmap,
+    clock::current_timestamp,
+    error::NTTError,
+    queue::inbox::{InboxItem, InboxRateLimit, ReleaseStatus},
+};
+
+#[derive(Accounts)]
+pub struct GetInboxItemStatus<'info> {
+    #[account(
+        constraint = InboxItem::is_current_layout(&inbox_item.to_account_info()) @ NTTError::QueueItemNotMigrated,
+    )]
+    pub inbox_item: AccountLoader<'info, InboxItem>,
+
+    #[account(
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            inbox_item.load()?.from_chain.to_be_bytes().as_ref(),
+        ],
+        bump = inbox_rate_limit.bump,
+    )]
+    /// The inbound rate limit of the chain the transfer was received from.
+    /// Inbox items that predate [`InboxItem::from_chain`] don't record the
+    /// chain, so their status can't be looked up.
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct InboxItemStatus {
+    pub amount: u64,
+    pub recipient_address: Pubkey,
+    pub votes: Bitmap,
+    pub release_status: ReleaseStatus,
+    /// Whether the transfer is held back by the inbound rate limit.
+    ///
+    /// * For transfers that have not been approved yet, this is whether the
+    /// current inbound capacity is insufficient to release them immediately.
+    /// * For approved transfers, this is whether the release timestamp is
+    /// still in the future.
+    pub rate_limited: bool,
+}
+
+pub fn get_inbox_item_status(ctx: Context<GetInboxItemStatus>) -> Result<InboxItemStatus> {
+    let inbox_item = ctx.accounts.inbox_item.load()?;
+    let release_status = inbox_item.release_status();
+
+    let rate_limited = match release_status {
+        ReleaseStatus::NotApproved => {
+            ctx.accounts.inbox_rate_limit.rate_limit.capacity() < inbox_item.amount
+        }
+        ReleaseStatus::ReleaseAfter(release_timestamp) => release_timestamp > current_timestamp(),
+        ReleaseStatus::Released => false,
+    };
+
+    Ok(InboxItemStatus {
+        amount: inbox_item.amount,
+        recipient_address: inbox_item.recipient_address,
+        votes: inbox_item.votes,
+        release_status,
+        rate_limited,
+    })
+}
@@ -1,4 +1,4 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::program_option::COption};
 use anchor_spl::{associated_token::AssociatedToken, token_interface};
 use ntt_messages::{chain_id::ChainId, mode::Mode};
 use wormhole_solana_utils::cpi::bpf_loader_upgradeable::BpfLoaderUpgradeable;
@@ -8,10 +8,13 @@ use crate::messages::Hack;
 
 use crate::{
     bitmap::Bitmap,
-    config::Config,
+    confidential_transfer::has_confidential_transfers,
+    config::{default_allowed_consistency_levels, Config},
+    custody_ledger::CustodyLedger,
     error::NTTError,
     queue::{outbox::OutboxRateLimit, rate_limit::RateLimitState},
     spl_multisig::SplMultisig,
+    transceivers::wormhole::accounts::default_fee_collector,
 };
 
 #[derive(Accounts)]
@@ -35,15 +38,20 @@ pub struct Initialize<'info> {
         space = 8 + Config::INIT_SPACE,
         payer = payer,
         seeds = [Config::SEED_PREFIX],
-        bump
+        bump,
+        // NOTE: this is checked here rather than on the mint so that it runs
+        // before the custody account is created, which would otherwise fail
+        // with a less descriptive error.
+        constraint = *mint.to_account_info().owner == token_program.key() @ NTTError::TokenProgramMismatch,
     )]
     pub config: Box<Account<'info, Config>>,
 
     #[account(
         constraint = args.mode == Mode::Locking
-            || mint.mint_authority.unwrap() == multisig_token_authority.as_ref().map_or(
-                token_authority.key(),
-                |multisig_token_authority| multisig_token_authority.key()
+            || check_mint_authority(
+                &mint,
+                &token_authority.key(),
+                multisig_token_authority.as_ref().map(|multisig| multisig.key()),
             ) @ NTTError::InvalidMintAuthority
     )]
     pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
@@ -57,6 +65,15 @@ pub struct Initialize<'info> {
     )]
     pub rate_limit: Account<'info, OutboxRateLimit>,
 
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CustodyLedger::INIT_SPACE,
+        seeds = [CustodyLedger::SEED_PREFIX],
+        bump,
+    )]
+    pub custody_ledger: Box<Account<'info, CustodyLedger>>,
+
     #[account(
         seeds = [crate::TOKEN_AUTHORITY_SEED],
         bump,
@@ -82,6 +99,7 @@ pub struct Initialize<'info> {
         associated_token::mint = mint,
         associated_token::authority = token_authority,
         associated_token::token_program = token_program,
+        constraint = *custody.to_account_info().owner == token_program.key() @ NTTError::TokenProgramMismatch,
     )]
     /// The custody account that holds tokens in locking mode and temporarily
     /// holds tokens in burning mode.
@@ -106,6 +124,11 @@ pub struct InitializeArgs {
 }
 
 pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
+    ctx.accounts.custody_ledger.set_inner(CustodyLedger {
+        bump: ctx.bumps.custody_ledger,
+        locked_amount: 0,
+    });
+
     initialize_config_and_rate_limit(
         ctx.accounts,
         ctx.bumps.config,
@@ -115,6 +138,42 @@ pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()>
     )
 }
 
+/// Whether the mint authority of `mint` is the one burning mode requires:
+/// `multisig_token_authority` if given, the token authority otherwise.
+///
+/// Initializing before handing over the mint authority is the most common
+/// deployment mistake, so the expected and actual authorities are logged when
+/// they don't match. The same goes for rotating it away after deployment,
+/// which is why this is also checked before minting and burning.
+pub fn check_mint_authority(
+    mint: &token_interface::Mint,
+    token_authority: &Pubkey,
+    multisig_token_authority: Option<Pubkey>,
+) -> bool {
+    let expected = multisig_token_authority.unwrap_or(*token_authority);
+    if mint.mint_authority == COption::Some(expected) {
+        return true;
+    }
+
+    match multisig_token_authority {
+        Some(multisig) => msg!(
+            "Expected mint authority: {} (multisig of token authority {})",
+            multisig,
+            token_authority
+        ),
+        None => msg!(
+            "Expected mint authority: {} (token authority), or a multisig of it",
+            token_authority
+        ),
+    }
+    match mint.mint_authority {
+        COption::Some(actual) => msg!("Actual mint authority: {}", actual),
+        COption::None => msg!("Actual mint authority: none"),
+    }
+
+    false
+}
+
 fn initialize_config_and_rate_limit(
     common: &mut Initialize<'_>,
     config_bump: u8,
@@ -130,12 +189,42 @@ fn initialize_config_and_rate_limit(
         chain_id: ChainId { id: chain_id },
         owner: common.deployer.key(),
         pending_owner: None,
-        paused: false,
+        paused_outbound: false,
         next_transceiver_id: 0,
         // NOTE: can be changed via `set_threshold` ix
         threshold: 1,
         enabled_transceivers: Bitmap::new(),
         custody: common.custody.key(),
+        // NOTE: can be changed via `set_routes_default_enabled` ix
+        routes_default_enabled: true,
+        mint_decimals: common.mint.decimals,
+        // NOTE: can be changed via `set_receive_hook` ix
+        receive_hook: None,
+        // NOTE: can be changed via `set_fee_collector` ix
+        fee_collector: default_fee_collector(),
+        confidential_transfers: has_confidential_transfers(&common.mint.to_account_info())?,
+        // NOTE: can be changed via `set_dust_account` ix
+        dust_account: None,
+        // NOTE: can be changed via `set_limits_manager` ix
+        limits_manager: None,
+        // NOTE: can be changed via `set_max_vaa_age` ix
+        max_vaa_age: None,
+        // NOTE: can be changed via `set_reject_unconsumed_payload` ix
+        reject_unconsumed_payload: false,
+        // NOTE: can be changed via `begin_decommission` ix
+        decommission_timestamp: None,
+        // NOTE: can be changed via `finalize_decommission` ix
+        terminated: false,
+        // NOTE: can be changed via `set_allowed_consistency_levels` ix
+        allowed_consistency_levels: default_allowed_consistency_levels(),
+        paused_inbound: false,
+        // NOTE: can be changed via `set_sender_limit` ix
+        sender_limit: None,
+        // NOTE: can be changed via `set_per_chain_outbound_limits` ix
+        per_chain_outbound_limits: false,
+        // NOTE: set by the first `set_sender_limit_override` ix
+        sender_limit_overrides: false,
+        tracks_custody: true,
     });
 
     common.rate_limit.set_inner(OutboxRateLimit {
@@ -0,0 +1,184 @@
+use anchor_lang::{prelude::*, system_program, Space};
+use ntt_messages::chain_id::{known_chain, ChainId};
+
+use crate::{
+    error::NTTError,
+    instructions::{initialize, Initialize, InitializeArgs},
+    peer::{check_peer_address, NttManagerPeer, PeerIndex},
+    queue::{inbox::InboxRateLimit, rate_limit::RateLimitState},
+    transceivers::accounts::peer::{PeerAddressMatch, TransceiverPeer, TransceiverPeerIndex},
+};
+
+/// The most peers [`initialize_with_peers`] takes. Three peers (with
+/// transceiver peers) is what fits in a transaction without an address lookup
+/// table. More can be added afterwards with [`crate::instructions::set_peer`].
+pub const MAX_INITIAL_PEERS: usize = 3;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitialPeer {
+    pub chain_id: ChainId,
+    /// The NTT manager on the peer chain.
+    pub address: [u8; 32],
+    /// The token decimals on the peer chain.
+    pub token_decimals: u8,
+    pub inbound_limit: u64,
+    /// The peer of the integrated Wormhole transceiver, see
+    /// [`crate::transceivers::wormhole::set_transceiver_peer`]. Standalone
+    /// transceivers have to be set up separately.
+    pub transceiver_address: Option<[u8; 32]>,
+    /// Skip the check that `chain_id` is a known Wormhole chain id.
+    pub allow_unknown_chain: bool,
+    /// Skip the check that `address` is well-formed for the chain, see
+    /// [`check_peer_address`].
+    pub allow_nonstandard_address: bool,
+}
+
+/// [`initialize`], and sets up `peers` in the same transaction, so that a
+/// failure midway doesn't leave a partially wired deployment behind.
+///
+/// For each peer, the remaining accounts are its [`NttManagerPeer`] and
+/// [`InboxRateLimit`], followed by its [`TransceiverPeer`] when it has a
+/// transceiver address. These are followed by the [`PeerIndex`] (unless
+/// `peers` is empty), and then the [`TransceiverPeerIndex`] (if any peer has a
+/// transceiver address).
+pub fn initialize_with_peers<'info>(
+    ctx: Context<'_, '_, '_, 'info, Initialize<'info>>,
+    args: InitializeArgs,
+    peers: Vec<InitialPeer>,
+) -> Result<()> {
+    require!(
+        peers.len() <= MAX_INITIAL_PEERS,
+        NTTError::TooManyInitialPeers
+    );
+
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let remaining_accounts = ctx.remaining_accounts;
+    let mut accounts = remaining_accounts.iter();
+    let chain_id = args.chain_id;
+
+    initialize(ctx, args)?;
+
+    let mut chain_ids = vec![];
+    let mut transceiver_chain_ids = vec![];
+
+    for peer in peers {
+        require!(peer.chain_id.id != chain_id, NTTError::InvalidChainId);
+        require!(
+            peer.allow_unknown_chain || known_chain(peer.chain_id.id),
+            NTTError::UnknownChainId
+        );
+        check_peer_address(
+            peer.chain_id.id,
+            &peer.address,
+            peer.allow_nonstandard_address,
+        )?;
+        let chain = peer.chain_id.id.to_be_bytes();
+
+        // same as a new peer in [`crate::instructions::set_peer`]
+        init_pda(
+            &payer,
+            accounts.next(),
+            &system_program,
+            &[NttManagerPeer::SEED_PREFIX, &chain],
+            |bump| NttManagerPeer {
+                bump,
+                address: peer.address,
+                token_decimals: peer.token_decimals,
+                enabled: true,
+                outbound_in_flight: 0,
+                max_outbound_in_flight: None,
+                allow_queue: true,
+                destination_fee_bps: 0,
+                fallback_recipient: None,
+                inbound_in_flight: 0,
+                allow_inbound_queue: true,
+            },
+        )?;
+
+        init_pda(
+            &payer,
+            accounts.next(),
+            &system_program,
+            &[InboxRateLimit::SEED_PREFIX, &chain],
+            |bump| InboxRateLimit {
+                bump,
+                rate_limit: RateLimitState::new(peer.inbound_limit),
+            },
+        )?;
+
+        if let Some(address) = peer.transceiver_address {
+            init_pda(
+                &payer,
+                accounts.next(),
+                &system_program,
+                &[TransceiverPeer::SEED_PREFIX, &chain],
+                |bump| TransceiverPeer {
+                    bump,
+                    address,
+                    address_match: PeerAddressMatch::Exact,
+                },
+            )?;
+            transceiver_chain_ids.push(peer.chain_id.id);
+        }
+        chain_ids.push(peer.chain_id.id);
+    }
+
+    if !chain_ids.is_empty() {
+        init_pda(
+            &payer,
+            accounts.next(),
+            &system_program,
+            &[PeerIndex::SEED_PREFIX],
+            |bump| PeerIndex { bump, chain_ids },
+        )?;
+    }
+
+    if !transceiver_chain_ids.is_empty() {
+        init_pda(
+            &payer,
+            accounts.next(),
+            &system_program,
+            &[TransceiverPeerIndex::SEED_PREFIX],
+            |bump| TransceiverPeerIndex {
+                bump,
+                chain_ids: transceiver_chain_ids,
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Creates the account of this program at `seeds`, and writes `new(bump)` to it.
+fn init_pda<'info, T: AccountSerialize + Space>(
+    payer: &AccountInfo<'info>,
+    account: Option<&AccountInfo<'info>>,
+    system_program: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    new: impl FnOnce(u8) -> T,
+) -> Result<()> {
+    let account = account.ok_or(ErrorCode::AccountNotEnoughKeys)?;
+    let (address, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(account.key(), address, ErrorCode::ConstraintSeeds);
+
+    let space = 8 + T::INIT_SPACE;
+    let bump_seed = [bump];
+    let signer_seeds: Vec<&[u8]> = seeds.iter().copied().chain([&bump_seed[..]]).collect();
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::CreateAccount {
+                from: payer.clone(),
+                to: account.clone(),
+            },
+            &[&signer_seeds[..]],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space.try_into().unwrap(),
+        &crate::ID,
+    )?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    new(bump).try_serialize(&mut &mut data[..])
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    config::*, error::NTTError, queue::outbox::OutboxItem,
+    config::*, error::NTTError, peer::NttManagerPeer, queue::outbox::OutboxItem,
     registered_transceiver::RegisteredTransceiver,
 };
 
@@ -16,13 +16,21 @@ pub struct MarkOutboxItemAsReleased<'info> {
     )]
     pub signer: Signer<'info>,
 
-    pub config: NotPausedConfig<'info>,
+    pub config: NotPausedOutboundConfig<'info>,
 
     #[account(
         mut,
-        constraint = !outbox_item.released.get(transceiver.id)? @ NTTError::MessageAlreadySent,
+        constraint = OutboxItem::is_current_layout(&outbox_item.to_account_info()) @ NTTError::QueueItemNotMigrated,
+        constraint = !outbox_item.load()?.released.get(transceiver.id)? @ NTTError::MessageAlreadySent,
     )]
-    pub outbox_item: Account<'info, OutboxItem>,
+    pub outbox_item: AccountLoader<'info, OutboxItem>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, outbox_item.load()?.recipient_chain.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
 
     #[account(
         constraint = config.enabled_transceivers.get(transceiver.id)? @ NTTError::DisabledTransceiver
@@ -32,6 +40,9 @@ pub struct MarkOutboxItemAsReleased<'info> {
 
 pub fn mark_outbox_item_as_released(ctx: Context<MarkOutboxItemAsReleased>) -> Result<bool> {
     let accs = ctx.accounts;
-    let released = accs.outbox_item.try_release(accs.transceiver.id)?;
+    let released = accs
+        .outbox_item
+        .load_mut()?
+        .try_release(accs.transceiver.id, &mut accs.peer)?;
     Ok(released)
 }
@@ -0,0 +1,104 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    error::NTTError,
+    instructions::migrate_queue_item::resize,
+    peer::{NttManagerPeer, PeerIndex},
+};
+
+// * Migrate peers
+
+/// Layout of [`NttManagerPeer`] before the fields following `token_decimals`
+/// were appended to it.
+#[derive(AnchorDeserialize)]
+struct LegacyNttManagerPeer {
+    bump: u8,
+    address: [u8; 32],
+    token_decimals: u8,
+}
+
+impl LegacyNttManagerPeer {
+    const SPACE: usize = 1 + 32 + 1;
+}
+
+/// Brings an [`NttManagerPeer`] created by an earlier version of the program
+/// up to the current layout, so that transfers to and from its chain (and
+/// [`crate::instructions::set_peer`]) work again. The peer is also added to
+/// the [`PeerIndex`], which didn't exist when it was set.
+///
+/// This is permissionless, as it doesn't change the peer's configuration: the
+/// new fields get the values that match how the peer behaved before they
+/// existed.
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct MigratePeer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    /// CHECK: deserialized manually in [`migrate_peer`], as it is still in the
+    /// legacy layout.
+    pub peer: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + PeerIndex::INIT_SPACE,
+        payer = payer,
+        seeds = [PeerIndex::SEED_PREFIX],
+        bump,
+    )]
+    pub peer_index: Account<'info, PeerIndex>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_peer(ctx: Context<MigratePeer>, chain_id: u16) -> Result<()> {
+    let info = ctx.accounts.peer.to_account_info();
+
+    let legacy = {
+        let data = info.try_borrow_data()?;
+        require!(
+            data.starts_with(&NttManagerPeer::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        require!(
+            data.len() == 8 + LegacyNttManagerPeer::SPACE,
+            NTTError::PeerAlreadyMigrated
+        );
+        LegacyNttManagerPeer::deserialize(&mut &data[8..])?
+    };
+
+    resize(
+        &info,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        8 + NttManagerPeer::INIT_SPACE,
+    )?;
+
+    let peer = NttManagerPeer {
+        bump: legacy.bump,
+        address: legacy.address,
+        token_decimals: legacy.token_decimals,
+        // legacy peers were always enabled, and had no cap on (or count of)
+        // their in-flight transfers
+        enabled: true,
+        outbound_in_flight: 0,
+        max_outbound_in_flight: None,
+        allow_queue: true,
+        destination_fee_bps: 0,
+        fallback_recipient: None,
+        inbound_in_flight: 0,
+        allow_inbound_queue: true,
+    };
+    peer.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    ctx.accounts.peer_index.bump = ctx.bumps.peer_index;
+    ctx.accounts.peer_index.insert(chain_id)?;
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    error::NTTError,
+    queue::{
+        inbox::{InboxItem, LegacyInboxItem},
+        outbox::{LegacyOutboxItem, OutboxItem},
+    },
+};
+
+// * Migrate queue items
+
+/// Converts an [`OutboxItem`] created before it was made zero-copy (see
+/// [`LegacyOutboxItem`]) to the current layout, so that it can be released.
+///
+/// This is permissionless, as it doesn't change the contents of the item.
+#[derive(Accounts)]
+pub struct MigrateOutboxItem<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    /// CHECK: deserialized manually in [`migrate_outbox_item`], as it is still
+    /// in the legacy layout.
+    pub outbox_item: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_outbox_item(ctx: Context<MigrateOutboxItem>) -> Result<()> {
+    let info = ctx.accounts.outbox_item.to_account_info();
+
+    require!(
+        !OutboxItem::is_current_layout(&info),
+        NTTError::QueueItemAlreadyMigrated
+    );
+
+    let legacy: LegacyOutboxItem = read_legacy(&info, &OutboxItem::DISCRIMINATOR)?;
+
+    resize(
+        &info,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        8 + OutboxItem::INIT_SPACE,
+    )?;
+    *AccountLoader::<OutboxItem>::try_from(&info)?.load_mut()? = legacy.into();
+
+    Ok(())
+}
+
+/// Converts an [`InboxItem`] created before it was made zero-copy to the
+/// current layout, so that it can be voted on and released.
+///
+/// This is permissionless, as it doesn't change the contents of the item.
+#[derive(Accounts)]
+pub struct MigrateInboxItem<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    /// CHECK: deserialized manually in [`migrate_inbox_item`], as it is still
+    /// in the legacy layout.
+    pub inbox_item: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_inbox_item(ctx: Context<MigrateInboxItem>) -> Result<()> {
+    let info = ctx.accounts.inbox_item.to_account_info();
+
+    require!(
+        !InboxItem::is_current_layout(&info),
+        NTTError::QueueItemAlreadyMigrated
+    );
+    require!(
+        !InboxItem::is_closed(&info),
+        NTTError::TransferAlreadyRedeemed
+    );
+    let legacy: LegacyInboxItem = read_legacy(&info, &InboxItem::DISCRIMINATOR)?;
+
+    resize(
+        &info,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        8 + InboxItem::INIT_SPACE,
+    )?;
+    *AccountLoader::<InboxItem>::try_from(&info)?.load_mut()? = legacy.into();
+
+    Ok(())
+}
+
+/// The discriminator is the same in both layouts, so only the length of the
+/// account tells them apart (see the `is_current_layout` functions).
+fn read_legacy<T: AnchorDeserialize>(info: &AccountInfo, discriminator: &[u8; 8]) -> Result<T> {
+    let data = info.try_borrow_data()?;
+    require!(
+        data.starts_with(discriminator),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    Ok(T::deserialize(&mut &data[8..])?)
+}
+
+/// Resizes the account to `new_len`, topping up its rent from `payer` if it
+/// grows.
+pub fn resize<'info>(
+    info: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    new_len: usize,
+) -> Result<()> {
+    let rent = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(info.lamports());
+    if rent > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            rent,
+        )?;
+    }
+    info.realloc(new_len, false)
+}
@@ -1,15 +1,51 @@
 pub mod admin;
+pub mod cancel_outbound_transfer;
+pub mod close_expired_outbox_item;
+pub mod close_inbox_item;
+pub mod enabled_transceivers;
+pub mod inbox_item_status;
 pub mod initialize;
+pub mod initialize_with_peers;
 pub mod luts;
 pub mod mark_outbox_item_as_released;
+pub mod migrate_peer;
+pub mod migrate_queue_item;
+pub mod peer_decimals;
+pub mod peer_index;
+pub mod preflight_initialize;
+pub mod preview_transfer;
+pub mod rate_limit_params;
+pub mod reconcile_custody;
 pub mod redeem;
 pub mod release_inbound;
+pub mod repair_custody;
+pub mod stats;
 pub mod transfer;
+pub mod transfer_status;
+pub mod would_queue;
 
 pub use admin::*;
+pub use cancel_outbound_transfer::*;
+pub use close_expired_outbox_item::*;
+pub use close_inbox_item::*;
+pub use enabled_transceivers::*;
+pub use inbox_item_status::*;
 pub use initialize::*;
+pub use initialize_with_peers::*;
 pub use luts::*;
 pub use mark_outbox_item_as_released::*;
+pub use migrate_peer::*;
+pub use migrate_queue_item::*;
+pub use peer_decimals::*;
+pub use peer_index::*;
+pub use preflight_initialize::*;
+pub use preview_transfer::*;
+pub use rate_limit_params::*;
+pub use reconcile_custody::*;
 pub use redeem::*;
 pub use release_inbound::*;
+pub use repair_custody::*;
+pub use stats::*;
 pub use transfer::*;
+pub use transfer_status::*;
+pub use would_queue::*;
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use ntt_messages::trimmed_amount::TrimmedAmount;
+
+use crate::{config::Config, peer::NttManagerPeer};
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct GetPeerDecimals<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        address = config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PeerDecimals {
+    /// The decimals of the local mint.
+    pub mint_decimals: u8,
+    /// The decimals of the peer's token, as recorded in [`NttManagerPeer`].
+    pub peer_token_decimals: u8,
+    /// The decimals amounts are trimmed to in transfers to the peer.
+    pub trimmed_decimals: u8,
+}
+
+/// Reports the decimals involved in transfers to and from the peer on
+/// `chain_id`, so that operators can check they are consistent with the
+/// token deployed on the peer chain.
+pub fn get_peer_decimals(ctx: Context<GetPeerDecimals>, _chain_id: u16) -> Result<PeerDecimals> {
+    let mint_decimals = ctx.accounts.mint.decimals;
+    let peer_token_decimals = ctx.accounts.peer.token_decimals;
+
+    Ok(PeerDecimals {
+        mint_decimals,
+        peer_token_decimals,
+        trimmed_decimals: TrimmedAmount::trimmed_decimals(mint_decimals, peer_token_decimals),
+    })
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::peer::PeerIndex;
+
+#[derive(Accounts)]
+pub struct GetPeerIndex<'info> {
+    #[account(
+        seeds = [PeerIndex::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the index doesn't exist until the first peer is set, which is
+    /// checked in [`get_peer_index`].
+    pub peer_index: UncheckedAccount<'info>,
+}
+
+/// Returns the chain ids of the configured peers (see [`PeerIndex`]).
+pub fn get_peer_index(ctx: Context<GetPeerIndex>) -> Result<Vec<u16>> {
+    indexed_chain_ids(&ctx.accounts.peer_index)
+}
+
+#[derive(Accounts)]
+pub struct GetConfiguredChains<'info> {
+    #[account(
+        seeds = [PeerIndex::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: see [`GetPeerIndex::peer_index`].
+    pub peer_index: UncheckedAccount<'info>,
+}
+
+/// Returns the chains with a configured peer, in the order they were first
+/// set, so that UIs can list the routes without scanning the program's
+/// accounts. Whether a route is enabled is up to its [`crate::peer::NttManagerPeer`].
+pub fn get_configured_chains(ctx: Context<GetConfiguredChains>) -> Result<Vec<u16>> {
+    indexed_chain_ids(&ctx.accounts.peer_index)
+}
+
+fn indexed_chain_ids(peer_index: &AccountInfo) -> Result<Vec<u16>> {
+    if peer_index.data_is_empty() {
+        return Ok(vec![]);
+    }
+
+    let peer_index = PeerIndex::try_deserialize(&mut &peer_index.try_borrow_data()?[..])?;
+    Ok(peer_index.chain_ids)
+}
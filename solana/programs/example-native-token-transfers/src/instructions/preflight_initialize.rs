@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::get_associated_token_address_with_program_id, token_interface};
+use ntt_messages::{mode::Mode, trimmed_amount::TRIMMED_DECIMALS};
+use spl_token_2022::{
+    extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+    state::Account,
+};
+
+use crate::{config::Config, instructions::check_mint_authority, spl_multisig::SplMultisig};
+
+/// The problems [`preflight_initialize`] reports, as bits of its return value.
+pub mod diagnostics {
+    /// The program has already been initialized.
+    pub const ALREADY_INITIALIZED: u32 = 1 << 0;
+    /// The mint is not owned by the given token program.
+    pub const TOKEN_PROGRAM_MISMATCH: u32 = 1 << 1;
+    /// Burning mode, and the mint authority has not been handed over to the
+    /// token authority (or the given multisig).
+    pub const INVALID_MINT_AUTHORITY: u32 = 1 << 2;
+    /// The given multisig is not a 1-of-n multisig of the token authority.
+    pub const INVALID_MULTISIG: u32 = 1 << 3;
+    /// The mint has too many decimals for amounts to be scaled back up from
+    /// their trimmed representation.
+    pub const UNSUPPORTED_DECIMALS: u32 = 1 << 4;
+    /// Locking mode, and the mint charges transfer fees, so the amount that
+    /// arrives in custody would not match the amount transferred.
+    pub const TRANSFER_FEE: u32 = 1 << 5;
+    /// The custody account already exists and holds tokens, which would not
+    /// be accounted for in the custody ledger.
+    pub const CUSTODY_NOT_EMPTY: u32 = 1 << 6;
+}
+
+/// Runs the checks [`crate::instructions::initialize`] does (and a few it
+/// can't do until tokens start moving), without creating anything. This way
+/// misconfigurations surface before the deployment is under way.
+#[derive(Accounts)]
+pub struct PreflightInitialize<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: only checked for existence.
+    pub config: UncheckedAccount<'info>,
+
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: only its address is used.
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        address = get_associated_token_address_with_program_id(
+            &token_authority.key(),
+            &mint.key(),
+            &token_program.key(),
+        ),
+    )]
+    /// CHECK: the custody account may not exist yet.
+    pub custody: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+
+    pub multisig_token_authority: Option<Box<InterfaceAccount<'info, SplMultisig>>>,
+}
+
+/// Returns the problems found as a bitmask of [`diagnostics`], zero if
+/// [`crate::instructions::initialize`] would succeed in `mode`.
+pub fn preflight_initialize(ctx: Context<PreflightInitialize>, mode: Mode) -> Result<u32> {
+    let accs = ctx.accounts;
+    let mint_info = accs.mint.to_account_info();
+    let mut problems = 0;
+
+    if !accs.config.data_is_empty() {
+        problems |= diagnostics::ALREADY_INITIALIZED;
+    }
+
+    if *mint_info.owner != accs.token_program.key() {
+        problems |= diagnostics::TOKEN_PROGRAM_MISMATCH;
+    }
+
+    if mode == Mode::Burning
+        && !check_mint_authority(
+            &accs.mint,
+            &accs.token_authority.key(),
+            accs.multisig_token_authority
+                .as_ref()
+                .map(|multisig| multisig.key()),
+        )
+    {
+        problems |= diagnostics::INVALID_MINT_AUTHORITY;
+    }
+
+    if let Some(multisig) = &accs.multisig_token_authority {
+        if multisig.m != 1 || !multisig.signers.contains(&accs.token_authority.key()) {
+            problems |= diagnostics::INVALID_MULTISIG;
+        }
+    }
+
+    // see [`ntt_messages::trimmed_amount::TrimmedAmount::untrim`]
+    let excess_decimals = accs.mint.decimals.saturating_sub(TRIMMED_DECIMALS);
+    if 10u64.checked_pow(excess_decimals.into()).is_none() {
+        problems |= diagnostics::UNSUPPORTED_DECIMALS;
+    }
+
+    if mode == Mode::Locking && has_transfer_fee(&mint_info)? {
+        problems |= diagnostics::TRANSFER_FEE;
+    }
+
+    if !accs.custody.data_is_empty() {
+        let data = accs.custody.try_borrow_data()?;
+        let custody = StateWithExtensions::<Account>::unpack(&data)?;
+        if custody.base.amount > 0 {
+            problems |= diagnostics::CUSTODY_NOT_EMPTY;
+        }
+    }
+
+    Ok(problems)
+}
+
+/// Whether `mint` has the transfer fee extension.
+fn has_transfer_fee(mint: &AccountInfo) -> Result<bool> {
+    // classic token mints don't have extensions
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(false);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    Ok(mint.get_extension::<TransferFeeConfig>().is_ok())
+}
@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use ntt_messages::trimmed_amount::TrimmedAmount;
+use wormhole_anchor_sdk::wormhole;
+
+use crate::{
+    clock::current_time,
+    config::Config,
+    error::NTTError,
+    instructions::{
+        transfer::{consume_outbound_rate_limits, uses_chain_outbound_limit},
+        TransferArgs,
+    },
+    peer::NttManagerPeer,
+    queue::{
+        outbox::{ChainOutboxRateLimit, OutboxItem, OutboxRateLimit, SenderRateLimit},
+        rate_limit::{RateLimitResult, RateLimitState},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(args: TransferArgs)]
+pub struct PreviewTransfer<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        address = config.mint @ NTTError::SourceMintMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        constraint = from.mint == config.mint @ NTTError::SourceMintMismatch,
+    )]
+    /// The token account the transfer would be sent from.
+    pub from: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        seeds = [OutboxRateLimit::SEED_PREFIX],
+        bump,
+    )]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
+    #[account(
+        seeds = [ChainOutboxRateLimit::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump,
+    )]
+    /// CHECK: see [`crate::instructions::TransferBurn::chain_outbox_rate_limit`].
+    pub chain_outbox_rate_limit: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [SenderRateLimit::SEED_PREFIX, from.owner.as_ref()],
+        bump,
+    )]
+    /// CHECK: doesn't exist until the sender's first transfer once senders are
+    /// limited, which is checked in [`preview_transfer`].
+    pub sender_rate_limit: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [wormhole::BridgeData::SEED_PREFIX],
+        bump,
+        seeds::program = wormhole::program::ID,
+    )]
+    pub wormhole_bridge: Account<'info, wormhole::BridgeData>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TransferPreview {
+    /// The amount taken from the sender, i.e. the requested amount without the
+    /// dust that can't be represented on the peer.
+    pub amount: u64,
+    /// The dust left in the sender's account, or collected into the dust
+    /// account (see [`Config::dust_account`]).
+    pub dust: u64,
+    /// The amount carried by the transfer message.
+    pub trimmed_amount: TrimmedAmount,
+    /// What the recipient is expected to receive after the peer's destination
+    /// fee, see [`NttManagerPeer::expected_amount_out`].
+    pub expected_amount_out: u64,
+    pub peer_decimals: u8,
+    /// Whether transfers to the peer are enabled at all.
+    pub route_enabled: bool,
+    /// Whether the rate limits would hold the transfer back.
+    pub rate_limited: bool,
+    /// Whether the transfer would be held back by the sender's own limit (see
+    /// [`Config::sender_limit`]) rather than the outbound one.
+    pub exceeds_sender_limit: bool,
+    /// Whether a rate limited transfer would be queued rather than rejected,
+    /// which takes both the sender asking for it and the peer allowing it.
+    pub queue_allowed: bool,
+    /// When the transfer could be released by the transceivers.
+    pub release_timestamp: i64,
+    /// The core bridge fee the Wormhole transceiver pays to release the
+    /// transfer.
+    pub wormhole_fee: u64,
+    /// The rent of the outbox item, refunded once it's closed.
+    pub outbox_item_rent: u64,
+    /// The rent of the sender's rate limit account, if the transfer would
+    /// create it.
+    pub sender_rate_limit_rent: u64,
+}
+
+/// Previews a transfer with `args` from [`PreviewTransfer::from`], without
+/// sending anything, so that wallets can show what it amounts to in a single
+/// call. Everything is worked out the same way the transfer instructions do,
+/// on a copy of the rate limits.
+pub fn preview_transfer(
+    ctx: Context<PreviewTransfer>,
+    args: TransferArgs,
+) -> Result<TransferPreview> {
+    let accs = ctx.accounts;
+    let now = current_time()?;
+    let rent = Rent::get()?;
+
+    let mut amount = args.amount;
+    let trimmed_amount =
+        TrimmedAmount::remove_dust(&mut amount, accs.mint.decimals, accs.peer.token_decimals)
+            .map_err(NTTError::from)?;
+
+    let mut outbound_rate_limit =
+        if uses_chain_outbound_limit(&accs.config, Some(accs.chain_outbox_rate_limit.as_ref()))? {
+            Account::<ChainOutboxRateLimit>::try_from(&accs.chain_outbox_rate_limit)?
+                .rate_limit
+                .clone()
+        } else {
+            accs.outbox_rate_limit.rate_limit.clone()
+        };
+
+    // the transfer creates the sender's rate limit zeroed out, if it needs it
+    // at all
+    let (mut sender_rate_limit, sender_rate_limit_rent) = if accs.sender_rate_limit.data_is_empty()
+    {
+        let sender_rate_limit = SenderRateLimit {
+            bump: ctx.bumps.sender_rate_limit,
+            limit_override: None,
+            rate_limit: RateLimitState {
+                limit: 0,
+                capacity_at_last_tx: 0,
+                last_tx_timestamp: 0,
+            },
+        };
+        let sender_rate_limit_rent = if accs.config.limits_senders() {
+            rent.minimum_balance(8 + SenderRateLimit::INIT_SPACE)
+        } else {
+            0
+        };
+        (sender_rate_limit, sender_rate_limit_rent)
+    } else {
+        let sender_rate_limit = Account::<SenderRateLimit>::try_from(&accs.sender_rate_limit)?;
+        (sender_rate_limit.into_inner(), 0)
+    };
+
+    let (rate_limit_result, within_sender_limit) = consume_outbound_rate_limits(
+        &mut outbound_rate_limit,
+        sender_rate_limit.current_rate_limit(accs.config.sender_limit),
+        now,
+        amount,
+    );
+    let (rate_limited, release_timestamp) = match rate_limit_result {
+        RateLimitResult::Consumed(release_timestamp) => (false, release_timestamp),
+        RateLimitResult::Delayed(release_timestamp) => (true, release_timestamp),
+    };
+
+    Ok(TransferPreview {
+        amount,
+        dust: args.amount - amount,
+        trimmed_amount,
+        expected_amount_out: accs.peer.expected_amount_out(trimmed_amount),
+        peer_decimals: accs.peer.token_decimals,
+        route_enabled: accs.peer.enabled,
+        rate_limited,
+        exceeds_sender_limit: !within_sender_limit,
+        queue_allowed: args.should_queue && accs.peer.allow_queue,
+        release_timestamp,
+        wormhole_fee: accs.wormhole_bridge.fee(),
+        outbox_item_rent: rent.minimum_balance(8 + OutboxItem::INIT_SPACE),
+        sender_rate_limit_rent,
+    })
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    clock::current_time,
+    queue::{inbox::InboxRateLimit, outbox::OutboxRateLimit, rate_limit::RateLimitState},
+};
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct GetRateLimitParams<'info> {
+    #[account(
+        seeds = [InboxRateLimit::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        seeds = [OutboxRateLimit::SEED_PREFIX],
+        bump,
+    )]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RateLimitParams {
+    /// See [`RateLimitState::limit`].
+    pub limit: u64,
+    /// See [`RateLimitState::capacity_at_last_tx`].
+    pub capacity_at_last_tx: u64,
+    /// See [`RateLimitState::last_tx_timestamp`].
+    pub last_tx_timestamp: i64,
+    /// The capacity right now, i.e. [`Self::capacity_at_last_tx`] plus what
+    /// has been refilled since.
+    pub capacity: u64,
+    /// How many seconds it takes to refill from empty to [`Self::limit`].
+    pub refill_duration: i64,
+}
+
+impl RateLimitParams {
+    fn new(rate_limit: &RateLimitState, now: i64) -> Self {
+        Self {
+            limit: rate_limit.limit,
+            capacity_at_last_tx: rate_limit.capacity_at_last_tx,
+            last_tx_timestamp: rate_limit.last_tx_timestamp,
+            capacity: rate_limit.capacity_at(now),
+            refill_duration: RateLimitState::RATE_LIMIT_DURATION,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RateLimits {
+    /// The inbound rate limit of the given chain.
+    pub inbound: RateLimitParams,
+    /// The outbound rate limit, which is shared by all chains.
+    pub outbound: RateLimitParams,
+}
+
+/// Reports the inbound rate limit of `chain_id` and the outbound rate limit,
+/// so that monitoring doesn't have to deserialize [`RateLimitState`].
+pub fn get_rate_limit_params(
+    ctx: Context<GetRateLimitParams>,
+    _chain_id: u16,
+) -> Result<RateLimits> {
+    let now = current_time()?;
+
+    Ok(RateLimits {
+        inbound: RateLimitParams::new(&ctx.accounts.inbox_rate_limit.rate_limit, now),
+        outbound: RateLimitParams::new(&ctx.accounts.outbox_rate_limit.rate_limit, now),
+    })
+}
+
+#[derive(Accounts)]
+pub struct GetOutboundCapacity<'info> {
+    #[account(
+        seeds = [OutboxRateLimit::SEED_PREFIX],
+        bump,
+    )]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+}
+
+/// Reports the current outbound capacity, i.e. the largest transfer that can
+/// be sent right now without being queued.
+pub fn get_outbound_capacity(ctx: Context<GetOutboundCapacity>) -> Result<u64> {
+    Ok(ctx
+        .accounts
+        .outbox_rate_limit
+        .rate_limit
+        .capacity_at(current_time()?))
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct GetInboundCapacity<'info> {
+    #[account(
+        seeds = [InboxRateLimit::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+}
+
+/// Reports the current inbound capacity of `chain_id`, i.e. the largest
+/// transfer from it that can be released right now without being queued.
+pub fn get_inbound_capacity(ctx: Context<GetInboundCapacity>, _chain_id: u16) -> Result<u64> {
+    Ok(ctx
+        .accounts
+        .inbox_rate_limit
+        .rate_limit
+        .capacity_at(current_time()?))
+}
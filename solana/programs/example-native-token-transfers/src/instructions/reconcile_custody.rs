@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+
+use crate::{config::Config, custody_ledger::CustodyLedger};
+
+#[derive(Accounts)]
+pub struct ReconcileCustody<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        address = config.custody,
+    )]
+    pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        seeds = [CustodyLedger::SEED_PREFIX],
+        bump = custody_ledger.bump,
+    )]
+    pub custody_ledger: Account<'info, CustodyLedger>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CustodyReconciliation {
+    /// The token balance of the custody account.
+    pub custody_balance: u64,
+    /// The amount the program believes is locked in custody, as tracked in
+    /// [`CustodyLedger`].
+    pub locked_amount: u64,
+    /// Whether the two agree. A mismatch means tokens were transferred into
+    /// (or out of) custody outside of the program's transfers, or a bug.
+    pub matches: bool,
+}
+
+/// Reports the custody balance against the amount tracked as locked, so that
+/// drift between the two can be detected.
+pub fn reconcile_custody(ctx: Context<ReconcileCustody>) -> Result<CustodyReconciliation> {
+    let custody_balance = ctx.accounts.custody.amount;
+    let locked_amount = ctx.accounts.custody_ledger.locked_amount;
+
+    Ok(CustodyReconciliation {
+        custody_balance,
+        locked_amount,
+        matches: custody_balance == locked_amount,
+    })
+}
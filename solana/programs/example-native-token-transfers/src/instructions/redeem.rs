@@ -1,19 +1,19 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface;
 use ntt_messages::{ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage};
 
 use crate::{
-    bitmap::Bitmap,
+    clock::current_time,
     config::*,
     error::NTTError,
     messages::ValidatedTransceiverMessage,
     peer::NttManagerPeer,
     queue::{
-        inbox::{InboxItem, InboxRateLimit, ReleaseStatus},
+        inbox::{InboxItem, InboxRateLimit},
         outbox::OutboxRateLimit,
         rate_limit::RateLimitResult,
     },
     registered_transceiver::*,
+    retry_after::retry_after,
     transfer::Payload,
 };
 
@@ -22,27 +22,35 @@ pub struct Redeem<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    // NOTE: this works when the contract is paused
     #[account(
         constraint = config.threshold > 0 @ NTTError::ZeroThreshold
     )]
-    pub config: Account<'info, Config>,
+    pub config: NotPausedInboundConfig<'info>,
 
     #[account(
+        mut,
         seeds = [NttManagerPeer::SEED_PREFIX, ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::from_chain(&transceiver_message)?.id.to_be_bytes().as_ref()],
         constraint = peer.address == ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.source_ntt_manager() @ NTTError::InvalidNttManagerPeer,
+        constraint = peer.enabled @ NTTError::RouteDisabled,
         bump = peer.bump,
     )]
     pub peer: Account<'info, NttManagerPeer>,
 
     #[account(
         // check that the message is targeted to this chain
-        constraint = ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.ntt_manager_payload().payload.to_chain == config.chain_id @ NTTError::InvalidChainId,
+        constraint = ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.ntt_manager_payload()?.payload.to_chain == config.chain_id @ NTTError::InvalidChainId,
         // check that we're the intended recipient
-        constraint = ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.recipient_ntt_manager() == crate::ID.to_bytes() @ NTTError::InvalidRecipientNttManager,
+        constraint = is_recipient_ntt_manager(ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.recipient_ntt_manager()) @ NTTError::InvalidRecipientNttManager,
         // NOTE: we don't replay protect VAAs. Instead, we replay protect
         // executing the messages themselves with the [`released`] flag.
-        owner = transceiver.transceiver_address
+        owner = transceiver.transceiver_address,
+        seeds = [
+            ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::SEED_PREFIX,
+            ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::from_chain(&transceiver_message)?.id.to_be_bytes().as_ref(),
+            ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.ntt_manager_payload()?.id.as_ref(),
+        ],
+        seeds::program = transceiver.transceiver_address,
+        bump = ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::bump(&transceiver_message, &transceiver.transceiver_address)?,
     )]
     /// CHECK: `transceiver_message` has to be manually deserialized as Anchor
     /// `Account<T>` and `owner` constraints are mutually-exclusive
@@ -53,20 +61,15 @@ pub struct Redeem<'info> {
     )]
     pub transceiver: Account<'info, RegisteredTransceiver>,
 
-    #[account(
-        constraint = mint.key() == config.mint
-    )]
-    pub mint: InterfaceAccount<'info, token_interface::Mint>,
-
     #[account(
         init_if_needed,
         payer = payer,
         space = 8 + InboxItem::INIT_SPACE,
         seeds = [
             InboxItem::SEED_PREFIX,
-            ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.ntt_manager_payload().keccak256(
-                ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::from_chain(&transceiver_message)?
-            ).as_ref(),
+            // the hash the transceiver stored on receive, see
+            // [`ValidatedTransceiverMessage::message_hash`]
+            ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message_hash(&transceiver_message)?.as_ref(),
         ],
         bump,
     )]
@@ -80,9 +83,12 @@ pub struct Redeem<'info> {
     /// default values.
     /// On subsequent calls, we want to modify the `InboxItem` by "voting" on it. Therefore the
     /// program should not fail which would occur when using the `init` constraint.
-    /// The [`InboxItem::init`] field is used to guard against malicious or accidental modification
-    /// InboxItem fields that should remain constant.
-    pub inbox_item: Account<'info, InboxItem>,
+    /// The account discriminator is only written once the first call exits, so its absence is
+    /// used to guard against malicious or accidental modification InboxItem fields that should
+    /// remain constant.
+    /// NOTE: inbox items in the legacy layout fail the `space` check of `init_if_needed`, and
+    /// have to be converted with [`crate::instructions::migrate_inbox_item`] first.
+    pub inbox_item: AccountLoader<'info, InboxItem>,
 
     #[account(
         mut,
@@ -100,11 +106,26 @@ pub struct Redeem<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Whether the message is addressed to this manager.
+/// If it isn't (e.g. it's meant for another NTT deployment on Solana), the
+/// intended manager is logged so that relayers can route the message there.
+fn is_recipient_ntt_manager(recipient_ntt_manager: [u8; 32]) -> bool {
+    if recipient_ntt_manager == crate::ID.to_bytes() {
+        return true;
+    }
+    msg!(
+        "Message is addressed to NTT manager {}",
+        Pubkey::from(recipient_ntt_manager)
+    );
+    false
+}
+
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct RedeemArgs {}
 
 pub fn redeem(ctx: Context<Redeem>, _args: RedeemArgs) -> Result<()> {
     let accs = ctx.accounts;
+    let now = current_time()?;
 
     let transceiver_message: ValidatedTransceiverMessage<NativeTokenTransfer<Payload>> =
         ValidatedTransceiverMessage::try_from(
@@ -114,6 +135,9 @@ pub fn redeem(ctx: Context<Redeem>, _args: RedeemArgs) -> Result<()> {
     let message: NttManagerMessage<NativeTokenTransfer<Payload>> =
         transceiver_message.message.ntt_manager_payload.clone();
 
+    accs.config
+        .check_unconsumed_payload(transceiver_message.unconsumed_payload_len.into())?;
+
     // Calculate the scaled amount based on the appropriate decimal encoding for the token.
     // Return an error if the resulting amount overflows.
     // Ideally this state should never be reached: the sender should avoid sending invalid
@@ -121,28 +145,35 @@ pub fn redeem(ctx: Context<Redeem>, _args: RedeemArgs) -> Result<()> {
     let amount = message
         .payload
         .amount
-        .untrim(accs.mint.decimals)
+        .untrim(accs.config.mint_decimals)
         .map_err(NTTError::from)?;
 
-    if !accs.inbox_item.init {
+    let is_new = accs
+        .inbox_item
+        .to_account_info()
+        .try_borrow_data()?
+        .starts_with(&[0; 8]);
+    let mut inbox_item = if is_new {
         let recipient_address =
             Pubkey::try_from(message.payload.to).map_err(|_| NTTError::InvalidRecipientAddress)?;
 
-        accs.inbox_item.set_inner(InboxItem {
-            init: true,
-            bump: ctx.bumps.inbox_item,
+        let mut inbox_item = accs.inbox_item.load_init()?;
+        *inbox_item = InboxItem::new(
+            ctx.bumps.inbox_item,
             amount,
             recipient_address,
-            release_status: ReleaseStatus::NotApproved,
-            votes: Bitmap::new(),
-        });
-    }
+            transceiver_message.from_chain.id,
+            transceiver_message.vaa_timestamp,
+        );
+        inbox_item
+    } else {
+        accs.inbox_item.load_mut()?
+    };
 
     // idempotent
-    accs.inbox_item.votes.set(accs.transceiver.id, true)?;
+    inbox_item.votes.set(accs.transceiver.id, true)?;
 
-    if accs
-        .inbox_item
+    if inbox_item
         .votes
         .count_enabled_votes(accs.config.enabled_transceivers)
         < accs.config.threshold
@@ -150,17 +181,31 @@ pub fn redeem(ctx: Context<Redeem>, _args: RedeemArgs) -> Result<()> {
         return Ok(());
     }
 
-    let release_timestamp = match accs.inbox_rate_limit.rate_limit.consume_or_delay(amount) {
+    let release_timestamp = match accs
+        .inbox_rate_limit
+        .rate_limit
+        .consume_or_delay_at(now, amount)
+    {
         RateLimitResult::Consumed(now) => {
             // When receiving a transfer, we refill the outbound rate limit with
             // the same amount (we call this "backflow")
             accs.outbox_rate_limit.rate_limit.refill(now, amount);
             now
         }
-        RateLimitResult::Delayed(release_timestamp) => release_timestamp,
+        RateLimitResult::Delayed(release_timestamp) => {
+            if !accs.peer.allow_inbound_queue {
+                // report when there will be enough capacity to redeem again
+                return Err(retry_after(
+                    release_timestamp,
+                    NTTError::TransferExceedsRateLimit,
+                ));
+            }
+            release_timestamp
+        }
     };
 
-    accs.inbox_item.release_after(release_timestamp)?;
+    inbox_item.release_after(release_timestamp)?;
+    accs.peer.start_inbound();
 
     Ok(())
 }
@@ -4,9 +4,16 @@ use ntt_messages::mode::Mode;
 use spl_token_2022::onchain;
 
 use crate::{
+    clock::current_timestamp,
+    confidential_transfer::accepts_non_confidential_credits,
     config::*,
+    custody_ledger::CustodyLedger,
     error::NTTError,
+    instructions::check_mint_authority,
+    peer::NttManagerPeer,
+    peer_stats::PeerStats,
     queue::inbox::{InboxItem, ReleaseStatus},
+    retry_after::retry_after,
     spl_multisig::SplMultisig,
 };
 
@@ -15,14 +22,24 @@ pub struct ReleaseInbound<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    pub config: NotPausedConfig<'info>,
+    pub config: NotPausedInboundConfig<'info>,
 
-    #[account(mut)]
-    pub inbox_item: Account<'info, InboxItem>,
+    #[account(
+        mut,
+        constraint = !InboxItem::is_closed(&inbox_item.to_account_info()) @ NTTError::TransferAlreadyRedeemed,
+        constraint = InboxItem::is_current_layout(&inbox_item.to_account_info()) @ NTTError::QueueItemNotMigrated,
+    )]
+    pub inbox_item: AccountLoader<'info, InboxItem>,
 
     #[account(
         mut,
-        associated_token::authority = inbox_item.recipient_address,
+        // NOTE: implied by the `associated_token::mint` check below, but this
+        // way a wrong mint surfaces as a specific error
+        constraint = recipient.mint == config.mint @ NTTError::RecipientMintMismatch,
+        constraint = !config.confidential_transfers
+            || accepts_non_confidential_credits(&recipient.to_account_info())?
+            @ NTTError::ConfidentialTransfersUnsupported,
+        associated_token::authority = inbox_item.load()?.recipient_address,
         associated_token::mint = mint,
         associated_token::token_program = token_program,
     )]
@@ -37,7 +54,7 @@ pub struct ReleaseInbound<'info> {
 
     #[account(
         mut,
-        address = config.mint,
+        address = config.mint @ NTTError::RecipientMintMismatch,
     )]
     /// CHECK: the mint address matches the config
     pub mint: InterfaceAccount<'info, token_interface::Mint>,
@@ -50,6 +67,83 @@ pub struct ReleaseInbound<'info> {
         address = config.custody
     )]
     pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            NttManagerPeer::SEED_PREFIX,
+            inbox_item.load()?.from_chain.to_be_bytes().as_ref(),
+        ],
+        bump = peer.bump,
+    )]
+    /// The peer the transfer came from, whose
+    /// [`NttManagerPeer::inbound_in_flight`] is decremented. Only optional
+    /// for inbox items that predate [`InboxItem::from_chain`].
+    pub peer: Option<Account<'info, NttManagerPeer>>,
+
+    #[account(
+        mut,
+        constraint = peer.as_ref().and_then(|peer| peer.fallback_recipient)
+            == Some(fallback_recipient.key()) @ NTTError::InvalidFallbackRecipient,
+    )]
+    /// The peer's [`NttManagerPeer::fallback_recipient`]. The tokens are
+    /// delivered here instead if [`ReleaseInbound::recipient`] is frozen.
+    pub fallback_recipient: Option<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            PeerStats::SEED_PREFIX,
+            inbox_item.load()?.from_chain.to_be_bytes().as_ref(),
+        ],
+        bump = peer_stats.bump,
+    )]
+    /// When passed, the delivery latency of the transfer is recorded in it.
+    pub peer_stats: Option<Account<'info, PeerStats>>,
+}
+
+impl<'info> ReleaseInbound<'info> {
+    /// The token account to deliver the tokens to: the recipient, unless it is
+    /// frozen and a fallback recipient was passed.
+    fn destination(&self) -> AccountInfo<'info> {
+        match &self.fallback_recipient {
+            Some(fallback_recipient) if self.recipient.is_frozen() => {
+                msg!(
+                    "Recipient {} is frozen, delivering to fallback recipient {}",
+                    self.recipient.key(),
+                    fallback_recipient.key()
+                );
+                fallback_recipient.to_account_info()
+            }
+            _ => self.recipient.to_account_info(),
+        }
+    }
+
+    /// Records the release in [`ReleaseInbound::peer`]. Inbox items that
+    /// predate [`InboxItem::from_chain`] were never counted, so there's no
+    /// peer to record it in.
+    fn finish_inbound(&mut self) -> Result<()> {
+        if self.inbox_item.load()?.from_chain == 0 {
+            return Ok(());
+        }
+        self.peer
+            .as_mut()
+            .ok_or(ErrorCode::AccountNotEnoughKeys)?
+            .finish_inbound();
+        Ok(())
+    }
+
+    /// Records the time from the VAA timestamp to now in
+    /// [`ReleaseInbound::peer_stats`], if passed.
+    fn record_latency(&mut self) -> Result<()> {
+        if let Some(peer_stats) = &mut self.peer_stats {
+            let vaa_timestamp = self.inbox_item.load()?.vaa_timestamp;
+            peer_stats
+                .inbound_latency
+                .record(vaa_timestamp, current_timestamp());
+        }
+        Ok(())
+    }
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
@@ -78,6 +172,8 @@ pub struct ReleaseInboundMint<'info> {
 /// When `revert_when_not_ready` is true, the transaction will revert if the
 /// release timestamp has not been reached. When `revert_when_not_ready` is false, the
 /// transaction succeeds, but the minting is not performed.
+/// If the recipient's token account is frozen, the tokens are delivered to the
+/// peer's fallback recipient instead, when that is passed.
 /// Setting this flag to `false` is useful when bundling this instruction
 /// together with [`crate::instructions::redeem`] in a transaction, so that the minting
 /// is attempted optimistically.
@@ -87,15 +183,26 @@ pub fn release_inbound_mint<'info>(
     ctx: Context<'_, '_, '_, 'info, ReleaseInboundMint<'info>>,
     args: ReleaseInboundArgs,
 ) -> Result<()> {
-    let inbox_item = release_inbox_item(
-        &mut ctx.accounts.common.inbox_item,
-        args.revert_when_not_ready,
-    )?;
-    if inbox_item.is_none() {
+    let amount = release_inbox_item(&ctx.accounts.common.inbox_item, args.revert_when_not_ready)?;
+    if amount.is_none() {
         return Ok(());
     }
-    let inbox_item = inbox_item.unwrap();
-    assert!(inbox_item.release_status == ReleaseStatus::Released);
+    let amount = amount.unwrap();
+    ctx.accounts.common.finish_inbound()?;
+
+    // NOTE: the token program would reject the mint below anyway, but with an
+    // error that doesn't say which authority is wrong
+    require!(
+        check_mint_authority(
+            &ctx.accounts.common.mint,
+            &ctx.accounts.common.token_authority.key(),
+            ctx.accounts
+                .multisig_token_authority
+                .as_ref()
+                .map(|multisig| multisig.key()),
+        ),
+        NTTError::MintAuthorityRotatedAway
+    );
 
     // NOTE: minting tokens is a two-step process:
     // 1. Mint tokens to the custody account
@@ -126,7 +233,7 @@ pub fn release_inbound_mint<'info>(
             multisig_token_authority.to_account_info(),
             ctx.accounts.common.token_authority.to_account_info(),
             token_authority_sig,
-            inbox_item.amount,
+            amount,
         )?,
         None => mint_to_custody_from_token_authority(
             ctx.accounts.common.token_program.to_account_info(),
@@ -134,7 +241,7 @@ pub fn release_inbound_mint<'info>(
             ctx.accounts.common.custody.to_account_info(),
             ctx.accounts.common.token_authority.to_account_info(),
             token_authority_sig,
-            inbox_item.amount,
+            amount,
         )?,
     };
 
@@ -143,17 +250,17 @@ pub fn release_inbound_mint<'info>(
         &ctx.accounts.common.token_program.key(),
         ctx.accounts.common.custody.to_account_info(),
         ctx.accounts.common.mint.to_account_info(),
-        ctx.accounts.common.recipient.to_account_info(),
+        ctx.accounts.common.destination(),
         ctx.accounts.common.token_authority.to_account_info(),
         ctx.remaining_accounts,
-        inbox_item.amount,
+        amount,
         ctx.accounts.common.mint.decimals,
         token_authority_sig,
     )?;
-    Ok(())
+    ctx.accounts.common.record_latency()
 }
 
-fn mint_to_custody_from_token_authority<'info>(
+pub(crate) fn mint_to_custody_from_token_authority<'info>(
     token_program: AccountInfo<'info>,
     mint: AccountInfo<'info>,
     custody: AccountInfo<'info>,
@@ -176,7 +283,7 @@ fn mint_to_custody_from_token_authority<'info>(
     Ok(())
 }
 
-fn mint_to_custody_from_multisig_token_authority<'info>(
+pub(crate) fn mint_to_custody_from_multisig_token_authority<'info>(
     token_program: AccountInfo<'info>,
     mint: AccountInfo<'info>,
     custody: AccountInfo<'info>,
@@ -208,12 +315,23 @@ pub struct ReleaseInboundUnlock<'info> {
         constraint = common.config.mode == Mode::Locking @ NTTError::InvalidMode,
     )]
     common: ReleaseInbound<'info>,
+
+    #[account(
+        mut,
+        seeds = [CustodyLedger::SEED_PREFIX],
+        bump = custody_ledger.bump,
+    )]
+    /// Required unless the deployment doesn't track custody, see
+    /// [`Config::tracks_custody`].
+    pub custody_ledger: Option<Account<'info, CustodyLedger>>,
 }
 
 /// Release an inbound transfer and unlock the tokens to the recipient.
 /// When `revert_when_not_ready` is true, the transaction will revert if the
 /// release timestamp has not been reached. When `revert_when_not_ready` is false, the
 /// transaction succeeds, but the unlocking is not performed.
+/// If the recipient's token account is frozen, the tokens are delivered to the
+/// peer's fallback recipient instead, when that is passed.
 /// Setting this flag to `false` is useful when bundling this instruction
 /// together with [`crate::instructions::redeem`], so that the unlocking
 /// is attempted optimistically.
@@ -223,43 +341,51 @@ pub fn release_inbound_unlock<'info>(
     ctx: Context<'_, '_, '_, 'info, ReleaseInboundUnlock<'info>>,
     args: ReleaseInboundArgs,
 ) -> Result<()> {
-    let inbox_item = release_inbox_item(
-        &mut ctx.accounts.common.inbox_item,
-        args.revert_when_not_ready,
-    )?;
-    if inbox_item.is_none() {
+    let amount = release_inbox_item(&ctx.accounts.common.inbox_item, args.revert_when_not_ready)?;
+    if amount.is_none() {
         return Ok(());
     }
-    let inbox_item = inbox_item.unwrap();
-    assert!(inbox_item.release_status == ReleaseStatus::Released);
+    let amount = amount.unwrap();
+    ctx.accounts.common.finish_inbound()?;
 
     onchain::invoke_transfer_checked(
         &ctx.accounts.common.token_program.key(),
         ctx.accounts.common.custody.to_account_info(),
         ctx.accounts.common.mint.to_account_info(),
-        ctx.accounts.common.recipient.to_account_info(),
+        ctx.accounts.common.destination(),
         ctx.accounts.common.token_authority.to_account_info(),
         ctx.remaining_accounts,
-        inbox_item.amount,
+        amount,
         ctx.accounts.common.mint.decimals,
         &[&[
             crate::TOKEN_AUTHORITY_SEED,
             &[ctx.bumps.common.token_authority],
         ]],
     )?;
-    Ok(())
+    CustodyLedger::update(
+        &ctx.accounts.common.config,
+        ctx.accounts.custody_ledger.as_deref_mut(),
+        |custody_ledger| custody_ledger.unlock(amount),
+    )?;
+    ctx.accounts.common.record_latency()
 }
 
+/// Releases the inbox item, returning the amount to transfer to the recipient,
+/// or `None` if the transfer can't be released yet.
 fn release_inbox_item(
-    inbox_item: &mut InboxItem,
+    inbox_item: &AccountLoader<InboxItem>,
     revert_when_not_ready: bool,
-) -> Result<Option<&mut InboxItem>> {
+) -> Result<Option<u64>> {
+    let mut inbox_item = inbox_item.load_mut()?;
     if inbox_item.try_release()? {
-        Ok(Some(inbox_item))
+        assert!(inbox_item.release_status() == ReleaseStatus::Released);
+        Ok(Some(inbox_item.amount))
     } else if revert_when_not_ready {
-        match inbox_item.release_status {
+        match inbox_item.release_status() {
             ReleaseStatus::NotApproved => Err(NTTError::TransferNotApproved.into()),
-            ReleaseStatus::ReleaseAfter(_) => Err(NTTError::CantReleaseYet.into()),
+            ReleaseStatus::ReleaseAfter(release_timestamp) => {
+                Err(retry_after(release_timestamp, NTTError::CantReleaseYet))
+            }
             // Unreachable: if released, [`InboxItem::try_release`] will return an Error immediately
             // rather than Ok(bool).
             ReleaseStatus::Released => Err(NTTError::TransferAlreadyRedeemed.into()),
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface};
+
+use crate::config::Config;
+
+#[derive(Accounts)]
+pub struct RepairCustody<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        has_one = mint,
+        has_one = token_program,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint ensures that this is the correct address
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = token_authority,
+        associated_token::token_program = token_program,
+    )]
+    /// The custody account is the associated token account of the token
+    /// authority, so re-deriving it from the configured mint and token program
+    /// yields exactly [`Config::custody`]. `init_if_needed` makes repairing an
+    /// existing account a no-op.
+    pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Re-creates the custody account if it has been closed.
+/// This instruction is permissionless, as the custody account can only ever be
+/// created at its canonical address, owned by the token authority.
+pub fn repair_custody(_ctx: Context<RepairCustody>) -> Result<()> {
+    Ok(())
+}
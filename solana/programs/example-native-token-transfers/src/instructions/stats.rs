@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    peer::NttManagerPeer,
+    peer_stats::{LatencyStats, PeerStats},
+};
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct InitPeerStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PeerStats::INIT_SPACE,
+        seeds = [PeerStats::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump,
+    )]
+    pub peer_stats: Account<'info, PeerStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the [`PeerStats`] of the peer on `chain_id`. Permissionless, as the
+/// stats are only recorded when the account is passed to the release
+/// instructions, which is up to whoever releases.
+pub fn init_peer_stats(ctx: Context<InitPeerStats>, _chain_id: u16) -> Result<()> {
+    ctx.accounts.peer_stats.set_inner(PeerStats {
+        bump: ctx.bumps.peer_stats,
+        inbound_latency: LatencyStats::default(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct GetStats<'info> {
+    #[account(
+        seeds = [PeerStats::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump = peer_stats.bump,
+    )]
+    pub peer_stats: Account<'info, PeerStats>,
+}
+
+/// Reports the inbound delivery latency from `chain_id`, so that monitoring
+/// can pick up relayer degradation without deserializing [`PeerStats`].
+pub fn get_stats(ctx: Context<GetStats>, _chain_id: u16) -> Result<LatencyStats> {
+    Ok(ctx.accounts.peer_stats.inbound_latency)
+}
@@ -9,25 +9,37 @@
 //! amount of duplication between `TransferBurn` and `TransferLock` (exactly the
 //! accounts whose constraints refer to the instruction data).
 //!
+//! The accounts added since the initial release are optional, and come after
+//! the original ones, so that existing clients' account lists keep working:
+//! trailing optional accounts can be left out altogether (see the
+//! `allow-missing-optionals` feature of Anchor). Clients that pass remaining
+//! accounts (e.g. for a transfer hook) have to fill in the optional ones first,
+//! with the program id for any that are left out.
+//!
 //! See the documentation of [`crate::SESSION_AUTHORITY_SEED`] for an
 //! explanation of the approval flow.
 
 #![allow(clippy::too_many_arguments)]
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::clock::UnixTimestamp, system_program};
 use anchor_spl::token_interface;
 use ntt_messages::{chain_id::ChainId, mode::Mode, trimmed_amount::TrimmedAmount};
 use spl_token_2022::onchain;
+use std::ops::DerefMut;
 
 use crate::{
-    bitmap::Bitmap,
+    clock::current_time,
     config::*,
+    custody_ledger::CustodyLedger,
     error::NTTError,
+    instructions::check_mint_authority,
     peer::NttManagerPeer,
     queue::{
         inbox::InboxRateLimit,
-        outbox::{OutboxItem, OutboxRateLimit},
-        rate_limit::RateLimitResult,
+        outbox::{ChainOutboxRateLimit, OutboxItem, OutboxRateLimit, SenderRateLimit},
+        rate_limit::{RateLimitResult, RateLimitState},
     },
+    retry_after::retry_after,
+    spl_multisig::SplMultisig,
 };
 
 // this will burn the funds and create an account that either allows sending the
@@ -40,18 +52,22 @@ pub struct Transfer<'info> {
     // Ensure that there exists at least one enabled transceiver
     #[account(
         constraint = !config.enabled_transceivers.is_empty() @ NTTError::NoRegisteredTransceivers,
+        constraint = config.decommission_timestamp.is_none() @ NTTError::DeploymentDecommissioning,
     )]
-    pub config: NotPausedConfig<'info>,
+    pub config: NotPausedOutboundConfig<'info>,
 
     #[account(
         mut,
-        address = config.mint,
+        address = config.mint @ NTTError::SourceMintMismatch,
     )]
     /// CHECK: the mint address matches the config
     pub mint: InterfaceAccount<'info, token_interface::Mint>,
 
     #[account(
         mut,
+        // NOTE: implied by the `token::mint` check below, but this way a
+        // wrong mint surfaces as a specific error
+        constraint = from.mint == config.mint @ NTTError::SourceMintMismatch,
         token::mint = mint,
     )]
     /// CHECK: the spl token program will check that the session_authority
@@ -65,10 +81,18 @@ pub struct Transfer<'info> {
         payer = payer,
         space = 8 + OutboxItem::INIT_SPACE,
     )]
-    pub outbox_item: Account<'info, OutboxItem>,
+    pub outbox_item: AccountLoader<'info, OutboxItem>,
 
-    #[account(mut)]
-    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+    #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX],
+        bump,
+        constraint = OutboxRateLimit::is_initialized(&outbox_rate_limit)? @ NTTError::RateLimitNotInitialized,
+    )]
+    /// CHECK: deserialized in [`insert_into_outbox`]. Not an `Account`, so that
+    /// a missing rate limit fails with a specific error rather than Anchor's
+    /// generic one.
+    pub outbox_rate_limit: UncheckedAccount<'info>,
 
     #[account(
         mut,
@@ -90,20 +114,63 @@ pub struct TransferArgs {
     pub should_queue: bool,
 }
 
+/// Optional conditions on a transfer, taken by [`transfer_burn_with_options`]
+/// and [`transfer_lock_with_options`]. They're not part of [`TransferArgs`], so
+/// that its encoding stays the same for existing clients.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct TransferOptions {
+    /// The least the recipient must receive on the destination chain, after
+    /// the peer's [`NttManagerPeer::destination_fee_bps`] is deducted. This is
+    /// in the trimmed decimals of the transfer (see
+    /// [`crate::instructions::get_peer_decimals`]).
+    pub min_amount_out: Option<u64>,
+    /// The time after which the transfer is discarded rather than released
+    /// (see [`OutboxItem::expiry_timestamp`]).
+    pub expiry_timestamp: Option<i64>,
+}
+
 impl TransferArgs {
     pub fn keccak256(&self) -> solana_program::keccak::Hash {
+        self.keccak256_with_options(&TransferOptions::default())
+    }
+
+    /// The hash the session authority of a transfer with `options` is derived
+    /// from. Options that aren't set are left out, so that it's the same as
+    /// [`Self::keccak256`] without any.
+    pub fn keccak256_with_options(
+        &self,
+        options: &TransferOptions,
+    ) -> solana_program::keccak::Hash {
         let TransferArgs {
             amount,
             recipient_chain,
             recipient_address,
             should_queue,
         } = self;
-        solana_program::keccak::hashv(&[
-            amount.to_be_bytes().as_ref(),
-            recipient_chain.id.to_be_bytes().as_ref(),
-            recipient_address,
-            &[u8::from(*should_queue)],
-        ])
+        let TransferOptions {
+            min_amount_out,
+            expiry_timestamp,
+        } = options;
+        let amount = amount.to_be_bytes();
+        let recipient_chain = recipient_chain.id.to_be_bytes();
+        let should_queue = [u8::from(*should_queue)];
+        let min_amount_out = min_amount_out.map(u64::to_be_bytes);
+        // NOTE: the expiry has to be hashed, or anyone holding the approval
+        // could attach one that lapses before the transfer can be released
+        let expiry_timestamp = expiry_timestamp.map(i64::to_be_bytes);
+        let mut preimage = vec![
+            amount.as_ref(),
+            recipient_chain.as_ref(),
+            recipient_address.as_ref(),
+            should_queue.as_ref(),
+        ];
+        if let Some(min_amount_out) = &min_amount_out {
+            preimage.push(min_amount_out.as_ref());
+        }
+        if let Some(expiry_timestamp) = &expiry_timestamp {
+            preimage.push(expiry_timestamp.as_ref());
+        }
+        solana_program::keccak::hashv(&preimage)
     }
 }
 
@@ -127,20 +194,15 @@ pub struct TransferBurn<'info> {
     pub inbox_rate_limit: Account<'info, InboxRateLimit>,
 
     #[account(
+        mut,
         seeds = [NttManagerPeer::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
         bump = peer.bump,
+        constraint = peer.enabled @ NTTError::RouteDisabled,
     )]
     pub peer: Account<'info, NttManagerPeer>,
 
-    #[account(
-        seeds = [
-            crate::SESSION_AUTHORITY_SEED,
-            common.from.owner.as_ref(),
-            args.keccak256().as_ref()
-        ],
-        bump,
-    )]
-    /// CHECK: The seeds constraint enforces that this is the correct account.
+    /// CHECK: derived from the transfer args (and options), which is checked
+    /// in the handler, see [`session_authority_bump`].
     /// See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow.
     pub session_authority: UncheckedAccount<'info>,
 
@@ -150,6 +212,44 @@ pub struct TransferBurn<'info> {
     )]
     /// CHECK: The seeds constraint enforces that this is the correct account.
     pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = multisig_token_authority.m == 1
+            && multisig_token_authority.signers.contains(&token_authority.key())
+            @ NTTError::InvalidMultisig,
+    )]
+    /// Required when the mint authority is a multisig of the token authority.
+    /// Burning doesn't need the mint authority, but tokens burned while it
+    /// has been rotated away could not be minted back when they return.
+    pub multisig_token_authority: Option<InterfaceAccount<'info, SplMultisig>>,
+
+    #[account(
+        mut,
+        constraint = common.config.dust_account == Some(dust_account.key()) @ NTTError::InvalidDustAccount,
+    )]
+    /// Required when dust collection is turned on (see [`Config::dust_account`]).
+    pub dust_account: Option<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [SenderRateLimit::SEED_PREFIX, common.from.owner.as_ref()],
+        bump,
+    )]
+    /// CHECK: only required once senders are limited (see
+    /// [`Config::limits_senders`]), in which case it's created on the sender's
+    /// first transfer. See [`insert_into_outbox`].
+    pub sender_rate_limit: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [ChainOutboxRateLimit::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump,
+    )]
+    /// CHECK: deserialized in [`insert_into_outbox`] when it's used. Only
+    /// required once per-chain limits are turned on (see
+    /// [`Config::per_chain_outbound_limits`]), and it only exists for chains
+    /// that have been given their own limit.
+    pub chain_outbox_rate_limit: Option<UncheckedAccount<'info>>,
 }
 
 /// Burns tokens and issues a corresponding notification to the outbox of the
@@ -157,15 +257,49 @@ pub struct TransferBurn<'info> {
 pub fn transfer_burn<'info>(
     ctx: Context<'_, '_, '_, 'info, TransferBurn<'info>>,
     args: TransferArgs,
+) -> Result<()> {
+    transfer_burn_with_options(ctx, args, TransferOptions::default())
+}
+
+/// [`transfer_burn`], with the conditions in `options`.
+pub fn transfer_burn_with_options<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferBurn<'info>>,
+    args: TransferArgs,
+    options: TransferOptions,
 ) -> Result<()> {
     let accs = ctx.accounts;
 
+    let transfer_hash = args.keccak256_with_options(&options);
+    let session_authority_bump = session_authority_bump(
+        &accs.session_authority,
+        &accs.common.from.owner,
+        &transfer_hash,
+    )?;
+
     let TransferArgs {
         mut amount,
         recipient_chain,
         recipient_address,
         should_queue,
     } = args;
+    let TransferOptions {
+        min_amount_out,
+        expiry_timestamp,
+    } = options;
+
+    let now = current_time()?;
+    check_expiry_timestamp(now, expiry_timestamp)?;
+
+    require!(
+        check_mint_authority(
+            &accs.common.mint,
+            &accs.token_authority.key(),
+            accs.multisig_token_authority
+                .as_ref()
+                .map(|multisig| multisig.key()),
+        ),
+        NTTError::MintAuthorityRotatedAway
+    );
 
     // TODO: should we revert if we have dust?
     let trimmed_amount = TrimmedAmount::remove_dust(
@@ -175,6 +309,22 @@ pub fn transfer_burn<'info>(
     )
     .map_err(NTTError::from)?;
 
+    check_min_amount_out(&accs.peer, trimmed_amount, min_amount_out)?;
+
+    collect_dust(
+        &accs.common,
+        accs.dust_account.as_ref(),
+        &accs.session_authority,
+        ctx.remaining_accounts,
+        args.amount - amount,
+        &[&[
+            crate::SESSION_AUTHORITY_SEED,
+            accs.common.from.owner.as_ref(),
+            transfer_hash.as_ref(),
+            &[session_authority_bump],
+        ]],
+    )?;
+
     let before = accs.common.custody.amount;
 
     // NOTE: burning tokens is a two-step process:
@@ -205,8 +355,8 @@ pub fn transfer_burn<'info>(
         &[&[
             crate::SESSION_AUTHORITY_SEED,
             accs.common.from.owner.as_ref(),
-            args.keccak256().as_ref(),
-            &[ctx.bumps.session_authority],
+            transfer_hash.as_ref(),
+            &[session_authority_bump],
         ]],
     )?;
 
@@ -241,17 +391,42 @@ pub fn transfer_burn<'info>(
         return Err(NTTError::BadAmountAfterBurn.into());
     }
 
+    accs.peer.start_outbound()?;
     let recipient_ntt_manager = accs.peer.address;
 
     insert_into_outbox(
         &mut accs.common,
+        accs.sender_rate_limit
+            .as_ref()
+            .zip(ctx.bumps.sender_rate_limit),
         &mut accs.inbox_rate_limit,
+        accs.chain_outbox_rate_limit.as_deref(),
+        now,
         amount,
         trimmed_amount,
         recipient_chain,
         recipient_ntt_manager,
         recipient_address,
-        should_queue,
+        // queueing can be disabled per peer
+        should_queue && accs.peer.allow_queue,
+        expiry_timestamp,
+    )
+}
+
+/// [`transfer_burn`], for transfers that should be discarded rather than
+/// released once `expiry_timestamp` has passed.
+pub fn transfer_burn_with_expiry<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferBurn<'info>>,
+    args: TransferArgs,
+    expiry_timestamp: i64,
+) -> Result<()> {
+    transfer_burn_with_options(
+        ctx,
+        args,
+        TransferOptions {
+            expiry_timestamp: Some(expiry_timestamp),
+            ..TransferOptions::default()
+        },
     )
 }
 
@@ -275,22 +450,54 @@ pub struct TransferLock<'info> {
     pub inbox_rate_limit: Account<'info, InboxRateLimit>,
 
     #[account(
+        mut,
         seeds = [NttManagerPeer::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
         bump = peer.bump,
+        constraint = peer.enabled @ NTTError::RouteDisabled,
     )]
     pub peer: Account<'info, NttManagerPeer>,
 
+    /// CHECK: derived from the transfer args (and options), which is checked
+    /// in the handler, see [`session_authority_bump`].
+    /// See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow.
+    pub session_authority: UncheckedAccount<'info>,
+
     #[account(
-        seeds = [
-            crate::SESSION_AUTHORITY_SEED,
-            common.from.owner.as_ref(),
-            args.keccak256().as_ref()
-        ],
+        mut,
+        constraint = common.config.dust_account == Some(dust_account.key()) @ NTTError::InvalidDustAccount,
+    )]
+    /// Required when dust collection is turned on (see [`Config::dust_account`]).
+    pub dust_account: Option<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [SenderRateLimit::SEED_PREFIX, common.from.owner.as_ref()],
         bump,
     )]
-    /// CHECK: The seeds constraint enforces that this is the correct account
-    /// See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow.
-    pub session_authority: UncheckedAccount<'info>,
+    /// CHECK: only required once senders are limited (see
+    /// [`Config::limits_senders`]), in which case it's created on the sender's
+    /// first transfer. See [`insert_into_outbox`].
+    pub sender_rate_limit: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [ChainOutboxRateLimit::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump,
+    )]
+    /// CHECK: deserialized in [`insert_into_outbox`] when it's used. Only
+    /// required once per-chain limits are turned on (see
+    /// [`Config::per_chain_outbound_limits`]), and it only exists for chains
+    /// that have been given their own limit.
+    pub chain_outbox_rate_limit: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [CustodyLedger::SEED_PREFIX],
+        bump = custody_ledger.bump,
+    )]
+    /// Required unless the deployment doesn't track custody, see
+    /// [`Config::tracks_custody`].
+    pub custody_ledger: Option<Account<'info, CustodyLedger>>,
 }
 
 /// Locks tokens and issues a corresponding notification to the outbox of the
@@ -299,15 +506,38 @@ pub struct TransferLock<'info> {
 pub fn transfer_lock<'info>(
     ctx: Context<'_, '_, '_, 'info, TransferLock<'info>>,
     args: TransferArgs,
+) -> Result<()> {
+    transfer_lock_with_options(ctx, args, TransferOptions::default())
+}
+
+/// [`transfer_lock`], with the conditions in `options`.
+pub fn transfer_lock_with_options<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferLock<'info>>,
+    args: TransferArgs,
+    options: TransferOptions,
 ) -> Result<()> {
     let accs = ctx.accounts;
 
+    let transfer_hash = args.keccak256_with_options(&options);
+    let session_authority_bump = session_authority_bump(
+        &accs.session_authority,
+        &accs.common.from.owner,
+        &transfer_hash,
+    )?;
+
     let TransferArgs {
         mut amount,
         recipient_chain,
         recipient_address,
         should_queue,
     } = args;
+    let TransferOptions {
+        min_amount_out,
+        expiry_timestamp,
+    } = options;
+
+    let now = current_time()?;
+    check_expiry_timestamp(now, expiry_timestamp)?;
 
     // TODO: should we revert if we have dust?
     let trimmed_amount = TrimmedAmount::remove_dust(
@@ -317,6 +547,22 @@ pub fn transfer_lock<'info>(
     )
     .map_err(NTTError::from)?;
 
+    check_min_amount_out(&accs.peer, trimmed_amount, min_amount_out)?;
+
+    collect_dust(
+        &accs.common,
+        accs.dust_account.as_ref(),
+        &accs.session_authority,
+        ctx.remaining_accounts,
+        args.amount - amount,
+        &[&[
+            crate::SESSION_AUTHORITY_SEED,
+            accs.common.from.owner.as_ref(),
+            transfer_hash.as_ref(),
+            &[session_authority_bump],
+        ]],
+    )?;
+
     let before = accs.common.custody.amount;
 
     onchain::invoke_transfer_checked(
@@ -331,8 +577,8 @@ pub fn transfer_lock<'info>(
         &[&[
             crate::SESSION_AUTHORITY_SEED,
             accs.common.from.owner.as_ref(),
-            args.keccak256().as_ref(),
-            &[ctx.bumps.session_authority],
+            transfer_hash.as_ref(),
+            &[session_authority_bump],
         ]],
     )?;
 
@@ -350,56 +596,354 @@ pub fn transfer_lock<'info>(
     if after != before + amount {
         return Err(NTTError::BadAmountAfterTransfer.into());
     }
+    CustodyLedger::update(
+        &accs.common.config,
+        accs.custody_ledger.as_deref_mut(),
+        |custody_ledger| custody_ledger.lock(amount),
+    )?;
 
+    accs.peer.start_outbound()?;
     let recipient_ntt_manager = accs.peer.address;
 
     insert_into_outbox(
         &mut accs.common,
+        accs.sender_rate_limit
+            .as_ref()
+            .zip(ctx.bumps.sender_rate_limit),
         &mut accs.inbox_rate_limit,
+        accs.chain_outbox_rate_limit.as_deref(),
+        now,
         amount,
         trimmed_amount,
         recipient_chain,
         recipient_ntt_manager,
         recipient_address,
-        should_queue,
+        // queueing can be disabled per peer
+        should_queue && accs.peer.allow_queue,
+        expiry_timestamp,
+    )
+}
+
+/// [`transfer_lock`], for transfers that should be discarded rather than
+/// released once `expiry_timestamp` has passed.
+pub fn transfer_lock_with_expiry<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferLock<'info>>,
+    args: TransferArgs,
+    expiry_timestamp: i64,
+) -> Result<()> {
+    transfer_lock_with_options(
+        ctx,
+        args,
+        TransferOptions {
+            expiry_timestamp: Some(expiry_timestamp),
+            ..TransferOptions::default()
+        },
     )
 }
 
-fn insert_into_outbox(
-    common: &mut Transfer<'_>,
+/// Checks that `session_authority` is the one derived from the sender and
+/// `transfer_hash`, i.e. the one the sender approved for this transfer (see
+/// [`crate::SESSION_AUTHORITY_SEED`]), returning its bump.
+///
+/// NOTE: this can't be a seeds constraint, as the options aren't part of the
+/// instruction data of [`transfer_burn`] and [`transfer_lock`].
+fn session_authority_bump(
+    session_authority: &AccountInfo,
+    sender: &Pubkey,
+    transfer_hash: &solana_program::keccak::Hash,
+) -> Result<u8> {
+    let (expected, bump) = Pubkey::find_program_address(
+        &[
+            crate::SESSION_AUTHORITY_SEED,
+            sender.as_ref(),
+            transfer_hash.as_ref(),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        session_authority.key(),
+        expected,
+        ErrorCode::ConstraintSeeds
+    );
+    Ok(bump)
+}
+
+/// Checks that the expiry, if any, is still ahead. A transfer that is expired
+/// from the start could never be released.
+fn check_expiry_timestamp(now: UnixTimestamp, expiry_timestamp: Option<i64>) -> Result<()> {
+    match expiry_timestamp {
+        Some(expiry_timestamp) if expiry_timestamp <= now => {
+            msg!(
+                "Expiry timestamp {} is not after the current time {}",
+                expiry_timestamp,
+                now
+            );
+            Err(NTTError::InvalidExpiryTimestamp.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks that the recipient is expected to receive at least `min_amount_out`
+/// after the peer deducts its fee. Nothing is deducted here.
+fn check_min_amount_out(
+    peer: &NttManagerPeer,
+    trimmed_amount: TrimmedAmount,
+    min_amount_out: Option<u64>,
+) -> Result<()> {
+    let Some(min_amount_out) = min_amount_out else {
+        return Ok(());
+    };
+    let expected_out = peer.expected_amount_out(trimmed_amount);
+    if expected_out < min_amount_out {
+        msg!(
+            "Expected amount out {} is below the minimum {}",
+            expected_out,
+            min_amount_out
+        );
+        return Err(NTTError::SlippageExceeded.into());
+    }
+    Ok(())
+}
+
+/// Transfers the dust trimmed off a transfer into the configured dust account,
+/// if any. Otherwise the dust is left in the sender's account.
+fn collect_dust<'info>(
+    common: &Transfer<'info>,
+    dust_account: Option<&InterfaceAccount<'info, token_interface::TokenAccount>>,
+    session_authority: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    dust: u64,
+    session_authority_signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if common.config.dust_account.is_none() || dust == 0 {
+        return Ok(());
+    }
+
+    let dust_account = dust_account.ok_or(NTTError::InvalidDustAccount)?;
+
+    onchain::invoke_transfer_checked(
+        &common.token_program.key(),
+        common.from.to_account_info(),
+        common.mint.to_account_info(),
+        dust_account.to_account_info(),
+        session_authority.clone(),
+        remaining_accounts,
+        dust,
+        common.mint.decimals,
+        session_authority_signer_seeds,
+    )?;
+
+    Ok(())
+}
+
+fn insert_into_outbox<'info>(
+    common: &mut Transfer<'info>,
+    sender_rate_limit: Option<(&UncheckedAccount<'info>, u8)>,
     inbox_rate_limit: &mut InboxRateLimit,
+    chain_outbox_rate_limit: Option<&AccountInfo>,
+    now: UnixTimestamp,
     amount: u64,
     trimmed_amount: TrimmedAmount,
     recipient_chain: ChainId,
     recipient_ntt_manager: [u8; 32],
     recipient_address: [u8; 32],
     should_queue: bool,
+    expiry_timestamp: Option<i64>,
 ) -> Result<()> {
+    // transfers to a chain with its own limit consume from it instead of the
+    // global one, when per-chain limits are turned on
+    let use_chain_limit = uses_chain_outbound_limit(&common.config, chain_outbox_rate_limit)?;
+    let mut sender_rate_limit = if common.config.limits_senders() {
+        // leaving it out would let the sender get around its limit
+        let (sender_rate_limit, bump) = sender_rate_limit.ok_or(ErrorCode::AccountNotEnoughKeys)?;
+        if sender_rate_limit.data_is_empty() {
+            create_sender_rate_limit(common, sender_rate_limit, bump)?;
+        }
+        Some(Account::<SenderRateLimit>::try_from(sender_rate_limit)?)
+    } else {
+        None
+    };
+    let sender_limit = common.config.sender_limit;
+    let sender_rate_limit_state = sender_rate_limit
+        .as_mut()
+        .and_then(|sender_rate_limit| sender_rate_limit.current_rate_limit(sender_limit));
+    let sender_limited = sender_rate_limit_state.is_some();
+
     // consume the rate limit, or delay the transfer if it's outside the limit
-    let release_timestamp = match common.outbox_rate_limit.rate_limit.consume_or_delay(amount) {
+    let consume = |outbound_rate_limit: &mut RateLimitState| {
+        consume_outbound_rate_limits(outbound_rate_limit, sender_rate_limit_state, now, amount)
+    };
+    let (rate_limit_result, within_sender_limit) = match chain_outbox_rate_limit {
+        Some(chain_outbox_rate_limit) if use_chain_limit => {
+            update_rate_limit::<ChainOutboxRateLimit, _, _>(chain_outbox_rate_limit, consume)?
+        }
+        _ => update_rate_limit::<OutboxRateLimit, _, _>(&common.outbox_rate_limit, consume)?,
+    };
+    if let Some(sender_rate_limit) = &sender_rate_limit {
+        sender_rate_limit.exit(&crate::ID)?;
+    }
+    let (release_timestamp, inbound_backflow) = match rate_limit_result {
         RateLimitResult::Consumed(now) => {
             // When sending a transfer, we refill the inbound rate limit for
             // that chain the same amount (we call this "backflow")
+            let before = inbox_rate_limit.rate_limit.capacity_at(now);
             inbox_rate_limit.rate_limit.refill(now, amount);
-            now
+            // recorded, as the refill stops at the limit, so that cancelling
+            // the transfer takes back no more than it added
+            let inbound_backflow = inbox_rate_limit.rate_limit.capacity_at(now) - before;
+            (now, Some(inbound_backflow))
         }
         RateLimitResult::Delayed(release_timestamp) => {
             if !should_queue {
-                return Err(NTTError::TransferExceedsRateLimit.into());
+                // report how long the transfer would have been queued for
+                return Err(retry_after(
+                    release_timestamp,
+                    if within_sender_limit {
+                        NTTError::TransferExceedsRateLimit
+                    } else {
+                        NTTError::TransferExceedsSenderRateLimit
+                    },
+                ));
             }
-            release_timestamp
+            (release_timestamp, None)
         }
     };
 
-    common.outbox_item.set_inner(OutboxItem {
-        amount: trimmed_amount,
-        sender: common.from.owner,
+    let mut outbox_item = common.outbox_item.load_init()?;
+    *outbox_item = OutboxItem::new(
+        trimmed_amount,
+        common.from.owner,
         recipient_chain,
         recipient_ntt_manager,
         recipient_address,
         release_timestamp,
-        released: Bitmap::new(),
-    });
+    );
+    outbox_item.expiry_timestamp = expiry_timestamp.unwrap_or(0);
+    outbox_item.rent_payer = common.payer.key();
+    if let Some(inbound_backflow) = inbound_backflow {
+        outbox_item.record_consumed_rate_limits(use_chain_limit, sender_limited);
+        outbox_item.inbound_backflow = inbound_backflow;
+    }
 
     Ok(())
 }
+
+/// Creates the rate limit of the sender of `common` at `info`, zeroed out, so
+/// that it starts out at full capacity (see [`SenderRateLimit::sync_limit`]).
+///
+/// NOTE: anyone can send lamports to the address ahead of time, so the account
+/// is funded, allocated and assigned rather than created in one go.
+fn create_sender_rate_limit<'info>(
+    common: &Transfer<'info>,
+    info: &AccountInfo<'info>,
+    bump: u8,
+) -> Result<()> {
+    let space = 8 + SenderRateLimit::INIT_SPACE;
+    let system_program = common.system_program.to_account_info();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SenderRateLimit::SEED_PREFIX,
+        common.from.owner.as_ref(),
+        &[bump],
+    ]];
+
+    let lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(info.lamports());
+    if lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: common.payer.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: info.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program,
+            system_program::Assign {
+                account_to_assign: info.clone(),
+            },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )?;
+
+    SenderRateLimit {
+        bump,
+        limit_override: None,
+        rate_limit: RateLimitState::new(0),
+    }
+    .try_serialize(&mut &mut info.try_borrow_mut_data()?[..])
+}
+
+/// Whether transfers to the chain of `chain_outbox_rate_limit` consume from
+/// it rather than the global [`OutboxRateLimit`], which is only the case when
+/// [`Config::per_chain_outbound_limits`] is set and the chain has been given
+/// its own limit.
+pub(crate) fn uses_chain_outbound_limit(
+    config: &Config,
+    chain_outbox_rate_limit: Option<&AccountInfo>,
+) -> Result<bool> {
+    if !config.per_chain_outbound_limits {
+        return Ok(false);
+    }
+    // leaving it out would let the transfer get around the chain's limit
+    let chain_outbox_rate_limit = chain_outbox_rate_limit.ok_or(ErrorCode::AccountNotEnoughKeys)?;
+    ChainOutboxRateLimit::is_initialized(chain_outbox_rate_limit)
+}
+
+/// Consumes `amount` from the outbound rate limit, and from the sender's rate
+/// limit if the sender is limited, or delays the transfer if it's outside
+/// either. The sender's limit is checked first, so that a transfer it delays
+/// doesn't consume the outbound capacity.
+///
+/// Returns the result, along with whether the transfer was within the
+/// sender's limit.
+pub(crate) fn consume_outbound_rate_limits(
+    outbound_rate_limit: &mut RateLimitState,
+    sender_rate_limit: Option<&mut RateLimitState>,
+    now: UnixTimestamp,
+    amount: u64,
+) -> (RateLimitResult, bool) {
+    // the sender's delay is computed the same way as the outbound one, on a
+    // copy, as its capacity is only consumed once the outbound limit is too
+    if let Some(sender_rate_limit) = &sender_rate_limit {
+        let result = sender_rate_limit.clone().consume_or_delay_at(now, amount);
+        if let RateLimitResult::Delayed(_) = result {
+            return (result, false);
+        }
+    }
+
+    let result = outbound_rate_limit.consume_or_delay_at(now, amount);
+    if let (RateLimitResult::Consumed(now), Some(sender_rate_limit)) = (result, sender_rate_limit) {
+        sender_rate_limit.consume_or_delay_at(now, amount);
+    }
+    (result, true)
+}
+
+/// Runs `f` on the outbound rate limit in `info`, and writes it back.
+fn update_rate_limit<T, R, F>(info: &AccountInfo, f: F) -> Result<R>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Clone + DerefMut<Target = RateLimitState>,
+    F: FnOnce(&mut RateLimitState) -> R,
+{
+    let mut rate_limit = Account::<T>::try_from(info)?;
+    let result = f(&mut rate_limit);
+    rate_limit.exit(&crate::ID)?;
+    Ok(result)
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use ntt_messages::trimmed_amount::TrimmedAmount;
+use wormhole_anchor_sdk::wormhole;
+
+use crate::{bitmap::Bitmap, clock::current_timestamp, error::NTTError, queue::outbox::OutboxItem};
+
+/// Offset of the sequence in a core bridge `PostedMessage` account: the
+/// `msg` discriminator, followed by the version (1), consistency level (1),
+/// VAA time (4), VAA signature account (32), submission time (4) and nonce (4).
+const POSTED_MESSAGE_SEQUENCE_OFFSET: usize = 3 + 1 + 1 + 4 + 32 + 4 + 4;
+
+#[derive(Accounts)]
+pub struct GetTransferStatus<'info> {
+    #[account(
+        constraint = OutboxItem::is_current_layout(&outbox_item.to_account_info()) @ NTTError::QueueItemNotMigrated,
+    )]
+    pub outbox_item: AccountLoader<'info, OutboxItem>,
+
+    #[account(
+        seeds = [b"message", outbox_item.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: the message posted by the integrated Wormhole transceiver when
+    /// it released the transfer. It doesn't exist until then, which is
+    /// checked in [`get_transfer_status`].
+    pub wormhole_message: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TransferStatus {
+    pub amount: TrimmedAmount,
+    pub recipient_chain: u16,
+    pub recipient_address: [u8; 32],
+    pub release_timestamp: i64,
+    /// Whether the transfer is held back by the outbound rate limit, i.e.
+    /// whether the release timestamp is still in the future.
+    pub rate_limited: bool,
+    /// The transceivers that have released the transfer, by id.
+    pub released: Bitmap,
+    /// The sequence of the message the integrated Wormhole transceiver posted,
+    /// if it has released the transfer. Always `None` for standalone
+    /// transceivers, whose messages aren't tied to the outbox item.
+    pub wormhole_sequence: Option<u64>,
+}
+
+/// Reports where an outbound transfer is in its lifecycle, so that UIs can
+/// track it from creation to release in a single call.
+pub fn get_transfer_status(ctx: Context<GetTransferStatus>) -> Result<TransferStatus> {
+    let outbox_item = ctx.accounts.outbox_item.load()?;
+
+    Ok(TransferStatus {
+        amount: outbox_item.trimmed_amount(),
+        recipient_chain: outbox_item.recipient_chain,
+        recipient_address: outbox_item.recipient_address,
+        release_timestamp: outbox_item.release_timestamp,
+        rate_limited: outbox_item.release_timestamp > current_timestamp(),
+        released: outbox_item.released,
+        wormhole_sequence: posted_message_sequence(&ctx.accounts.wormhole_message)?,
+    })
+}
+
+fn posted_message_sequence(message: &AccountInfo) -> Result<Option<u64>> {
+    if *message.owner != wormhole::program::ID {
+        return Ok(None);
+    }
+
+    let data = message.try_borrow_data()?;
+    let Some(sequence) =
+        data.get(POSTED_MESSAGE_SEQUENCE_OFFSET..POSTED_MESSAGE_SEQUENCE_OFFSET + 8)
+    else {
+        return Ok(None);
+    };
+    Ok(Some(u64::from_le_bytes(sequence.try_into().unwrap())))
+}
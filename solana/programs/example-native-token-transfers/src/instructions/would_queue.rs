@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use ntt_messages::trimmed_amount::TrimmedAmount;
+
+use crate::{
+    config::Config,
+    error::NTTError,
+    peer::NttManagerPeer,
+    queue::{outbox::OutboxRateLimit, rate_limit::RateLimitState},
+};
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct WouldQueue<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    /// The peer the transfer would be sent to. Its decimals determine how much
+    /// of the amount is dust.
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        seeds = [OutboxRateLimit::SEED_PREFIX],
+        bump,
+    )]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct WouldQueueResult {
+    /// Whether a transfer of the given amount would exceed the current
+    /// outbound capacity, and thus be queued (or rejected, if queueing is not
+    /// requested).
+    pub would_queue: bool,
+    /// How many seconds the transfer would be queued for, if it would be.
+    pub release_delay: Option<i64>,
+}
+
+/// Reports whether a transfer of `amount` to `chain_id` would currently be
+/// queued by the outbound rate limit, without sending anything.
+/// The amount is checked after removing dust, the same way the transfer
+/// instructions do.
+pub fn would_queue(
+    ctx: Context<WouldQueue>,
+    _chain_id: u16,
+    mut amount: u64,
+) -> Result<WouldQueueResult> {
+    TrimmedAmount::remove_dust(
+        &mut amount,
+        ctx.accounts.config.mint_decimals,
+        ctx.accounts.peer.token_decimals,
+    )
+    .map_err(NTTError::from)?;
+
+    let would_queue = ctx.accounts.outbox_rate_limit.rate_limit.capacity() < amount;
+
+    Ok(WouldQueueResult {
+        would_queue,
+        release_delay: would_queue.then_some(RateLimitState::RATE_LIMIT_DURATION),
+    })
+}
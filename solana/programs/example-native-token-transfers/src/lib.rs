@@ -13,15 +13,22 @@ compile_error!("Cannot enable both solana-devnet and tilt-devnet features at the
 
 pub mod bitmap;
 pub mod clock;
+pub mod confidential_transfer;
 pub mod config;
+pub mod custody_ledger;
 pub mod error;
 pub mod instructions;
 pub mod messages;
 pub mod peer;
+pub mod peer_stats;
+pub mod pending_mint_migration;
 pub mod pending_token_authority;
 pub mod queue;
+pub mod receive_hook;
 pub mod registered_transceiver;
+pub mod retry_after;
 pub mod spl_multisig;
+pub mod token_metadata;
 pub mod transceivers;
 pub mod transfer;
 
@@ -76,6 +83,21 @@ pub mod example_native_token_transfers {
         instructions::initialize(ctx, args)
     }
 
+    pub fn initialize_with_peers<'info>(
+        ctx: Context<'_, '_, '_, 'info, Initialize<'info>>,
+        args: InitializeArgs,
+        peers: Vec<InitialPeer>,
+    ) -> Result<()> {
+        instructions::initialize_with_peers(ctx, args, peers)
+    }
+
+    pub fn preflight_initialize(
+        ctx: Context<PreflightInitialize>,
+        mode: ntt_messages::mode::Mode,
+    ) -> Result<u32> {
+        instructions::preflight_initialize(ctx, mode)
+    }
+
     pub fn initialize_lut(ctx: Context<InitializeLUT>, recent_slot: u64) -> Result<()> {
         instructions::initialize_lut(ctx, recent_slot)
     }
@@ -98,6 +120,54 @@ pub mod example_native_token_transfers {
         instructions::transfer_lock(ctx, args)
     }
 
+    pub fn transfer_burn_with_options<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferBurn<'info>>,
+        args: TransferArgs,
+        options: TransferOptions,
+    ) -> Result<()> {
+        instructions::transfer_burn_with_options(ctx, args, options)
+    }
+
+    pub fn transfer_lock_with_options<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferLock<'info>>,
+        args: TransferArgs,
+        options: TransferOptions,
+    ) -> Result<()> {
+        instructions::transfer_lock_with_options(ctx, args, options)
+    }
+
+    pub fn transfer_burn_with_expiry<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferBurn<'info>>,
+        args: TransferArgs,
+        expiry_timestamp: i64,
+    ) -> Result<()> {
+        instructions::transfer_burn_with_expiry(ctx, args, expiry_timestamp)
+    }
+
+    pub fn transfer_lock_with_expiry<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferLock<'info>>,
+        args: TransferArgs,
+        expiry_timestamp: i64,
+    ) -> Result<()> {
+        instructions::transfer_lock_with_expiry(ctx, args, expiry_timestamp)
+    }
+
+    pub fn cancel_outbound_transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelOutbound<'info>>,
+    ) -> Result<()> {
+        instructions::cancel_outbound_transfer(ctx)
+    }
+
+    pub fn close_expired_outbox_item<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseExpiredOutboxItem<'info>>,
+    ) -> Result<()> {
+        instructions::close_expired_outbox_item(ctx)
+    }
+
+    pub fn close_inbox_item(ctx: Context<CloseInboxItem>) -> Result<()> {
+        instructions::close_inbox_item(ctx)
+    }
+
     pub fn redeem(ctx: Context<Redeem>, args: RedeemArgs) -> Result<()> {
         instructions::redeem(ctx, args)
     }
@@ -116,6 +186,78 @@ pub mod example_native_token_transfers {
         instructions::release_inbound_unlock(ctx, args)
     }
 
+    pub fn repair_custody(ctx: Context<RepairCustody>) -> Result<()> {
+        instructions::repair_custody(ctx)
+    }
+
+    pub fn reconcile_custody(ctx: Context<ReconcileCustody>) -> Result<CustodyReconciliation> {
+        instructions::reconcile_custody(ctx)
+    }
+
+    pub fn get_enabled_transceivers<'info>(
+        ctx: Context<'_, '_, '_, 'info, GetEnabledTransceivers<'info>>,
+    ) -> Result<Vec<Pubkey>> {
+        instructions::get_enabled_transceivers(ctx)
+    }
+
+    pub fn get_inbox_item_status(ctx: Context<GetInboxItemStatus>) -> Result<InboxItemStatus> {
+        instructions::get_inbox_item_status(ctx)
+    }
+
+    pub fn get_peer_decimals(ctx: Context<GetPeerDecimals>, chain_id: u16) -> Result<PeerDecimals> {
+        instructions::get_peer_decimals(ctx, chain_id)
+    }
+
+    pub fn get_peer_index(ctx: Context<GetPeerIndex>) -> Result<Vec<u16>> {
+        instructions::get_peer_index(ctx)
+    }
+
+    pub fn get_configured_chains(ctx: Context<GetConfiguredChains>) -> Result<Vec<u16>> {
+        instructions::get_configured_chains(ctx)
+    }
+
+    pub fn get_rate_limit_params(
+        ctx: Context<GetRateLimitParams>,
+        chain_id: u16,
+    ) -> Result<RateLimits> {
+        instructions::get_rate_limit_params(ctx, chain_id)
+    }
+
+    pub fn get_outbound_capacity(ctx: Context<GetOutboundCapacity>) -> Result<u64> {
+        instructions::get_outbound_capacity(ctx)
+    }
+
+    pub fn get_inbound_capacity(ctx: Context<GetInboundCapacity>, chain_id: u16) -> Result<u64> {
+        instructions::get_inbound_capacity(ctx, chain_id)
+    }
+
+    pub fn init_peer_stats(ctx: Context<InitPeerStats>, chain_id: u16) -> Result<()> {
+        instructions::init_peer_stats(ctx, chain_id)
+    }
+
+    pub fn get_stats(ctx: Context<GetStats>, chain_id: u16) -> Result<peer_stats::LatencyStats> {
+        instructions::get_stats(ctx, chain_id)
+    }
+
+    pub fn get_transfer_status(ctx: Context<GetTransferStatus>) -> Result<TransferStatus> {
+        instructions::get_transfer_status(ctx)
+    }
+
+    pub fn would_queue(
+        ctx: Context<WouldQueue>,
+        chain_id: u16,
+        amount: u64,
+    ) -> Result<WouldQueueResult> {
+        instructions::would_queue(ctx, chain_id, amount)
+    }
+
+    pub fn preview_transfer(
+        ctx: Context<PreviewTransfer>,
+        args: TransferArgs,
+    ) -> Result<TransferPreview> {
+        instructions::preview_transfer(ctx, args)
+    }
+
     pub fn transfer_ownership(ctx: Context<TransferOwnership>) -> Result<()> {
         instructions::transfer_ownership(ctx)
     }
@@ -128,6 +270,10 @@ pub mod example_native_token_transfers {
         instructions::claim_ownership(ctx)
     }
 
+    pub fn get_pending_owner(ctx: Context<GetPendingOwner>) -> Result<Option<Pubkey>> {
+        instructions::get_pending_owner(ctx)
+    }
+
     pub fn accept_token_authority(ctx: Context<AcceptTokenAuthority>) -> Result<()> {
         instructions::accept_token_authority(ctx)
     }
@@ -166,10 +312,30 @@ pub mod example_native_token_transfers {
         instructions::set_paused(ctx, pause)
     }
 
+    pub fn set_outbound_paused(ctx: Context<SetPaused>, pause: bool) -> Result<()> {
+        instructions::set_outbound_paused(ctx, pause)
+    }
+
+    pub fn set_inbound_paused(ctx: Context<SetPaused>, pause: bool) -> Result<()> {
+        instructions::set_inbound_paused(ctx, pause)
+    }
+
     pub fn set_peer(ctx: Context<SetPeer>, args: SetPeerArgs) -> Result<()> {
         instructions::set_peer(ctx, args)
     }
 
+    pub fn set_peer_with_options(
+        ctx: Context<SetPeer>,
+        args: SetPeerArgs,
+        options: SetPeerOptions,
+    ) -> Result<()> {
+        instructions::set_peer_with_options(ctx, args, options)
+    }
+
+    pub fn delete_peer(ctx: Context<DeletePeer>, chain_id: u16) -> Result<()> {
+        instructions::delete_peer(ctx, chain_id)
+    }
+
     pub fn register_transceiver(ctx: Context<RegisterTransceiver>) -> Result<()> {
         instructions::register_transceiver(ctx)
     }
@@ -178,6 +344,17 @@ pub mod example_native_token_transfers {
         instructions::deregister_transceiver(ctx)
     }
 
+    pub fn swap_transceivers(ctx: Context<SwapTransceivers>) -> Result<()> {
+        instructions::swap_transceivers(ctx)
+    }
+
+    pub fn set_limits_manager(
+        ctx: Context<SetLimitsManager>,
+        limits_manager: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_limits_manager(ctx, limits_manager)
+    }
+
     pub fn set_outbound_limit(
         ctx: Context<SetOutboundLimit>,
         args: SetOutboundLimitArgs,
@@ -192,6 +369,31 @@ pub mod example_native_token_transfers {
         instructions::set_inbound_limit(ctx, args)
     }
 
+    pub fn set_sender_limit(ctx: Context<SetSenderLimit>, limit: Option<u64>) -> Result<()> {
+        instructions::set_sender_limit(ctx, limit)
+    }
+
+    pub fn set_sender_limit_override(
+        ctx: Context<SetSenderLimitOverride>,
+        args: SetSenderLimitOverrideArgs,
+    ) -> Result<()> {
+        instructions::set_sender_limit_override(ctx, args)
+    }
+
+    pub fn set_per_chain_outbound_limits(
+        ctx: Context<SetPerChainOutboundLimits>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_per_chain_outbound_limits(ctx, enabled)
+    }
+
+    pub fn set_chain_outbound_limit(
+        ctx: Context<SetChainOutboundLimit>,
+        args: SetChainOutboundLimitArgs,
+    ) -> Result<()> {
+        instructions::set_chain_outbound_limit(ctx, args)
+    }
+
     pub fn mark_outbox_item_as_released(ctx: Context<MarkOutboxItemAsReleased>) -> Result<bool> {
         instructions::mark_outbox_item_as_released(ctx)
     }
@@ -200,6 +402,146 @@ pub mod example_native_token_transfers {
         instructions::set_threshold(ctx, threshold)
     }
 
+    pub fn enable_route(ctx: Context<SetRouteEnabled>, _chain_id: u16) -> Result<()> {
+        instructions::set_route_enabled(ctx, true)
+    }
+
+    pub fn disable_route(ctx: Context<SetRouteEnabled>, _chain_id: u16) -> Result<()> {
+        instructions::set_route_enabled(ctx, false)
+    }
+
+    pub fn set_routes_default_enabled(
+        ctx: Context<SetRoutesDefaultEnabled>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_routes_default_enabled(ctx, enabled)
+    }
+
+    pub fn set_max_outbound_in_flight(
+        ctx: Context<SetMaxOutboundInFlight>,
+        _chain_id: u16,
+        max: Option<u64>,
+    ) -> Result<()> {
+        instructions::set_max_outbound_in_flight(ctx, max)
+    }
+
+    pub fn set_allow_queue(
+        ctx: Context<SetAllowQueue>,
+        _chain_id: u16,
+        allow_queue: bool,
+    ) -> Result<()> {
+        instructions::set_allow_queue(ctx, allow_queue)
+    }
+
+    pub fn set_allow_inbound_queue(
+        ctx: Context<SetAllowQueue>,
+        _chain_id: u16,
+        allow_inbound_queue: bool,
+    ) -> Result<()> {
+        instructions::set_allow_inbound_queue(ctx, allow_inbound_queue)
+    }
+
+    pub fn set_destination_fee_bps(
+        ctx: Context<SetDestinationFeeBps>,
+        _chain_id: u16,
+        fee_bps: u16,
+    ) -> Result<()> {
+        instructions::set_destination_fee_bps(ctx, fee_bps)
+    }
+
+    pub fn set_fallback_recipient(
+        ctx: Context<SetFallbackRecipient>,
+        _chain_id: u16,
+    ) -> Result<()> {
+        instructions::set_fallback_recipient(ctx)
+    }
+
+    pub fn set_receive_hook(
+        ctx: Context<SetReceiveHook>,
+        receive_hook: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_receive_hook(ctx, receive_hook)
+    }
+
+    pub fn set_dust_account(ctx: Context<SetDustAccount>) -> Result<()> {
+        instructions::set_dust_account(ctx)
+    }
+
+    pub fn set_max_vaa_age(ctx: Context<SetMaxVaaAge>, max_vaa_age: Option<u32>) -> Result<()> {
+        instructions::set_max_vaa_age(ctx, max_vaa_age)
+    }
+
+    pub fn set_reject_unconsumed_payload(
+        ctx: Context<SetRejectUnconsumedPayload>,
+        reject: bool,
+    ) -> Result<()> {
+        instructions::set_reject_unconsumed_payload(ctx, reject)
+    }
+
+    pub fn set_allowed_consistency_levels(
+        ctx: Context<SetAllowedConsistencyLevels>,
+        consistency_levels: Vec<u8>,
+    ) -> Result<()> {
+        instructions::set_allowed_consistency_levels(ctx, consistency_levels)
+    }
+
+    pub fn set_fee_collector(ctx: Context<SetFeeCollector>, fee_collector: Pubkey) -> Result<()> {
+        instructions::set_fee_collector(ctx, fee_collector)
+    }
+
+    pub fn begin_mint_migration(ctx: Context<BeginMintMigration>) -> Result<()> {
+        instructions::begin_mint_migration(ctx)
+    }
+
+    pub fn complete_mint_migration(ctx: Context<CompleteMintMigration>) -> Result<()> {
+        instructions::complete_mint_migration(ctx)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::migrate_config(ctx)
+    }
+
+    pub fn migrate_outbox_item(ctx: Context<MigrateOutboxItem>) -> Result<()> {
+        instructions::migrate_outbox_item(ctx)
+    }
+
+    pub fn migrate_inbox_item(ctx: Context<MigrateInboxItem>) -> Result<()> {
+        instructions::migrate_inbox_item(ctx)
+    }
+
+    pub fn migrate_peer(ctx: Context<MigratePeer>, chain_id: u16) -> Result<()> {
+        instructions::migrate_peer(ctx, chain_id)
+    }
+
+    pub fn purge_inbox_item(ctx: Context<PurgeInboxItem>, args: PurgeInboxItemArgs) -> Result<()> {
+        instructions::purge_inbox_item(ctx, args)
+    }
+
+    pub fn purge_inbox_item_v2(
+        ctx: Context<PurgeInboxItemV2>,
+        args: PurgeInboxItemArgs,
+    ) -> Result<()> {
+        instructions::purge_inbox_item_v2(ctx, args)
+    }
+
+    pub fn force_mark_released(
+        ctx: Context<ForceMarkReleased>,
+        args: ForceMarkReleasedArgs,
+    ) -> Result<()> {
+        instructions::force_mark_released(ctx, args)
+    }
+
+    pub fn begin_decommission(
+        ctx: Context<BeginDecommission>,
+        grace_period_seconds: u64,
+    ) -> Result<()> {
+        instructions::begin_decommission(ctx, grace_period_seconds)
+    }
+
+    pub fn finalize_decommission(ctx: Context<FinalizeDecommission>) -> Result<()> {
+        instructions::finalize_decommission(ctx)
+    }
+
     // standalone transceiver stuff
 
     pub fn set_wormhole_peer(
@@ -209,6 +551,29 @@ pub mod example_native_token_transfers {
         transceivers::wormhole::instructions::set_transceiver_peer(ctx, args)
     }
 
+    pub fn set_wormhole_peer_with_options(
+        ctx: Context<SetTransceiverPeer>,
+        args: SetTransceiverPeerArgs,
+        options: SetTransceiverPeerOptions,
+    ) -> Result<()> {
+        transceivers::wormhole::instructions::set_transceiver_peer_with_options(ctx, args, options)
+    }
+
+    pub fn delete_wormhole_peer(ctx: Context<DeleteTransceiverPeer>, chain_id: u16) -> Result<()> {
+        transceivers::wormhole::instructions::delete_transceiver_peer(ctx, chain_id)
+    }
+
+    pub fn get_wormhole_peer_index(ctx: Context<GetTransceiverPeerIndex>) -> Result<Vec<u16>> {
+        transceivers::wormhole::instructions::get_transceiver_peer_index(ctx)
+    }
+
+    pub fn migrate_wormhole_peer(
+        ctx: Context<MigrateTransceiverPeer>,
+        chain_id: u16,
+    ) -> Result<()> {
+        transceivers::wormhole::instructions::migrate_transceiver_peer(ctx, chain_id)
+    }
+
     pub fn receive_wormhole_message(ctx: Context<ReceiveMessage>) -> Result<()> {
         transceivers::wormhole::instructions::receive_message(ctx)
     }
@@ -220,6 +585,14 @@ pub mod example_native_token_transfers {
         transceivers::wormhole::instructions::release_outbound(ctx, args)
     }
 
+    pub fn release_wormhole_outbound_with_options(
+        ctx: Context<ReleaseOutbound>,
+        args: ReleaseOutboundArgs,
+        options: ReleaseOutboundOptions,
+    ) -> Result<()> {
+        transceivers::wormhole::instructions::release_outbound_with_options(ctx, args, options)
+    }
+
     pub fn broadcast_wormhole_id(ctx: Context<BroadcastId>) -> Result<()> {
         transceivers::wormhole::instructions::broadcast_id(ctx)
     }
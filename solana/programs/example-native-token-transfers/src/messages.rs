@@ -4,17 +4,74 @@ use ntt_messages::{
     transceiver::{TransceiverMessageData, TransceiverMessageDataBytes},
 };
 use std::{collections::HashMap, marker::PhantomData};
+use wormhole_io::TypePrefixedPayload;
 
+use crate::error::NTTError;
+
+/// A message delivered by a transceiver, waiting to be redeemed.
+///
+/// Transceivers store any message from a registered peer, without checking
+/// whether it's addressed to this manager: that is only checked in
+/// [`crate::instructions::redeem`], which rejects messages meant for another
+/// manager (such as another NTT deployment on Solana) and logs the intended
+/// one. Such messages are inert: they can never be redeemed here, and they
+/// don't get in the way of delivering the same VAA to the intended manager,
+/// whose transceivers store it under their own program.
+///
+/// These accounts are never closed, redeemed or not, so the rent paid by
+/// whoever received the message is not recoverable. Relayers should check the
+/// recipient manager of a VAA before submitting it.
+///
+/// Transceivers store these at the PDA seeded by
+/// `[SEED_PREFIX, from_chain, id]` under their own program, and
+/// [`crate::instructions::redeem`] checks the address against the stored bump.
 #[account]
 #[derive(InitSpace)]
 pub struct ValidatedTransceiverMessage<A: AnchorDeserialize + AnchorSerialize + Space + Clone> {
     pub from_chain: ChainId,
     pub message: TransceiverMessageData<A>,
+    /// NOTE: this and the fields below were appended after the initial
+    /// release, whose accounts don't have them (see [`Self::LEGACY_SPACE`]).
+    pub bump: u8,
+    /// The timestamp in the body of the VAA the message was delivered in.
+    pub vaa_timestamp: u32,
+    /// `keccak256(from_chain || ntt_manager_payload)`, which the inbox item of
+    /// the message is seeded by. Computed once when the message is received,
+    /// so that [`crate::instructions::redeem`] doesn't hash it again on every
+    /// vote, see [`Self::message_hash`].
+    pub message_hash: [u8; 32],
+    /// The bytes of additional payload the message carried that decoding it
+    /// as `A` left unread. Redeem rejects or drops them, see
+    /// [`crate::config::Config::check_unconsumed_payload`].
+    pub unconsumed_payload_len: u16,
 }
 
 impl<A: AnchorDeserialize + AnchorSerialize + Space + Clone> ValidatedTransceiverMessage<A> {
     pub const SEED_PREFIX: &'static [u8] = b"transceiver_message";
 
+    /// The size of the accounts allocated by the initial release, which only
+    /// stored `from_chain` and `message`. Room was made for a
+    /// `TransceiverMessageData<TransceiverMessageData<A>>`, so the message is
+    /// followed by zeroes, and the fields appended since are not there.
+    pub const LEGACY_SPACE: usize =
+        8 + ChainId::INIT_SPACE + TransceiverMessageData::<TransceiverMessageData<A>>::INIT_SPACE;
+
+    /// The offset of [`Self::bump`]. The message has a fixed-size encoding, so
+    /// the fields appended after it are at fixed offsets.
+    pub const BUMP_OFFSET: usize =
+        8 + ChainId::INIT_SPACE + TransceiverMessageData::<A>::INIT_SPACE;
+
+    /// The offset of [`Self::message_hash`], after the bump and the VAA
+    /// timestamp.
+    const MESSAGE_HASH_OFFSET: usize = Self::BUMP_OFFSET + 1 + 4;
+
+    /// Whether the account has already been written to. Freshly allocated
+    /// accounts are zeroed, and the discriminator is only written when the
+    /// instruction exits.
+    pub fn is_initialized(info: &AccountInfo) -> Result<bool> {
+        Ok(info.try_borrow_data()?.starts_with(&Self::DISCRIMINATOR))
+    }
+
     pub fn discriminator_check(data: &[u8]) -> Result<()> {
         if data.len() < Self::DISCRIMINATOR.len() {
             return Err(ErrorCode::AccountDiscriminatorNotFound.into());
@@ -26,7 +83,10 @@ impl<A: AnchorDeserialize + AnchorSerialize + Space + Clone> ValidatedTransceive
         Ok(())
     }
 
-    pub fn try_from(info: &UncheckedAccount, expected_owner: &Pubkey) -> Result<Self> {
+    pub fn try_from(info: &UncheckedAccount, expected_owner: &Pubkey) -> Result<Self>
+    where
+        A: TypePrefixedPayload,
+    {
         if info.owner == &system_program::ID && info.lamports() == 0 {
             return Err(ErrorCode::AccountNotInitialized.into());
         }
@@ -34,23 +94,189 @@ impl<A: AnchorDeserialize + AnchorSerialize + Space + Clone> ValidatedTransceive
             return Err(Error::from(ErrorCode::AccountOwnedByWrongProgram)
                 .with_pubkeys((*info.owner, *expected_owner)));
         }
-        let mut data: &[u8] = &info.try_borrow_data()?;
-        ValidatedTransceiverMessage::try_deserialize(&mut data)
+        let data: &[u8] = &info.try_borrow_data()?;
+        if data.len() == Self::LEGACY_SPACE {
+            return Self::try_from_legacy(info, data, expected_owner);
+        }
+        ValidatedTransceiverMessage::try_deserialize(&mut &data[..])
+    }
+
+    /// Reads an account in the [`Self::LEGACY_SPACE`] layout. The fields it
+    /// doesn't store are derived (the bump), computed (the message hash) or
+    /// left unknown (the VAA timestamp, see [`crate::peer_stats`], and the
+    /// unconsumed payload, which such messages are never rejected for).
+    fn try_from_legacy(info: &UncheckedAccount, data: &[u8], owner: &Pubkey) -> Result<Self>
+    where
+        A: TypePrefixedPayload,
+    {
+        Self::discriminator_check(data)?;
+        let mut fields = &data[8..];
+        let from_chain =
+            ChainId::deserialize(&mut fields).map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+        let message = TransceiverMessageData::<A>::deserialize(&mut fields)
+            .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+
+        Ok(ValidatedTransceiverMessage {
+            message_hash: message.ntt_manager_payload.keccak256(from_chain).to_bytes(),
+            bump: Self::bump(info, owner)?,
+            vaa_timestamp: 0,
+            unconsumed_payload_len: 0,
+            from_chain,
+            message,
+        })
+    }
+
+    /// The bump of the account's PDA under `owner` (the transceiver). Accounts
+    /// in the [`Self::LEGACY_SPACE`] layout don't store it, so the canonical
+    /// bump is derived instead.
+    pub fn bump(info: &UncheckedAccount, owner: &Pubkey) -> Result<u8> {
+        let data: &[u8] = &info.try_borrow_data()?;
+        Self::discriminator_check(data)?;
+        if data.len() == Self::LEGACY_SPACE {
+            let id = Self::message(data)?
+                .ntt_manager_payload()
+                .map_err(|_| ErrorCode::AccountDidNotDeserialize)?
+                .id;
+            let (_, bump) = Pubkey::find_program_address(
+                &[
+                    Self::SEED_PREFIX,
+                    Self::from_chain(info)?.id.to_be_bytes().as_ref(),
+                    id.as_ref(),
+                ],
+                owner,
+            );
+            return Ok(bump);
+        }
+        Ok(*data
+            .get(Self::BUMP_OFFSET)
+            .ok_or(ErrorCode::AccountDidNotDeserialize)?)
     }
 
     pub fn from_chain(info: &UncheckedAccount) -> Result<ChainId> {
-        let data: &[u8] = &info.try_borrow_data().unwrap();
+        let data: &[u8] = &info.try_borrow_data()?;
         Self::discriminator_check(data)?;
+        let from_chain = data.get(8..10).ok_or(ErrorCode::AccountDidNotDeserialize)?;
         Ok(ChainId {
             // This is LE bytes because we deserialize using Borsh.
             // Not to be confused with the wire format (which is BE bytes)
-            id: u16::from_le_bytes(data[8..10].try_into().unwrap()),
+            id: u16::from_le_bytes(from_chain.try_into().unwrap()),
         })
     }
 
     pub fn message(data: &[u8]) -> Result<TransceiverMessageDataBytes<A>> {
         Self::discriminator_check(data)?;
-        Ok(TransceiverMessageDataBytes::parse(&data[10..]))
+        let message = data.get(10..).ok_or(ErrorCode::AccountDidNotDeserialize)?;
+        TransceiverMessageDataBytes::parse(message)
+            .map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
+
+    /// The hash the inbox item of the message is seeded by, as stored on
+    /// receive. Accounts in the [`Self::LEGACY_SPACE`] layout don't have it,
+    /// and it is computed from the message instead.
+    ///
+    /// NOTE: the stored hash is trusted as is, without hashing the message
+    /// again to compare. The account is owned by the transceiver (which
+    /// [`crate::instructions::redeem`] checks with `owner` and
+    /// `seeds::program`), and the transceiver computed the hash from the very
+    /// message it stored next to it: a transceiver that stored a wrong hash
+    /// could just as well have stored a wrong message, so re-checking it would
+    /// cost the compute units without protecting against anything.
+    pub fn message_hash(info: &UncheckedAccount) -> Result<[u8; 32]>
+    where
+        A: TypePrefixedPayload,
+    {
+        let data: &[u8] = &info.try_borrow_data()?;
+        Self::discriminator_check(data)?;
+        if data.len() == Self::LEGACY_SPACE {
+            let message = Self::message(data)?
+                .ntt_manager_payload()
+                .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+            return Ok(message.keccak256(Self::from_chain(info)?).to_bytes());
+        }
+        let message_hash = data
+            .get(Self::MESSAGE_HASH_OFFSET..Self::MESSAGE_HASH_OFFSET + 32)
+            .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+        Ok(message_hash.try_into().unwrap())
+    }
+}
+
+/// Allocates a [`ValidatedTransceiverMessage`] account of `space` bytes at the
+/// PDA derived from `signer_seeds`, owned by `owner` and paid for by `payer`.
+///
+/// The payer's balance is checked first, so that an underfunded payer fails
+/// with [`NTTError::InsufficientRentForMessage`] (and the required amount in
+/// the logs) rather than with an opaque system program error.
+pub fn create_transceiver_message_account<'info>(
+    payer: &AccountInfo<'info>,
+    transceiver_message: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let current_lamports = transceiver_message.lamports();
+    let required_lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(current_lamports);
+
+    if payer.lamports() < required_lamports {
+        msg!(
+            "Payer has {} lamports, but {} are required for the transceiver message",
+            payer.lamports(),
+            required_lamports
+        );
+        return Err(NTTError::InsufficientRentForMessage.into());
+    }
+
+    // NOTE: the account may have been funded ahead of time (anyone can send
+    // lamports to it), in which case it can't be created directly
+    if current_lamports == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: transceiver_message.clone(),
+                },
+                &[signer_seeds],
+            ),
+            required_lamports,
+            space as u64,
+            owner,
+        )
+    } else {
+        if required_lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: payer.clone(),
+                        to: transceiver_message.clone(),
+                    },
+                ),
+                required_lamports,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Allocate {
+                    account_to_allocate: transceiver_message.clone(),
+                },
+                &[signer_seeds],
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Assign {
+                    account_to_assign: transceiver_message.clone(),
+                },
+                &[signer_seeds],
+            ),
+            owner,
+        )
     }
 }
 
@@ -1,4 +1,7 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, Discriminator};
+use ntt_messages::{chain_id::AddressFormat, trimmed_amount::TrimmedAmount};
+
+use crate::error::NTTError;
 
 #[account]
 #[derive(InitSpace)]
@@ -7,8 +10,161 @@ pub struct NttManagerPeer {
     pub bump: u8,
     pub address: [u8; 32],
     pub token_decimals: u8,
+    /// Whether transfers to and from this chain are accepted.
+    pub enabled: bool,
+    /// Number of outbound transfers to this chain that have not been released
+    /// by any transceiver yet.
+    pub outbound_in_flight: u64,
+    /// Cap on [`Self::outbound_in_flight`]. `None` means no cap.
+    pub max_outbound_in_flight: Option<u64>,
+    /// Whether outbound transfers to this chain may be queued when they exceed
+    /// the rate limit. When false, such transfers fail even if the sender asked
+    /// for them to be queued.
+    pub allow_queue: bool,
+    /// The execution fee the peer deducts from delivered transfers, in basis
+    /// points. Informational only: nothing is deducted here, but transfers
+    /// with a `min_amount_out` are checked against it.
+    pub destination_fee_bps: u16,
+    /// Token account that inbound transfers from this chain are delivered to
+    /// when the recipient's token account is frozen. `None` means such
+    /// transfers can't be released until it is thawed.
+    pub fallback_recipient: Option<Pubkey>,
+    /// Number of inbound transfers from this chain that have been approved,
+    /// but not released yet.
+    pub inbound_in_flight: u64,
+    /// Whether inbound transfers from this chain that exceed the inbound rate
+    /// limit are queued (released once the capacity allows). When false, they
+    /// are rejected, and can only be redeemed again once there is capacity.
+    pub allow_inbound_queue: bool,
 }
 
 impl NttManagerPeer {
     pub const SEED_PREFIX: &'static [u8] = b"peer";
+
+    /// Upper bound on [`Self::destination_fee_bps`], i.e. 100%.
+    pub const MAX_FEE_BPS: u16 = 10_000;
+
+    /// Whether the account has already been written to. Freshly allocated
+    /// accounts are zeroed, and the discriminator is only written when the
+    /// instruction exits.
+    pub fn is_initialized(info: &AccountInfo) -> Result<bool> {
+        Ok(info.try_borrow_data()?.starts_with(&Self::DISCRIMINATOR))
+    }
+
+    /// Records a new outbound transfer to this chain, failing if that would
+    /// exceed [`Self::max_outbound_in_flight`].
+    pub fn start_outbound(&mut self) -> Result<()> {
+        let in_flight = self.outbound_in_flight + 1;
+        if let Some(max) = self.max_outbound_in_flight {
+            require!(in_flight <= max, NTTError::TooManyOutboundInFlight);
+        }
+        self.outbound_in_flight = in_flight;
+        Ok(())
+    }
+
+    /// Records that an outbound transfer to this chain has been released.
+    pub fn finish_outbound(&mut self) {
+        // saturating, as transfers made before the counter was introduced
+        // were never counted
+        self.outbound_in_flight = self.outbound_in_flight.saturating_sub(1);
+    }
+
+    /// Records that an inbound transfer from this chain has been approved.
+    pub fn start_inbound(&mut self) {
+        self.inbound_in_flight = self.inbound_in_flight.saturating_add(1);
+    }
+
+    /// Records that an inbound transfer from this chain has been released.
+    pub fn finish_inbound(&mut self) {
+        // saturating, as transfers approved before the counter was introduced
+        // were never counted
+        self.inbound_in_flight = self.inbound_in_flight.saturating_sub(1);
+    }
+
+    /// The amount the recipient is expected to receive on the peer chain for a
+    /// transfer of `amount`, i.e. `amount` minus [`Self::destination_fee_bps`].
+    /// The fee is rounded up, so this is never more than what's delivered.
+    /// The result is in the same (trimmed) decimals as `amount`.
+    pub fn expected_amount_out(&self, amount: TrimmedAmount) -> u64 {
+        let fee = (u128::from(amount.amount()) * u128::from(self.destination_fee_bps))
+            .div_ceil(u128::from(Self::MAX_FEE_BPS));
+        amount
+            .amount()
+            .saturating_sub(u64::try_from(fee).unwrap_or(u64::MAX))
+    }
+}
+
+/// Checks that `address` is well-formed for the address format of `chain_id`
+/// (see [`AddressFormat::is_valid`]), unless `allow_nonstandard` is set. The
+/// 20 byte address of EVM peers is logged for operators to compare against
+/// the deployed manager.
+pub fn check_peer_address(
+    chain_id: u16,
+    address: &[u8; 32],
+    allow_nonstandard: bool,
+) -> Result<()> {
+    let format = AddressFormat::of(chain_id);
+    if !format.is_valid(address) {
+        require!(allow_nonstandard, NTTError::InvalidPeerAddress);
+        msg!(
+            "WARNING: registering nonstandard {:?} address for chain id {}",
+            format,
+            chain_id
+        );
+        return Ok(());
+    }
+
+    if format == AddressFormat::Evm {
+        let native: String = address[12..].iter().map(|b| format!("{:02x}", b)).collect();
+        msg!("Peer address: 0x{}", native);
+    }
+
+    Ok(())
+}
+
+/// The most chains a [`PeerIndex`] holds.
+pub const MAX_INDEXED_PEERS: usize = 64;
+
+#[account]
+#[derive(InitSpace)]
+/// The chain ids of the configured peers, in the order they were added, so
+/// that they can be listed without scanning the program's accounts (which many
+/// RPC providers don't allow). Updated in the same instruction as the peers
+/// themselves, see [`crate::instructions::set_peer`] and
+/// [`crate::instructions::delete_peer`].
+///
+/// NOTE: peers set before the index was introduced are only added when they're
+/// migrated, see [`crate::instructions::migrate_peer`].
+pub struct PeerIndex {
+    pub bump: u8,
+    #[max_len(MAX_INDEXED_PEERS)]
+    pub chain_ids: Vec<u16>,
+}
+
+impl PeerIndex {
+    pub const SEED_PREFIX: &'static [u8] = b"peer_index";
+
+    pub fn insert(&mut self, chain_id: u16) -> Result<()> {
+        insert_chain_id(&mut self.chain_ids, chain_id)
+    }
+
+    pub fn remove(&mut self, chain_id: u16) {
+        remove_chain_id(&mut self.chain_ids, chain_id)
+    }
+}
+
+/// Adds `chain_id` to a peer index, unless it's already there. Fails if the
+/// index already holds [`MAX_INDEXED_PEERS`] chains.
+pub fn insert_chain_id(chain_ids: &mut Vec<u16>, chain_id: u16) -> Result<()> {
+    if chain_ids.contains(&chain_id) {
+        return Ok(());
+    }
+    require!(chain_ids.len() < MAX_INDEXED_PEERS, NTTError::PeerIndexFull);
+    chain_ids.push(chain_id);
+    Ok(())
+}
+
+/// Removes `chain_id` from a peer index, if it's there.
+pub fn remove_chain_id(chain_ids: &mut Vec<u16>, chain_id: u16) {
+    chain_ids.retain(|&id| id != chain_id);
 }
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+/// Delivery statistics of a peer, for monitoring.
+/// PDA seeded by the chain id, created permissionlessly with
+/// [`crate::instructions::init_peer_stats`].
+pub struct PeerStats {
+    pub bump: u8,
+    /// The time from the VAA timestamp to the release of inbound transfers.
+    pub inbound_latency: LatencyStats,
+}
+
+impl PeerStats {
+    pub const SEED_PREFIX: &'static [u8] = b"peer_stats";
+}
+
+/// A running aggregate of latencies, in seconds.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct LatencyStats {
+    /// The number of samples in [`Self::sum_seconds`] and [`Self::max_seconds`].
+    pub count: u64,
+    pub sum_seconds: u64,
+    pub max_seconds: u64,
+    /// The number of samples longer than [`Self::MAX_SAMPLE_SECONDS`]. These
+    /// are counted separately, so that a single stale (or misdated) message
+    /// doesn't skew the aggregate.
+    pub outliers: u64,
+}
+
+impl LatencyStats {
+    pub const MAX_SAMPLE_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+    /// Records the latency from `vaa_timestamp` to `now`. A zero timestamp
+    /// means it is unknown (see [`crate::queue::inbox::InboxItem::vaa_timestamp`]),
+    /// and is not recorded. A VAA timestamp ahead of `now` (clock skew between
+    /// the guardians and Solana) counts as zero latency.
+    pub fn record(&mut self, vaa_timestamp: u32, now: i64) {
+        if vaa_timestamp == 0 {
+            return;
+        }
+
+        let latency = u64::try_from(now.saturating_sub(vaa_timestamp.into())).unwrap_or(0);
+        if latency > Self::MAX_SAMPLE_SECONDS {
+            self.outliers = self.outliers.saturating_add(1);
+            return;
+        }
+
+        self.count = self.count.saturating_add(1);
+        self.sum_seconds = self.sum_seconds.saturating_add(latency);
+        self.max_seconds = self.max_seconds.max(latency);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let mut stats = LatencyStats::default();
+
+        stats.record(1000, 1100);
+        stats.record(1000, 1030);
+        // skewed
+        stats.record(1200, 1100);
+        // unknown
+        stats.record(0, 1100);
+        // stale
+        let stale = 1000 + i64::try_from(LatencyStats::MAX_SAMPLE_SECONDS).unwrap() + 1;
+        stats.record(1000, stale);
+
+        assert_eq!(
+            stats,
+            LatencyStats {
+                count: 3,
+                sum_seconds: 130,
+                max_seconds: 100,
+                outliers: 1,
+            }
+        );
+    }
+}
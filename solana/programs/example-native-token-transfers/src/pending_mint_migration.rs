@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// A mint migration started by
+/// [`crate::instructions::begin_mint_migration`], waiting for the custody to
+/// be wound down before [`crate::instructions::complete_mint_migration`]
+/// retargets the program to [`PendingMintMigration::mint`].
+#[account]
+#[derive(InitSpace)]
+pub struct PendingMintMigration {
+    pub bump: u8,
+    /// The mint the program is migrated to.
+    pub mint: Pubkey,
+    /// The token program that owns [`PendingMintMigration::mint`].
+    pub token_program: Pubkey,
+    pub rent_payer: Pubkey,
+}
+
+impl PendingMintMigration {
+    pub const SEED_PREFIX: &'static [u8] = b"pending_mint_migration";
+}
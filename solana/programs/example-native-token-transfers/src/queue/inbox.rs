@@ -5,18 +5,25 @@ use crate::{bitmap::Bitmap, clock::current_timestamp, error::NTTError};
 
 use super::rate_limit::RateLimitState;
 
-#[account]
+#[account(zero_copy)]
 #[derive(InitSpace)]
 // TODO: generalise this to arbitrary inbound messages (via a generic parameter in place of amount and recipient info)
 pub struct InboxItem {
-    // Whether the InboxItem has already been initialized. This is used during the redeem process
-    // to guard against modifications to the `bump` and `amounts` fields.
-    pub init: bool,
-    pub bump: u8,
     pub amount: u64,
+    /// Only meaningful when the status is [`ReleaseStatus::ReleaseAfter`],
+    /// see [`InboxItem::release_status`].
+    release_timestamp: i64,
     pub recipient_address: Pubkey,
     pub votes: Bitmap,
-    pub release_status: ReleaseStatus,
+    pub bump: u8,
+    release_status: u8,
+    /// The chain the transfer came from, or 0 for items redeemed before it
+    /// was recorded.
+    pub from_chain: u16,
+    /// The timestamp of the VAA the transfer was first redeemed with, or 0
+    /// for items redeemed before it was recorded. Used for the delivery
+    /// latency in [`crate::peer_stats::PeerStats`].
+    pub vaa_timestamp: u32,
 }
 
 /// The status of an InboxItem. This determines whether the tokens are minted/unlocked to the recipient. As
@@ -30,9 +37,67 @@ pub enum ReleaseStatus {
     Released,
 }
 
+impl ReleaseStatus {
+    const NOT_APPROVED: u8 = 0;
+    const RELEASE_AFTER: u8 = 1;
+    const RELEASED: u8 = 2;
+}
+
 impl InboxItem {
     pub const SEED_PREFIX: &'static [u8] = b"inbox_item";
 
+    pub fn new(
+        bump: u8,
+        amount: u64,
+        recipient_address: Pubkey,
+        from_chain: u16,
+        vaa_timestamp: u32,
+    ) -> Self {
+        Self {
+            amount,
+            release_timestamp: 0,
+            recipient_address,
+            votes: Bitmap::new(),
+            bump,
+            release_status: ReleaseStatus::NOT_APPROVED,
+            from_chain,
+            vaa_timestamp,
+        }
+    }
+
+    /// Whether the account is in the zero-copy layout. Inbox items created
+    /// before the layout change have to be converted with
+    /// [`crate::instructions::migrate_inbox_item`] before they can be used.
+    pub fn is_current_layout(info: &AccountInfo) -> bool {
+        info.data_len() == 8 + Self::INIT_SPACE
+    }
+
+    /// Whether the item has been closed with
+    /// [`crate::instructions::close_inbox_item`], which leaves only the
+    /// discriminator behind.
+    pub fn is_closed(info: &AccountInfo) -> bool {
+        info.data_len() == 8
+    }
+
+    pub fn release_status(&self) -> ReleaseStatus {
+        match self.release_status {
+            ReleaseStatus::NOT_APPROVED => ReleaseStatus::NotApproved,
+            ReleaseStatus::RELEASE_AFTER => ReleaseStatus::ReleaseAfter(self.release_timestamp),
+            // only the tags written by [`InboxItem::set_release_status`] are ever stored
+            _ => ReleaseStatus::Released,
+        }
+    }
+
+    fn set_release_status(&mut self, release_status: ReleaseStatus) {
+        (self.release_status, self.release_timestamp) = match release_status {
+            ReleaseStatus::NotApproved => (ReleaseStatus::NOT_APPROVED, 0),
+            ReleaseStatus::ReleaseAfter(release_timestamp) => {
+                (ReleaseStatus::RELEASE_AFTER, release_timestamp)
+            }
+            ReleaseStatus::Released => (ReleaseStatus::RELEASED, self.release_timestamp),
+        };
+    }
+
     /// Attempt to release the transfer.
     ///
     /// * If the inbox item status is [`ReleaseStatus::ReleaseAfter`], this function returns true if the current timestamp
@@ -47,13 +112,13 @@ impl InboxItem {
     pub fn try_release(&mut self) -> Result<bool> {
         let now = current_timestamp();
 
-        match self.release_status {
+        match self.release_status() {
             ReleaseStatus::NotApproved => Ok(false),
             ReleaseStatus::ReleaseAfter(release_timestamp) => {
                 if release_timestamp > now {
                     return Ok(false);
                 }
-                self.release_status = ReleaseStatus::Released;
+                self.set_release_status(ReleaseStatus::Released);
                 Ok(true)
             }
             ReleaseStatus::Released => Err(NTTError::TransferAlreadyRedeemed.into()),
@@ -61,14 +126,36 @@ impl InboxItem {
     }
 
     pub fn release_after(&mut self, release_timestamp: i64) -> Result<()> {
-        if self.release_status != ReleaseStatus::NotApproved {
+        if self.release_status() != ReleaseStatus::NotApproved {
             return Err(NTTError::TransferCannotBeRedeemed.into());
         };
-        self.release_status = ReleaseStatus::ReleaseAfter(release_timestamp);
+        self.set_release_status(ReleaseStatus::ReleaseAfter(release_timestamp));
         Ok(())
     }
 }
 
+/// The borsh layout of [`InboxItem`] before it was made zero-copy.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyInboxItem {
+    pub init: bool,
+    pub bump: u8,
+    pub amount: u64,
+    pub recipient_address: Pubkey,
+    pub votes: Bitmap,
+    pub release_status: ReleaseStatus,
+}
+
+impl From<LegacyInboxItem> for InboxItem {
+    fn from(legacy: LegacyInboxItem) -> Self {
+        let mut inbox_item = InboxItem {
+            votes: legacy.votes,
+            ..InboxItem::new(legacy.bump, legacy.amount, legacy.recipient_address, 0, 0)
+        };
+        inbox_item.set_release_status(legacy.release_status);
+        inbox_item
+    }
+}
+
 /// Inbound rate limit per chain.
 /// SECURITY: must check the PDA (since there are multiple PDAs, namely one for each chain.)
 #[account]
@@ -94,3 +181,37 @@ impl DerefMut for InboxRateLimit {
         &mut self.rate_limit
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inbox_item_fits_account() {
+        assert_eq!(InboxItem::INIT_SPACE, std::mem::size_of::<InboxItem>());
+        // legacy accounts can't be mistaken for the current layout
+        assert_ne!(InboxItem::INIT_SPACE, LegacyInboxItem::INIT_SPACE);
+    }
+
+    #[test]
+    fn test_inbox_item_release_status() {
+        let mut inbox_item =
+            InboxItem::new(u8::MAX, u64::MAX, Pubkey::new_unique(), u16::MAX, u32::MAX);
+        assert_eq!(inbox_item.release_status(), ReleaseStatus::NotApproved);
+
+        inbox_item.release_after(i64::MIN).unwrap();
+        assert_eq!(
+            inbox_item.release_status(),
+            ReleaseStatus::ReleaseAfter(i64::MIN)
+        );
+
+        for release_status in [
+            ReleaseStatus::NotApproved,
+            ReleaseStatus::ReleaseAfter(i64::MAX),
+            ReleaseStatus::Released,
+        ] {
+            inbox_item.set_release_status(release_status.clone());
+            assert_eq!(inbox_item.release_status(), release_status);
+        }
+    }
+}
@@ -1,41 +1,262 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, Discriminator};
 use ntt_messages::{chain_id::ChainId, trimmed_amount::TrimmedAmount};
 use std::ops::{Deref, DerefMut};
 
-use crate::{bitmap::*, clock::current_timestamp, error::NTTError};
+use crate::{
+    bitmap::*, clock::current_timestamp, error::NTTError, peer::NttManagerPeer,
+    registered_transceiver::MAX_ENABLED_TRANSCEIVERS,
+};
 
 use super::rate_limit::RateLimitState;
 
-#[account]
+#[account(zero_copy)]
 #[derive(InitSpace, Debug, PartialEq, Eq)]
 // TODO: generalise this to arbitrary outbound messages (via a generic parameter in place of amount and recipient info)
 pub struct OutboxItem {
-    pub amount: TrimmedAmount,
+    /// The trimmed amount, see [`OutboxItem::trimmed_amount`].
+    pub amount: u64,
+    pub release_timestamp: i64,
     pub sender: Pubkey,
-    pub recipient_chain: ChainId,
     pub recipient_ntt_manager: [u8; 32],
     pub recipient_address: [u8; 32],
-    pub release_timestamp: i64,
     pub released: Bitmap,
+    pub recipient_chain: u16,
+    /// The decimals of the trimmed amount.
+    pub decimals: u8,
+    /// Whether the sender cancelled the transfer, see
+    /// [`OutboxItem::is_cancelled`]. Taken out of the padding, so existing
+    /// items read as not cancelled.
+    cancelled: u8,
+    /// Which outbound rate limits the transfer consumed from, see
+    /// [`OutboxItem::consumed_outbound_limit`]. Taken out of the padding, so
+    /// existing items read as having consumed none.
+    consumed_rate_limits: u8,
+    _padding: [u8; 3],
+    /// The ids of the transceivers that released the transfer, in the order
+    /// they did, alongside [`OutboxItem::released_at`].
+    pub released_by: [u8; OutboxItem::MAX_RECORDED_RELEASES],
+    /// When the transceivers in [`OutboxItem::released_by`] released the
+    /// transfer, or 0 for unused entries. [`OutboxItem::released`] stays the
+    /// source of truth for whether a transceiver has, see
+    /// [`OutboxItem::released_at`].
+    pub released_at: [i64; OutboxItem::MAX_RECORDED_RELEASES],
+    /// The sender-specified deadline after which the transfer is no longer
+    /// released, or 0 if it has none. See [`OutboxItem::expiry`].
+    pub expiry_timestamp: i64,
+    /// The account that paid the rent of the item, which gets it back if the
+    /// transfer expires (see [`crate::instructions::close_expired_outbox_item`]).
+    /// Zero for items created before it was recorded.
+    pub rent_payer: Pubkey,
+    /// How much sending the transfer refilled the inbound rate limit of the
+    /// recipient chain (the "backflow"), which is taken back if it's
+    /// cancelled or expires instead. Zero for queued transfers, and for items
+    /// created before it was recorded.
+    pub inbound_backflow: u64,
 }
 
 impl OutboxItem {
+    /// Only as many transceivers as can be enabled at once release a
+    /// transfer, unless the enabled set changes while it's in flight. Further
+    /// releases are not timestamped.
+    pub const MAX_RECORDED_RELEASES: usize = MAX_ENABLED_TRANSCEIVERS as usize;
+
+    const CONSUMED_OUTBOUND_LIMIT: u8 = 1 << 0;
+    const CONSUMED_CHAIN_OUTBOUND_LIMIT: u8 = 1 << 1;
+    const CONSUMED_SENDER_LIMIT: u8 = 1 << 2;
+
+    pub fn new(
+        amount: TrimmedAmount,
+        sender: Pubkey,
+        recipient_chain: ChainId,
+        recipient_ntt_manager: [u8; 32],
+        recipient_address: [u8; 32],
+        release_timestamp: i64,
+    ) -> Self {
+        Self {
+            amount: amount.amount,
+            release_timestamp,
+            sender,
+            recipient_ntt_manager,
+            recipient_address,
+            released: Bitmap::new(),
+            recipient_chain: recipient_chain.id,
+            decimals: amount.decimals,
+            cancelled: 0,
+            consumed_rate_limits: 0,
+            _padding: [0; 3],
+            released_by: [0; Self::MAX_RECORDED_RELEASES],
+            released_at: [0; Self::MAX_RECORDED_RELEASES],
+            expiry_timestamp: 0,
+            rent_payer: Pubkey::default(),
+            inbound_backflow: 0,
+        }
+    }
+
+    pub fn trimmed_amount(&self) -> TrimmedAmount {
+        TrimmedAmount::new(self.amount, self.decimals)
+    }
+
+    /// The deadline after which the transfer is discarded rather than
+    /// released, if the sender set one.
+    pub fn expiry(&self) -> Option<i64> {
+        (self.expiry_timestamp != 0).then_some(self.expiry_timestamp)
+    }
+
+    /// Whether the transfer has passed its deadline at `now`.
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expiry().is_some_and(|expiry| now > expiry)
+    }
+
+    /// Whether the sender took the transfer back with
+    /// [`crate::instructions::cancel_outbound_transfer`]. Cancelled transfers
+    /// are never released. Only items without a recorded
+    /// [`OutboxItem::rent_payer`] outlive their cancellation.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled != 0
+    }
+
+    pub fn cancel(&mut self) {
+        self.cancelled = 1;
+    }
+
+    /// Records that the transfer went out without being queued, consuming
+    /// from the outbound rate limit (the chain's own one if `chain_limit`,
+    /// see [`ChainOutboxRateLimit`]), and from the sender's rate limit if
+    /// `sender_limit` (see [`SenderRateLimit`]).
+    pub fn record_consumed_rate_limits(&mut self, chain_limit: bool, sender_limit: bool) {
+        self.consumed_rate_limits = Self::CONSUMED_OUTBOUND_LIMIT;
+        if chain_limit {
+            self.consumed_rate_limits |= Self::CONSUMED_CHAIN_OUTBOUND_LIMIT;
+        }
+        if sender_limit {
+            self.consumed_rate_limits |= Self::CONSUMED_SENDER_LIMIT;
+        }
+    }
+
+    /// Whether the transfer consumed from an outbound rate limit, which is
+    /// given back if it's cancelled or expires instead. Queued transfers don't. Neither do items
+    /// created before it was recorded, whose capacity is not given back.
+    pub fn consumed_outbound_limit(&self) -> bool {
+        self.consumed_rate_limits & Self::CONSUMED_OUTBOUND_LIMIT != 0
+    }
+
+    /// Whether the outbound rate limit the transfer consumed from is the
+    /// [`ChainOutboxRateLimit`] of the recipient chain, rather than the
+    /// global [`OutboxRateLimit`].
+    pub fn consumed_chain_outbound_limit(&self) -> bool {
+        self.consumed_rate_limits & Self::CONSUMED_CHAIN_OUTBOUND_LIMIT != 0
+    }
+
+    /// Whether the transfer also consumed from the sender's
+    /// [`SenderRateLimit`].
+    pub fn consumed_sender_limit(&self) -> bool {
+        self.consumed_rate_limits & Self::CONSUMED_SENDER_LIMIT != 0
+    }
+
+    /// Whether the account is in the zero-copy layout. Outbox items created
+    /// before the layout change have to be converted with
+    /// [`crate::instructions::migrate_outbox_item`] before they can be used.
+    pub fn is_current_layout(info: &AccountInfo) -> bool {
+        info.data_len() == 8 + Self::INIT_SPACE
+    }
+
     /// Attempt to release the transfer.
     /// Returns true if the transfer was released, false if it was not yet time to release it.
-    pub fn try_release(&mut self, transceiver_index: u8) -> Result<bool> {
+    /// Fails with [`NTTError::TransferAlreadyCancelled`] if the sender cancelled
+    /// the transfer, and with [`NTTError::TransferExpired`] once it has expired.
+    /// The first release takes the transfer off `peer`'s in-flight count.
+    pub fn try_release(
+        &mut self,
+        transceiver_index: u8,
+        peer: &mut NttManagerPeer,
+    ) -> Result<bool> {
         let now = current_timestamp();
 
+        if self.is_cancelled() {
+            return Err(NTTError::TransferAlreadyCancelled.into());
+        }
+
+        if self.is_expired(now) {
+            return Err(NTTError::TransferExpired.into());
+        }
+
         if self.release_timestamp > now {
             return Ok(false);
         }
 
+        self.mark_released(transceiver_index, peer)?;
+
+        Ok(true)
+    }
+
+    /// Mark the transfer as released by the transceiver, regardless of the
+    /// release timestamp.
+    /// The first release takes the transfer off `peer`'s in-flight count.
+    pub fn mark_released(
+        &mut self,
+        transceiver_index: u8,
+        peer: &mut NttManagerPeer,
+    ) -> Result<()> {
         if self.released.get(transceiver_index)? {
             return Err(NTTError::MessageAlreadySent.into());
         }
 
+        if self.released.is_empty() {
+            peer.finish_outbound();
+        }
+
         self.released.set(transceiver_index, true)?;
+        if let Some(entry) = self.released_at.iter().position(|at| *at == 0) {
+            self.released_by[entry] = transceiver_index;
+            self.released_at[entry] = current_timestamp();
+        }
 
-        Ok(true)
+        Ok(())
+    }
+
+    /// When the transceiver released the transfer, or `None` if it hasn't.
+    /// This is 0 for releases that were not timestamped: those that happened
+    /// before the timestamps were recorded, or after
+    /// [`OutboxItem::MAX_RECORDED_RELEASES`] others.
+    pub fn released_at(&self, transceiver_index: u8) -> Result<Option<i64>> {
+        if !self.released.get(transceiver_index)? {
+            return Ok(None);
+        }
+        let released_at = self
+            .released_by
+            .iter()
+            .zip(self.released_at)
+            .find(|(id, at)| **id == transceiver_index && *at != 0)
+            .map_or(0, |(_, at)| at);
+        Ok(Some(released_at))
+    }
+}
+
+/// The borsh layout of [`OutboxItem`] before it was made zero-copy.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyOutboxItem {
+    pub amount: TrimmedAmount,
+    pub sender: Pubkey,
+    pub recipient_chain: ChainId,
+    pub recipient_ntt_manager: [u8; 32],
+    pub recipient_address: [u8; 32],
+    pub release_timestamp: i64,
+    pub released: Bitmap,
+}
+
+impl From<LegacyOutboxItem> for OutboxItem {
+    fn from(legacy: LegacyOutboxItem) -> Self {
+        Self {
+            released: legacy.released,
+            ..OutboxItem::new(
+                legacy.amount,
+                legacy.sender,
+                legacy.recipient_chain,
+                legacy.recipient_ntt_manager,
+                legacy.recipient_address,
+                legacy.release_timestamp,
+            )
+        }
     }
 }
 
@@ -49,6 +270,12 @@ pub struct OutboxRateLimit {
 /// NOTE: only one of this account can exist, so we don't need to check the PDA.
 impl OutboxRateLimit {
     pub const SEED_PREFIX: &'static [u8] = b"outbox_rate_limit";
+
+    /// Whether the account has been created by
+    /// [`crate::instructions::initialize`] and not tampered with since.
+    pub fn is_initialized(info: &AccountInfo) -> Result<bool> {
+        Ok(*info.owner == crate::ID && info.try_borrow_data()?.starts_with(&Self::DISCRIMINATOR))
+    }
 }
 
 impl Deref for OutboxRateLimit {
@@ -64,3 +291,178 @@ impl DerefMut for OutboxRateLimit {
         &mut self.rate_limit
     }
 }
+
+/// Outbound rate limit for transfers to a single chain, used in place of the
+/// global [`OutboxRateLimit`] when
+/// [`crate::config::Config::per_chain_outbound_limits`] is set. Chains
+/// without one fall back to the global limit.
+#[account]
+#[derive(InitSpace, PartialEq, Eq, Debug)]
+pub struct ChainOutboxRateLimit {
+    pub bump: u8,
+    pub rate_limit: RateLimitState,
+}
+
+impl ChainOutboxRateLimit {
+    pub const SEED_PREFIX: &'static [u8] = b"chain_outbox_rate_limit";
+
+    /// Whether the account has been created by `set_chain_outbound_limit`.
+    pub fn is_initialized(info: &AccountInfo) -> Result<bool> {
+        Ok(*info.owner == crate::ID && info.try_borrow_data()?.starts_with(&Self::DISCRIMINATOR))
+    }
+}
+
+impl Deref for ChainOutboxRateLimit {
+    type Target = RateLimitState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.rate_limit
+    }
+}
+
+impl DerefMut for ChainOutboxRateLimit {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.rate_limit
+    }
+}
+
+/// Rate limit on the outbound transfers of a single sender (the owner of the
+/// token account the tokens are sent from), on top of the global
+/// [`OutboxRateLimit`]. Created on the sender's first transfer, or when an
+/// override is set for it.
+#[account]
+#[derive(InitSpace, PartialEq, Eq, Debug)]
+pub struct SenderRateLimit {
+    pub bump: u8,
+    /// Replaces [`crate::config::Config::sender_limit`] for this sender when
+    /// set. Set via `set_sender_limit_override`.
+    pub limit_override: Option<u64>,
+    pub rate_limit: RateLimitState,
+}
+
+impl SenderRateLimit {
+    pub const SEED_PREFIX: &'static [u8] = b"sender_limit";
+
+    /// The limit that applies to this sender given the configured default
+    /// `sender_limit`, or `None` if its transfers are not limited.
+    pub fn limit(&self, sender_limit: Option<u64>) -> Option<u64> {
+        self.limit_override.or(sender_limit)
+    }
+
+    /// Moves the rate limit over to `limit` if it changed since the sender's
+    /// last transfer, adjusting the capacity as
+    /// [`RateLimitState::set_limit`] does. A fresh account starts out at full
+    /// capacity.
+    pub fn sync_limit(&mut self, limit: u64) {
+        if self.rate_limit.limit != limit {
+            self.rate_limit.set_limit(limit);
+        }
+    }
+
+    /// The rate limit the sender's next transfer consumes from, moved over to
+    /// the limit that applies to it (see [`Self::sync_limit`]), or `None` if
+    /// its transfers are not limited.
+    pub fn current_rate_limit(&mut self, sender_limit: Option<u64>) -> Option<&mut RateLimitState> {
+        let limit = self.limit(sender_limit)?;
+        self.sync_limit(limit);
+        Some(&mut self.rate_limit)
+    }
+}
+
+impl Deref for SenderRateLimit {
+    type Target = RateLimitState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.rate_limit
+    }
+}
+
+impl DerefMut for SenderRateLimit {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.rate_limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outbox_item_fits_account() {
+        assert_eq!(OutboxItem::INIT_SPACE, std::mem::size_of::<OutboxItem>());
+        // legacy accounts can't be mistaken for the current layout
+        assert_ne!(OutboxItem::INIT_SPACE, LegacyOutboxItem::INIT_SPACE);
+    }
+
+    #[test]
+    fn test_expiry() {
+        let mut item = OutboxItem::new(
+            TrimmedAmount::new(1, 8),
+            Pubkey::default(),
+            ChainId { id: 2 },
+            [0; 32],
+            [0; 32],
+            0,
+        );
+        assert_eq!(item.expiry(), None);
+        assert!(!item.is_expired(i64::MAX));
+
+        item.expiry_timestamp = 100;
+        assert_eq!(item.expiry(), Some(100));
+        // the deadline itself is still in time
+        assert!(!item.is_expired(100));
+        assert!(item.is_expired(101));
+    }
+
+    #[test]
+    fn test_released_at() {
+        let mut item = OutboxItem::new(
+            TrimmedAmount::new(1, 8),
+            Pubkey::default(),
+            ChainId { id: 2 },
+            [0; 32],
+            [0; 32],
+            0,
+        );
+        item.released.set(100, true).unwrap();
+        item.released_by[0] = 100;
+        item.released_at[0] = 5;
+        assert_eq!(item.released_at(100).unwrap(), Some(5));
+
+        // released without a timestamp
+        item.released.set(0, true).unwrap();
+        assert_eq!(item.released_at(0).unwrap(), Some(0));
+
+        assert_eq!(item.released_at(1).unwrap(), None);
+    }
+
+    #[test]
+    fn test_sender_rate_limit_sync() {
+        let mut sender_rate_limit = SenderRateLimit {
+            bump: 0,
+            limit_override: None,
+            rate_limit: RateLimitState {
+                limit: 0,
+                capacity_at_last_tx: 0,
+                last_tx_timestamp: 0,
+            },
+        };
+
+        // a fresh account starts out at full capacity
+        assert_eq!(sender_rate_limit.limit(Some(1000)), Some(1000));
+        sender_rate_limit.sync_limit(1000);
+        assert_eq!(sender_rate_limit.capacity(), 1000);
+
+        sender_rate_limit.consume_or_delay(600);
+        assert_eq!(sender_rate_limit.capacity(), 400);
+
+        // the override takes precedence, and the capacity follows the limit
+        sender_rate_limit.limit_override = Some(500);
+        assert_eq!(sender_rate_limit.limit(Some(1000)), Some(500));
+        sender_rate_limit.sync_limit(500);
+        assert_eq!(sender_rate_limit.capacity(), 0);
+
+        sender_rate_limit.limit_override = None;
+        assert_eq!(sender_rate_limit.limit(None), None);
+    }
+}
@@ -92,7 +92,12 @@ impl RateLimitState {
     /// Otherwise, the timestamp at which the capacity will be available is
     /// returned.
     pub fn consume_or_delay(&mut self, amount: u64) -> RateLimitResult {
-        let now = current_timestamp();
+        self.consume_or_delay_at(current_timestamp(), amount)
+    }
+
+    /// [`consume_or_delay`] at time `now`, for instructions that have already
+    /// read the clock (see [`crate::clock::current_time`]).
+    pub fn consume_or_delay_at(&mut self, now: UnixTimestamp, amount: u64) -> RateLimitResult {
         let capacity = self.capacity_at(now);
         if capacity >= amount {
             self.capacity_at_last_tx = capacity - amount;
@@ -110,6 +115,14 @@ impl RateLimitState {
         self.last_tx_timestamp = now;
     }
 
+    /// Takes the given amount out of the capacity, without going below zero.
+    /// This is used to take back a backflow (see [`Self::refill`]) for a
+    /// transfer that was cancelled.
+    pub fn drain(&mut self, now: UnixTimestamp, amount: u64) {
+        self.capacity_at_last_tx = self.capacity_at(now).saturating_sub(amount);
+        self.last_tx_timestamp = now;
+    }
+
     pub fn set_limit(&mut self, limit: u64) {
         let old_limit = self.limit;
         let now = current_timestamp();
@@ -190,5 +203,13 @@ mod tests {
         // now refill 50k
         rate_limit_state.refill(now, 50_000);
         assert_eq!(rate_limit_state.capacity(), 100_000);
+
+        // now drain 30k
+        rate_limit_state.drain(now, 30_000);
+        assert_eq!(rate_limit_state.capacity(), 70_000);
+
+        // draining more than is left empties it
+        rate_limit_state.drain(now, 80_000);
+        assert_eq!(rate_limit_state.capacity(), 0);
     }
 }
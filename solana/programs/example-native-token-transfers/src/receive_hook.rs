@@ -0,0 +1,74 @@
+//! Optional notification for a third party (monitoring) program whenever a
+//! transceiver receives (and attests to) a message, before it is redeemed.
+//!
+//! The hook is configured in [`Config::receive_hook`]. The notification is a
+//! [`ReceiveHookNotified`] event addressed to the hook, which the hook (or an
+//! off-chain service acting on its behalf) picks up from the transaction logs.
+//!
+//! NOTE: the hook is not invoked directly, as the notification has to fail
+//! open: a misbehaving hook must never block receiving. On Solana, errors
+//! raised by a CPI can't be caught (a failed CPI aborts the whole transaction),
+//! whereas emitting an event can't fail, and doesn't hand any of the caller's
+//! accounts (or signatures) to the hook.
+
+use anchor_lang::prelude::*;
+use ntt_messages::{
+    chain_id::ChainId, ntt::NativeTokenTransfer, transceiver::TransceiverMessageData,
+    trimmed_amount::TrimmedAmount,
+};
+
+use crate::{config::Config, transfer::Payload};
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, PartialEq)]
+pub struct ReceiveHookArgs {
+    pub from_chain: ChainId,
+    pub id: [u8; 32],
+    pub source_ntt_manager: [u8; 32],
+    pub sender: [u8; 32],
+    pub amount: TrimmedAmount,
+    pub to: [u8; 32],
+}
+
+impl ReceiveHookArgs {
+    pub fn new(
+        from_chain: ChainId,
+        message: &TransceiverMessageData<NativeTokenTransfer<Payload>>,
+    ) -> Self {
+        Self {
+            from_chain,
+            id: message.ntt_manager_payload.id,
+            source_ntt_manager: message.source_ntt_manager,
+            sender: message.ntt_manager_payload.sender,
+            amount: message.ntt_manager_payload.payload.amount,
+            to: message.ntt_manager_payload.payload.to,
+        }
+    }
+}
+
+#[event]
+#[derive(Debug, PartialEq)]
+pub struct ReceiveHookNotified {
+    /// The hook the notification is addressed to, see [`Config::receive_hook`].
+    pub receive_hook: Pubkey,
+    pub transceiver_message: Pubkey,
+    pub args: ReceiveHookArgs,
+}
+
+/// Notifies the configured receive hook (if any) about `transceiver_message`.
+pub fn notify_receive_hook(
+    config: &Config,
+    transceiver_message: Pubkey,
+    args: ReceiveHookArgs,
+) -> Result<()> {
+    let Some(receive_hook) = config.receive_hook else {
+        return Ok(());
+    };
+
+    emit!(ReceiveHookNotified {
+        receive_hook,
+        transceiver_message,
+        args,
+    });
+
+    Ok(())
+}
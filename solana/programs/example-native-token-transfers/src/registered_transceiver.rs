@@ -1,5 +1,13 @@
 use anchor_lang::prelude::*;
 
+/// The most transceivers that can be enabled at once. Each enabled
+/// transceiver is another attestation a message may have to collect, so this
+/// bounds the work (and accounts) involved in reaching the threshold, well
+/// below what the [`crate::bitmap::Bitmap`] of enabled transceivers could hold.
+/// It also sizes the release timestamps kept on each outbox item, see
+/// [`crate::queue::outbox::OutboxItem::MAX_RECORDED_RELEASES`].
+pub const MAX_ENABLED_TRANSCEIVERS: u8 = 8;
+
 #[account]
 #[derive(InitSpace)]
 pub struct RegisteredTransceiver {
@@ -0,0 +1,28 @@
+//! Transfers held back by a rate limit fail with [`NTTError::CantReleaseYet`]
+//! when released too early (or with [`NTTError::TransferExceedsRateLimit`]
+//! when the sender didn't want the transfer queued). Before returning the
+//! error, the instruction writes the number of seconds left until the transfer
+//! can be released into the return data, as a little-endian `u64`.
+//!
+//! Return data is included in simulation results even when the transaction
+//! fails, so relayers can simulate the instruction to find out how long to back
+//! off for, instead of retrying on a fixed schedule.
+
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
+use std::time::Duration;
+
+use crate::{clock::current_timestamp, error::NTTError};
+
+/// Writes the time left until `release_timestamp` into the return data, and
+/// returns `error`.
+pub fn retry_after(release_timestamp: i64, error: NTTError) -> Error {
+    let wait = u64::try_from(release_timestamp.saturating_sub(current_timestamp())).unwrap_or(0);
+    set_return_data(&wait.to_le_bytes());
+    error.into()
+}
+
+/// Decodes the return data written by [`retry_after`].
+pub fn decode_retry_after(return_data: &[u8]) -> Option<Duration> {
+    let wait = u64::from_le_bytes(return_data.try_into().ok()?);
+    Some(Duration::from_secs(wait))
+}
@@ -0,0 +1,70 @@
+//! Metaplex token metadata of the mint, which is committed to in
+//! [`WormholeTransceiverInfo`](ntt_messages::transceivers::wormhole::WormholeTransceiverInfo)
+//! so that the destination chain can tie the token address to its
+//! human-readable identity.
+
+use anchor_lang::prelude::*;
+
+/// The Metaplex token metadata program.
+pub mod metadata_program {
+    use anchor_lang::declare_id;
+
+    declare_id!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+}
+
+/// The metadata account of `mint`.
+pub fn metadata_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"metadata", metadata_program::ID.as_ref(), mint.as_ref()],
+        &metadata_program::ID,
+    )
+    .0
+}
+
+/// The leading fields of a Metaplex `Metadata` account, up to the ones we
+/// hash. The rest of the account is ignored.
+#[derive(AnchorDeserialize)]
+struct MetadataPrefix {
+    _key: u8,
+    _update_authority: Pubkey,
+    mint: Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+}
+
+/// Hashes the name, symbol and URI of the token as
+/// `keccak256(len(name) || name || len(symbol) || symbol || len(uri) || uri)`,
+/// with the lengths as big-endian `u32`s, and the null padding Metaplex adds to
+/// the strings removed.
+///
+/// Returns all zeroes if the token has no metadata, i.e. if the account was not
+/// passed, or the metadata program hasn't created it.
+///
+/// NOTE: the caller is expected to check that `metadata` is the metadata
+/// account of `mint` (see [`metadata_address`]).
+pub fn token_metadata_hash(metadata: Option<&AccountInfo>, mint: &Pubkey) -> Result<[u8; 32]> {
+    let Some(metadata) = metadata else {
+        return Ok([0; 32]);
+    };
+    if *metadata.owner != metadata_program::ID || metadata.data_is_empty() {
+        return Ok([0; 32]);
+    }
+
+    let data = metadata.try_borrow_data()?;
+    let prefix = MetadataPrefix::deserialize(&mut &data[..])?;
+    require_keys_eq!(prefix.mint, *mint, ErrorCode::ConstraintAddress);
+
+    let name = prefix.name.trim_end_matches('\0').as_bytes();
+    let symbol = prefix.symbol.trim_end_matches('\0').as_bytes();
+    let uri = prefix.uri.trim_end_matches('\0').as_bytes();
+
+    let name_len = u32::try_from(name.len()).unwrap().to_be_bytes();
+    let symbol_len = u32::try_from(symbol.len()).unwrap().to_be_bytes();
+    let uri_len = u32::try_from(uri.len()).unwrap().to_be_bytes();
+
+    Ok(
+        solana_program::keccak::hashv(&[&name_len, name, &symbol_len, symbol, &uri_len, uri])
+            .to_bytes(),
+    )
+}
@@ -1,13 +1,120 @@
 use anchor_lang::prelude::*;
 
+use crate::peer::{insert_chain_id, remove_chain_id, MAX_INDEXED_PEERS};
+
 #[account]
 #[derive(InitSpace)]
 /// A peer on another chain. Stored in a PDA seeded by the chain id.
 pub struct TransceiverPeer {
     pub bump: u8,
     pub address: [u8; 32],
+    /// How [`Self::address`] is compared against the emitter of received
+    /// messages.
+    pub address_match: PeerAddressMatch,
 }
 
 impl TransceiverPeer {
     pub const SEED_PREFIX: &'static [u8] = b"transceiver_peer";
+
+    /// Whether `emitter_address` is this peer, see [`PeerAddressMatch`].
+    pub fn matches(&self, emitter_address: &[u8; 32]) -> bool {
+        self.address_match.matches(&self.address, emitter_address)
+    }
+}
+
+/// How strictly a transceiver peer address is compared against the emitter
+/// address of received messages.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub enum PeerAddressMatch {
+    /// The 32 byte addresses are equal.
+    #[default]
+    Exact,
+    /// The addresses are equal once 20 byte (EVM) addresses are left-padded to
+    /// 32 bytes, as Wormhole universal addresses are. This is for transceivers
+    /// that left-align them instead (i.e. pad them with zeros on the right).
+    Normalized,
+}
+
+impl PeerAddressMatch {
+    pub fn matches(&self, peer_address: &[u8; 32], emitter_address: &[u8; 32]) -> bool {
+        match self {
+            PeerAddressMatch::Exact => peer_address == emitter_address,
+            PeerAddressMatch::Normalized => {
+                left_pad_evm_address(peer_address) == left_pad_evm_address(emitter_address)
+            }
+        }
+    }
+}
+
+/// The size of an EVM address.
+const EVM_ADDRESS_SIZE: usize = 20;
+
+/// `address` as a universal address, if it's a left-aligned 20 byte (EVM)
+/// address. Any other address is returned as is, so only the position of the
+/// 20 bytes is normalized.
+fn left_pad_evm_address(address: &[u8; 32]) -> [u8; 32] {
+    if address[EVM_ADDRESS_SIZE..].iter().any(|b| *b != 0) {
+        return *address;
+    }
+    let mut universal = [0u8; 32];
+    universal[32 - EVM_ADDRESS_SIZE..].copy_from_slice(&address[..EVM_ADDRESS_SIZE]);
+    universal
+}
+
+#[account]
+#[derive(InitSpace)]
+/// The chain ids of the configured [`TransceiverPeer`]s, see
+/// [`crate::peer::PeerIndex`].
+///
+/// NOTE: peers set before the index was introduced are only added when they're
+/// migrated, see [`crate::transceivers::wormhole::instructions::migrate_transceiver_peer`].
+pub struct TransceiverPeerIndex {
+    pub bump: u8,
+    #[max_len(MAX_INDEXED_PEERS)]
+    pub chain_ids: Vec<u16>,
+}
+
+impl TransceiverPeerIndex {
+    pub const SEED_PREFIX: &'static [u8] = b"transceiver_peer_index";
+
+    pub fn insert(&mut self, chain_id: u16) -> Result<()> {
+        insert_chain_id(&mut self.chain_ids, chain_id)
+    }
+
+    pub fn remove(&mut self, chain_id: u16) {
+        remove_chain_id(&mut self.chain_ids, chain_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peer_address_match() {
+        let mut universal = [0u8; 32];
+        universal[12..].copy_from_slice(&[0xAB; 20]);
+        let mut left_aligned = [0u8; 32];
+        left_aligned[..20].copy_from_slice(&[0xAB; 20]);
+        let mut other = universal;
+        other[31] = 0xCD;
+
+        assert!(PeerAddressMatch::Exact.matches(&universal, &universal));
+        assert!(!PeerAddressMatch::Exact.matches(&universal, &left_aligned));
+        assert!(PeerAddressMatch::Normalized.matches(&universal, &left_aligned));
+        assert!(!PeerAddressMatch::Normalized.matches(&universal, &other));
+        assert!(PeerAddressMatch::Normalized.matches(&left_aligned, &universal));
+        assert!(PeerAddressMatch::Normalized.matches(&universal, &universal));
+        assert!(PeerAddressMatch::Normalized.matches(&[0; 32], &[0; 32]));
+
+        // only the left padding is normalized: 0x…ab00 is not 0x…00ab
+        let mut trailing_zero = [0u8; 32];
+        trailing_zero[30] = 0xAB;
+        let mut leading_zero = [0u8; 32];
+        leading_zero[31] = 0xAB;
+        assert!(!PeerAddressMatch::Normalized.matches(&trailing_zero, &leading_zero));
+        assert!(!PeerAddressMatch::Normalized.matches(&leading_zero, &trailing_zero));
+    }
 }
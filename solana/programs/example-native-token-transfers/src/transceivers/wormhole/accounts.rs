@@ -36,6 +36,20 @@ pub struct WormholeAccounts<'info> {
     // legacy
     pub clock: Sysvar<'info, Clock>,
     pub rent: Sysvar<'info, Rent>,
+
+    #[account(mut)]
+    /// Pays the core bridge fee if given, so that the payer of the instruction
+    /// only covers rent.
+    pub fee_payer: Option<Signer<'info>>,
+}
+
+/// The core bridge fee collector, as derived from the core bridge program.
+pub fn default_fee_collector() -> Pubkey {
+    let (fee_collector, _) = Pubkey::find_program_address(
+        &[wormhole::FeeCollector::SEED_PREFIX],
+        &wormhole::program::ID,
+    );
+    fee_collector
 }
 
 /// SECURITY: Owner checks are disabled. Each of [`WormholeAccounts::bridge`], [`WormholeAccounts::fee_collector`],
@@ -89,11 +103,15 @@ fn pay_wormhole_fee<'info>(
     payer: &AccountInfo<'info>,
 ) -> Result<()> {
     if wormhole.bridge.fee() > 0 {
+        let from = match &wormhole.fee_payer {
+            Some(fee_payer) => fee_payer.to_account_info(),
+            None => payer.to_account_info(),
+        };
         anchor_lang::system_program::transfer(
             CpiContext::new(
                 wormhole.system_program.to_account_info(),
                 anchor_lang::system_program::Transfer {
-                    from: payer.to_account_info(),
+                    from,
                     to: wormhole.fee_collector.to_account_info(),
                 },
             ),
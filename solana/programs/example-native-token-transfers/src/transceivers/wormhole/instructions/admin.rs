@@ -1,7 +1,12 @@
-use anchor_lang::prelude::*;
-use ntt_messages::chain_id::ChainId;
+use anchor_lang::{prelude::*, Discriminator};
+use ntt_messages::chain_id::{known_chain, ChainId};
 
-use crate::{config::Config, transceivers::accounts::peer::TransceiverPeer};
+use crate::{
+    config::Config,
+    error::NTTError,
+    instructions::migrate_queue_item::resize,
+    transceivers::accounts::peer::{PeerAddressMatch, TransceiverPeer, TransceiverPeerIndex},
+};
 
 #[derive(Accounts)]
 #[instruction(args: SetTransceiverPeerArgs)]
@@ -25,6 +30,15 @@ pub struct SetTransceiverPeer<'info> {
     )]
     pub peer: Account<'info, TransceiverPeer>,
 
+    #[account(
+        init_if_needed,
+        space = 8 + TransceiverPeerIndex::INIT_SPACE,
+        payer = payer,
+        seeds = [TransceiverPeerIndex::SEED_PREFIX],
+        bump,
+    )]
+    pub peer_index: Account<'info, TransceiverPeerIndex>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -34,14 +48,191 @@ pub struct SetTransceiverPeerArgs {
     pub address: [u8; 32],
 }
 
+/// Settings of [`set_transceiver_peer_with_options`] that
+/// [`set_transceiver_peer`] leaves at their defaults. They're not part of
+/// [`SetTransceiverPeerArgs`], so that its encoding stays the same for
+/// existing clients.
+#[derive(AnchorDeserialize, AnchorSerialize, Default)]
+pub struct SetTransceiverPeerOptions {
+    /// Skip the check that `chain_id` is a known Wormhole chain id.
+    /// Only needed for chains added to Wormhole after this program was built.
+    pub allow_unknown_chain: bool,
+    /// How `address` is compared against the emitter of received messages.
+    pub address_match: PeerAddressMatch,
+}
+
 pub fn set_transceiver_peer(
     ctx: Context<SetTransceiverPeer>,
     args: SetTransceiverPeerArgs,
 ) -> Result<()> {
+    set_transceiver_peer_with_options(ctx, args, SetTransceiverPeerOptions::default())
+}
+
+pub fn set_transceiver_peer_with_options(
+    ctx: Context<SetTransceiverPeer>,
+    args: SetTransceiverPeerArgs,
+    options: SetTransceiverPeerOptions,
+) -> Result<()> {
+    require!(
+        options.allow_unknown_chain || known_chain(args.chain_id.id),
+        NTTError::UnknownChainId
+    );
+    if !known_chain(args.chain_id.id) {
+        msg!(
+            "WARNING: registering peer for unknown chain id {}",
+            args.chain_id.id
+        );
+    }
+
     ctx.accounts.peer.set_inner(TransceiverPeer {
         bump: ctx.bumps.peer,
         address: args.address,
+        address_match: options.address_match,
     });
 
+    ctx.accounts.peer_index.bump = ctx.bumps.peer_index;
+    ctx.accounts.peer_index.insert(args.chain_id.id)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct DeleteTransceiverPeer<'info> {
+    #[account(has_one = owner)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [TransceiverPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, TransceiverPeer>,
+
+    #[account(
+        mut,
+        seeds = [TransceiverPeerIndex::SEED_PREFIX],
+        bump = peer_index.bump,
+    )]
+    pub peer_index: Account<'info, TransceiverPeerIndex>,
+}
+
+/// Removes the transceiver peer on `chain_id`, so that it can be set again
+/// (transceiver peers can't be overwritten).
+pub fn delete_transceiver_peer(ctx: Context<DeleteTransceiverPeer>, chain_id: u16) -> Result<()> {
+    ctx.accounts.peer_index.remove(chain_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct GetTransceiverPeerIndex<'info> {
+    #[account(
+        seeds = [TransceiverPeerIndex::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the index doesn't exist until the first peer is set, which is
+    /// checked in [`get_transceiver_peer_index`].
+    pub peer_index: UncheckedAccount<'info>,
+}
+
+/// Returns the chain ids of the configured transceiver peers.
+pub fn get_transceiver_peer_index(ctx: Context<GetTransceiverPeerIndex>) -> Result<Vec<u16>> {
+    let peer_index = &ctx.accounts.peer_index;
+    if peer_index.data_is_empty() {
+        return Ok(vec![]);
+    }
+
+    let peer_index =
+        TransceiverPeerIndex::try_deserialize(&mut &peer_index.try_borrow_data()?[..])?;
+    Ok(peer_index.chain_ids)
+}
+
+// * Migrate peers
+
+/// Layout of [`TransceiverPeer`] before [`TransceiverPeer::address_match`] was
+/// appended to it.
+#[derive(AnchorDeserialize)]
+struct LegacyTransceiverPeer {
+    bump: u8,
+    address: [u8; 32],
+}
+
+impl LegacyTransceiverPeer {
+    const SPACE: usize = 1 + 32;
+}
+
+/// Brings a [`TransceiverPeer`] created by an earlier version of the program
+/// up to the current layout, so that messages from its chain can be received
+/// again. The peer is also added to the [`TransceiverPeerIndex`], which didn't
+/// exist when it was set.
+///
+/// This is permissionless, as it doesn't change the peer's configuration:
+/// legacy peers matched emitter addresses exactly.
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct MigrateTransceiverPeer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TransceiverPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    /// CHECK: deserialized manually in [`migrate_transceiver_peer`], as it is
+    /// still in the legacy layout.
+    pub peer: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + TransceiverPeerIndex::INIT_SPACE,
+        payer = payer,
+        seeds = [TransceiverPeerIndex::SEED_PREFIX],
+        bump,
+    )]
+    pub peer_index: Account<'info, TransceiverPeerIndex>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_transceiver_peer(ctx: Context<MigrateTransceiverPeer>, chain_id: u16) -> Result<()> {
+    let info = ctx.accounts.peer.to_account_info();
+
+    let legacy = {
+        let data = info.try_borrow_data()?;
+        require!(
+            data.starts_with(&TransceiverPeer::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        require!(
+            data.len() == 8 + LegacyTransceiverPeer::SPACE,
+            NTTError::PeerAlreadyMigrated
+        );
+        LegacyTransceiverPeer::deserialize(&mut &data[8..])?
+    };
+
+    resize(
+        &info,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        8 + TransceiverPeer::INIT_SPACE,
+    )?;
+
+    let peer = TransceiverPeer {
+        bump: legacy.bump,
+        address: legacy.address,
+        address_match: PeerAddressMatch::Exact,
+    };
+    peer.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    ctx.accounts.peer_index.bump = ctx.bumps.peer_index;
+    ctx.accounts.peer_index.insert(chain_id)?;
+
     Ok(())
 }
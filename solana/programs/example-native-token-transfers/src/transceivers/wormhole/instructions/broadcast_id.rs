@@ -2,7 +2,11 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface;
 use ntt_messages::transceivers::wormhole::WormholeTransceiverInfo;
 
-use crate::{config::*, transceivers::wormhole::accounts::*};
+use crate::{
+    config::*,
+    token_metadata::{self, token_metadata_hash},
+    transceivers::wormhole::accounts::*,
+};
 
 #[derive(Accounts)]
 pub struct BroadcastId<'info> {
@@ -30,6 +34,14 @@ pub struct BroadcastId<'info> {
     pub emitter: UncheckedAccount<'info>,
 
     pub wormhole: WormholeAccounts<'info>,
+
+    #[account(
+        address = token_metadata::metadata_address(&mint.key()),
+    )]
+    /// CHECK: the address is derived from the mint, and the account is parsed
+    /// in [`token_metadata_hash`]. It's optional, as not every token has
+    /// Metaplex metadata.
+    pub token_metadata: Option<UncheckedAccount<'info>>,
 }
 
 pub fn broadcast_id(ctx: Context<BroadcastId>) -> Result<()> {
@@ -39,6 +51,10 @@ pub fn broadcast_id(ctx: Context<BroadcastId>) -> Result<()> {
         manager_mode: accs.config.mode,
         token_address: accs.mint.to_account_info().key.to_bytes(),
         token_decimals: accs.mint.decimals,
+        token_metadata_hash: Some(token_metadata_hash(
+            accs.token_metadata.as_deref(),
+            &accs.mint.key(),
+        )?),
     };
 
     // TODO: should we send this as an unreliable message into a PDA?
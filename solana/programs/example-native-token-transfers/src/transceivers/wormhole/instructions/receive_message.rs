@@ -9,30 +9,53 @@ use ntt_messages::{
 use wormhole_anchor_sdk::wormhole::PostedVaa;
 
 use crate::{
-    config::*, error::NTTError, messages::ValidatedTransceiverMessage,
-    transceivers::accounts::peer::TransceiverPeer, transfer::Payload,
+    config::*,
+    error::NTTError,
+    messages::{create_transceiver_message_account, ValidatedTransceiverMessage},
+    peer::NttManagerPeer,
+    receive_hook::{notify_receive_hook, ReceiveHookArgs},
+    transceivers::accounts::peer::TransceiverPeer,
+    transfer::Payload,
 };
 
+/// Offset of the payload in a core bridge `PostedVaa` account: the `vaa`
+/// discriminator, followed by the version (1), consistency level (1), VAA time
+/// (4), signature set (32), submission time (4), nonce (4), sequence (8),
+/// emitter chain (2), emitter address (32) and payload length (4).
+const POSTED_VAA_PAYLOAD_OFFSET: usize = 3 + 1 + 1 + 4 + 32 + 4 + 4 + 8 + 2 + 32 + 4;
+
 #[derive(Accounts)]
 pub struct ReceiveMessage<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    pub config: NotPausedConfig<'info>,
+    pub config: NotPausedInboundConfig<'info>,
 
     #[account(
         seeds = [TransceiverPeer::SEED_PREFIX, vaa.emitter_chain().to_be_bytes().as_ref()],
-        constraint = peer.address == *vaa.emitter_address() @ NTTError::InvalidTransceiverPeer,
+        constraint = peer.matches(vaa.emitter_address()) @ NTTError::InvalidTransceiverPeer,
         bump = peer.bump,
     )]
     pub peer: Account<'info, TransceiverPeer>,
 
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, vaa.emitter_chain().to_be_bytes().as_ref()],
+        constraint = manager_peer.address == vaa.message().source_ntt_manager @ NTTError::InvalidNttManagerPeer,
+        bump = manager_peer.bump,
+    )]
+    /// The manager peer is also checked in [`crate::instructions::redeem`],
+    /// but checking it here already rejects messages from the wrong manager
+    /// before they're stored.
+    pub manager_peer: Account<'info, NttManagerPeer>,
+
     // TODO: Consider using VaaAccount from wormhole-solana-vaa crate. Using a zero-copy reader
     // will allow this instruction to be generic (instead of strictly specifying NativeTokenTransfer
     // as the message type).
     #[account(
         // check that the messages is targeted to this chain
         constraint = vaa.message().ntt_manager_payload.payload.to_chain == config.chain_id @ NTTError::InvalidChainId,
+        // check that we understand the payload format
+        constraint = vaa.message().ntt_manager_payload.payload.version == NativeTokenTransfer::<Payload>::CURRENT_VERSION @ NTTError::UnsupportedPayloadVersion,
         // NOTE: we don't replay protect VAAs. Instead, we replay protect
         // executing the messages themselves with the [`released`] flag.
     )]
@@ -42,9 +65,7 @@ pub struct ReceiveMessage<'info> {
     >,
 
     #[account(
-        init,
-        payer = payer,
-        space = 8 + ValidatedTransceiverMessage::<TransceiverMessageData<NativeTokenTransfer<Payload>>>::INIT_SPACE,
+        mut,
         seeds = [
             ValidatedTransceiverMessage::<TransceiverMessageData<NativeTokenTransfer<Payload>>>::SEED_PREFIX,
             vaa.emitter_chain().to_be_bytes().as_ref(),
@@ -52,25 +73,81 @@ pub struct ReceiveMessage<'info> {
         ],
         bump,
     )]
-    // NOTE: in order to handle multiple transceivers, we can just augment the
-    // inbox item transfer struct with a bitmap storing which transceivers have
-    // attested to the transfer. Then we only release it if there's quorum.
-    // We would need to maybe_init this account in that case.
-    pub transceiver_message:
-        Account<'info, ValidatedTransceiverMessage<NativeTokenTransfer<Payload>>>,
+    /// CHECK: created in [`receive_message`] unless the message has already
+    /// been received (see [`ValidatedTransceiverMessage::is_initialized`]).
+    pub transceiver_message: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
+/// Stores the message from the posted VAA in [`ValidatedTransceiverMessage`],
+/// and notifies the receive hook about newly received messages, if one is
+/// configured (see [`crate::receive_hook`]).
 pub fn receive_message(ctx: Context<ReceiveMessage>) -> Result<()> {
-    let message = ctx.accounts.vaa.message().message_data.clone();
-    let chain_id = ctx.accounts.vaa.emitter_chain();
-    ctx.accounts
-        .transceiver_message
-        .set_inner(ValidatedTransceiverMessage {
-            from_chain: ChainId { id: chain_id },
-            message,
-        });
-
-    Ok(())
+    let accs = ctx.accounts;
+    let transceiver_message = accs.transceiver_message.to_account_info();
+
+    // the message has already been received from this transceiver
+    if *transceiver_message.owner == crate::ID
+        && ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::is_initialized(
+            &transceiver_message,
+        )?
+    {
+        return Ok(());
+    }
+
+    accs.config.check_vaa_age(accs.vaa.timestamp())?;
+
+    let message = accs.vaa.message().message_data.clone();
+    let chain_id = accs.vaa.emitter_chain();
+    let message_hash = message
+        .ntt_manager_payload
+        .keccak256(ChainId { id: chain_id })
+        .to_bytes();
+
+    create_transceiver_message_account(
+        &accs.payer.to_account_info(),
+        &transceiver_message,
+        &accs.system_program.to_account_info(),
+        8 + ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::INIT_SPACE,
+        &crate::ID,
+        &[
+            ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::SEED_PREFIX,
+            chain_id.to_be_bytes().as_ref(),
+            message.ntt_manager_payload.id.as_ref(),
+            &[ctx.bumps.transceiver_message],
+        ],
+    )?;
+
+    let args = ReceiveHookArgs::new(ChainId { id: chain_id }, &message);
+    ValidatedTransceiverMessage {
+        bump: ctx.bumps.transceiver_message,
+        from_chain: ChainId { id: chain_id },
+        message,
+        vaa_timestamp: accs.vaa.timestamp(),
+        message_hash,
+        unconsumed_payload_len: unconsumed_payload_len(&accs.vaa)?,
+    }
+    .try_serialize(&mut &mut transceiver_message.try_borrow_mut_data()?[..])?;
+
+    notify_receive_hook(&accs.config, transceiver_message.key(), args)
+}
+
+/// The bytes of additional payload in `vaa` that decoding it as [`Payload`]
+/// left unread. These are not visible in the deserialized account, so they're
+/// counted from the raw payload.
+fn unconsumed_payload_len(
+    vaa: &Account<PostedVaa<TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>>>>,
+) -> Result<u16> {
+    let info = vaa.to_account_info();
+    let data = info.try_borrow_data()?;
+    let payload = data
+        .get(POSTED_VAA_PAYLOAD_OFFSET..)
+        .ok_or(NTTError::MalformedMessage)?;
+    TransceiverMessage::<WormholeTransceiver, NativeTokenTransfer<Payload>>::unconsumed_payload_len(
+        payload,
+    )
+    .ok()
+    .and_then(|len| u16::try_from(len).ok())
+    .ok_or_else(|| NTTError::MalformedMessage.into())
 }
@@ -1,13 +1,14 @@
 use anchor_lang::prelude::*;
 
 use ntt_messages::{
-    ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage, transceiver::TransceiverMessage,
-    transceivers::wormhole::WormholeTransceiver,
+    chain_id::ChainId, ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage,
+    transceiver::TransceiverMessage, transceivers::wormhole::WormholeTransceiver,
 };
 
 use crate::{
-    config::*, error::NTTError, queue::outbox::OutboxItem, registered_transceiver::*,
-    transceivers::wormhole::accounts::*, transfer::Payload,
+    config::*, error::NTTError, peer::NttManagerPeer, queue::outbox::OutboxItem,
+    registered_transceiver::*, retry_after::retry_after, transceivers::wormhole::accounts::*,
+    transfer::Payload,
 };
 
 #[derive(Accounts)]
@@ -15,18 +16,28 @@ pub struct ReleaseOutbound<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    pub config: NotPausedConfig<'info>,
+    pub config: NotPausedOutboundConfig<'info>,
 
     #[account(
         mut,
-        constraint = !outbox_item.released.get(transceiver.id)? @ NTTError::MessageAlreadySent,
+        constraint = OutboxItem::is_current_layout(&outbox_item.to_account_info()) @ NTTError::QueueItemNotMigrated,
+        constraint = !outbox_item.load()?.released.get(transceiver.id)? @ NTTError::MessageAlreadySent,
     )]
-    pub outbox_item: Account<'info, OutboxItem>,
+    pub outbox_item: AccountLoader<'info, OutboxItem>,
 
     #[account(
-        constraint = transceiver.transceiver_address == crate::ID,
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, outbox_item.load()?.recipient_chain.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        constraint = transceiver.transceiver_address == crate::ID @ NTTError::InvalidTransceiverProgram,
         constraint = config.enabled_transceivers.get(transceiver.id)? @ NTTError::DisabledTransceiver
     )]
+    /// The registration of this program. Its id is the bit marked in the
+    /// outbox item's `released` bitmap.
     pub transceiver: Account<'info, RegisteredTransceiver>,
 
     #[account(
@@ -45,6 +56,9 @@ pub struct ReleaseOutbound<'info> {
     /// CHECK: wormhole uses this as the emitter address
     pub emitter: UncheckedAccount<'info>,
 
+    #[account(
+        constraint = wormhole.fee_collector.key() == config.fee_collector @ NTTError::InvalidFeeCollector,
+    )]
     pub wormhole: WormholeAccounts<'info>,
 }
 
@@ -53,37 +67,77 @@ pub struct ReleaseOutboundArgs {
     pub revert_on_delay: bool,
 }
 
+/// Settings of [`release_outbound_with_options`] that [`release_outbound`]
+/// leaves at their defaults. They're not part of [`ReleaseOutboundArgs`], so
+/// that its encoding stays the same for existing clients.
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct ReleaseOutboundOptions {
+    /// The most the payer is willing to pay for the wormhole message fee.
+    /// Guards against the core bridge fee being raised between building and
+    /// landing the transaction. Unlimited when unset.
+    pub max_wormhole_fee: Option<u64>,
+}
+
 pub fn release_outbound(ctx: Context<ReleaseOutbound>, args: ReleaseOutboundArgs) -> Result<()> {
+    release_outbound_with_options(ctx, args, ReleaseOutboundOptions::default())
+}
+
+pub fn release_outbound_with_options(
+    ctx: Context<ReleaseOutbound>,
+    args: ReleaseOutboundArgs,
+    options: ReleaseOutboundOptions,
+) -> Result<()> {
     let accs = ctx.accounts;
-    let released = accs.outbox_item.try_release(accs.transceiver.id)?;
+
+    if let Some(max_wormhole_fee) = options.max_wormhole_fee {
+        let wormhole_fee = accs.wormhole.bridge.fee();
+        if wormhole_fee > max_wormhole_fee {
+            msg!(
+                "Wormhole fee {} exceeds the maximum of {}",
+                wormhole_fee,
+                max_wormhole_fee
+            );
+            return Err(NTTError::WormholeFeeTooHigh.into());
+        }
+    }
+
+    let mut outbox_item = accs.outbox_item.load_mut()?;
+    let released = outbox_item.try_release(accs.transceiver.id, &mut accs.peer)?;
 
     if !released {
         if args.revert_on_delay {
-            return Err(NTTError::CantReleaseYet.into());
+            return Err(retry_after(
+                outbox_item.release_timestamp,
+                NTTError::CantReleaseYet,
+            ));
         } else {
             return Ok(());
         }
     }
 
-    assert!(accs.outbox_item.released.get(accs.transceiver.id)?);
+    assert!(outbox_item.released.get(accs.transceiver.id)?);
     let message: TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>> =
         TransceiverMessage::new(
             // TODO: should we just put the ntt id here statically?
             accs.outbox_item.to_account_info().owner.to_bytes(),
-            accs.outbox_item.recipient_ntt_manager,
+            outbox_item.recipient_ntt_manager,
             NttManagerMessage {
                 id: accs.outbox_item.key().to_bytes(),
-                sender: accs.outbox_item.sender.to_bytes(),
+                sender: outbox_item.sender.to_bytes(),
                 payload: NativeTokenTransfer {
-                    amount: accs.outbox_item.amount,
+                    version: NativeTokenTransfer::<Payload>::CURRENT_VERSION,
+                    amount: outbox_item.trimmed_amount(),
                     source_token: accs.config.mint.to_bytes(),
-                    to: accs.outbox_item.recipient_address,
-                    to_chain: accs.outbox_item.recipient_chain,
+                    to: outbox_item.recipient_address,
+                    to_chain: ChainId {
+                        id: outbox_item.recipient_chain,
+                    },
                     additional_payload: Payload {},
                 },
             },
             vec![],
         );
+    drop(outbox_item);
 
     post_message(
         &accs.wormhole,
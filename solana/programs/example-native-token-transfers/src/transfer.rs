@@ -1,3 +1,18 @@
-use ntt_messages::ntt::EmptyPayload;
+use anchor_lang::Space;
+use ntt_messages::ntt::{EmptyPayload, NativeTokenTransfer};
 
 pub type Payload = EmptyPayload;
+
+/// Upper bound on the (borsh) encoded size of [`Payload`], in bytes.
+/// Accounts that embed a [`NativeTokenTransfer<Payload>`] are allocated with
+/// room for exactly this much payload, so this has to grow with [`Payload`].
+pub const MAX_ADDITIONAL_PAYLOAD_LEN: usize = 0;
+
+// If these fail, the space of accounts embedding the payload is out of sync
+// with the payload type. Such accounts would be under-allocated, and fail to
+// serialize at the end of the instruction.
+const _: () = assert!(Payload::INIT_SPACE == MAX_ADDITIONAL_PAYLOAD_LEN);
+const _: () = assert!(
+    NativeTokenTransfer::<Payload>::INIT_SPACE
+        == NativeTokenTransfer::<EmptyPayload>::INIT_SPACE + MAX_ADDITIONAL_PAYLOAD_LEN
+);
@@ -1,21 +1,67 @@
 #![cfg(feature = "test-sbf")]
-#![feature(type_changing_struct_update)]
 
-use anchor_lang::{system_program::System, Id};
-use example_native_token_transfers::error::NTTError;
-use ntt_messages::mode::Mode;
+use anchor_lang::{
+    prelude::{ErrorCode, Pubkey},
+    system_program::System,
+    AnchorSerialize, Discriminator, Id, Space,
+};
+use example_native_token_transfers::{
+    bitmap::Bitmap,
+    config::{default_allowed_consistency_levels, Config},
+    error::NTTError,
+    instructions::{
+        InitializeArgs, PeerDecimals, SetInboundLimitArgs, SetOutboundLimitArgs, SetPeerArgs,
+        SetPeerOptions,
+    },
+    peer::{NttManagerPeer, MAX_INDEXED_PEERS},
+    queue::{inbox::InboxRateLimit, outbox::OutboxRateLimit},
+    registered_transceiver::MAX_ENABLED_TRANSCEIVERS,
+    transceivers::accounts::peer::TransceiverPeer,
+};
+use ntt_messages::{
+    chain_id::{known_chain, ChainId},
+    mode::Mode,
+};
 use solana_program_test::*;
-use solana_sdk::{instruction::InstructionError, signer::Signer, transaction::TransactionError};
+use solana_sdk::{
+    account::Account, bpf_loader, instruction::InstructionError, rent::Rent, signature::Keypair,
+    signer::Signer, transaction::TransactionError,
+};
 use test_utils::{
-    common::submit::Submittable,
-    helpers::{assert_threshold, assert_transceiver_id, setup},
+    common::{
+        fixtures::{
+            evm_address, ANOTHER_CHAIN, ANOTHER_MANAGER, INBOUND_LIMIT, OTHER_CHAIN, OTHER_MANAGER,
+            OTHER_TRANSCEIVER, OUTBOUND_LIMIT, THIS_CHAIN, UNREGISTERED_CHAIN,
+        },
+        query::GetAccountDataAnchor,
+        submit::Submittable,
+    },
+    helpers::{
+        assert_threshold, assert_transceiver_id, configured_chains, enabled_transceivers,
+        peer_decimals, peer_index, pending_owner, setup, setup_accounts, setup_programs,
+        setup_with_extra_accounts, transceiver_peer_index,
+    },
     sdk::{
-        accounts::good_ntt,
+        accounts::{good_ntt, NTTAccounts},
         instructions::admin::{
-            deregister_transceiver, register_transceiver, set_threshold, DeregisterTransceiver,
-            RegisterTransceiver, SetThreshold,
+            claim_ownership, delete_peer, deregister_transceiver, migrate_config,
+            register_transceiver, set_inbound_limit, set_limits_manager, set_outbound_limit,
+            set_peer, set_peer_with_options, set_threshold, swap_transceivers, transfer_ownership,
+            ClaimOwnership, DeletePeer, DeregisterTransceiver, MigrateConfig, RegisterTransceiver,
+            SetInboundLimit, SetLimitsManager, SetOutboundLimit, SetPeer, SetThreshold,
+            SwapTransceivers, TransferOwnership,
+        },
+        instructions::initialize::{initialize, Initialize},
+        instructions::migrate_peer::{migrate_peer, MigratePeer},
+        transceivers::{
+            accounts::{good_ntt_transceiver, NTTTransceiverAccounts},
+            instructions::admin::{
+                delete_transceiver_peer, migrate_transceiver_peer, set_transceiver_peer,
+                set_transceiver_peer_with_options, DeleteTransceiverPeer, MigrateTransceiverPeer,
+                PeerAddressMatch, SetTransceiverPeer, SetTransceiverPeerArgs,
+                SetTransceiverPeerOptions,
+            },
         },
-        transceivers::accounts::{good_ntt_transceiver, NTTTransceiverAccounts},
     },
 };
 
@@ -135,6 +181,156 @@ async fn test_reregister_all_transceivers() {
     assert_threshold(&good_ntt, &mut ctx, 1).await;
 }
 
+#[tokio::test]
+async fn test_max_enabled_transceivers() {
+    // transceivers have to be executable, so they're added on setup
+    let dummy_transceivers: Vec<(Pubkey, Account)> = (0..MAX_ENABLED_TRANSCEIVERS)
+        .map(|_| {
+            let account = Account {
+                lamports: Rent::default().minimum_balance(0),
+                data: vec![],
+                owner: bpf_loader::id(),
+                executable: true,
+                rent_epoch: 0,
+            };
+            (Pubkey::new_unique(), account)
+        })
+        .collect();
+    let (mut ctx, test_data) = setup_with_extra_accounts(Mode::Locking, &dummy_transceivers).await;
+
+    let payer = ctx.payer.pubkey();
+    let owner = test_data.program_owner.pubkey();
+    let register = |transceiver: Pubkey| {
+        register_transceiver(
+            &good_ntt,
+            RegisterTransceiver {
+                payer,
+                owner,
+                transceiver,
+            },
+        )
+    };
+
+    // the baked-in transceiver is enabled already, so this is up to the cap
+    let (last, below_cap) = dummy_transceivers.split_last().unwrap();
+    for (transceiver, _) in below_cap {
+        register(*transceiver)
+            .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+            .await
+            .unwrap();
+    }
+
+    let err = register(last.0)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TooManyEnabledTransceivers.into())
+        )
+    );
+
+    // re-registering an enabled transceiver at the cap is fine
+    register(below_cap[0].0)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    // deregistering one frees up a slot
+    deregister_transceiver(
+        &good_ntt,
+        DeregisterTransceiver {
+            owner: test_data.program_owner.pubkey(),
+            transceiver: below_cap[0].0,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    register(last.0)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config.enabled_transceivers.len(), MAX_ENABLED_TRANSCEIVERS);
+}
+
+#[tokio::test]
+async fn test_swap_transceivers() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let old_transceiver = good_ntt_transceiver.program();
+    let new_transceiver = wormhole_governance::ID;
+
+    // with a single transceiver, deregistering it first isn't possible, and
+    // registering the new one first would leave both enabled for a while
+    swap_transceivers(
+        &good_ntt,
+        SwapTransceivers {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            old_transceiver,
+            new_transceiver,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_transceiver_id(&good_ntt, &mut ctx, &new_transceiver, 1).await;
+    assert_eq!(
+        enabled_transceivers(&good_ntt, &mut ctx, &[old_transceiver, new_transceiver]).await,
+        vec![new_transceiver]
+    );
+    // the threshold can still be met
+    assert_threshold(&good_ntt, &mut ctx, 1).await;
+
+    // and back again, reusing the old transceiver's id
+    swap_transceivers(
+        &good_ntt,
+        SwapTransceivers {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            old_transceiver: new_transceiver,
+            new_transceiver: old_transceiver,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_transceiver_id(&good_ntt, &mut ctx, &old_transceiver, 0).await;
+    assert_eq!(
+        enabled_transceivers(&good_ntt, &mut ctx, &[old_transceiver, new_transceiver]).await,
+        vec![old_transceiver]
+    );
+    assert_threshold(&good_ntt, &mut ctx, 1).await;
+
+    // the old transceiver has to be enabled
+    let err = swap_transceivers(
+        &good_ntt,
+        SwapTransceivers {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            old_transceiver: new_transceiver,
+            new_transceiver: wormhole_anchor_sdk::wormhole::program::Wormhole::id(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::DisabledTransceiver.into())
+        )
+    );
+}
+
 #[tokio::test]
 async fn test_deregister_last_enabled_transceiver() {
     let (mut ctx, test_data) = setup(Mode::Locking).await;
@@ -204,6 +400,56 @@ async fn test_deregister_last_enabled_transceiver() {
     );
 }
 
+#[tokio::test]
+async fn test_enabled_transceivers() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // register arbitrary executable program as dummy transceiver, then disable it
+    let dummy_transceiver = wormhole_anchor_sdk::wormhole::program::Wormhole::id();
+    register_transceiver(
+        &good_ntt,
+        RegisterTransceiver {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            transceiver: dummy_transceiver,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        enabled_transceivers(
+            &good_ntt,
+            &mut ctx,
+            &[good_ntt_transceiver.program(), dummy_transceiver]
+        )
+        .await,
+        vec![good_ntt_transceiver.program(), dummy_transceiver]
+    );
+
+    deregister_transceiver(
+        &good_ntt,
+        DeregisterTransceiver {
+            owner: test_data.program_owner.pubkey(),
+            transceiver: dummy_transceiver,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        enabled_transceivers(
+            &good_ntt,
+            &mut ctx,
+            &[good_ntt_transceiver.program(), dummy_transceiver]
+        )
+        .await,
+        vec![good_ntt_transceiver.program()]
+    );
+}
+
 #[tokio::test]
 async fn test_zero_threshold() {
     let (mut ctx, test_data) = setup(Mode::Locking).await;
@@ -249,3 +495,894 @@ async fn test_threshold_too_high() {
         )
     );
 }
+
+#[test]
+fn test_fixture_chains_are_known() {
+    for chain_id in [THIS_CHAIN, OTHER_CHAIN, ANOTHER_CHAIN] {
+        assert!(known_chain(chain_id));
+    }
+    assert!(!known_chain(UNREGISTERED_CHAIN));
+}
+
+#[tokio::test]
+async fn test_set_peer_unknown_chain() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    for allow_unknown_chain in [false, true] {
+        let res = set_peer_with_options(
+            &good_ntt,
+            SetPeer {
+                payer: ctx.payer.pubkey(),
+                owner: test_data.program_owner.pubkey(),
+            },
+            SetPeerArgs {
+                chain_id: ChainId {
+                    id: UNREGISTERED_CHAIN,
+                },
+                address: OTHER_MANAGER,
+                limit: INBOUND_LIMIT,
+                token_decimals: 7,
+            },
+            SetPeerOptions {
+                allow_unknown_chain,
+                allow_nonstandard_address: false,
+            },
+        )
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await;
+
+        if allow_unknown_chain {
+            res.unwrap();
+        } else {
+            assert_eq!(
+                res.unwrap_err().unwrap(),
+                TransactionError::InstructionError(
+                    0,
+                    InstructionError::Custom(NTTError::UnknownChainId.into())
+                )
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_set_peer_address_format() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let mut right_padded = [0u8; 32];
+    right_padded[..20].copy_from_slice(&[9u8; 20]);
+
+    let set_peer_args = |address| SetPeerArgs {
+        chain_id: ChainId { id: OTHER_CHAIN },
+        address,
+        limit: INBOUND_LIMIT,
+        token_decimals: 7,
+    };
+
+    // OTHER_CHAIN is an EVM chain, so a right-padded address is rejected...
+    for address in [right_padded, [0u8; 32]] {
+        let err = set_peer(
+            &good_ntt,
+            SetPeer {
+                payer: ctx.payer.pubkey(),
+                owner: test_data.program_owner.pubkey(),
+            },
+            set_peer_args(address),
+        )
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(NTTError::InvalidPeerAddress.into())
+            )
+        );
+    }
+
+    // ...while a left-padded one is accepted
+    set_peer(
+        &good_ntt,
+        SetPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        set_peer_args(evm_address([9u8; 20])),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // unless the check is skipped
+    set_peer_with_options(
+        &good_ntt,
+        SetPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        set_peer_args(right_padded),
+        SetPeerOptions {
+            allow_unknown_chain: false,
+            allow_nonstandard_address: true,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(peer.address, right_padded);
+}
+
+#[tokio::test]
+async fn test_get_peer_decimals() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    assert_eq!(
+        peer_decimals(&good_ntt, &mut ctx, test_data.mint, OTHER_CHAIN).await,
+        PeerDecimals {
+            mint_decimals: 9,
+            peer_token_decimals: 7,
+            trimmed_decimals: 7,
+        }
+    );
+
+    // a peer with more decimals than the mint is capped at the trim target
+    set_peer(
+        &good_ntt,
+        SetPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetPeerArgs {
+            chain_id: ChainId { id: ANOTHER_CHAIN },
+            address: OTHER_MANAGER,
+            limit: INBOUND_LIMIT,
+            token_decimals: 18,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        peer_decimals(&good_ntt, &mut ctx, test_data.mint, ANOTHER_CHAIN).await,
+        PeerDecimals {
+            mint_decimals: 9,
+            peer_token_decimals: 18,
+            trimmed_decimals: 8,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_set_transceiver_peer_unknown_chain() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    for allow_unknown_chain in [false, true] {
+        let res = set_transceiver_peer_with_options(
+            &good_ntt,
+            &good_ntt_transceiver,
+            SetTransceiverPeer {
+                payer: ctx.payer.pubkey(),
+                owner: test_data.program_owner.pubkey(),
+            },
+            SetTransceiverPeerArgs {
+                chain_id: ChainId {
+                    id: UNREGISTERED_CHAIN,
+                },
+                address: OTHER_TRANSCEIVER,
+            },
+            SetTransceiverPeerOptions {
+                allow_unknown_chain,
+                address_match: PeerAddressMatch::Exact,
+            },
+        )
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await;
+
+        if allow_unknown_chain {
+            res.unwrap();
+        } else {
+            assert_eq!(
+                res.unwrap_err().unwrap(),
+                TransactionError::InstructionError(
+                    0,
+                    InstructionError::Custom(NTTError::UnknownChainId.into())
+                )
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_peer_index() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    assert_eq!(
+        peer_index(&good_ntt, &mut ctx).await,
+        vec![OTHER_CHAIN, ANOTHER_CHAIN]
+    );
+
+    for (chain_id, allow_unknown_chain) in [(UNREGISTERED_CHAIN, true), (OTHER_CHAIN, false)] {
+        set_peer_with_options(
+            &good_ntt,
+            SetPeer {
+                payer: ctx.payer.pubkey(),
+                owner: test_data.program_owner.pubkey(),
+            },
+            SetPeerArgs {
+                chain_id: ChainId { id: chain_id },
+                address: OTHER_MANAGER,
+                limit: INBOUND_LIMIT,
+                token_decimals: 7,
+            },
+            SetPeerOptions {
+                allow_unknown_chain,
+                allow_nonstandard_address: false,
+            },
+        )
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+    }
+
+    // updating an existing peer doesn't add it again
+    assert_eq!(
+        peer_index(&good_ntt, &mut ctx).await,
+        vec![OTHER_CHAIN, ANOTHER_CHAIN, UNREGISTERED_CHAIN]
+    );
+
+    delete_peer(
+        &good_ntt,
+        DeletePeer {
+            owner: test_data.program_owner.pubkey(),
+        },
+        ANOTHER_CHAIN,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        peer_index(&good_ntt, &mut ctx).await,
+        vec![OTHER_CHAIN, UNREGISTERED_CHAIN]
+    );
+    assert!(ctx
+        .banks_client
+        .get_account(good_ntt.peer(ANOTHER_CHAIN))
+        .await
+        .unwrap()
+        .is_none());
+    assert!(ctx
+        .banks_client
+        .get_account(good_ntt.inbox_rate_limit(ANOTHER_CHAIN))
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_peer_index_of_new_deployment() {
+    let program_owner = Keypair::new();
+    let program_test = setup_programs(program_owner.pubkey()).await.unwrap();
+    let mut ctx = program_test.start_with_context().await;
+    let test_data = setup_accounts(&mut ctx, program_owner).await;
+
+    initialize(
+        &good_ntt,
+        Initialize {
+            payer: ctx.payer.pubkey(),
+            deployer: test_data.program_owner.pubkey(),
+            mint: test_data.mint,
+            multisig_token_authority: None,
+        },
+        InitializeArgs {
+            chain_id: THIS_CHAIN,
+            limit: OUTBOUND_LIMIT,
+            mode: Mode::Locking,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // the index doesn't exist until the first peer is set
+    assert_eq!(peer_index(&good_ntt, &mut ctx).await, vec![]);
+    assert_eq!(configured_chains(&good_ntt, &mut ctx).await, vec![]);
+
+    let peers = [
+        (OTHER_CHAIN, OTHER_MANAGER),
+        (ANOTHER_CHAIN, ANOTHER_MANAGER),
+        (UNREGISTERED_CHAIN, OTHER_MANAGER),
+    ];
+    for (chain_id, address) in peers {
+        set_peer_with_options(
+            &good_ntt,
+            SetPeer {
+                payer: ctx.payer.pubkey(),
+                owner: test_data.program_owner.pubkey(),
+            },
+            SetPeerArgs {
+                chain_id: ChainId { id: chain_id },
+                address,
+                limit: INBOUND_LIMIT,
+                token_decimals: 7,
+            },
+            SetPeerOptions {
+                allow_unknown_chain: chain_id == UNREGISTERED_CHAIN,
+                allow_nonstandard_address: false,
+            },
+        )
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+    }
+
+    assert_eq!(
+        peer_index(&good_ntt, &mut ctx).await,
+        vec![OTHER_CHAIN, ANOTHER_CHAIN, UNREGISTERED_CHAIN]
+    );
+    assert_eq!(
+        configured_chains(&good_ntt, &mut ctx).await,
+        vec![OTHER_CHAIN, ANOTHER_CHAIN, UNREGISTERED_CHAIN]
+    );
+}
+
+#[tokio::test]
+async fn test_peer_index_full() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let set_unknown_peer = |chain_id: u16, ctx: &ProgramTestContext| {
+        set_peer_with_options(
+            &good_ntt,
+            SetPeer {
+                payer: ctx.payer.pubkey(),
+                owner: test_data.program_owner.pubkey(),
+            },
+            SetPeerArgs {
+                chain_id: ChainId { id: chain_id },
+                address: OTHER_MANAGER,
+                limit: INBOUND_LIMIT,
+                token_decimals: 7,
+            },
+            SetPeerOptions {
+                allow_unknown_chain: true,
+                allow_nonstandard_address: false,
+            },
+        )
+    };
+
+    // the setup added two peers already
+    for chain_id in 1000..1000 + u16::try_from(MAX_INDEXED_PEERS - 2).unwrap() {
+        set_unknown_peer(chain_id, &ctx)
+            .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+            .await
+            .unwrap();
+    }
+
+    let full = peer_index(&good_ntt, &mut ctx).await;
+    assert_eq!(full.len(), MAX_INDEXED_PEERS);
+
+    let err = set_unknown_peer(2000, &ctx)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::PeerIndexFull.into())
+        )
+    );
+    assert_eq!(peer_index(&good_ntt, &mut ctx).await, full);
+    assert!(ctx
+        .banks_client
+        .get_account(good_ntt.peer(2000))
+        .await
+        .unwrap()
+        .is_none());
+
+    // existing peers can still be updated
+    set_unknown_peer(1000, &ctx)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+    assert_eq!(peer_index(&good_ntt, &mut ctx).await, full);
+}
+
+#[tokio::test]
+async fn test_transceiver_peer_index() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    assert_eq!(
+        transceiver_peer_index(&good_ntt_transceiver, &mut ctx).await,
+        vec![OTHER_CHAIN]
+    );
+
+    set_transceiver_peer(
+        &good_ntt,
+        &good_ntt_transceiver,
+        SetTransceiverPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetTransceiverPeerArgs {
+            chain_id: ChainId { id: ANOTHER_CHAIN },
+            address: OTHER_TRANSCEIVER,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    delete_transceiver_peer(
+        &good_ntt,
+        &good_ntt_transceiver,
+        DeleteTransceiverPeer {
+            owner: test_data.program_owner.pubkey(),
+        },
+        OTHER_CHAIN,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        transceiver_peer_index(&good_ntt_transceiver, &mut ctx).await,
+        vec![ANOTHER_CHAIN]
+    );
+
+    // deleted peers can be set again
+    set_transceiver_peer(
+        &good_ntt,
+        &good_ntt_transceiver,
+        SetTransceiverPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetTransceiverPeerArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            address: OTHER_TRANSCEIVER,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        transceiver_peer_index(&good_ntt_transceiver, &mut ctx).await,
+        vec![ANOTHER_CHAIN, OTHER_CHAIN]
+    );
+}
+
+#[tokio::test]
+async fn test_migrate_config() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+
+    // rewrite the config in the legacy layout, i.e. without the trailing
+    // fields following `custody`. The slack at the end is filled with
+    // garbage, as it can be on chain.
+    let legacy_len = 8 + Config::INIT_SPACE
+        - (1 + 1 + 33 + 32 + 1 + 33 + 33 + 5 + 1 + 9 + 1 + 16 + 1 + 9 + 1 + 1 + 1);
+    let mut data = Config::DISCRIMINATOR.to_vec();
+    data.extend(
+        Config {
+            // the legacy `paused` flag
+            paused_outbound: true,
+            routes_default_enabled: false,
+            mint_decimals: 0,
+            receive_hook: None,
+            fee_collector: Pubkey::default(),
+            confidential_transfers: false,
+            dust_account: None,
+            limits_manager: None,
+            max_vaa_age: None,
+            reject_unconsumed_payload: false,
+            decommission_timestamp: None,
+            terminated: false,
+            allowed_consistency_levels: Bitmap::new(),
+            paused_inbound: false,
+            sender_limit: None,
+            per_chain_outbound_limits: false,
+            sender_limit_overrides: false,
+            tracks_custody: false,
+            ..config.clone()
+        }
+        .try_to_vec()
+        .unwrap(),
+    );
+    data.truncate(
+        data.len() - (1 + 1 + 1 + 32 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 16 + 1 + 1 + 1 + 1 + 1),
+    );
+    data.resize(legacy_len, 0xff);
+
+    let mut config_account = ctx
+        .banks_client
+        .get_account(good_ntt.config())
+        .await
+        .unwrap()
+        .unwrap();
+    config_account.data = data;
+    ctx.set_account(&good_ntt.config(), &config_account.into());
+
+    // the mint has to match the config
+    let err = migrate_config(
+        &good_ntt,
+        MigrateConfig {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            mint: test_data.bad_mint,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintRaw.into())
+        )
+    );
+
+    migrate_config(
+        &good_ntt,
+        MigrateConfig {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            mint: test_data.mint,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let config_account = ctx
+        .banks_client
+        .get_account(good_ntt.config())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(config_account.data.len(), 8 + Config::INIT_SPACE);
+
+    let migrated: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(migrated.owner, config.owner);
+    assert_eq!(migrated.mint, config.mint);
+    assert_eq!(migrated.custody, config.custody);
+    assert_eq!(migrated.threshold, config.threshold);
+    assert!(migrated.routes_default_enabled);
+    assert_eq!(migrated.mint_decimals, 9);
+    assert_eq!(migrated.fee_collector, good_ntt.wormhole().fee_collector());
+    assert_eq!(migrated.dust_account, None);
+    assert_eq!(migrated.limits_manager, None);
+    assert_eq!(migrated.max_vaa_age, None);
+    assert!(!migrated.reject_unconsumed_payload);
+    assert_eq!(migrated.decommission_timestamp, None);
+    assert!(!migrated.terminated);
+    assert_eq!(
+        migrated.allowed_consistency_levels,
+        default_allowed_consistency_levels()
+    );
+    // a paused legacy deployment stays paused in both directions
+    assert!(migrated.is_paused());
+    assert_eq!(migrated.sender_limit, None);
+    assert!(!migrated.per_chain_outbound_limits);
+    assert!(!migrated.sender_limit_overrides);
+    // there's no custody ledger to track custody in
+    assert!(!migrated.tracks_custody);
+}
+
+#[tokio::test]
+async fn test_migrate_peer() {
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
+
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+
+    // rewrite the peer in the legacy layout, i.e. just the bump, address and
+    // decimals
+    let mut data = NttManagerPeer::DISCRIMINATOR.to_vec();
+    data.push(peer.bump);
+    data.extend(peer.address);
+    data.push(peer.token_decimals);
+
+    let mut peer_account = ctx
+        .banks_client
+        .get_account(good_ntt.peer(OTHER_CHAIN))
+        .await
+        .unwrap()
+        .unwrap();
+    peer_account.data = data;
+    ctx.set_account(&good_ntt.peer(OTHER_CHAIN), &peer_account.into());
+
+    migrate_peer(
+        &good_ntt,
+        MigratePeer {
+            payer: ctx.payer.pubkey(),
+        },
+        OTHER_CHAIN,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let peer_account = ctx
+        .banks_client
+        .get_account(good_ntt.peer(OTHER_CHAIN))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(peer_account.data.len(), 8 + NttManagerPeer::INIT_SPACE);
+
+    let migrated: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(migrated.bump, peer.bump);
+    assert_eq!(migrated.address, peer.address);
+    assert_eq!(migrated.token_decimals, peer.token_decimals);
+    assert!(migrated.enabled);
+    assert_eq!(migrated.outbound_in_flight, 0);
+    assert_eq!(migrated.max_outbound_in_flight, None);
+    assert!(migrated.allow_queue);
+    assert_eq!(migrated.destination_fee_bps, 0);
+    assert_eq!(migrated.fallback_recipient, None);
+    assert_eq!(migrated.inbound_in_flight, 0);
+    assert!(migrated.allow_inbound_queue);
+    assert!(peer_index(&good_ntt, &mut ctx).await.contains(&OTHER_CHAIN));
+
+    // peers in the current layout can't be migrated again
+    let err = migrate_peer(
+        &good_ntt,
+        MigratePeer {
+            payer: ctx.payer.pubkey(),
+        },
+        OTHER_CHAIN,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::PeerAlreadyMigrated.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_migrate_transceiver_peer() {
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
+
+    let peer = good_ntt_transceiver.transceiver_peer(OTHER_CHAIN);
+    let mut peer_account = ctx.banks_client.get_account(peer).await.unwrap().unwrap();
+    let current = peer_account.data.clone();
+    assert_eq!(current.len(), 8 + TransceiverPeer::INIT_SPACE);
+
+    // rewrite the peer in the legacy layout, i.e. without the address match
+    peer_account.data.truncate(8 + 1 + 32);
+    ctx.set_account(&peer, &peer_account.into());
+
+    migrate_transceiver_peer(
+        &good_ntt_transceiver,
+        MigrateTransceiverPeer {
+            payer: ctx.payer.pubkey(),
+        },
+        OTHER_CHAIN,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    // legacy peers matched exactly, which is the current default
+    let peer_account = ctx.banks_client.get_account(peer).await.unwrap().unwrap();
+    assert_eq!(peer_account.data, current);
+    assert!(transceiver_peer_index(&good_ntt_transceiver, &mut ctx)
+        .await
+        .contains(&OTHER_CHAIN));
+
+    // peers in the current layout can't be migrated again
+    let err = migrate_transceiver_peer(
+        &good_ntt_transceiver,
+        MigrateTransceiverPeer {
+            payer: ctx.payer.pubkey(),
+        },
+        OTHER_CHAIN,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::PeerAlreadyMigrated.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_get_pending_owner() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    assert_eq!(pending_owner(&good_ntt, &mut ctx).await, None);
+
+    let new_owner = Keypair::new();
+
+    transfer_ownership(
+        &good_ntt,
+        TransferOwnership {
+            owner: test_data.program_owner.pubkey(),
+            new_owner: new_owner.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        pending_owner(&good_ntt, &mut ctx).await,
+        Some(new_owner.pubkey())
+    );
+
+    claim_ownership(
+        &good_ntt,
+        ClaimOwnership {
+            new_owner: new_owner.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&new_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(pending_owner(&good_ntt, &mut ctx).await, None);
+
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config.owner, new_owner.pubkey());
+}
+
+#[tokio::test]
+async fn test_limits_manager() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let limits_manager = Keypair::new();
+
+    set_limits_manager(
+        &good_ntt,
+        SetLimitsManager {
+            owner: test_data.program_owner.pubkey(),
+        },
+        Some(limits_manager.pubkey()),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // the limits manager can adjust rate limits...
+    set_outbound_limit(
+        &good_ntt,
+        SetOutboundLimit {
+            authority: limits_manager.pubkey(),
+        },
+        SetOutboundLimitArgs { limit: 1234 },
+    )
+    .submit_with_signers(&[&limits_manager], &mut ctx)
+    .await
+    .unwrap();
+
+    set_inbound_limit(
+        &good_ntt,
+        SetInboundLimit {
+            authority: limits_manager.pubkey(),
+        },
+        SetInboundLimitArgs {
+            limit: 5678,
+            chain_id: ChainId { id: OTHER_CHAIN },
+        },
+    )
+    .submit_with_signers(&[&limits_manager], &mut ctx)
+    .await
+    .unwrap();
+
+    let outbox_rate_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+    assert_eq!(outbox_rate_limit.rate_limit.limit, 1234);
+
+    let inbox_rate_limit: InboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.inbox_rate_limit(OTHER_CHAIN))
+        .await;
+    assert_eq!(inbox_rate_limit.rate_limit.limit, 5678);
+
+    // ...but nothing else
+    let err = set_peer(
+        &good_ntt,
+        SetPeer {
+            payer: ctx.payer.pubkey(),
+            owner: limits_manager.pubkey(),
+        },
+        SetPeerArgs {
+            chain_id: ChainId { id: ANOTHER_CHAIN },
+            address: OTHER_MANAGER,
+            limit: INBOUND_LIMIT,
+            token_decimals: 7,
+        },
+    )
+    .submit_with_signers(&[&limits_manager], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintHasOne.into())
+        )
+    );
+
+    let err = set_threshold(
+        &good_ntt,
+        SetThreshold {
+            owner: limits_manager.pubkey(),
+        },
+        1,
+    )
+    .submit_with_signers(&[&limits_manager], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintHasOne.into())
+        )
+    );
+
+    // revoking the role takes away access to the rate limits
+    set_limits_manager(
+        &good_ntt,
+        SetLimitsManager {
+            owner: test_data.program_owner.pubkey(),
+        },
+        None,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let err = set_outbound_limit(
+        &good_ntt,
+        SetOutboundLimit {
+            authority: limits_manager.pubkey(),
+        },
+        SetOutboundLimitArgs { limit: 4321 },
+    )
+    .submit_with_signers(&[&limits_manager], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::Unauthorized.into())
+        )
+    );
+
+    // the owner keeps access regardless
+    set_outbound_limit(
+        &good_ntt,
+        SetOutboundLimit {
+            authority: test_data.program_owner.pubkey(),
+        },
+        SetOutboundLimitArgs { limit: 4321 },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+}
@@ -1,5 +1,4 @@
 #![cfg(feature = "test-sbf")]
-#![feature(type_changing_struct_update)]
 
 use ntt_messages::{
     chain_id::ChainId,
@@ -14,7 +13,7 @@ use test_utils::{
         query::GetAccountDataAnchor,
         submit::Submittable,
     },
-    helpers::setup,
+    helpers::{add_token_metadata, setup, token_metadata_hash},
     sdk::{
         accounts::{good_ntt, NTTAccounts},
         transceivers::{
@@ -90,6 +89,50 @@ async fn test_broadcast_id() {
             manager_mode: Mode::Locking,
             token_address: test_data.mint.to_bytes(),
             token_decimals: 9,
+            // the test mint has no metadata
+            token_metadata_hash: Some([0; 32]),
         }
     );
 }
+
+#[tokio::test]
+async fn test_broadcast_id_with_metadata() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    add_token_metadata(
+        &mut ctx,
+        &test_data.mint,
+        "Test Token",
+        "TEST",
+        "https://example.com/test.json",
+    )
+    .await;
+
+    let wh_message = Keypair::new();
+
+    broadcast_id(
+        &good_ntt,
+        &good_ntt_transceiver,
+        BroadcastId {
+            payer: ctx.payer.pubkey(),
+            wormhole_message: wh_message.pubkey(),
+            mint: test_data.mint,
+        },
+    )
+    .submit_with_signers(&[&wh_message], &mut ctx)
+    .await
+    .unwrap();
+
+    let msg: PostedVaa<WormholeTransceiverInfo> = ctx
+        .get_account_data_anchor_unchecked(wh_message.pubkey())
+        .await;
+
+    assert_eq!(
+        msg.data().token_metadata_hash,
+        Some(token_metadata_hash(
+            "Test Token",
+            "TEST",
+            "https://example.com/test.json"
+        ))
+    );
+}
@@ -1,5 +1,4 @@
 #![cfg(feature = "test-sbf")]
-#![feature(type_changing_struct_update)]
 
 use example_native_token_transfers::instructions::RedeemArgs;
 use ntt_messages::mode::Mode;
@@ -7,7 +6,7 @@ use solana_program_test::*;
 use solana_sdk::{signature::Keypair, signer::Signer};
 use test_utils::{
     common::{
-        fixtures::{OTHER_CHAIN, OTHER_TRANSCEIVER},
+        fixtures::{TestData, OTHER_CHAIN, OTHER_TRANSCEIVER},
         submit::Submittable,
     },
     helpers::{
@@ -17,6 +16,7 @@ use test_utils::{
     sdk::{
         accounts::good_ntt,
         instructions::{
+            cancel_outbound_transfer::{cancel_outbound_transfer, CancelOutbound},
             redeem::redeem,
             transfer::{approve_token_authority, transfer},
         },
@@ -75,7 +75,6 @@ async fn test_cancel() {
             &good_ntt,
             &good_ntt_transceiver,
             &mut ctx,
-            &test_data,
             OTHER_CHAIN,
             msg0.ntt_manager_payload.clone(),
         ),
@@ -152,7 +151,6 @@ async fn test_cancel() {
             &good_ntt,
             &good_ntt_transceiver,
             &mut ctx,
-            &test_data,
             OTHER_CHAIN,
             msg1.ntt_manager_payload.clone(),
         ),
@@ -172,3 +170,157 @@ async fn test_cancel() {
         inbound_capacity(&good_ntt, &mut ctx).await
     );
 }
+
+/// Sends `amount` from the user out to the peer.
+async fn send_transfer(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    outbox_item: &Keypair,
+    amount: u64,
+    should_queue: bool,
+) {
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        ctx,
+        test_data,
+        outbox_item.pubkey(),
+        amount,
+        should_queue,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[outbox_item], ctx)
+        .await
+        .unwrap();
+}
+
+async fn cancel_transfer(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    outbox_item: &Keypair,
+) {
+    cancel_outbound_transfer(
+        &good_ntt,
+        CancelOutbound {
+            sender: test_data.user.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            rent_payer: Some(ctx.payer.pubkey()),
+            recipient_chain: OTHER_CHAIN,
+            mint: test_data.mint,
+            to: test_data.user_token_account,
+            consumed_chain_limit: false,
+            consumed_sender_limit: false,
+        },
+        Mode::Locking,
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_cancel_outbound_transfer_restores_rate_limits() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+    let vaa = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+
+    let inbound_limit_before = inbound_capacity(&good_ntt, &mut ctx).await;
+    let outbound_limit_before = outbound_capacity(&good_ntt, &mut ctx).await;
+
+    receive_message(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(&good_ntt_transceiver, &mut ctx, vaa, OTHER_CHAIN, [0u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let outbox_item = Keypair::new();
+    send_transfer(&mut ctx, &test_data, &outbox_item, 1500, false).await;
+
+    assert_eq!(
+        outbound_limit_before - 1500,
+        outbound_capacity(&good_ntt, &mut ctx).await
+    );
+    // the backflow stops at the limit
+    assert_eq!(
+        inbound_limit_before,
+        inbound_capacity(&good_ntt, &mut ctx).await
+    );
+
+    cancel_transfer(&mut ctx, &test_data, &outbox_item).await;
+
+    assert_eq!(
+        outbound_limit_before,
+        outbound_capacity(&good_ntt, &mut ctx).await
+    );
+    // only what the backflow added is taken back
+    assert_eq!(
+        inbound_limit_before - 1000,
+        inbound_capacity(&good_ntt, &mut ctx).await
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_queued_outbound_transfer_leaves_rate_limits() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let inbound_limit_before = inbound_capacity(&good_ntt, &mut ctx).await;
+    let outbound_limit_before = outbound_capacity(&good_ntt, &mut ctx).await;
+
+    // over the outbound limit, so it's queued without consuming any
+    let outbox_item = Keypair::new();
+    send_transfer(
+        &mut ctx,
+        &test_data,
+        &outbox_item,
+        outbound_limit_before + 1000,
+        true,
+    )
+    .await;
+
+    cancel_transfer(&mut ctx, &test_data, &outbox_item).await;
+
+    assert_eq!(
+        outbound_limit_before,
+        outbound_capacity(&good_ntt, &mut ctx).await
+    );
+    assert_eq!(
+        inbound_limit_before,
+        inbound_capacity(&good_ntt, &mut ctx).await
+    );
+}
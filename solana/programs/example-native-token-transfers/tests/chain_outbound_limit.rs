@@ -0,0 +1,234 @@
+#![cfg(feature = "test-sbf")]
+
+use anchor_lang::error::ErrorCode;
+use example_native_token_transfers::{
+    config::Config,
+    error::NTTError,
+    instructions::SetChainOutboundLimitArgs,
+    queue::outbox::{ChainOutboxRateLimit, OutboxRateLimit},
+};
+use ntt_messages::{chain_id::ChainId, mode::Mode};
+use solana_program::instruction::{AccountMeta, InstructionError};
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::TransactionError};
+use test_utils::{
+    common::{
+        fixtures::{TestData, ANOTHER_CHAIN, OTHER_CHAIN, OUTBOUND_LIMIT},
+        query::GetAccountDataAnchor,
+        submit::Submittable,
+    },
+    helpers::{init_transfer_accs_args, setup},
+    sdk::{
+        accounts::{good_ntt, NTTAccounts},
+        instructions::{
+            admin::{
+                set_chain_outbound_limit, set_per_chain_outbound_limits, SetChainOutboundLimit,
+                SetPerChainOutboundLimits,
+            },
+            transfer::{approve_token_authority, transfer, Transfer},
+        },
+    },
+};
+
+const CHAIN_LIMIT: u64 = 1500;
+
+/// Sends `amount` from the user out to the peer on `chain`.
+async fn send_transfer(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    chain: u16,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    send_transfer_with_chain_limit(ctx, test_data, chain, amount, true).await
+}
+
+/// [`send_transfer`], leaving the chain's outbound rate limit out unless
+/// `pass_chain_limit`.
+async fn send_transfer_with_chain_limit(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    chain: u16,
+    amount: u64,
+    pass_chain_limit: bool,
+) -> Result<(), BanksClientError> {
+    let outbox_item = Keypair::new();
+    let (accs, mut args) = init_transfer_accs_args(
+        &good_ntt,
+        ctx,
+        test_data,
+        outbox_item.pubkey(),
+        amount,
+        false,
+    );
+    let accs = Transfer {
+        peer: good_ntt.peer(chain),
+        ..accs
+    };
+    args.recipient_chain = ChainId { id: chain };
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+    let mut ix = transfer(&good_ntt, accs, args, Mode::Locking);
+    if !pass_chain_limit {
+        // an optional account is left out by passing the program id instead
+        let chain_outbox_rate_limit = good_ntt.chain_outbox_rate_limit(chain);
+        let meta = ix
+            .accounts
+            .iter_mut()
+            .find(|meta| meta.pubkey == chain_outbox_rate_limit)
+            .unwrap();
+        *meta = AccountMeta::new_readonly(good_ntt.program(), false);
+    }
+    ix.submit_with_signers(&[&outbox_item], ctx).await
+}
+
+async fn set_per_chain_limits(ctx: &mut ProgramTestContext, test_data: &TestData, enabled: bool) {
+    set_per_chain_outbound_limits(
+        &good_ntt,
+        SetPerChainOutboundLimits {
+            authority: test_data.program_owner.pubkey(),
+        },
+        enabled,
+    )
+    .submit_with_signers(&[&test_data.program_owner], ctx)
+    .await
+    .unwrap();
+}
+
+async fn global_capacity(ctx: &mut ProgramTestContext) -> u64 {
+    let outbox_rate_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+    outbox_rate_limit.rate_limit.capacity_at_last_tx
+}
+
+fn assert_ntt_error(err: BanksClientError, error: NTTError) {
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(error.into()))
+    );
+}
+
+#[tokio::test]
+async fn test_chain_outbound_limit() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_chain_outbound_limit(
+        &good_ntt,
+        SetChainOutboundLimit {
+            payer: ctx.payer.pubkey(),
+            authority: test_data.program_owner.pubkey(),
+        },
+        SetChainOutboundLimitArgs {
+            limit: CHAIN_LIMIT,
+            chain_id: ChainId { id: OTHER_CHAIN },
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    set_per_chain_limits(&mut ctx, &test_data, true).await;
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert!(config.per_chain_outbound_limits);
+
+    send_transfer(&mut ctx, &test_data, OTHER_CHAIN, 1000)
+        .await
+        .unwrap();
+    let chain_rate_limit: ChainOutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.chain_outbox_rate_limit(OTHER_CHAIN))
+        .await;
+    assert_eq!(chain_rate_limit.rate_limit.limit, CHAIN_LIMIT);
+    assert_eq!(chain_rate_limit.rate_limit.capacity_at_last_tx, 500);
+    // the global limit is left alone
+    assert_eq!(global_capacity(&mut ctx).await, OUTBOUND_LIMIT);
+
+    // well within the global limit, but not the chain's
+    let err = send_transfer(&mut ctx, &test_data, OTHER_CHAIN, 1000)
+        .await
+        .unwrap_err();
+    assert_ntt_error(err, NTTError::TransferExceedsRateLimit);
+
+    // a chain without its own limit falls back to the global one
+    send_transfer(&mut ctx, &test_data, ANOTHER_CHAIN, 1000)
+        .await
+        .unwrap();
+    assert_eq!(global_capacity(&mut ctx).await, OUTBOUND_LIMIT - 1000);
+
+    // with per-chain limits turned off, the chain's limit no longer applies
+    set_per_chain_limits(&mut ctx, &test_data, false).await;
+    send_transfer(&mut ctx, &test_data, OTHER_CHAIN, 1000)
+        .await
+        .unwrap();
+    assert_eq!(global_capacity(&mut ctx).await, OUTBOUND_LIMIT - 2000);
+}
+
+#[tokio::test]
+async fn test_chain_outbound_limit_only_needed_when_enabled() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // per-chain limits are off, so the chain's limit is not needed
+    send_transfer_with_chain_limit(&mut ctx, &test_data, OTHER_CHAIN, 1000, false)
+        .await
+        .unwrap();
+    assert_eq!(global_capacity(&mut ctx).await, OUTBOUND_LIMIT - 1000);
+
+    // once they're on, leaving it out would get around the chain's limit. That
+    // holds even for a chain without a limit of its own (like this one), as
+    // the program can't tell without the account
+    set_per_chain_limits(&mut ctx, &test_data, true).await;
+    let err = send_transfer_with_chain_limit(&mut ctx, &test_data, OTHER_CHAIN, 1000, false)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::AccountNotEnoughKeys.into())
+        )
+    );
+
+    // passing it, the transfer falls back to the global limit
+    send_transfer(&mut ctx, &test_data, OTHER_CHAIN, 1000)
+        .await
+        .unwrap();
+    assert_eq!(global_capacity(&mut ctx).await, OUTBOUND_LIMIT - 2000);
+}
+
+#[tokio::test]
+async fn test_set_chain_outbound_limit_unauthorized() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let err = set_per_chain_outbound_limits(
+        &good_ntt,
+        SetPerChainOutboundLimits {
+            authority: test_data.user.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_ntt_error(err, NTTError::Unauthorized);
+
+    let err = set_chain_outbound_limit(
+        &good_ntt,
+        SetChainOutboundLimit {
+            payer: ctx.payer.pubkey(),
+            authority: test_data.user.pubkey(),
+        },
+        SetChainOutboundLimitArgs {
+            limit: u64::MAX,
+            chain_id: ChainId { id: OTHER_CHAIN },
+        },
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_ntt_error(err, NTTError::Unauthorized);
+}
@@ -0,0 +1,235 @@
+#![cfg(feature = "test-sbf")]
+
+use anchor_lang::prelude::Clock;
+use example_native_token_transfers::{
+    config::Config,
+    error::NTTError,
+    instructions::{RedeemArgs, ReleaseInboundArgs},
+    peer::NttManagerPeer,
+    transfer::Payload,
+};
+use ntt_messages::{mode::Mode, ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage};
+use solana_program::instruction::InstructionError;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::TransactionError};
+use test_utils::{
+    common::{
+        fixtures::{TestData, ANOTHER_CHAIN, OTHER_CHAIN, OTHER_TRANSCEIVER},
+        query::GetAccountDataAnchor,
+        submit::Submittable,
+    },
+    helpers::{
+        init_receive_message_accs, init_redeem_accs, init_transfer_accs_args,
+        make_transfer_message, post_vaa_helper, setup, token_balance,
+    },
+    sdk::{
+        accounts::{good_ntt, NTTAccounts},
+        instructions::{
+            admin::{
+                begin_decommission, delete_peer, finalize_decommission, BeginDecommission,
+                DeletePeer, FinalizeDecommission,
+            },
+            redeem::redeem,
+            release_inbound::{release_inbound_mint, ReleaseInbound},
+            transfer::{approve_token_authority, transfer},
+        },
+        transceivers::{
+            accounts::good_ntt_transceiver, instructions::receive_message::receive_message,
+        },
+    },
+};
+use wormhole_sdk::Address;
+
+const GRACE_PERIOD: u64 = 3600;
+
+/// Receives a transfer of `amount` to the user, and redeems it (without
+/// releasing it).
+async fn redeem_transfer(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    amount: u64,
+) -> NttManagerMessage<NativeTokenTransfer<Payload>> {
+    let msg = make_transfer_message(&good_ntt, [7u8; 32], amount, &test_data.user.pubkey());
+    let vaa = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        ctx,
+    )
+    .await;
+
+    receive_message(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(&good_ntt_transceiver, ctx, vaa, OTHER_CHAIN, [7u8; 32]),
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            ctx,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    msg.ntt_manager_payload
+}
+
+/// Sends 1000 tokens from the user out to the peer.
+async fn send_transfer(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+) -> Result<(), BanksClientError> {
+    let outbox_item = Keypair::new();
+    let (accs, args) =
+        init_transfer_accs_args(&good_ntt, ctx, test_data, outbox_item.pubkey(), 1000, false);
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Burning)
+        .submit_with_signers(&[&outbox_item], ctx)
+        .await
+}
+
+fn assert_ntt_error(err: BanksClientError, error: NTTError) {
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(error.into()))
+    );
+}
+
+#[tokio::test]
+async fn test_decommission() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+    let owner = test_data.program_owner.pubkey();
+
+    // approved before the decommission starts, but not released
+    let payload = redeem_transfer(&mut ctx, &test_data, 1000).await;
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(peer.inbound_in_flight, 1);
+
+    let finalize = || finalize_decommission(&good_ntt, FinalizeDecommission { owner });
+
+    let err = finalize()
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_ntt_error(err, NTTError::NotDecommissioning);
+
+    let begin = || begin_decommission(&good_ntt, BeginDecommission { owner }, GRACE_PERIOD);
+    begin()
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(
+        config.decommission_timestamp,
+        Some(clock.unix_timestamp + i64::try_from(GRACE_PERIOD).unwrap())
+    );
+
+    // it can't be restarted to move the deadline
+    let err = begin()
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_ntt_error(err, NTTError::DeploymentDecommissioning);
+
+    // outbound transfers are rejected
+    let err = send_transfer(&mut ctx, &test_data).await.unwrap_err();
+    assert_ntt_error(err, NTTError::DeploymentDecommissioning);
+
+    let err = finalize()
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_ntt_error(err, NTTError::DecommissionGracePeriodActive);
+
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += i64::try_from(GRACE_PERIOD).unwrap();
+    ctx.set_sysvar(&clock);
+
+    let err = finalize()
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_ntt_error(err, NTTError::PeersRemaining);
+
+    // the unreleased transfer holds up deleting its peer
+    let err = delete_peer(&good_ntt, DeletePeer { owner }, OTHER_CHAIN)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_ntt_error(err, NTTError::PeerHasInboundInFlight);
+
+    // inbound transfers still go through
+    let balance = token_balance(&mut ctx, &test_data.user_token_account).await;
+    release_inbound_mint(
+        &good_ntt,
+        ReleaseInbound {
+            payer: ctx.payer.pubkey(),
+            inbox_item: good_ntt.inbox_item(OTHER_CHAIN, payload),
+            from_chain: OTHER_CHAIN,
+            mint: test_data.mint,
+            recipient: test_data.user_token_account,
+        },
+        ReleaseInboundArgs {
+            revert_when_not_ready: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+    assert_eq!(
+        token_balance(&mut ctx, &test_data.user_token_account).await,
+        balance + 1000
+    );
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(peer.inbound_in_flight, 0);
+
+    for chain_id in [OTHER_CHAIN, ANOTHER_CHAIN] {
+        delete_peer(&good_ntt, DeletePeer { owner }, chain_id)
+            .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+            .await
+            .unwrap();
+    }
+
+    finalize()
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert!(config.terminated);
+    assert!(ctx
+        .banks_client
+        .get_account(good_ntt.outbox_rate_limit())
+        .await
+        .unwrap()
+        .is_none());
+
+    let err = send_transfer(&mut ctx, &test_data).await.unwrap_err();
+    assert_ntt_error(err, NTTError::DeploymentTerminated);
+}
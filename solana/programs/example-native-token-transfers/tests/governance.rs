@@ -1,9 +1,14 @@
 #![cfg(feature = "test-sbf")]
-#![feature(type_changing_struct_update)]
 
 use anchor_lang::{prelude::*, InstructionData};
-use example_native_token_transfers::config::Config;
-use ntt_messages::mode::Mode;
+use example_native_token_transfers::{
+    config::Config,
+    error::NTTError,
+    instructions::{SetOutboundLimitArgs, SetPeerArgs, SetPeerOptions},
+    peer::NttManagerPeer,
+    queue::outbox::OutboxRateLimit,
+};
+use ntt_messages::{chain_id::ChainId, mode::Mode};
 use solana_program::{
     instruction::{Instruction, InstructionError},
     system_instruction::SystemError,
@@ -12,19 +17,26 @@ use solana_program_test::*;
 use solana_sdk::{pubkey::Pubkey, signer::Signer, transaction::TransactionError};
 use std::sync::atomic::AtomicU64;
 use test_utils::{
-    common::{fixtures::TestData, query::GetAccountDataAnchor, submit::Submittable},
+    common::{
+        fixtures::{TestData, INBOUND_LIMIT, OTHER_MANAGER, UNREGISTERED_CHAIN},
+        query::GetAccountDataAnchor,
+        submit::Submittable,
+    },
     helpers::setup,
     sdk::{
         accounts::{good_ntt, Governance, NTTAccounts, Wormhole},
         instructions::{
-            admin::{set_paused, SetPaused},
+            admin::{
+                set_outbound_limit, set_paused, set_peer_with_options, SetOutboundLimit, SetPaused,
+                SetPeer,
+            },
             post_vaa::post_vaa,
         },
     },
 };
 use wormhole_governance::{
     error::GovernanceError,
-    instructions::{GovernanceMessage, ReplayProtection, OWNER},
+    instructions::{GovernanceMessage, ReplayProtection, SetOutboundLimitMessage, OWNER, PAYER},
 };
 use wormhole_sdk::{Address, Vaa, GOVERNANCE_EMITTER};
 use wormhole_solana_utils::cpi::bpf_loader_upgradeable;
@@ -107,7 +119,8 @@ async fn transfer_ownership_to_gov_program(
     };
 
     let config_account: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
-    assert!(!config_account.paused); // make sure not paused before
+    // make sure not paused before
+    assert!(!config_account.paused_outbound && !config_account.paused_inbound);
 
     let vaa = wrap_governance(
         ctx,
@@ -146,7 +159,97 @@ async fn test_governance() {
     .unwrap();
 
     let config_account: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
-    assert!(config_account.paused);
+    assert!(config_account.is_paused());
+}
+
+#[tokio::test]
+async fn test_governance_set_outbound_limit() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let ix = set_outbound_limit(
+        &good_ntt,
+        SetOutboundLimit { authority: OWNER },
+        SetOutboundLimitArgs { limit: 1234 },
+    );
+
+    // the governance PDA has to own the config
+    let err = wrap_governance(
+        &mut ctx,
+        &test_data.governance,
+        &good_ntt.wormhole(),
+        ix.clone(),
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::Unauthorized.into())
+        )
+    );
+
+    transfer_ownership_to_gov_program(&mut ctx, &test_data, None)
+        .await
+        .0
+        .unwrap();
+
+    wrap_governance(
+        &mut ctx,
+        &test_data.governance,
+        &good_ntt.wormhole(),
+        ix,
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    let outbox_rate_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+    assert_eq!(outbox_rate_limit.rate_limit.limit, 1234);
+}
+
+#[tokio::test]
+async fn test_governance_typed_set_outbound_limit() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let message = SetOutboundLimitMessage {
+        governance_program_id: wormhole_governance::ID,
+        ntt_manager: good_ntt.program(),
+        limit: 1234,
+    };
+
+    // the governance PDA has to own the config
+    let err = submit_set_outbound_limit(&mut ctx, &test_data.governance, message.clone())
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(GovernanceError::NotConfigOwner.into())
+        )
+    );
+
+    transfer_ownership_to_gov_program(&mut ctx, &test_data, None)
+        .await
+        .0
+        .unwrap();
+
+    submit_set_outbound_limit(&mut ctx, &test_data.governance, message)
+        .await
+        .unwrap();
+
+    let outbox_rate_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+    assert_eq!(outbox_rate_limit.rate_limit.limit, 1234);
 }
 
 #[tokio::test]
@@ -177,7 +280,8 @@ async fn test_governance_one_step_transfer() {
     .unwrap();
 
     let config_account: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
-    assert!(!config_account.paused); // make sure not paused before
+    // make sure not paused before
+    assert!(!config_account.paused_outbound && !config_account.paused_inbound);
 
     // step 2. set paused
     wrap_governance(
@@ -193,7 +297,7 @@ async fn test_governance_one_step_transfer() {
     .unwrap();
 
     let config_account: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
-    assert!(config_account.paused);
+    assert!(config_account.is_paused());
 }
 
 #[tokio::test]
@@ -267,6 +371,115 @@ async fn test_governance_replay() {
     );
 }
 
+#[tokio::test]
+async fn test_governance_symbolic_accounts() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    transfer_ownership_to_gov_program(&mut ctx, &test_data, None)
+        .await
+        .0
+        .unwrap();
+
+    let mut ix = set_peer_with_options(
+        &good_ntt,
+        SetPeer {
+            payer: PAYER,
+            owner: OWNER,
+        },
+        SetPeerArgs {
+            chain_id: ChainId {
+                id: UNREGISTERED_CHAIN,
+            },
+            address: OTHER_MANAGER,
+            limit: INBOUND_LIMIT,
+            token_decimals: 7,
+        },
+        SetPeerOptions {
+            allow_unknown_chain: true,
+            allow_nonstandard_address: false,
+        },
+    );
+
+    // the flags of the symbolic accounts are filled in by the governance program
+    for acc in ix.accounts.iter_mut() {
+        if acc.pubkey == OWNER || acc.pubkey == PAYER {
+            acc.is_signer = false;
+            acc.is_writable = false;
+        }
+    }
+
+    wrap_governance(
+        &mut ctx,
+        &test_data.governance,
+        &good_ntt.wormhole(),
+        ix,
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(UNREGISTERED_CHAIN))
+        .await;
+    assert_eq!(peer.address, OTHER_MANAGER);
+}
+
+#[tokio::test]
+async fn test_governance_account_flags_mismatch() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    transfer_ownership_to_gov_program(&mut ctx, &test_data, None)
+        .await
+        .0
+        .unwrap();
+
+    let ix = set_paused(&good_ntt, SetPaused { owner: OWNER }, true);
+
+    let (vaa_key, vaa) = post_governance_vaa(
+        &mut ctx,
+        &good_ntt.wormhole(),
+        GovernanceMessage::from(ix.clone()),
+        None,
+        None,
+    )
+    .await;
+
+    // the config is writable in the payload, but passed as read-only
+    let remaining_accounts = ix
+        .accounts
+        .iter()
+        .map(|acc| AccountMeta {
+            is_signer: false,
+            is_writable: false,
+            ..acc.clone()
+        })
+        .collect();
+
+    let err = submit_governance(
+        &mut ctx,
+        &test_data.governance,
+        vaa_key,
+        &vaa,
+        ix.program_id,
+        remaining_accounts,
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(GovernanceError::AccountFlagsMismatch.into())
+        )
+    );
+
+    let config_account: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert!(!config_account.paused_outbound && !config_account.paused_inbound);
+}
+
 // TODO: move (some of) this into the governance library
 async fn wrap_governance(
     ctx: &mut ProgramTestContext,
@@ -278,7 +491,6 @@ async fn wrap_governance(
     vaa: Option<Vaa<GovernanceMessage>>,
 ) -> core::result::Result<Vaa<GovernanceMessage>, BanksClientError> {
     let program = ix.program_id;
-    let data = wormhole_governance::instruction::Governance {};
 
     let mut gov_message: GovernanceMessage = ix.clone().into();
 
@@ -289,6 +501,29 @@ async fn wrap_governance(
     let (vaa_key, vaa) =
         post_governance_vaa(ctx, wormhole, gov_message, emitter_override, vaa).await;
 
+    let remaining_accounts = ix
+        .accounts
+        .iter()
+        .map(|acc| AccountMeta {
+            is_signer: false,
+            ..acc.clone()
+        })
+        .collect();
+
+    submit_governance(ctx, gov_program, vaa_key, &vaa, program, remaining_accounts).await?;
+    Ok(vaa)
+}
+
+async fn submit_governance(
+    ctx: &mut ProgramTestContext,
+    gov_program: &Governance,
+    vaa_key: Pubkey,
+    vaa: &Vaa<GovernanceMessage>,
+    program: Pubkey,
+    remaining_accounts: Vec<AccountMeta>,
+) -> core::result::Result<(), BanksClientError> {
+    let data = wormhole_governance::instruction::Governance {};
+
     let (replay, _) = Pubkey::find_program_address(
         &[
             &ReplayProtection::SEED_PREFIX,
@@ -309,12 +544,6 @@ async fn wrap_governance(
     };
 
     let mut accounts = gov_accounts.to_account_metas(None);
-
-    let remaining_accounts = ix.accounts.iter().map(|acc| AccountMeta {
-        is_signer: false,
-        ..acc.clone()
-    });
-
     accounts.extend(remaining_accounts);
 
     let gov_ix = Instruction {
@@ -323,6 +552,42 @@ async fn wrap_governance(
         data: data.data(),
     };
 
-    gov_ix.submit(ctx).await?;
-    Ok(vaa)
+    gov_ix.submit(ctx).await
+}
+
+async fn submit_set_outbound_limit(
+    ctx: &mut ProgramTestContext,
+    gov_program: &Governance,
+    message: SetOutboundLimitMessage,
+) -> core::result::Result<(), BanksClientError> {
+    let (vaa_key, vaa) = post_governance_vaa(ctx, &good_ntt.wormhole(), message, None, None).await;
+
+    let (replay, _) = Pubkey::find_program_address(
+        &[
+            &ReplayProtection::SEED_PREFIX,
+            &u16::from(vaa.emitter_chain).to_be_bytes(),
+            &vaa.emitter_address.0.as_ref(),
+            &vaa.sequence.to_be_bytes(),
+        ],
+        &gov_program.program,
+    );
+
+    let accounts = wormhole_governance::accounts::SetOutboundLimit {
+        payer: ctx.payer.pubkey(),
+        governance: gov_program.governance(),
+        vaa: vaa_key,
+        ntt_manager: good_ntt.program(),
+        config: good_ntt.config(),
+        outbox_rate_limit: good_ntt.outbox_rate_limit(),
+        replay,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: gov_program.program,
+        accounts: accounts.to_account_metas(None),
+        data: wormhole_governance::instruction::SetOutboundLimit {}.data(),
+    }
+    .submit(ctx)
+    .await
 }
@@ -0,0 +1,466 @@
+#![cfg(feature = "test-sbf")]
+
+use anchor_lang::Id;
+use anchor_spl::token::{Token, TokenAccount};
+use example_native_token_transfers::{
+    error::NTTError,
+    instructions::{diagnostics, InitialPeer, InitializeArgs, RedeemArgs, ReleaseInboundArgs},
+    peer::NttManagerPeer,
+};
+use ntt_messages::{chain_id::ChainId, mode::Mode};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, program_pack::Pack, signature::Keypair, signer::Signer,
+    system_instruction, transaction::TransactionError,
+};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use test_utils::{
+    common::{
+        fixtures::{
+            ANOTHER_CHAIN, ANOTHER_MANAGER, ANOTHER_TRANSCEIVER, INBOUND_LIMIT, OTHER_CHAIN,
+            OTHER_MANAGER, OTHER_TRANSCEIVER, OUTBOUND_LIMIT, THIS_CHAIN,
+        },
+        query::GetAccountDataAnchor,
+        submit::Submittable,
+    },
+    helpers::{
+        create_mint, init_receive_message_accs, init_redeem_accs, make_transfer_message,
+        post_vaa_helper, preflight_diagnostics, setup_accounts, setup_accounts_with_transfer_fee,
+        setup_programs,
+    },
+    sdk::{
+        accounts::{good_ntt, NTTAccounts},
+        instructions::{
+            admin::{register_transceiver, RegisterTransceiver},
+            initialize::{
+                initialize, initialize_with_peers, initialize_with_token_program_id, Initialize,
+                PreflightInitialize,
+            },
+            redeem::redeem,
+            release_inbound::{release_inbound_unlock, ReleaseInbound},
+        },
+        transceivers::{
+            accounts::{good_ntt_transceiver, NTTTransceiverAccounts},
+            instructions::receive_message::receive_message,
+        },
+    },
+};
+use wormhole_sdk::Address;
+
+#[tokio::test]
+async fn test_initialize_token_program_mismatch() {
+    let program_owner = Keypair::new();
+    let program_test = setup_programs(program_owner.pubkey()).await.unwrap();
+    let mut ctx = program_test.start_with_context().await;
+
+    // the mint is a token-2022 mint
+    let test_data = setup_accounts_with_transfer_fee(&mut ctx, program_owner).await;
+
+    // ...but the custody account is derived for the classic token program
+    let err = initialize_with_token_program_id(
+        &good_ntt,
+        Initialize {
+            payer: ctx.payer.pubkey(),
+            deployer: test_data.program_owner.pubkey(),
+            mint: test_data.mint,
+            multisig_token_authority: None,
+        },
+        InitializeArgs {
+            chain_id: THIS_CHAIN,
+            limit: OUTBOUND_LIMIT,
+            mode: Mode::Locking,
+        },
+        &Token::id(),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TokenProgramMismatch.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_initialize_logs_expected_mint_authority() {
+    let program_owner = Keypair::new();
+    let program_test = setup_programs(program_owner.pubkey()).await.unwrap();
+    let mut ctx = program_test.start_with_context().await;
+    let test_data = setup_accounts(&mut ctx, program_owner).await;
+
+    // the mint authority was never handed over to the token authority
+    let out = initialize(
+        &good_ntt,
+        Initialize {
+            payer: ctx.payer.pubkey(),
+            deployer: test_data.program_owner.pubkey(),
+            mint: test_data.mint,
+            multisig_token_authority: None,
+        },
+        InitializeArgs {
+            chain_id: THIS_CHAIN,
+            limit: OUTBOUND_LIMIT,
+            mode: Mode::Burning,
+        },
+    )
+    .simulate_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        out.result.unwrap().unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidMintAuthority.into())
+        )
+    );
+
+    let logs = out.simulation_details.unwrap().logs;
+    let expected_log = format!("Expected mint authority: {}", good_ntt.token_authority());
+    let actual_log = format!(
+        "Actual mint authority: {}",
+        test_data.mint_authority.pubkey()
+    );
+    assert!(logs.iter().any(|log| log.contains(&expected_log)));
+    assert!(logs.iter().any(|log| log.contains(&actual_log)));
+}
+
+#[tokio::test]
+async fn test_preflight_initialize() {
+    let program_owner = Keypair::new();
+    let program_test = setup_programs(program_owner.pubkey()).await.unwrap();
+    let mut ctx = program_test.start_with_context().await;
+    let test_data = setup_accounts(&mut ctx, program_owner).await;
+
+    let accounts = || PreflightInitialize {
+        mint: test_data.mint,
+        multisig_token_authority: None,
+    };
+
+    assert_eq!(
+        preflight_diagnostics(&good_ntt, &mut ctx, accounts(), Mode::Locking, &Token::id()).await,
+        0
+    );
+    assert_eq!(
+        preflight_diagnostics(&good_ntt, &mut ctx, accounts(), Mode::Burning, &Token::id()).await,
+        diagnostics::INVALID_MINT_AUTHORITY
+    );
+
+    // tokens sent to the custody account ahead of time
+    let custody = good_ntt.custody(&test_data.mint);
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &ctx.payer.pubkey(),
+        &good_ntt.token_authority(),
+        &test_data.mint,
+        &Token::id(),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+    spl_token::instruction::mint_to(
+        &Token::id(),
+        &test_data.mint,
+        &custody,
+        &test_data.mint_authority.pubkey(),
+        &[],
+        1000,
+    )
+    .unwrap()
+    .submit_with_signers(&[&test_data.mint_authority], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        preflight_diagnostics(&good_ntt, &mut ctx, accounts(), Mode::Locking, &Token::id()).await,
+        diagnostics::CUSTODY_NOT_EMPTY
+    );
+
+    initialize(
+        &good_ntt,
+        Initialize {
+            payer: ctx.payer.pubkey(),
+            deployer: test_data.program_owner.pubkey(),
+            mint: test_data.mint,
+            multisig_token_authority: None,
+        },
+        InitializeArgs {
+            chain_id: THIS_CHAIN,
+            limit: OUTBOUND_LIMIT,
+            mode: Mode::Locking,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        preflight_diagnostics(&good_ntt, &mut ctx, accounts(), Mode::Locking, &Token::id()).await,
+        diagnostics::ALREADY_INITIALIZED | diagnostics::CUSTODY_NOT_EMPTY
+    );
+}
+
+#[tokio::test]
+async fn test_preflight_initialize_transfer_fee_mint() {
+    let program_owner = Keypair::new();
+    let program_test = setup_programs(program_owner.pubkey()).await.unwrap();
+    let mut ctx = program_test.start_with_context().await;
+    let test_data = setup_accounts_with_transfer_fee(&mut ctx, program_owner).await;
+
+    let accounts = || PreflightInitialize {
+        mint: test_data.mint,
+        multisig_token_authority: None,
+    };
+
+    // transfer fees only matter when tokens are locked in custody
+    assert_eq!(
+        preflight_diagnostics(
+            &good_ntt,
+            &mut ctx,
+            accounts(),
+            Mode::Locking,
+            &spl_token_2022::ID
+        )
+        .await,
+        diagnostics::TRANSFER_FEE
+    );
+    assert_eq!(
+        preflight_diagnostics(
+            &good_ntt,
+            &mut ctx,
+            accounts(),
+            Mode::Burning,
+            &spl_token_2022::ID
+        )
+        .await,
+        diagnostics::INVALID_MINT_AUTHORITY
+    );
+
+    // the mint is a token-2022 mint
+    assert_eq!(
+        preflight_diagnostics(&good_ntt, &mut ctx, accounts(), Mode::Locking, &Token::id()).await,
+        diagnostics::TOKEN_PROGRAM_MISMATCH | diagnostics::TRANSFER_FEE
+    );
+}
+
+#[tokio::test]
+async fn test_preflight_initialize_unsupported_decimals() {
+    let program_owner = Keypair::new();
+    let program_test = setup_programs(program_owner.pubkey()).await.unwrap();
+    let mut ctx = program_test.start_with_context().await;
+
+    let mint_authority = Keypair::new();
+    for (decimals, expected) in [(27, 0), (28, diagnostics::UNSUPPORTED_DECIMALS)] {
+        let mint = Keypair::new();
+        create_mint(&mut ctx, &mint, &mint_authority.pubkey(), decimals)
+            .await
+            .submit_with_signers(&[&mint], &mut ctx)
+            .await
+            .unwrap();
+
+        let accounts = PreflightInitialize {
+            mint: mint.pubkey(),
+            multisig_token_authority: None,
+        };
+        assert_eq!(
+            preflight_diagnostics(&good_ntt, &mut ctx, accounts, Mode::Locking, &Token::id()).await,
+            expected
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_preflight_initialize_invalid_multisig() {
+    let program_owner = Keypair::new();
+    let program_test = setup_programs(program_owner.pubkey()).await.unwrap();
+    let mut ctx = program_test.start_with_context().await;
+    let test_data = setup_accounts(&mut ctx, program_owner).await;
+
+    // a 2-of-2 multisig, so the token authority can't mint on its own
+    let multisig = Keypair::new();
+    let other_signer = Keypair::new();
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let len = spl_token::state::Multisig::LEN;
+    system_instruction::create_account(
+        &ctx.payer.pubkey(),
+        &multisig.pubkey(),
+        rent.minimum_balance(len),
+        len.try_into().unwrap(),
+        &Token::id(),
+    )
+    .submit_with_signers(&[&multisig], &mut ctx)
+    .await
+    .unwrap();
+    spl_token::instruction::initialize_multisig2(
+        &Token::id(),
+        &multisig.pubkey(),
+        &[&good_ntt.token_authority(), &other_signer.pubkey()],
+        2,
+    )
+    .unwrap()
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let accounts = PreflightInitialize {
+        mint: test_data.mint,
+        multisig_token_authority: Some(multisig.pubkey()),
+    };
+    assert_eq!(
+        preflight_diagnostics(&good_ntt, &mut ctx, accounts, Mode::Locking, &Token::id()).await,
+        diagnostics::INVALID_MULTISIG
+    );
+}
+
+#[tokio::test]
+async fn test_initialize_with_peers() {
+    let program_owner = Keypair::new();
+    let program_test = setup_programs(program_owner.pubkey()).await.unwrap();
+    let mut ctx = program_test.start_with_context().await;
+    let test_data = setup_accounts(&mut ctx, program_owner).await;
+
+    let peers = [
+        (OTHER_CHAIN, OTHER_MANAGER, OTHER_TRANSCEIVER),
+        (ANOTHER_CHAIN, ANOTHER_MANAGER, ANOTHER_TRANSCEIVER),
+    ];
+
+    initialize_with_peers(
+        &good_ntt,
+        Initialize {
+            payer: ctx.payer.pubkey(),
+            deployer: test_data.program_owner.pubkey(),
+            mint: test_data.mint,
+            multisig_token_authority: None,
+        },
+        InitializeArgs {
+            chain_id: THIS_CHAIN,
+            limit: OUTBOUND_LIMIT,
+            mode: Mode::Locking,
+        },
+        peers
+            .iter()
+            .map(|&(chain_id, address, transceiver_address)| InitialPeer {
+                chain_id: ChainId { id: chain_id },
+                address,
+                token_decimals: 7,
+                inbound_limit: INBOUND_LIMIT,
+                transceiver_address: Some(transceiver_address),
+                allow_unknown_chain: false,
+                allow_nonstandard_address: false,
+            })
+            .collect(),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    register_transceiver(
+        &good_ntt,
+        RegisterTransceiver {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            transceiver: good_ntt_transceiver.program(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // transfer tokens to custody account
+    spl_token::instruction::transfer_checked(
+        &Token::id(),
+        &test_data.user_token_account,
+        &test_data.mint,
+        &good_ntt.custody(&test_data.mint),
+        &test_data.user.pubkey(),
+        &[],
+        2000,
+        9,
+    )
+    .unwrap()
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    let recipient = Keypair::new();
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &ctx.payer.pubkey(),
+        &recipient.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+    let recipient_token_account = get_associated_token_address_with_program_id(
+        &recipient.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    );
+
+    // both peers can be redeemed from straight away
+    for (chain_id, manager, transceiver) in peers {
+        let peer: NttManagerPeer = ctx.get_account_data_anchor(good_ntt.peer(chain_id)).await;
+        assert_eq!(peer.address, manager);
+        assert_eq!(peer.token_decimals, 7);
+
+        let mut msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+        msg.message_data.source_ntt_manager = manager;
+
+        let vaa = post_vaa_helper(
+            &good_ntt,
+            chain_id.into(),
+            Address(transceiver),
+            msg.clone(),
+            &mut ctx,
+        )
+        .await;
+
+        receive_message(
+            &good_ntt,
+            &good_ntt_transceiver,
+            init_receive_message_accs(&good_ntt_transceiver, &mut ctx, vaa, chain_id, [0u8; 32]),
+        )
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+        redeem(
+            &good_ntt,
+            init_redeem_accs(
+                &good_ntt,
+                &good_ntt_transceiver,
+                &mut ctx,
+                chain_id,
+                msg.ntt_manager_payload.clone(),
+            ),
+            RedeemArgs {},
+        )
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+        release_inbound_unlock(
+            &good_ntt,
+            ReleaseInbound {
+                payer: ctx.payer.pubkey(),
+                inbox_item: good_ntt.inbox_item(chain_id, msg.ntt_manager_payload),
+                from_chain: chain_id,
+                mint: test_data.mint,
+                recipient: recipient_token_account,
+            },
+            ReleaseInboundArgs {
+                revert_when_not_ready: true,
+            },
+        )
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    }
+
+    let token_account: TokenAccount = ctx.get_account_data_anchor(recipient_token_account).await;
+    assert_eq!(token_account.amount, 2000);
+}
@@ -0,0 +1,354 @@
+#![cfg(feature = "test-sbf")]
+//! Runs the program against a deployment of the previous release, see
+//! [`test_utils::helpers::setup_with_v_prev_accounts`]. Its clients are
+//! simulated by truncating the account lists to the ones of that release.
+//!
+//! NOTE: when compatibility is broken on purpose (and the break documented),
+//! these tests can be skipped with `--skip v_prev`.
+
+use anchor_lang::prelude::ErrorCode;
+use anchor_spl::token::{Token, TokenAccount};
+use example_native_token_transfers::{
+    bitmap::Bitmap,
+    config::Config,
+    instructions::{RedeemArgs, ReleaseInboundArgs, SetOutboundLimitArgs, SetPeerArgs},
+    peer::NttManagerPeer,
+    queue::{
+        inbox::{InboxItem, ReleaseStatus},
+        outbox::{OutboxItem, OutboxRateLimit},
+    },
+    transceivers::wormhole::ReleaseOutboundArgs,
+};
+use ntt_messages::{chain_id::ChainId, mode::Mode, trimmed_amount::TrimmedAmount};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    transaction::TransactionError,
+};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use test_utils::{
+    common::{
+        fixtures::{OTHER_CHAIN, OTHER_MANAGER, OTHER_TRANSCEIVER},
+        query::GetAccountDataAnchor,
+        submit::Submittable,
+    },
+    helpers::{
+        capture_v_prev_accounts, init_receive_message_accs, init_redeem_accs,
+        init_transfer_accs_args, make_transfer_message, migrate_v_prev_accounts, post_vaa_helper,
+        setup_programs, setup_with_v_prev_accounts, token_balance, v_prev_inbox_item, v_prev_owner,
+        V_PREV_CUSTODY_AMOUNT, V_PREV_FIXTURES, V_PREV_INBOX_ITEM_AMOUNT, V_PREV_OUTBOX_ITEM,
+        V_PREV_OUTBOX_ITEM_AMOUNT, V_PREV_RECIPIENT, V_PREV_SENDER, V_PREV_TIMESTAMP,
+    },
+    sdk::{
+        accounts::{good_ntt, NTTAccounts},
+        instructions::{
+            admin::{
+                set_outbound_limit, set_paused, set_peer, SetOutboundLimit, SetPaused, SetPeer,
+            },
+            redeem::redeem,
+            release_inbound::{release_inbound_unlock, ReleaseInbound},
+            transfer::{approve_token_authority, transfer},
+        },
+        transceivers::{
+            accounts::good_ntt_transceiver,
+            instructions::{
+                receive_message::receive_message,
+                release_outbound::{release_outbound, ReleaseOutbound},
+            },
+        },
+    },
+};
+use wormhole_sdk::Address;
+
+/// The accounts of `transfer_lock` in the previous release.
+const V_PREV_TRANSFER_LOCK_ACCOUNTS: usize = 12;
+
+/// The accounts of `release_inbound_unlock` in the previous release.
+const V_PREV_RELEASE_INBOUND_UNLOCK_ACCOUNTS: usize = 8;
+
+#[tokio::test]
+async fn test_v_prev_admin() {
+    let (mut ctx, test_data) = setup_with_v_prev_accounts().await;
+
+    // the accounts are unusable until they are migrated
+    let err = set_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::AccountDidNotDeserialize.into())
+        )
+    );
+
+    migrate_v_prev_accounts(&mut ctx, &test_data).await;
+
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config.owner, v_prev_owner().pubkey());
+    assert_eq!(config.mint, test_data.mint);
+    assert_eq!(config.custody, good_ntt.custody(&test_data.mint));
+    assert_eq!(config.mode, Mode::Locking);
+    assert_eq!(config.threshold, 1);
+    assert_eq!(config.enabled_transceivers, Bitmap::from_value(1));
+    assert!(!config.paused_outbound);
+    // there's no custody ledger to keep in sync with custody
+    assert!(!config.tracks_custody);
+
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(peer.address, OTHER_MANAGER);
+    assert_eq!(peer.token_decimals, 7);
+
+    set_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert!(config.paused_outbound && config.paused_inbound);
+
+    set_outbound_limit(
+        &good_ntt,
+        SetOutboundLimit {
+            authority: test_data.program_owner.pubkey(),
+        },
+        SetOutboundLimitArgs { limit: 20000 },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    let outbox_rate_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+    assert_eq!(outbox_rate_limit.rate_limit.limit, 20000);
+
+    set_peer(
+        &good_ntt,
+        SetPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetPeerArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            address: OTHER_MANAGER,
+            limit: 1000,
+            token_decimals: 8,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(peer.token_decimals, 8);
+}
+
+#[tokio::test]
+async fn test_v_prev_transfer() {
+    let (mut ctx, test_data) = setup_with_v_prev_accounts().await;
+    migrate_v_prev_accounts(&mut ctx, &test_data).await;
+
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        1000,
+        false,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    let mut ix = transfer(&good_ntt, accs, args, Mode::Locking);
+    ix.accounts.truncate(V_PREV_TRANSFER_LOCK_ACCOUNTS);
+    ix.submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let outbox_item: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert_eq!(
+        outbox_item.amount,
+        TrimmedAmount {
+            amount: 10,
+            decimals: 7,
+        }
+    );
+    assert_eq!(outbox_item.recipient_ntt_manager, OTHER_MANAGER);
+    assert_eq!(
+        token_balance(&mut ctx, &good_ntt.custody(&test_data.mint)).await,
+        V_PREV_CUSTODY_AMOUNT + 1000
+    );
+}
+
+#[tokio::test]
+async fn test_v_prev_release_outbound() {
+    let (mut ctx, test_data) = setup_with_v_prev_accounts().await;
+    migrate_v_prev_accounts(&mut ctx, &test_data).await;
+
+    release_outbound(
+        &good_ntt,
+        &good_ntt_transceiver,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item: V_PREV_OUTBOX_ITEM,
+            recipient_chain: OTHER_CHAIN,
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let outbox_item: OutboxItem = ctx.get_account_data_anchor(V_PREV_OUTBOX_ITEM).await;
+    assert_eq!(outbox_item.amount, V_PREV_OUTBOX_ITEM_AMOUNT);
+    assert_eq!(outbox_item.sender, V_PREV_SENDER);
+    assert_eq!(outbox_item.recipient_address, V_PREV_RECIPIENT.to_bytes());
+    assert_eq!(outbox_item.released, Bitmap::from_value(1));
+}
+
+#[tokio::test]
+async fn test_v_prev_redeem() {
+    let (mut ctx, test_data) = setup_with_v_prev_accounts().await;
+    migrate_v_prev_accounts(&mut ctx, &test_data).await;
+
+    let recipient = get_associated_token_address_with_program_id(
+        &V_PREV_RECIPIENT,
+        &test_data.mint,
+        &Token::id(),
+    );
+    let msg = make_transfer_message(&good_ntt, [1u8; 32], 1000, &V_PREV_RECIPIENT);
+
+    let vaa = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+
+    receive_message(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(&good_ntt_transceiver, &mut ctx, vaa, OTHER_CHAIN, [1u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let mut ix = release_inbound_unlock(
+        &good_ntt,
+        ReleaseInbound {
+            payer: ctx.payer.pubkey(),
+            inbox_item: good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone()),
+            from_chain: OTHER_CHAIN,
+            mint: test_data.mint,
+            recipient,
+        },
+        ReleaseInboundArgs {
+            revert_when_not_ready: true,
+        },
+    );
+    // the deployment doesn't track custody, so there's no custody ledger
+    ix.accounts.pop();
+    ix.submit(&mut ctx).await.unwrap();
+
+    let token_account: TokenAccount = ctx.get_account_data_anchor(recipient).await;
+    assert_eq!(token_account.amount, 1000);
+}
+
+#[tokio::test]
+async fn test_v_prev_release_inbound() {
+    let (mut ctx, test_data) = setup_with_v_prev_accounts().await;
+    migrate_v_prev_accounts(&mut ctx, &test_data).await;
+
+    let inbox_item: InboxItem = ctx.get_account_data_anchor(v_prev_inbox_item()).await;
+    assert_eq!(inbox_item.amount, V_PREV_INBOX_ITEM_AMOUNT);
+    assert_eq!(inbox_item.recipient_address, V_PREV_RECIPIENT);
+    assert_eq!(
+        inbox_item.release_status(),
+        ReleaseStatus::ReleaseAfter(V_PREV_TIMESTAMP)
+    );
+
+    let recipient = get_associated_token_address_with_program_id(
+        &V_PREV_RECIPIENT,
+        &test_data.mint,
+        &Token::id(),
+    );
+    let mut ix = release_inbound_unlock(
+        &good_ntt,
+        ReleaseInbound {
+            payer: ctx.payer.pubkey(),
+            inbox_item: v_prev_inbox_item(),
+            // the chain is not recorded in legacy inbox items
+            from_chain: 0,
+            mint: test_data.mint,
+            recipient,
+        },
+        ReleaseInboundArgs {
+            revert_when_not_ready: true,
+        },
+    );
+    ix.accounts.truncate(V_PREV_RELEASE_INBOUND_UNLOCK_ACCOUNTS);
+    ix.submit(&mut ctx).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut ctx, &recipient).await,
+        V_PREV_INBOX_ITEM_AMOUNT
+    );
+    assert_eq!(
+        token_balance(&mut ctx, &good_ntt.custody(&test_data.mint)).await,
+        V_PREV_CUSTODY_AMOUNT - V_PREV_INBOX_ITEM_AMOUNT
+    );
+}
+
+/// Rewrites the images in [`V_PREV_FIXTURES`]. Only needed if the images
+/// themselves change, as the layouts they are in don't.
+#[tokio::test]
+#[ignore = "rewrites the fixtures"]
+async fn capture_v_prev_fixtures() {
+    let program_test = setup_programs(v_prev_owner().pubkey()).await.unwrap();
+    let mut ctx = program_test.start_with_context().await;
+    capture_v_prev_accounts(&mut ctx, V_PREV_FIXTURES).await;
+}
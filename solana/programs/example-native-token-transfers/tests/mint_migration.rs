@@ -0,0 +1,319 @@
+#![cfg(feature = "test-sbf")]
+
+use anchor_lang::prelude::Pubkey;
+use example_native_token_transfers::{
+    config::Config,
+    error::NTTError,
+    instructions::{RedeemArgs, ReleaseInboundArgs},
+};
+use ntt_messages::mode::Mode;
+use solana_program::{instruction::InstructionError, program_pack::Pack};
+use solana_program_test::*;
+use solana_sdk::{
+    signature::Keypair, signer::Signer, system_instruction, transaction::Transaction,
+    transaction::TransactionError,
+};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use test_utils::{
+    common::{
+        fixtures::{TestData, OTHER_CHAIN, OTHER_TRANSCEIVER},
+        query::GetAccountDataAnchor,
+        submit::Submittable,
+    },
+    helpers::{
+        fund_token_account, init_receive_message_accs, init_redeem_accs, init_transfer_accs_args,
+        make_transfer_message, post_vaa_helper, setup, token_balance,
+    },
+    sdk::{
+        accounts::{good_ntt, NTTAccounts},
+        instructions::{
+            admin::{
+                begin_mint_migration, complete_mint_migration, set_paused, BeginMintMigration,
+                CompleteMintMigration, SetPaused,
+            },
+            redeem::redeem,
+            release_inbound::{release_inbound_unlock, ReleaseInbound},
+            transfer::{
+                approve_token_authority, approve_token_authority_with_token_program_id, transfer,
+                transfer_lock_with_token_program_id, Transfer,
+            },
+        },
+        transceivers::{
+            accounts::good_ntt_transceiver, instructions::receive_message::receive_message,
+        },
+    },
+};
+use wormhole_sdk::Address;
+
+/// Creates a Token-2022 mint with the same decimals as the test mint.
+async fn create_token_2022_mint(
+    ctx: &mut ProgramTestContext,
+    mint: &Keypair,
+    mint_authority: &Pubkey,
+) {
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let len = spl_token_2022::state::Mint::LEN;
+
+    Transaction::new_with_payer(
+        &[
+            system_instruction::create_account(
+                &ctx.payer.pubkey(),
+                &mint.pubkey(),
+                rent.minimum_balance(len),
+                len as u64,
+                &spl_token_2022::ID,
+            ),
+            spl_token_2022::instruction::initialize_mint2(
+                &spl_token_2022::ID,
+                &mint.pubkey(),
+                mint_authority,
+                None,
+                9,
+            )
+            .unwrap(),
+        ],
+        Some(&ctx.payer.pubkey()),
+    )
+    .submit_with_signers(&[mint], ctx)
+    .await
+    .unwrap();
+}
+
+/// Sends `amount` from the user back in, which unlocks it from custody.
+async fn release_inbound(ctx: &mut ProgramTestContext, test_data: &TestData, amount: u64) {
+    let msg = make_transfer_message(&good_ntt, [7u8; 32], amount, &test_data.user.pubkey());
+    let vaa = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        ctx,
+    )
+    .await;
+
+    receive_message(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(&good_ntt_transceiver, ctx, vaa, OTHER_CHAIN, [7u8; 32]),
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            ctx,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    release_inbound_unlock(
+        &good_ntt,
+        ReleaseInbound {
+            payer: ctx.payer.pubkey(),
+            inbox_item: good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload),
+            from_chain: OTHER_CHAIN,
+            mint: test_data.mint,
+            recipient: test_data.user_token_account,
+        },
+        ReleaseInboundArgs {
+            revert_when_not_ready: true,
+        },
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_mint_migration() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // lock some tokens in custody
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        1000,
+        false,
+    );
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let new_mint = Keypair::new();
+    let new_mint_authority = Keypair::new();
+    create_token_2022_mint(&mut ctx, &new_mint, &new_mint_authority.pubkey()).await;
+
+    let begin = |payer| {
+        begin_mint_migration(
+            &good_ntt,
+            BeginMintMigration {
+                payer,
+                owner: test_data.program_owner.pubkey(),
+                mint: test_data.mint,
+                new_mint: new_mint.pubkey(),
+                new_token_program: spl_token_2022::ID,
+            },
+        )
+    };
+    let complete = |payer| {
+        complete_mint_migration(
+            &good_ntt,
+            CompleteMintMigration {
+                payer,
+                owner: test_data.program_owner.pubkey(),
+                rent_payer: payer,
+                custody: good_ntt.custody(&test_data.mint),
+                new_mint: new_mint.pubkey(),
+                new_token_program: spl_token_2022::ID,
+            },
+        )
+    };
+    let payer = ctx.payer.pubkey();
+
+    begin(payer)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert!(config.is_paused());
+
+    // the locked tokens would be stranded
+    let err = complete(payer)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::CustodyNotEmpty.into())
+        )
+    );
+
+    // wind down
+    set_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        false,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    release_inbound(&mut ctx, &test_data, 1000).await;
+
+    // the migration has to be restarted, as transfers were resumed
+    let err = complete(payer)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(NTTError::NotPaused.into()))
+    );
+
+    begin(payer)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+    complete(payer)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let new_custody =
+        good_ntt.custody_with_token_program_id(&new_mint.pubkey(), &spl_token_2022::ID);
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config.mint, new_mint.pubkey());
+    assert_eq!(config.token_program, spl_token_2022::ID);
+    assert_eq!(config.custody, new_custody);
+    assert!(!config.paused_outbound && !config.paused_inbound);
+    assert!(ctx
+        .banks_client
+        .get_account(good_ntt.pending_mint_migration())
+        .await
+        .unwrap()
+        .is_none());
+
+    // transfers now go through in the new mint
+    let user_token_account = get_associated_token_address_with_program_id(
+        &test_data.user.pubkey(),
+        &new_mint.pubkey(),
+        &spl_token_2022::ID,
+    );
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &payer,
+        &test_data.user.pubkey(),
+        &new_mint.pubkey(),
+        &spl_token_2022::ID,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+    fund_token_account(
+        &mut ctx,
+        &new_mint.pubkey(),
+        &new_mint_authority,
+        &user_token_account,
+        1000,
+    )
+    .await;
+
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        1000,
+        false,
+    );
+    approve_token_authority_with_token_program_id(
+        &good_ntt,
+        &user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+        &spl_token_2022::ID,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer_lock_with_token_program_id(
+        &good_ntt,
+        Transfer {
+            mint: new_mint.pubkey(),
+            from: user_token_account,
+            ..accs
+        },
+        args,
+        &spl_token_2022::ID,
+    )
+    .submit_with_signers(&[&outbox_item], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(token_balance(&mut ctx, &new_custody).await, 1000);
+}
@@ -0,0 +1,216 @@
+#![cfg(feature = "test-sbf")]
+
+use example_native_token_transfers::{
+    config::Config,
+    error::NTTError,
+    instructions::{RedeemArgs, ReleaseInboundArgs},
+    transfer::Payload,
+};
+use ntt_messages::{mode::Mode, ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage};
+use solana_program::instruction::InstructionError;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction, signature::Keypair, signer::Signer, transaction::TransactionError,
+};
+use test_utils::{
+    common::{
+        fixtures::{TestData, OTHER_CHAIN, OTHER_TRANSCEIVER},
+        query::GetAccountDataAnchor,
+        submit::Submittable,
+    },
+    helpers::{
+        init_receive_message_accs, init_redeem_accs, init_transfer_accs_args,
+        make_transfer_message, post_vaa_helper, setup, token_balance,
+    },
+    sdk::{
+        accounts::{good_ntt, NTTAccounts},
+        instructions::{
+            admin::{set_inbound_paused, set_outbound_paused, SetPaused},
+            redeem::redeem,
+            release_inbound::{release_inbound_mint, ReleaseInbound},
+            transfer::{approve_token_authority, transfer},
+        },
+        transceivers::{
+            accounts::good_ntt_transceiver, instructions::receive_message::receive_message,
+        },
+    },
+};
+use wormhole_sdk::Address;
+
+/// Receives a transfer of `amount` to the user with id `id`, without
+/// redeeming it.
+async fn receive_transfer(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    id: [u8; 32],
+    amount: u64,
+) -> NttManagerMessage<NativeTokenTransfer<Payload>> {
+    let msg = make_transfer_message(&good_ntt, id, amount, &test_data.user.pubkey());
+    let vaa = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        ctx,
+    )
+    .await;
+
+    receive_message(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(&good_ntt_transceiver, ctx, vaa, OTHER_CHAIN, id),
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    msg.ntt_manager_payload
+}
+
+fn redeem_ix(
+    ctx: &mut ProgramTestContext,
+    payload: NttManagerMessage<NativeTokenTransfer<Payload>>,
+) -> Instruction {
+    redeem(
+        &good_ntt,
+        init_redeem_accs(&good_ntt, &good_ntt_transceiver, ctx, OTHER_CHAIN, payload),
+        RedeemArgs {},
+    )
+}
+
+/// Sends 1000 tokens from the user out to the peer.
+async fn send_transfer(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+) -> Result<(), BanksClientError> {
+    let outbox_item = Keypair::new();
+    let (accs, args) =
+        init_transfer_accs_args(&good_ntt, ctx, test_data, outbox_item.pubkey(), 1000, false);
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Burning)
+        .submit_with_signers(&[&outbox_item], ctx)
+        .await
+}
+
+fn assert_paused(err: BanksClientError) {
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(NTTError::Paused.into()))
+    );
+}
+
+#[tokio::test]
+async fn test_outbound_paused() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+
+    set_outbound_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert!(config.paused_outbound);
+    assert!(!config.paused_inbound);
+
+    let err = send_transfer(&mut ctx, &test_data).await.unwrap_err();
+    assert_paused(err);
+
+    // inbound transfers still go through
+    let payload = receive_transfer(&mut ctx, &test_data, [1u8; 32], 1000).await;
+    redeem_ix(&mut ctx, payload.clone())
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    let balance = token_balance(&mut ctx, &test_data.user_token_account).await;
+    release_inbound_mint(
+        &good_ntt,
+        ReleaseInbound {
+            payer: ctx.payer.pubkey(),
+            inbox_item: good_ntt.inbox_item(OTHER_CHAIN, payload),
+            from_chain: OTHER_CHAIN,
+            mint: test_data.mint,
+            recipient: test_data.user_token_account,
+        },
+        ReleaseInboundArgs {
+            revert_when_not_ready: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+    assert_eq!(
+        token_balance(&mut ctx, &test_data.user_token_account).await,
+        balance + 1000
+    );
+
+    set_outbound_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        false,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    send_transfer(&mut ctx, &test_data).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_inbound_paused() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+
+    // received before the pause
+    let payload = receive_transfer(&mut ctx, &test_data, [1u8; 32], 1000).await;
+
+    set_inbound_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert!(!config.paused_outbound);
+    assert!(config.paused_inbound);
+
+    let err = redeem_ix(&mut ctx, payload.clone())
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+    assert_paused(err);
+
+    // outbound transfers still go through
+    send_transfer(&mut ctx, &test_data).await.unwrap();
+
+    set_inbound_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        false,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    redeem_ix(&mut ctx, payload).submit(&mut ctx).await.unwrap();
+}
@@ -0,0 +1,179 @@
+#![cfg(feature = "test-sbf")]
+
+//! Measures the compute units used by the instructions on the hot path of
+//! transfers, and reports the delta against the compute units they used
+//! before `OutboxItem` and `InboxItem` were made zero-copy. Regressions (e.g.
+//! from account (de)serialization) that eat into the savings fail the tests.
+//! Run with `--nocapture` to see the numbers.
+
+use example_native_token_transfers::{
+    instructions::RedeemArgs, messages::ValidatedTransceiverMessage, transfer::Payload,
+};
+use ntt_messages::{mode::Mode, ntt::NativeTokenTransfer};
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use test_utils::{
+    common::{
+        fixtures::{OTHER_CHAIN, OTHER_TRANSCEIVER},
+        submit::Submittable,
+    },
+    helpers::{
+        init_receive_message_accs, init_redeem_accs, init_transfer_accs_args,
+        make_transfer_message, post_vaa_helper, setup,
+    },
+    sdk::{
+        accounts::good_ntt,
+        instructions::{
+            redeem::redeem,
+            transfer::{approve_token_authority, transfer},
+        },
+        transceivers::{
+            accounts::{good_ntt_transceiver, NTTTransceiverAccounts},
+            instructions::receive_message::receive_message,
+        },
+    },
+};
+use wormhole_sdk::Address;
+
+/// The compute units used by `transfer_lock` in this test before the queue
+/// items were made zero-copy (i.e. borsh (de)serializing the `OutboxItem`).
+const PRE_ZERO_COPY_TRANSFER_LOCK_COMPUTE_UNITS: u64 = 60_000;
+
+/// The compute units used by `redeem` in this test before the queue items were
+/// made zero-copy (i.e. borsh (de)serializing the `InboxItem`).
+const PRE_ZERO_COPY_REDEEM_COMPUTE_UNITS: u64 = 45_000;
+
+/// The minimum reduction (in percent) against the pre zero-copy baseline.
+const MIN_REDUCTION_PERCENT: u64 = 10;
+
+/// Reports the compute units used by `instruction` against `baseline`, and
+/// checks that they were reduced by at least [`MIN_REDUCTION_PERCENT`].
+fn report_compute_units(instruction: &str, units_consumed: u64, baseline: u64) {
+    let reduction = baseline.saturating_sub(units_consumed) as f64 * 100.0 / baseline as f64;
+    println!(
+        "{instruction}: {units_consumed} compute units ({baseline} before zero-copy, {reduction:.1}% reduction)"
+    );
+    assert!(
+        units_consumed * 100 <= baseline * (100 - MIN_REDUCTION_PERCENT),
+        "{instruction} used {units_consumed} compute units, expected at least a \
+         {MIN_REDUCTION_PERCENT}% reduction from {baseline}"
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_lock_compute_units() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        154,
+        false,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    let out = transfer(&good_ntt, accs, args, Mode::Locking)
+        .simulate_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    report_compute_units(
+        "transfer_lock",
+        out.simulation_details.unwrap().units_consumed,
+        PRE_ZERO_COPY_TRANSFER_LOCK_COMPUTE_UNITS,
+    );
+}
+
+/// The compute units used to redeem a freshly received message. With
+/// `legacy_message`, the message is first rewritten in the layout of the
+/// initial release, which doesn't store the message hash (nor the bump), so
+/// redeem has to compute it.
+async fn redeem_compute_units(legacy_message: bool) -> u64 {
+    let recipient = Keypair::new();
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+
+    let vaa0 = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+
+    receive_message(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(
+            &good_ntt_transceiver,
+            &mut ctx,
+            vaa0,
+            OTHER_CHAIN,
+            [0u8; 32],
+        ),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    if legacy_message {
+        let transceiver_message = good_ntt_transceiver.transceiver_message(OTHER_CHAIN, [0u8; 32]);
+        let mut account = ctx
+            .banks_client
+            .get_account(transceiver_message)
+            .await
+            .unwrap()
+            .unwrap();
+        account
+            .data
+            .truncate(ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::BUMP_OFFSET);
+        account.data.resize(
+            ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::LEGACY_SPACE,
+            0,
+        );
+        ctx.set_account(&transceiver_message, &account.into());
+    }
+
+    let out = redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload,
+        ),
+        RedeemArgs {},
+    )
+    .simulate(&mut ctx)
+    .await
+    .unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    out.simulation_details.unwrap().units_consumed
+}
+
+#[tokio::test]
+async fn test_redeem_compute_units() {
+    let units_consumed = redeem_compute_units(false).await;
+    report_compute_units("redeem", units_consumed, PRE_ZERO_COPY_REDEEM_COMPUTE_UNITS);
+
+    // the inbox item is derived from the hash stored on receive
+    let legacy_units_consumed = redeem_compute_units(true).await;
+    assert!(units_consumed < legacy_units_consumed);
+}
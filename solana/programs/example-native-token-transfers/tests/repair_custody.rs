@@ -0,0 +1,137 @@
+#![cfg(feature = "test-sbf")]
+
+use anchor_lang::Id;
+use anchor_spl::token::{Token, TokenAccount};
+use example_native_token_transfers::instructions::CustodyReconciliation;
+use ntt_messages::mode::Mode;
+use solana_program_test::*;
+use solana_sdk::{account::AccountSharedData, signature::Keypair, signer::Signer};
+use test_utils::{
+    common::{query::GetAccountDataAnchor, submit::Submittable},
+    helpers::{custody_reconciliation, init_transfer_accs_args, setup},
+    sdk::{
+        accounts::{good_ntt, NTTAccounts},
+        instructions::{
+            repair_custody::{repair_custody, RepairCustody},
+            transfer::{approve_token_authority, transfer},
+        },
+    },
+};
+
+#[tokio::test]
+async fn test_repair_custody() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+
+    let custody = good_ntt.custody(&test_data.mint);
+
+    // simulate the custody account being closed
+    ctx.set_account(&custody, &AccountSharedData::default());
+    assert!(ctx
+        .banks_client
+        .get_account(custody)
+        .await
+        .unwrap()
+        .is_none());
+
+    repair_custody(
+        &good_ntt,
+        RepairCustody {
+            payer: ctx.payer.pubkey(),
+            mint: test_data.mint,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let custody_account: TokenAccount = ctx.get_account_data_anchor(custody).await;
+    assert_eq!(custody_account.owner, good_ntt.token_authority());
+    assert_eq!(custody_account.mint, test_data.mint);
+    assert_eq!(custody_account.amount, 0);
+
+    // repairing an existing custody account is a no-op
+    repair_custody(
+        &good_ntt,
+        RepairCustody {
+            payer: ctx.payer.pubkey(),
+            mint: test_data.mint,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_reconcile_custody() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    assert_eq!(
+        custody_reconciliation(&good_ntt, &mut ctx, test_data.mint).await,
+        CustodyReconciliation {
+            custody_balance: 0,
+            locked_amount: 0,
+            matches: true,
+        }
+    );
+
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        1000,
+        false,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    // locked tokens are tracked
+    assert_eq!(
+        custody_reconciliation(&good_ntt, &mut ctx, test_data.mint).await,
+        CustodyReconciliation {
+            custody_balance: 1000,
+            locked_amount: 1000,
+            matches: true,
+        }
+    );
+
+    // tokens sent straight to custody are not
+    spl_token::instruction::transfer_checked(
+        &Token::id(),
+        &test_data.user_token_account,
+        &test_data.mint,
+        &good_ntt.custody(&test_data.mint),
+        &test_data.user.pubkey(),
+        &[],
+        5,
+        9,
+    )
+    .unwrap()
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        custody_reconciliation(&good_ntt, &mut ctx, test_data.mint).await,
+        CustodyReconciliation {
+            custody_balance: 1005,
+            locked_amount: 1000,
+            matches: false,
+        }
+    );
+}
@@ -0,0 +1,237 @@
+#![cfg(feature = "test-sbf")]
+
+use example_native_token_transfers::{
+    config::Config,
+    error::NTTError,
+    instructions::{RedeemArgs, SetPeerArgs, SetPeerOptions},
+    peer::NttManagerPeer,
+};
+use ntt_messages::{chain_id::ChainId, mode::Mode};
+use solana_program::instruction::InstructionError;
+use solana_program_test::*;
+use solana_sdk::{
+    pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::TransactionError,
+};
+use test_utils::{
+    common::{
+        fixtures::{
+            TestData, INBOUND_LIMIT, OTHER_CHAIN, OTHER_MANAGER, OTHER_TRANSCEIVER,
+            UNREGISTERED_CHAIN,
+        },
+        query::GetAccountDataAnchor,
+        submit::Submittable,
+    },
+    helpers::{
+        init_receive_message_accs, init_redeem_accs, init_transfer_accs_args,
+        make_transfer_message, post_vaa_helper, setup,
+    },
+    sdk::{
+        accounts::{good_ntt, NTTAccounts},
+        instructions::{
+            admin::{
+                disable_route, enable_route, set_peer_with_options, set_routes_default_enabled,
+                SetPeer, SetRouteEnabled, SetRoutesDefaultEnabled,
+            },
+            redeem::redeem,
+            transfer::{approve_token_authority, transfer},
+        },
+        transceivers::{
+            accounts::good_ntt_transceiver, instructions::receive_message::receive_message,
+        },
+    },
+};
+use wormhole_sdk::Address;
+
+async fn set_route(ctx: &mut ProgramTestContext, test_data: &TestData, enabled: bool) {
+    let accounts = SetRouteEnabled {
+        owner: test_data.program_owner.pubkey(),
+    };
+    let ix = if enabled {
+        enable_route(&good_ntt, accounts, OTHER_CHAIN)
+    } else {
+        disable_route(&good_ntt, accounts, OTHER_CHAIN)
+    };
+    ix.submit_with_signers(&[&test_data.program_owner], ctx)
+        .await
+        .unwrap();
+}
+
+async fn try_transfer(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+) -> Result<(), BanksClientError> {
+    let outbox_item = Keypair::new();
+
+    let (accs, args) =
+        init_transfer_accs_args(&good_ntt, ctx, test_data, outbox_item.pubkey(), 100, false);
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], ctx)
+        .await
+}
+
+async fn try_redeem(ctx: &mut ProgramTestContext, id: [u8; 32]) -> Result<(), BanksClientError> {
+    let msg = make_transfer_message(&good_ntt, id, 1000, &Pubkey::new_unique());
+
+    let vaa = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        ctx,
+    )
+    .await;
+
+    receive_message(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(&good_ntt_transceiver, ctx, vaa, OTHER_CHAIN, id),
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            ctx,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(ctx)
+    .await
+}
+
+#[tokio::test]
+async fn test_routes_enabled_by_default() {
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
+
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert!(config.routes_default_enabled);
+
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert!(peer.enabled);
+}
+
+#[tokio::test]
+async fn test_new_routes_default_disabled() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_routes_default_enabled(
+        &good_ntt,
+        SetRoutesDefaultEnabled {
+            owner: test_data.program_owner.pubkey(),
+        },
+        false,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    for chain_id in [OTHER_CHAIN, UNREGISTERED_CHAIN] {
+        set_peer_with_options(
+            &good_ntt,
+            SetPeer {
+                payer: ctx.payer.pubkey(),
+                owner: test_data.program_owner.pubkey(),
+            },
+            SetPeerArgs {
+                chain_id: ChainId { id: chain_id },
+                address: OTHER_MANAGER,
+                limit: INBOUND_LIMIT,
+                token_decimals: 7,
+            },
+            SetPeerOptions {
+                allow_unknown_chain: chain_id == UNREGISTERED_CHAIN,
+                allow_nonstandard_address: false,
+            },
+        )
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+    }
+
+    // updating an existing peer leaves its route as it was
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert!(peer.enabled);
+
+    // new peers start out disabled
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(UNREGISTERED_CHAIN))
+        .await;
+    assert!(!peer.enabled);
+
+    enable_route(
+        &good_ntt,
+        SetRouteEnabled {
+            owner: test_data.program_owner.pubkey(),
+        },
+        UNREGISTERED_CHAIN,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(UNREGISTERED_CHAIN))
+        .await;
+    assert!(peer.enabled);
+}
+
+#[tokio::test]
+async fn test_disabled_route_outbound() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_route(&mut ctx, &test_data, false).await;
+
+    let err = try_transfer(&mut ctx, &test_data).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::RouteDisabled.into())
+        )
+    );
+
+    set_route(&mut ctx, &test_data, true).await;
+
+    try_transfer(&mut ctx, &test_data).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_disabled_route_inbound() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_route(&mut ctx, &test_data, false).await;
+
+    let err = try_redeem(&mut ctx, [0u8; 32]).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::RouteDisabled.into())
+        )
+    );
+
+    set_route(&mut ctx, &test_data, true).await;
+
+    try_redeem(&mut ctx, [1u8; 32]).await.unwrap();
+}
@@ -0,0 +1,324 @@
+#![cfg(feature = "test-sbf")]
+
+use anchor_lang::prelude::{Clock, ErrorCode, Pubkey};
+use anchor_spl::token::Token;
+use example_native_token_transfers::{
+    config::Config,
+    error::NTTError,
+    instructions::SetSenderLimitOverrideArgs,
+    queue::{
+        outbox::{OutboxItem, SenderRateLimit},
+        rate_limit::RateLimitState,
+    },
+};
+use ntt_messages::mode::Mode;
+use solana_program::instruction::InstructionError;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::TransactionError};
+use spl_associated_token_account::get_associated_token_address;
+use test_utils::{
+    common::{fixtures::TestData, query::GetAccountDataAnchor, submit::Submittable},
+    helpers::{assert_queued, init_transfer_accs_args, setup},
+    sdk::{
+        accounts::{good_ntt, NTTAccounts},
+        instructions::{
+            admin::{
+                set_sender_limit, set_sender_limit_override, SetSenderLimit, SetSenderLimitOverride,
+            },
+            transfer::{approve_token_authority, transfer, Transfer},
+        },
+    },
+};
+
+const SENDER_LIMIT: u64 = 1500;
+
+/// Sends `amount` from `from`, owned by `sender`, out to the peer, returning
+/// the outbox item.
+async fn send_transfer(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    sender: &Keypair,
+    from: Pubkey,
+    amount: u64,
+    should_queue: bool,
+) -> Result<Pubkey, BanksClientError> {
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        ctx,
+        test_data,
+        outbox_item.pubkey(),
+        amount,
+        should_queue,
+    );
+    let accs = Transfer {
+        from,
+        from_authority: sender.pubkey(),
+        sender_limited: true,
+        ..accs
+    };
+    approve_token_authority(&good_ntt, &from, &sender.pubkey(), &args)
+        .submit_with_signers(&[sender], ctx)
+        .await
+        .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], ctx)
+        .await?;
+    Ok(outbox_item.pubkey())
+}
+
+/// Sends 1000 tokens from the test user out to the peer, passing the user's
+/// rate limit only if `sender_limited`.
+async fn send_user_transfer(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    sender_limited: bool,
+) -> Result<(), BanksClientError> {
+    let outbox_item = Keypair::new();
+    let (accs, args) =
+        init_transfer_accs_args(&good_ntt, ctx, test_data, outbox_item.pubkey(), 1000, false);
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+    transfer(
+        &good_ntt,
+        Transfer {
+            sender_limited,
+            ..accs
+        },
+        args,
+        Mode::Locking,
+    )
+    .submit_with_signers(&[&outbox_item], ctx)
+    .await
+}
+
+/// A sender other than the test user, holding `amount` tokens.
+async fn other_sender(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    amount: u64,
+) -> (Keypair, Pubkey) {
+    let sender = Keypair::new();
+    let token_account = get_associated_token_address(&sender.pubkey(), &test_data.mint);
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &ctx.payer.pubkey(),
+        &sender.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+    spl_token::instruction::transfer_checked(
+        &Token::id(),
+        &test_data.user_token_account,
+        &test_data.mint,
+        &token_account,
+        &test_data.user.pubkey(),
+        &[],
+        amount,
+        9,
+    )
+    .unwrap()
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+    (sender, token_account)
+}
+
+fn assert_ntt_error(err: BanksClientError, error: NTTError) {
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(error.into()))
+    );
+}
+
+#[tokio::test]
+async fn test_sender_limit() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let (other_sender, other_token_account) = other_sender(&mut ctx, &test_data, 1000).await;
+
+    set_sender_limit(
+        &good_ntt,
+        SetSenderLimit {
+            authority: test_data.program_owner.pubkey(),
+        },
+        Some(SENDER_LIMIT),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config.sender_limit, Some(SENDER_LIMIT));
+
+    let user = &test_data.user;
+    let user_token_account = test_data.user_token_account;
+
+    // the sender's rate limit is created on their first transfer
+    send_transfer(&mut ctx, &test_data, user, user_token_account, 1000, false)
+        .await
+        .unwrap();
+    let sender_rate_limit: SenderRateLimit = ctx
+        .get_account_data_anchor(good_ntt.sender_rate_limit(&user.pubkey()))
+        .await;
+    assert_eq!(sender_rate_limit.limit_override, None);
+    assert_eq!(sender_rate_limit.rate_limit.limit, SENDER_LIMIT);
+    assert_eq!(sender_rate_limit.rate_limit.capacity_at_last_tx, 500);
+
+    // well within the global limit, but not the sender's
+    let err = send_transfer(&mut ctx, &test_data, user, user_token_account, 1000, false)
+        .await
+        .unwrap_err();
+    assert_ntt_error(err, NTTError::TransferExceedsSenderRateLimit);
+
+    let outbox_item = send_transfer(&mut ctx, &test_data, user, user_token_account, 1000, true)
+        .await
+        .unwrap();
+    assert_queued(&mut ctx, outbox_item).await;
+
+    // delayed the same way as by the outbound rate limit
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let queued_item: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+    assert_eq!(
+        queued_item.release_timestamp,
+        clock.unix_timestamp + RateLimitState::RATE_LIMIT_DURATION
+    );
+
+    // other senders are unaffected
+    let outbox_item = send_transfer(
+        &mut ctx,
+        &test_data,
+        &other_sender,
+        other_token_account,
+        1000,
+        false,
+    )
+    .await
+    .unwrap();
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let outbox_item: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+    assert!(outbox_item.release_timestamp <= clock.unix_timestamp);
+
+    // an override raises the limit for this sender only
+    set_sender_limit_override(
+        &good_ntt,
+        SetSenderLimitOverride {
+            payer: ctx.payer.pubkey(),
+            authority: test_data.program_owner.pubkey(),
+        },
+        SetSenderLimitOverrideArgs {
+            sender: user.pubkey(),
+            limit: Some(2 * SENDER_LIMIT),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    let sender_rate_limit: SenderRateLimit = ctx
+        .get_account_data_anchor(good_ntt.sender_rate_limit(&user.pubkey()))
+        .await;
+    assert_eq!(sender_rate_limit.limit_override, Some(2 * SENDER_LIMIT));
+    assert_eq!(sender_rate_limit.rate_limit.limit, 2 * SENDER_LIMIT);
+
+    send_transfer(&mut ctx, &test_data, user, user_token_account, 1000, false)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_set_sender_limit_unauthorized() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let err = set_sender_limit(
+        &good_ntt,
+        SetSenderLimit {
+            authority: test_data.user.pubkey(),
+        },
+        Some(SENDER_LIMIT),
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_ntt_error(err, NTTError::Unauthorized);
+
+    let err = set_sender_limit_override(
+        &good_ntt,
+        SetSenderLimitOverride {
+            payer: ctx.payer.pubkey(),
+            authority: test_data.user.pubkey(),
+        },
+        SetSenderLimitOverrideArgs {
+            sender: test_data.user.pubkey(),
+            limit: Some(u64::MAX),
+        },
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_ntt_error(err, NTTError::Unauthorized);
+}
+
+#[tokio::test]
+async fn test_sender_rate_limit_only_needed_when_limited() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let sender_rate_limit = good_ntt.sender_rate_limit(&test_data.user.pubkey());
+
+    // senders are not limited, so their rate limit is neither needed nor
+    // created, even when it's passed
+    for sender_limited in [false, true] {
+        send_user_transfer(&mut ctx, &test_data, sender_limited)
+            .await
+            .unwrap();
+        assert!(ctx
+            .banks_client
+            .get_account(sender_rate_limit)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    // an override for any sender limits that sender without a default limit,
+    // so every transfer has to pass its rate limit from then on
+    let other_sender = Keypair::new();
+    set_sender_limit_override(
+        &good_ntt,
+        SetSenderLimitOverride {
+            payer: ctx.payer.pubkey(),
+            authority: test_data.program_owner.pubkey(),
+        },
+        SetSenderLimitOverrideArgs {
+            sender: other_sender.pubkey(),
+            limit: Some(SENDER_LIMIT),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert!(config.limits_senders());
+
+    let err = send_user_transfer(&mut ctx, &test_data, false)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::AccountNotEnoughKeys.into())
+        )
+    );
+
+    send_user_transfer(&mut ctx, &test_data, true)
+        .await
+        .unwrap();
+    let sender_rate_limit: SenderRateLimit = ctx.get_account_data_anchor(sender_rate_limit).await;
+    // the user has no limit of its own
+    assert_eq!(sender_rate_limit.limit(config.sender_limit), None);
+}
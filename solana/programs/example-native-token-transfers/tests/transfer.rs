@@ -1,14 +1,25 @@
 #![cfg(feature = "test-sbf")]
-#![feature(type_changing_struct_update)]
 
-use anchor_lang::prelude::{Clock, ErrorCode, Pubkey};
-use anchor_spl::token::{Mint, TokenAccount};
+use anchor_lang::{
+    prelude::{Clock, ErrorCode, Pubkey},
+    AccountSerialize, Id,
+};
+use anchor_spl::token::{Mint, Token, TokenAccount};
 use example_native_token_transfers::{
     bitmap::Bitmap,
+    config::Config,
     error::NTTError,
-    instructions::{SetOutboundLimitArgs, TransferArgs},
-    queue::outbox::{OutboxItem, OutboxRateLimit},
-    transceivers::wormhole::ReleaseOutboundArgs,
+    instructions::{
+        ForceMarkReleasedArgs, RateLimitParams, RateLimits, SetOutboundLimitArgs, TransferArgs,
+        TransferOptions, TransferStatus, WouldQueueResult,
+    },
+    peer::NttManagerPeer,
+    queue::{
+        inbox::InboxRateLimit,
+        outbox::{OutboxItem, OutboxRateLimit},
+        rate_limit::RateLimitState,
+    },
+    transceivers::wormhole::{ReleaseOutboundArgs, ReleaseOutboundOptions},
     transfer::Payload,
 };
 use ntt_messages::{
@@ -18,32 +29,56 @@ use ntt_messages::{
 };
 use solana_program_test::*;
 use solana_sdk::{
-    instruction::InstructionError, signature::Keypair, signer::Signer,
-    transaction::TransactionError,
+    account::AccountSharedData,
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    system_instruction,
+    transaction::{Transaction, TransactionError},
 };
+use spl_associated_token_account::get_associated_token_address_with_program_id;
 use test_utils::{
     common::{
-        fixtures::{TestData, ANOTHER_CHAIN, OTHER_MANAGER, OUTBOUND_LIMIT, UNREGISTERED_CHAIN},
+        fixtures::{
+            TestData, ANOTHER_CHAIN, OTHER_CHAIN, OTHER_MANAGER, OUTBOUND_LIMIT, UNREGISTERED_CHAIN,
+        },
         query::GetAccountDataAnchor,
         submit::Submittable,
     },
-    helpers::{assert_queued, init_transfer_accs_args, setup, setup_with_transfer_fee},
+    helpers::{
+        assert_queued, assert_retry_after, fund_token_account, inbound_capacity,
+        init_transfer_accs_args, outbound_capacity, overwrite_mint_authority, rate_limit_params,
+        reported_inbound_capacity, reported_outbound_capacity, setup, setup_with_transfer_fee,
+        token_balance, transfer_preview, transfer_status, would_queue_status,
+    },
     sdk::{
         accounts::{good_ntt, NTTAccounts},
         instructions::{
-            admin::{set_outbound_limit, set_paused, SetOutboundLimit, SetPaused},
+            admin::{
+                force_mark_released, set_allow_queue, set_destination_fee_bps, set_dust_account,
+                set_fee_collector, set_max_outbound_in_flight, set_outbound_limit, set_paused,
+                set_sender_limit, ForceMarkReleased, SetAllowQueue, SetDestinationFeeBps,
+                SetDustAccount, SetFeeCollector, SetMaxOutboundInFlight, SetOutboundLimit,
+                SetPaused, SetSenderLimit,
+            },
+            cancel_outbound_transfer::{cancel_outbound_transfer, CancelOutbound},
+            close_expired_outbox_item::{close_expired_outbox_item, CloseExpiredOutboxItem},
             transfer::{
-                approve_token_authority, approve_token_authority_with_token_program_id, transfer,
-                transfer_with_token_program_id,
+                approve_token_authority, approve_token_authority_with_options,
+                approve_token_authority_with_token_program_id, transfer, transfer_with_expiry,
+                transfer_with_options, transfer_with_token_program_id, Transfer,
             },
         },
         transceivers::{
             accounts::{good_ntt_transceiver, NTTTransceiverAccounts},
-            instructions::release_outbound::{release_outbound, ReleaseOutbound},
+            instructions::release_outbound::{
+                release_outbound, release_outbound_with_fee_payer, release_outbound_with_options,
+                ReleaseOutbound,
+            },
         },
     },
 };
-use wormhole_anchor_sdk::wormhole::PostedVaa;
+use wormhole_anchor_sdk::wormhole::{BridgeData, PostedVaa};
 
 #[tokio::test]
 pub async fn test_transfer_locking() {
@@ -81,6 +116,91 @@ pub async fn test_transfer_burning_with_transfer_fee() {
     .await;
 }
 
+/// Transfers keep working with the account lists of clients that predate the
+/// optional accounts, as long as the features those are for are off.
+#[tokio::test]
+pub async fn test_transfer_with_initial_accounts() {
+    for mode in [Mode::Burning, Mode::Locking] {
+        let (mut ctx, test_data) = setup(mode).await;
+        // the accounts of `transfer_burn` and `transfer_lock` in the initial
+        // release
+        let initial_accounts = match mode {
+            Mode::Burning => 13,
+            Mode::Locking => 12,
+        };
+
+        let send = |ctx: &mut ProgramTestContext| {
+            let outbox_item = Keypair::new();
+            let (accs, args) = init_transfer_accs_args(
+                &good_ntt,
+                ctx,
+                &test_data,
+                outbox_item.pubkey(),
+                1000,
+                false,
+            );
+            let approve = approve_token_authority(
+                &good_ntt,
+                &test_data.user_token_account,
+                &test_data.user.pubkey(),
+                &args,
+            );
+            let mut ix = transfer(&good_ntt, accs, args, mode);
+            ix.accounts.truncate(initial_accounts);
+            (approve, ix, outbox_item)
+        };
+
+        // deployments created along with the custody ledger require it
+        let (approve, ix, outbox_item) = send(&mut ctx);
+        approve
+            .submit_with_signers(&[&test_data.user], &mut ctx)
+            .await
+            .unwrap();
+        let result = ix.submit_with_signers(&[&outbox_item], &mut ctx).await;
+        match mode {
+            Mode::Burning => result.unwrap(),
+            Mode::Locking => assert_eq!(
+                result.unwrap_err().unwrap(),
+                TransactionError::InstructionError(
+                    0,
+                    InstructionError::Custom(ErrorCode::AccountNotEnoughKeys.into())
+                )
+            ),
+        }
+
+        // whereas deployments migrated from before it don't have one
+        let mut config_account = ctx
+            .banks_client
+            .get_account(good_ntt.config())
+            .await
+            .unwrap()
+            .unwrap();
+        let mut config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+        config.tracks_custody = false;
+        config
+            .try_serialize(&mut &mut config_account.data[..])
+            .unwrap();
+        ctx.set_account(&good_ntt.config(), &config_account.into());
+
+        let (approve, ix, outbox_item) = send(&mut ctx);
+        approve
+            .submit_with_signers(&[&test_data.user], &mut ctx)
+            .await
+            .unwrap();
+        ix.submit_with_signers(&[&outbox_item], &mut ctx)
+            .await
+            .unwrap();
+        let outbox_item: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+        assert_eq!(
+            outbox_item.amount,
+            TrimmedAmount {
+                amount: 10,
+                decimals: 7,
+            }
+        );
+    }
+}
+
 /// This tests the happy path of a transfer, with all the relevant account checks.
 /// Written as a helper function so both modes can be tested.
 async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode: Mode) {
@@ -107,21 +227,19 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
 
     let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
 
-    assert_eq!(
-        outbox_item_account,
-        OutboxItem {
-            amount: TrimmedAmount {
-                amount: 1,
-                decimals: 7
-            },
-            sender: test_data.user.pubkey(),
-            recipient_chain: ChainId { id: 2 },
-            recipient_ntt_manager: OTHER_MANAGER,
-            recipient_address: [1u8; 32],
-            release_timestamp: clock.unix_timestamp,
-            released: Bitmap::new(),
-        }
+    let mut expected = OutboxItem::new(
+        TrimmedAmount {
+            amount: 1,
+            decimals: 7,
+        },
+        test_data.user.pubkey(),
+        ChainId { id: 2 },
+        OTHER_MANAGER,
+        [1u8; 32],
+        clock.unix_timestamp,
     );
+    expected.rent_payer = ctx.payer.pubkey();
+    assert_eq!(outbox_item_account, expected);
 
     release_outbound(
         &good_ntt,
@@ -129,6 +247,7 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
             outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
         },
         ReleaseOutboundArgs {
             revert_on_delay: true,
@@ -142,13 +261,11 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
         ctx.get_account_data_anchor(outbox_item.pubkey()).await;
 
     // make sure the outbox item is now released, but nothing else has changed
-    assert_eq!(
-        OutboxItem {
-            released: Bitmap::from_value(1),
-            ..outbox_item_account
-        },
-        outbox_item_account_after,
-    );
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let mut expected = outbox_item_account;
+    expected.released = Bitmap::from_value(1);
+    expected.released_at[0] = clock.unix_timestamp;
+    assert_eq!(expected, outbox_item_account_after);
 
     let wh_message = good_ntt_transceiver.wormhole_message(&outbox_item.pubkey());
 
@@ -171,6 +288,7 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
                 id: outbox_item.pubkey().to_bytes(),
                 sender: test_data.user.pubkey().to_bytes(),
                 payload: NativeTokenTransfer {
+                    version: NativeTokenTransfer::<Payload>::CURRENT_VERSION,
                     amount: TrimmedAmount {
                         amount: 1,
                         decimals: 7
@@ -268,6 +386,210 @@ async fn test_burn_mode_burns_tokens() {
     );
 }
 
+#[tokio::test]
+async fn test_burn_mint_authority_rotated_away() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+
+    let outbox_item = Keypair::new();
+
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        100,
+        false,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    overwrite_mint_authority(&mut ctx, &test_data.mint, Some(Pubkey::new_unique())).await;
+
+    let err = transfer(&good_ntt, accs, args, Mode::Burning)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::MintAuthorityRotatedAway.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_status() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        154,
+        false,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let created = TransferStatus {
+        amount: TrimmedAmount {
+            amount: 1,
+            decimals: 7,
+        },
+        recipient_chain: OTHER_CHAIN,
+        recipient_address: [1u8; 32],
+        release_timestamp: clock.unix_timestamp,
+        rate_limited: false,
+        released: Bitmap::new(),
+        wormhole_sequence: None,
+    };
+    assert_eq!(
+        transfer_status(&good_ntt, &mut ctx, outbox_item.pubkey()).await,
+        created
+    );
+
+    release_outbound(
+        &good_ntt,
+        &good_ntt_transceiver,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    // see [`test_transfer`] on parsing the posted message as a VAA
+    let msg: PostedVaa<TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>>> = ctx
+        .get_account_data_anchor_unchecked(
+            good_ntt_transceiver.wormhole_message(&outbox_item.pubkey()),
+        )
+        .await;
+
+    assert_eq!(
+        transfer_status(&good_ntt, &mut ctx, outbox_item.pubkey()).await,
+        TransferStatus {
+            released: Bitmap::from_value(1),
+            wormhole_sequence: Some(msg.sequence()),
+            ..created
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_rate_limit_not_initialized() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // simulate the outbox rate limit account going missing
+    ctx.set_account(&good_ntt.outbox_rate_limit(), &AccountSharedData::default());
+
+    let outbox_item = Keypair::new();
+
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        100,
+        false,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    let err = transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::RateLimitNotInitialized.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_rejects_zero_clock() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        100,
+        true,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    // as on a misconfigured local validator
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp = 0;
+    ctx.set_sysvar(&clock);
+
+    let err = transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidClock.into())
+        )
+    );
+}
+
 #[tokio::test]
 async fn locking_mode_locks_tokens() {
     let (mut ctx, test_data) = setup(Mode::Locking).await;
@@ -379,7 +701,49 @@ async fn test_bad_mint() {
         err.unwrap(),
         TransactionError::InstructionError(
             0,
-            InstructionError::Custom(ErrorCode::ConstraintAddress.into())
+            InstructionError::Custom(NTTError::SourceMintMismatch.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_bad_mint_source_token_account() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+
+    let (mut accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        1050,
+        false,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.bad_user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    // the mint is right, but the tokens are sent from an account of another mint
+    accs.from = test_data.bad_user_token_account;
+
+    let err = transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::SourceMintMismatch.into())
         )
     );
 }
@@ -577,8 +941,9 @@ async fn test_rate_limit() {
 }
 
 #[tokio::test]
-async fn test_transfer_wrong_mode() {
-    let (mut ctx, test_data) = setup(Mode::Burning).await;
+async fn test_rate_limit_params() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
     let outbox_item = Keypair::new();
 
     let (accs, args) = init_transfer_accs_args(
@@ -599,37 +964,294 @@ async fn test_transfer_wrong_mode() {
     .submit_with_signers(&[&test_data.user], &mut ctx)
     .await
     .unwrap();
-    // make sure we can't transfer in the wrong mode
-    let err = transfer(&good_ntt, accs.clone(), args.clone(), Mode::Locking)
+    transfer(&good_ntt, accs, args, Mode::Locking)
         .submit_with_signers(&[&outbox_item], &mut ctx)
         .await
-        .unwrap_err();
+        .unwrap();
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let inbound: InboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.inbox_rate_limit(OTHER_CHAIN))
+        .await;
+    let outbound: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+    let expected = |rate_limit: &RateLimitState| RateLimitParams {
+        limit: rate_limit.limit,
+        capacity_at_last_tx: rate_limit.capacity_at_last_tx,
+        last_tx_timestamp: rate_limit.last_tx_timestamp,
+        capacity: rate_limit.capacity_at(clock.unix_timestamp),
+        refill_duration: RateLimitState::RATE_LIMIT_DURATION,
+    };
 
+    let params = rate_limit_params(&good_ntt, &mut ctx, OTHER_CHAIN).await;
     assert_eq!(
-        err.unwrap(),
-        TransactionError::InstructionError(
-            0,
-            InstructionError::Custom(NTTError::InvalidMode.into())
-        )
+        params,
+        RateLimits {
+            inbound: expected(&inbound.rate_limit),
+            outbound: expected(&outbound.rate_limit),
+        }
     );
+    assert_eq!(params.outbound.capacity, OUTBOUND_LIMIT - 100);
+    assert_eq!(params.outbound.last_tx_timestamp, clock.unix_timestamp);
 }
 
 #[tokio::test]
-async fn test_cant_transfer_more_than_balance() {
+async fn test_get_capacity() {
     let (mut ctx, test_data) = setup(Mode::Locking).await;
 
     let outbox_item = Keypair::new();
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        OUTBOUND_LIMIT / 2,
+        false,
+    );
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let capacity = reported_outbound_capacity(&good_ntt, &mut ctx).await;
+    assert_eq!(capacity, OUTBOUND_LIMIT / 2);
+    assert_eq!(capacity, outbound_capacity(&good_ntt, &mut ctx).await);
+    assert_eq!(
+        reported_inbound_capacity(&good_ntt, &mut ctx, OTHER_CHAIN).await,
+        inbound_capacity(&good_ntt, &mut ctx).await
+    );
+
+    // the capacity refills over time, which is taken into account
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += RateLimitState::RATE_LIMIT_DURATION / 4;
+    ctx.set_sysvar(&clock);
+
+    let capacity = reported_outbound_capacity(&good_ntt, &mut ctx).await;
+    assert!(capacity > OUTBOUND_LIMIT / 2);
+    assert_eq!(capacity, outbound_capacity(&good_ntt, &mut ctx).await);
+}
+
+#[tokio::test]
+async fn test_rate_limit_same_transaction() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+
+    // each transfer fits in the limit on its own, but not both together
+    let first_amount = OUTBOUND_LIMIT - 4000;
+    let second_amount = OUTBOUND_LIMIT - 5000;
+    assert!(first_amount + second_amount > OUTBOUND_LIMIT);
+
+    let first_outbox_item = Keypair::new();
+    let (first_accs, first_args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        first_outbox_item.pubkey(),
+        first_amount,
+        true,
+    );
+
+    let second_outbox_item = Keypair::new();
+    let (second_accs, second_args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        second_outbox_item.pubkey(),
+        second_amount,
+        true,
+    );
+
+    // NOTE: a token account only has a single delegate, so each transfer is
+    // approved right before it's made
+    Transaction::new_with_payer(
+        &[
+            approve_token_authority(
+                &good_ntt,
+                &test_data.user_token_account,
+                &test_data.user.pubkey(),
+                &first_args,
+            ),
+            transfer(&good_ntt, first_accs, first_args, Mode::Locking),
+            approve_token_authority(
+                &good_ntt,
+                &test_data.user_token_account,
+                &test_data.user.pubkey(),
+                &second_args,
+            ),
+            transfer(&good_ntt, second_accs, second_args, Mode::Locking),
+        ],
+        Some(&ctx.payer.pubkey()),
+    )
+    .submit_with_signers(
+        &[&test_data.user, &first_outbox_item, &second_outbox_item],
+        &mut ctx,
+    )
+    .await
+    .unwrap();
+
+    // the first transfer consumed the capacity, which the second one saw
+    let first: OutboxItem = ctx
+        .get_account_data_anchor(first_outbox_item.pubkey())
+        .await;
+    assert_eq!(first.release_timestamp, clock.unix_timestamp);
+    assert_queued(&mut ctx, second_outbox_item.pubkey()).await;
+
+    let outbound_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+    assert_eq!(
+        outbound_limit.capacity_at(clock.unix_timestamp),
+        OUTBOUND_LIMIT - first_amount
+    );
+}
+
+#[tokio::test]
+async fn test_set_outbound_limit_with_queued_transfer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let mut send = |amount, should_queue| {
+        let outbox_item = Keypair::new();
+        let (accs, args) = init_transfer_accs_args(
+            &good_ntt,
+            &mut ctx,
+            &test_data,
+            outbox_item.pubkey(),
+            amount,
+            should_queue,
+        );
+        let tx = Transaction::new_with_payer(
+            &[
+                approve_token_authority(
+                    &good_ntt,
+                    &test_data.user_token_account,
+                    &test_data.user.pubkey(),
+                    &args,
+                ),
+                transfer(&good_ntt, accs, args, Mode::Locking),
+            ],
+            Some(&ctx.payer.pubkey()),
+        );
+        (tx, outbox_item)
+    };
+
+    let (tx, first_outbox_item) = send(OUTBOUND_LIMIT - 4000, false);
+    let (second_tx, second_outbox_item) = send(5000, true);
+
+    tx.submit_with_signers(&[&test_data.user, &first_outbox_item], &mut ctx)
+        .await
+        .unwrap();
+    assert_eq!(outbound_capacity(&good_ntt, &mut ctx).await, 4000);
+
+    second_tx
+        .submit_with_signers(&[&test_data.user, &second_outbox_item], &mut ctx)
+        .await
+        .unwrap();
+    assert_queued(&mut ctx, second_outbox_item.pubkey()).await;
+    assert_eq!(outbound_capacity(&good_ntt, &mut ctx).await, 4000);
+
+    let set_limit = |limit| {
+        set_outbound_limit(
+            &good_ntt,
+            SetOutboundLimit {
+                authority: test_data.program_owner.pubkey(),
+            },
+            SetOutboundLimitArgs { limit },
+        )
+    };
+
+    // lowering the limit takes the difference off the current capacity...
+    set_limit(OUTBOUND_LIMIT - 3000)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+    assert_eq!(outbound_capacity(&good_ntt, &mut ctx).await, 1000);
+
+    // ...without going below zero
+    set_limit(3000)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+    assert_eq!(outbound_capacity(&good_ntt, &mut ctx).await, 0);
+
+    // raising it only adds the difference, rather than refilling it
+    set_limit(OUTBOUND_LIMIT)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+    assert_eq!(
+        outbound_capacity(&good_ntt, &mut ctx).await,
+        OUTBOUND_LIMIT - 3000
+    );
+
+    // the queued transfer is left as it was
+    assert_queued(&mut ctx, second_outbox_item.pubkey()).await;
+}
+
+#[tokio::test]
+async fn test_transfer_wrong_mode() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+    let outbox_item = Keypair::new();
+
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        100,
+        false,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    // make sure we can't transfer in the wrong mode
+    let err = transfer(&good_ntt, accs.clone(), args.clone(), Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidMode.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_cant_transfer_more_than_balance() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+
+    let token_account: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+    let more_than_balance = token_account.amount.checked_add(100).unwrap();
 
-    let token_account: TokenAccount = ctx
-        .get_account_data_anchor(test_data.user_token_account)
-        .await;
-    let more_than_balance = token_account.amount.checked_add(100).unwrap();
-
     // extend outbound limit to avoid TransferExceedsRateLimit error
     set_outbound_limit(
         &good_ntt,
         SetOutboundLimit {
-            owner: test_data.program_owner.pubkey(),
+            authority: test_data.program_owner.pubkey(),
         },
         SetOutboundLimitArgs {
             limit: more_than_balance,
@@ -718,31 +1340,73 @@ async fn test_large_tx_queue() {
 }
 
 #[tokio::test]
-async fn test_cant_transfer_when_paused() {
-    let (mut ctx, test_data) = setup(Mode::Locking).await;
+async fn test_would_queue() {
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
 
-    let outbox_item = Keypair::new();
+    let status = would_queue_status(&good_ntt, &mut ctx, OTHER_CHAIN, OUTBOUND_LIMIT).await;
+    assert_eq!(
+        status,
+        WouldQueueResult {
+            would_queue: false,
+            release_delay: None,
+        }
+    );
 
-    let (accs, args) = init_transfer_accs_args(
-        &good_ntt,
-        &mut ctx,
-        &test_data,
-        outbox_item.pubkey(),
-        100,
-        false,
+    // dust is removed before checking the capacity, like in the transfer
+    let status = would_queue_status(&good_ntt, &mut ctx, OTHER_CHAIN, OUTBOUND_LIMIT + 99).await;
+    assert!(!status.would_queue);
+
+    let status = would_queue_status(&good_ntt, &mut ctx, OTHER_CHAIN, OUTBOUND_LIMIT + 1000).await;
+    assert_eq!(
+        status,
+        WouldQueueResult {
+            would_queue: true,
+            release_delay: Some(RateLimitState::RATE_LIMIT_DURATION),
+        }
     );
+}
 
-    set_paused(
+#[tokio::test]
+async fn test_preview_transfer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let bridge: BridgeData = ctx
+        .get_account_data_anchor(good_ntt.wormhole().bridge())
+        .await;
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+
+    // senders are limited, but not by enough to make a difference here
+    set_sender_limit(
         &good_ntt,
-        SetPaused {
-            owner: test_data.program_owner.pubkey(),
+        SetSenderLimit {
+            authority: test_data.program_owner.pubkey(),
         },
-        true,
+        Some(u64::MAX),
     )
     .submit_with_signers(&[&test_data.program_owner], &mut ctx)
     .await
     .unwrap();
 
+    // the first transfer goes through, with some dust, and creates the
+    // sender's rate limit
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        1234,
+        false,
+    );
+    let accs = Transfer {
+        sender_limited: true,
+        ..accs
+    };
+    let preview = transfer_preview(&good_ntt, &mut ctx, &accs, &args).await;
+
+    let balance = token_balance(&mut ctx, &test_data.user_token_account).await;
     approve_token_authority(
         &good_ntt,
         &test_data.user_token_account,
@@ -752,27 +1416,62 @@ async fn test_cant_transfer_when_paused() {
     .submit_with_signers(&[&test_data.user], &mut ctx)
     .await
     .unwrap();
-    let err = transfer(&good_ntt, accs.clone(), args.clone(), Mode::Locking)
+    transfer(&good_ntt, accs, args, Mode::Locking)
         .submit_with_signers(&[&outbox_item], &mut ctx)
         .await
-        .unwrap_err();
+        .unwrap();
 
+    let item: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    let outbox_item_account = ctx
+        .banks_client
+        .get_account(outbox_item.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let sender_rate_limit_account = ctx
+        .banks_client
+        .get_account(good_ntt.sender_rate_limit(&test_data.user.pubkey()))
+        .await
+        .unwrap()
+        .unwrap();
     assert_eq!(
-        err.unwrap(),
-        TransactionError::InstructionError(0, InstructionError::Custom(NTTError::Paused.into()))
+        preview.amount,
+        balance - token_balance(&mut ctx, &test_data.user_token_account).await
+    );
+    assert_eq!(preview.dust, 34);
+    assert_eq!(preview.trimmed_amount, item.trimmed_amount());
+    assert_eq!(
+        preview.expected_amount_out,
+        peer.expected_amount_out(item.trimmed_amount())
+    );
+    assert_eq!(preview.peer_decimals, peer.token_decimals);
+    assert!(preview.route_enabled);
+    assert!(!preview.rate_limited);
+    assert!(!preview.exceeds_sender_limit);
+    assert!(!preview.queue_allowed);
+    assert_eq!(preview.release_timestamp, item.release_timestamp);
+    assert_eq!(preview.wormhole_fee, bridge.fee());
+    assert_eq!(preview.outbox_item_rent, outbox_item_account.lamports);
+    assert_eq!(
+        preview.sender_rate_limit_rent,
+        sender_rate_limit_account.lamports
     );
 
-    // make sure we can unpause
-    set_paused(
+    // the second one is queued, and the sender's rate limit already exists
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_transfer_accs_args(
         &good_ntt,
-        SetPaused {
-            owner: test_data.program_owner.pubkey(),
-        },
-        false,
-    )
-    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
-    .await
-    .unwrap();
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        OUTBOUND_LIMIT,
+        true,
+    );
+    let accs = Transfer {
+        sender_limited: true,
+        ..accs
+    };
+    let preview = transfer_preview(&good_ntt, &mut ctx, &accs, &args).await;
 
     approve_token_authority(
         &good_ntt,
@@ -787,16 +1486,37 @@ async fn test_cant_transfer_when_paused() {
         .submit_with_signers(&[&outbox_item], &mut ctx)
         .await
         .unwrap();
+
+    assert_queued(&mut ctx, outbox_item.pubkey()).await;
+    let item: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert!(preview.rate_limited);
+    assert!(!preview.exceeds_sender_limit);
+    assert!(preview.queue_allowed);
+    assert_eq!(preview.release_timestamp, item.release_timestamp);
+    assert_eq!(preview.trimmed_amount, item.trimmed_amount());
+    assert_eq!(preview.sender_rate_limit_rent, 0);
 }
 
 #[tokio::test]
-async fn test_large_tx_no_queue() {
+async fn test_large_tx_queue_disallowed() {
     let (mut ctx, test_data) = setup(Mode::Locking).await;
 
+    set_allow_queue(
+        &good_ntt,
+        SetAllowQueue {
+            owner: test_data.program_owner.pubkey(),
+        },
+        OTHER_CHAIN,
+        false,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
     let outbox_item = Keypair::new();
 
     let too_much = OUTBOUND_LIMIT + 1000;
-    let should_queue = false;
+    let should_queue = true;
     let (accs, args) = init_transfer_accs_args(
         &good_ntt,
         &mut ctx,
@@ -815,6 +1535,9 @@ async fn test_large_tx_no_queue() {
     .submit_with_signers(&[&test_data.user], &mut ctx)
     .await
     .unwrap();
+
+    // the peer doesn't allow queueing, so the transfer fails even though the
+    // sender asked for it to be queued
     let err = transfer(&good_ntt, accs, args, Mode::Locking)
         .submit_with_signers(&[&outbox_item], &mut ctx)
         .await
@@ -830,21 +1553,31 @@ async fn test_large_tx_no_queue() {
 }
 
 #[tokio::test]
-async fn test_cant_release_queued() {
+async fn test_cant_transfer_when_paused() {
     let (mut ctx, test_data) = setup(Mode::Locking).await;
 
     let outbox_item = Keypair::new();
 
-    let too_much = OUTBOUND_LIMIT + 1000;
     let (accs, args) = init_transfer_accs_args(
         &good_ntt,
         &mut ctx,
         &test_data,
         outbox_item.pubkey(),
-        too_much,
-        true,
+        100,
+        false,
     );
 
+    set_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
     approve_token_authority(
         &good_ntt,
         &test_data.user_token_account,
@@ -854,20 +1587,156 @@ async fn test_cant_release_queued() {
     .submit_with_signers(&[&test_data.user], &mut ctx)
     .await
     .unwrap();
-    transfer(&good_ntt, accs, args, Mode::Locking)
+    let err = transfer(&good_ntt, accs.clone(), args.clone(), Mode::Locking)
         .submit_with_signers(&[&outbox_item], &mut ctx)
         .await
-        .unwrap();
+        .unwrap_err();
 
-    assert_queued(&mut ctx, outbox_item.pubkey()).await;
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(NTTError::Paused.into()))
+    );
 
-    // check that 'revert_on_delay = true' returns correct error
-    let err = release_outbound(
+    // make sure we can unpause
+    set_paused(
         &good_ntt,
-        &good_ntt_transceiver,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        false,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_large_tx_no_queue() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+
+    let too_much = OUTBOUND_LIMIT + 1000;
+    let should_queue = false;
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        too_much,
+        should_queue,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    // the failed simulation reports how long the transfer would have been queued for
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let out = transfer(&good_ntt, accs.clone(), args.clone(), Mode::Locking)
+        .simulate_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+    assert_retry_after(
+        &mut ctx,
+        &out,
+        clock.unix_timestamp + RateLimitState::RATE_LIMIT_DURATION,
+    )
+    .await;
+
+    let err = transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TransferExceedsRateLimit.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_cant_release_queued() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+
+    let too_much = OUTBOUND_LIMIT + 1000;
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        too_much,
+        true,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    assert_queued(&mut ctx, outbox_item.pubkey()).await;
+
+    // the failed simulation reports how long until the transfer can be released
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    let out = release_outbound(
+        &good_ntt,
+        &good_ntt_transceiver,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .simulate(&mut ctx)
+    .await
+    .unwrap();
+    assert_retry_after(&mut ctx, &out, outbox_item_account.release_timestamp).await;
+
+    // check that 'revert_on_delay = true' returns correct error
+    let err = release_outbound(
+        &good_ntt,
+        &good_ntt_transceiver,
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
             outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
         },
         ReleaseOutboundArgs {
             revert_on_delay: true,
@@ -892,6 +1761,7 @@ async fn test_cant_release_queued() {
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
             outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
         },
         ReleaseOutboundArgs {
             revert_on_delay: false,
@@ -948,6 +1818,7 @@ async fn test_cant_release_twice() {
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
             outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
         },
         ReleaseOutboundArgs {
             revert_on_delay: true,
@@ -964,6 +1835,7 @@ async fn test_cant_release_twice() {
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
             outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
         },
         ReleaseOutboundArgs {
             revert_on_delay: true,
@@ -981,3 +1853,1363 @@ async fn test_cant_release_twice() {
         )
     );
 }
+
+#[tokio::test]
+async fn test_release_records_timestamp() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        100,
+        false,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let payer = ctx.payer.pubkey();
+    let release = || {
+        release_outbound(
+            &good_ntt,
+            &good_ntt_transceiver,
+            ReleaseOutbound {
+                payer,
+                outbox_item: outbox_item.pubkey(),
+                recipient_chain: OTHER_CHAIN,
+            },
+            ReleaseOutboundArgs {
+                revert_on_delay: true,
+            },
+        )
+    };
+
+    release().submit(&mut ctx).await.unwrap();
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert_eq!(
+        outbox_item_account.released_at(0).unwrap(),
+        Some(clock.unix_timestamp)
+    );
+    assert_eq!(outbox_item_account.released_at(1).unwrap(), None);
+
+    // releasing again later is rejected, and leaves the timestamp as it was
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: clock.unix_timestamp + 100,
+        ..clock
+    });
+
+    let err = release().submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::MessageAlreadySent.into())
+        )
+    );
+
+    let outbox_item_account_after: OutboxItem =
+        ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert_eq!(outbox_item_account_after, outbox_item_account);
+    assert_eq!(
+        outbox_item_account_after.released_at(0).unwrap(),
+        Some(clock.unix_timestamp)
+    );
+}
+
+#[tokio::test]
+async fn test_release_outbound_max_wormhole_fee() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        100,
+        false,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let bridge: BridgeData = ctx
+        .get_account_data_anchor(good_ntt.wormhole().bridge())
+        .await;
+    let fee = bridge.fee();
+    assert!(fee > 0);
+
+    let payer = ctx.payer.pubkey();
+    let release = |max_wormhole_fee| {
+        release_outbound_with_options(
+            &good_ntt,
+            &good_ntt_transceiver,
+            ReleaseOutbound {
+                payer,
+                outbox_item: outbox_item.pubkey(),
+                recipient_chain: OTHER_CHAIN,
+            },
+            ReleaseOutboundArgs {
+                revert_on_delay: true,
+            },
+            ReleaseOutboundOptions {
+                max_wormhole_fee: Some(max_wormhole_fee),
+            },
+        )
+    };
+
+    let err = release(fee - 1).submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::WormholeFeeTooHigh.into())
+        )
+    );
+
+    release(fee).submit(&mut ctx).await.unwrap();
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert!(outbox_item_account.released.get(0).unwrap());
+}
+
+#[tokio::test]
+async fn test_release_outbound_separate_fee_payer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let first = Keypair::new();
+    let second = Keypair::new();
+    for outbox_item in [&first, &second] {
+        let (accs, args) = init_transfer_accs_args(
+            &good_ntt,
+            &mut ctx,
+            &test_data,
+            outbox_item.pubkey(),
+            100,
+            false,
+        );
+
+        approve_token_authority(
+            &good_ntt,
+            &test_data.user_token_account,
+            &test_data.user.pubkey(),
+            &args,
+        )
+        .submit_with_signers(&[&test_data.user], &mut ctx)
+        .await
+        .unwrap();
+        transfer(&good_ntt, accs, args, Mode::Locking)
+            .submit_with_signers(&[outbox_item], &mut ctx)
+            .await
+            .unwrap();
+    }
+
+    let release_args = || ReleaseOutboundArgs {
+        revert_on_delay: true,
+    };
+
+    // the first message also creates the emitter's sequence account
+    release_outbound(
+        &good_ntt,
+        &good_ntt_transceiver,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item: first.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        release_args(),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let bridge: BridgeData = ctx
+        .get_account_data_anchor(good_ntt.wormhole().bridge())
+        .await;
+    let fee = bridge.fee();
+    assert!(fee > 0);
+
+    let rent_payer = Keypair::new();
+    let fee_payer = Keypair::new();
+    for signer in [&rent_payer, &fee_payer] {
+        system_instruction::transfer(&ctx.payer.pubkey(), &signer.pubkey(), 1_000_000_000)
+            .submit(&mut ctx)
+            .await
+            .unwrap();
+    }
+
+    let fee_collector = good_ntt.wormhole().fee_collector();
+    let mut balances = vec![];
+    for account in [rent_payer.pubkey(), fee_payer.pubkey(), fee_collector] {
+        balances.push(ctx.banks_client.get_balance(account).await.unwrap());
+    }
+
+    // the transaction fee is paid by the context's payer
+    release_outbound_with_fee_payer(
+        &good_ntt,
+        &good_ntt_transceiver,
+        ReleaseOutbound {
+            payer: rent_payer.pubkey(),
+            outbox_item: second.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        release_args(),
+        Some(fee_payer.pubkey()),
+    )
+    .submit_with_signers(&[&rent_payer, &fee_payer], &mut ctx)
+    .await
+    .unwrap();
+
+    // the message account is created for each outbox item
+    let message_rent = ctx
+        .banks_client
+        .get_balance(good_ntt_transceiver.wormhole_message(&second.pubkey()))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        ctx.banks_client
+            .get_balance(rent_payer.pubkey())
+            .await
+            .unwrap(),
+        balances[0] - message_rent
+    );
+    assert_eq!(
+        ctx.banks_client
+            .get_balance(fee_payer.pubkey())
+            .await
+            .unwrap(),
+        balances[1] - fee
+    );
+    assert_eq!(
+        ctx.banks_client.get_balance(fee_collector).await.unwrap(),
+        balances[2] + fee
+    );
+}
+
+#[tokio::test]
+async fn test_release_fee_collector() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        154,
+        false,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let payer = ctx.payer.pubkey();
+    let old_fee_collector = good_ntt.wormhole().fee_collector();
+    let new_fee_collector = Pubkey::new_unique();
+
+    let release = |fee_collector: Pubkey| {
+        let mut ix = release_outbound(
+            &good_ntt,
+            &good_ntt_transceiver,
+            ReleaseOutbound {
+                payer,
+                outbox_item: outbox_item.pubkey(),
+                recipient_chain: OTHER_CHAIN,
+            },
+            ReleaseOutboundArgs {
+                revert_on_delay: true,
+            },
+        );
+        for acc in ix.accounts.iter_mut() {
+            if acc.pubkey == old_fee_collector {
+                acc.pubkey = fee_collector;
+            }
+        }
+        ix
+    };
+
+    set_fee_collector(
+        &good_ntt,
+        SetFeeCollector {
+            owner: test_data.program_owner.pubkey(),
+        },
+        new_fee_collector,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config.fee_collector, new_fee_collector);
+
+    // the old fee collector is no longer accepted
+    let err = release(old_fee_collector)
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidFeeCollector.into())
+        )
+    );
+
+    // the new one passes the check (but is rejected by the core bridge, which
+    // hasn't actually moved its fee collector here)
+    let err = release(new_fee_collector)
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+    assert_ne!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidFeeCollector.into())
+        )
+    );
+
+    // rotating back allows releasing again
+    set_fee_collector(
+        &good_ntt,
+        SetFeeCollector {
+            owner: test_data.program_owner.pubkey(),
+        },
+        old_fee_collector,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    release(old_fee_collector).submit(&mut ctx).await.unwrap();
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert!(outbox_item_account.released.get(0).unwrap());
+}
+
+async fn transfer_small(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    outbox_item: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (accs, args) =
+        init_transfer_accs_args(&good_ntt, ctx, test_data, outbox_item.pubkey(), 100, false);
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[outbox_item], ctx)
+        .await
+}
+
+#[tokio::test]
+async fn test_max_outbound_in_flight() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_max_outbound_in_flight(
+        &good_ntt,
+        SetMaxOutboundInFlight {
+            owner: test_data.program_owner.pubkey(),
+        },
+        OTHER_CHAIN,
+        Some(2),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let outbox_items = [Keypair::new(), Keypair::new(), Keypair::new()];
+
+    for outbox_item in &outbox_items[..2] {
+        transfer_small(&mut ctx, &test_data, outbox_item)
+            .await
+            .unwrap();
+    }
+
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(peer.outbound_in_flight, 2);
+
+    // the cap has been reached
+    let err = transfer_small(&mut ctx, &test_data, &outbox_items[2])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TooManyOutboundInFlight.into())
+        )
+    );
+
+    release_outbound(
+        &good_ntt,
+        &good_ntt_transceiver,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item: outbox_items[0].pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(peer.outbound_in_flight, 1);
+
+    // releasing one frees up a slot
+    transfer_small(&mut ctx, &test_data, &outbox_items[2])
+        .await
+        .unwrap();
+
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(peer.outbound_in_flight, 2);
+}
+
+#[tokio::test]
+async fn test_force_mark_released() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+    transfer_small(&mut ctx, &test_data, &outbox_item)
+        .await
+        .unwrap();
+
+    // the confirmation has to match the outbox item
+    let err = force_mark_released(
+        &good_ntt,
+        ForceMarkReleased {
+            owner: test_data.program_owner.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        ForceMarkReleasedArgs {
+            transceiver_id: 0,
+            confirm_outbox_item: Pubkey::new_unique(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::ForceReleaseNotConfirmed.into())
+        )
+    );
+
+    force_mark_released(
+        &good_ntt,
+        ForceMarkReleased {
+            owner: test_data.program_owner.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        ForceMarkReleasedArgs {
+            transceiver_id: 0,
+            confirm_outbox_item: outbox_item.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert!(outbox_item_account.released.get(0).unwrap());
+
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(peer.outbound_in_flight, 0);
+
+    // the transceiver can't release it anymore
+    let err = release_outbound(
+        &good_ntt,
+        &good_ntt_transceiver,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::MessageAlreadySent.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_force_mark_released_already_released() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+    transfer_small(&mut ctx, &test_data, &outbox_item)
+        .await
+        .unwrap();
+
+    release_outbound(
+        &good_ntt,
+        &good_ntt_transceiver,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let err = force_mark_released(
+        &good_ntt,
+        ForceMarkReleased {
+            owner: test_data.program_owner.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        ForceMarkReleasedArgs {
+            transceiver_id: 0,
+            confirm_outbox_item: outbox_item.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::MessageAlreadySent.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_force_mark_released_not_owner() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+    transfer_small(&mut ctx, &test_data, &outbox_item)
+        .await
+        .unwrap();
+
+    let err = force_mark_released(
+        &good_ntt,
+        ForceMarkReleased {
+            owner: test_data.user.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        ForceMarkReleasedArgs {
+            transceiver_id: 0,
+            confirm_outbox_item: outbox_item.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintHasOne.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_collects_dust() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &ctx.payer.pubkey(),
+        &test_data.program_owner.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let dust_account = get_associated_token_address_with_program_id(
+        &test_data.program_owner.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    );
+
+    set_dust_account(
+        &good_ntt,
+        SetDustAccount {
+            owner: test_data.program_owner.pubkey(),
+            dust_account: Some(dust_account),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let user_before: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+
+    // amounts are trimmed to 7 decimals, so anything below 100 is dust
+    let amounts = [154, 1099, 2000, 7];
+    for amount in amounts {
+        let outbox_item = Keypair::new();
+        let (mut accs, args) = init_transfer_accs_args(
+            &good_ntt,
+            &mut ctx,
+            &test_data,
+            outbox_item.pubkey(),
+            amount,
+            false,
+        );
+        accs.dust_account = Some(dust_account);
+
+        approve_token_authority(
+            &good_ntt,
+            &test_data.user_token_account,
+            &test_data.user.pubkey(),
+            &args,
+        )
+        .submit_with_signers(&[&test_data.user], &mut ctx)
+        .await
+        .unwrap();
+        transfer(&good_ntt, accs, args, Mode::Locking)
+            .submit_with_signers(&[&outbox_item], &mut ctx)
+            .await
+            .unwrap();
+    }
+
+    let dust: TokenAccount = ctx.get_account_data_anchor(dust_account).await;
+    assert_eq!(dust.amount, amounts.iter().map(|a| a % 100).sum::<u64>());
+
+    // the dust is taken from the sender rather than left behind
+    let user_after: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+    assert_eq!(
+        user_before.amount - user_after.amount,
+        amounts.iter().sum::<u64>()
+    );
+
+    // once collection is turned on, transfers have to pass the dust account
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        154,
+        false,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    let err = transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidDustAccount.into())
+        )
+    );
+}
+
+async fn transfer_with_min_amount_out(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    amount: u64,
+    min_amount_out: u64,
+) -> Result<(), BanksClientError> {
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        ctx,
+        test_data,
+        outbox_item.pubkey(),
+        amount,
+        false,
+    );
+    let options = TransferOptions {
+        min_amount_out: Some(min_amount_out),
+        ..TransferOptions::default()
+    };
+
+    approve_token_authority_with_options(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+        &options,
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+    transfer_with_options(&good_ntt, accs, args, options, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], ctx)
+        .await?;
+
+    let outbox_item: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    // nothing is deducted on this side
+    assert_eq!(
+        outbox_item.trimmed_amount(),
+        TrimmedAmount::trim(amount, 9, 7).unwrap()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_min_amount_out_zero_fee() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(peer.destination_fee_bps, 0);
+
+    // 5000 is 50 in the 7 trimmed decimals, all of which is delivered
+    let err = transfer_with_min_amount_out(&mut ctx, &test_data, 5000, 51)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::SlippageExceeded.into())
+        )
+    );
+
+    transfer_with_min_amount_out(&mut ctx, &test_data, 5000, 50)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_min_amount_out_destination_fee() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_destination_fee_bps(
+        &good_ntt,
+        SetDestinationFeeBps {
+            owner: test_data.program_owner.pubkey(),
+        },
+        OTHER_CHAIN,
+        250,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // 2.5% of 50 is 1.25, rounded up to 2, so 48 is delivered
+    let err = transfer_with_min_amount_out(&mut ctx, &test_data, 5000, 49)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::SlippageExceeded.into())
+        )
+    );
+
+    transfer_with_min_amount_out(&mut ctx, &test_data, 5000, 48)
+        .await
+        .unwrap();
+
+    // more than 100% is rejected
+    let err = set_destination_fee_bps(
+        &good_ntt,
+        SetDestinationFeeBps {
+            owner: test_data.program_owner.pubkey(),
+        },
+        OTHER_CHAIN,
+        NttManagerPeer::MAX_FEE_BPS + 1,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidDestinationFeeBps.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_fund_token_account() {
+    // a classic mint and a Token-2022 mint
+    for (mut ctx, test_data) in [
+        setup(Mode::Locking).await,
+        setup_with_transfer_fee(Mode::Locking).await,
+    ] {
+        let balance = token_balance(&mut ctx, &test_data.user_token_account).await;
+
+        fund_token_account(
+            &mut ctx,
+            &test_data.mint,
+            &test_data.mint_authority,
+            &test_data.user_token_account,
+            1000,
+        )
+        .await;
+
+        assert_eq!(
+            token_balance(&mut ctx, &test_data.user_token_account).await,
+            balance + 1000
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_transfer_with_expiry() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let payer = ctx.payer.pubkey();
+    let balance = token_balance(&mut ctx, &test_data.user_token_account).await;
+    let outbound_limit_before = outbound_capacity(&good_ntt, &mut ctx).await;
+
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        100,
+        false,
+    );
+    let approve = |expiry_timestamp| {
+        approve_token_authority_with_options(
+            &good_ntt,
+            &test_data.user_token_account,
+            &test_data.user.pubkey(),
+            &args,
+            &TransferOptions {
+                expiry_timestamp: Some(expiry_timestamp),
+                ..TransferOptions::default()
+            },
+        )
+    };
+
+    // the expiry has to be ahead
+    approve(clock.unix_timestamp)
+        .submit_with_signers(&[&test_data.user], &mut ctx)
+        .await
+        .unwrap();
+    let err = transfer_with_expiry(
+        &good_ntt,
+        accs.clone(),
+        args.clone(),
+        clock.unix_timestamp,
+        Mode::Locking,
+    )
+    .submit_with_signers(&[&outbox_item], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidExpiryTimestamp.into())
+        )
+    );
+
+    // the expiry is part of what the sender approves
+    approve(clock.unix_timestamp + 60)
+        .submit_with_signers(&[&test_data.user], &mut ctx)
+        .await
+        .unwrap();
+    let err = transfer_with_expiry(
+        &good_ntt,
+        accs.clone(),
+        args.clone(),
+        clock.unix_timestamp + 30,
+        Mode::Locking,
+    )
+    .submit_with_signers(&[&outbox_item], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintSeeds.into())
+        )
+    );
+
+    transfer_with_expiry(
+        &good_ntt,
+        accs,
+        args,
+        clock.unix_timestamp + 60,
+        Mode::Locking,
+    )
+    .submit_with_signers(&[&outbox_item], &mut ctx)
+    .await
+    .unwrap();
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert_eq!(
+        outbox_item_account.expiry(),
+        Some(clock.unix_timestamp + 60)
+    );
+    assert_eq!(outbox_item_account.rent_payer, payer);
+
+    let close = || {
+        close_expired_outbox_item(
+            &good_ntt,
+            CloseExpiredOutboxItem {
+                outbox_item: outbox_item.pubkey(),
+                rent_payer: payer,
+                recipient_chain: OTHER_CHAIN,
+                sender: test_data.user.pubkey(),
+                mint: test_data.mint,
+                to: test_data.user_token_account,
+                consumed_chain_limit: false,
+                consumed_sender_limit: false,
+            },
+            Mode::Locking,
+        )
+    };
+
+    // still in time
+    let err = close().submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TransferNotExpired.into())
+        )
+    );
+
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: clock.unix_timestamp + 61,
+        ..clock
+    });
+
+    let err = release_outbound(
+        &good_ntt,
+        &good_ntt_transceiver,
+        ReleaseOutbound {
+            payer,
+            outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TransferExpired.into())
+        )
+    );
+
+    // the rent goes back to the payer, and the transfer is no longer in flight
+    let payer_balance = ctx.banks_client.get_balance(payer).await.unwrap();
+    close().submit(&mut ctx).await.unwrap();
+    assert!(ctx
+        .banks_client
+        .get_account(outbox_item.pubkey())
+        .await
+        .unwrap()
+        .is_none());
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(peer.outbound_in_flight, 0);
+    assert!(ctx.banks_client.get_balance(payer).await.unwrap() > payer_balance);
+
+    // the transfer never went out, so the sender gets the tokens and the
+    // outbound capacity back
+    assert_eq!(
+        token_balance(&mut ctx, &test_data.user_token_account).await,
+        balance
+    );
+    assert_eq!(
+        outbound_capacity(&good_ntt, &mut ctx).await,
+        outbound_limit_before
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_outbound_transfer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+    let balance = token_balance(&mut ctx, &test_data.user_token_account).await;
+    transfer_small(&mut ctx, &test_data, &outbox_item)
+        .await
+        .unwrap();
+    let payer = ctx.payer.pubkey();
+    let cancel = |sender| {
+        cancel_outbound_transfer(
+            &good_ntt,
+            CancelOutbound {
+                sender,
+                outbox_item: outbox_item.pubkey(),
+                rent_payer: Some(payer),
+                recipient_chain: OTHER_CHAIN,
+                mint: test_data.mint,
+                to: test_data.user_token_account,
+                consumed_chain_limit: false,
+                consumed_sender_limit: false,
+            },
+            Mode::Locking,
+        )
+    };
+
+    // only the sender can take the transfer back
+    let not_sender = Keypair::new();
+    let err = cancel(not_sender.pubkey())
+        .submit_with_signers(&[&not_sender], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintHasOne.into())
+        )
+    );
+
+    // the rent can only go back to whoever paid for the outbox item
+    let err = cancel_outbound_transfer(
+        &good_ntt,
+        CancelOutbound {
+            sender: test_data.user.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            rent_payer: Some(not_sender.pubkey()),
+            recipient_chain: OTHER_CHAIN,
+            mint: test_data.mint,
+            to: test_data.user_token_account,
+            consumed_chain_limit: false,
+            consumed_sender_limit: false,
+        },
+        Mode::Locking,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintAddress.into())
+        )
+    );
+
+    let payer_balance = ctx.banks_client.get_balance(payer).await.unwrap();
+    cancel(test_data.user.pubkey())
+        .submit_with_signers(&[&test_data.user], &mut ctx)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        token_balance(&mut ctx, &test_data.user_token_account).await,
+        balance
+    );
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(good_ntt.peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(peer.outbound_in_flight, 0);
+
+    // the outbox item is closed, and its rent goes back to the payer
+    assert!(ctx
+        .banks_client
+        .get_account(outbox_item.pubkey())
+        .await
+        .unwrap()
+        .is_none());
+    assert!(ctx.banks_client.get_balance(payer).await.unwrap() > payer_balance);
+
+    // the transceiver can't release it anymore
+    let err = release_outbound(
+        &good_ntt,
+        &good_ntt_transceiver,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::AccountOwnedByWrongProgram.into())
+        )
+    );
+
+    // and it can't be cancelled twice
+    let err = cancel(test_data.user.pubkey())
+        .submit_with_signers(&[&test_data.user], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::AccountOwnedByWrongProgram.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_outbound_transfer_burning() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+
+    let outbox_item = Keypair::new();
+    let balance = token_balance(&mut ctx, &test_data.user_token_account).await;
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        100,
+        false,
+    );
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Burning)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+    assert_eq!(
+        token_balance(&mut ctx, &test_data.user_token_account).await,
+        balance - 100
+    );
+
+    cancel_outbound_transfer(
+        &good_ntt,
+        CancelOutbound {
+            sender: test_data.user.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            rent_payer: Some(ctx.payer.pubkey()),
+            recipient_chain: OTHER_CHAIN,
+            mint: test_data.mint,
+            to: test_data.user_token_account,
+            consumed_chain_limit: false,
+            consumed_sender_limit: false,
+        },
+        Mode::Burning,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    // the tokens are minted back
+    assert_eq!(
+        token_balance(&mut ctx, &test_data.user_token_account).await,
+        balance
+    );
+    assert_eq!(
+        token_balance(&mut ctx, &good_ntt.custody(&test_data.mint)).await,
+        0
+    );
+    assert!(ctx
+        .banks_client
+        .get_account(outbox_item.pubkey())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_cancel_outbound_transfer_while_paused() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+    let balance = token_balance(&mut ctx, &test_data.user_token_account).await;
+    transfer_small(&mut ctx, &test_data, &outbox_item)
+        .await
+        .unwrap();
+
+    set_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // the pause holds the transfer up, but the sender can still take it back
+    cancel_outbound_transfer(
+        &good_ntt,
+        CancelOutbound {
+            sender: test_data.user.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            rent_payer: Some(ctx.payer.pubkey()),
+            recipient_chain: OTHER_CHAIN,
+            mint: test_data.mint,
+            to: test_data.user_token_account,
+            consumed_chain_limit: false,
+            consumed_sender_limit: false,
+        },
+        Mode::Locking,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        token_balance(&mut ctx, &test_data.user_token_account).await,
+        balance
+    );
+}
+
+#[tokio::test]
+async fn test_cant_cancel_released_transfer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+    transfer_small(&mut ctx, &test_data, &outbox_item)
+        .await
+        .unwrap();
+
+    release_outbound(
+        &good_ntt,
+        &good_ntt_transceiver,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    // the transfer can be redeemed on the peer already
+    let err = cancel_outbound_transfer(
+        &good_ntt,
+        CancelOutbound {
+            sender: test_data.user.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            rent_payer: Some(ctx.payer.pubkey()),
+            recipient_chain: OTHER_CHAIN,
+            mint: test_data.mint,
+            to: test_data.user_token_account,
+            consumed_chain_limit: false,
+            consumed_sender_limit: false,
+        },
+        Mode::Locking,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::MessageAlreadySent.into())
+        )
+    );
+}
@@ -0,0 +1,148 @@
+#![cfg(feature = "test-sbf")]
+
+use anchor_spl::token_2022::spl_token_2022;
+use example_native_token_transfers::instructions::{RedeemArgs, ReleaseInboundArgs};
+use ntt_messages::mode::Mode;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use test_utils::{
+    common::{
+        fixtures::{OTHER_CHAIN, OTHER_TRANSCEIVER},
+        submit::Submittable,
+    },
+    helpers::{
+        add_transfer_hook_accounts, init_receive_message_accs, init_redeem_accs,
+        init_transfer_accs_args, make_transfer_message, post_vaa_helper, setup_with_transfer_hook,
+        token_balance, transfer_hook_invocations,
+    },
+    sdk::{
+        accounts::{good_ntt, NTTAccounts},
+        instructions::{
+            redeem::redeem,
+            release_inbound::{release_inbound_unlock_with_token_program_id, ReleaseInbound},
+            transfer::{
+                approve_token_authority_with_token_program_id, transfer_with_token_program_id,
+            },
+        },
+        transceivers::{
+            accounts::good_ntt_transceiver, instructions::receive_message::receive_message,
+        },
+    },
+};
+use wormhole_sdk::Address;
+
+#[tokio::test]
+async fn test_transfer_hook_locking() {
+    let (mut ctx, test_data) = setup_with_transfer_hook(Mode::Locking).await;
+    let custody = good_ntt.custody_with_token_program_id(&test_data.mint, &spl_token_2022::id());
+    assert_eq!(transfer_hook_invocations(&mut ctx).await, 0);
+
+    // outbound: the tokens are locked in custody by the session authority
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        1000,
+        false,
+    );
+    approve_token_authority_with_token_program_id(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+        &spl_token_2022::id(),
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    let session_authority = good_ntt.session_authority(&test_data.user.pubkey(), &args);
+    let amount = args.amount;
+    let mut transfer_ix =
+        transfer_with_token_program_id(&good_ntt, accs, args, Mode::Locking, &spl_token_2022::id());
+    add_transfer_hook_accounts(
+        &ctx,
+        &mut transfer_ix,
+        &test_data.user_token_account,
+        &test_data.mint,
+        &custody,
+        &session_authority,
+        amount,
+    )
+    .await;
+    transfer_ix
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    assert_eq!(token_balance(&mut ctx, &custody).await, 1000);
+    assert_eq!(transfer_hook_invocations(&mut ctx).await, 1);
+
+    // inbound: the tokens are unlocked from custody by the token authority
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &test_data.user.pubkey());
+    let vaa = post_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+    receive_message(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(&good_ntt_transceiver, &mut ctx, vaa, OTHER_CHAIN, [0u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+    redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let balance = token_balance(&mut ctx, &test_data.user_token_account).await;
+    let mut release_ix = release_inbound_unlock_with_token_program_id(
+        &good_ntt,
+        ReleaseInbound {
+            payer: ctx.payer.pubkey(),
+            inbox_item: good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload),
+            from_chain: OTHER_CHAIN,
+            mint: test_data.mint,
+            recipient: test_data.user_token_account,
+        },
+        ReleaseInboundArgs {
+            revert_when_not_ready: true,
+        },
+        &spl_token_2022::id(),
+    );
+    add_transfer_hook_accounts(
+        &ctx,
+        &mut release_ix,
+        &custody,
+        &test_data.mint,
+        &test_data.user_token_account,
+        &good_ntt.token_authority(),
+        1000,
+    )
+    .await;
+    release_ix.submit(&mut ctx).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut ctx, &test_data.user_token_account).await,
+        balance + 1000
+    );
+    assert_eq!(transfer_hook_invocations(&mut ctx).await, 2);
+}
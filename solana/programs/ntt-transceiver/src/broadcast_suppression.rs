@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace, Debug, PartialEq, Eq)]
+/// How long identical peer broadcasts are suppressed for. Set by the owner
+/// with [`crate::wormhole::instructions::set_broadcast_suppression_window`].
+/// Singleton PDA, initialized by the owner or by the first peer broadcast.
+/// The window is zero (i.e. nothing is suppressed) until it's configured.
+pub struct WormholeBroadcastSuppression {
+    pub bump: u8,
+    /// In seconds.
+    pub window: u32,
+}
+
+impl WormholeBroadcastSuppression {
+    pub const SEED_PREFIX: &'static [u8] = b"wormhole_broadcast_suppression";
+}
+
+#[account]
+#[derive(InitSpace, Debug, PartialEq, Eq)]
+/// The last broadcast of a peer, so that identical broadcasts can be
+/// suppressed. Stored in a PDA seeded by the chain id, initialized by the
+/// first broadcast.
+pub struct WormholePeerBroadcast {
+    pub bump: u8,
+    /// Keccak256 hash of the payload of the last broadcast.
+    pub payload_hash: [u8; 32],
+    /// Unix timestamp of the last broadcast. Zero if none has been sent yet.
+    pub last_broadcast: i64,
+}
+
+impl WormholePeerBroadcast {
+    pub const SEED_PREFIX: &'static [u8] = b"wormhole_peer_broadcast";
+
+    /// The earliest time at which the payload hashing to `payload_hash` can be
+    /// broadcast. Only identical broadcasts are suppressed, so a different
+    /// payload can be broadcast right away.
+    pub fn next_broadcast(&self, payload_hash: &[u8; 32], window: u32) -> i64 {
+        if self.last_broadcast == 0 || self.payload_hash != *payload_hash {
+            0
+        } else {
+            self.last_broadcast.saturating_add(i64::from(window))
+        }
+    }
+}
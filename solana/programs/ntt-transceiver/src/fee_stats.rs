@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace, Debug, PartialEq, Eq)]
+/// Cumulative accounting of the Wormhole core bridge fees paid by this transceiver.
+/// Singleton PDA, initialized the first time a peer is registered. Deployments
+/// that haven't registered a peer since it was added post messages without it,
+/// see [`crate::wormhole::accounts::WormholeAccounts::fee_stats`].
+pub struct WormholeFeeStats {
+    pub bump: u8,
+    /// Total lamports transferred to the core bridge fee collector.
+    pub total_wormhole_fees_paid: u64,
+    /// Number of messages posted through the core bridge.
+    pub messages_posted: u64,
+}
+
+impl WormholeFeeStats {
+    pub const SEED_PREFIX: &'static [u8] = b"wormhole_fee_stats";
+
+    pub fn record_message(&mut self, fee: u64) {
+        self.total_wormhole_fees_paid = self.total_wormhole_fees_paid.saturating_add(fee);
+        self.messages_posted = self.messages_posted.saturating_add(1);
+    }
+}
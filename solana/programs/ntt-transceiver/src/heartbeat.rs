@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace, Debug, PartialEq, Eq)]
+/// Tracks when the last heartbeat was broadcast, so that heartbeats can be
+/// rate limited.
+/// Singleton PDA, initialized by the first heartbeat.
+pub struct WormholeHeartbeat {
+    pub bump: u8,
+    /// Unix timestamp of the last heartbeat. Zero if none has been sent yet.
+    pub last_heartbeat: i64,
+}
+
+impl WormholeHeartbeat {
+    pub const SEED_PREFIX: &'static [u8] = b"wormhole_heartbeat";
+
+    /// Minimum number of seconds between two heartbeats.
+    pub const MIN_INTERVAL: i64 = 10 * 60;
+
+    /// The earliest time at which the next heartbeat can be sent.
+    pub fn next_heartbeat(&self) -> i64 {
+        if self.last_heartbeat == 0 {
+            0
+        } else {
+            self.last_heartbeat.saturating_add(Self::MIN_INTERVAL)
+        }
+    }
+}
@@ -11,8 +11,12 @@ compile_error!("Cannot enable both mainnet and tilt-devnet features at the same
 #[cfg(all(feature = "solana-devnet", feature = "tilt-devnet"))]
 compile_error!("Cannot enable both solana-devnet and tilt-devnet features at the same time");
 
+pub mod broadcast_suppression;
+pub mod fee_stats;
+pub mod heartbeat;
 pub mod messages;
 pub mod peer;
+pub mod peer_emitter;
 pub mod vaa_body;
 pub mod wormhole;
 
@@ -58,12 +62,68 @@ pub mod ntt_transceiver {
         set_transceiver_peer(ctx, args)
     }
 
+    pub fn set_wormhole_peer_with_options(
+        ctx: Context<SetTransceiverPeer>,
+        args: SetTransceiverPeerArgs,
+        options: SetTransceiverPeerOptions,
+    ) -> Result<()> {
+        set_transceiver_peer_with_options(ctx, args, options)
+    }
+
+    pub fn delete_wormhole_peer(ctx: Context<DeleteTransceiverPeer>, chain_id: u16) -> Result<()> {
+        delete_transceiver_peer(ctx, chain_id)
+    }
+
+    pub fn get_wormhole_peer_index(ctx: Context<GetTransceiverPeerIndex>) -> Result<Vec<u16>> {
+        get_transceiver_peer_index(ctx)
+    }
+
+    pub fn migrate_wormhole_peer(
+        ctx: Context<MigrateTransceiverPeer>,
+        chain_id: u16,
+    ) -> Result<()> {
+        migrate_transceiver_peer(ctx, chain_id)
+    }
+
+    pub fn register_wormhole_peer_emitter(
+        ctx: Context<RegisterPeerEmitter>,
+        chain_id: u16,
+    ) -> Result<()> {
+        register_peer_emitter(ctx, chain_id)
+    }
+
+    pub fn set_wormhole_broadcast_suppression_window(
+        ctx: Context<SetBroadcastSuppressionWindow>,
+        window: u32,
+    ) -> Result<()> {
+        set_broadcast_suppression_window(ctx, window)
+    }
+
     pub fn receive_wormhole_message_instruction_data(
         ctx: Context<ReceiveMessageInstructionData>,
         guardian_set_bump: u8,
         vaa_body: VaaBodyData,
     ) -> Result<()> {
-        wormhole::instructions::receive_message_instruction_data(ctx, guardian_set_bump, vaa_body)
+        wormhole::instructions::receive_message_instruction_data(
+            ctx,
+            guardian_set_bump,
+            vaa_body,
+            None,
+        )
+    }
+
+    pub fn receive_wormhole_message_instruction_data_with_digest(
+        ctx: Context<ReceiveMessageInstructionData>,
+        guardian_set_bump: u8,
+        vaa_body: VaaBodyData,
+        expected_digest: [u8; 32],
+    ) -> Result<()> {
+        wormhole::instructions::receive_message_instruction_data(
+            ctx,
+            guardian_set_bump,
+            vaa_body,
+            Some(expected_digest),
+        )
     }
 
     pub fn post_unverified_wormhole_message_account(
@@ -80,12 +140,42 @@ pub mod ntt_transceiver {
         wormhole::instructions::close_unverified_message_account(ctx, seed)
     }
 
+    pub fn cleanup_expired_vaa_body(ctx: Context<CleanupExpiredVaaBody>, seed: u64) -> Result<()> {
+        wormhole::instructions::cleanup_expired_vaa_body(ctx, seed)
+    }
+
     pub fn receive_wormhole_message_account(
         ctx: Context<ReceiveMessageAccount>,
         guardian_set_bump: u8,
         seed: u64,
     ) -> Result<()> {
-        wormhole::instructions::receive_message_account(ctx, guardian_set_bump, seed)
+        wormhole::instructions::receive_message_account(ctx, guardian_set_bump, seed, None)
+    }
+
+    pub fn receive_wormhole_message_account_with_digest(
+        ctx: Context<ReceiveMessageAccount>,
+        guardian_set_bump: u8,
+        seed: u64,
+        expected_digest: [u8; 32],
+    ) -> Result<()> {
+        wormhole::instructions::receive_message_account(
+            ctx,
+            guardian_set_bump,
+            seed,
+            Some(expected_digest),
+        )
+    }
+
+    pub fn verify_pending_wormhole_message(
+        ctx: Context<VerifyPendingMessage>,
+        guardian_set_bump: u8,
+        seed: u64,
+    ) -> Result<()> {
+        wormhole::instructions::verify_pending_message(ctx, guardian_set_bump, seed)
+    }
+
+    pub fn promote_pending_wormhole_message(ctx: Context<PromotePendingMessage>) -> Result<()> {
+        wormhole::instructions::promote_pending_message(ctx)
     }
 
     pub fn release_wormhole_outbound(
@@ -95,6 +185,14 @@ pub mod ntt_transceiver {
         wormhole::instructions::release_outbound(ctx, args)
     }
 
+    pub fn release_wormhole_outbound_with_options(
+        ctx: Context<ReleaseOutbound>,
+        args: ReleaseOutboundArgs,
+        options: ReleaseOutboundOptions,
+    ) -> Result<()> {
+        wormhole::instructions::release_outbound_with_options(ctx, args, options)
+    }
+
     pub fn broadcast_wormhole_id(ctx: Context<BroadcastId>) -> Result<()> {
         wormhole::instructions::broadcast_id(ctx)
     }
@@ -105,6 +203,32 @@ pub mod ntt_transceiver {
     ) -> Result<()> {
         wormhole::instructions::broadcast_peer(ctx, args)
     }
+
+    pub fn broadcast_wormhole_peer_with_address_encoding(
+        ctx: Context<BroadcastPeer>,
+        args: BroadcastPeerArgs,
+        address_encoding: wormhole::AddressEncoding,
+    ) -> Result<()> {
+        wormhole::instructions::broadcast_peer_with_address_encoding(ctx, args, address_encoding)
+    }
+
+    pub fn broadcast_wormhole_heartbeat(ctx: Context<BroadcastHeartbeat>) -> Result<()> {
+        wormhole::instructions::broadcast_heartbeat(ctx)
+    }
+
+    pub fn get_wormhole_fee_stats(
+        ctx: Context<GetFeeStats>,
+    ) -> Result<fee_stats::WormholeFeeStats> {
+        wormhole::instructions::get_fee_stats(ctx)
+    }
+
+    pub fn quote_delivery_cost(
+        ctx: Context<QuoteDeliveryCost>,
+        vaa_size: u32,
+        recipient_has_ata: bool,
+    ) -> Result<DeliveryCostQuote> {
+        wormhole::instructions::quote_delivery_cost(ctx, vaa_size, recipient_has_ata)
+    }
 }
 
 #[derive(Accounts)]
@@ -1,16 +1,62 @@
-use anchor_lang::prelude::*;
-use ntt_messages::{chain_id::ChainId, transceiver::TransceiverMessageData};
+use anchor_lang::{prelude::*, Discriminator};
+use example_native_token_transfers::transfer::Payload;
+use ntt_messages::{
+    chain_id::ChainId, ntt::NativeTokenTransfer, transceiver::TransceiverMessageData,
+};
 use std::{collections::HashMap, marker::PhantomData};
 
+/// NOTE: this has the same layout as the manager's
+/// [`example_native_token_transfers::messages::ValidatedTransceiverMessage`],
+/// which is what redeem reads.
 #[account]
 #[derive(InitSpace)]
 pub struct ValidatedTransceiverMessage<A: AnchorDeserialize + AnchorSerialize + Space + Clone> {
     pub from_chain: ChainId,
     pub message: TransceiverMessageData<A>,
+    pub bump: u8,
+    /// The timestamp in the body of the VAA the message was delivered in.
+    pub vaa_timestamp: u32,
+    /// `keccak256(from_chain || ntt_manager_payload)`, which the inbox item of
+    /// the message is seeded by.
+    pub message_hash: [u8; 32],
+    /// The bytes of additional payload the message carried that decoding it
+    /// as `A` left unread.
+    pub unconsumed_payload_len: u16,
 }
 
 impl<A: AnchorDeserialize + AnchorSerialize + Space + Clone> ValidatedTransceiverMessage<A> {
     pub const SEED_PREFIX: &'static [u8] = b"transceiver_message";
+
+    /// Whether the account has already been written to. Freshly allocated
+    /// accounts are zeroed until the validated message is serialized into them.
+    pub fn is_initialized(info: &AccountInfo) -> Result<bool> {
+        Ok(info.try_borrow_data()?.starts_with(&Self::DISCRIMINATOR))
+    }
+}
+
+/// A message whose VAA has been verified, but which has not been accepted yet.
+/// This allows relayers to verify messages while the program is paused, and
+/// then cheaply promote them into a [`ValidatedTransceiverMessage`] once
+/// unpaused.
+#[account]
+#[derive(InitSpace)]
+pub struct VerifiedPendingMessage {
+    /// The account that paid for the rent of this account, refunded on promotion.
+    pub rent_payer: Pubkey,
+    pub from_chain: ChainId,
+    /// The emitter of the VAA. The peer is checked against this again on
+    /// promotion, in case it was changed while the program was paused.
+    pub emitter_address: [u8; 32],
+    pub message: TransceiverMessageData<NativeTokenTransfer<Payload>>,
+    /// The timestamp of the VAA, carried over on promotion.
+    pub vaa_timestamp: u32,
+    /// Carried over on promotion, see
+    /// [`ValidatedTransceiverMessage::unconsumed_payload_len`].
+    pub unconsumed_payload_len: u16,
+}
+
+impl VerifiedPendingMessage {
+    pub const SEED_PREFIX: &'static [u8] = b"verified_pending_message";
 }
 
 // This is a hack to get around the fact that the IDL generator doesn't support
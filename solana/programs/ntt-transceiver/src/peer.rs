@@ -1,4 +1,8 @@
 use anchor_lang::prelude::*;
+use example_native_token_transfers::{
+    peer::{insert_chain_id, remove_chain_id, MAX_INDEXED_PEERS},
+    transceivers::accounts::peer::PeerAddressMatch,
+};
 
 #[account]
 #[derive(InitSpace)]
@@ -6,8 +10,41 @@ use anchor_lang::prelude::*;
 pub struct TransceiverPeer {
     pub bump: u8,
     pub address: [u8; 32],
+    /// How [`Self::address`] is compared against the emitter of received
+    /// messages.
+    pub address_match: PeerAddressMatch,
 }
 
 impl TransceiverPeer {
     pub const SEED_PREFIX: &'static [u8] = b"transceiver_peer";
+
+    /// Whether `emitter_address` is this peer, see [`PeerAddressMatch`].
+    pub fn matches(&self, emitter_address: &[u8; 32]) -> bool {
+        self.address_match.matches(&self.address, emitter_address)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+/// The chain ids of the configured [`TransceiverPeer`]s, see
+/// [`example_native_token_transfers::peer::PeerIndex`].
+///
+/// NOTE: peers set before the index was introduced are only added when they're
+/// migrated, see [`crate::wormhole::instructions::migrate_transceiver_peer`].
+pub struct TransceiverPeerIndex {
+    pub bump: u8,
+    #[max_len(MAX_INDEXED_PEERS)]
+    pub chain_ids: Vec<u16>,
+}
+
+impl TransceiverPeerIndex {
+    pub const SEED_PREFIX: &'static [u8] = b"transceiver_peer_index";
+
+    pub fn insert(&mut self, chain_id: u16) -> Result<()> {
+        insert_chain_id(&mut self.chain_ids, chain_id)
+    }
+
+    pub fn remove(&mut self, chain_id: u16) {
+        remove_chain_id(&mut self.chain_ids, chain_id)
+    }
 }
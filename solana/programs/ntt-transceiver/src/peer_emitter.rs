@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace, Debug, PartialEq, Eq)]
+/// A Wormhole emitter registered for the peer on `chain_id`, for deployments
+/// that run a distinct emitter per destination. Broadcasts opt into it by
+/// passing this account. Stored in a PDA seeded by the chain id, created by
+/// the owner with [`crate::wormhole::instructions::register_peer_emitter`].
+///
+/// The emitter itself is the PDA seeded by `b"emitter"` and the chain id,
+/// next to the default emitter seeded by just `b"emitter"`.
+pub struct WormholePeerEmitter {
+    pub bump: u8,
+    pub chain_id: u16,
+}
+
+impl WormholePeerEmitter {
+    pub const SEED_PREFIX: &'static [u8] = b"wormhole_peer_emitter";
+
+    /// The seed following `b"emitter"` in the seeds of the emitter to post
+    /// from: the chain id of `peer_emitter` if given, and nothing otherwise.
+    /// An empty seed doesn't change the derived address, so the latter is the
+    /// default emitter.
+    pub fn emitter_seed(peer_emitter: Option<&Self>) -> Vec<u8> {
+        peer_emitter
+            .map(|peer_emitter| peer_emitter.chain_id.to_be_bytes().to_vec())
+            .unwrap_or_default()
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 
 use anchor_lang::prelude::*;
+use example_native_token_transfers::error::NTTError;
 use ntt_messages::{
     chain_id::ChainId,
     transceiver::{Transceiver, TransceiverMessage, TransceiverMessageData},
@@ -8,6 +9,8 @@ use ntt_messages::{
 };
 use wormhole_io::TypePrefixedPayload;
 
+use crate::messages::ValidatedTransceiverMessage;
+
 #[derive(AnchorDeserialize, AnchorSerialize, Default, PartialEq)]
 pub struct VaaBodyData {
     pub span: Vec<u8>,
@@ -22,10 +25,27 @@ impl<'a> AsVaaBodyBytes<'a> for VaaBodyData {
 #[account]
 pub struct VaaBody {
     pub span: Vec<u8>,
+    /// When the first chunk was staged. Once [`VaaBody::TTL`] has passed, the
+    /// account can be closed by anyone (see `cleanup_expired_vaa_body`).
+    pub created_at: i64,
 }
 
 impl VaaBody {
     pub const SEED_PREFIX: &'static [u8] = b"vaa_body";
+
+    /// How long (in seconds) a staged VAA body is left to its payer before
+    /// anyone can close it.
+    pub const TTL: i64 = 7 * 24 * 60 * 60;
+
+    /// The size of the account required to stage a VAA body of `message_size` bytes.
+    pub fn space(message_size: u32) -> usize {
+        8 + 4 + message_size as usize + 8
+    }
+
+    /// Whether [`VaaBody::TTL`] has passed at `now`.
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.created_at.saturating_add(Self::TTL) <= now
+    }
 }
 
 impl<'a> AsVaaBodyBytes<'a> for VaaBody {
@@ -34,31 +54,65 @@ impl<'a> AsVaaBodyBytes<'a> for VaaBody {
     }
 }
 
+/// Zero-copy view over a VAA body.
+///
+/// NOTE: this is the only place where VAA bodies are parsed. Both receive paths
+/// (instruction data and staged [`VaaBody`] account) go through it, so they
+/// always agree on the `(emitter_chain, id)` pair that seeds the
+/// [`ValidatedTransceiverMessage`] account.
+///
+/// The span is arbitrary user input, so the accessors fail with
+/// [`NTTError::MalformedMessage`] when it's too short for the field they read.
 pub struct VaaBodyBytes<'a> {
     pub span: &'a [u8],
 }
 
 impl<'a> VaaBodyBytes<'a> {
-    pub fn emitter_chain(&self) -> u16 {
-        u16::from_be_bytes(self.span[8..10].try_into().unwrap())
+    pub fn timestamp(&self) -> Result<u32> {
+        Ok(u32::from_be_bytes(*self.field::<4>(0)?))
     }
 
-    pub fn emitter_address(&self) -> &[u8; 32] {
-        self.span[10..42].try_into().unwrap()
+    pub fn emitter_chain(&self) -> Result<u16> {
+        Ok(u16::from_be_bytes(*self.field::<2>(8)?))
     }
 
-    pub fn id(&self) -> &[u8; 32] {
-        self.span[121..153].try_into().unwrap()
+    pub fn emitter_address(&self) -> Result<&'a [u8; 32]> {
+        self.field(10)
     }
 
-    pub fn to_chain(&self) -> ChainId {
-        ChainId {
-            id: u16::from_be_bytes(self.span[264..266].try_into().unwrap()),
-        }
+    pub fn source_ntt_manager(&self) -> Result<&'a [u8; 32]> {
+        self.field(55)
+    }
+
+    pub fn id(&self) -> Result<&'a [u8; 32]> {
+        self.field(121)
     }
 
-    fn message_data(&self) -> &[u8] {
-        &self.span[51..]
+    /// The version byte of the [`ntt_messages::ntt::NativeTokenTransfer`]
+    /// payload, following its 3-byte prefix.
+    pub fn version(&self) -> Result<u8> {
+        Ok(self.field::<1>(190)?[0])
+    }
+
+    pub fn to_chain(&self) -> Result<ChainId> {
+        Ok(ChainId {
+            id: u16::from_be_bytes(*self.field::<2>(264)?),
+        })
+    }
+
+    fn message_data(&self) -> Result<&'a [u8]> {
+        self.span
+            .get(51..)
+            .ok_or_else(|| NTTError::MalformedMessage.into())
+    }
+
+    /// The `N` bytes at `offset`.
+    fn field<const N: usize>(&self, offset: usize) -> Result<&'a [u8; N]> {
+        offset
+            .checked_add(N)
+            .and_then(|end| self.span.get(offset..end))
+            .and_then(|field| field.try_into().ok())
+            .ok_or_else(|| NTTError::MalformedMessage.into())
     }
 
     pub fn transceiver_message_data<
@@ -68,11 +122,304 @@ impl<'a> VaaBodyBytes<'a> {
         &self,
     ) -> Result<TransceiverMessageData<A>> {
         let transceiver_message: TransceiverMessage<E, A> =
-            TransceiverMessage::read_slice(self.message_data())?;
+            TransceiverMessage::read_slice(self.message_data()?)?;
         Ok(transceiver_message.message_data)
     }
+
+    /// The bytes of additional payload that decoding the message as `A`
+    /// leaves unread, see [`ValidatedTransceiverMessage::unconsumed_payload_len`].
+    pub fn unconsumed_payload_len<E: Transceiver, A: TypePrefixedPayload + MaybeSpace>(
+        &self,
+    ) -> Result<u16> {
+        TransceiverMessage::<E, A>::unconsumed_payload_len(self.message_data()?)
+            .ok()
+            .and_then(|len| u16::try_from(len).ok())
+            .ok_or_else(|| NTTError::MalformedMessage.into())
+    }
+
+    /// The message to store at the [`ValidatedTransceiverMessage`] PDA with
+    /// bump `bump`.
+    pub fn validated_transceiver_message<
+        E: Transceiver + Debug + Clone,
+        A: AnchorDeserialize + AnchorSerialize + Space + Clone + TypePrefixedPayload + MaybeSpace,
+    >(
+        &self,
+        bump: u8,
+    ) -> Result<ValidatedTransceiverMessage<A>> {
+        let from_chain = ChainId {
+            id: self.emitter_chain()?,
+        };
+        let message = self.transceiver_message_data::<E, A>()?;
+        Ok(ValidatedTransceiverMessage {
+            bump,
+            from_chain,
+            message_hash: message.ntt_manager_payload.keccak256(from_chain).to_bytes(),
+            message,
+            vaa_timestamp: self.timestamp()?,
+            unconsumed_payload_len: self.unconsumed_payload_len::<E, A>()?,
+        })
+    }
 }
 
 pub trait AsVaaBodyBytes<'a> {
     fn as_vaa_body_bytes(&'a self) -> VaaBodyBytes<'a>;
 }
+
+#[cfg(test)]
+mod tests {
+    use example_native_token_transfers::transfer::Payload;
+    use ntt_messages::{
+        ntt::{ExtendedPayload, NativeTokenTransfer, PayloadExtension},
+        ntt_manager::NttManagerMessage,
+        transceivers::wormhole::WormholeTransceiver,
+        trimmed_amount::TrimmedAmount,
+    };
+
+    use super::*;
+    use crate::wormhole::instructions::TRANSCEIVER_MESSAGE_SPACE;
+
+    type Transfer = NativeTokenTransfer<Payload>;
+
+    fn make_vaa_body(
+        emitter_chain: u16,
+        id: [u8; 32],
+        to_chain: u16,
+        transceiver_payload: Vec<u8>,
+    ) -> Vec<u8> {
+        let message: TransceiverMessage<WormholeTransceiver, Transfer> = TransceiverMessage::new(
+            [1u8; 32],
+            [2u8; 32],
+            NttManagerMessage {
+                id,
+                sender: [3u8; 32],
+                payload: NativeTokenTransfer {
+                    version: Transfer::CURRENT_VERSION,
+                    amount: TrimmedAmount::new(u64::MAX, 8),
+                    source_token: [4u8; 32],
+                    to_chain: ChainId { id: to_chain },
+                    to: [5u8; 32],
+                    additional_payload: Payload {},
+                },
+            },
+            transceiver_payload,
+        );
+
+        vaa_body_with_message(emitter_chain, &message)
+    }
+
+    fn vaa_body_with_message<A: TypePrefixedPayload + MaybeSpace>(
+        emitter_chain: u16,
+        message: &TransceiverMessage<WormholeTransceiver, A>,
+    ) -> Vec<u8> {
+        let mut span = vec![];
+        span.extend_from_slice(&u32::MAX.to_be_bytes()); // timestamp
+        span.extend_from_slice(&u32::MAX.to_be_bytes()); // nonce
+        span.extend_from_slice(&emitter_chain.to_be_bytes());
+        span.extend_from_slice(&[6u8; 32]); // emitter address
+        span.extend_from_slice(&u64::MAX.to_be_bytes()); // sequence
+        span.push(u8::MAX); // consistency level
+        span.extend_from_slice(&TypePrefixedPayload::to_vec_payload(message));
+        span
+    }
+
+    fn transceiver_message_address(vaa_body: &VaaBodyBytes) -> Pubkey {
+        let (address, _) = Pubkey::find_program_address(
+            &[
+                ValidatedTransceiverMessage::<Transfer>::SEED_PREFIX,
+                vaa_body.emitter_chain().unwrap().to_be_bytes().as_ref(),
+                vaa_body.id().unwrap(),
+            ],
+            &crate::ID,
+        );
+        address
+    }
+
+    #[test]
+    fn test_receive_paths_agree() {
+        let corpus = [
+            make_vaa_body(0, [0u8; 32], 0, vec![]),
+            make_vaa_body(u16::MAX, [u8::MAX; 32], u16::MAX, vec![]),
+            make_vaa_body(1, [7u8; 32], u16::MAX, vec![u8::MAX; u16::MAX as usize]),
+            make_vaa_body(u16::MAX, [0u8; 32], 0, vec![0u8; 1]),
+        ];
+
+        for span in corpus {
+            let instruction_data = VaaBodyData { span: span.clone() };
+            let account = VaaBody {
+                span,
+                created_at: 0,
+            };
+
+            let from_instruction_data = instruction_data.as_vaa_body_bytes();
+            let from_account = account.as_vaa_body_bytes();
+
+            assert_eq!(
+                from_instruction_data.emitter_chain().unwrap(),
+                from_account.emitter_chain().unwrap()
+            );
+            assert_eq!(
+                from_instruction_data.id().unwrap(),
+                from_account.id().unwrap()
+            );
+            assert_eq!(
+                transceiver_message_address(&from_instruction_data),
+                transceiver_message_address(&from_account)
+            );
+
+            let expected = from_instruction_data
+                .validated_transceiver_message::<WormholeTransceiver, Transfer>(0)
+                .unwrap();
+            let actual = from_account
+                .validated_transceiver_message::<WormholeTransceiver, Transfer>(0)
+                .unwrap();
+
+            assert_eq!(expected.from_chain, actual.from_chain);
+            assert_eq!(expected.message, actual.message);
+
+            assert_eq!(from_account.timestamp().unwrap(), u32::MAX);
+
+            // the fixed offsets agree with the parsed message
+            assert_eq!(
+                expected.from_chain.id,
+                from_account.emitter_chain().unwrap()
+            );
+            assert_eq!(
+                &expected.message.ntt_manager_payload.id,
+                from_account.id().unwrap()
+            );
+            assert_eq!(
+                &expected.message.source_ntt_manager,
+                from_account.source_ntt_manager().unwrap()
+            );
+            assert_eq!(
+                expected.message.ntt_manager_payload.payload.version,
+                from_account.version().unwrap()
+            );
+            assert_eq!(
+                expected.message.ntt_manager_payload.payload.to_chain,
+                from_account.to_chain().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_max_size_message_fits_account() {
+        // the transceiver payload is not stored, and `make_vaa_body` uses the
+        // largest additional payload, so this is the largest possible message
+        let body = VaaBodyData {
+            span: make_vaa_body(
+                u16::MAX,
+                [u8::MAX; 32],
+                u16::MAX,
+                vec![u8::MAX; u16::MAX as usize],
+            ),
+        };
+        let message = body
+            .as_vaa_body_bytes()
+            .validated_transceiver_message::<WormholeTransceiver, Transfer>(0)
+            .unwrap();
+
+        let mut data = vec![];
+        message.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), TRANSCEIVER_MESSAGE_SPACE);
+    }
+
+    /// Reads every field of `span`, which must not panic whatever its contents.
+    fn read_all(span: &[u8]) {
+        let vaa_body = VaaBodyBytes { span };
+        let _ = vaa_body.timestamp();
+        let _ = vaa_body.emitter_chain();
+        let _ = vaa_body.emitter_address();
+        let _ = vaa_body.source_ntt_manager();
+        let _ = vaa_body.id();
+        let _ = vaa_body.version();
+        let _ = vaa_body.to_chain();
+        let _ = vaa_body.validated_transceiver_message::<WormholeTransceiver, Transfer>(0);
+    }
+
+    #[test]
+    fn test_truncated_body_is_rejected() {
+        let span = make_vaa_body(1, [7u8; 32], 2, vec![8u8; 4]);
+
+        for len in 0..span.len() {
+            read_all(&span[..len]);
+
+            let vaa_body = VaaBodyBytes { span: &span[..len] };
+            assert_eq!(vaa_body.timestamp().is_ok(), len >= 4);
+            assert_eq!(vaa_body.emitter_chain().is_ok(), len >= 10);
+            assert_eq!(vaa_body.emitter_address().is_ok(), len >= 42);
+            assert_eq!(vaa_body.source_ntt_manager().is_ok(), len >= 87);
+            assert_eq!(vaa_body.id().is_ok(), len >= 153);
+            assert_eq!(vaa_body.version().is_ok(), len >= 191);
+            assert_eq!(vaa_body.to_chain().is_ok(), len >= 266);
+            assert!(vaa_body
+                .validated_transceiver_message::<WormholeTransceiver, Transfer>(0)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn test_mutated_body_does_not_panic() {
+        let span = make_vaa_body(1, [7u8; 32], 2, vec![8u8; 4]);
+
+        for i in 0..span.len() {
+            for byte in [0x00, 0x01, 0x7f, 0x80, 0xff] {
+                let mut mutated = span.clone();
+                mutated[i] = byte;
+                read_all(&mutated);
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+    struct Swap {
+        min_amount_out: u64,
+    }
+
+    impl PayloadExtension for Swap {
+        const PREFIX: [u8; 4] = *b"SWAP";
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+    struct Memo {
+        id: u64,
+    }
+
+    impl PayloadExtension for Memo {
+        const PREFIX: [u8; 4] = *b"MEMO";
+    }
+
+    #[test]
+    fn test_extended_payload() {
+        let payload = NativeTokenTransfer {
+            version: Transfer::CURRENT_VERSION,
+            amount: TrimmedAmount::new(1, 8),
+            source_token: [4u8; 32],
+            to_chain: ChainId { id: 2 },
+            to: [5u8; 32],
+            additional_payload: ExtendedPayload(Swap { min_amount_out: 42 }),
+        };
+        let message: TransceiverMessage<WormholeTransceiver, _> = TransceiverMessage::new(
+            [1u8; 32],
+            [2u8; 32],
+            NttManagerMessage {
+                id: [7u8; 32],
+                sender: [3u8; 32],
+                payload: payload.clone(),
+            },
+            vec![],
+        );
+        let span = vaa_body_with_message(1, &message);
+        let vaa_body = VaaBodyBytes { span: &span };
+
+        let data = vaa_body
+            .transceiver_message_data::<WormholeTransceiver, NativeTokenTransfer<ExtendedPayload<Swap>>>()
+            .unwrap();
+        assert_eq!(data.ntt_manager_payload.payload, payload);
+
+        // a different extension is rejected rather than misread
+        assert!(vaa_body
+            .transceiver_message_data::<WormholeTransceiver, NativeTokenTransfer<ExtendedPayload<Memo>>>()
+            .is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::fee_stats::WormholeFeeStats;
 use anchor_lang::prelude::*;
 use wormhole_anchor_sdk::wormhole;
 use wormhole_io::TypePrefixedPayload;
@@ -28,9 +29,23 @@ pub struct WormholeAccounts<'info> {
     /// CHECK: Shim event authority
     pub wormhole_post_message_shim_ea: UncheckedAccount<'info>,
 
+    #[account(
+        mut,
+        seeds = [WormholeFeeStats::SEED_PREFIX],
+        bump = fee_stats.bump,
+    )]
+    /// Records the fee when given. Optional, as it's only created when a peer
+    /// is registered.
+    pub fee_stats: Option<Account<'info, WormholeFeeStats>>,
+
     // legacy
     pub clock: Sysvar<'info, Clock>,
     pub rent: Sysvar<'info, Rent>,
+
+    #[account(mut)]
+    /// Pays the core bridge fee if given, so that the payer of the instruction
+    /// only covers rent.
+    pub fee_payer: Option<Signer<'info>>,
 }
 
 /// SECURITY: Owner checks are disabled. Each of [`WormholeAccounts::bridge`], [`WormholeAccounts::fee_collector`],
@@ -38,11 +53,12 @@ pub struct WormholeAccounts<'info> {
 /// SECURITY: Signer checks are disabled. The only valid sender is the
 /// [`wormhole::PostMessage::emitter`], enforced by the [`CpiContext`] below.
 pub fn post_message<'info, A: TypePrefixedPayload>(
-    wormhole: &WormholeAccounts<'info>,
+    wormhole: &mut WormholeAccounts<'info>,
     payer: AccountInfo<'info>,
     message: AccountInfo<'info>,
     emitter: AccountInfo<'info>,
-    emitter_bump: u8,
+    emitter_seeds: &[&[u8]],
+    finality: Finality,
     payload: &A,
 ) -> Result<()> {
     let batch_id = 0;
@@ -65,10 +81,10 @@ pub fn post_message<'info, A: TypePrefixedPayload>(
                 program: wormhole.post_message_shim.to_account_info(),
                 event_authority: wormhole.wormhole_post_message_shim_ea.to_account_info(),
             },
-            &[&[b"emitter", &[emitter_bump]]],
+            &[emitter_seeds],
         ),
         batch_id,
-        Finality::Finalized,
+        finality,
         TypePrefixedPayload::to_vec_payload(payload),
     )?;
 
@@ -82,7 +98,7 @@ pub fn post_message<'info, A: TypePrefixedPayload>(
 
         let ix_data = wormhole_post_message_shim_interface::instruction::PostMessage {
             nonce: batch_id,
-            consistency_level: Finality::Finalized,
+            consistency_level: finality,
             payload: TypePrefixedPayload::to_vec_payload(payload),
         }
         .data();
@@ -92,24 +108,61 @@ pub fn post_message<'info, A: TypePrefixedPayload>(
     Ok(())
 }
 
+/// Posts a finalized broadcast message (see [`post_message`]), and returns its payload
+/// as return data in all builds (unlike the `testing` feature return data of
+/// [`post_message`]), so that simulating a broadcast shows exactly what it
+/// emits.
+pub fn post_broadcast_message<'info, A: TypePrefixedPayload>(
+    wormhole: &mut WormholeAccounts<'info>,
+    payer: AccountInfo<'info>,
+    message: AccountInfo<'info>,
+    emitter: AccountInfo<'info>,
+    emitter_seeds: &[&[u8]],
+    payload: &A,
+) -> Result<()> {
+    post_message(
+        wormhole,
+        payer,
+        message,
+        emitter,
+        emitter_seeds,
+        Finality::Finalized,
+        payload,
+    )?;
+
+    solana_program::program::set_return_data(&TypePrefixedPayload::to_vec_payload(payload));
+
+    Ok(())
+}
+
 /// SECURITY: Owner and signer checks are not performed here as this private function is used only by
 /// [`post_message`].
 fn pay_wormhole_fee<'info>(
-    wormhole: &WormholeAccounts<'info>,
+    wormhole: &mut WormholeAccounts<'info>,
     payer: &AccountInfo<'info>,
 ) -> Result<()> {
-    if wormhole.bridge.fee() > 0 {
+    let fee = wormhole.bridge.fee();
+
+    if fee > 0 {
+        let from = match &wormhole.fee_payer {
+            Some(fee_payer) => fee_payer.to_account_info(),
+            None => payer.to_account_info(),
+        };
         anchor_lang::system_program::transfer(
             CpiContext::new(
                 wormhole.system_program.to_account_info(),
                 anchor_lang::system_program::Transfer {
-                    from: payer.to_account_info(),
+                    from,
                     to: wormhole.fee_collector.to_account_info(),
                 },
             ),
-            wormhole.bridge.fee(),
+            fee,
         )?;
     }
 
+    if let Some(fee_stats) = &mut wormhole.fee_stats {
+        fee_stats.record_message(fee);
+    }
+
     Ok(())
 }
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use example_native_token_transfers::error::NTTError;
+
+/// How a peer address is laid out in a broadcast payload.
+///
+/// Wormhole universal addresses are 32 bytes, with shorter native addresses
+/// right-aligned (left-padded with zeros). Some transceivers instead expect
+/// shorter addresses to be left-aligned.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressEncoding {
+    /// The 32 byte universal address, as is.
+    Universal,
+    /// The trailing `len` bytes of the universal address, right-padded with zeros.
+    RightPadded { len: u8 },
+}
+
+impl AddressEncoding {
+    pub fn encode(&self, address: [u8; 32]) -> Result<[u8; 32]> {
+        match *self {
+            AddressEncoding::Universal => Ok(address),
+            AddressEncoding::RightPadded { len } => {
+                let len = usize::from(len);
+                // the native address must fit, and moving it must not drop any bytes
+                if len > address.len() || address[..address.len() - len].iter().any(|b| *b != 0) {
+                    return err!(NTTError::InvalidAddressEncoding);
+                }
+                let mut encoded = [0u8; 32];
+                encoded[..len].copy_from_slice(&address[address.len() - len..]);
+                Ok(encoded)
+            }
+        }
+    }
+}
@@ -1,7 +1,15 @@
-use crate::peer::TransceiverPeer;
-use anchor_lang::prelude::*;
-use example_native_token_transfers::config::Config;
-use ntt_messages::chain_id::ChainId;
+use crate::{
+    broadcast_suppression::WormholeBroadcastSuppression,
+    fee_stats::WormholeFeeStats,
+    peer::{TransceiverPeer, TransceiverPeerIndex},
+    peer_emitter::WormholePeerEmitter,
+};
+use anchor_lang::{prelude::*, Discriminator};
+use example_native_token_transfers::{
+    config::Config, error::NTTError, instructions::migrate_queue_item::resize,
+    registered_transceiver::RegisteredTransceiver, transceivers::accounts::peer::PeerAddressMatch,
+};
+use ntt_messages::chain_id::{known_chain, ChainId};
 
 #[derive(Accounts)]
 #[instruction(args: SetTransceiverPeerArgs)]
@@ -16,6 +24,17 @@ pub struct SetTransceiverPeer<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    #[account(
+        seeds = [RegisteredTransceiver::SEED_PREFIX, crate::ID.as_ref()],
+        bump,
+        seeds::program = example_native_token_transfers::ID,
+        constraint = is_enabled(&registered_transceiver, &config)? @ NTTError::TransceiverNotRegistered,
+    )]
+    /// CHECK: this transceiver's registration with the manager. It's not
+    /// deserialized as an `Account`, as it doesn't exist until the transceiver
+    /// is registered, which is checked in [`is_enabled`].
+    pub registered_transceiver: UncheckedAccount<'info>,
+
     #[account(
         init,
         space = 8 + TransceiverPeer::INIT_SPACE,
@@ -25,6 +44,24 @@ pub struct SetTransceiverPeer<'info> {
     )]
     pub peer: Account<'info, TransceiverPeer>,
 
+    #[account(
+        init_if_needed,
+        space = 8 + TransceiverPeerIndex::INIT_SPACE,
+        payer = payer,
+        seeds = [TransceiverPeerIndex::SEED_PREFIX],
+        bump,
+    )]
+    pub peer_index: Account<'info, TransceiverPeerIndex>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + WormholeFeeStats::INIT_SPACE,
+        payer = payer,
+        seeds = [WormholeFeeStats::SEED_PREFIX],
+        bump
+    )]
+    pub fee_stats: Account<'info, WormholeFeeStats>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -34,14 +71,289 @@ pub struct SetTransceiverPeerArgs {
     pub address: [u8; 32],
 }
 
+/// Whether the transceiver is registered with the manager, and enabled.
+/// Peers can only be set after registration, so that the transceiver can't
+/// receive messages that the manager doesn't know about.
+fn is_enabled(registered_transceiver: &AccountInfo, config: &Config) -> Result<bool> {
+    if *registered_transceiver.owner != example_native_token_transfers::ID
+        || registered_transceiver.data_is_empty()
+    {
+        return Ok(false);
+    }
+    let registered_transceiver = RegisteredTransceiver::try_deserialize(
+        &mut &registered_transceiver.try_borrow_data()?[..],
+    )?;
+    config.enabled_transceivers.get(registered_transceiver.id)
+}
+
+/// Settings of [`set_transceiver_peer_with_options`] that
+/// [`set_transceiver_peer`] leaves at their defaults. They're not part of
+/// [`SetTransceiverPeerArgs`], so that its encoding stays the same for
+/// existing clients.
+#[derive(AnchorDeserialize, AnchorSerialize, Default)]
+pub struct SetTransceiverPeerOptions {
+    /// Skip the check that `chain_id` is a known Wormhole chain id.
+    /// Only needed for chains added to Wormhole after this program was built.
+    pub allow_unknown_chain: bool,
+    /// How `address` is compared against the emitter of received messages.
+    pub address_match: PeerAddressMatch,
+}
+
 pub fn set_transceiver_peer(
     ctx: Context<SetTransceiverPeer>,
     args: SetTransceiverPeerArgs,
 ) -> Result<()> {
+    set_transceiver_peer_with_options(ctx, args, SetTransceiverPeerOptions::default())
+}
+
+pub fn set_transceiver_peer_with_options(
+    ctx: Context<SetTransceiverPeer>,
+    args: SetTransceiverPeerArgs,
+    options: SetTransceiverPeerOptions,
+) -> Result<()> {
+    require!(
+        options.allow_unknown_chain || known_chain(args.chain_id.id),
+        NTTError::UnknownChainId
+    );
+    if !known_chain(args.chain_id.id) {
+        msg!(
+            "WARNING: registering peer for unknown chain id {}",
+            args.chain_id.id
+        );
+    }
+
     ctx.accounts.peer.set_inner(TransceiverPeer {
         bump: ctx.bumps.peer,
         address: args.address,
+        address_match: options.address_match,
+    });
+
+    // NOTE: the bump is written on every call, but the counters are only ever
+    // zero-initialized the first time the account is created.
+    ctx.accounts.fee_stats.bump = ctx.bumps.fee_stats;
+
+    ctx.accounts.peer_index.bump = ctx.bumps.peer_index;
+    ctx.accounts.peer_index.insert(args.chain_id.id)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct DeleteTransceiverPeer<'info> {
+    #[account(has_one = owner)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [TransceiverPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, TransceiverPeer>,
+
+    #[account(
+        mut,
+        seeds = [TransceiverPeerIndex::SEED_PREFIX],
+        bump = peer_index.bump,
+    )]
+    pub peer_index: Account<'info, TransceiverPeerIndex>,
+}
+
+/// Removes the peer on `chain_id`, so that it can be set again (peers can't be
+/// overwritten).
+pub fn delete_transceiver_peer(ctx: Context<DeleteTransceiverPeer>, chain_id: u16) -> Result<()> {
+    ctx.accounts.peer_index.remove(chain_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct GetTransceiverPeerIndex<'info> {
+    #[account(
+        seeds = [TransceiverPeerIndex::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the index doesn't exist until the first peer is set, which is
+    /// checked in [`get_transceiver_peer_index`].
+    pub peer_index: UncheckedAccount<'info>,
+}
+
+/// Returns the chain ids of the configured peers (see [`TransceiverPeerIndex`]).
+pub fn get_transceiver_peer_index(ctx: Context<GetTransceiverPeerIndex>) -> Result<Vec<u16>> {
+    let peer_index = &ctx.accounts.peer_index;
+    if peer_index.data_is_empty() {
+        return Ok(vec![]);
+    }
+
+    let peer_index =
+        TransceiverPeerIndex::try_deserialize(&mut &peer_index.try_borrow_data()?[..])?;
+    Ok(peer_index.chain_ids)
+}
+
+// * Migrate peers
+
+/// Layout of [`TransceiverPeer`] before [`TransceiverPeer::address_match`] was
+/// appended to it.
+#[derive(AnchorDeserialize)]
+struct LegacyTransceiverPeer {
+    bump: u8,
+    address: [u8; 32],
+}
+
+impl LegacyTransceiverPeer {
+    const SPACE: usize = 1 + 32;
+}
+
+/// Brings a [`TransceiverPeer`] created by an earlier version of the program
+/// up to the current layout, so that messages from its chain can be received
+/// again. The peer is also added to the [`TransceiverPeerIndex`], which didn't
+/// exist when it was set.
+///
+/// This is permissionless, as it doesn't change the peer's configuration:
+/// legacy peers matched emitter addresses exactly.
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct MigrateTransceiverPeer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TransceiverPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    /// CHECK: deserialized manually in [`migrate_transceiver_peer`], as it is
+    /// still in the legacy layout.
+    pub peer: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + TransceiverPeerIndex::INIT_SPACE,
+        payer = payer,
+        seeds = [TransceiverPeerIndex::SEED_PREFIX],
+        bump,
+    )]
+    pub peer_index: Account<'info, TransceiverPeerIndex>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_transceiver_peer(ctx: Context<MigrateTransceiverPeer>, chain_id: u16) -> Result<()> {
+    let info = ctx.accounts.peer.to_account_info();
+
+    let legacy = {
+        let data = info.try_borrow_data()?;
+        require!(
+            data.starts_with(&TransceiverPeer::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        require!(
+            data.len() == 8 + LegacyTransceiverPeer::SPACE,
+            NTTError::PeerAlreadyMigrated
+        );
+        LegacyTransceiverPeer::deserialize(&mut &data[8..])?
+    };
+
+    resize(
+        &info,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        8 + TransceiverPeer::INIT_SPACE,
+    )?;
+
+    let peer = TransceiverPeer {
+        bump: legacy.bump,
+        address: legacy.address,
+        address_match: PeerAddressMatch::Exact,
+    };
+    peer.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    ctx.accounts.peer_index.bump = ctx.bumps.peer_index;
+    ctx.accounts.peer_index.insert(chain_id)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct RegisterPeerEmitter<'info> {
+    #[account(has_one = owner)]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [TransceiverPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, TransceiverPeer>,
+
+    #[account(
+        init,
+        space = 8 + WormholePeerEmitter::INIT_SPACE,
+        payer = payer,
+        seeds = [WormholePeerEmitter::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump
+    )]
+    pub peer_emitter: Account<'info, WormholePeerEmitter>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers an emitter for the peer on `chain_id`, which broadcasts can then
+/// be posted from (see [`WormholePeerEmitter`]).
+pub fn register_peer_emitter(ctx: Context<RegisterPeerEmitter>, chain_id: u16) -> Result<()> {
+    ctx.accounts.peer_emitter.set_inner(WormholePeerEmitter {
+        bump: ctx.bumps.peer_emitter,
+        chain_id,
     });
 
     Ok(())
 }
+
+#[derive(Accounts)]
+pub struct SetBroadcastSuppressionWindow<'info> {
+    #[account(has_one = owner)]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + WormholeBroadcastSuppression::INIT_SPACE,
+        payer = payer,
+        seeds = [WormholeBroadcastSuppression::SEED_PREFIX],
+        bump
+    )]
+    pub broadcast_suppression: Account<'info, WormholeBroadcastSuppression>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets the number of seconds for which a peer broadcast identical to the
+/// previous one is rejected (see [`crate::wormhole::instructions::broadcast_peer`]).
+/// A window of zero disables the suppression.
+pub fn set_broadcast_suppression_window(
+    ctx: Context<SetBroadcastSuppressionWindow>,
+    window: u32,
+) -> Result<()> {
+    ctx.accounts
+        .broadcast_suppression
+        .set_inner(WormholeBroadcastSuppression {
+            bump: ctx.bumps.broadcast_suppression,
+            window,
+        });
+
+    Ok(())
+}
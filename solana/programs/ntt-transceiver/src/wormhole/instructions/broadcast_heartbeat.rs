@@ -0,0 +1,73 @@
+use crate::{heartbeat::WormholeHeartbeat, wormhole::accounts::*};
+use anchor_lang::prelude::*;
+use example_native_token_transfers::{
+    clock::current_timestamp, config::*, error::NTTError, retry_after::retry_after,
+};
+use ntt_messages::transceivers::wormhole::WormholeTransceiverHeartbeat;
+
+#[derive(Accounts)]
+pub struct BroadcastHeartbeat<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + WormholeHeartbeat::INIT_SPACE,
+        seeds = [WormholeHeartbeat::SEED_PREFIX],
+        bump,
+    )]
+    pub heartbeat: Account<'info, WormholeHeartbeat>,
+
+    /// CHECK: initialized and written to by wormhole core bridge
+    #[account(mut, seeds = [&emitter.key.to_bytes()], bump, seeds::program = wormhole_svm_definitions::solana::POST_MESSAGE_SHIM_PROGRAM_ID)]
+    pub wormhole_message: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"emitter"],
+        bump
+    )]
+    /// CHECK: The only valid sender is the [`wormhole::PostMessage::emitter`]
+    /// enforced by the [`CpiContext`] call in [`post_message`].
+    /// The seeds constraint ensures that this is the correct address
+    pub emitter: UncheckedAccount<'info>,
+
+    pub wormhole: WormholeAccounts<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Broadcasts a [`WormholeTransceiverHeartbeat`] with the current timestamp.
+/// Anyone can send a heartbeat, but at most one every
+/// [`WormholeHeartbeat::MIN_INTERVAL`] seconds. Earlier calls fail with
+/// [`NTTError::HeartbeatTooSoon`], and the time left is written into the
+/// return data (see [`example_native_token_transfers::retry_after`]).
+pub fn broadcast_heartbeat(ctx: Context<BroadcastHeartbeat>) -> Result<()> {
+    let accs = ctx.accounts;
+    let now = current_timestamp();
+
+    let next_heartbeat = accs.heartbeat.next_heartbeat();
+    if now < next_heartbeat {
+        return Err(retry_after(next_heartbeat, NTTError::HeartbeatTooSoon));
+    }
+    accs.heartbeat.bump = ctx.bumps.heartbeat;
+    accs.heartbeat.last_heartbeat = now;
+
+    let message = WormholeTransceiverHeartbeat {
+        manager_address: accs.config.to_account_info().owner.to_bytes(),
+        timestamp: u64::try_from(now).unwrap_or(0),
+    };
+
+    post_broadcast_message(
+        &mut accs.wormhole,
+        accs.payer.to_account_info(),
+        accs.wormhole_message.to_account_info(),
+        accs.emitter.to_account_info(),
+        &[b"emitter", &[ctx.bumps.emitter]],
+        &message,
+    )?;
+
+    Ok(())
+}
@@ -1,7 +1,10 @@
-use crate::wormhole::accounts::*;
+use crate::{peer_emitter::WormholePeerEmitter, wormhole::accounts::*};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface;
-use example_native_token_transfers::config::*;
+use example_native_token_transfers::{
+    config::*,
+    token_metadata::{self, token_metadata_hash},
+};
 use ntt_messages::transceivers::wormhole::WormholeTransceiverInfo;
 
 #[derive(Accounts)]
@@ -21,15 +24,39 @@ pub struct BroadcastId<'info> {
     pub wormhole_message: UncheckedAccount<'info>,
 
     #[account(
-        seeds = [b"emitter"],
+        seeds = [
+            b"emitter",
+            WormholePeerEmitter::emitter_seed(peer_emitter.as_deref()).as_ref(),
+        ],
         bump
     )]
     /// CHECK: The only valid sender is the [`wormhole::PostMessage::emitter`]
     /// enforced by the [`CpiContext`] call in [`post_message`].
-    /// The seeds constraint ensures that this is the correct address
+    /// The seeds constraint ensures that this is the correct address: the
+    /// emitter registered in [`Self::peer_emitter`] if given, and the default
+    /// one otherwise.
     pub emitter: UncheckedAccount<'info>,
 
     pub wormhole: WormholeAccounts<'info>,
+
+    #[account(
+        address = token_metadata::metadata_address(&mint.key()),
+    )]
+    /// CHECK: the address is derived from the mint, and the account is parsed
+    /// in [`token_metadata_hash`]. It's optional, as not every token has
+    /// Metaplex metadata.
+    pub token_metadata: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [
+            WormholePeerEmitter::SEED_PREFIX,
+            peer_emitter.chain_id.to_be_bytes().as_ref(),
+        ],
+        bump = peer_emitter.bump,
+    )]
+    /// The emitter to broadcast from instead of the default one, see
+    /// [`WormholePeerEmitter`].
+    pub peer_emitter: Option<Account<'info, WormholePeerEmitter>>,
 }
 
 pub fn broadcast_id(ctx: Context<BroadcastId>) -> Result<()> {
@@ -39,15 +66,20 @@ pub fn broadcast_id(ctx: Context<BroadcastId>) -> Result<()> {
         manager_mode: accs.config.mode,
         token_address: accs.mint.to_account_info().key.to_bytes(),
         token_decimals: accs.mint.decimals,
+        token_metadata_hash: Some(token_metadata_hash(
+            accs.token_metadata.as_deref(),
+            &accs.mint.key(),
+        )?),
     };
 
+    let emitter_seed = WormholePeerEmitter::emitter_seed(accs.peer_emitter.as_deref());
     // TODO: should we send this as an unreliable message into a PDA?
-    post_message(
-        &accs.wormhole,
+    post_broadcast_message(
+        &mut accs.wormhole,
         accs.payer.to_account_info(),
         accs.wormhole_message.to_account_info(),
         accs.emitter.to_account_info(),
-        ctx.bumps.emitter,
+        &[b"emitter", &emitter_seed, &[ctx.bumps.emitter]],
         &message,
     )?;
 
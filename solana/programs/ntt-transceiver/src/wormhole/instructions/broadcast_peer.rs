@@ -1,7 +1,15 @@
-use crate::{peer::TransceiverPeer, wormhole::accounts::*};
+use crate::{
+    broadcast_suppression::{WormholeBroadcastSuppression, WormholePeerBroadcast},
+    peer::TransceiverPeer,
+    peer_emitter::WormholePeerEmitter,
+    wormhole::{accounts::*, AddressEncoding},
+};
 use anchor_lang::prelude::*;
-use example_native_token_transfers::config::*;
+use example_native_token_transfers::{
+    clock::current_timestamp, config::*, error::NTTError, retry_after::retry_after,
+};
 use ntt_messages::{chain_id::ChainId, transceivers::wormhole::WormholeTransceiverRegistration};
+use wormhole_io::TypePrefixedPayload;
 
 #[derive(Accounts)]
 #[instruction(args: BroadcastPeerArgs)]
@@ -22,13 +30,49 @@ pub struct BroadcastPeer<'info> {
     pub wormhole_message: UncheckedAccount<'info>,
 
     #[account(
-        seeds = [b"emitter"],
+        seeds = [
+            b"emitter",
+            WormholePeerEmitter::emitter_seed(peer_emitter.as_deref()).as_ref(),
+        ],
         bump
     )]
-    /// CHECK: The seeds constraint ensures that this is the correct address
+    /// CHECK: The seeds constraint ensures that this is the correct address: the
+    /// emitter registered in [`Self::peer_emitter`] if given, and the default
+    /// one otherwise.
     pub emitter: UncheckedAccount<'info>,
 
     pub wormhole: WormholeAccounts<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + WormholeBroadcastSuppression::INIT_SPACE,
+        seeds = [WormholeBroadcastSuppression::SEED_PREFIX],
+        bump,
+    )]
+    pub broadcast_suppression: Account<'info, WormholeBroadcastSuppression>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + WormholePeerBroadcast::INIT_SPACE,
+        seeds = [WormholePeerBroadcast::SEED_PREFIX, args.chain_id.to_be_bytes().as_ref()],
+        bump,
+    )]
+    pub peer_broadcast: Account<'info, WormholePeerBroadcast>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [
+            WormholePeerEmitter::SEED_PREFIX,
+            peer_emitter.chain_id.to_be_bytes().as_ref(),
+        ],
+        bump = peer_emitter.bump,
+    )]
+    /// The emitter to broadcast from instead of the default one, see
+    /// [`WormholePeerEmitter`].
+    pub peer_emitter: Option<Account<'info, WormholePeerEmitter>>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -36,24 +80,64 @@ pub struct BroadcastPeerArgs {
     pub chain_id: u16,
 }
 
+/// Broadcasts the peer registered for `args.chain_id`. A broadcast identical to
+/// the previous one for the same chain fails with
+/// [`NTTError::BroadcastSuppressed`] for
+/// [`WormholeBroadcastSuppression::window`] seconds, and the time left is
+/// written into the return data (see
+/// [`example_native_token_transfers::retry_after`]).
+///
 /// SECURITY: Owner checks are disabled. [`BroadcastPeer::emitter`] is enforced to be a PDA.
 #[allow(unknown_lints)]
 #[allow(missing_owner_check)]
 pub fn broadcast_peer(ctx: Context<BroadcastPeer>, args: BroadcastPeerArgs) -> Result<()> {
+    broadcast_peer_with_address_encoding(ctx, args, AddressEncoding::Universal)
+}
+
+/// [`broadcast_peer`], with the peer address encoded in the payload as
+/// `address_encoding`, for transceivers that don't expect the Wormhole
+/// universal address format. It's not part of [`BroadcastPeerArgs`], so that
+/// its encoding stays the same for existing clients.
+///
+/// SECURITY: Owner checks are disabled. [`BroadcastPeer::emitter`] is enforced to be a PDA.
+#[allow(unknown_lints)]
+#[allow(missing_owner_check)]
+pub fn broadcast_peer_with_address_encoding(
+    ctx: Context<BroadcastPeer>,
+    args: BroadcastPeerArgs,
+    address_encoding: AddressEncoding,
+) -> Result<()> {
     let accs = ctx.accounts;
 
     let message = WormholeTransceiverRegistration {
         chain_id: ChainId { id: args.chain_id },
-        transceiver_address: accs.peer.address,
+        transceiver_address: address_encoding.encode(accs.peer.address)?,
     };
 
+    let now = current_timestamp();
+    let payload_hash =
+        solana_program::keccak::hash(&TypePrefixedPayload::to_vec_payload(&message)).to_bytes();
+    let next_broadcast = accs
+        .peer_broadcast
+        .next_broadcast(&payload_hash, accs.broadcast_suppression.window);
+    if now < next_broadcast {
+        return Err(retry_after(next_broadcast, NTTError::BroadcastSuppressed));
+    }
+    accs.broadcast_suppression.bump = ctx.bumps.broadcast_suppression;
+    accs.peer_broadcast.set_inner(WormholePeerBroadcast {
+        bump: ctx.bumps.peer_broadcast,
+        payload_hash,
+        last_broadcast: now,
+    });
+
+    let emitter_seed = WormholePeerEmitter::emitter_seed(accs.peer_emitter.as_deref());
     // TODO: should we send this as an unreliable message into a PDA?
-    post_message(
-        &accs.wormhole,
+    post_broadcast_message(
+        &mut accs.wormhole,
         accs.payer.to_account_info(),
         accs.wormhole_message.to_account_info(),
         accs.emitter.to_account_info(),
-        ctx.bumps.emitter,
+        &[b"emitter", &emitter_seed, &[ctx.bumps.emitter]],
         &message,
     )?;
 
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::fee_stats::WormholeFeeStats;
+
+#[derive(Accounts)]
+pub struct GetFeeStats<'info> {
+    #[account(
+        seeds = [WormholeFeeStats::SEED_PREFIX],
+        bump = fee_stats.bump,
+    )]
+    pub fee_stats: Account<'info, WormholeFeeStats>,
+}
+
+/// Returns the Wormhole core bridge fees paid by this transceiver so far, so
+/// that they can be read with a single simulated call.
+pub fn get_fee_stats(ctx: Context<GetFeeStats>) -> Result<WormholeFeeStats> {
+    Ok(ctx.accounts.fee_stats.clone().into_inner())
+}
@@ -1,13 +1,21 @@
 pub mod admin;
+pub mod broadcast_heartbeat;
 pub mod broadcast_id;
 pub mod broadcast_peer;
+pub mod get_fee_stats;
+pub mod pending_message;
+pub mod quote_delivery_cost;
 pub mod receive_message;
 pub mod release_outbound;
 pub mod unverified_message_account;
 
 pub use admin::*;
+pub use broadcast_heartbeat::*;
 pub use broadcast_id::*;
 pub use broadcast_peer::*;
+pub use get_fee_stats::*;
+pub use pending_message::*;
+pub use quote_delivery_cost::*;
 pub use receive_message::*;
 pub use release_outbound::*;
 pub use unverified_message_account::*;
@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+
+use example_native_token_transfers::{
+    config::{anchor_reexports::*, *},
+    error::NTTError,
+    peer::NttManagerPeer,
+    receive_hook::{notify_receive_hook, ReceiveHookArgs},
+    transfer::Payload,
+};
+use ntt_messages::{
+    chain_id::ChainId, ntt::NativeTokenTransfer, transceivers::wormhole::WormholeTransceiver,
+};
+use wormhole_verify_vaa_shim_interface::program::WormholeVerifyVaaShim;
+
+use crate::{
+    messages::{ValidatedTransceiverMessage, VerifiedPendingMessage},
+    peer::TransceiverPeer,
+    vaa_body::{AsVaaBodyBytes, VaaBody},
+    wormhole::instructions::{verify_vaa_body, TRANSCEIVER_MESSAGE_SPACE},
+};
+
+#[derive(Accounts)]
+#[instruction(_guardian_set_bump: u8, seed: u64)]
+pub struct VerifyPendingMessage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        // check that the messages is targeted to this chain
+        constraint = message.as_vaa_body_bytes().to_chain()? == config.chain_id @ NTTError::InvalidChainId,
+        // check that we understand the payload format
+        constraint = message.as_vaa_body_bytes().version()? == NativeTokenTransfer::<Payload>::CURRENT_VERSION @ NTTError::UnsupportedPayloadVersion,
+    )]
+    /// NOTE: unlike the receive instructions, this is allowed while the
+    /// program is paused. The message is only accepted once it's promoted
+    /// (see [`promote_pending_message`]).
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [TransceiverPeer::SEED_PREFIX, message.as_vaa_body_bytes().emitter_chain()?.to_be_bytes().as_ref()],
+        constraint = peer.matches(message.as_vaa_body_bytes().emitter_address()?) @ NTTError::InvalidTransceiverPeer,
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, TransceiverPeer>,
+
+    #[account(
+        mut,
+        seeds = [
+            VaaBody::SEED_PREFIX,
+            &payer.key.to_bytes(),
+            seed.to_be_bytes().as_ref(),
+        ],
+        bump,
+        close = payer,
+    )]
+    pub message: Account<'info, VaaBody>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + VerifiedPendingMessage::INIT_SPACE,
+        seeds = [
+            VerifiedPendingMessage::SEED_PREFIX,
+            message.as_vaa_body_bytes().emitter_chain()?.to_be_bytes().as_ref(),
+            message.as_vaa_body_bytes().id()?,
+        ],
+        bump,
+    )]
+    pub pending_message: Account<'info, VerifiedPendingMessage>,
+
+    /// CHECK: Guardian set used for signature verification by shim.
+    /// Derivation is checked by the shim.
+    pub guardian_set: UncheckedAccount<'info>,
+
+    /// CHECK: Stored guardian signatures to be verified by shim.
+    /// Ownership and discriminator are checked in [`verify_vaa_body`] before
+    /// invoking the shim.
+    pub guardian_signatures: UncheckedAccount<'info>,
+
+    pub verify_vaa_shim: Program<'info, WormholeVerifyVaaShim>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Verifies the guardian signatures over a staged VAA body, and holds the
+/// parsed message in a [`VerifiedPendingMessage`] account.
+pub fn verify_pending_message(
+    ctx: Context<VerifyPendingMessage>,
+    guardian_set_bump: u8,
+    _seed: u64,
+) -> Result<()> {
+    let accs = ctx.accounts;
+    let vaa_body = accs.message.as_vaa_body_bytes();
+
+    accs.config.check_vaa_age(vaa_body.timestamp()?)?;
+
+    verify_vaa_body(
+        &accs.verify_vaa_shim,
+        &accs.guardian_set,
+        &accs.guardian_signatures,
+        guardian_set_bump,
+        &vaa_body,
+        None,
+    )?;
+
+    let message =
+        vaa_body.transceiver_message_data::<WormholeTransceiver, NativeTokenTransfer<Payload>>()?;
+
+    accs.pending_message.set_inner(VerifiedPendingMessage {
+        rent_payer: accs.payer.key(),
+        from_chain: ChainId {
+            id: vaa_body.emitter_chain()?,
+        },
+        emitter_address: *vaa_body.emitter_address()?,
+        message,
+        vaa_timestamp: vaa_body.timestamp()?,
+        unconsumed_payload_len: vaa_body
+            .unconsumed_payload_len::<WormholeTransceiver, NativeTokenTransfer<Payload>>()?,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PromotePendingMessage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub config: NotPausedInboundConfig<'info>,
+
+    #[account(
+        seeds = [TransceiverPeer::SEED_PREFIX, pending_message.from_chain.id.to_be_bytes().as_ref()],
+        constraint = peer.matches(&pending_message.emitter_address) @ NTTError::InvalidTransceiverPeer,
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, TransceiverPeer>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, pending_message.from_chain.id.to_be_bytes().as_ref()],
+        seeds::program = example_native_token_transfers::ID,
+        constraint = manager_peer.address == pending_message.message.source_ntt_manager @ NTTError::InvalidNttManagerPeer,
+        bump = manager_peer.bump,
+    )]
+    pub manager_peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        mut,
+        has_one = rent_payer,
+        close = rent_payer,
+    )]
+    pub pending_message: Account<'info, VerifiedPendingMessage>,
+
+    #[account(mut)]
+    /// CHECK: checked to be [`VerifiedPendingMessage::rent_payer`]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TRANSCEIVER_MESSAGE_SPACE,
+        seeds = [
+            ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::SEED_PREFIX,
+            pending_message.from_chain.id.to_be_bytes().as_ref(),
+            pending_message.message.ntt_manager_payload.id.as_ref(),
+        ],
+        bump,
+    )]
+    /// Promoting a message that this transceiver has already received is a
+    /// no-op, other than closing the pending account.
+    pub transceiver_message:
+        Account<'info, ValidatedTransceiverMessage<NativeTokenTransfer<Payload>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Converts a [`VerifiedPendingMessage`] into a [`ValidatedTransceiverMessage`].
+/// The signatures have already been verified, so this is cheap, and can be
+/// called once the program is unpaused.
+/// Like the other receive paths, the receive hook is then notified about the
+/// message, if one is configured.
+pub fn promote_pending_message(ctx: Context<PromotePendingMessage>) -> Result<()> {
+    let accs = ctx.accounts;
+
+    // the message has already been received from this transceiver
+    if ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::is_initialized(
+        &accs.transceiver_message.to_account_info(),
+    )? {
+        return Ok(());
+    }
+
+    accs.transceiver_message
+        .set_inner(ValidatedTransceiverMessage {
+            bump: ctx.bumps.transceiver_message,
+            from_chain: accs.pending_message.from_chain,
+            message: accs.pending_message.message.clone(),
+            vaa_timestamp: accs.pending_message.vaa_timestamp,
+            unconsumed_payload_len: accs.pending_message.unconsumed_payload_len,
+            message_hash: accs
+                .pending_message
+                .message
+                .ntt_manager_payload
+                .keccak256(accs.pending_message.from_chain)
+                .to_bytes(),
+        });
+
+    notify_receive_hook(
+        &accs.config,
+        accs.transceiver_message.key(),
+        ReceiveHookArgs::new(
+            accs.pending_message.from_chain,
+            &accs.pending_message.message,
+        ),
+    )
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token;
+use example_native_token_transfers::queue::inbox::InboxItem;
+use solana_program::program_pack::Pack;
+
+use crate::{vaa_body::VaaBody, wormhole::instructions::TRANSCEIVER_MESSAGE_SPACE};
+
+#[derive(Accounts)]
+pub struct QuoteDeliveryCost {}
+
+/// Itemized rent (in lamports) required to deliver an inbound transfer.
+///
+/// NOTE: transaction fees and the guardian signatures account (which is
+/// allocated by the verify VAA shim, and sized by the number of signatures)
+/// are not included.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeliveryCostQuote {
+    /// The staged [`VaaBody`] account. Refunded when the message is received.
+    pub vaa_body: u64,
+    /// The [`crate::messages::ValidatedTransceiverMessage`] account.
+    pub transceiver_message: u64,
+    /// The [`InboxItem`] account allocated by the manager on redeem.
+    pub inbox_item: u64,
+    /// The recipient's associated token account, if it doesn't exist yet.
+    pub recipient_token_account: u64,
+    /// Total rent that is refunded to the payer by the end of the delivery.
+    pub reclaimable: u64,
+    /// Total rent that remains locked in accounts after the delivery.
+    pub non_reclaimable: u64,
+}
+
+pub fn quote_delivery_cost(
+    _ctx: Context<QuoteDeliveryCost>,
+    vaa_size: u32,
+    recipient_has_ata: bool,
+) -> Result<DeliveryCostQuote> {
+    let rent = Rent::get()?;
+
+    let vaa_body = rent.minimum_balance(VaaBody::space(vaa_size));
+    let transceiver_message = rent.minimum_balance(TRANSCEIVER_MESSAGE_SPACE);
+    let inbox_item = rent.minimum_balance(8 + InboxItem::INIT_SPACE);
+    let recipient_token_account = if recipient_has_ata {
+        0
+    } else {
+        rent.minimum_balance(spl_token::state::Account::LEN)
+    };
+
+    Ok(DeliveryCostQuote {
+        vaa_body,
+        transceiver_message,
+        inbox_item,
+        recipient_token_account,
+        reclaimable: vaa_body,
+        non_reclaimable: transceiver_message + inbox_item + recipient_token_account,
+    })
+}
@@ -1,12 +1,15 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::hash};
 
 use example_native_token_transfers::{
     config::{anchor_reexports::*, *},
     error::NTTError,
+    messages::create_transceiver_message_account,
+    peer::NttManagerPeer,
+    receive_hook::{notify_receive_hook, ReceiveHookArgs},
     transfer::Payload,
 };
 use ntt_messages::{
-    chain_id::ChainId, ntt::NativeTokenTransfer, transceiver::TransceiverMessageData,
+    ntt::NativeTokenTransfer, transceiver::TransceiverMessageData,
     transceivers::wormhole::WormholeTransceiver,
 };
 use wormhole_sdk::vaa::digest;
@@ -15,9 +18,15 @@ use wormhole_verify_vaa_shim_interface::program::WormholeVerifyVaaShim;
 use crate::{
     messages::ValidatedTransceiverMessage,
     peer::TransceiverPeer,
-    vaa_body::{AsVaaBodyBytes, VaaBody, VaaBodyData},
+    vaa_body::{AsVaaBodyBytes, VaaBody, VaaBodyBytes, VaaBodyData},
 };
 
+/// The size of the [`ValidatedTransceiverMessage`] account allocated on receive.
+/// This covers messages with the largest additional payload (see
+/// [`example_native_token_transfers::transfer::MAX_ADDITIONAL_PAYLOAD_LEN`]).
+pub const TRANSCEIVER_MESSAGE_SPACE: usize =
+    8 + ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::INIT_SPACE;
+
 #[derive(Accounts)]
 #[instruction(_guardian_set_bump: u8, vaa_body: VaaBodyData)]
 pub struct ReceiveMessageInstructionData<'info> {
@@ -26,41 +35,51 @@ pub struct ReceiveMessageInstructionData<'info> {
 
     #[account(
         // check that the messages is targeted to this chain
-        constraint = vaa_body.as_vaa_body_bytes().to_chain() == config.chain_id @ NTTError::InvalidChainId,
+        constraint = vaa_body.as_vaa_body_bytes().to_chain()? == config.chain_id @ NTTError::InvalidChainId,
+        // check that we understand the payload format
+        constraint = vaa_body.as_vaa_body_bytes().version()? == NativeTokenTransfer::<Payload>::CURRENT_VERSION @ NTTError::UnsupportedPayloadVersion,
     )]
-    pub config: NotPausedConfig<'info>,
+    pub config: NotPausedInboundConfig<'info>,
 
     #[account(
-        seeds = [TransceiverPeer::SEED_PREFIX, vaa_body.as_vaa_body_bytes().emitter_chain().to_be_bytes().as_ref()],
-        constraint = peer.address == *vaa_body.as_vaa_body_bytes().emitter_address() @ NTTError::InvalidTransceiverPeer,
+        seeds = [TransceiverPeer::SEED_PREFIX, vaa_body.as_vaa_body_bytes().emitter_chain()?.to_be_bytes().as_ref()],
+        constraint = peer.matches(vaa_body.as_vaa_body_bytes().emitter_address()?) @ NTTError::InvalidTransceiverPeer,
         bump = peer.bump,
     )]
     pub peer: Account<'info, TransceiverPeer>,
 
     #[account(
-        init,
-        payer = payer,
-        space = 8 + ValidatedTransceiverMessage::<TransceiverMessageData<NativeTokenTransfer<Payload>>>::INIT_SPACE,
+        seeds = [NttManagerPeer::SEED_PREFIX, vaa_body.as_vaa_body_bytes().emitter_chain()?.to_be_bytes().as_ref()],
+        seeds::program = example_native_token_transfers::ID,
+        constraint = manager_peer.address == *vaa_body.as_vaa_body_bytes().source_ntt_manager()? @ NTTError::InvalidNttManagerPeer,
+        bump = manager_peer.bump,
+    )]
+    pub manager_peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        mut,
         seeds = [
             ValidatedTransceiverMessage::<TransceiverMessageData<NativeTokenTransfer<Payload>>>::SEED_PREFIX,
-            vaa_body.as_vaa_body_bytes().emitter_chain().to_be_bytes().as_ref(),
-            vaa_body.as_vaa_body_bytes().id(),
+            vaa_body.as_vaa_body_bytes().emitter_chain()?.to_be_bytes().as_ref(),
+            vaa_body.as_vaa_body_bytes().id()?,
         ],
         bump,
     )]
-    // NOTE: in order to handle multiple transceivers, we can just augment the
-    // inbox item transfer struct with a bitmap storing which transceivers have
-    // attested to the transfer. Then we only release it if there's quorum.
-    // We would need to maybe_init this account in that case.
-    pub transceiver_message:
-        Account<'info, ValidatedTransceiverMessage<NativeTokenTransfer<Payload>>>,
+    /// CHECK: created in [`verify_and_store_message`] unless it already
+    /// exists, so that receiving the same message twice from this transceiver
+    /// is a no-op rather than an error.
+    /// The account is seeded by the message's (emitter chain, id), so if it
+    /// has already been initialized, the message has already been received
+    /// (see [`ValidatedTransceiverMessage::is_initialized`]).
+    pub transceiver_message: UncheckedAccount<'info>,
 
     /// CHECK: Guardian set used for signature verification by shim.
     /// Derivation is checked by the shim.
     pub guardian_set: UncheckedAccount<'info>,
 
     /// CHECK: Stored guardian signatures to be verified by shim.
-    /// Ownership ownership and discriminator is checked by the shim.
+    /// Ownership and discriminator are checked in [`verify_vaa_body`] before
+    /// invoking the shim.
     pub guardian_signatures: UncheckedAccount<'info>,
 
     pub verify_vaa_shim: Program<'info, WormholeVerifyVaaShim>,
@@ -72,36 +91,22 @@ pub fn receive_message_instruction_data(
     ctx: Context<ReceiveMessageInstructionData>,
     guardian_set_bump: u8,
     vaa_body: VaaBodyData,
+    expected_digest: Option<[u8; 32]>,
 ) -> Result<()> {
-    let vaa_body = vaa_body.as_vaa_body_bytes();
-    // verify the hash against the signatures
-    let digest = digest(vaa_body.span)?;
-    wormhole_verify_vaa_shim_interface::cpi::verify_hash(
-        CpiContext::new(
-            ctx.accounts.verify_vaa_shim.to_account_info(),
-            wormhole_verify_vaa_shim_interface::cpi::accounts::VerifyHash {
-                guardian_set: ctx.accounts.guardian_set.to_account_info(),
-                guardian_signatures: ctx.accounts.guardian_signatures.to_account_info(),
-            },
-        ),
+    let accs = ctx.accounts;
+    verify_and_store_message(
+        &accs.config,
+        &accs.payer,
+        &accs.system_program,
+        &accs.verify_vaa_shim,
+        &accs.guardian_set,
+        &accs.guardian_signatures,
+        &accs.transceiver_message,
+        ctx.bumps.transceiver_message,
         guardian_set_bump,
-        digest.secp256k_hash,
-    )?;
-
-    // update transceiver_message
-    let message = vaa_body
-        .transceiver_message_data::<WormholeTransceiver, NativeTokenTransfer<Payload>>()?
-        .clone();
-    ctx.accounts
-        .transceiver_message
-        .set_inner(ValidatedTransceiverMessage {
-            from_chain: ChainId {
-                id: vaa_body.emitter_chain(),
-            },
-            message,
-        });
-
-    Ok(())
+        &vaa_body.as_vaa_body_bytes(),
+        expected_digest,
+    )
 }
 
 #[derive(Accounts)]
@@ -112,17 +117,27 @@ pub struct ReceiveMessageAccount<'info> {
 
     #[account(
         // check that the messages is targeted to this chain
-        constraint = message.as_vaa_body_bytes().to_chain() == config.chain_id @ NTTError::InvalidChainId,
+        constraint = message.as_vaa_body_bytes().to_chain()? == config.chain_id @ NTTError::InvalidChainId,
+        // check that we understand the payload format
+        constraint = message.as_vaa_body_bytes().version()? == NativeTokenTransfer::<Payload>::CURRENT_VERSION @ NTTError::UnsupportedPayloadVersion,
     )]
-    pub config: NotPausedConfig<'info>,
+    pub config: NotPausedInboundConfig<'info>,
 
     #[account(
-        seeds = [TransceiverPeer::SEED_PREFIX, message.as_vaa_body_bytes().emitter_chain().to_be_bytes().as_ref()],
-        constraint = peer.address == *message.as_vaa_body_bytes().emitter_address() @ NTTError::InvalidTransceiverPeer,
+        seeds = [TransceiverPeer::SEED_PREFIX, message.as_vaa_body_bytes().emitter_chain()?.to_be_bytes().as_ref()],
+        constraint = peer.matches(message.as_vaa_body_bytes().emitter_address()?) @ NTTError::InvalidTransceiverPeer,
         bump = peer.bump,
     )]
     pub peer: Account<'info, TransceiverPeer>,
 
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, message.as_vaa_body_bytes().emitter_chain()?.to_be_bytes().as_ref()],
+        seeds::program = example_native_token_transfers::ID,
+        constraint = manager_peer.address == *message.as_vaa_body_bytes().source_ntt_manager()? @ NTTError::InvalidNttManagerPeer,
+        bump = manager_peer.bump,
+    )]
+    pub manager_peer: Account<'info, NttManagerPeer>,
+
     #[account(
         // NOTE: we don't replay protect VAAs. Instead, we replay protect
         // executing the messages themselves with the [`released`] flag.
@@ -138,29 +153,24 @@ pub struct ReceiveMessageAccount<'info> {
     pub message: Account<'info, VaaBody>,
 
     #[account(
-        init,
-        payer = payer,
-        space = 8 + ValidatedTransceiverMessage::<TransceiverMessageData<NativeTokenTransfer<Payload>>>::INIT_SPACE,
+        mut,
         seeds = [
             ValidatedTransceiverMessage::<TransceiverMessageData<NativeTokenTransfer<Payload>>>::SEED_PREFIX,
-            message.as_vaa_body_bytes().emitter_chain().to_be_bytes().as_ref(),
-            message.as_vaa_body_bytes().id(),
+            message.as_vaa_body_bytes().emitter_chain()?.to_be_bytes().as_ref(),
+            message.as_vaa_body_bytes().id()?,
         ],
         bump,
     )]
-    // NOTE: in order to handle multiple transceivers, we can just augment the
-    // inbox item transfer struct with a bitmap storing which transceivers have
-    // attested to the transfer. Then we only release it if there's quorum.
-    // We would need to maybe_init this account in that case.
-    pub transceiver_message:
-        Account<'info, ValidatedTransceiverMessage<NativeTokenTransfer<Payload>>>,
+    /// CHECK: see [`ReceiveMessageInstructionData::transceiver_message`].
+    pub transceiver_message: UncheckedAccount<'info>,
 
     /// CHECK: Guardian set used for signature verification by shim.
     /// Derivation is checked by the shim.
     pub guardian_set: UncheckedAccount<'info>,
 
     /// CHECK: Stored guardian signatures to be verified by shim.
-    /// Ownership ownership and discriminator is checked by the shim.
+    /// Ownership and discriminator are checked in [`verify_vaa_body`] before
+    /// invoking the shim.
     pub guardian_signatures: UncheckedAccount<'info>,
 
     pub verify_vaa_shim: Program<'info, WormholeVerifyVaaShim>,
@@ -172,34 +182,148 @@ pub fn receive_message_account(
     ctx: Context<ReceiveMessageAccount>,
     guardian_set_bump: u8,
     _seed: u64,
+    expected_digest: Option<[u8; 32]>,
 ) -> Result<()> {
-    let vaa_body = ctx.accounts.message.as_vaa_body_bytes();
-    // verify the hash against the signatures
+    let accs = ctx.accounts;
+    verify_and_store_message(
+        &accs.config,
+        &accs.payer,
+        &accs.system_program,
+        &accs.verify_vaa_shim,
+        &accs.guardian_set,
+        &accs.guardian_signatures,
+        &accs.transceiver_message,
+        ctx.bumps.transceiver_message,
+        guardian_set_bump,
+        &accs.message.as_vaa_body_bytes(),
+        expected_digest,
+    )
+}
+
+/// Verifies the guardian signatures over the VAA body, and writes the parsed
+/// message into [`ValidatedTransceiverMessage`].
+/// Both receive paths go through here so that a given VAA body always results
+/// in the same account contents, regardless of how it was delivered.
+/// The receive hook is then notified about newly received messages, if one is
+/// configured (see [`example_native_token_transfers::receive_hook`]).
+/// If `expected_digest` is provided, the VAA body must hash to it, which lets
+/// relayers make sure that they are verifying the message they meant to.
+fn verify_and_store_message<'info>(
+    config: &Config,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    verify_vaa_shim: &Program<'info, WormholeVerifyVaaShim>,
+    guardian_set: &UncheckedAccount<'info>,
+    guardian_signatures: &UncheckedAccount<'info>,
+    transceiver_message: &UncheckedAccount<'info>,
+    transceiver_message_bump: u8,
+    guardian_set_bump: u8,
+    vaa_body: &VaaBodyBytes,
+    expected_digest: Option<[u8; 32]>,
+) -> Result<()> {
+    // the message has already been received from this transceiver
+    if *transceiver_message.owner == crate::ID
+        && ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::is_initialized(
+            transceiver_message,
+        )?
+    {
+        return Ok(());
+    }
+
+    config.check_vaa_age(vaa_body.timestamp()?)?;
+
+    verify_vaa_body(
+        verify_vaa_shim,
+        guardian_set,
+        guardian_signatures,
+        guardian_set_bump,
+        vaa_body,
+        expected_digest,
+    )?;
+
+    create_transceiver_message_account(
+        payer,
+        transceiver_message,
+        system_program,
+        TRANSCEIVER_MESSAGE_SPACE,
+        &crate::ID,
+        &[
+            ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::SEED_PREFIX,
+            vaa_body.emitter_chain()?.to_be_bytes().as_ref(),
+            vaa_body.id()?,
+            &[transceiver_message_bump],
+        ],
+    )?;
+
+    // update transceiver_message
+    let validated_message = vaa_body
+        .validated_transceiver_message::<WormholeTransceiver, NativeTokenTransfer<Payload>>(
+            transceiver_message_bump,
+        )?;
+    validated_message.try_serialize(&mut &mut transceiver_message.try_borrow_mut_data()?[..])?;
+
+    notify_receive_hook(
+        config,
+        transceiver_message.key(),
+        ReceiveHookArgs::new(validated_message.from_chain, &validated_message.message),
+    )
+}
+
+/// Checks that `guardian_signatures` is a signatures account created by the
+/// verify VAA shim, so that unrelated accounts are rejected with a descriptive
+/// error before spending compute on the verification CPI.
+///
+/// NOTE: the signatures may have been posted over several `post_signatures`
+/// calls (the shim appends to the account), so nothing is assumed about how
+/// many there are. The shim checks for a quorum when verifying.
+fn check_guardian_signatures(guardian_signatures: &UncheckedAccount) -> Result<()> {
+    require_keys_eq!(
+        *guardian_signatures.owner,
+        WormholeVerifyVaaShim::id(),
+        NTTError::InvalidGuardianSignaturesAccount
+    );
+
+    let discriminator = &hash::hash(b"account:GuardianSignatures").to_bytes()[..8];
+    let data = guardian_signatures.try_borrow_data()?;
+    require!(
+        data.starts_with(discriminator),
+        NTTError::InvalidGuardianSignaturesAccount
+    );
+
+    Ok(())
+}
+
+/// Verifies the guardian signatures over the VAA body via the verify VAA shim.
+/// Fails with [`NTTError::DigestMismatch`] if the body doesn't hash to
+/// `expected_digest` (when provided).
+pub(crate) fn verify_vaa_body<'info>(
+    verify_vaa_shim: &Program<'info, WormholeVerifyVaaShim>,
+    guardian_set: &UncheckedAccount<'info>,
+    guardian_signatures: &UncheckedAccount<'info>,
+    guardian_set_bump: u8,
+    vaa_body: &VaaBodyBytes,
+    expected_digest: Option<[u8; 32]>,
+) -> Result<()> {
+    check_guardian_signatures(guardian_signatures)?;
+
     let digest = digest(vaa_body.span)?;
+    if let Some(expected_digest) = expected_digest {
+        require!(
+            digest.secp256k_hash == expected_digest,
+            NTTError::DigestMismatch
+        );
+    }
+
+    // verify the hash against the signatures
     wormhole_verify_vaa_shim_interface::cpi::verify_hash(
         CpiContext::new(
-            ctx.accounts.verify_vaa_shim.to_account_info(),
+            verify_vaa_shim.to_account_info(),
             wormhole_verify_vaa_shim_interface::cpi::accounts::VerifyHash {
-                guardian_set: ctx.accounts.guardian_set.to_account_info(),
-                guardian_signatures: ctx.accounts.guardian_signatures.to_account_info(),
+                guardian_set: guardian_set.to_account_info(),
+                guardian_signatures: guardian_signatures.to_account_info(),
             },
         ),
         guardian_set_bump,
         digest.secp256k_hash,
-    )?;
-
-    // update transceiver_message
-    let message = vaa_body
-        .transceiver_message_data::<WormholeTransceiver, NativeTokenTransfer<Payload>>()?
-        .clone();
-    ctx.accounts
-        .transceiver_message
-        .set_inner(ValidatedTransceiverMessage {
-            from_chain: ChainId {
-                id: vaa_body.emitter_chain(),
-            },
-            message,
-        });
-
-    Ok(())
+    )
 }
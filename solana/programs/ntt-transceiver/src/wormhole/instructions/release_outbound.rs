@@ -7,30 +7,40 @@ use example_native_token_transfers::{
     program::ExampleNativeTokenTransfers,
     queue::outbox::OutboxItem,
     registered_transceiver::RegisteredTransceiver,
+    retry_after::retry_after,
     transfer::Payload,
 };
 use ntt_messages::{
-    ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage, transceiver::TransceiverMessage,
-    transceivers::wormhole::WormholeTransceiver,
+    chain_id::ChainId, ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage,
+    transceiver::TransceiverMessage, transceivers::wormhole::WormholeTransceiver,
 };
+use wormhole_post_message_shim_interface::Finality;
 
 #[derive(Accounts)]
 pub struct ReleaseOutbound<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    pub config: NotPausedConfig<'info>,
+    pub config: NotPausedOutboundConfig<'info>,
 
     #[account(
         mut,
-        constraint = !outbox_item.released.get(transceiver.id)? @ NTTError::MessageAlreadySent,
+        constraint = OutboxItem::is_current_layout(&outbox_item.to_account_info()) @ NTTError::QueueItemNotMigrated,
+        constraint = !outbox_item.load()?.released.get(transceiver.id)? @ NTTError::MessageAlreadySent,
     )]
-    pub outbox_item: Account<'info, OutboxItem>,
+    pub outbox_item: AccountLoader<'info, OutboxItem>,
+
+    #[account(mut)]
+    /// CHECK: the manager checks that this is the peer of the outbox item's
+    /// recipient chain
+    pub peer: UncheckedAccount<'info>,
 
     #[account(
-        constraint = transceiver.transceiver_address == crate::ID,
+        constraint = transceiver.transceiver_address == crate::ID @ NTTError::InvalidTransceiverProgram,
         constraint = config.enabled_transceivers.get(transceiver.id)? @ NTTError::DisabledTransceiver
     )]
+    /// The registration of this program. Its id is the bit marked in the
+    /// outbox item's `released` bitmap.
     pub transceiver: Account<'info, RegisteredTransceiver>,
 
     #[account(mut, seeds = [&emitter.key.to_bytes()], bump, seeds::program = wormhole_svm_definitions::solana::POST_MESSAGE_SHIM_PROGRAM_ID)]
@@ -45,6 +55,9 @@ pub struct ReleaseOutbound<'info> {
     /// CHECK: wormhole uses this as the emitter address
     pub emitter: UncheckedAccount<'info>,
 
+    #[account(
+        constraint = wormhole.fee_collector.key() == config.fee_collector @ NTTError::InvalidFeeCollector,
+    )]
     pub wormhole: WormholeAccounts<'info>,
 
     // NOTE: we put `manager` and `outbox_item_signer` at the end so that the generated
@@ -66,10 +79,12 @@ impl<'info> ReleaseOutbound<'info> {
                 self.manager.to_account_info(),
                 example_native_token_transfers::cpi::accounts::MarkOutboxItemAsReleased {
                     signer: self.outbox_item_signer.to_account_info(),
-                    config: example_native_token_transfers::cpi::accounts::NotPausedConfig {
-                        config: self.config.config.to_account_info(),
-                    },
+                    config:
+                        example_native_token_transfers::cpi::accounts::NotPausedOutboundConfig {
+                            config: self.config.config.to_account_info(),
+                        },
                     outbox_item: self.outbox_item.to_account_info(),
+                    peer: self.peer.to_account_info(),
                     transceiver: self.transceiver.to_account_info(),
                 },
                 // signer seeds
@@ -85,46 +100,102 @@ pub struct ReleaseOutboundArgs {
     pub revert_on_delay: bool,
 }
 
+/// Settings of [`release_outbound_with_options`] that [`release_outbound`]
+/// leaves at their defaults. They're not part of [`ReleaseOutboundArgs`], so
+/// that its encoding stays the same for existing clients.
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct ReleaseOutboundOptions {
+    /// The most the payer is willing to pay for the wormhole message fee.
+    /// Guards against the core bridge fee being raised between building and
+    /// landing the transaction. Unlimited when unset.
+    pub max_wormhole_fee: Option<u64>,
+    /// VAA-encoded consistency level to post the message with. Finalized
+    /// when unset. Has to be one of the
+    /// [`Config::allowed_consistency_levels`].
+    pub consistency_level: Option<u8>,
+}
+
 pub fn release_outbound(ctx: Context<ReleaseOutbound>, args: ReleaseOutboundArgs) -> Result<()> {
+    release_outbound_with_options(ctx, args, ReleaseOutboundOptions::default())
+}
+
+pub fn release_outbound_with_options(
+    ctx: Context<ReleaseOutbound>,
+    args: ReleaseOutboundArgs,
+    options: ReleaseOutboundOptions,
+) -> Result<()> {
     let accs = ctx.accounts;
+
+    let consistency_level = options
+        .consistency_level
+        .unwrap_or(CONSISTENCY_LEVEL_FINALIZED);
+    accs.config.check_consistency_level(consistency_level)?;
+    let finality = match consistency_level {
+        CONSISTENCY_LEVEL_FINALIZED => Finality::Finalized,
+        CONSISTENCY_LEVEL_CONFIRMED => Finality::Confirmed,
+        _ => return Err(NTTError::UnsupportedConsistencyLevel.into()),
+    };
+
+    if let Some(max_wormhole_fee) = options.max_wormhole_fee {
+        let wormhole_fee = accs.wormhole.bridge.fee();
+        if wormhole_fee > max_wormhole_fee {
+            msg!(
+                "Wormhole fee {} exceeds the maximum of {}",
+                wormhole_fee,
+                max_wormhole_fee
+            );
+            return Err(NTTError::WormholeFeeTooHigh.into());
+        }
+    }
+
     let released = accs.mark_outbox_item_as_released(ctx.bumps.outbox_item_signer)?;
 
+    let outbox_item = accs.outbox_item.load()?;
+
     if !released {
         if args.revert_on_delay {
-            return Err(NTTError::CantReleaseYet.into());
+            // overwrites the return data of the manager CPI above
+            return Err(retry_after(
+                outbox_item.release_timestamp,
+                NTTError::CantReleaseYet,
+            ));
         } else {
             return Ok(());
         }
     }
 
-    accs.outbox_item.reload()?;
-    assert!(accs.outbox_item.released.get(accs.transceiver.id)?);
+    assert!(outbox_item.released.get(accs.transceiver.id)?);
 
     let message: TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>> =
         TransceiverMessage::new(
             // TODO: should we just put the ntt id here statically?
             accs.outbox_item.to_account_info().owner.to_bytes(),
-            accs.outbox_item.recipient_ntt_manager,
+            outbox_item.recipient_ntt_manager,
             NttManagerMessage {
                 id: accs.outbox_item.key().to_bytes(),
-                sender: accs.outbox_item.sender.to_bytes(),
+                sender: outbox_item.sender.to_bytes(),
                 payload: NativeTokenTransfer {
-                    amount: accs.outbox_item.amount,
+                    version: NativeTokenTransfer::<Payload>::CURRENT_VERSION,
+                    amount: outbox_item.trimmed_amount(),
                     source_token: accs.config.mint.to_bytes(),
-                    to: accs.outbox_item.recipient_address,
-                    to_chain: accs.outbox_item.recipient_chain,
+                    to: outbox_item.recipient_address,
+                    to_chain: ChainId {
+                        id: outbox_item.recipient_chain,
+                    },
                     additional_payload: Payload {},
                 },
             },
             vec![],
         );
+    drop(outbox_item);
 
     post_message(
-        &accs.wormhole,
+        &mut accs.wormhole,
         accs.payer.to_account_info(),
         accs.wormhole_message.to_account_info(),
         accs.emitter.to_account_info(),
-        ctx.bumps.emitter,
+        &[b"emitter", &[ctx.bumps.emitter]],
+        finality,
         &message,
     )?;
 
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use example_native_token_transfers::{clock::current_time, error::NTTError};
 
 use crate::vaa_body::VaaBody;
 
@@ -11,7 +12,7 @@ pub struct PostUnverifiedMessageAccount<'info> {
     #[account(
     init_if_needed,
     payer = payer,
-    space = 8 + 4 + args.message_size as usize,
+    space = VaaBody::space(args.message_size),
     seeds = [
         VaaBody::SEED_PREFIX,
         &payer.key.to_bytes(),
@@ -47,6 +48,9 @@ pub fn post_unverified_message_account(
     }
 
     let vaa_body = &mut ctx.accounts.message;
+    if vaa_body.created_at == 0 {
+        vaa_body.created_at = current_time()?;
+    }
     if vaa_body.span.len() < end {
         vaa_body.span.resize(end, 0);
     }
@@ -83,3 +87,37 @@ pub fn close_unverified_message_account(
 ) -> Result<()> {
     Ok(())
 }
+
+/// Closes a staged [`VaaBody`] that has been left around for longer than
+/// [`VaaBody::TTL`], e.g. by a relayer that crashed before receiving it.
+/// Anyone can call this, but the rent goes back to the original `payer`, so
+/// there's nothing to gain but a cleaner chain state. The payer can still
+/// close it early with [`close_unverified_message_account`].
+///
+/// NOTE: guardian signatures accounts posted alongside are owned by the
+/// verification shim, and can only be closed there by their refund recipient.
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct CleanupExpiredVaaBody<'info> {
+    #[account(mut)]
+    /// CHECK: the original payer, as the seeds of `message` tie it to them.
+    /// Only receives the rent.
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            VaaBody::SEED_PREFIX,
+            &payer.key.to_bytes(),
+            seed.to_be_bytes().as_ref(),
+        ],
+        bump,
+        close = payer,
+        constraint = message.is_expired(current_time()?) @ NTTError::VaaBodyNotExpired,
+    )]
+    pub message: Account<'info, VaaBody>,
+}
+
+pub fn cleanup_expired_vaa_body(_ctx: Context<CleanupExpiredVaaBody>, _seed: u64) -> Result<()> {
+    Ok(())
+}
@@ -1,4 +1,6 @@
 pub mod accounts;
+pub mod address_encoding;
 pub mod instructions;
 
+pub use address_encoding::*;
 pub use instructions::*;
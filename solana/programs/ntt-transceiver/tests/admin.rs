@@ -1,21 +1,38 @@
 #![cfg(feature = "test-sbf")]
-#![feature(type_changing_struct_update)]
 
 use anchor_lang::{system_program::System, Id};
-use example_native_token_transfers::error::NTTError;
-use ntt_messages::mode::Mode;
+use example_native_token_transfers::{error::NTTError, instructions::InitializeArgs};
+use ntt_messages::{chain_id::ChainId, mode::Mode};
 use solana_program_test::*;
-use solana_sdk::{instruction::InstructionError, signer::Signer, transaction::TransactionError};
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    transaction::TransactionError,
+};
 use test_utils::{
-    common::submit::Submittable,
-    helpers::{assert_threshold, assert_transceiver_id, setup},
+    common::{
+        fixtures::{ANOTHER_CHAIN, OTHER_CHAIN, OTHER_TRANSCEIVER, OUTBOUND_LIMIT, THIS_CHAIN},
+        submit::Submittable,
+    },
+    helpers::{
+        assert_threshold, assert_transceiver_id, setup, setup_accounts, setup_programs,
+        transceiver_peer_index,
+    },
     sdk::{
         accounts::good_ntt,
-        instructions::admin::{
-            deregister_transceiver, register_transceiver, set_threshold, DeregisterTransceiver,
-            RegisterTransceiver, SetThreshold,
+        instructions::{
+            admin::{
+                deregister_transceiver, register_transceiver, set_threshold, DeregisterTransceiver,
+                RegisterTransceiver, SetThreshold,
+            },
+            initialize::{initialize, Initialize},
+        },
+        transceivers::{
+            accounts::{good_ntt_transceiver, NTTTransceiverAccounts},
+            instructions::admin::{
+                delete_transceiver_peer, set_transceiver_peer, DeleteTransceiverPeer,
+                SetTransceiverPeer, SetTransceiverPeerArgs,
+            },
         },
-        transceivers::accounts::{good_ntt_transceiver, NTTTransceiverAccounts},
     },
 };
 use wormhole_svm_definitions::solana::{POST_MESSAGE_SHIM_PROGRAM_ID, VERIFY_VAA_SHIM_PROGRAM_ID};
@@ -206,3 +223,161 @@ async fn test_deregister_last_enabled_transceiver() {
         )
     );
 }
+
+#[tokio::test]
+async fn test_set_transceiver_peer_requires_registration() {
+    let program_owner = Keypair::new();
+    let program_test = setup_programs(program_owner.pubkey()).await.unwrap();
+    let mut ctx = program_test.start_with_context().await;
+    let test_data = setup_accounts(&mut ctx, program_owner).await;
+
+    initialize(
+        &good_ntt,
+        Initialize {
+            payer: ctx.payer.pubkey(),
+            deployer: test_data.program_owner.pubkey(),
+            mint: test_data.mint,
+            multisig_token_authority: None,
+        },
+        InitializeArgs {
+            chain_id: THIS_CHAIN,
+            limit: OUTBOUND_LIMIT,
+            mode: Mode::Locking,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let set_peer = |chain_id| {
+        set_transceiver_peer(
+            &good_ntt,
+            &good_ntt_transceiver,
+            SetTransceiverPeer {
+                payer: ctx.payer.pubkey(),
+                owner: test_data.program_owner.pubkey(),
+            },
+            SetTransceiverPeerArgs {
+                chain_id: ChainId { id: chain_id },
+                address: OTHER_TRANSCEIVER,
+            },
+        )
+    };
+    let other_chain_peer = set_peer(OTHER_CHAIN);
+    let another_chain_peer = set_peer(ANOTHER_CHAIN);
+
+    // the transceiver hasn't been registered with the manager yet
+    let err = other_chain_peer
+        .clone()
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TransceiverNotRegistered.into())
+        )
+    );
+
+    register_transceiver(
+        &good_ntt,
+        RegisterTransceiver {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            transceiver: good_ntt_transceiver.program(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    other_chain_peer
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    // once disabled, no more peers can be set
+    register_transceiver(
+        &good_ntt,
+        RegisterTransceiver {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            transceiver: wormhole_anchor_sdk::wormhole::program::Wormhole::id(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    deregister_transceiver(
+        &good_ntt,
+        DeregisterTransceiver {
+            owner: test_data.program_owner.pubkey(),
+            transceiver: good_ntt_transceiver.program(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let err = another_chain_peer
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TransceiverNotRegistered.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_transceiver_peer_index() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    assert_eq!(
+        transceiver_peer_index(&good_ntt_transceiver, &mut ctx).await,
+        vec![OTHER_CHAIN]
+    );
+
+    set_transceiver_peer(
+        &good_ntt,
+        &good_ntt_transceiver,
+        SetTransceiverPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetTransceiverPeerArgs {
+            chain_id: ChainId { id: ANOTHER_CHAIN },
+            address: OTHER_TRANSCEIVER,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    delete_transceiver_peer(
+        &good_ntt,
+        &good_ntt_transceiver,
+        DeleteTransceiverPeer {
+            owner: test_data.program_owner.pubkey(),
+        },
+        OTHER_CHAIN,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        transceiver_peer_index(&good_ntt_transceiver, &mut ctx).await,
+        vec![ANOTHER_CHAIN]
+    );
+    assert!(ctx
+        .banks_client
+        .get_account(good_ntt_transceiver.transceiver_peer(OTHER_CHAIN))
+        .await
+        .unwrap()
+        .is_none());
+}
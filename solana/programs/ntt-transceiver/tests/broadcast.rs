@@ -1,32 +1,56 @@
 #![cfg(feature = "test-sbf")]
-#![feature(type_changing_struct_update)]
 
-use anchor_lang::AnchorDeserialize;
+use anchor_lang::{error::ErrorCode, AnchorDeserialize, AnchorSerialize};
+use example_native_token_transfers::error::NTTError;
 use ntt_messages::{
     chain_id::ChainId,
     mode::Mode,
-    transceivers::wormhole::{WormholeTransceiverInfo, WormholeTransceiverRegistration},
+    transceivers::wormhole::{
+        WormholeTransceiver, WormholeTransceiverHeartbeat, WormholeTransceiverInfo,
+        WormholeTransceiverRegistration,
+    },
+};
+use ntt_transceiver::{
+    broadcast_suppression::WormholePeerBroadcast, fee_stats::WormholeFeeStats,
+    heartbeat::WormholeHeartbeat, wormhole::AddressEncoding,
 };
+use solana_program::{clock::Clock, instruction::InstructionError};
 use solana_program_test::*;
-use solana_sdk::signer::Signer;
+use solana_sdk::{signer::Signer, transaction::TransactionError};
 use test_utils::{
     common::{
-        fixtures::{OTHER_CHAIN, OTHER_TRANSCEIVER},
+        fixtures::{
+            ANOTHER_CHAIN, ANOTHER_TRANSCEIVER, OTHER_CHAIN, OTHER_TRANSCEIVER, UNREGISTERED_CHAIN,
+        },
+        query::GetAccountDataAnchor,
         submit::Submittable,
     },
-    helpers::{get_message_data, setup},
+    helpers::{
+        add_token_metadata, assert_retry_after, get_broadcast_payload, get_emitter_sequence, setup,
+        token_metadata_hash,
+    },
     sdk::{
         accounts::{good_ntt, NTTAccounts},
         transceivers::{
-            accounts::good_ntt_transceiver,
+            accounts::{good_ntt_transceiver, NTTTransceiverAccounts},
             instructions::{
-                broadcast_id::{broadcast_id, BroadcastId},
-                broadcast_peer::{broadcast_peer, BroadcastPeer},
+                admin::{
+                    register_peer_emitter, set_broadcast_suppression_window, set_transceiver_peer,
+                    RegisterPeerEmitter, SetBroadcastSuppressionWindow, SetTransceiverPeer,
+                    SetTransceiverPeerArgs,
+                },
+                broadcast_heartbeat::{broadcast_heartbeat, BroadcastHeartbeat},
+                broadcast_id::{broadcast_id, broadcast_id_with_peer_emitter, BroadcastId},
+                broadcast_peer::{
+                    broadcast_peer, broadcast_peer_with_address_encoding,
+                    broadcast_peer_with_peer_emitter, BroadcastPeer,
+                },
+                get_fee_stats::get_fee_stats,
             },
         },
     },
 };
-use wormhole_svm_definitions::{EncodeFinality, Finality::Finalized};
+use wormhole_anchor_sdk::wormhole::{BridgeConfig, BridgeData};
 
 #[tokio::test]
 async fn test_broadcast_peer() {
@@ -42,7 +66,7 @@ async fn test_broadcast_peer() {
     );
 
     // simulate to fetch data before submitting ix
-    let msg = get_message_data(
+    let msg = get_broadcast_payload(
         &good_ntt.wormhole(),
         &good_ntt_transceiver,
         &mut ctx,
@@ -50,9 +74,16 @@ async fn test_broadcast_peer() {
     )
     .await;
     ix.submit(&mut ctx).await.unwrap();
+    assert_eq!(
+        get_emitter_sequence(
+            &good_ntt.wormhole(),
+            &mut ctx,
+            &good_ntt_transceiver.emitter()
+        )
+        .await,
+        msg.sequence + 1
+    );
 
-    assert_eq!(msg.nonce, 0); // hardcoded
-    assert_eq!(msg.consistency_level, Finalized.encode()); // hardcoded
     assert_eq!(
         WormholeTransceiverRegistration::deserialize(&mut &msg.payload[..]).unwrap(),
         WormholeTransceiverRegistration {
@@ -62,6 +93,192 @@ async fn test_broadcast_peer() {
     );
 }
 
+#[tokio::test]
+async fn test_broadcast_peer_address_encoding() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // a 20 byte address, in the universal (left-padded) format
+    let mut evm_transceiver = [0u8; 32];
+    evm_transceiver[12..].copy_from_slice(&[0xab; 20]);
+
+    set_transceiver_peer(
+        &good_ntt,
+        &good_ntt_transceiver,
+        SetTransceiverPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetTransceiverPeerArgs {
+            chain_id: ChainId { id: ANOTHER_CHAIN },
+            address: evm_transceiver,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let ix = broadcast_peer_with_address_encoding(
+        &good_ntt,
+        &good_ntt_transceiver,
+        BroadcastPeer {
+            payer: ctx.payer.pubkey(),
+            chain_id: ANOTHER_CHAIN,
+        },
+        AddressEncoding::RightPadded { len: 20 },
+    );
+
+    let msg = get_broadcast_payload(
+        &good_ntt.wormhole(),
+        &good_ntt_transceiver,
+        &mut ctx,
+        ix.clone(),
+    )
+    .await;
+    ix.submit(&mut ctx).await.unwrap();
+    assert_eq!(
+        get_emitter_sequence(
+            &good_ntt.wormhole(),
+            &mut ctx,
+            &good_ntt_transceiver.emitter()
+        )
+        .await,
+        msg.sequence + 1
+    );
+
+    let mut expected_address = [0u8; 32];
+    expected_address[..20].copy_from_slice(&[0xab; 20]);
+    assert_eq!(
+        WormholeTransceiverRegistration::deserialize(&mut &msg.payload[..]).unwrap(),
+        WormholeTransceiverRegistration {
+            chain_id: ChainId { id: ANOTHER_CHAIN },
+            transceiver_address: expected_address
+        }
+    );
+
+    // the address doesn't fit in 20 bytes
+    let err = broadcast_peer_with_address_encoding(
+        &good_ntt,
+        &good_ntt_transceiver,
+        BroadcastPeer {
+            payer: ctx.payer.pubkey(),
+            chain_id: OTHER_CHAIN,
+        },
+        AddressEncoding::RightPadded { len: 20 },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidAddressEncoding.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_broadcast_peer_emitter() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_transceiver_peer(
+        &good_ntt,
+        &good_ntt_transceiver,
+        SetTransceiverPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetTransceiverPeerArgs {
+            chain_id: ChainId { id: ANOTHER_CHAIN },
+            address: ANOTHER_TRANSCEIVER,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    for chain_id in [OTHER_CHAIN, ANOTHER_CHAIN] {
+        register_peer_emitter(
+            &good_ntt,
+            &good_ntt_transceiver,
+            RegisterPeerEmitter {
+                payer: ctx.payer.pubkey(),
+                owner: test_data.program_owner.pubkey(),
+            },
+            chain_id,
+        )
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+    }
+
+    let default_sequence = get_emitter_sequence(
+        &good_ntt.wormhole(),
+        &mut ctx,
+        &good_ntt_transceiver.emitter(),
+    )
+    .await;
+
+    // each peer is broadcast from its own emitter
+    for chain_id in [OTHER_CHAIN, ANOTHER_CHAIN] {
+        broadcast_peer_with_peer_emitter(
+            &good_ntt,
+            &good_ntt_transceiver,
+            BroadcastPeer {
+                payer: ctx.payer.pubkey(),
+                chain_id,
+            },
+            AddressEncoding::Universal,
+            Some(chain_id),
+        )
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    }
+
+    for chain_id in [OTHER_CHAIN, ANOTHER_CHAIN] {
+        assert_eq!(
+            get_emitter_sequence(
+                &good_ntt.wormhole(),
+                &mut ctx,
+                &good_ntt_transceiver.emitter_for_peer(chain_id)
+            )
+            .await,
+            1
+        );
+    }
+    assert_eq!(
+        get_emitter_sequence(
+            &good_ntt.wormhole(),
+            &mut ctx,
+            &good_ntt_transceiver.emitter()
+        )
+        .await,
+        default_sequence
+    );
+
+    // emitters have to be registered
+    let err = broadcast_id_with_peer_emitter(
+        &good_ntt,
+        &good_ntt_transceiver,
+        BroadcastId {
+            payer: ctx.payer.pubkey(),
+            mint: test_data.mint,
+        },
+        Some(UNREGISTERED_CHAIN),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::AccountNotInitialized.into())
+        )
+    );
+}
+
 #[tokio::test]
 async fn test_broadcast_id() {
     let (mut ctx, test_data) = setup(Mode::Locking).await;
@@ -76,7 +293,7 @@ async fn test_broadcast_id() {
     );
 
     // simulate to fetch data before submitting ix
-    let msg = get_message_data(
+    let msg = get_broadcast_payload(
         &good_ntt.wormhole(),
         &good_ntt_transceiver,
         &mut ctx,
@@ -84,9 +301,16 @@ async fn test_broadcast_id() {
     )
     .await;
     ix.submit(&mut ctx).await.unwrap();
+    assert_eq!(
+        get_emitter_sequence(
+            &good_ntt.wormhole(),
+            &mut ctx,
+            &good_ntt_transceiver.emitter()
+        )
+        .await,
+        msg.sequence + 1
+    );
 
-    assert_eq!(msg.nonce, 0); // hardcoded
-    assert_eq!(msg.consistency_level, Finalized.encode()); // hardcoded
     assert_eq!(
         WormholeTransceiverInfo::deserialize(&mut &msg.payload[..]).unwrap(),
         WormholeTransceiverInfo {
@@ -94,6 +318,284 @@ async fn test_broadcast_id() {
             manager_mode: Mode::Locking,
             token_address: test_data.mint.to_bytes(),
             token_decimals: 9,
+            // the test mint has no metadata
+            token_metadata_hash: Some([0; 32]),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_broadcast_id_with_metadata() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    add_token_metadata(
+        &mut ctx,
+        &test_data.mint,
+        "Test Token",
+        "TEST",
+        "https://example.com/test.json",
+    )
+    .await;
+
+    let ix = broadcast_id(
+        &good_ntt,
+        &good_ntt_transceiver,
+        BroadcastId {
+            payer: ctx.payer.pubkey(),
+            mint: test_data.mint,
+        },
+    );
+
+    let msg =
+        get_broadcast_payload(&good_ntt.wormhole(), &good_ntt_transceiver, &mut ctx, ix).await;
+
+    let info = WormholeTransceiverInfo::deserialize(&mut &msg.payload[..]).unwrap();
+    assert_eq!(
+        info.token_metadata_hash,
+        Some(token_metadata_hash(
+            "Test Token",
+            "TEST",
+            "https://example.com/test.json"
+        ))
+    );
+}
+
+#[tokio::test]
+async fn test_broadcast_heartbeat() {
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
+
+    let ix = broadcast_heartbeat(
+        &good_ntt,
+        &good_ntt_transceiver,
+        BroadcastHeartbeat {
+            payer: ctx.payer.pubkey(),
+        },
+    );
+
+    // simulate to fetch data before submitting ix
+    let msg = get_broadcast_payload(
+        &good_ntt.wormhole(),
+        &good_ntt_transceiver,
+        &mut ctx,
+        ix.clone(),
+    )
+    .await;
+    ix.submit(&mut ctx).await.unwrap();
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+
+    assert_eq!(msg.payload[..4], WormholeTransceiver::HEARTBEAT_PREFIX);
+    assert_eq!(
+        WormholeTransceiverHeartbeat::deserialize(&mut &msg.payload[..]).unwrap(),
+        WormholeTransceiverHeartbeat {
+            manager_address: good_ntt.program().to_bytes(),
+            timestamp: u64::try_from(clock.unix_timestamp).unwrap(),
         }
     );
+
+    let heartbeat: WormholeHeartbeat = ctx
+        .get_account_data_anchor(good_ntt_transceiver.heartbeat())
+        .await;
+    assert_eq!(heartbeat.last_heartbeat, clock.unix_timestamp);
+
+    // a second heartbeat right away is rate limited
+    let out = broadcast_heartbeat(
+        &good_ntt,
+        &good_ntt_transceiver,
+        BroadcastHeartbeat {
+            payer: ctx.payer.pubkey(),
+        },
+    )
+    .simulate(&mut ctx)
+    .await
+    .unwrap();
+    assert_eq!(
+        out.result.clone().unwrap().unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::HeartbeatTooSoon.into())
+        )
+    );
+    assert_retry_after(
+        &mut ctx,
+        &out,
+        clock.unix_timestamp + WormholeHeartbeat::MIN_INTERVAL,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_broadcast_peer_suppression_window() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let window = 60;
+    set_broadcast_suppression_window(
+        &good_ntt,
+        &good_ntt_transceiver,
+        SetBroadcastSuppressionWindow {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        window,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let ix = broadcast_peer(
+        &good_ntt,
+        &good_ntt_transceiver,
+        BroadcastPeer {
+            payer: ctx.payer.pubkey(),
+            chain_id: OTHER_CHAIN,
+        },
+    );
+    ix.clone().submit(&mut ctx).await.unwrap();
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let peer_broadcast: WormholePeerBroadcast = ctx
+        .get_account_data_anchor(good_ntt_transceiver.peer_broadcast(OTHER_CHAIN))
+        .await;
+    assert_eq!(peer_broadcast.last_broadcast, clock.unix_timestamp);
+
+    // the same broadcast within the window is suppressed
+    let out = ix.clone().simulate(&mut ctx).await.unwrap();
+    assert_eq!(
+        out.result.clone().unwrap().unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::BroadcastSuppressed.into())
+        )
+    );
+    assert_retry_after(&mut ctx, &out, clock.unix_timestamp + i64::from(window)).await;
+
+    // and allowed again once it has passed
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: clock.unix_timestamp + i64::from(window),
+        ..clock
+    });
+    ix.submit(&mut ctx).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_wormhole_fee_stats() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let fee_stats: WormholeFeeStats = ctx
+        .get_account_data_anchor(good_ntt_transceiver.fee_stats())
+        .await;
+    assert_eq!(fee_stats.total_wormhole_fees_paid, 0);
+    assert_eq!(fee_stats.messages_posted, 0);
+
+    let bridge: BridgeData = ctx
+        .get_account_data_anchor(good_ntt.wormhole().bridge())
+        .await;
+    let fee = bridge.fee();
+    assert!(fee > 0);
+
+    for _ in 0..3 {
+        broadcast_id(
+            &good_ntt,
+            &good_ntt_transceiver,
+            BroadcastId {
+                payer: ctx.payer.pubkey(),
+                mint: test_data.mint,
+            },
+        )
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    }
+
+    let fee_stats: WormholeFeeStats = ctx
+        .get_account_data_anchor(good_ntt_transceiver.fee_stats())
+        .await;
+    assert_eq!(fee_stats.total_wormhole_fees_paid, 3 * fee);
+    assert_eq!(fee_stats.messages_posted, 3);
+
+    // bump the fee in the core bridge config
+    let new_fee = fee * 2;
+    let mut bridge_account = ctx
+        .banks_client
+        .get_account(good_ntt.wormhole().bridge())
+        .await
+        .unwrap()
+        .unwrap();
+    bridge_account.data = BridgeData {
+        config: BridgeConfig {
+            fee: new_fee,
+            ..bridge.config
+        },
+        ..bridge
+    }
+    .try_to_vec()
+    .unwrap();
+    ctx.set_account(&good_ntt.wormhole().bridge(), &bridge_account.into());
+
+    broadcast_peer(
+        &good_ntt,
+        &good_ntt_transceiver,
+        BroadcastPeer {
+            payer: ctx.payer.pubkey(),
+            chain_id: OTHER_CHAIN,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let fee_stats: WormholeFeeStats = ctx
+        .get_account_data_anchor(good_ntt_transceiver.fee_stats())
+        .await;
+    assert_eq!(fee_stats.total_wormhole_fees_paid, 3 * fee + new_fee);
+    assert_eq!(fee_stats.messages_posted, 4);
+    assert_eq!(
+        get_emitter_sequence(
+            &good_ntt.wormhole(),
+            &mut ctx,
+            &good_ntt_transceiver.emitter()
+        )
+        .await,
+        4
+    );
+
+    let out = get_fee_stats(&good_ntt_transceiver)
+        .simulate(&mut ctx)
+        .await
+        .unwrap();
+    assert!(out.result.unwrap().is_ok());
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    assert_eq!(
+        WormholeFeeStats::deserialize(&mut &return_data[..]).unwrap(),
+        fee_stats
+    );
+}
+
+#[tokio::test]
+async fn test_broadcast_without_fee_stats() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // deployments that haven't registered a peer since the fee stats were
+    // added don't have the account, and pass none
+    let mut ix = broadcast_id(
+        &good_ntt,
+        &good_ntt_transceiver,
+        BroadcastId {
+            payer: ctx.payer.pubkey(),
+            mint: test_data.mint,
+        },
+    );
+    let fee_stats = ix
+        .accounts
+        .iter_mut()
+        .find(|meta| meta.pubkey == good_ntt_transceiver.fee_stats())
+        .unwrap();
+    fee_stats.pubkey = good_ntt_transceiver.program();
+    fee_stats.is_writable = false;
+    ix.submit(&mut ctx).await.unwrap();
+
+    let fee_stats: WormholeFeeStats = ctx
+        .get_account_data_anchor(good_ntt_transceiver.fee_stats())
+        .await;
+    assert_eq!(fee_stats.messages_posted, 0);
 }
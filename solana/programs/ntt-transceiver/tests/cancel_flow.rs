@@ -1,5 +1,4 @@
 #![cfg(feature = "test-sbf")]
-#![feature(type_changing_struct_update)]
 
 use example_native_token_transfers::instructions::RedeemArgs;
 use ntt_messages::mode::Mode;
@@ -83,7 +82,6 @@ async fn test_cancel() {
             &good_ntt,
             &good_ntt_transceiver,
             &mut ctx,
-            &test_data,
             OTHER_CHAIN,
             msg0.ntt_manager_payload.clone(),
         ),
@@ -165,7 +163,6 @@ async fn test_cancel() {
             &good_ntt,
             &good_ntt_transceiver,
             &mut ctx,
-            &test_data,
             OTHER_CHAIN,
             msg1.ntt_manager_payload.clone(),
         ),
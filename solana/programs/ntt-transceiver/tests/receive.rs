@@ -1,44 +1,80 @@
 #![cfg(feature = "test-sbf")]
-#![feature(type_changing_struct_update)]
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount};
 use example_native_token_transfers::{
+    bitmap::Bitmap,
     error::NTTError,
     instructions::{RedeemArgs, ReleaseInboundArgs},
+    queue::inbox::InboxItem,
+    receive_hook::{ReceiveHookArgs, ReceiveHookNotified},
+    transfer::Payload,
 };
-use ntt_messages::mode::Mode;
-use ntt_transceiver::vaa_body::VaaBodyData;
-use solana_program::instruction::InstructionError;
+use ntt_messages::{
+    chain_id::ChainId, mode::Mode, ntt::NativeTokenTransfer, transceiver::TransceiverMessage,
+    transceivers::wormhole::WormholeTransceiver,
+};
+use ntt_transceiver::{
+    messages::ValidatedTransceiverMessage,
+    vaa_body::{VaaBody, VaaBodyData},
+    wormhole::{instructions::TRANSCEIVER_MESSAGE_SPACE, DeliveryCostQuote},
+};
+use solana_program::instruction::{Instruction, InstructionError};
 use solana_program_test::*;
 use solana_sdk::{
-    pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::TransactionError,
+    message::Message, pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction,
+    transaction::TransactionError,
 };
 use spl_associated_token_account::get_associated_token_address_with_program_id;
 use test_utils::{
     common::{
-        fixtures::{ANOTHER_CHAIN, OTHER_CHAIN, OTHER_TRANSCEIVER},
+        fixtures::{TestData, ANOTHER_CHAIN, OTHER_CHAIN, OTHER_MANAGER, OTHER_TRANSCEIVER},
         query::GetAccountDataAnchor,
-        submit::Submittable,
+        submit::{ComputeUnitLimit, Submittable},
     },
     helpers::{
-        init_receive_message_accs, init_redeem_accs, make_transfer_message, post_vaa_helper, setup,
+        add_guardian_set, guardian_secret_keys, init_receive_message_accs, init_redeem_accs,
+        make_transfer_message, make_vaa, post_legacy_vaa_helper, post_vaa_helper,
+        post_vaa_helper_with_timestamp, receive_hook_notifications, register_legacy_transceiver,
+        setup, token_balance, vaa_body,
     },
     sdk::{
         accounts::{good_ntt, NTTAccounts},
         instructions::{
-            post_vaa::close_signatures,
-            redeem::redeem,
+            admin::{
+                set_max_vaa_age, set_paused, set_receive_hook, set_threshold, SetMaxVaaAge,
+                SetPaused, SetReceiveHook, SetThreshold,
+            },
+            post_vaa::{
+                close_signatures, post_signatures_batch, post_signatures_in_batches, sign_vaa,
+            },
+            redeem::{redeem, Redeem},
             release_inbound::{release_inbound_unlock, ReleaseInbound},
         },
         transceivers::{
-            accounts::good_ntt_transceiver,
+            accounts::{good_ntt_transceiver, NTTTransceiverAccounts},
             instructions::{
-                receive_message::{receive_message_account, receive_message_instruction_data},
+                pending_message::{
+                    promote_pending_message, verify_pending_message, PromotePendingMessage,
+                },
+                quote_delivery_cost::quote_delivery_cost,
+                receive_message::{
+                    receive_message_account, receive_message_instruction_data, ReceiveMessage,
+                },
                 unverified_message_account::{
+                    cleanup_expired_vaa_body, close_unverified_message_account,
                     post_unverified_message_account, UnverifiedMessageAccount,
                 },
             },
+            legacy::{
+                accounts::{
+                    good_ntt_transceiver as legacy_transceiver, NTTTransceiverAccounts as _,
+                },
+                instructions::receive_message::{
+                    receive_message as legacy_receive_message,
+                    ReceiveMessage as LegacyReceiveMessage,
+                },
+            },
         },
     },
 };
@@ -118,7 +154,6 @@ async fn test_receive_instruction_data() {
             &good_ntt,
             &good_ntt_transceiver,
             &mut ctx,
-            &test_data,
             OTHER_CHAIN,
             msg.ntt_manager_payload.clone(),
         ),
@@ -137,6 +172,7 @@ async fn test_receive_instruction_data() {
         ReleaseInbound {
             payer: ctx.payer.pubkey(),
             inbox_item: good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone()),
+            from_chain: OTHER_CHAIN,
             mint: test_data.mint,
             recipient: recipient_token_account,
         },
@@ -157,6 +193,7 @@ async fn test_receive_instruction_data() {
         ReleaseInbound {
             payer: ctx.payer.pubkey(),
             inbox_item: good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone()),
+            from_chain: OTHER_CHAIN,
             mint: test_data.mint,
             recipient: recipient_token_account,
         },
@@ -178,45 +215,69 @@ async fn test_receive_instruction_data() {
 }
 
 #[tokio::test]
-async fn test_receive_message_account() {
+async fn test_receive_bad_guardian_signatures() {
     let recipient = Keypair::new();
-    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
 
-    // transfer tokens to custody account
-    spl_token::instruction::transfer_checked(
-        &Token::id(),
-        &test_data.user_token_account,
-        &test_data.mint,
-        &good_ntt.custody(&test_data.mint),
-        &test_data.user.pubkey(),
-        &[],
-        1000,
-        9,
-    )
-    .unwrap()
-    .submit_with_signers(&[&test_data.user], &mut ctx)
-    .await
-    .unwrap();
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
 
-    spl_associated_token_account::instruction::create_associated_token_account(
-        &ctx.payer.pubkey(),
-        &recipient.pubkey(),
-        &test_data.mint,
-        &Token::id(),
+    let (guardian_signatures, guardian_set_index, span) = post_vaa_helper(
+        &good_ntt_transceiver,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
     )
-    .submit(&mut ctx)
-    .await
-    .unwrap();
+    .await;
 
-    let recipient_token_account = get_associated_token_address_with_program_id(
-        &recipient.pubkey(),
-        &test_data.mint,
-        &Token::id(),
-    );
+    // a copy of the real signatures account, owned by another program
+    let mut account = ctx
+        .banks_client
+        .get_account(guardian_signatures)
+        .await
+        .unwrap()
+        .unwrap();
+    account.owner = Pubkey::new_unique();
+    let wrong_owner = Pubkey::new_unique();
+    ctx.set_account(&wrong_owner, &account.into());
+
+    for bad_guardian_signatures in [Pubkey::new_unique(), wrong_owner] {
+        let err = receive_message_instruction_data(
+            &good_ntt,
+            &good_ntt_transceiver,
+            init_receive_message_accs(
+                &good_ntt,
+                &good_ntt_transceiver,
+                &mut ctx,
+                OTHER_CHAIN,
+                [0u8; 32],
+                guardian_set_index,
+                bad_guardian_signatures,
+            ),
+            VaaBodyData { span: span.clone() },
+        )
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            err.unwrap(),
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(NTTError::InvalidGuardianSignaturesAccount.into())
+            )
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_receive_expected_digest() {
+    let recipient = Keypair::new();
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
 
     let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
 
-    let (guardian_signatures, guardian_set_index, vaa_body) = post_vaa_helper(
+    let (guardian_signatures, guardian_set_index, span) = post_vaa_helper(
         &good_ntt_transceiver,
         OTHER_CHAIN.into(),
         Address(OTHER_TRANSCEIVER),
@@ -225,114 +286,196 @@ async fn test_receive_message_account() {
     )
     .await;
 
-    // arbitrary seed to identify this message
-    let seed = u64::from_be_bytes(digest(&vaa_body).unwrap().hash[24..].try_into().unwrap());
+    let accs = init_receive_message_accs(
+        &good_ntt,
+        &good_ntt_transceiver,
+        &mut ctx,
+        OTHER_CHAIN,
+        [0u8; 32],
+        guardian_set_index,
+        guardian_signatures,
+    );
 
-    post_unverified_message_account(
+    let err = receive_message_instruction_data(
+        &good_ntt,
         &good_ntt_transceiver,
-        UnverifiedMessageAccount {
-            payer: ctx.payer.pubkey(),
+        ReceiveMessage {
+            expected_digest: Some([1u8; 32]),
+            ..accs.clone()
         },
-        seed,
-        vaa_body,
+        VaaBodyData { span: span.clone() },
     )
     .submit(&mut ctx)
     .await
-    .unwrap();
+    .unwrap_err();
 
-    receive_message_account(
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::DigestMismatch.into())
+        )
+    );
+
+    receive_message_instruction_data(
         &good_ntt,
         &good_ntt_transceiver,
-        init_receive_message_accs(
-            &good_ntt,
-            &good_ntt_transceiver,
-            &mut ctx,
-            OTHER_CHAIN,
-            [0u8; 32],
-            guardian_set_index,
-            guardian_signatures,
-        ),
-        seed,
+        ReceiveMessage {
+            expected_digest: Some(digest(&span).unwrap().secp256k_hash),
+            ..accs
+        },
+        VaaBodyData { span },
     )
     .submit(&mut ctx)
     .await
     .unwrap();
 
-    close_signatures(&good_ntt_transceiver, &mut ctx, &guardian_signatures).await;
+    assert!(ctx
+        .banks_client
+        .get_account(good_ntt_transceiver.transceiver_message(OTHER_CHAIN, [0u8; 32]))
+        .await
+        .unwrap()
+        .is_some());
+}
 
-    redeem(
-        &good_ntt,
-        init_redeem_accs(
-            &good_ntt,
-            &good_ntt_transceiver,
-            &mut ctx,
-            &test_data,
-            OTHER_CHAIN,
-            msg.ntt_manager_payload.clone(),
-        ),
-        RedeemArgs {},
-    )
-    .submit(&mut ctx)
-    .await
-    .unwrap();
+#[tokio::test]
+async fn test_receive_vaa_too_old() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
 
-    let token_account: TokenAccount = ctx.get_account_data_anchor(recipient_token_account).await;
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
 
-    assert_eq!(token_account.amount, 0);
+    // the helper's VAAs are timestamped in 1970
+    let (guardian_signatures, guardian_set_index, span) = post_vaa_helper(
+        &good_ntt_transceiver,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
 
-    release_inbound_unlock(
+    let accs = init_receive_message_accs(
         &good_ntt,
-        ReleaseInbound {
-            payer: ctx.payer.pubkey(),
-            inbox_item: good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone()),
-            mint: test_data.mint,
-            recipient: recipient_token_account,
-        },
-        ReleaseInboundArgs {
-            revert_when_not_ready: false,
-        },
-    )
-    .submit(&mut ctx)
-    .await
-    .unwrap();
+        &good_ntt_transceiver,
+        &mut ctx,
+        OTHER_CHAIN,
+        [0u8; 32],
+        guardian_set_index,
+        guardian_signatures,
+    );
 
-    let token_account: TokenAccount = ctx.get_account_data_anchor(recipient_token_account).await;
-    assert_eq!(token_account.amount, 1000);
+    let set_max_age = |max_vaa_age| {
+        set_max_vaa_age(
+            &good_ntt,
+            SetMaxVaaAge {
+                owner: test_data.program_owner.pubkey(),
+            },
+            max_vaa_age,
+        )
+    };
 
-    // let's make sure we can't redeem again.
-    let err = release_inbound_unlock(
+    // one day
+    set_max_age(Some(24 * 60 * 60))
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let err = receive_message_instruction_data(
         &good_ntt,
-        ReleaseInbound {
-            payer: ctx.payer.pubkey(),
-            inbox_item: good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone()),
-            mint: test_data.mint,
-            recipient: recipient_token_account,
-        },
-        ReleaseInboundArgs {
-            revert_when_not_ready: false,
-        },
+        &good_ntt_transceiver,
+        accs.clone(),
+        VaaBodyData { span: span.clone() },
     )
     .submit(&mut ctx)
     .await
     .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(NTTError::VaaTooOld.into()))
+    );
+
+    set_max_age(None)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    receive_message_instruction_data(&good_ntt, &good_ntt_transceiver, accs, VaaBodyData { span })
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    assert!(ctx
+        .banks_client
+        .get_account(good_ntt_transceiver.transceiver_message(OTHER_CHAIN, [0u8; 32]))
+        .await
+        .unwrap()
+        .is_some());
+}
+
+/// Receives a transfer whose VAA is timestamped `timestamp`.
+async fn receive_with_vaa_timestamp(
+    ctx: &mut ProgramTestContext,
+    id: [u8; 32],
+    timestamp: u32,
+) -> std::result::Result<(), BanksClientError> {
+    let msg = make_transfer_message(&good_ntt, id, 1000, &Pubkey::new_unique());
+    let (guardian_signatures, guardian_set_index, span) = post_vaa_helper_with_timestamp(
+        &good_ntt_transceiver,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg,
+        timestamp,
+        ctx,
+    )
+    .await;
+    let accs = init_receive_message_accs(
+        &good_ntt,
+        &good_ntt_transceiver,
+        ctx,
+        OTHER_CHAIN,
+        id,
+        guardian_set_index,
+        guardian_signatures,
+    );
+    receive_message_instruction_data(&good_ntt, &good_ntt_transceiver, accs, VaaBodyData { span })
+        .submit(ctx)
+        .await
+}
+
+#[tokio::test]
+async fn test_receive_vaa_from_future() {
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
 
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let now = u32::try_from(clock.unix_timestamp).unwrap();
+
+    // a day ahead of the clock
+    let err = receive_with_vaa_timestamp(&mut ctx, [0u8; 32], now + 24 * 60 * 60)
+        .await
+        .unwrap_err();
     assert_eq!(
         err.unwrap(),
         TransactionError::InstructionError(
             0,
-            InstructionError::Custom(NTTError::TransferAlreadyRedeemed.into())
+            InstructionError::Custom(NTTError::VaaTimestampInFuture.into())
         )
     );
+
+    // a little clock skew is tolerated
+    receive_with_vaa_timestamp(&mut ctx, [1u8; 32], now + 60)
+        .await
+        .unwrap();
 }
 
 #[tokio::test]
-async fn test_double_receive() {
-    let recipient = Keypair::new();
+async fn test_receive_max_size_message() {
     let (mut ctx, _test_data) = setup(Mode::Locking).await;
 
-    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+    // `make_transfer_message` uses the largest additional payload
+    let msg = make_transfer_message(&good_ntt, [u8::MAX; 32], u64::MAX, &Pubkey::new_unique());
 
-    let (guardian_signatures0, guardian_set_index0, span0) = post_vaa_helper(
+    let (guardian_signatures, guardian_set_index, span) = post_vaa_helper(
         &good_ntt_transceiver,
         OTHER_CHAIN.into(),
         Address(OTHER_TRANSCEIVER),
@@ -340,14 +483,6 @@ async fn test_double_receive() {
         &mut ctx,
     )
     .await;
-    let (guardian_signatures1, guardian_set_index1, span1) = post_vaa_helper(
-        &good_ntt_transceiver,
-        OTHER_CHAIN.into(),
-        Address(OTHER_TRANSCEIVER),
-        msg,
-        &mut ctx,
-    )
-    .await;
 
     receive_message_instruction_data(
         &good_ntt,
@@ -357,52 +492,55 @@ async fn test_double_receive() {
             &good_ntt_transceiver,
             &mut ctx,
             OTHER_CHAIN,
-            [0u8; 32],
-            guardian_set_index0,
-            guardian_signatures0,
+            [u8::MAX; 32],
+            guardian_set_index,
+            guardian_signatures,
         ),
-        VaaBodyData { span: span0 },
+        VaaBodyData { span },
     )
     .submit(&mut ctx)
     .await
     .unwrap();
 
-    let err = receive_message_instruction_data(
+    let transceiver_message = good_ntt_transceiver.transceiver_message(OTHER_CHAIN, [u8::MAX; 32]);
+    let account = ctx
+        .banks_client
+        .get_account(transceiver_message)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data.len(), TRANSCEIVER_MESSAGE_SPACE);
+
+    let validated: ValidatedTransceiverMessage<NativeTokenTransfer<Payload>> =
+        ctx.get_account_data_anchor(transceiver_message).await;
+    assert_eq!(validated.message, msg.message_data);
+
+    redeem(
         &good_ntt,
-        &good_ntt_transceiver,
-        init_receive_message_accs(
+        init_redeem_accs(
             &good_ntt,
             &good_ntt_transceiver,
             &mut ctx,
             OTHER_CHAIN,
-            [0u8; 32],
-            guardian_set_index1,
-            guardian_signatures1,
+            msg.ntt_manager_payload.clone(),
         ),
-        VaaBodyData { span: span1 },
+        RedeemArgs {},
     )
     .submit(&mut ctx)
     .await
-    .unwrap_err();
-
-    close_signatures(&good_ntt_transceiver, &mut ctx, &guardian_signatures0).await;
-    close_signatures(&good_ntt_transceiver, &mut ctx, &guardian_signatures1).await;
+    .unwrap();
 
-    assert_eq!(
-        err.unwrap(),
-        // AccountAlreadyInUse
-        TransactionError::InstructionError(0, InstructionError::Custom(0))
-    );
+    let inbox_item: InboxItem = ctx
+        .get_account_data_anchor(good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload))
+        .await;
+    assert_eq!(inbox_item.amount, u64::MAX);
 }
 
 #[tokio::test]
-async fn test_wrong_recipient_ntt_manager() {
-    let recipient = Keypair::new();
-    let (mut ctx, test_data) = setup(Mode::Locking).await;
-
-    let mut msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+async fn test_receive_with_compute_unit_limit() {
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
 
-    msg.recipient_ntt_manager = Pubkey::new_unique().to_bytes();
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &Pubkey::new_unique());
 
     let (guardian_signatures, guardian_set_index, span) = post_vaa_helper(
         &good_ntt_transceiver,
@@ -413,7 +551,7 @@ async fn test_wrong_recipient_ntt_manager() {
     )
     .await;
 
-    receive_message_instruction_data(
+    let receive = receive_message_instruction_data(
         &good_ntt,
         &good_ntt_transceiver,
         init_receive_message_accs(
@@ -426,40 +564,193 @@ async fn test_wrong_recipient_ntt_manager() {
             guardian_signatures,
         ),
         VaaBodyData { span },
-    )
-    .submit(&mut ctx)
-    .await
-    .unwrap();
+    );
 
-    close_signatures(&good_ntt_transceiver, &mut ctx, &guardian_signatures).await;
+    // the limit is applied: too low and the receive (after the compute budget
+    // instruction) runs out
+    let err = receive
+        .clone()
+        .with_compute_unit_limit(1_000)
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(1, InstructionError::ComputationalBudgetExceeded)
+    );
 
-    let err = redeem(
+    receive
+        .with_compute_unit_limit(1_400_000)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    let validated: ValidatedTransceiverMessage<NativeTokenTransfer<Payload>> = ctx
+        .get_account_data_anchor(good_ntt_transceiver.transceiver_message(OTHER_CHAIN, [0u8; 32]))
+        .await;
+    assert_eq!(validated.message, msg.message_data);
+}
+
+/// Size of a guardian set large enough (mainnet's) that a quorum of signatures
+/// doesn't fit in a single transaction.
+const LARGE_GUARDIAN_SET_SIZE: u8 = 19;
+const LARGE_GUARDIAN_SET_INDEX: u32 = 1;
+
+#[tokio::test]
+async fn test_receive_signatures_posted_in_batches() {
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
+
+    let guardian_keys = guardian_secret_keys(LARGE_GUARDIAN_SET_SIZE);
+    add_guardian_set(
+        &mut ctx,
+        &good_ntt.wormhole(),
+        LARGE_GUARDIAN_SET_INDEX,
+        &guardian_keys,
+    );
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &Pubkey::new_unique());
+    let mut vaa = make_vaa(
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        LARGE_GUARDIAN_SET_INDEX,
+    );
+    // a quorum of 13 out of 19 guardians
+    for (index, key) in guardian_keys.iter().enumerate().take(13) {
+        let signature = sign_vaa(vaa.clone(), u8::try_from(index).unwrap(), key);
+        vaa.signatures.push(signature);
+    }
+
+    // 7 signatures in the first transaction, 6 in the second
+    let guardian_signatures = Keypair::new();
+    post_signatures_in_batches(
+        &good_ntt_transceiver,
+        &mut ctx,
+        &guardian_signatures,
+        &vaa,
+        7,
+    )
+    .await
+    .unwrap();
+
+    receive_message_instruction_data(
         &good_ntt,
-        init_redeem_accs(
+        &good_ntt_transceiver,
+        init_receive_message_accs(
             &good_ntt,
             &good_ntt_transceiver,
             &mut ctx,
-            &test_data,
             OTHER_CHAIN,
-            msg.ntt_manager_payload.clone(),
+            [0u8; 32],
+            LARGE_GUARDIAN_SET_INDEX,
+            guardian_signatures.pubkey(),
         ),
-        RedeemArgs {},
+        VaaBodyData {
+            span: vaa_body(&vaa),
+        },
     )
+    .with_compute_unit_limit(1_400_000)
     .submit(&mut ctx)
     .await
-    .unwrap_err();
+    .unwrap();
 
-    assert_eq!(
-        err.unwrap(),
-        TransactionError::InstructionError(
-            0,
-            InstructionError::Custom(NTTError::InvalidRecipientNttManager.into())
-        )
+    let validated: ValidatedTransceiverMessage<NativeTokenTransfer<Payload>> = ctx
+        .get_account_data_anchor(good_ntt_transceiver.transceiver_message(OTHER_CHAIN, [0u8; 32]))
+        .await;
+    assert_eq!(validated.message, msg.message_data);
+}
+
+#[tokio::test]
+async fn test_post_signatures_at_capacity() {
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
+
+    let guardian_keys = guardian_secret_keys(LARGE_GUARDIAN_SET_SIZE);
+    add_guardian_set(
+        &mut ctx,
+        &good_ntt.wormhole(),
+        LARGE_GUARDIAN_SET_INDEX,
+        &guardian_keys,
+    );
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &Pubkey::new_unique());
+    let mut vaa = make_vaa(
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg,
+        LARGE_GUARDIAN_SET_INDEX,
     );
+    for (index, key) in guardian_keys.iter().enumerate() {
+        let signature = sign_vaa(vaa.clone(), u8::try_from(index).unwrap(), key);
+        vaa.signatures.push(signature);
+    }
+
+    // the account is sized for every guardian's signature, and filled up in
+    // batches of 7, 7 and 5
+    let guardian_signatures = Keypair::new();
+    post_signatures_in_batches(
+        &good_ntt_transceiver,
+        &mut ctx,
+        &guardian_signatures,
+        &vaa,
+        7,
+    )
+    .await
+    .unwrap();
+
+    // there's no room for any more signatures
+    let extra_signature = vaa.signatures[0].clone();
+    assert!(post_signatures_batch(
+        &good_ntt_transceiver,
+        &mut ctx,
+        &guardian_signatures,
+        LARGE_GUARDIAN_SET_INDEX,
+        LARGE_GUARDIAN_SET_SIZE,
+        &[extra_signature],
+    )
+    .await
+    .is_err());
+
+    receive_message_instruction_data(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            [0u8; 32],
+            LARGE_GUARDIAN_SET_INDEX,
+            guardian_signatures.pubkey(),
+        ),
+        VaaBodyData {
+            span: vaa_body(&vaa),
+        },
+    )
+    .with_compute_unit_limit(1_400_000)
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+}
+
+#[test]
+fn test_cached_guardian_set_bump() {
+    let wormhole = good_ntt.wormhole();
+    for guardian_set_index in [0, LARGE_GUARDIAN_SET_INDEX, u32::MAX] {
+        let derived = wormhole.guardian_set_with_bump(guardian_set_index);
+        // the first call fills the cache, the second reads from it
+        assert_eq!(
+            wormhole.cached_guardian_set_with_bump(guardian_set_index),
+            derived
+        );
+        assert_eq!(
+            wormhole.cached_guardian_set_with_bump(guardian_set_index),
+            derived
+        );
+    }
 }
 
 #[tokio::test]
-async fn test_wrong_transceiver_peer() {
+async fn test_receive_underfunded_payer() {
     let recipient = Keypair::new();
     let (mut ctx, _test_data) = setup(Mode::Locking).await;
 
@@ -468,51 +759,92 @@ async fn test_wrong_transceiver_peer() {
     let (guardian_signatures, guardian_set_index, span) = post_vaa_helper(
         &good_ntt_transceiver,
         OTHER_CHAIN.into(),
-        Address(Pubkey::new_unique().to_bytes()), // not the expected transceiver
+        Address(OTHER_TRANSCEIVER),
         msg.clone(),
         &mut ctx,
     )
     .await;
 
+    // signs the instruction, but holds no lamports to pay for the message account
+    let payer = Keypair::new();
+
     let err = receive_message_instruction_data(
         &good_ntt,
         &good_ntt_transceiver,
-        init_receive_message_accs(
-            &good_ntt,
-            &good_ntt_transceiver,
-            &mut ctx,
-            OTHER_CHAIN,
-            [0u8; 32],
-            guardian_set_index,
-            guardian_signatures,
-        ),
+        ReceiveMessage {
+            payer: payer.pubkey(),
+            ..init_receive_message_accs(
+                &good_ntt,
+                &good_ntt_transceiver,
+                &mut ctx,
+                OTHER_CHAIN,
+                [0u8; 32],
+                guardian_set_index,
+                guardian_signatures,
+            )
+        },
         VaaBodyData { span },
     )
-    .submit(&mut ctx)
+    .submit_with_signers(&[&payer], &mut ctx)
     .await
     .unwrap_err();
 
-    close_signatures(&good_ntt_transceiver, &mut ctx, &guardian_signatures).await;
-
     assert_eq!(
         err.unwrap(),
         TransactionError::InstructionError(
             0,
-            InstructionError::Custom(NTTError::InvalidTransceiverPeer.into())
+            InstructionError::Custom(NTTError::InsufficientRentForMessage.into())
         )
     );
+
+    assert!(ctx
+        .banks_client
+        .get_account(good_ntt_transceiver.transceiver_message(OTHER_CHAIN, [0u8; 32]))
+        .await
+        .unwrap()
+        .is_none());
 }
 
 #[tokio::test]
-async fn test_wrong_manager_peer() {
+async fn test_receive_message_account() {
     let recipient = Keypair::new();
     let (mut ctx, test_data) = setup(Mode::Locking).await;
 
-    let mut msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+    // transfer tokens to custody account
+    spl_token::instruction::transfer_checked(
+        &Token::id(),
+        &test_data.user_token_account,
+        &test_data.mint,
+        &good_ntt.custody(&test_data.mint),
+        &test_data.user.pubkey(),
+        &[],
+        1000,
+        9,
+    )
+    .unwrap()
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
 
-    msg.source_ntt_manager = Pubkey::new_unique().to_bytes(); // not the expected source manager
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &ctx.payer.pubkey(),
+        &recipient.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
 
-    let (guardian_signatures, guardian_set_index, span) = post_vaa_helper(
+    let recipient_token_account = get_associated_token_address_with_program_id(
+        &recipient.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    );
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+
+    let (guardian_signatures, guardian_set_index, vaa_body) = post_vaa_helper(
         &good_ntt_transceiver,
         OTHER_CHAIN.into(),
         Address(OTHER_TRANSCEIVER),
@@ -521,7 +853,22 @@ async fn test_wrong_manager_peer() {
     )
     .await;
 
-    receive_message_instruction_data(
+    // arbitrary seed to identify this message
+    let seed = u64::from_be_bytes(digest(&vaa_body).unwrap().hash[24..].try_into().unwrap());
+
+    post_unverified_message_account(
+        &good_ntt_transceiver,
+        UnverifiedMessageAccount {
+            payer: ctx.payer.pubkey(),
+        },
+        seed,
+        vaa_body,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    receive_message_account(
         &good_ntt,
         &good_ntt_transceiver,
         init_receive_message_accs(
@@ -533,7 +880,7 @@ async fn test_wrong_manager_peer() {
             guardian_set_index,
             guardian_signatures,
         ),
-        VaaBodyData { span },
+        seed,
     )
     .submit(&mut ctx)
     .await
@@ -541,13 +888,12 @@ async fn test_wrong_manager_peer() {
 
     close_signatures(&good_ntt_transceiver, &mut ctx, &guardian_signatures).await;
 
-    let err = redeem(
+    redeem(
         &good_ntt,
         init_redeem_accs(
             &good_ntt,
             &good_ntt_transceiver,
             &mut ctx,
-            &test_data,
             OTHER_CHAIN,
             msg.ntt_manager_payload.clone(),
         ),
@@ -555,25 +901,77 @@ async fn test_wrong_manager_peer() {
     )
     .submit(&mut ctx)
     .await
+    .unwrap();
+
+    let token_account: TokenAccount = ctx.get_account_data_anchor(recipient_token_account).await;
+
+    assert_eq!(token_account.amount, 0);
+
+    release_inbound_unlock(
+        &good_ntt,
+        ReleaseInbound {
+            payer: ctx.payer.pubkey(),
+            inbox_item: good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone()),
+            from_chain: OTHER_CHAIN,
+            mint: test_data.mint,
+            recipient: recipient_token_account,
+        },
+        ReleaseInboundArgs {
+            revert_when_not_ready: false,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let token_account: TokenAccount = ctx.get_account_data_anchor(recipient_token_account).await;
+    assert_eq!(token_account.amount, 1000);
+
+    // let's make sure we can't redeem again.
+    let err = release_inbound_unlock(
+        &good_ntt,
+        ReleaseInbound {
+            payer: ctx.payer.pubkey(),
+            inbox_item: good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone()),
+            from_chain: OTHER_CHAIN,
+            mint: test_data.mint,
+            recipient: recipient_token_account,
+        },
+        ReleaseInboundArgs {
+            revert_when_not_ready: false,
+        },
+    )
+    .submit(&mut ctx)
+    .await
     .unwrap_err();
 
     assert_eq!(
         err.unwrap(),
         TransactionError::InstructionError(
             0,
-            InstructionError::Custom(NTTError::InvalidNttManagerPeer.into())
+            InstructionError::Custom(NTTError::TransferAlreadyRedeemed.into())
         )
     );
 }
 
 #[tokio::test]
-async fn test_wrong_inbox_item() {
-    let recipient = Keypair::new();
+async fn test_receive_while_paused() {
     let (mut ctx, test_data) = setup(Mode::Locking).await;
 
-    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+    set_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
 
-    let (guardian_signatures, guardian_set_index, span) = post_vaa_helper(
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &Pubkey::new_unique());
+
+    let (guardian_signatures, guardian_set_index, vaa_body) = post_vaa_helper(
         &good_ntt_transceiver,
         OTHER_CHAIN.into(),
         Address(OTHER_TRANSCEIVER),
@@ -582,7 +980,23 @@ async fn test_wrong_inbox_item() {
     )
     .await;
 
-    receive_message_instruction_data(
+    // arbitrary seed to identify this message
+    let seed = u64::from_be_bytes(digest(&vaa_body).unwrap().hash[24..].try_into().unwrap());
+
+    post_unverified_message_account(
+        &good_ntt_transceiver,
+        UnverifiedMessageAccount {
+            payer: ctx.payer.pubkey(),
+        },
+        seed,
+        vaa_body,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    // verifying is allowed while paused
+    verify_pending_message(
         &good_ntt,
         &good_ntt_transceiver,
         init_receive_message_accs(
@@ -594,7 +1008,7 @@ async fn test_wrong_inbox_item() {
             guardian_set_index,
             guardian_signatures,
         ),
-        VaaBodyData { span },
+        seed,
     )
     .submit(&mut ctx)
     .await
@@ -602,27 +1016,1084 @@ async fn test_wrong_inbox_item() {
 
     close_signatures(&good_ntt_transceiver, &mut ctx, &guardian_signatures).await;
 
-    // use 'ANOTHER_CHAIN' inbox item account here
-    let mut redeem_accs = init_redeem_accs(
-        &good_ntt,
-        &good_ntt_transceiver,
-        &mut ctx,
-        &test_data,
-        OTHER_CHAIN,
-        msg.ntt_manager_payload.clone(),
-    );
-    redeem_accs.inbox_item = good_ntt.inbox_item(ANOTHER_CHAIN, msg.ntt_manager_payload.clone());
+    let promote = PromotePendingMessage {
+        payer: ctx.payer.pubkey(),
+        rent_payer: ctx.payer.pubkey(),
+        peer: good_ntt_transceiver.transceiver_peer(OTHER_CHAIN),
+        chain_id: OTHER_CHAIN,
+        id: [0u8; 32],
+    };
 
-    let err = redeem(&good_ntt, redeem_accs, RedeemArgs {})
+    // ...but accepting the message is not
+    let err = promote_pending_message(&good_ntt, &good_ntt_transceiver, promote.clone())
         .submit(&mut ctx)
         .await
         .unwrap_err();
-
     assert_eq!(
         err.unwrap(),
-        TransactionError::InstructionError(
-            0,
-            InstructionError::Custom(ErrorCode::ConstraintSeeds.into())
-        )
+        TransactionError::InstructionError(0, InstructionError::Custom(NTTError::Paused.into()))
     );
+
+    set_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        false,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    promote_pending_message(&good_ntt, &good_ntt_transceiver, promote)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    // the pending message is closed on promotion
+    assert!(ctx
+        .banks_client
+        .get_account(good_ntt_transceiver.pending_message(OTHER_CHAIN, [0u8; 32]))
+        .await
+        .unwrap()
+        .is_none());
+
+    redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_double_receive() {
+    let recipient = Keypair::new();
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+
+    let (guardian_signatures0, guardian_set_index0, span0) = post_vaa_helper(
+        &good_ntt_transceiver,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+    let (guardian_signatures1, guardian_set_index1, span1) = post_vaa_helper(
+        &good_ntt_transceiver,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg,
+        &mut ctx,
+    )
+    .await;
+
+    receive_message_instruction_data(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            [0u8; 32],
+            guardian_set_index0,
+            guardian_signatures0,
+        ),
+        VaaBodyData { span: span0 },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let transceiver_message = good_ntt_transceiver.transceiver_message(OTHER_CHAIN, [0u8; 32]);
+    let before = ctx
+        .banks_client
+        .get_account(transceiver_message)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // receiving the same message again from the same transceiver is a no-op
+    receive_message_instruction_data(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            [0u8; 32],
+            guardian_set_index1,
+            guardian_signatures1,
+        ),
+        VaaBodyData { span: span1 },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    close_signatures(&good_ntt_transceiver, &mut ctx, &guardian_signatures0).await;
+    close_signatures(&good_ntt_transceiver, &mut ctx, &guardian_signatures1).await;
+
+    let after = ctx
+        .banks_client
+        .get_account(transceiver_message)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(before, after);
+}
+
+#[tokio::test]
+async fn test_double_receive_across_paths() {
+    let recipient = Keypair::new();
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+
+    let (guardian_signatures0, guardian_set_index0, span0) = post_vaa_helper(
+        &good_ntt_transceiver,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+    let (guardian_signatures1, guardian_set_index1, span1) = post_vaa_helper(
+        &good_ntt_transceiver,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg,
+        &mut ctx,
+    )
+    .await;
+
+    // deliver via instruction data first
+    receive_message_instruction_data(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            [0u8; 32],
+            guardian_set_index0,
+            guardian_signatures0,
+        ),
+        VaaBodyData { span: span0 },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    // then via a staged account
+    let seed = u64::from_be_bytes(digest(&span1).unwrap().hash[24..].try_into().unwrap());
+
+    post_unverified_message_account(
+        &good_ntt_transceiver,
+        UnverifiedMessageAccount {
+            payer: ctx.payer.pubkey(),
+        },
+        seed,
+        span1,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let transceiver_message = good_ntt_transceiver.transceiver_message(OTHER_CHAIN, [0u8; 32]);
+    let before = ctx
+        .banks_client
+        .get_account(transceiver_message)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // both paths resolve to the same account, so the second delivery is a no-op
+    receive_message_account(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            [0u8; 32],
+            guardian_set_index1,
+            guardian_signatures1,
+        ),
+        seed,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    close_signatures(&good_ntt_transceiver, &mut ctx, &guardian_signatures0).await;
+    close_signatures(&good_ntt_transceiver, &mut ctx, &guardian_signatures1).await;
+
+    let after = ctx
+        .banks_client
+        .get_account(transceiver_message)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(before, after);
+}
+
+#[tokio::test]
+async fn test_wrong_recipient_ntt_manager() {
+    let recipient = Keypair::new();
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
+
+    let mut msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+
+    msg.recipient_ntt_manager = Pubkey::new_unique().to_bytes();
+
+    let (guardian_signatures, guardian_set_index, span) = post_vaa_helper(
+        &good_ntt_transceiver,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+
+    receive_message_instruction_data(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            [0u8; 32],
+            guardian_set_index,
+            guardian_signatures,
+        ),
+        VaaBodyData { span },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    close_signatures(&good_ntt_transceiver, &mut ctx, &guardian_signatures).await;
+
+    let err = redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidRecipientNttManager.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_wrong_transceiver_peer() {
+    let recipient = Keypair::new();
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+
+    let (guardian_signatures, guardian_set_index, span) = post_vaa_helper(
+        &good_ntt_transceiver,
+        OTHER_CHAIN.into(),
+        Address(Pubkey::new_unique().to_bytes()), // not the expected transceiver
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+
+    let err = receive_message_instruction_data(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            [0u8; 32],
+            guardian_set_index,
+            guardian_signatures,
+        ),
+        VaaBodyData { span },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    close_signatures(&good_ntt_transceiver, &mut ctx, &guardian_signatures).await;
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidTransceiverPeer.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_wrong_manager_peer() {
+    let recipient = Keypair::new();
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
+
+    let mut msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+
+    msg.source_ntt_manager = Pubkey::new_unique().to_bytes(); // not the expected source manager
+
+    let (guardian_signatures, guardian_set_index, span) = post_vaa_helper(
+        &good_ntt_transceiver,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+
+    // the message is rejected on receive, before it ever gets to redeem
+    let err = receive_message_instruction_data(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            [0u8; 32],
+            guardian_set_index,
+            guardian_signatures,
+        ),
+        VaaBodyData { span },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidNttManagerPeer.into())
+        )
+    );
+
+    close_signatures(&good_ntt_transceiver, &mut ctx, &guardian_signatures).await;
+}
+
+#[tokio::test]
+async fn test_wrong_inbox_item() {
+    let recipient = Keypair::new();
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+
+    let (guardian_signatures, guardian_set_index, span) = post_vaa_helper(
+        &good_ntt_transceiver,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+
+    receive_message_instruction_data(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            [0u8; 32],
+            guardian_set_index,
+            guardian_signatures,
+        ),
+        VaaBodyData { span },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    close_signatures(&good_ntt_transceiver, &mut ctx, &guardian_signatures).await;
+
+    // use 'ANOTHER_CHAIN' inbox item account here
+    let mut redeem_accs = init_redeem_accs(
+        &good_ntt,
+        &good_ntt_transceiver,
+        &mut ctx,
+        OTHER_CHAIN,
+        msg.ntt_manager_payload.clone(),
+    );
+    redeem_accs.inbox_item = good_ntt.inbox_item(ANOTHER_CHAIN, msg.ntt_manager_payload.clone());
+
+    let err = redeem(&good_ntt, redeem_accs, RedeemArgs {})
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintSeeds.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_quote_delivery_cost() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &recipient.pubkey());
+
+    let (guardian_signatures, guardian_set_index, vaa_body) = post_vaa_helper(
+        &good_ntt_transceiver,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+
+    let out = quote_delivery_cost(
+        &good_ntt_transceiver,
+        vaa_body.len().try_into().unwrap(),
+        false,
+    )
+    .simulate(&mut ctx)
+    .await
+    .unwrap();
+    assert!(out.result.unwrap().is_ok());
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    let quote = DeliveryCostQuote::deserialize(&mut &return_data[..]).unwrap();
+    assert_eq!(quote.reclaimable, quote.vaa_body);
+    assert!(quote.recipient_token_account > 0);
+
+    let fee_per_transaction = ctx
+        .banks_client
+        .get_fee_for_message(Message::new(&[], Some(&ctx.payer.pubkey())))
+        .await
+        .unwrap()
+        .unwrap();
+    let balance_before = ctx
+        .banks_client
+        .get_balance(ctx.payer.pubkey())
+        .await
+        .unwrap();
+
+    // arbitrary seed to identify this message
+    let seed = u64::from_be_bytes(digest(&vaa_body).unwrap().hash[24..].try_into().unwrap());
+
+    post_unverified_message_account(
+        &good_ntt_transceiver,
+        UnverifiedMessageAccount {
+            payer: ctx.payer.pubkey(),
+        },
+        seed,
+        vaa_body,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    receive_message_account(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            [0u8; 32],
+            guardian_set_index,
+            guardian_signatures,
+        ),
+        seed,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &ctx.payer.pubkey(),
+        &recipient.pubkey(),
+        &test_data.mint,
+        &Token::id(),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let balance_after = ctx
+        .banks_client
+        .get_balance(ctx.payer.pubkey())
+        .await
+        .unwrap();
+
+    close_signatures(&good_ntt_transceiver, &mut ctx, &guardian_signatures).await;
+
+    let transaction_fees = 4 * fee_per_transaction;
+    assert_eq!(
+        balance_before - balance_after - transaction_fees,
+        quote.non_reclaimable
+    );
+}
+
+/// Configures a receive hook, returning its address.
+async fn setup_receive_hook(ctx: &mut ProgramTestContext, test_data: &TestData) -> Pubkey {
+    let receive_hook = Pubkey::new_unique();
+
+    set_receive_hook(
+        &good_ntt,
+        SetReceiveHook {
+            owner: test_data.program_owner.pubkey(),
+        },
+        Some(receive_hook),
+    )
+    .submit_with_signers(&[&test_data.program_owner], ctx)
+    .await
+    .unwrap();
+
+    receive_hook
+}
+
+/// Simulates `ix` (so that its logs can be inspected) before submitting it,
+/// returning the receive hook notifications it emitted.
+async fn submit_and_collect_notifications(
+    ix: Instruction,
+    ctx: &mut ProgramTestContext,
+) -> Vec<ReceiveHookNotified> {
+    let out = ix.clone().simulate(ctx).await.unwrap();
+    assert!(out.result.unwrap().is_ok());
+    ix.submit(ctx).await.unwrap();
+
+    receive_hook_notifications(&out.simulation_details.unwrap().logs)
+}
+
+#[tokio::test]
+async fn test_receive_hook() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    for (id, with_hook) in [([0u8; 32], false), ([1u8; 32], true)] {
+        let receive_hook = if with_hook {
+            Some(setup_receive_hook(&mut ctx, &test_data).await)
+        } else {
+            None
+        };
+
+        let msg = make_transfer_message(&good_ntt, id, 1000, &Pubkey::new_unique());
+
+        let (guardian_signatures, guardian_set_index, span) = post_vaa_helper(
+            &good_ntt_transceiver,
+            OTHER_CHAIN.into(),
+            Address(OTHER_TRANSCEIVER),
+            msg.clone(),
+            &mut ctx,
+        )
+        .await;
+
+        let ix = receive_message_instruction_data(
+            &good_ntt,
+            &good_ntt_transceiver,
+            init_receive_message_accs(
+                &good_ntt,
+                &good_ntt_transceiver,
+                &mut ctx,
+                OTHER_CHAIN,
+                id,
+                guardian_set_index,
+                guardian_signatures,
+            ),
+            VaaBodyData { span },
+        );
+        let notifications = submit_and_collect_notifications(ix.clone(), &mut ctx).await;
+
+        let Some(receive_hook) = receive_hook else {
+            // nothing is emitted when no hook is configured
+            assert_eq!(notifications, vec![]);
+            continue;
+        };
+
+        assert_eq!(
+            notifications,
+            vec![ReceiveHookNotified {
+                receive_hook,
+                transceiver_message: good_ntt_transceiver.transceiver_message(OTHER_CHAIN, id),
+                args: ReceiveHookArgs {
+                    from_chain: ChainId { id: OTHER_CHAIN },
+                    id,
+                    source_ntt_manager: OTHER_MANAGER,
+                    sender: msg.ntt_manager_payload.sender,
+                    amount: msg.ntt_manager_payload.payload.amount,
+                    to: msg.ntt_manager_payload.payload.to,
+                },
+            }]
+        );
+
+        // the hook is only notified once per message
+        let notifications = submit_and_collect_notifications(ix, &mut ctx).await;
+        assert_eq!(notifications, vec![]);
+    }
+}
+
+#[tokio::test]
+async fn test_receive_hook_other_receive_paths() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    register_legacy_transceiver(&mut ctx, &test_data).await;
+    let receive_hook = setup_receive_hook(&mut ctx, &test_data).await;
+
+    // verified ahead of time, and promoted
+    let msg = make_transfer_message(&good_ntt, [0u8; 32], 1000, &Pubkey::new_unique());
+    let (guardian_signatures, guardian_set_index, vaa_body) = post_vaa_helper(
+        &good_ntt_transceiver,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+    let seed = u64::from_be_bytes(digest(&vaa_body).unwrap().hash[24..].try_into().unwrap());
+    post_unverified_message_account(
+        &good_ntt_transceiver,
+        UnverifiedMessageAccount {
+            payer: ctx.payer.pubkey(),
+        },
+        seed,
+        vaa_body,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+    verify_pending_message(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            &mut ctx,
+            OTHER_CHAIN,
+            [0u8; 32],
+            guardian_set_index,
+            guardian_signatures,
+        ),
+        seed,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let ix = promote_pending_message(
+        &good_ntt,
+        &good_ntt_transceiver,
+        PromotePendingMessage {
+            payer: ctx.payer.pubkey(),
+            rent_payer: ctx.payer.pubkey(),
+            peer: good_ntt_transceiver.transceiver_peer(OTHER_CHAIN),
+            chain_id: OTHER_CHAIN,
+            id: [0u8; 32],
+        },
+    );
+    let notifications = submit_and_collect_notifications(ix, &mut ctx).await;
+
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].receive_hook, receive_hook);
+    assert_eq!(
+        notifications[0].transceiver_message,
+        good_ntt_transceiver.transceiver_message(OTHER_CHAIN, [0u8; 32])
+    );
+    assert_eq!(notifications[0].args.sender, msg.ntt_manager_payload.sender);
+
+    // received through the legacy transceiver
+    let id = [1u8; 32];
+    let msg = make_transfer_message(&good_ntt, id, 1000, &Pubkey::new_unique());
+    let vaa = post_legacy_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        &mut ctx,
+    )
+    .await;
+    let ix = legacy_receive_message(
+        &good_ntt,
+        &legacy_transceiver,
+        LegacyReceiveMessage {
+            payer: ctx.payer.pubkey(),
+            peer: legacy_transceiver.transceiver_peer(OTHER_CHAIN),
+            vaa,
+            chain_id: OTHER_CHAIN,
+            id,
+        },
+    );
+    let notifications = submit_and_collect_notifications(ix, &mut ctx).await;
+
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].receive_hook, receive_hook);
+    assert_eq!(
+        notifications[0].transceiver_message,
+        legacy_transceiver.transceiver_message(OTHER_CHAIN, id)
+    );
+    assert_eq!(notifications[0].args.id, id);
+}
+
+/// Receives `msg` through the legacy transceiver, as if its VAA had been
+/// posted to the core bridge before the shim took over, and redeems it.
+async fn redeem_via_legacy_transceiver(
+    ctx: &mut ProgramTestContext,
+    msg: &TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>>,
+) {
+    let id = msg.ntt_manager_payload.id;
+    let vaa = post_legacy_vaa_helper(
+        &good_ntt,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        ctx,
+    )
+    .await;
+
+    legacy_receive_message(
+        &good_ntt,
+        &legacy_transceiver,
+        LegacyReceiveMessage {
+            payer: ctx.payer.pubkey(),
+            peer: legacy_transceiver.transceiver_peer(OTHER_CHAIN),
+            vaa,
+            chain_id: OTHER_CHAIN,
+            id,
+        },
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    redeem(
+        &good_ntt,
+        Redeem {
+            transceiver: legacy_transceiver.program(),
+            transceiver_message: legacy_transceiver.transceiver_message(OTHER_CHAIN, id),
+            ..init_redeem_accs(
+                &good_ntt,
+                &good_ntt_transceiver,
+                ctx,
+                OTHER_CHAIN,
+                msg.ntt_manager_payload.clone(),
+            )
+        },
+        RedeemArgs {},
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+}
+
+/// Receives `msg` through the shim transceiver, and redeems it.
+async fn redeem_via_shim_transceiver(
+    ctx: &mut ProgramTestContext,
+    msg: &TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>>,
+) {
+    let (guardian_signatures, guardian_set_index, span) = post_vaa_helper(
+        &good_ntt_transceiver,
+        OTHER_CHAIN.into(),
+        Address(OTHER_TRANSCEIVER),
+        msg.clone(),
+        ctx,
+    )
+    .await;
+
+    receive_message_instruction_data(
+        &good_ntt,
+        &good_ntt_transceiver,
+        init_receive_message_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            ctx,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.id,
+            guardian_set_index,
+            guardian_signatures,
+        ),
+        VaaBodyData { span },
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    close_signatures(&good_ntt_transceiver, ctx, &guardian_signatures).await;
+
+    redeem(
+        &good_ntt,
+        init_redeem_accs(
+            &good_ntt,
+            &good_ntt_transceiver,
+            ctx,
+            OTHER_CHAIN,
+            msg.ntt_manager_payload.clone(),
+        ),
+        RedeemArgs {},
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+}
+
+/// Releases the transfer of `msg` to the user.
+fn release_to_user(
+    ctx: &ProgramTestContext,
+    test_data: &TestData,
+    msg: &TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>>,
+) -> Instruction {
+    release_inbound_unlock(
+        &good_ntt,
+        ReleaseInbound {
+            payer: ctx.payer.pubkey(),
+            inbox_item: good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone()),
+            from_chain: OTHER_CHAIN,
+            mint: test_data.mint,
+            recipient: test_data.user_token_account,
+        },
+        ReleaseInboundArgs {
+            revert_when_not_ready: true,
+        },
+    )
+}
+
+/// Transfers `amount` from the user to the custody account, so that inbound
+/// transfers can be unlocked.
+async fn fund_custody(ctx: &mut ProgramTestContext, test_data: &TestData, amount: u64) {
+    spl_token::instruction::transfer_checked(
+        &Token::id(),
+        &test_data.user_token_account,
+        &test_data.mint,
+        &good_ntt.custody(&test_data.mint),
+        &test_data.user.pubkey(),
+        &[],
+        amount,
+        9,
+    )
+    .unwrap()
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_receive_via_legacy_and_shim_transceivers() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    register_legacy_transceiver(&mut ctx, &test_data).await;
+    fund_custody(&mut ctx, &test_data, 2000).await;
+
+    // the transceivers derive their messages from the same seeds, but under
+    // their own program ids
+    assert_ne!(
+        legacy_transceiver.transceiver_message(OTHER_CHAIN, [1u8; 32]),
+        good_ntt_transceiver.transceiver_message(OTHER_CHAIN, [1u8; 32])
+    );
+
+    // a VAA posted to the core bridge before the shim took over, and one
+    // delivered through the shim
+    let legacy_msg = make_transfer_message(&good_ntt, [1u8; 32], 1000, &test_data.user.pubkey());
+    redeem_via_legacy_transceiver(&mut ctx, &legacy_msg).await;
+    let shim_msg = make_transfer_message(&good_ntt, [2u8; 32], 1000, &test_data.user.pubkey());
+    redeem_via_shim_transceiver(&mut ctx, &shim_msg).await;
+
+    // with a threshold of 1, either attestation is enough to release
+    let balance = token_balance(&mut ctx, &test_data.user_token_account).await;
+    for msg in [legacy_msg, shim_msg] {
+        release_to_user(&ctx, &test_data, &msg)
+            .submit(&mut ctx)
+            .await
+            .unwrap();
+    }
+    assert_eq!(
+        token_balance(&mut ctx, &test_data.user_token_account).await,
+        balance + 2000
+    );
+}
+
+#[tokio::test]
+async fn test_threshold_across_legacy_and_shim_transceivers() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    register_legacy_transceiver(&mut ctx, &test_data).await;
+    fund_custody(&mut ctx, &test_data, 1000).await;
+
+    set_threshold(
+        &good_ntt,
+        SetThreshold {
+            owner: test_data.program_owner.pubkey(),
+        },
+        2,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let msg = make_transfer_message(&good_ntt, [1u8; 32], 1000, &test_data.user.pubkey());
+    let inbox_item = good_ntt.inbox_item(OTHER_CHAIN, msg.ntt_manager_payload.clone());
+
+    // a single attestation is not enough, even when it is repeated
+    for _ in 0..2 {
+        redeem_via_legacy_transceiver(&mut ctx, &msg).await;
+
+        let votes = ctx
+            .get_account_data_anchor::<InboxItem>(inbox_item)
+            .await
+            .votes;
+        assert_eq!(votes, Bitmap::from_value(0b10));
+        let err = release_to_user(&ctx, &test_data, &msg)
+            .submit(&mut ctx)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(NTTError::TransferNotApproved.into())
+            )
+        );
+    }
+
+    // the second transceiver's attestation is counted as another vote
+    redeem_via_shim_transceiver(&mut ctx, &msg).await;
+    let votes = ctx
+        .get_account_data_anchor::<InboxItem>(inbox_item)
+        .await
+        .votes;
+    assert_eq!(votes, Bitmap::from_value(0b11));
+
+    let balance = token_balance(&mut ctx, &test_data.user_token_account).await;
+    release_to_user(&ctx, &test_data, &msg)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    assert_eq!(
+        token_balance(&mut ctx, &test_data.user_token_account).await,
+        balance + 1000
+    );
+}
+
+#[tokio::test]
+async fn test_cleanup_expired_vaa_body() {
+    let (mut ctx, _test_data) = setup(Mode::Locking).await;
+
+    let payer = Keypair::new();
+    system_instruction::transfer(&ctx.payer.pubkey(), &payer.pubkey(), 1_000_000_000)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    let accounts = || UnverifiedMessageAccount {
+        payer: payer.pubkey(),
+    };
+    let vaa_body = |seed| good_ntt_transceiver.unverified_message_account(&payer.pubkey(), seed);
+
+    // the payer can close it straight away
+    post_unverified_message_account(&good_ntt_transceiver, accounts(), 1, vec![1u8; 100])
+        .submit_with_signers(&[&payer], &mut ctx)
+        .await
+        .unwrap();
+    close_unverified_message_account(&good_ntt_transceiver, accounts(), 1)
+        .submit_with_signers(&[&payer], &mut ctx)
+        .await
+        .unwrap();
+    assert!(ctx
+        .banks_client
+        .get_account(vaa_body(1))
+        .await
+        .unwrap()
+        .is_none());
+
+    // ...but anyone else has to wait for it to expire
+    post_unverified_message_account(&good_ntt_transceiver, accounts(), 2, vec![2u8; 100])
+        .submit_with_signers(&[&payer], &mut ctx)
+        .await
+        .unwrap();
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let staged: VaaBody = ctx.get_account_data_anchor(vaa_body(2)).await;
+    assert_eq!(staged.created_at, clock.unix_timestamp);
+
+    let err = cleanup_expired_vaa_body(&good_ntt_transceiver, accounts(), 2)
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::VaaBodyNotExpired.into())
+        )
+    );
+
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp = staged.created_at + VaaBody::TTL;
+    ctx.set_sysvar(&clock);
+
+    let rent = ctx.banks_client.get_balance(vaa_body(2)).await.unwrap();
+    let balance_before = ctx.banks_client.get_balance(payer.pubkey()).await.unwrap();
+    cleanup_expired_vaa_body(&good_ntt_transceiver, accounts(), 2)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    assert!(ctx
+        .banks_client
+        .get_account(vaa_body(2))
+        .await
+        .unwrap()
+        .is_none());
+    // the rent goes back to the payer, not the cranker
+    assert_eq!(
+        ctx.banks_client.get_balance(payer.pubkey()).await.unwrap(),
+        balance_before + rent
+    );
+
+    // the seed can be reused for a new message
+    post_unverified_message_account(&good_ntt_transceiver, accounts(), 2, vec![3u8; 100])
+        .submit_with_signers(&[&payer], &mut ctx)
+        .await
+        .unwrap();
+    let staged: VaaBody = ctx.get_account_data_anchor(vaa_body(2)).await;
+    assert_eq!(staged.span, vec![3u8; 100]);
+    assert_eq!(staged.created_at, clock.unix_timestamp);
 }
@@ -1,38 +1,64 @@
 #![cfg(feature = "test-sbf")]
-#![feature(type_changing_struct_update)]
 
 use anchor_lang::{prelude::Clock, AnchorDeserialize};
+use example_native_token_transfers::transceivers::wormhole::ReleaseOutboundArgs as LegacyReleaseOutboundArgs;
 use example_native_token_transfers::{
-    bitmap::Bitmap, error::NTTError, queue::outbox::OutboxItem, transfer::Payload,
+    bitmap::Bitmap,
+    config::{CONSISTENCY_LEVEL_CONFIRMED, CONSISTENCY_LEVEL_FINALIZED},
+    error::NTTError,
+    queue::outbox::OutboxItem,
+    transfer::Payload,
 };
 use ntt_messages::{
     chain_id::ChainId, mode::Mode, ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage,
     transceiver::TransceiverMessage, transceivers::wormhole::WormholeTransceiver,
     trimmed_amount::TrimmedAmount,
 };
-use ntt_transceiver::wormhole::instructions::release_outbound::ReleaseOutboundArgs;
+use ntt_transceiver::wormhole::instructions::release_outbound::{
+    ReleaseOutboundArgs, ReleaseOutboundOptions,
+};
 use solana_program_test::*;
 use solana_sdk::{
-    instruction::InstructionError, signature::Keypair, signer::Signer,
+    instruction::InstructionError, signature::Keypair, signer::Signer, system_instruction,
     transaction::TransactionError,
 };
 use test_utils::{
     common::{
-        fixtures::{TestData, OTHER_MANAGER, OUTBOUND_LIMIT},
+        fixtures::{TestData, OTHER_CHAIN, OTHER_MANAGER, OUTBOUND_LIMIT},
         query::GetAccountDataAnchor,
         submit::Submittable,
     },
-    helpers::{assert_queued, get_message_data, init_transfer_accs_args, setup},
+    helpers::{
+        assert_queued, assert_retry_after, get_emitter_sequence, get_message_data,
+        init_transfer_accs_args, register_legacy_transceiver, setup,
+    },
     sdk::{
         accounts::{good_ntt, NTTAccounts},
-        instructions::transfer::{approve_token_authority, transfer},
+        instructions::{
+            admin::{set_allowed_consistency_levels, SetAllowedConsistencyLevels},
+            transfer::{approve_token_authority, transfer},
+        },
         transceivers::{
-            accounts::good_ntt_transceiver,
-            instructions::release_outbound::{release_outbound, ReleaseOutbound},
+            accounts::{good_ntt_transceiver, NTTTransceiverAccounts},
+            instructions::release_outbound::{
+                release_outbound, release_outbound_with_fee_payer, release_outbound_with_options,
+                ReleaseOutbound,
+            },
+            legacy::{
+                accounts::good_ntt_transceiver as legacy_transceiver,
+                instructions::release_outbound::{
+                    release_outbound as legacy_release_outbound,
+                    ReleaseOutbound as LegacyReleaseOutbound,
+                },
+            },
         },
     },
 };
-use wormhole_svm_definitions::{EncodeFinality, Finality::Finalized};
+use wormhole_anchor_sdk::wormhole::BridgeData;
+use wormhole_svm_definitions::{
+    EncodeFinality,
+    Finality::{Confirmed, Finalized},
+};
 
 #[tokio::test]
 pub async fn test_transfer_locking() {
@@ -72,21 +98,19 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
 
     let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
 
-    assert_eq!(
-        outbox_item_account,
-        OutboxItem {
-            amount: TrimmedAmount {
-                amount: 1,
-                decimals: 7
-            },
-            sender: test_data.user.pubkey(),
-            recipient_chain: ChainId { id: 2 },
-            recipient_ntt_manager: OTHER_MANAGER,
-            recipient_address: [1u8; 32],
-            release_timestamp: clock.unix_timestamp,
-            released: Bitmap::new(),
-        }
+    let mut expected = OutboxItem::new(
+        TrimmedAmount {
+            amount: 1,
+            decimals: 7,
+        },
+        test_data.user.pubkey(),
+        ChainId { id: 2 },
+        OTHER_MANAGER,
+        [1u8; 32],
+        clock.unix_timestamp,
     );
+    expected.rent_payer = ctx.payer.pubkey();
+    assert_eq!(outbox_item_account, expected);
 
     let ix = release_outbound(
         &good_ntt,
@@ -94,6 +118,7 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
             outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
         },
         ReleaseOutboundArgs {
             revert_on_delay: true,
@@ -103,17 +128,19 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
     // simulate to fetch data before submitting ix
     let msg = get_message_data(&good_ntt.wormhole(), &good_ntt_transceiver, ctx, ix.clone()).await;
     ix.submit(ctx).await.unwrap();
+    assert_eq!(
+        get_emitter_sequence(&good_ntt.wormhole(), ctx, &good_ntt_transceiver.emitter()).await,
+        msg.sequence + 1
+    );
 
     // make sure the outbox item is now released, but nothing else has changed
     let outbox_item_account_after: OutboxItem =
         ctx.get_account_data_anchor(outbox_item.pubkey()).await;
-    assert_eq!(
-        OutboxItem {
-            released: Bitmap::from_value(1),
-            ..outbox_item_account
-        },
-        outbox_item_account_after,
-    );
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let mut expected = outbox_item_account;
+    expected.released = Bitmap::from_value(1);
+    expected.released_at[0] = clock.unix_timestamp;
+    assert_eq!(expected, outbox_item_account_after);
 
     assert_eq!(msg.nonce, 0); // hardcoded
     assert_eq!(msg.consistency_level, Finalized.encode()); // hardcoded
@@ -129,6 +156,7 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
                 id: outbox_item.pubkey().to_bytes(),
                 sender: test_data.user.pubkey().to_bytes(),
                 payload: NativeTokenTransfer {
+                    version: NativeTokenTransfer::<Payload>::CURRENT_VERSION,
                     amount: TrimmedAmount {
                         amount: 1,
                         decimals: 7
@@ -176,6 +204,25 @@ async fn test_cant_release_queued() {
 
     assert_queued(&mut ctx, outbox_item.pubkey()).await;
 
+    // the failed simulation reports how long until the transfer can be released
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    let out = release_outbound(
+        &good_ntt,
+        &good_ntt_transceiver,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .simulate(&mut ctx)
+    .await
+    .unwrap();
+    assert_retry_after(&mut ctx, &out, outbox_item_account.release_timestamp).await;
+
     // check that 'revert_on_delay = true' returns correct error
     let err = release_outbound(
         &good_ntt,
@@ -183,6 +230,7 @@ async fn test_cant_release_queued() {
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
             outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
         },
         ReleaseOutboundArgs {
             revert_on_delay: true,
@@ -207,6 +255,7 @@ async fn test_cant_release_queued() {
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
             outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
         },
         ReleaseOutboundArgs {
             revert_on_delay: false,
@@ -254,6 +303,7 @@ async fn test_cant_release_twice() {
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
             outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
         },
         ReleaseOutboundArgs {
             revert_on_delay: true,
@@ -270,6 +320,7 @@ async fn test_cant_release_twice() {
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
             outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
         },
         ReleaseOutboundArgs {
             revert_on_delay: true,
@@ -287,3 +338,378 @@ async fn test_cant_release_twice() {
         )
     );
 }
+
+#[tokio::test]
+async fn test_release_outbound_max_wormhole_fee() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        100,
+        false,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let bridge: BridgeData = ctx
+        .get_account_data_anchor(good_ntt.wormhole().bridge())
+        .await;
+    let fee = bridge.fee();
+    assert!(fee > 0);
+
+    let payer = ctx.payer.pubkey();
+    let release = |max_wormhole_fee| {
+        release_outbound_with_options(
+            &good_ntt,
+            &good_ntt_transceiver,
+            ReleaseOutbound {
+                payer,
+                outbox_item: outbox_item.pubkey(),
+                recipient_chain: OTHER_CHAIN,
+            },
+            ReleaseOutboundArgs {
+                revert_on_delay: true,
+            },
+            ReleaseOutboundOptions {
+                max_wormhole_fee: Some(max_wormhole_fee),
+                consistency_level: None,
+            },
+        )
+    };
+
+    let err = release(fee - 1).submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::WormholeFeeTooHigh.into())
+        )
+    );
+
+    release(fee).submit(&mut ctx).await.unwrap();
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert!(outbox_item_account.released.get(0).unwrap());
+}
+
+#[tokio::test]
+async fn test_release_outbound_separate_fee_payer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let first = Keypair::new();
+    let second = Keypair::new();
+    for outbox_item in [&first, &second] {
+        let (accs, args) = init_transfer_accs_args(
+            &good_ntt,
+            &mut ctx,
+            &test_data,
+            outbox_item.pubkey(),
+            100,
+            false,
+        );
+
+        approve_token_authority(
+            &good_ntt,
+            &test_data.user_token_account,
+            &test_data.user.pubkey(),
+            &args,
+        )
+        .submit_with_signers(&[&test_data.user], &mut ctx)
+        .await
+        .unwrap();
+        transfer(&good_ntt, accs, args, Mode::Locking)
+            .submit_with_signers(&[outbox_item], &mut ctx)
+            .await
+            .unwrap();
+    }
+
+    let release_args = || ReleaseOutboundArgs {
+        revert_on_delay: true,
+    };
+
+    // the first message also creates the emitter's sequence account
+    release_outbound(
+        &good_ntt,
+        &good_ntt_transceiver,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item: first.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        release_args(),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let bridge: BridgeData = ctx
+        .get_account_data_anchor(good_ntt.wormhole().bridge())
+        .await;
+    let fee = bridge.fee();
+    assert!(fee > 0);
+
+    let rent_payer = Keypair::new();
+    let fee_payer = Keypair::new();
+    for signer in [&rent_payer, &fee_payer] {
+        system_instruction::transfer(&ctx.payer.pubkey(), &signer.pubkey(), 1_000_000_000)
+            .submit(&mut ctx)
+            .await
+            .unwrap();
+    }
+
+    let fee_collector = good_ntt.wormhole().fee_collector();
+    let mut balances = vec![];
+    for account in [rent_payer.pubkey(), fee_payer.pubkey(), fee_collector] {
+        balances.push(ctx.banks_client.get_balance(account).await.unwrap());
+    }
+
+    // the transaction fee is paid by the context's payer
+    release_outbound_with_fee_payer(
+        &good_ntt,
+        &good_ntt_transceiver,
+        ReleaseOutbound {
+            payer: rent_payer.pubkey(),
+            outbox_item: second.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        release_args(),
+        Some(fee_payer.pubkey()),
+    )
+    .submit_with_signers(&[&rent_payer, &fee_payer], &mut ctx)
+    .await
+    .unwrap();
+
+    // the message account is reused, so there is no rent to pay this time
+    let message_rent = 0;
+
+    assert_eq!(
+        ctx.banks_client
+            .get_balance(rent_payer.pubkey())
+            .await
+            .unwrap(),
+        balances[0] - message_rent
+    );
+    assert_eq!(
+        ctx.banks_client
+            .get_balance(fee_payer.pubkey())
+            .await
+            .unwrap(),
+        balances[1] - fee
+    );
+    assert_eq!(
+        ctx.banks_client.get_balance(fee_collector).await.unwrap(),
+        balances[2] + fee
+    );
+}
+
+#[tokio::test]
+async fn test_release_outbound_consistency_level() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        154,
+        false,
+    );
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let payer = ctx.payer.pubkey();
+    let release = || {
+        release_outbound_with_options(
+            &good_ntt,
+            &good_ntt_transceiver,
+            ReleaseOutbound {
+                payer,
+                outbox_item: outbox_item.pubkey(),
+                recipient_chain: OTHER_CHAIN,
+            },
+            ReleaseOutboundArgs {
+                revert_on_delay: true,
+            },
+            ReleaseOutboundOptions {
+                max_wormhole_fee: None,
+                consistency_level: Some(CONSISTENCY_LEVEL_CONFIRMED),
+            },
+        )
+    };
+    let set_allowed = |consistency_levels| {
+        set_allowed_consistency_levels(
+            &good_ntt,
+            SetAllowedConsistencyLevels {
+                owner: test_data.program_owner.pubkey(),
+            },
+            consistency_levels,
+        )
+    };
+
+    // only finalized is allowed by default
+    let err = release().submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::ConsistencyLevelNotAllowed.into())
+        )
+    );
+
+    let err = set_allowed(vec![CONSISTENCY_LEVEL_FINALIZED, 200])
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::UnsupportedConsistencyLevel.into())
+        )
+    );
+
+    set_allowed(vec![
+        CONSISTENCY_LEVEL_FINALIZED,
+        CONSISTENCY_LEVEL_CONFIRMED,
+    ])
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let ix = release();
+    let msg = get_message_data(
+        &good_ntt.wormhole(),
+        &good_ntt_transceiver,
+        &mut ctx,
+        ix.clone(),
+    )
+    .await;
+    ix.submit(&mut ctx).await.unwrap();
+    assert_eq!(msg.consistency_level, Confirmed.encode());
+}
+
+#[tokio::test]
+async fn test_release_outbound_via_legacy_and_shim_transceivers() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    register_legacy_transceiver(&mut ctx, &test_data).await;
+
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_transfer_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        154,
+        false,
+    );
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let payer = ctx.payer.pubkey();
+    let shim_release = || {
+        release_outbound(
+            &good_ntt,
+            &good_ntt_transceiver,
+            ReleaseOutbound {
+                payer,
+                outbox_item: outbox_item.pubkey(),
+                recipient_chain: OTHER_CHAIN,
+            },
+            ReleaseOutboundArgs {
+                revert_on_delay: true,
+            },
+        )
+    };
+
+    // the shim can't release under the legacy transceiver's registration
+    let mut ix = shim_release();
+    let registered_legacy = good_ntt.registered_transceiver(&good_ntt.program());
+    ix.accounts
+        .iter_mut()
+        .find(|meta| {
+            meta.pubkey == good_ntt.registered_transceiver(&good_ntt_transceiver.program())
+        })
+        .unwrap()
+        .pubkey = registered_legacy;
+    let err = ix.submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidTransceiverProgram.into())
+        )
+    );
+
+    // the shim was registered first (id 0), the legacy transceiver second (id 1)
+    shim_release().submit(&mut ctx).await.unwrap();
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert_eq!(outbox_item_account.released, Bitmap::from_value(0b01));
+
+    legacy_release_outbound(
+        &good_ntt,
+        &legacy_transceiver,
+        LegacyReleaseOutbound {
+            payer,
+            outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        LegacyReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert_eq!(outbox_item_account.released, Bitmap::from_value(0b11));
+
+    // each transceiver releases once
+    let err = shim_release().submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::MessageAlreadySent.into())
+        )
+    );
+}
@@ -8,4 +8,12 @@ pub enum GovernanceError {
     InvalidGovernanceEmitter,
     #[msg("InvalidGovernanceProgram")]
     InvalidGovernanceProgram,
+    #[msg("MissingAccount")]
+    MissingAccount,
+    #[msg("AccountFlagsMismatch")]
+    AccountFlagsMismatch,
+    #[msg("InvalidNttConfig")]
+    InvalidNttConfig,
+    #[msg("NotConfigOwner")]
+    NotConfigOwner,
 }
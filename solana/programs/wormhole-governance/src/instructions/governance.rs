@@ -10,8 +10,13 @@
 //!
 //! The instruction needs to be encoded in the VAA payload, with all the
 //! accounts. These accounts may be in any order and may include two additional placeholder accounts:
-//! - [`OWNER`]: the program will replace this account with the governance PDA
-//! - [`PAYER`]: the program will replace this account with the payer account
+//! - [`OWNER`]: the program will replace this account with the governance PDA (as a signer)
+//! - [`PAYER`]: the program will replace this account with the payer account (as a writable signer)
+//!
+//! All other accounts are taken literally, and have to be passed to the governance
+//! instruction with (at least) the signer and writable flags requested in the payload.
+//! This is checked before invoking the governed program, so that a badly
+//! assembled transaction fails with a descriptive error.
 use std::io;
 
 use anchor_lang::prelude::*;
@@ -105,7 +110,7 @@ pub struct GovernanceMessage {
 
 impl GovernanceMessage {
     // "GeneralPurposeGovernance" (left padded)
-    const MODULE: [u8; 32] = [
+    pub(crate) const MODULE: [u8; 32] = [
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x47, 0x65, 0x6E, 0x65, 0x72, 0x61, 0x6C,
         0x50, 0x75, 0x72, 0x70, 0x6F, 0x73, 0x65, 0x47, 0x6F, 0x76, 0x65, 0x72, 0x6E, 0x61, 0x6E,
         0x63, 0x65,
@@ -322,6 +327,26 @@ fn test_governance_message_parse_guardian() {
     assert_eq!(actual, expected)
 }
 
+#[test]
+fn test_governance_message_malformed() {
+    // the guardian message above
+    let h = hex::decode("000000000000000047656e6572616c507572706f7365476f7665726e616e63650200010e027fbc6b1e61365d4b0680a3179f791b15796f93e24e9b441e3fa04ccda4a000000000000000010000000000000000000000000000000000000000000000000002000000000000000200000000000000000000000000000000000000000000000001010000000000000003000000000000000000000000000000000000000000000000000100050102030405").unwrap();
+
+    // every truncation is rejected
+    for len in 0..h.len() {
+        assert!(GovernanceMessage::deserialize(&mut &h[..len]).is_err());
+    }
+
+    // and no mutation panics
+    for i in 0..h.len() {
+        for byte in [0x00, 0x01, 0x02, 0x7f, 0x80, 0xff] {
+            let mut mutated = h.clone();
+            mutated[i] = byte;
+            let _ = GovernanceMessage::deserialize(&mut mutated.as_slice());
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 /// The known set of governance actions.
 ///
@@ -338,6 +363,7 @@ pub enum GovernanceAction {
     Undefined,
     EvmCall,
     SolanaCall,
+    SolanaSetOutboundLimit,
 }
 
 impl Readable for GovernanceAction {
@@ -352,6 +378,7 @@ impl Readable for GovernanceAction {
             0u8 => Ok(GovernanceAction::Undefined),
             1 => Ok(GovernanceAction::EvmCall),
             2 => Ok(GovernanceAction::SolanaCall),
+            3 => Ok(GovernanceAction::SolanaSetOutboundLimit),
             n => Err(io::Error::new(
                 io::ErrorKind::InvalidData,
                 format!("invalid action {}", n),
@@ -373,6 +400,7 @@ impl Writeable for GovernanceAction {
             GovernanceAction::Undefined => Ok(()),
             GovernanceAction::EvmCall => 1u8.write(writer),
             GovernanceAction::SolanaCall => 2u8.write(writer),
+            GovernanceAction::SolanaSetOutboundLimit => 3u8.write(writer),
         }
     }
 }
@@ -459,6 +487,8 @@ impl From<AccountMeta> for Acc {
 /// NOTE: The VAA instruction may contain placeholder accounts with Pubkeys set to hard-coded values [OWNER] and [PAYER].
 /// These keys are overwritten. Because they are placeholders, we do not need to enforce e.g.
 ///  ownership checks.
+/// The remaining (literal) accounts are looked up among the accounts passed to
+/// this instruction, and their flags are checked against the payload.
 pub fn governance<'info>(ctx: Context<'_, '_, '_, 'info, Governance<'info>>) -> Result<()> {
     let vaa_data = ctx.accounts.vaa.data();
 
@@ -468,22 +498,38 @@ pub fn governance<'info>(ctx: Context<'_, '_, '_, 'info, Governance<'info>>) ->
         bump: ctx.bumps.replay,
     });
 
+    let mut all_account_infos = ctx.accounts.to_account_infos();
+    all_account_infos.extend_from_slice(ctx.remaining_accounts);
+
+    let governance = ctx.accounts.governance.key();
+
     // Iterate over a copy of all accounts provided in the VAA payload.
     // If the Pubkey for an account is equal to the hard-coded OWNER constant, overwrite with the
-    // governance program's Pubkey.
+    // governance program's Pubkey. It's signed for below, so it's always a signer.
     // If the Pubkey for an account is equal to the hard-coded PAYER constant, overwrite with the
     // payer program account's pubkey. (This must also be the Signer for the creator of the
     // Governance account.)
-    instruction.accounts.iter_mut().for_each(|acc| {
+    // Any other account has to be provided with the flags requested in the payload.
+    for acc in instruction.accounts.iter_mut() {
         if acc.pubkey == OWNER {
-            acc.pubkey = ctx.accounts.governance.key();
+            acc.pubkey = governance;
+            acc.is_signer = true;
         } else if acc.pubkey == PAYER {
             acc.pubkey = ctx.accounts.payer.key();
+            acc.is_signer = true;
+            acc.is_writable = true;
+        } else {
+            let info = all_account_infos
+                .iter()
+                .find(|info| *info.key == acc.pubkey)
+                .ok_or(GovernanceError::MissingAccount)?;
+            let can_sign = info.is_signer || *info.key == governance;
+            require!(
+                (!acc.is_signer || can_sign) && (!acc.is_writable || info.is_writable),
+                GovernanceError::AccountFlagsMismatch
+            );
         }
-    });
-
-    let mut all_account_infos = ctx.accounts.to_account_infos();
-    all_account_infos.extend_from_slice(ctx.remaining_accounts);
+    }
 
     solana_program::program::invoke_signed(
         &instruction,
@@ -1,3 +1,5 @@
 pub mod governance;
+pub mod set_outbound_limit;
 
 pub use governance::*;
+pub use set_outbound_limit::*;
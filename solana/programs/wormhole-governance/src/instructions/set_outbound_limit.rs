@@ -0,0 +1,303 @@
+//! Typed governance action to set the outbound limit of an NTT manager.
+//!
+//! Unlike the general purpose [`super::governance`] instruction, the payload
+//! doesn't carry an arbitrary instruction, just the NTT manager program and the
+//! new limit. The governance program assembles the `set_outbound_limit`
+//! instruction itself, and checks that its governance PDA is the owner of the
+//! manager's config before invoking it.
+//!
+//! The manager's accounts are validated by hand (rather than by depending on
+//! the manager crate), so that this program stays independent of any specific
+//! deployment.
+use std::io;
+
+use anchor_lang::{prelude::*, solana_program::hash};
+use solana_program::instruction::Instruction;
+use wormhole_anchor_sdk::wormhole::PostedVaa;
+use wormhole_io::{Readable, Writeable};
+use wormhole_sdk::{Chain, GOVERNANCE_EMITTER};
+
+use crate::error::GovernanceError;
+
+use super::{GovernanceAction, GovernanceMessage, ReplayProtection};
+
+/// Seed of the NTT manager's config account.
+const NTT_CONFIG_SEED: &[u8] = b"config";
+
+/// Seed of the NTT manager's global outbound rate limit account.
+const NTT_OUTBOX_RATE_LIMIT_SEED: &[u8] = b"outbox_rate_limit";
+
+#[derive(Accounts)]
+pub struct SetOutboundLimit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"governance"],
+        bump,
+    )]
+    /// CHECK: governance PDA. This PDA has to be the owner of the NTT manager's
+    /// config, which is checked in the handler.
+    pub governance: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = vaa.emitter_chain() == Into::<u16>::into(Chain::Solana) @ GovernanceError::InvalidGovernanceChain,
+        constraint = *vaa.emitter_address() == GOVERNANCE_EMITTER.0 @ GovernanceError::InvalidGovernanceEmitter,
+        constraint = vaa.payload.1.governance_program_id == crate::ID @ GovernanceError::InvalidGovernanceProgram,
+    )]
+    pub vaa: Account<'info, PostedVaa<SetOutboundLimitMessage>>,
+
+    #[account(
+        executable,
+        address = vaa.payload.1.ntt_manager,
+    )]
+    /// CHECK: the address is checked against the payload.
+    pub ntt_manager: UncheckedAccount<'info>,
+
+    #[account(
+        owner = ntt_manager.key(),
+        seeds = [NTT_CONFIG_SEED],
+        bump,
+        seeds::program = ntt_manager.key(),
+    )]
+    /// CHECK: the NTT manager's config. The discriminator and the owner field
+    /// are checked in the handler.
+    pub config: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [NTT_OUTBOX_RATE_LIMIT_SEED],
+        bump,
+        seeds::program = ntt_manager.key(),
+    )]
+    /// CHECK: the NTT manager's outbound rate limit. Validated by the manager.
+    pub outbox_rate_limit: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        space = 8 + ReplayProtection::INIT_SPACE,
+        payer = payer,
+        seeds = [
+            ReplayProtection::SEED_PREFIX,
+            vaa.emitter_chain().to_be_bytes().as_ref(),
+            vaa.emitter_address().as_ref(),
+            vaa.sequence().to_be_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub replay: Account<'info, ReplayProtection>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Governance message to set the outbound limit of an NTT manager.
+///
+/// The wire format for this message is:
+/// | field       | size (bytes) | description                           |
+/// |-------------+--------------+---------------------------------------|
+/// | MODULE      |           32 | Governance module identifier          |
+/// | ACTION      |            1 | Governance action identifier          |
+/// | CHAIN       |            2 | Chain identifier                      |
+/// |-------------+--------------+---------------------------------------|
+/// | ntt_manager |           32 | Program ID of the NTT manager         |
+/// | limit       |            8 | New outbound limit (big endian)       |
+///
+/// The header is the same as in [`GovernanceMessage`], with the action set to
+/// [`GovernanceAction::SolanaSetOutboundLimit`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetOutboundLimitMessage {
+    pub governance_program_id: Pubkey,
+    pub ntt_manager: Pubkey,
+    pub limit: u64,
+}
+
+impl SetOutboundLimitMessage {
+    /// Assembles the NTT manager's `set_outbound_limit` instruction, with
+    /// `authority` as the signer.
+    pub fn instruction(
+        &self,
+        config: Pubkey,
+        authority: Pubkey,
+        outbox_rate_limit: Pubkey,
+    ) -> Instruction {
+        let mut data = hash::hash(b"global:set_outbound_limit").to_bytes()[..8].to_vec();
+        // borsh encoding of `SetOutboundLimitArgs { limit }`
+        data.extend_from_slice(&self.limit.to_le_bytes());
+
+        Instruction {
+            program_id: self.ntt_manager,
+            accounts: vec![
+                AccountMeta::new_readonly(config, false),
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new(outbox_rate_limit, false),
+            ],
+            data,
+        }
+    }
+}
+
+impl AnchorDeserialize for SetOutboundLimitMessage {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        Readable::read(reader)
+    }
+}
+
+impl AnchorSerialize for SetOutboundLimitMessage {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Writeable::write(self, writer)
+    }
+}
+
+impl Readable for SetOutboundLimitMessage {
+    const SIZE: Option<usize> = Some(32 + 1 + 2 + 32 + 32 + 8);
+
+    fn read<R>(reader: &mut R) -> io::Result<Self>
+    where
+        Self: Sized,
+        R: io::Read,
+    {
+        let module: [u8; 32] = Readable::read(reader)?;
+        if module != GovernanceMessage::MODULE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Invalid SetOutboundLimitMessage module",
+            ));
+        }
+        let action: GovernanceAction = Readable::read(reader)?;
+        if action != GovernanceAction::SolanaSetOutboundLimit {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Invalid GovernanceAction",
+            ));
+        }
+        let chain: u16 = Readable::read(reader)?;
+        if Chain::from(chain) != Chain::Solana {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Invalid SetOutboundLimitMessage chain",
+            ));
+        }
+        let governance_program_id = Pubkey::new_from_array(Readable::read(reader)?);
+        let ntt_manager = Pubkey::new_from_array(Readable::read(reader)?);
+        let limit: u64 = Readable::read(reader)?;
+
+        Ok(SetOutboundLimitMessage {
+            governance_program_id,
+            ntt_manager,
+            limit,
+        })
+    }
+}
+
+impl Writeable for SetOutboundLimitMessage {
+    fn written_size(&self) -> usize {
+        Self::SIZE.unwrap()
+    }
+
+    fn write<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let SetOutboundLimitMessage {
+            governance_program_id,
+            ntt_manager,
+            limit,
+        } = self;
+
+        GovernanceMessage::MODULE.write(writer)?;
+        GovernanceAction::SolanaSetOutboundLimit.write(writer)?;
+        u16::from(Chain::Solana).write(writer)?;
+        governance_program_id.to_bytes().write(writer)?;
+        ntt_manager.to_bytes().write(writer)?;
+        limit.write(writer)
+    }
+}
+
+#[test]
+fn test_set_outbound_limit_message_serde() {
+    let msg = SetOutboundLimitMessage {
+        governance_program_id: crate::ID,
+        ntt_manager: Pubkey::new_unique(),
+        limit: 0x0102030405060708,
+    };
+
+    let mut buf = Vec::new();
+    msg.serialize(&mut buf).unwrap();
+    assert_eq!(buf.len(), msg.written_size());
+    // the action and the big endian limit
+    assert_eq!(buf[32], 3);
+    assert_eq!(&buf[buf.len() - 8..], &[1, 2, 3, 4, 5, 6, 7, 8]);
+
+    let msg2 = SetOutboundLimitMessage::deserialize(&mut buf.as_slice()).unwrap();
+    assert_eq!(msg, msg2);
+}
+
+#[test]
+fn test_set_outbound_limit_message_malformed() {
+    let msg = SetOutboundLimitMessage {
+        governance_program_id: crate::ID,
+        ntt_manager: Pubkey::new_unique(),
+        limit: 1234,
+    };
+    let mut buf = Vec::new();
+    msg.serialize(&mut buf).unwrap();
+
+    // every truncation is rejected
+    for len in 0..buf.len() {
+        assert!(SetOutboundLimitMessage::deserialize(&mut &buf[..len]).is_err());
+    }
+
+    // a general purpose call is not a set outbound limit action, and vice versa
+    let mut call = buf.clone();
+    call[32] = 2;
+    assert!(SetOutboundLimitMessage::deserialize(&mut call.as_slice()).is_err());
+    assert!(GovernanceMessage::deserialize(&mut buf.as_slice()).is_err());
+}
+
+/// Reads the owner out of the NTT manager's config, after checking the
+/// discriminator. The config starts with the bump, followed by the owner.
+fn config_owner(config: &UncheckedAccount) -> Result<Pubkey> {
+    let discriminator = &hash::hash(b"account:Config").to_bytes()[..8];
+    let data = config.try_borrow_data()?;
+    require!(
+        data.len() >= 8 + 1 + 32 && data.starts_with(discriminator),
+        GovernanceError::InvalidNttConfig
+    );
+
+    Ok(Pubkey::new_from_array(data[9..41].try_into().unwrap()))
+}
+
+/// Processes a [`SetOutboundLimitMessage`] VAA by invoking the NTT manager's
+/// `set_outbound_limit` instruction, signed by the governance PDA.
+pub fn set_outbound_limit(ctx: Context<SetOutboundLimit>) -> Result<()> {
+    let governance = ctx.accounts.governance.key();
+
+    require_keys_eq!(
+        config_owner(&ctx.accounts.config)?,
+        governance,
+        GovernanceError::NotConfigOwner
+    );
+
+    ctx.accounts.replay.set_inner(ReplayProtection {
+        bump: ctx.bumps.replay,
+    });
+
+    let instruction = ctx.accounts.vaa.data().instruction(
+        ctx.accounts.config.key(),
+        governance,
+        ctx.accounts.outbox_rate_limit.key(),
+    );
+
+    solana_program::program::invoke_signed(
+        &instruction,
+        &[
+            ctx.accounts.config.to_account_info(),
+            ctx.accounts.governance.to_account_info(),
+            ctx.accounts.outbox_rate_limit.to_account_info(),
+            ctx.accounts.ntt_manager.to_account_info(),
+        ],
+        &[&[b"governance", &[ctx.bumps.governance]]],
+    )?;
+
+    Ok(())
+}
@@ -14,4 +14,8 @@ pub mod wormhole_governance {
     pub fn governance<'info>(ctx: Context<'_, '_, '_, 'info, Governance<'info>>) -> Result<()> {
         instructions::governance(ctx)
     }
+
+    pub fn set_outbound_limit(ctx: Context<SetOutboundLimit>) -> Result<()> {
+        instructions::set_outbound_limit(ctx)
+    }
 }
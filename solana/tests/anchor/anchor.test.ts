@@ -261,7 +261,7 @@ describe("example-native-token-transfers", () => {
         outboxItem.publicKey
       );
       assert
-        .bn(outboxItemInfo.released.map)
+        .bn(new anchor.BN(outboxItemInfo.released.map, "le"))
         .setBits(Object.keys(nttTransceivers).length);
 
       // parse event and instruction data to re-build message
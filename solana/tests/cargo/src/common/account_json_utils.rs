@@ -1,8 +1,8 @@
 use anchor_lang::{prelude::Pubkey, AnchorDeserialize};
 use base64::Engine;
 use serde::{Deserialize, Serialize};
-use solana_program_test::ProgramTest;
-use std::{io::Error, str::FromStr};
+use solana_program_test::{ProgramTest, ProgramTestContext};
+use std::{io::Error, path::Path, str::FromStr};
 
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -90,3 +90,69 @@ pub fn add_account_unchecked(
     };
     Ok(result)
 }
+
+/// Writes the account at `pubkey` to `path`, in the JSON format of
+/// `solana account --output json` that [`add_account_unchecked`] reads. This is
+/// how account images are captured from one program version to be loaded
+/// into tests of another, e.g. to check that upgrades can still read the
+/// accounts created before them.
+pub async fn dump_account_state(
+    ctx: &mut ProgramTestContext,
+    pubkey: Pubkey,
+    path: impl AsRef<Path>,
+) -> Result<(), Error> {
+    let account = ctx
+        .banks_client
+        .get_account(pubkey)
+        .await
+        .map_err(|e| Error::new(std::io::ErrorKind::Other, e))?
+        .ok_or_else(|| {
+            Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("Account not found: {}", pubkey),
+            )
+        })?;
+
+    let json = Account {
+        pubkey: pubkey.to_string(),
+        account: AccountData {
+            lamports: account.lamports,
+            space: account.data.len() as u64,
+            data: (
+                base64::engine::general_purpose::STANDARD.encode(&account.data),
+                "base64".to_string(),
+            ),
+            owner: account.owner.to_string(),
+            executable: account.executable,
+            rent_epoch: account.rent_epoch,
+        },
+    };
+    std::fs::write(path, serde_json::to_string_pretty(&json)?)
+}
+
+/// Adds every account dumped into `dir` (see [`dump_account_state`]) to the
+/// program test. Returns the accounts that were added.
+pub fn add_accounts_from_dir(
+    program_test: &mut ProgramTest,
+    dir: impl AsRef<Path>,
+) -> Result<Vec<Account<Vec<u8>>>, Error> {
+    let mut paths = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    paths.retain(|path| path.extension().is_some_and(|ext| ext == "json"));
+    // sorted, so that the accounts are added in a deterministic order
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let path = path.to_str().ok_or_else(|| {
+                Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("Non UTF-8 path: {}", path.display()),
+                )
+            })?;
+            add_account_unchecked(program_test, path)
+        })
+        .collect()
+}
@@ -11,14 +11,31 @@ pub const INBOUND_LIMIT: u64 = 50000;
 
 pub const OTHER_TRANSCEIVER: [u8; 32] = [7u8; 32];
 pub const ANOTHER_TRANSCEIVER: [u8; 32] = [8u8; 32];
-pub const OTHER_MANAGER: [u8; 32] = [9u8; 32];
+/// An EVM manager, as [`OTHER_CHAIN`] is an EVM chain.
+pub const OTHER_MANAGER: [u8; 32] = evm_address([9u8; 20]);
 pub const ANOTHER_MANAGER: [u8; 32] = [5u8; 32];
+/// The manager of the second NTT deployment in the tilt devnet (see the
+/// `tilt-devnet2` feature of the manager program), i.e. another manager on
+/// this chain.
+pub const SECOND_SOLANA_MANAGER: Pubkey =
+    solana_program::pubkey!("NTTManager222222222222222222222222222222222");
 
 pub const THIS_CHAIN: u16 = 1;
 pub const OTHER_CHAIN: u16 = 2;
 pub const ANOTHER_CHAIN: u16 = 3;
 pub const UNREGISTERED_CHAIN: u16 = u16::MAX;
 
+/// `address` as a universal address, i.e. left-padded with zeros.
+pub const fn evm_address(address: [u8; 20]) -> [u8; 32] {
+    let mut universal = [0u8; 32];
+    let mut i = 0;
+    while i < address.len() {
+        universal[12 + i] = address[i];
+        i += 1;
+    }
+    universal
+}
+
 pub struct TestData {
     pub governance: Governance,
     pub program_owner: Keypair,
@@ -1,8 +1,8 @@
 use solana_banks_interface::BanksTransactionResultWithSimulation;
 use solana_program_test::{BanksClientError, ProgramTestBanksClientExt, ProgramTestContext};
 use solana_sdk::{
-    instruction::Instruction, signature::Keypair, signer::Signer, signers::Signers,
-    transaction::Transaction,
+    compute_budget::ComputeBudgetInstruction, instruction::Instruction, signature::Keypair,
+    signer::Signer, signers::Signers, transaction::Transaction,
 };
 
 pub trait Submittable {
@@ -130,3 +130,59 @@ impl Submittable for Transaction {
         ctx.banks_client.simulate_transaction(self).await
     }
 }
+
+/// An instruction submitted after a
+/// [`ComputeBudgetInstruction::set_compute_unit_limit`], for instructions that
+/// need more than the default compute budget. See
+/// [`ComputeUnitLimit::with_compute_unit_limit`].
+pub struct WithComputeUnitLimit {
+    instruction: Instruction,
+    units: u32,
+}
+
+pub trait ComputeUnitLimit {
+    fn with_compute_unit_limit(self, units: u32) -> WithComputeUnitLimit;
+}
+
+impl ComputeUnitLimit for Instruction {
+    fn with_compute_unit_limit(self, units: u32) -> WithComputeUnitLimit {
+        WithComputeUnitLimit {
+            instruction: self,
+            units,
+        }
+    }
+}
+
+impl WithComputeUnitLimit {
+    fn into_transaction(self, ctx: &ProgramTestContext) -> Transaction {
+        Transaction::new_with_payer(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(self.units),
+                self.instruction,
+            ],
+            Some(&ctx.payer.pubkey()),
+        )
+    }
+}
+
+impl Submittable for WithComputeUnitLimit {
+    async fn submit_with_signers<T: Signers + ?Sized>(
+        self,
+        signers: &T,
+        ctx: &mut ProgramTestContext,
+    ) -> Result<(), BanksClientError> {
+        self.into_transaction(ctx)
+            .submit_with_signers(signers, ctx)
+            .await
+    }
+
+    async fn simulate_with_signers<T: Signers + ?Sized>(
+        self,
+        signers: &T,
+        ctx: &mut ProgramTestContext,
+    ) -> Result<BanksTransactionResultWithSimulation, BanksClientError> {
+        self.into_transaction(ctx)
+            .simulate_with_signers(signers, ctx)
+            .await
+    }
+}
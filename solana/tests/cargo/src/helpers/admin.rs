@@ -1,10 +1,28 @@
-use anchor_lang::prelude::Pubkey;
+use anchor_lang::{prelude::Pubkey, AnchorDeserialize};
 use example_native_token_transfers::{
-    config::Config, registered_transceiver::RegisteredTransceiver,
+    config::Config,
+    instructions::{CustodyReconciliation, PeerDecimals},
+    peer_stats::LatencyStats,
+    registered_transceiver::RegisteredTransceiver,
 };
+use ntt_messages::mode::Mode;
 use solana_program_test::ProgramTestContext;
 
-use crate::{common::query::GetAccountDataAnchor, sdk::accounts::NTT};
+use crate::{
+    common::{query::GetAccountDataAnchor, submit::Submittable},
+    sdk::{
+        accounts::NTT,
+        instructions::{
+            admin::{get_configured_chains, get_peer_index, get_pending_owner},
+            enabled_transceivers::get_enabled_transceivers,
+            initialize::{preflight_initialize, PreflightInitialize},
+            peer_decimals::{get_peer_decimals, GetPeerDecimals},
+            peer_stats::get_stats,
+            reconcile_custody::{reconcile_custody, ReconcileCustody},
+        },
+        transceivers::{accounts::NTTTransceiver, instructions::admin::get_transceiver_peer_index},
+    },
+};
 
 pub async fn assert_threshold(ntt: &NTT, ctx: &mut ProgramTestContext, expected_threshold: u8) {
     let config_account: Config = ctx.get_account_data_anchor(ntt.config()).await;
@@ -26,3 +44,112 @@ pub async fn assert_transceiver_id(
     );
     assert_eq!(registered_transceiver_account.id, expected_id);
 }
+
+pub async fn enabled_transceivers(
+    ntt: &NTT,
+    ctx: &mut ProgramTestContext,
+    transceivers: &[Pubkey],
+) -> Vec<Pubkey> {
+    let out = get_enabled_transceivers(ntt, transceivers)
+        .simulate(ctx)
+        .await
+        .unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    Vec::<Pubkey>::deserialize(&mut &return_data[..]).unwrap()
+}
+
+pub async fn pending_owner(ntt: &NTT, ctx: &mut ProgramTestContext) -> Option<Pubkey> {
+    let out = get_pending_owner(ntt).simulate(ctx).await.unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    Option::<Pubkey>::deserialize(&mut &return_data[..]).unwrap()
+}
+
+pub async fn peer_decimals(
+    ntt: &NTT,
+    ctx: &mut ProgramTestContext,
+    mint: Pubkey,
+    chain_id: u16,
+) -> PeerDecimals {
+    let out = get_peer_decimals(ntt, GetPeerDecimals { mint, chain_id })
+        .simulate(ctx)
+        .await
+        .unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    PeerDecimals::deserialize(&mut &return_data[..]).unwrap()
+}
+
+pub async fn peer_stats(ntt: &NTT, ctx: &mut ProgramTestContext, chain_id: u16) -> LatencyStats {
+    let out = get_stats(ntt, chain_id).simulate(ctx).await.unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    LatencyStats::deserialize(&mut &return_data[..]).unwrap()
+}
+
+pub async fn peer_index(ntt: &NTT, ctx: &mut ProgramTestContext) -> Vec<u16> {
+    let out = get_peer_index(ntt).simulate(ctx).await.unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    Vec::<u16>::deserialize(&mut &return_data[..]).unwrap()
+}
+
+pub async fn configured_chains(ntt: &NTT, ctx: &mut ProgramTestContext) -> Vec<u16> {
+    let out = get_configured_chains(ntt).simulate(ctx).await.unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    Vec::<u16>::deserialize(&mut &return_data[..]).unwrap()
+}
+
+pub async fn transceiver_peer_index(
+    ntt_transceiver: &NTTTransceiver,
+    ctx: &mut ProgramTestContext,
+) -> Vec<u16> {
+    let out = get_transceiver_peer_index(ntt_transceiver)
+        .simulate(ctx)
+        .await
+        .unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    Vec::<u16>::deserialize(&mut &return_data[..]).unwrap()
+}
+
+pub async fn custody_reconciliation(
+    ntt: &NTT,
+    ctx: &mut ProgramTestContext,
+    mint: Pubkey,
+) -> CustodyReconciliation {
+    let out = reconcile_custody(ntt, ReconcileCustody { mint })
+        .simulate(ctx)
+        .await
+        .unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    CustodyReconciliation::deserialize(&mut &return_data[..]).unwrap()
+}
+
+pub async fn preflight_diagnostics(
+    ntt: &NTT,
+    ctx: &mut ProgramTestContext,
+    accounts: PreflightInitialize,
+    mode: Mode,
+    token_program_id: &Pubkey,
+) -> u32 {
+    let out = preflight_initialize(ntt, accounts, mode, token_program_id)
+        .simulate(ctx)
+        .await
+        .unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    u32::deserialize(&mut &return_data[..]).unwrap()
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::Pubkey;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use spl_token_2022::extension::StateWithExtensions;
+
+use crate::common::submit::Submittable;
+
+/// Mints `amount` of `mint` to `account`, signed by the mint `authority`.
+/// The token program is taken from the owner of the mint, so this works for
+/// both classic and Token-2022 mints.
+pub async fn fund_token_account(
+    ctx: &mut ProgramTestContext,
+    mint: &Pubkey,
+    authority: &Keypair,
+    account: &Pubkey,
+    amount: u64,
+) {
+    let token_program = ctx
+        .banks_client
+        .get_account(*mint)
+        .await
+        .unwrap()
+        .unwrap()
+        .owner;
+
+    spl_token_2022::instruction::mint_to(
+        &token_program,
+        mint,
+        account,
+        &authority.pubkey(),
+        &[],
+        amount,
+    )
+    .unwrap()
+    .submit_with_signers(&[authority], ctx)
+    .await
+    .unwrap();
+}
+
+/// The balance of the token account `account`, of either token program.
+pub async fn token_balance(ctx: &mut ProgramTestContext, account: &Pubkey) -> u64 {
+    let account = ctx
+        .banks_client
+        .get_account(*account)
+        .await
+        .unwrap()
+        .unwrap();
+    StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)
+        .unwrap()
+        .base
+        .amount
+}
@@ -0,0 +1,422 @@
+//! A deployment of the previous release of the program (d3e280a), for checking
+//! that the current program keeps working with the accounts it created.
+//!
+//! The accounts are loaded from the images in [`V_PREV_FIXTURES`], which hold
+//! a locking mode deployment with the built-in Wormhole transceiver, a peer on
+//! [`OTHER_CHAIN`], an outbound transfer that hasn't been released yet, and an
+//! inbound one that was queued by the rate limit. The images are written by
+//! [`capture_v_prev_accounts`] from the layouts of that release, see the README
+//! of the fixtures.
+
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::token::Token;
+use example_native_token_transfers::{
+    bitmap::Bitmap,
+    config::Config,
+    peer::NttManagerPeer,
+    queue::{
+        inbox::{InboxItem, InboxRateLimit, LegacyInboxItem, ReleaseStatus},
+        outbox::{LegacyOutboxItem, OutboxItem, OutboxRateLimit},
+        rate_limit::RateLimitState,
+    },
+    registered_transceiver::RegisteredTransceiver,
+    transceivers::accounts::peer::TransceiverPeer,
+    transfer::Payload,
+};
+use ntt_messages::{
+    chain_id::ChainId, mode::Mode, ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage,
+    trimmed_amount::TrimmedAmount,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    account::Account,
+    signature::Keypair,
+    signer::{keypair::keypair_from_seed, Signer},
+};
+use std::path::Path;
+
+use crate::{
+    common::{
+        account_json_utils::{add_accounts_from_dir, dump_account_state},
+        fixtures::{
+            TestData, INBOUND_LIMIT, OTHER_CHAIN, OTHER_MANAGER, OTHER_TRANSCEIVER, OUTBOUND_LIMIT,
+            THIS_CHAIN,
+        },
+        submit::Submittable,
+    },
+    helpers::{
+        fund_token_account, make_transfer_message, setup_accounts_with_mint, setup_programs,
+    },
+    sdk::{
+        accounts::{good_ntt, NTTAccounts},
+        instructions::{
+            admin::{migrate_config, MigrateConfig},
+            migrate_peer::{migrate_peer, MigratePeer},
+            migrate_queue_item::{
+                migrate_inbox_item, migrate_outbox_item, MigrateInboxItem, MigrateOutboxItem,
+            },
+        },
+        transceivers::legacy::{
+            accounts::{good_ntt_transceiver, NTTTransceiverAccounts},
+            instructions::admin::{migrate_transceiver_peer, MigrateTransceiverPeer},
+        },
+    },
+};
+
+/// The account images, relative to the test crates of the programs.
+pub const V_PREV_FIXTURES: &str = "../../tests/cargo/fixtures/v_prev";
+
+/// When the transfers in the images were made.
+pub const V_PREV_TIMESTAMP: i64 = 1_700_000_000;
+
+const V_PREV_OWNER_SEED: [u8; 32] = [1; 32];
+const V_PREV_MINT_SEED: [u8; 32] = [2; 32];
+
+/// The outbound transfer that hasn't been released yet.
+pub const V_PREV_OUTBOX_ITEM: Pubkey = Pubkey::new_from_array([3; 32]);
+pub const V_PREV_OUTBOX_ITEM_AMOUNT: TrimmedAmount = TrimmedAmount {
+    amount: 10,
+    decimals: 7,
+};
+pub const V_PREV_SENDER: Pubkey = Pubkey::new_from_array([4; 32]);
+
+/// The recipient of the inbound transfer queued by the rate limit, see
+/// [`v_prev_inbound_message`].
+pub const V_PREV_RECIPIENT: Pubkey = Pubkey::new_from_array([5; 32]);
+pub const V_PREV_INBOX_ITEM_ID: [u8; 32] = [6; 32];
+pub const V_PREV_INBOX_ITEM_AMOUNT: u64 = 500;
+
+/// The tokens locked in custody: those of the outbound transfer, and those of
+/// the inbound one that are still to be released.
+pub const V_PREV_CUSTODY_AMOUNT: u64 = 1000 + V_PREV_INBOX_ITEM_AMOUNT;
+
+/// The owner of the deployment (and upgrade authority of the program).
+pub fn v_prev_owner() -> Keypair {
+    keypair_from_seed(&V_PREV_OWNER_SEED).unwrap()
+}
+
+/// The mint of the deployment. Only the images of the program's own accounts
+/// are stored, so the mint is created along with the other token accounts
+/// when the images are loaded.
+pub fn v_prev_mint() -> Keypair {
+    keypair_from_seed(&V_PREV_MINT_SEED).unwrap()
+}
+
+/// The message of the inbound transfer queued by the rate limit.
+pub fn v_prev_inbound_message() -> NttManagerMessage<NativeTokenTransfer<Payload>> {
+    make_transfer_message(
+        &good_ntt,
+        V_PREV_INBOX_ITEM_ID,
+        V_PREV_INBOX_ITEM_AMOUNT,
+        &V_PREV_RECIPIENT,
+    )
+    .ntt_manager_payload
+}
+
+pub fn v_prev_inbox_item() -> Pubkey {
+    good_ntt.inbox_item(OTHER_CHAIN, v_prev_inbound_message())
+}
+
+/// Sets up the deployment from the images in [`V_PREV_FIXTURES`], along with
+/// the token accounts it refers to. Its accounts are still in the layouts of
+/// the previous release, see [`migrate_v_prev_accounts`].
+pub async fn setup_with_v_prev_accounts() -> (ProgramTestContext, TestData) {
+    let program_owner = v_prev_owner();
+    let mut program_test = setup_programs(program_owner.pubkey()).await.unwrap();
+    add_accounts_from_dir(&mut program_test, V_PREV_FIXTURES).unwrap();
+
+    let mut ctx = program_test.start_with_context().await;
+    let test_data = setup_accounts_with_mint(&mut ctx, program_owner, v_prev_mint()).await;
+
+    for owner in [good_ntt.token_authority(), V_PREV_RECIPIENT] {
+        spl_associated_token_account::instruction::create_associated_token_account(
+            &ctx.payer.pubkey(),
+            &owner,
+            &test_data.mint,
+            &Token::id(),
+        )
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    }
+    fund_token_account(
+        &mut ctx,
+        &test_data.mint,
+        &test_data.mint_authority,
+        &good_ntt.custody(&test_data.mint),
+        V_PREV_CUSTODY_AMOUNT,
+    )
+    .await;
+
+    (ctx, test_data)
+}
+
+/// Brings the accounts loaded by [`setup_with_v_prev_accounts`] up to the
+/// current layouts, the way a deployment is upgraded.
+pub async fn migrate_v_prev_accounts(ctx: &mut ProgramTestContext, test_data: &TestData) {
+    migrate_config(
+        &good_ntt,
+        MigrateConfig {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            mint: test_data.mint,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], ctx)
+    .await
+    .unwrap();
+
+    migrate_peer(
+        &good_ntt,
+        MigratePeer {
+            payer: ctx.payer.pubkey(),
+        },
+        OTHER_CHAIN,
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    migrate_transceiver_peer(
+        &good_ntt_transceiver,
+        MigrateTransceiverPeer {
+            payer: ctx.payer.pubkey(),
+        },
+        OTHER_CHAIN,
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    migrate_outbox_item(
+        &good_ntt,
+        MigrateOutboxItem {
+            payer: ctx.payer.pubkey(),
+            outbox_item: V_PREV_OUTBOX_ITEM,
+        },
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    migrate_inbox_item(
+        &good_ntt,
+        MigrateInboxItem {
+            payer: ctx.payer.pubkey(),
+            inbox_item: v_prev_inbox_item(),
+        },
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+}
+
+/// The layouts of the accounts that changed since the previous release.
+mod layout {
+    use super::*;
+
+    #[derive(AnchorSerialize)]
+    pub struct Config {
+        pub bump: u8,
+        pub owner: Pubkey,
+        pub pending_owner: Option<Pubkey>,
+        pub mint: Pubkey,
+        pub token_program: Pubkey,
+        pub mode: Mode,
+        pub chain_id: ChainId,
+        pub next_transceiver_id: u8,
+        pub threshold: u8,
+        pub enabled_transceivers: Bitmap,
+        pub paused: bool,
+        pub custody: Pubkey,
+    }
+
+    impl Config {
+        pub const SPACE: usize = 1 + 32 + (1 + 32) + 32 + 32 + 1 + 2 + 1 + 1 + 16 + 1 + 32;
+    }
+
+    #[derive(AnchorSerialize)]
+    pub struct NttManagerPeer {
+        pub bump: u8,
+        pub address: [u8; 32],
+        pub token_decimals: u8,
+    }
+
+    #[derive(AnchorSerialize)]
+    pub struct TransceiverPeer {
+        pub bump: u8,
+        pub address: [u8; 32],
+    }
+}
+
+/// An account holding `data`, allocated with `space` bytes (plus the
+/// discriminator) as the previous release did.
+fn account_image(discriminator: [u8; 8], data: impl AnchorSerialize, space: usize) -> Account {
+    let mut bytes = discriminator.to_vec();
+    data.serialize(&mut bytes).unwrap();
+    // the space of variable length fields is allocated for their largest value
+    bytes.resize(8 + space, 0);
+
+    Account {
+        lamports: Rent::default().minimum_balance(bytes.len()),
+        data: bytes,
+        owner: example_native_token_transfers::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn bump(seeds: &[&[u8]]) -> u8 {
+    Pubkey::find_program_address(seeds, &example_native_token_transfers::ID).1
+}
+
+/// The images of the deployment in [`V_PREV_FIXTURES`], in the layouts of the
+/// previous release, by the name of their file.
+pub fn v_prev_account_images() -> Vec<(&'static str, Pubkey, Account)> {
+    let mint = v_prev_mint().pubkey();
+    let other_chain = OTHER_CHAIN.to_be_bytes();
+    let inbound_message = v_prev_inbound_message();
+    let inbox_item_hash = inbound_message.keccak256(ChainId { id: OTHER_CHAIN });
+
+    vec![
+        (
+            "config",
+            good_ntt.config(),
+            account_image(
+                Config::DISCRIMINATOR,
+                layout::Config {
+                    bump: bump(&[Config::SEED_PREFIX]),
+                    owner: v_prev_owner().pubkey(),
+                    pending_owner: None,
+                    mint,
+                    token_program: Token::id(),
+                    mode: Mode::Locking,
+                    chain_id: ChainId { id: THIS_CHAIN },
+                    next_transceiver_id: 1,
+                    threshold: 1,
+                    enabled_transceivers: Bitmap::from_value(1),
+                    paused: false,
+                    custody: good_ntt.custody(&mint),
+                },
+                layout::Config::SPACE,
+            ),
+        ),
+        (
+            "outbox_rate_limit",
+            good_ntt.outbox_rate_limit(),
+            account_image(
+                OutboxRateLimit::DISCRIMINATOR,
+                OutboxRateLimit {
+                    rate_limit: RateLimitState {
+                        limit: OUTBOUND_LIMIT,
+                        capacity_at_last_tx: OUTBOUND_LIMIT - 1000,
+                        last_tx_timestamp: V_PREV_TIMESTAMP,
+                    },
+                },
+                OutboxRateLimit::INIT_SPACE,
+            ),
+        ),
+        (
+            "inbox_rate_limit",
+            good_ntt.inbox_rate_limit(OTHER_CHAIN),
+            account_image(
+                InboxRateLimit::DISCRIMINATOR,
+                InboxRateLimit {
+                    bump: bump(&[InboxRateLimit::SEED_PREFIX, &other_chain]),
+                    rate_limit: RateLimitState {
+                        limit: INBOUND_LIMIT,
+                        capacity_at_last_tx: 0,
+                        last_tx_timestamp: V_PREV_TIMESTAMP,
+                    },
+                },
+                InboxRateLimit::INIT_SPACE,
+            ),
+        ),
+        (
+            "peer",
+            good_ntt.peer(OTHER_CHAIN),
+            account_image(
+                NttManagerPeer::DISCRIMINATOR,
+                layout::NttManagerPeer {
+                    bump: bump(&[NttManagerPeer::SEED_PREFIX, &other_chain]),
+                    address: OTHER_MANAGER,
+                    token_decimals: 7,
+                },
+                1 + 32 + 1,
+            ),
+        ),
+        (
+            "registered_transceiver",
+            good_ntt.registered_transceiver(&good_ntt_transceiver.program()),
+            account_image(
+                RegisteredTransceiver::DISCRIMINATOR,
+                RegisteredTransceiver {
+                    bump: bump(&[
+                        RegisteredTransceiver::SEED_PREFIX,
+                        good_ntt_transceiver.program().as_ref(),
+                    ]),
+                    id: 0,
+                    transceiver_address: good_ntt_transceiver.program(),
+                },
+                RegisteredTransceiver::INIT_SPACE,
+            ),
+        ),
+        (
+            "transceiver_peer",
+            good_ntt_transceiver.transceiver_peer(OTHER_CHAIN),
+            account_image(
+                TransceiverPeer::DISCRIMINATOR,
+                layout::TransceiverPeer {
+                    bump: bump(&[TransceiverPeer::SEED_PREFIX, &other_chain]),
+                    address: OTHER_TRANSCEIVER,
+                },
+                1 + 32,
+            ),
+        ),
+        (
+            "outbox_item",
+            V_PREV_OUTBOX_ITEM,
+            account_image(
+                OutboxItem::DISCRIMINATOR,
+                LegacyOutboxItem {
+                    amount: V_PREV_OUTBOX_ITEM_AMOUNT,
+                    sender: V_PREV_SENDER,
+                    recipient_chain: ChainId { id: OTHER_CHAIN },
+                    recipient_ntt_manager: OTHER_MANAGER,
+                    recipient_address: V_PREV_RECIPIENT.to_bytes(),
+                    release_timestamp: V_PREV_TIMESTAMP,
+                    released: Bitmap::new(),
+                },
+                LegacyOutboxItem::INIT_SPACE,
+            ),
+        ),
+        (
+            "inbox_item",
+            v_prev_inbox_item(),
+            account_image(
+                InboxItem::DISCRIMINATOR,
+                LegacyInboxItem {
+                    init: true,
+                    bump: bump(&[InboxItem::SEED_PREFIX, &inbox_item_hash.to_bytes()]),
+                    amount: V_PREV_INBOX_ITEM_AMOUNT,
+                    recipient_address: V_PREV_RECIPIENT,
+                    // voted by the (only) transceiver
+                    votes: Bitmap::from_value(1),
+                    release_status: ReleaseStatus::ReleaseAfter(V_PREV_TIMESTAMP),
+                },
+                LegacyInboxItem::INIT_SPACE,
+            ),
+        ),
+    ]
+}
+
+/// Writes [`v_prev_account_images`] to `dir`, the way they would be captured
+/// from a live deployment: loaded into `ctx`, and dumped from there.
+pub async fn capture_v_prev_accounts(ctx: &mut ProgramTestContext, dir: impl AsRef<Path>) {
+    for (name, pubkey, account) in v_prev_account_images() {
+        ctx.set_account(&pubkey, &account.into());
+        dump_account_state(ctx, pubkey, dir.as_ref().join(format!("{name}.json")))
+            .await
+            .unwrap();
+    }
+}
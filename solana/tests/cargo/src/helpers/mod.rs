@@ -1,4 +1,6 @@
 mod admin;
+mod faucet;
+mod migration;
 #[cfg(feature = "shim")]
 mod post_message_shim;
 mod post_vaa;
@@ -7,9 +9,12 @@ mod rate_limit;
 mod receive_message;
 mod redeem;
 mod setup;
+mod token_metadata;
 mod transfer;
 
 pub use admin::*;
+pub use faucet::*;
+pub use migration::*;
 #[cfg(feature = "shim")]
 pub use post_message_shim::*;
 pub use post_vaa::*;
@@ -18,4 +23,5 @@ pub use rate_limit::*;
 pub use receive_message::*;
 pub use redeem::*;
 pub use setup::*;
+pub use token_metadata::*;
 pub use transfer::*;
@@ -1,15 +1,52 @@
+use anchor_lang::{prelude::Pubkey, AccountDeserialize};
+use solana_banks_interface::TransactionSimulationDetails;
 use solana_program_test::ProgramTestContext;
 use solana_sdk::instruction::Instruction;
+use wormhole_anchor_sdk::wormhole::SequenceTracker;
 
 use crate::{
     common::submit::Submittable,
-    sdk::{accounts::Wormhole, transceivers::accounts::NTTTransceiver},
+    sdk::{
+        accounts::Wormhole,
+        transceivers::accounts::{NTTTransceiver, NTTTransceiverAccounts},
+    },
 };
 
 pub struct PostMessageShimInstructionData {
     pub nonce: u32,
     pub consistency_level: u8,
     pub payload: Vec<u8>,
+    /// The sequence number the message is posted with.
+    pub sequence: u64,
+}
+
+pub struct BroadcastPayload {
+    pub payload: Vec<u8>,
+    /// The sequence number the message is posted with.
+    pub sequence: u64,
+}
+
+/// Returns the sequence number of the next message posted by `emitter`.
+/// The core bridge only creates the sequence tracker on the first message,
+/// so this is 0 if the account doesn't exist yet.
+pub async fn get_emitter_sequence(
+    wh: &Wormhole,
+    ctx: &mut ProgramTestContext,
+    emitter: &Pubkey,
+) -> u64 {
+    match ctx
+        .banks_client
+        .get_account(wh.sequence(emitter))
+        .await
+        .unwrap()
+    {
+        Some(account) => {
+            SequenceTracker::try_deserialize(&mut account.data.as_ref())
+                .unwrap()
+                .sequence
+        }
+        None => 0,
+    }
 }
 
 // TODO: Figure out how to get CPI event that can be parsed to re-create the VAA message.
@@ -21,6 +58,51 @@ pub async fn get_message_data(
     ctx: &mut ProgramTestContext,
     ix: Instruction,
 ) -> PostMessageShimInstructionData {
+    let (details, sequence) = simulate_post_message(wh, ntt_transceiver, ctx, ix).await;
+
+    // parse return data
+    let ix_data = details.return_data.unwrap().data;
+    // 8-byte instruction discriminator
+    let nonce = u32::from_le_bytes(ix_data[8..12].try_into().unwrap());
+    let consistency_level: u8 = ix_data[12];
+    // 4-byte Vec length
+    let payload = ix_data[17..].to_vec();
+
+    PostMessageShimInstructionData {
+        nonce,
+        consistency_level,
+        payload,
+        sequence,
+    }
+}
+
+/// Simulates one of the broadcast instructions, and returns the payload it
+/// emits. Unlike [`get_message_data`], this doesn't rely on the `testing`
+/// feature, as the broadcast instructions return their payload in all builds.
+pub async fn get_broadcast_payload(
+    wh: &Wormhole,
+    ntt_transceiver: &NTTTransceiver,
+    ctx: &mut ProgramTestContext,
+    ix: Instruction,
+) -> BroadcastPayload {
+    let (details, sequence) = simulate_post_message(wh, ntt_transceiver, ctx, ix).await;
+
+    BroadcastPayload {
+        payload: details.return_data.unwrap().data,
+        sequence,
+    }
+}
+
+/// Simulates `ix`, and checks that it posted exactly one message.
+/// Returns the simulation details along with the message's sequence number.
+async fn simulate_post_message(
+    wh: &Wormhole,
+    ntt_transceiver: &NTTTransceiver,
+    ctx: &mut ProgramTestContext,
+    ix: Instruction,
+) -> (TransactionSimulationDetails, u64) {
+    let sequence = get_emitter_sequence(wh, ctx, &ntt_transceiver.emitter()).await;
+
     // simulate ix
     let out = ix.simulate(ctx).await.unwrap();
     assert!(out.result.unwrap().is_ok());
@@ -33,7 +115,7 @@ pub async fn get_message_data(
         |line: &String| line.contains(format!("Program {} invoke [3]", wh.program).as_str());
     assert_eq!(
         logs.iter()
-            .filter(|line| { line.contains("Program log: Sequence: 0") })
+            .filter(|line| { line.contains(format!("Program log: Sequence: {sequence}").as_str()) })
             .count(),
         1
     );
@@ -54,17 +136,5 @@ pub async fn get_message_data(
         1
     );
 
-    // parse return data
-    let ix_data = details.return_data.unwrap().data;
-    // 8-byte instruction discriminator
-    let nonce = u32::from_le_bytes(ix_data[8..12].try_into().unwrap());
-    let consistency_level: u8 = ix_data[12];
-    // 4-byte Vec length
-    let payload = ix_data[17..].to_vec();
-
-    PostMessageShimInstructionData {
-        nonce,
-        consistency_level,
-        payload,
-    }
+    (details, sequence)
 }
@@ -1,17 +1,28 @@
 use anchor_lang::AnchorSerialize;
 use solana_program::pubkey::Pubkey;
 use solana_program_test::ProgramTestContext;
-use std::sync::atomic::AtomicU64;
+use std::sync::atomic::{AtomicU64, Ordering};
 use wormhole_sdk::{Address, Chain, Vaa};
 
+/// Sequence number of the next VAA posted by [`post_vaa_helper`].
+static VAA_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// Resets the sequence numbers of the VAAs posted by [`post_vaa_helper`], so
+/// that tests don't depend on how many VAAs were posted before them.
+pub fn reset_vaa_sequence() {
+    VAA_SEQUENCE.store(0, Ordering::Release);
+}
+
 cfg_if! {
     if #[cfg(feature = "shim")] {
-        use crate::sdk::{transceivers::accounts::NTTTransceiver,
+        use crate::sdk::{transceivers::accounts::NTTTransceiver, accounts::{NTT, Wormhole},
             instructions::post_vaa::{
-                get_guardian_signature, post_signatures, GUARDIAN_INDEX, GUARDIAN_SET_INDEX,
+                get_guardian_signature, post_signatures, post_vaa, GUARDIAN_INDEX,
+                GUARDIAN_SET_INDEX,
             }
         };
-        use solana_sdk::{signature::Keypair, signer::Signer};
+        use solana_program::{keccak, rent::Rent};
+        use solana_sdk::{account::Account, signature::Keypair, signer::Signer};
 
         pub async fn post_vaa_helper<A: AnchorSerialize + Clone>(
             ntt_transceiver: &NTTTransceiver,
@@ -20,22 +31,31 @@ cfg_if! {
             msg: A,
             ctx: &mut ProgramTestContext,
         ) -> (Pubkey, u32, Vec<u8>) {
-            static I: AtomicU64 = AtomicU64::new(0);
-
-            let sequence = I.fetch_add(1, std::sync::atomic::Ordering::Acquire);
+            let vaa = make_vaa(emitter_chain, emitter_address, msg, GUARDIAN_SET_INDEX);
+            post_signed_vaa(ntt_transceiver, vaa, ctx).await
+        }
 
-            let mut vaa = Vaa {
-                version: 1,
-                guardian_set_index: GUARDIAN_SET_INDEX,
-                signatures: vec![],
-                timestamp: 123232,
-                nonce: 0,
-                emitter_chain,
-                emitter_address,
-                sequence,
-                consistency_level: 0,
-                payload: msg,
+        /// [`post_vaa_helper`], with the VAA timestamp set to `timestamp`.
+        pub async fn post_vaa_helper_with_timestamp<A: AnchorSerialize + Clone>(
+            ntt_transceiver: &NTTTransceiver,
+            emitter_chain: Chain,
+            emitter_address: Address,
+            msg: A,
+            timestamp: u32,
+            ctx: &mut ProgramTestContext,
+        ) -> (Pubkey, u32, Vec<u8>) {
+            let vaa = Vaa {
+                timestamp,
+                ..make_vaa(emitter_chain, emitter_address, msg, GUARDIAN_SET_INDEX)
             };
+            post_signed_vaa(ntt_transceiver, vaa, ctx).await
+        }
+
+        async fn post_signed_vaa<A: AnchorSerialize + Clone>(
+            ntt_transceiver: &NTTTransceiver,
+            mut vaa: Vaa<A>,
+            ctx: &mut ProgramTestContext,
+        ) -> (Pubkey, u32, Vec<u8>) {
             vaa.signatures
                 .push(get_guardian_signature(vaa.clone(), GUARDIAN_INDEX));
 
@@ -49,7 +69,44 @@ cfg_if! {
             )
         }
 
-        fn vaa_body<A: AnchorSerialize + Clone>(vaa: &Vaa<A>) -> Vec<u8> {
+        /// Posts `msg` to the core bridge, for the legacy transceiver built into
+        /// the manager (see [`crate::helpers::register_legacy_transceiver`]).
+        pub async fn post_legacy_vaa_helper<A: AnchorSerialize + Clone>(
+            ntt: &NTT,
+            emitter_chain: Chain,
+            emitter_address: Address,
+            msg: A,
+            ctx: &mut ProgramTestContext,
+        ) -> Pubkey {
+            let vaa = make_vaa(emitter_chain, emitter_address, msg, GUARDIAN_SET_INDEX);
+            post_vaa(&ntt.wormhole(), ctx, vaa).await
+        }
+
+        /// An unsigned VAA with the next sequence number (see
+        /// [`post_vaa_helper`]).
+        pub fn make_vaa<A: AnchorSerialize + Clone>(
+            emitter_chain: Chain,
+            emitter_address: Address,
+            msg: A,
+            guardian_set_index: u32,
+        ) -> Vaa<A> {
+            let sequence = VAA_SEQUENCE.fetch_add(1, Ordering::AcqRel);
+
+            Vaa {
+                version: 1,
+                guardian_set_index,
+                signatures: vec![],
+                timestamp: 123232,
+                nonce: 0,
+                emitter_chain,
+                emitter_address,
+                sequence,
+                consistency_level: 0,
+                payload: msg,
+            }
+        }
+
+        pub fn vaa_body<A: AnchorSerialize + Clone>(vaa: &Vaa<A>) -> Vec<u8> {
             let mut bytes = Vec::new();
             bytes.extend_from_slice(&vaa.timestamp.to_be_bytes());
             bytes.extend_from_slice(&vaa.nonce.to_be_bytes());
@@ -61,6 +118,58 @@ cfg_if! {
             bytes.extend_from_slice(&payload_bytes);
             bytes
         }
+
+        /// Secret keys for a guardian set of `num_guardians` guardians (see
+        /// [`add_guardian_set`]).
+        pub fn guardian_secret_keys(num_guardians: u8) -> Vec<libsecp256k1::SecretKey> {
+            (1..=num_guardians)
+                .map(|i| libsecp256k1::SecretKey::parse(&[i; 32]).unwrap())
+                .collect()
+        }
+
+        /// Adds a core bridge guardian set at `guardian_set_index`, with a
+        /// guardian for each of `secret_keys`, for tests that need more
+        /// guardians than the single one of the mainnet guardian set fixture.
+        pub fn add_guardian_set(
+            ctx: &mut ProgramTestContext,
+            wormhole: &Wormhole,
+            guardian_set_index: u32,
+            secret_keys: &[libsecp256k1::SecretKey],
+        ) {
+            let keys = secret_keys
+                .iter()
+                .map(|secret_key| {
+                    let public_key = libsecp256k1::PublicKey::from_secret_key(secret_key);
+                    let hash = keccak::hash(&public_key.serialize()[1..]).to_bytes();
+                    let mut key = [0u8; 20];
+                    key.copy_from_slice(&hash[12..]);
+                    key
+                })
+                .collect::<Vec<[u8; 20]>>();
+
+            // the (borsh) layout of the core bridge's guardian set account
+            let mut data = guardian_set_index.to_le_bytes().to_vec();
+            data.extend_from_slice(&u32::try_from(keys.len()).unwrap().to_le_bytes());
+            for key in keys {
+                data.extend_from_slice(&key);
+            }
+            // creation time
+            data.extend_from_slice(&0u32.to_le_bytes());
+            // expiration time, zero for a guardian set that doesn't expire
+            data.extend_from_slice(&0u32.to_le_bytes());
+
+            ctx.set_account(
+                &wormhole.guardian_set(guardian_set_index),
+                &Account {
+                    lamports: Rent::default().minimum_balance(data.len()),
+                    data,
+                    owner: wormhole.program,
+                    executable: false,
+                    rent_epoch: 0,
+                }
+                .into(),
+            );
+        }
     } else {
         use crate::sdk::{instructions::post_vaa::post_vaa, accounts::NTT};
 
@@ -71,15 +180,26 @@ cfg_if! {
             msg: A,
             ctx: &mut ProgramTestContext,
         ) -> Pubkey {
-            static I: AtomicU64 = AtomicU64::new(0);
+            post_vaa_helper_with_timestamp(ntt, emitter_chain, emitter_address, msg, 123232, ctx)
+                .await
+        }
 
-            let sequence = I.fetch_add(1, std::sync::atomic::Ordering::Acquire);
+        /// [`post_vaa_helper`], with the VAA timestamp set to `timestamp`.
+        pub async fn post_vaa_helper_with_timestamp<A: AnchorSerialize + Clone>(
+            ntt: &NTT,
+            emitter_chain: Chain,
+            emitter_address: Address,
+            msg: A,
+            timestamp: u32,
+            ctx: &mut ProgramTestContext,
+        ) -> Pubkey {
+            let sequence = VAA_SEQUENCE.fetch_add(1, Ordering::AcqRel);
 
             let vaa = Vaa {
                 version: 1,
                 guardian_set_index: 0,
                 signatures: vec![],
-                timestamp: 123232,
+                timestamp,
                 nonce: 0,
                 emitter_chain,
                 emitter_address,
@@ -1,8 +1,27 @@
-use anchor_lang::prelude::{Clock, Pubkey};
-use example_native_token_transfers::queue::outbox::OutboxItem;
+use anchor_lang::{
+    prelude::{Clock, Pubkey},
+    AnchorDeserialize,
+};
+use example_native_token_transfers::{
+    instructions::{InboxItemStatus, TransferStatus, WouldQueueResult},
+    queue::outbox::OutboxItem,
+    retry_after::decode_retry_after,
+};
+use solana_banks_interface::BanksTransactionResultWithSimulation;
 use solana_program_test::ProgramTestContext;
+use std::time::Duration;
 
-use crate::common::query::GetAccountDataAnchor;
+use crate::{
+    common::{query::GetAccountDataAnchor, submit::Submittable},
+    sdk::{
+        accounts::NTT,
+        instructions::{
+            inbox_item_status::{get_inbox_item_status, GetInboxItemStatus},
+            transfer_status::{get_transfer_status, GetTransferStatus},
+            would_queue::{would_queue, WouldQueue},
+        },
+    },
+};
 
 pub async fn assert_queued(ctx: &mut ProgramTestContext, outbox_item: Pubkey) {
     let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
@@ -12,3 +31,91 @@ pub async fn assert_queued(ctx: &mut ProgramTestContext, outbox_item: Pubkey) {
     assert!(!outbox_item_account.released.get(0).unwrap());
     assert!(outbox_item_account.release_timestamp > clock.unix_timestamp);
 }
+
+pub async fn inbox_item_status(
+    ntt: &NTT,
+    ctx: &mut ProgramTestContext,
+    inbox_item: Pubkey,
+    chain_id: u16,
+) -> InboxItemStatus {
+    let out = get_inbox_item_status(
+        ntt,
+        GetInboxItemStatus {
+            inbox_item,
+            chain_id,
+        },
+    )
+    .simulate(ctx)
+    .await
+    .unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    InboxItemStatus::deserialize(&mut &return_data[..]).unwrap()
+}
+
+pub async fn transfer_status(
+    ntt: &NTT,
+    ctx: &mut ProgramTestContext,
+    outbox_item: Pubkey,
+) -> TransferStatus {
+    let out = get_transfer_status(ntt, GetTransferStatus { outbox_item })
+        .simulate(ctx)
+        .await
+        .unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    TransferStatus::deserialize(&mut &return_data[..]).unwrap()
+}
+
+pub async fn would_queue_status(
+    ntt: &NTT,
+    ctx: &mut ProgramTestContext,
+    chain_id: u16,
+    amount: u64,
+) -> WouldQueueResult {
+    let out = would_queue(ntt, WouldQueue { chain_id, amount })
+        .simulate(ctx)
+        .await
+        .unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    WouldQueueResult::deserialize(&mut &return_data[..]).unwrap()
+}
+
+/// Parses how long to wait before retrying from a simulation that failed
+/// because a transfer is still rate limited.
+/// Returns `None` if the simulation succeeded or carries no wait time.
+pub fn parse_retry_after(
+    simulation_result: &BanksTransactionResultWithSimulation,
+) -> Option<Duration> {
+    if !matches!(simulation_result.result, Some(Err(_))) {
+        return None;
+    }
+
+    let return_data = simulation_result
+        .simulation_details
+        .as_ref()?
+        .return_data
+        .as_ref()?;
+    decode_retry_after(&return_data.data)
+}
+
+/// Asserts that the wait time reported by a failed simulation matches
+/// `release_timestamp` (within a second).
+pub async fn assert_retry_after(
+    ctx: &mut ProgramTestContext,
+    simulation_result: &BanksTransactionResultWithSimulation,
+    release_timestamp: i64,
+) {
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+
+    let retry_after = parse_retry_after(simulation_result).unwrap();
+    let expected = release_timestamp - clock.unix_timestamp;
+    assert!(
+        (i64::try_from(retry_after.as_secs()).unwrap() - expected).abs() <= 1,
+        "retry after {retry_after:?}, expected {expected}s"
+    );
+}
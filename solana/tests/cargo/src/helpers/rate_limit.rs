@@ -1,10 +1,18 @@
-use anchor_lang::prelude::Clock;
-use example_native_token_transfers::queue::{inbox::InboxRateLimit, outbox::OutboxRateLimit};
+use anchor_lang::{prelude::Clock, AnchorDeserialize};
+use example_native_token_transfers::{
+    instructions::RateLimits,
+    queue::{inbox::InboxRateLimit, outbox::OutboxRateLimit},
+};
 use solana_program_test::ProgramTestContext;
 
 use crate::{
-    common::{fixtures::OTHER_CHAIN, query::GetAccountDataAnchor},
-    sdk::accounts::NTT,
+    common::{fixtures::OTHER_CHAIN, query::GetAccountDataAnchor, submit::Submittable},
+    sdk::{
+        accounts::NTT,
+        instructions::rate_limit_params::{
+            get_inbound_capacity, get_outbound_capacity, get_rate_limit_params,
+        },
+    },
 };
 
 pub async fn outbound_capacity(ntt: &NTT, ctx: &mut ProgramTestContext) -> u64 {
@@ -22,3 +30,45 @@ pub async fn inbound_capacity(ntt: &NTT, ctx: &mut ProgramTestContext) -> u64 {
 
     rate_limit.rate_limit.capacity_at(clock.unix_timestamp)
 }
+
+pub async fn rate_limit_params(
+    ntt: &NTT,
+    ctx: &mut ProgramTestContext,
+    chain_id: u16,
+) -> RateLimits {
+    let out = get_rate_limit_params(ntt, chain_id)
+        .simulate(ctx)
+        .await
+        .unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    RateLimits::deserialize(&mut &return_data[..]).unwrap()
+}
+
+/// The outbound capacity as reported by the program (see
+/// [`outbound_capacity`] for computing it from the account).
+pub async fn reported_outbound_capacity(ntt: &NTT, ctx: &mut ProgramTestContext) -> u64 {
+    let out = get_outbound_capacity(ntt).simulate(ctx).await.unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    u64::deserialize(&mut &return_data[..]).unwrap()
+}
+
+/// The inbound capacity of `chain_id` as reported by the program (see
+/// [`inbound_capacity`] for computing it from the account).
+pub async fn reported_inbound_capacity(
+    ntt: &NTT,
+    ctx: &mut ProgramTestContext,
+    chain_id: u16,
+) -> u64 {
+    let out = get_inbound_capacity(ntt, chain_id)
+        .simulate(ctx)
+        .await
+        .unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    u64::deserialize(&mut &return_data[..]).unwrap()
+}
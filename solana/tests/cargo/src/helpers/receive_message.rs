@@ -1,4 +1,6 @@
-use anchor_lang::prelude::Pubkey;
+use anchor_lang::{prelude::Pubkey, AnchorDeserialize, Discriminator};
+use base64::Engine;
+use example_native_token_transfers::receive_hook::ReceiveHookNotified;
 use solana_program_test::ProgramTestContext;
 use solana_sdk::signer::Signer;
 
@@ -26,8 +28,9 @@ cfg_if! {
                 id,
                 guardian_set: ntt
                     .wormhole()
-                    .guardian_set_with_bump(guardian_set_index),
+                    .cached_guardian_set_with_bump(guardian_set_index),
                 guardian_signatures,
+                expected_digest: None,
             }
         }
     } else {
@@ -48,3 +51,14 @@ cfg_if! {
         }
     }
 }
+
+/// Decodes the [`ReceiveHookNotified`] events emitted in a transaction's
+/// `logs`.
+pub fn receive_hook_notifications(logs: &[String]) -> Vec<ReceiveHookNotified> {
+    logs.iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .filter_map(|data| base64::engine::general_purpose::STANDARD.decode(data).ok())
+        .filter(|data| data.starts_with(&ReceiveHookNotified::DISCRIMINATOR))
+        .map(|data| ReceiveHookNotified::try_from_slice(&data[8..]).unwrap())
+        .collect()
+}
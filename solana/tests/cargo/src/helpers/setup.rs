@@ -1,17 +1,24 @@
-use anchor_lang::prelude::{Error, Id, Pubkey};
+use anchor_lang::{
+    prelude::{Error, Id, Pubkey},
+    system_program::System,
+    InstructionData, ToAccountMetas,
+};
 use anchor_spl::{
     associated_token::get_associated_token_address_with_program_id,
     token::{Mint, Token},
 };
 use example_native_token_transfers::instructions::{InitializeArgs, SetPeerArgs};
 use ntt_messages::{chain_id::ChainId, mode::Mode};
-use solana_program::{bpf_loader_upgradeable::UpgradeableLoaderState, rent::Rent};
+use solana_program::{
+    bpf_loader_upgradeable::UpgradeableLoaderState, program_pack::Pack, rent::Rent,
+};
 use solana_program_runtime::log_collector::log::{trace, warn};
 use solana_program_test::{read_file, ProgramTest, ProgramTestContext};
 use solana_sdk::{
-    account::Account, signature::Keypair, signer::Signer, system_instruction,
-    transaction::Transaction,
+    account::Account, instruction::Instruction, signature::Keypair, signer::Signer,
+    system_instruction, transaction::Transaction,
 };
+use spl_transfer_hook_interface::get_extra_account_metas_address;
 use std::path::PathBuf;
 use wormhole_anchor_sdk::wormhole::{BridgeData, FeeCollector};
 
@@ -22,8 +29,10 @@ use crate::{
             TestData, ANOTHER_CHAIN, ANOTHER_MANAGER, INBOUND_LIMIT, MINT_AMOUNT, OTHER_CHAIN,
             OTHER_MANAGER, OTHER_TRANSCEIVER, OUTBOUND_LIMIT, THIS_CHAIN,
         },
+        query::GetAccountDataAnchor,
         submit::Submittable,
     },
+    helpers::fund_token_account,
     sdk::{
         accounts::{good_ntt, Governance, NTTAccounts},
         instructions::{
@@ -85,6 +94,43 @@ pub async fn setup_with_transfer_fee(mode: Mode) -> (ProgramTestContext, TestDat
     setup_with_extra_accounts_with_transfer_fee(mode, &[]).await
 }
 
+pub async fn setup_with_confidential_transfers(mode: Mode) -> (ProgramTestContext, TestData) {
+    let program_owner = Keypair::new();
+    let program_test = setup_programs(program_owner.pubkey()).await.unwrap();
+    let mut ctx = program_test.start_with_context().await;
+
+    let test_data = setup_accounts_with_confidential_transfers(&mut ctx, program_owner).await;
+    setup_ntt_with_token_program_id(&mut ctx, &test_data, mode, &spl_token_2022::id()).await;
+
+    (ctx, test_data)
+}
+
+/// Like [`setup_with_transfer_fee`], but the mint has a transfer hook, which
+/// counts the transfers it's called for (see [`transfer_hook_invocations`]).
+pub async fn setup_with_transfer_hook(mode: Mode) -> (ProgramTestContext, TestData) {
+    let program_owner = Keypair::new();
+    let program_test = setup_programs(program_owner.pubkey()).await.unwrap();
+    let mut ctx = program_test.start_with_context().await;
+
+    let test_data = setup_accounts_with_transfer_hook(&mut ctx, program_owner).await;
+    setup_ntt_with_token_program_id(&mut ctx, &test_data, mode, &spl_token_2022::id()).await;
+
+    (ctx, test_data)
+}
+
+/// The account in which [`dummy_transfer_hook`] counts its invocations.
+fn transfer_hook_counter() -> Pubkey {
+    Pubkey::find_program_address(&[b"counter"], &dummy_transfer_hook::ID).0
+}
+
+/// How many times the transfer hook set up by [`setup_with_transfer_hook`]
+/// has been called.
+pub async fn transfer_hook_invocations(ctx: &mut ProgramTestContext) -> u64 {
+    let counter: dummy_transfer_hook::Counter =
+        ctx.get_account_data_anchor(transfer_hook_counter()).await;
+    counter.count
+}
+
 fn prefer_bpf() -> bool {
     std::env::var("BPF_OUT_DIR").is_ok() || std::env::var("SBF_OUT_DIR").is_ok()
 }
@@ -105,6 +151,13 @@ pub async fn setup_programs(program_owner: Pubkey) -> Result<ProgramTest, Error>
         None,
     );
 
+    add_program_upgradeable(
+        &mut program_test,
+        "dummy_transfer_hook",
+        dummy_transfer_hook::ID,
+        None,
+    );
+
     add_program_upgradeable(
         &mut program_test,
         "mainnet_core_bridge",
@@ -268,9 +321,58 @@ pub async fn setup_ntt_with_token_program_id(
     .unwrap();
 }
 
+/// Registers the legacy Wormhole transceiver built into the manager alongside
+/// the shim transceiver registered in [`setup_ntt`], with the same peer on
+/// [`OTHER_CHAIN`], as deployments do while moving over to the shim.
+#[cfg(feature = "shim")]
+pub async fn register_legacy_transceiver(ctx: &mut ProgramTestContext, test_data: &TestData) {
+    use crate::sdk::transceivers::legacy::{
+        accounts::{good_ntt_transceiver as legacy_transceiver, NTTTransceiverAccounts as _},
+        instructions::admin as legacy_admin,
+    };
+
+    register_transceiver(
+        &good_ntt,
+        RegisterTransceiver {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            transceiver: legacy_transceiver.program(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], ctx)
+    .await
+    .unwrap();
+
+    legacy_admin::set_transceiver_peer(
+        &good_ntt,
+        &legacy_transceiver,
+        legacy_admin::SetTransceiverPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        legacy_admin::SetTransceiverPeerArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            address: OTHER_TRANSCEIVER,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], ctx)
+    .await
+    .unwrap();
+}
+
 pub async fn setup_accounts(ctx: &mut ProgramTestContext, program_owner: Keypair) -> TestData {
+    setup_accounts_with_mint(ctx, program_owner, Keypair::new()).await
+}
+
+/// [`setup_accounts`], with the mint created at the address of `mint`, for
+/// deployments whose config already refers to it (see
+/// [`super::setup_with_v_prev_accounts`]).
+pub async fn setup_accounts_with_mint(
+    ctx: &mut ProgramTestContext,
+    program_owner: Keypair,
+    mint: Keypair,
+) -> TestData {
     // create mint
-    let mint = Keypair::new();
     let mint_authority = Keypair::new();
 
     let bad_mint = Keypair::new();
@@ -321,31 +423,23 @@ pub async fn setup_accounts(ctx: &mut ProgramTestContext, program_owner: Keypair
     .await
     .unwrap();
 
-    spl_token::instruction::mint_to(
-        &Token::id(),
+    fund_token_account(
+        ctx,
         &mint.pubkey(),
+        &mint_authority,
         &user_token_account,
-        &mint_authority.pubkey(),
-        &[],
         MINT_AMOUNT,
     )
-    .unwrap()
-    .submit_with_signers(&[&mint_authority], ctx)
-    .await
-    .unwrap();
+    .await;
 
-    spl_token::instruction::mint_to(
-        &Token::id(),
+    fund_token_account(
+        ctx,
         &bad_mint.pubkey(),
+        &bad_mint_authority,
         &bad_user_token_account,
-        &bad_mint_authority.pubkey(),
-        &[],
         MINT_AMOUNT,
     )
-    .unwrap()
-    .submit_with_signers(&[&bad_mint_authority], ctx)
-    .await
-    .unwrap();
+    .await;
 
     TestData {
         governance: Governance {
@@ -373,9 +467,6 @@ pub async fn setup_accounts_with_transfer_fee(
     let bad_mint = Keypair::new();
     let bad_mint_authority = Keypair::new();
 
-    let user = Keypair::new();
-    let payer = ctx.payer.pubkey();
-
     create_mint_with_transfer_fee(ctx, &mint, &mint_authority.pubkey(), 9, 500, 5000)
         .await
         .submit_with_signers(&[&mint], ctx)
@@ -388,6 +479,120 @@ pub async fn setup_accounts_with_transfer_fee(
         .await
         .unwrap();
 
+    setup_token_2022_accounts(
+        ctx,
+        program_owner,
+        mint,
+        mint_authority,
+        bad_mint,
+        bad_mint_authority,
+    )
+    .await
+}
+
+pub async fn setup_accounts_with_confidential_transfers(
+    ctx: &mut ProgramTestContext,
+    program_owner: Keypair,
+) -> TestData {
+    // create mint
+    let mint = Keypair::new();
+    let mint_authority = Keypair::new();
+
+    let bad_mint = Keypair::new();
+    let bad_mint_authority = Keypair::new();
+
+    create_mint_with_confidential_transfers(ctx, &mint, &mint_authority.pubkey(), 9)
+        .await
+        .submit_with_signers(&[&mint], ctx)
+        .await
+        .unwrap();
+
+    create_mint_with_confidential_transfers(ctx, &bad_mint, &bad_mint_authority.pubkey(), 9)
+        .await
+        .submit_with_signers(&[&bad_mint], ctx)
+        .await
+        .unwrap();
+
+    setup_token_2022_accounts(
+        ctx,
+        program_owner,
+        mint,
+        mint_authority,
+        bad_mint,
+        bad_mint_authority,
+    )
+    .await
+}
+
+/// NOTE: only the transfer hook of the mint is set up (the hook counts in a
+/// single account), so transfers of the bad mint fail.
+pub async fn setup_accounts_with_transfer_hook(
+    ctx: &mut ProgramTestContext,
+    program_owner: Keypair,
+) -> TestData {
+    // create mint
+    let mint = Keypair::new();
+    let mint_authority = Keypair::new();
+
+    let bad_mint = Keypair::new();
+    let bad_mint_authority = Keypair::new();
+
+    create_mint_with_transfer_hook(ctx, &mint, &mint_authority.pubkey(), 9)
+        .await
+        .submit_with_signers(&[&mint], ctx)
+        .await
+        .unwrap();
+
+    create_mint_with_transfer_hook(ctx, &bad_mint, &bad_mint_authority.pubkey(), 9)
+        .await
+        .submit_with_signers(&[&bad_mint], ctx)
+        .await
+        .unwrap();
+
+    Instruction {
+        program_id: dummy_transfer_hook::ID,
+        accounts: dummy_transfer_hook::accounts::InitializeExtraAccountMetaList {
+            payer: ctx.payer.pubkey(),
+            extra_account_meta_list: get_extra_account_metas_address(
+                &mint.pubkey(),
+                &dummy_transfer_hook::ID,
+            ),
+            mint: mint.pubkey(),
+            token_program: spl_token_2022::id(),
+            associated_token_program: spl_associated_token_account::id(),
+            counter: transfer_hook_counter(),
+            system_program: System::id(),
+        }
+        .to_account_metas(None),
+        data: dummy_transfer_hook::instruction::InitializeExtraAccountMetaList {}.data(),
+    }
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    setup_token_2022_accounts(
+        ctx,
+        program_owner,
+        mint,
+        mint_authority,
+        bad_mint,
+        bad_mint_authority,
+    )
+    .await
+}
+
+/// Creates and funds the user's token accounts for token-2022 mints.
+async fn setup_token_2022_accounts(
+    ctx: &mut ProgramTestContext,
+    program_owner: Keypair,
+    mint: Keypair,
+    mint_authority: Keypair,
+    bad_mint: Keypair,
+    bad_mint_authority: Keypair,
+) -> TestData {
+    let user = Keypair::new();
+    let payer = ctx.payer.pubkey();
+
     // create associated token account for user
     let user_token_account = get_associated_token_address_with_program_id(
         &user.pubkey(),
@@ -421,31 +626,23 @@ pub async fn setup_accounts_with_transfer_fee(
     .await
     .unwrap();
 
-    spl_token_2022::instruction::mint_to(
-        &spl_token_2022::id(),
+    fund_token_account(
+        ctx,
         &mint.pubkey(),
+        &mint_authority,
         &user_token_account,
-        &mint_authority.pubkey(),
-        &[],
         MINT_AMOUNT,
     )
-    .unwrap()
-    .submit_with_signers(&[&mint_authority], ctx)
-    .await
-    .unwrap();
+    .await;
 
-    spl_token_2022::instruction::mint_to(
-        &spl_token_2022::id(),
+    fund_token_account(
+        ctx,
         &bad_mint.pubkey(),
+        &bad_mint_authority,
         &bad_user_token_account,
-        &bad_mint_authority.pubkey(),
-        &[],
         MINT_AMOUNT,
     )
-    .unwrap()
-    .submit_with_signers(&[&bad_mint_authority], ctx)
-    .await
-    .unwrap();
+    .await;
 
     TestData {
         governance: Governance {
@@ -482,11 +679,13 @@ pub async fn create_mint(
                 Mint::LEN as u64,
                 &spl_token::ID,
             ),
+            // the mint authority can also freeze accounts, to test frozen
+            // recipients
             spl_token::instruction::initialize_mint2(
                 &spl_token::ID,
                 &mint.pubkey(),
                 mint_authority,
-                None,
+                Some(mint_authority),
                 decimals,
             )
             .unwrap(),
@@ -548,6 +747,116 @@ pub async fn create_mint_with_transfer_fee(
     )
 }
 
+pub async fn create_mint_with_confidential_transfers(
+    ctx: &mut ProgramTestContext,
+    mint: &Keypair,
+    mint_authority: &Pubkey,
+    decimals: u8,
+) -> Transaction {
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let extension_types = vec![spl_token_2022::extension::ExtensionType::ConfidentialTransferMint];
+    let space = spl_token_2022::extension::ExtensionType::try_calculate_account_len::<
+        spl_token_2022::state::Mint,
+    >(&extension_types)
+    .unwrap();
+    let mint_rent = rent.minimum_balance(space);
+
+    let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+
+    Transaction::new_signed_with_payer(
+        &[
+            system_instruction::create_account(
+                &ctx.payer.pubkey(),
+                &mint.pubkey(),
+                mint_rent,
+                space as u64,
+                &spl_token_2022::id(),
+            ),
+            spl_token_2022::extension::confidential_transfer::instruction::initialize_mint(
+                &spl_token_2022::id(),
+                &mint.pubkey(),
+                Some(*mint_authority),
+                true,
+                None,
+            )
+            .unwrap(),
+            spl_token_2022::instruction::initialize_mint2(
+                &spl_token_2022::id(),
+                &mint.pubkey(),
+                mint_authority,
+                None,
+                decimals,
+            )
+            .unwrap(),
+        ],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &mint],
+        blockhash,
+    )
+}
+
+pub async fn create_mint_with_transfer_hook(
+    ctx: &mut ProgramTestContext,
+    mint: &Keypair,
+    mint_authority: &Pubkey,
+    decimals: u8,
+) -> Transaction {
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let extension_types = vec![spl_token_2022::extension::ExtensionType::TransferHook];
+    let space = spl_token_2022::extension::ExtensionType::try_calculate_account_len::<
+        spl_token_2022::state::Mint,
+    >(&extension_types)
+    .unwrap();
+    let mint_rent = rent.minimum_balance(space);
+
+    let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+
+    Transaction::new_signed_with_payer(
+        &[
+            system_instruction::create_account(
+                &ctx.payer.pubkey(),
+                &mint.pubkey(),
+                mint_rent,
+                space as u64,
+                &spl_token_2022::id(),
+            ),
+            spl_token_2022::extension::transfer_hook::instruction::initialize(
+                &spl_token_2022::id(),
+                &mint.pubkey(),
+                Some(*mint_authority),
+                Some(dummy_transfer_hook::ID),
+            )
+            .unwrap(),
+            spl_token_2022::instruction::initialize_mint2(
+                &spl_token_2022::id(),
+                &mint.pubkey(),
+                mint_authority,
+                None,
+                decimals,
+            )
+            .unwrap(),
+        ],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &mint],
+        blockhash,
+    )
+}
+
+/// Overwrites the mint authority of the (classic token) `mint`, as if it had
+/// been rotated by whoever holds it. In burning mode that is the token
+/// authority, which can't sign in tests.
+pub async fn overwrite_mint_authority(
+    ctx: &mut ProgramTestContext,
+    mint: &Pubkey,
+    mint_authority: Option<Pubkey>,
+) {
+    let mut mint_account = ctx.banks_client.get_account(*mint).await.unwrap().unwrap();
+    let mut mint_state = spl_token::state::Mint::unpack(&mint_account.data).unwrap();
+    mint_state.mint_authority = mint_authority.into();
+    spl_token::state::Mint::pack(mint_state, &mut mint_account.data).unwrap();
+    ctx.set_account(mint, &mint_account.into());
+}
+
 // TODO: upstream this to solana-program-test
 
 /// Add a SBF program to the test environment. (copied from solana_program_test
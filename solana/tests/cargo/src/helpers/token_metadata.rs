@@ -0,0 +1,59 @@
+use anchor_lang::{prelude::Pubkey, AnchorSerialize};
+use example_native_token_transfers::token_metadata::{metadata_address, metadata_program};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{account::Account, keccak};
+
+/// Writes a Metaplex metadata account for `mint`, with the strings padded the
+/// way the metadata program pads them. Only the fields up to the URI are
+/// meaningful.
+pub async fn add_token_metadata(
+    ctx: &mut ProgramTestContext,
+    mint: &Pubkey,
+    name: &str,
+    symbol: &str,
+    uri: &str,
+) {
+    let mut data = vec![4u8]; // Key::MetadataV1
+    data.extend(Pubkey::new_unique().to_bytes()); // update authority
+    data.extend(mint.to_bytes());
+    for (value, max_len) in [(name, 32), (symbol, 10), (uri, 200)] {
+        format!("{value}{}", "\0".repeat(max_len - value.len()))
+            .serialize(&mut data)
+            .unwrap();
+    }
+    // seller fee basis points, no creators, primary sale happened, is mutable
+    data.extend([0u8; 2 + 1 + 1 + 1]);
+
+    let lamports = ctx
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(data.len());
+    ctx.set_account(
+        &metadata_address(mint),
+        &Account {
+            lamports,
+            data,
+            owner: metadata_program::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+}
+
+/// The hash of the token metadata in
+/// [`ntt_messages::transceivers::wormhole::WormholeTransceiverInfo`].
+pub fn token_metadata_hash(name: &str, symbol: &str, uri: &str) -> [u8; 32] {
+    let len = |s: &str| u32::try_from(s.len()).unwrap().to_be_bytes();
+    keccak::hashv(&[
+        &len(name),
+        name.as_bytes(),
+        &len(symbol),
+        symbol.as_bytes(),
+        &len(uri),
+        uri.as_bytes(),
+    ])
+    .to_bytes()
+}
@@ -1,5 +1,5 @@
-use anchor_lang::prelude::Pubkey;
-use example_native_token_transfers::instructions::TransferArgs;
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize, Pubkey};
+use example_native_token_transfers::instructions::{TransferArgs, TransferPreview};
 use example_native_token_transfers::transfer::Payload;
 use ntt_messages::{
     chain_id::ChainId, ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage,
@@ -7,11 +7,21 @@ use ntt_messages::{
     trimmed_amount::TrimmedAmount,
 };
 use solana_program_test::ProgramTestContext;
-use solana_sdk::signer::Signer;
+use solana_sdk::{instruction::Instruction, signer::Signer};
+use wormhole_io::TypePrefixedPayload;
 
 use crate::{
-    common::fixtures::{TestData, OTHER_CHAIN, OTHER_MANAGER, THIS_CHAIN},
-    sdk::{accounts::NTT, instructions::transfer::Transfer},
+    common::{
+        fixtures::{TestData, OTHER_CHAIN, OTHER_MANAGER, THIS_CHAIN},
+        submit::Submittable,
+    },
+    sdk::{
+        accounts::NTT,
+        instructions::{
+            preview_transfer::{preview_transfer, PreviewTransfer},
+            transfer::{add_extra_account_metas_for_transfer, Transfer},
+        },
+    },
 };
 
 /// Helper function for setting up transfer accounts and args.
@@ -32,6 +42,8 @@ pub fn init_transfer_accs_args(
         from_authority: test_data.user.pubkey(),
         peer: ntt.peer(OTHER_CHAIN),
         outbox_item,
+        dust_account: None,
+        sender_limited: false,
     };
 
     let args = TransferArgs {
@@ -44,6 +56,32 @@ pub fn init_transfer_accs_args(
     (accs, args)
 }
 
+/// Previews the transfer `accs` and `args` describe, see
+/// [`example_native_token_transfers::instructions::preview_transfer`].
+pub async fn transfer_preview(
+    ntt: &NTT,
+    ctx: &mut ProgramTestContext,
+    accs: &Transfer,
+    args: &TransferArgs,
+) -> TransferPreview {
+    let out = preview_transfer(
+        ntt,
+        PreviewTransfer {
+            mint: accs.mint,
+            from: accs.from,
+            from_authority: accs.from_authority,
+        },
+        args.clone(),
+    )
+    .simulate(ctx)
+    .await
+    .unwrap();
+    assert!(out.result.unwrap().is_ok());
+
+    let return_data = out.simulation_details.unwrap().return_data.unwrap().data;
+    TransferPreview::deserialize(&mut &return_data[..]).unwrap()
+}
+
 pub fn make_transfer_message(
     ntt: &NTT,
     id: [u8; 32],
@@ -54,6 +92,7 @@ pub fn make_transfer_message(
         id,
         sender: [4u8; 32],
         payload: NativeTokenTransfer {
+            version: NativeTokenTransfer::<Payload>::CURRENT_VERSION,
             amount: TrimmedAmount {
                 amount,
                 decimals: 9,
@@ -72,3 +111,72 @@ pub fn make_transfer_message(
         vec![],
     )
 }
+
+/// An already encoded message, posted as is (see [`with_additional_payload`]).
+#[derive(Clone)]
+pub struct RawMessage(pub Vec<u8>);
+
+impl AnchorSerialize for RawMessage {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&self.0)
+    }
+}
+
+/// `msg` encoded with `additional_payload` attached to the transfer, the way
+/// the EVM manager encodes transfers that carry a payload. [`Payload`] doesn't
+/// decode it, so it's left unconsumed on receive.
+pub fn with_additional_payload(
+    msg: &TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>>,
+    additional_payload: &[u8],
+) -> RawMessage {
+    // prefix, source and recipient manager, followed by the manager payload
+    // length
+    const LEN_OFFSET: usize = 4 + 32 + 32;
+
+    let mut data = msg.to_vec_payload();
+    let len = u16::from_be_bytes([data[LEN_OFFSET], data[LEN_OFFSET + 1]]);
+    let end = LEN_OFFSET + 2 + usize::from(len);
+
+    let mut extra = u16::try_from(additional_payload.len())
+        .unwrap()
+        .to_be_bytes()
+        .to_vec();
+    extra.extend_from_slice(additional_payload);
+    let new_len = len + u16::try_from(extra.len()).unwrap();
+
+    data.splice(end..end, extra);
+    data[LEN_OFFSET..LEN_OFFSET + 2].copy_from_slice(&new_len.to_be_bytes());
+    RawMessage(data)
+}
+
+/// Appends the accounts required by the transfer hook of `mint` to
+/// `instruction`, resolved against the accounts in `ctx` (see
+/// [`add_extra_account_metas_for_transfer`]).
+pub async fn add_transfer_hook_accounts(
+    ctx: &ProgramTestContext,
+    instruction: &mut Instruction,
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+) {
+    let banks_client = ctx.banks_client.clone();
+    add_extra_account_metas_for_transfer(
+        instruction,
+        source,
+        mint,
+        destination,
+        authority,
+        amount,
+        |address| {
+            let mut banks_client = banks_client.clone();
+            async move {
+                let account = banks_client.get_account(address).await?;
+                Ok(account.map(|account| account.data))
+            }
+        },
+    )
+    .await
+    .unwrap();
+}
@@ -1,4 +1,3 @@
-#![feature(type_changing_struct_update)]
 pub mod common;
 pub mod helpers;
 pub mod sdk;
@@ -1,10 +1,13 @@
 use anchor_lang::{prelude::Pubkey, Id};
 use example_native_token_transfers::{
     config::Config,
-    instructions::TransferArgs,
+    custody_ledger::CustodyLedger,
+    instructions::{TransferArgs, TransferOptions},
+    peer_stats::PeerStats,
+    pending_mint_migration::PendingMintMigration,
     queue::{
         inbox::{InboxItem, InboxRateLimit},
-        outbox::OutboxRateLimit,
+        outbox::{ChainOutboxRateLimit, OutboxRateLimit, SenderRateLimit},
     },
     registered_transceiver::RegisteredTransceiver,
     transfer::Payload,
@@ -43,6 +46,26 @@ pub trait NTTAccounts {
         outbox_rate_limit
     }
 
+    fn sender_rate_limit(&self, sender: &Pubkey) -> Pubkey {
+        let (sender_rate_limit, _) = Pubkey::find_program_address(
+            &[SenderRateLimit::SEED_PREFIX, sender.as_ref()],
+            &self.program(),
+        );
+        sender_rate_limit
+    }
+
+    fn custody_ledger(&self) -> Pubkey {
+        let (custody_ledger, _) =
+            Pubkey::find_program_address(&[CustodyLedger::SEED_PREFIX], &self.program());
+        custody_ledger
+    }
+
+    fn pending_mint_migration(&self) -> Pubkey {
+        let (pending_mint_migration, _) =
+            Pubkey::find_program_address(&[PendingMintMigration::SEED_PREFIX], &self.program());
+        pending_mint_migration
+    }
+
     fn inbox_rate_limit(&self, chain: u16) -> Pubkey {
         let (inbox_rate_limit, _) = Pubkey::find_program_address(
             &[InboxRateLimit::SEED_PREFIX, &chain.to_be_bytes()],
@@ -51,19 +74,54 @@ pub trait NTTAccounts {
         inbox_rate_limit
     }
 
+    fn chain_outbox_rate_limit(&self, chain: u16) -> Pubkey {
+        let (chain_outbox_rate_limit, _) = Pubkey::find_program_address(
+            &[ChainOutboxRateLimit::SEED_PREFIX, &chain.to_be_bytes()],
+            &self.program(),
+        );
+        chain_outbox_rate_limit
+    }
+
+    fn peer_stats(&self, chain: u16) -> Pubkey {
+        let (peer_stats, _) = Pubkey::find_program_address(
+            &[PeerStats::SEED_PREFIX, &chain.to_be_bytes()],
+            &self.program(),
+        );
+        peer_stats
+    }
+
     fn session_authority(&self, sender: &Pubkey, args: &TransferArgs) -> Pubkey {
+        self.session_authority_with_options(sender, args, &TransferOptions::default())
+    }
+
+    fn session_authority_with_options(
+        &self,
+        sender: &Pubkey,
+        args: &TransferArgs,
+        options: &TransferOptions,
+    ) -> Pubkey {
         let TransferArgs {
             amount,
             recipient_chain,
             recipient_address,
             should_queue,
         } = args;
+        let TransferOptions {
+            min_amount_out,
+            expiry_timestamp,
+        } = options;
         let mut hasher = Keccak256::new();
 
         hasher.update(amount.to_be_bytes());
         hasher.update(recipient_chain.id.to_be_bytes());
         hasher.update(recipient_address);
         hasher.update([*should_queue as u8]);
+        if let Some(min_amount_out) = min_amount_out {
+            hasher.update(min_amount_out.to_be_bytes());
+        }
+        if let Some(expiry_timestamp) = expiry_timestamp {
+            hasher.update(expiry_timestamp.to_be_bytes());
+        }
 
         let (session_authority, _) = Pubkey::find_program_address(
             &[SESSION_AUTHORITY_SEED, sender.as_ref(), &hasher.finalize()],
@@ -110,6 +168,12 @@ pub trait NTTAccounts {
         peer
     }
 
+    fn peer_index(&self) -> Pubkey {
+        let (peer_index, _) =
+            Pubkey::find_program_address(&[b"peer_index".as_ref()], &self.program());
+        peer_index
+    }
+
     fn custody(&self, mint: &Pubkey) -> Pubkey {
         self.custody_with_token_program_id(mint, &anchor_spl::token::spl_token::ID)
     }
@@ -136,6 +200,12 @@ pub trait NTTAccounts {
         let (addr, _) = Pubkey::find_program_address(&[b"upgrade_lock"], &self.program());
         addr
     }
+
+    /// The PDA that signs the self-CPI of events emitted via `emit_cpi!`.
+    fn event_authority(&self) -> Pubkey {
+        let (addr, _) = Pubkey::find_program_address(&[b"__event_authority"], &self.program());
+        addr
+    }
 }
 
 /// This implements the account derivations correctly. For negative tests, other
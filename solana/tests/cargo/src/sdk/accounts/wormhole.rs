@@ -1,6 +1,14 @@
 use anchor_lang::prelude::Pubkey;
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
 use wormhole_anchor_sdk::wormhole;
 
+/// Guardian set addresses and bumps by Wormhole program and guardian set
+/// index, see [`Wormhole::cached_guardian_set_with_bump`].
+static GUARDIAN_SETS: OnceLock<Mutex<HashMap<(Pubkey, u32), (Pubkey, u8)>>> = OnceLock::new();
+
 pub struct Wormhole {
     pub program: Pubkey,
 }
@@ -34,6 +42,17 @@ impl Wormhole {
         (guardian_set, guardian_set_bump)
     }
 
+    /// Like [`Self::guardian_set_with_bump`], but the address is only derived
+    /// the first time, as every receive needs it.
+    pub fn cached_guardian_set_with_bump(&self, guardian_set_index: u32) -> (Pubkey, u8) {
+        *GUARDIAN_SETS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .entry((self.program, guardian_set_index))
+            .or_insert_with(|| self.guardian_set_with_bump(guardian_set_index))
+    }
+
     pub fn guardian_set(&self, guardian_set_index: u32) -> Pubkey {
         self.guardian_set_with_bump(guardian_set_index).0
     }
@@ -1,5 +1,9 @@
 use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
-use example_native_token_transfers::instructions::{SetOutboundLimitArgs, SetPeerArgs};
+use anchor_spl::associated_token::AssociatedToken;
+use example_native_token_transfers::instructions::{
+    ForceMarkReleasedArgs, PurgeInboxItemArgs, SetChainOutboundLimitArgs, SetInboundLimitArgs,
+    SetOutboundLimitArgs, SetPeerArgs, SetPeerOptions, SetSenderLimitOverrideArgs,
+};
 use solana_sdk::instruction::Instruction;
 
 use crate::sdk::accounts::NTT;
@@ -13,15 +17,82 @@ pub fn set_peer(ntt: &NTT, accounts: SetPeer, args: SetPeerArgs) -> Instruction
     let chain_id = args.chain_id.id;
     let data = example_native_token_transfers::instruction::SetPeer { args };
 
+    set_peer_with_data(ntt, accounts, chain_id, data.data())
+}
+
+pub fn set_peer_with_options(
+    ntt: &NTT,
+    accounts: SetPeer,
+    args: SetPeerArgs,
+    options: SetPeerOptions,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::SetPeerWithOptions { args, options };
+
+    set_peer_with_data(ntt, accounts, chain_id, data.data())
+}
+
+fn set_peer_with_data(ntt: &NTT, accounts: SetPeer, chain_id: u16, data: Vec<u8>) -> Instruction {
     let accounts = example_native_token_transfers::accounts::SetPeer {
         config: ntt.config(),
         owner: accounts.owner,
         payer: accounts.payer,
         peer: ntt.peer(chain_id),
         inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+        peer_index: ntt.peer_index(),
         system_program: System::id(),
     };
 
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data,
+    }
+}
+
+pub struct DeletePeer {
+    pub owner: Pubkey,
+}
+
+pub fn delete_peer(ntt: &NTT, accounts: DeletePeer, chain_id: u16) -> Instruction {
+    let data = example_native_token_transfers::instruction::DeletePeer { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::DeletePeer {
+        owner: accounts.owner,
+        config: ntt.config(),
+        peer: ntt.peer(chain_id),
+        inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+        peer_index: ntt.peer_index(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn get_peer_index(ntt: &NTT) -> Instruction {
+    let data = example_native_token_transfers::instruction::GetPeerIndex {};
+
+    let accounts = example_native_token_transfers::accounts::GetPeerIndex {
+        peer_index: ntt.peer_index(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn get_configured_chains(ntt: &NTT) -> Instruction {
+    let data = example_native_token_transfers::instruction::GetConfiguredChains {};
+
+    let accounts = example_native_token_transfers::accounts::GetConfiguredChains {
+        peer_index: ntt.peer_index(),
+    };
+
     Instruction {
         program_id: ntt.program(),
         accounts: accounts.to_account_metas(None),
@@ -36,11 +107,94 @@ pub struct SetPaused {
 pub fn set_paused(ntt: &NTT, accounts: SetPaused, pause: bool) -> Instruction {
     let data = example_native_token_transfers::instruction::SetPaused { pause };
 
+    set_paused_with_data(ntt, accounts, data.data())
+}
+
+pub fn set_outbound_paused(ntt: &NTT, accounts: SetPaused, pause: bool) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetOutboundPaused { pause };
+
+    set_paused_with_data(ntt, accounts, data.data())
+}
+
+pub fn set_inbound_paused(ntt: &NTT, accounts: SetPaused, pause: bool) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetInboundPaused { pause };
+
+    set_paused_with_data(ntt, accounts, data.data())
+}
+
+fn set_paused_with_data(ntt: &NTT, accounts: SetPaused, data: Vec<u8>) -> Instruction {
     let accounts = example_native_token_transfers::accounts::SetPaused {
         owner: accounts.owner,
         config: ntt.config(),
     };
 
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data,
+    }
+}
+
+pub struct SetReceiveHook {
+    pub owner: Pubkey,
+}
+
+pub fn set_receive_hook(
+    ntt: &NTT,
+    accounts: SetReceiveHook,
+    receive_hook: Option<Pubkey>,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetReceiveHook { receive_hook };
+
+    let accounts = example_native_token_transfers::accounts::SetReceiveHook {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetDustAccount {
+    pub owner: Pubkey,
+    pub dust_account: Option<Pubkey>,
+}
+
+pub fn set_dust_account(ntt: &NTT, accounts: SetDustAccount) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetDustAccount {};
+
+    let accounts = example_native_token_transfers::accounts::SetDustAccount {
+        owner: accounts.owner,
+        config: ntt.config(),
+        dust_account: accounts.dust_account,
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetFeeCollector {
+    pub owner: Pubkey,
+}
+
+pub fn set_fee_collector(
+    ntt: &NTT,
+    accounts: SetFeeCollector,
+    fee_collector: Pubkey,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetFeeCollector { fee_collector };
+
+    let accounts = example_native_token_transfers::accounts::SetFeeCollector {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
     Instruction {
         program_id: ntt.program(),
         accounts: accounts.to_account_metas(None),
@@ -94,6 +248,33 @@ pub fn deregister_transceiver(ntt: &NTT, accounts: DeregisterTransceiver) -> Ins
     }
 }
 
+pub struct SwapTransceivers {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+    pub old_transceiver: Pubkey,
+    pub new_transceiver: Pubkey,
+}
+
+pub fn swap_transceivers(ntt: &NTT, accounts: SwapTransceivers) -> Instruction {
+    let data = example_native_token_transfers::instruction::SwapTransceivers {};
+
+    let accounts = example_native_token_transfers::accounts::SwapTransceivers {
+        config: ntt.config(),
+        owner: accounts.owner,
+        payer: accounts.payer,
+        old_registered_transceiver: ntt.registered_transceiver(&accounts.old_transceiver),
+        new_transceiver: accounts.new_transceiver,
+        new_registered_transceiver: ntt.registered_transceiver(&accounts.new_transceiver),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
 pub struct SetThreshold {
     pub owner: Pubkey,
 }
@@ -113,21 +294,700 @@ pub fn set_threshold(ntt: &NTT, accounts: SetThreshold, threshold: u8) -> Instru
     }
 }
 
-pub struct SetOutboundLimit {
+pub struct SetRouteEnabled {
     pub owner: Pubkey,
 }
 
-pub fn set_outbound_limit(
+pub fn enable_route(ntt: &NTT, accounts: SetRouteEnabled, chain_id: u16) -> Instruction {
+    let data = example_native_token_transfers::instruction::EnableRoute {
+        _chain_id: chain_id,
+    };
+
+    set_route_enabled(ntt, accounts, chain_id, data.data())
+}
+
+pub fn disable_route(ntt: &NTT, accounts: SetRouteEnabled, chain_id: u16) -> Instruction {
+    let data = example_native_token_transfers::instruction::DisableRoute {
+        _chain_id: chain_id,
+    };
+
+    set_route_enabled(ntt, accounts, chain_id, data.data())
+}
+
+fn set_route_enabled(
     ntt: &NTT,
-    accounts: SetOutboundLimit,
-    args: SetOutboundLimitArgs,
+    accounts: SetRouteEnabled,
+    chain_id: u16,
+    data: Vec<u8>,
 ) -> Instruction {
-    let data = example_native_token_transfers::instruction::SetOutboundLimit { args };
+    let accounts = example_native_token_transfers::accounts::SetRouteEnabled {
+        owner: accounts.owner,
+        config: ntt.config(),
+        peer: ntt.peer(chain_id),
+    };
 
-    let accounts = example_native_token_transfers::accounts::SetOutboundLimit {
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data,
+    }
+}
+
+pub struct SetRoutesDefaultEnabled {
+    pub owner: Pubkey,
+}
+
+pub fn set_routes_default_enabled(
+    ntt: &NTT,
+    accounts: SetRoutesDefaultEnabled,
+    enabled: bool,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetRoutesDefaultEnabled { enabled };
+
+    let accounts = example_native_token_transfers::accounts::SetRoutesDefaultEnabled {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetMaxOutboundInFlight {
+    pub owner: Pubkey,
+}
+
+pub fn set_max_outbound_in_flight(
+    ntt: &NTT,
+    accounts: SetMaxOutboundInFlight,
+    chain_id: u16,
+    max: Option<u64>,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetMaxOutboundInFlight {
+        _chain_id: chain_id,
+        max,
+    };
+
+    let accounts = example_native_token_transfers::accounts::SetMaxOutboundInFlight {
+        owner: accounts.owner,
         config: ntt.config(),
+        peer: ntt.peer(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetAllowQueue {
+    pub owner: Pubkey,
+}
+
+pub fn set_allow_queue(
+    ntt: &NTT,
+    accounts: SetAllowQueue,
+    chain_id: u16,
+    allow_queue: bool,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetAllowQueue {
+        _chain_id: chain_id,
+        allow_queue,
+    };
+
+    let accounts = example_native_token_transfers::accounts::SetAllowQueue {
         owner: accounts.owner,
-        rate_limit: ntt.outbox_rate_limit(),
+        config: ntt.config(),
+        peer: ntt.peer(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn set_allow_inbound_queue(
+    ntt: &NTT,
+    accounts: SetAllowQueue,
+    chain_id: u16,
+    allow_inbound_queue: bool,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetAllowInboundQueue {
+        _chain_id: chain_id,
+        allow_inbound_queue,
+    };
+
+    let accounts = example_native_token_transfers::accounts::SetAllowQueue {
+        owner: accounts.owner,
+        config: ntt.config(),
+        peer: ntt.peer(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetDestinationFeeBps {
+    pub owner: Pubkey,
+}
+
+pub fn set_destination_fee_bps(
+    ntt: &NTT,
+    accounts: SetDestinationFeeBps,
+    chain_id: u16,
+    fee_bps: u16,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetDestinationFeeBps {
+        _chain_id: chain_id,
+        fee_bps,
+    };
+
+    let accounts = example_native_token_transfers::accounts::SetDestinationFeeBps {
+        owner: accounts.owner,
+        config: ntt.config(),
+        peer: ntt.peer(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetFallbackRecipient {
+    pub owner: Pubkey,
+    pub fallback_recipient: Option<Pubkey>,
+}
+
+pub fn set_fallback_recipient(
+    ntt: &NTT,
+    accounts: SetFallbackRecipient,
+    chain_id: u16,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetFallbackRecipient {
+        _chain_id: chain_id,
+    };
+
+    let accounts = example_native_token_transfers::accounts::SetFallbackRecipient {
+        owner: accounts.owner,
+        config: ntt.config(),
+        peer: ntt.peer(chain_id),
+        fallback_recipient: accounts.fallback_recipient,
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetLimitsManager {
+    pub owner: Pubkey,
+}
+
+pub fn set_limits_manager(
+    ntt: &NTT,
+    accounts: SetLimitsManager,
+    limits_manager: Option<Pubkey>,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetLimitsManager { limits_manager };
+
+    let accounts = example_native_token_transfers::accounts::SetLimitsManager {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetMaxVaaAge {
+    pub owner: Pubkey,
+}
+
+pub fn set_max_vaa_age(ntt: &NTT, accounts: SetMaxVaaAge, max_vaa_age: Option<u32>) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetMaxVaaAge { max_vaa_age };
+
+    let accounts = example_native_token_transfers::accounts::SetMaxVaaAge {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetRejectUnconsumedPayload {
+    pub owner: Pubkey,
+}
+
+pub fn set_reject_unconsumed_payload(
+    ntt: &NTT,
+    accounts: SetRejectUnconsumedPayload,
+    reject: bool,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetRejectUnconsumedPayload { reject };
+
+    let accounts = example_native_token_transfers::accounts::SetRejectUnconsumedPayload {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetAllowedConsistencyLevels {
+    pub owner: Pubkey,
+}
+
+pub fn set_allowed_consistency_levels(
+    ntt: &NTT,
+    accounts: SetAllowedConsistencyLevels,
+    consistency_levels: Vec<u8>,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetAllowedConsistencyLevels {
+        consistency_levels,
+    };
+
+    let accounts = example_native_token_transfers::accounts::SetAllowedConsistencyLevels {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetOutboundLimit {
+    pub authority: Pubkey,
+}
+
+pub fn set_outbound_limit(
+    ntt: &NTT,
+    accounts: SetOutboundLimit,
+    args: SetOutboundLimitArgs,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetOutboundLimit { args };
+
+    let accounts = example_native_token_transfers::accounts::SetOutboundLimit {
+        config: ntt.config(),
+        authority: accounts.authority,
+        rate_limit: ntt.outbox_rate_limit(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetInboundLimit {
+    pub authority: Pubkey,
+}
+
+pub fn set_inbound_limit(
+    ntt: &NTT,
+    accounts: SetInboundLimit,
+    args: SetInboundLimitArgs,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::SetInboundLimit { args };
+
+    let accounts = example_native_token_transfers::accounts::SetInboundLimit {
+        config: ntt.config(),
+        authority: accounts.authority,
+        rate_limit: ntt.inbox_rate_limit(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetSenderLimit {
+    pub authority: Pubkey,
+}
+
+pub fn set_sender_limit(ntt: &NTT, accounts: SetSenderLimit, limit: Option<u64>) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetSenderLimit { limit };
+
+    let accounts = example_native_token_transfers::accounts::SetSenderLimit {
+        config: ntt.config(),
+        authority: accounts.authority,
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetSenderLimitOverride {
+    pub payer: Pubkey,
+    pub authority: Pubkey,
+}
+
+pub fn set_sender_limit_override(
+    ntt: &NTT,
+    accounts: SetSenderLimitOverride,
+    args: SetSenderLimitOverrideArgs,
+) -> Instruction {
+    let sender_rate_limit = ntt.sender_rate_limit(&args.sender);
+    let data = example_native_token_transfers::instruction::SetSenderLimitOverride { args };
+
+    let accounts = example_native_token_transfers::accounts::SetSenderLimitOverride {
+        payer: accounts.payer,
+        config: ntt.config(),
+        authority: accounts.authority,
+        sender_rate_limit,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetPerChainOutboundLimits {
+    pub authority: Pubkey,
+}
+
+pub fn set_per_chain_outbound_limits(
+    ntt: &NTT,
+    accounts: SetPerChainOutboundLimits,
+    enabled: bool,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetPerChainOutboundLimits { enabled };
+
+    let accounts = example_native_token_transfers::accounts::SetPerChainOutboundLimits {
+        config: ntt.config(),
+        authority: accounts.authority,
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetChainOutboundLimit {
+    pub payer: Pubkey,
+    pub authority: Pubkey,
+}
+
+pub fn set_chain_outbound_limit(
+    ntt: &NTT,
+    accounts: SetChainOutboundLimit,
+    args: SetChainOutboundLimitArgs,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::SetChainOutboundLimit { args };
+
+    let accounts = example_native_token_transfers::accounts::SetChainOutboundLimit {
+        payer: accounts.payer,
+        config: ntt.config(),
+        authority: accounts.authority,
+        rate_limit: ntt.chain_outbox_rate_limit(chain_id),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct PurgeInboxItem {
+    pub owner: Pubkey,
+    /// Use the deprecated account layout without the event authority accounts
+    /// (`purge_inbox_item` rather than `purge_inbox_item_v2`).
+    pub omit_event_authority: bool,
+}
+
+pub fn purge_inbox_item(
+    ntt: &NTT,
+    accounts: PurgeInboxItem,
+    args: PurgeInboxItemArgs,
+) -> Instruction {
+    let inbox_item = ntt.inbox_item(args.chain_id.id, args.ntt_manager_message.clone());
+
+    let common = example_native_token_transfers::accounts::PurgeInboxItem {
+        owner: accounts.owner,
+        config: ntt.config(),
+        inbox_item,
+        peer: Some(ntt.peer(args.chain_id.id)),
+    };
+
+    let (accounts, data) = if accounts.omit_event_authority {
+        (
+            common.to_account_metas(None),
+            example_native_token_transfers::instruction::PurgeInboxItem { args }.data(),
+        )
+    } else {
+        (
+            example_native_token_transfers::accounts::PurgeInboxItemV2 {
+                common,
+                event_authority: ntt.event_authority(),
+                program: ntt.program(),
+            }
+            .to_account_metas(None),
+            example_native_token_transfers::instruction::PurgeInboxItemV2 { args }.data(),
+        )
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts,
+        data,
+    }
+}
+
+pub struct MigrateConfig {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+}
+
+pub fn migrate_config(ntt: &NTT, accounts: MigrateConfig) -> Instruction {
+    let data = example_native_token_transfers::instruction::MigrateConfig {};
+
+    let accounts = example_native_token_transfers::accounts::MigrateConfig {
+        payer: accounts.payer,
+        owner: accounts.owner,
+        config: ntt.config(),
+        mint: accounts.mint,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct BeginMintMigration {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub new_mint: Pubkey,
+    pub new_token_program: Pubkey,
+}
+
+pub fn begin_mint_migration(ntt: &NTT, accounts: BeginMintMigration) -> Instruction {
+    let data = example_native_token_transfers::instruction::BeginMintMigration {};
+
+    let accounts = example_native_token_transfers::accounts::BeginMintMigration {
+        payer: accounts.payer,
+        owner: accounts.owner,
+        config: ntt.config(),
+        mint: accounts.mint,
+        new_mint: accounts.new_mint,
+        new_token_program: accounts.new_token_program,
+        pending_mint_migration: ntt.pending_mint_migration(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct CompleteMintMigration {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+    /// The rent payer of [`begin_mint_migration`].
+    pub rent_payer: Pubkey,
+    pub custody: Pubkey,
+    pub new_mint: Pubkey,
+    pub new_token_program: Pubkey,
+}
+
+pub fn complete_mint_migration(ntt: &NTT, accounts: CompleteMintMigration) -> Instruction {
+    let data = example_native_token_transfers::instruction::CompleteMintMigration {};
+
+    let accounts = example_native_token_transfers::accounts::CompleteMintMigration {
+        payer: accounts.payer,
+        owner: accounts.owner,
+        config: ntt.config(),
+        pending_mint_migration: ntt.pending_mint_migration(),
+        rent_payer: accounts.rent_payer,
+        custody: accounts.custody,
+        custody_ledger: ntt.custody_ledger(),
+        new_mint: accounts.new_mint,
+        token_authority: ntt.token_authority(),
+        new_custody: ntt
+            .custody_with_token_program_id(&accounts.new_mint, &accounts.new_token_program),
+        new_token_program: accounts.new_token_program,
+        associated_token_program: AssociatedToken::id(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ForceMarkReleased {
+    pub owner: Pubkey,
+    pub outbox_item: Pubkey,
+    pub recipient_chain: u16,
+}
+
+pub fn force_mark_released(
+    ntt: &NTT,
+    accounts: ForceMarkReleased,
+    args: ForceMarkReleasedArgs,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::ForceMarkReleased { args };
+
+    let accounts = example_native_token_transfers::accounts::ForceMarkReleased {
+        owner: accounts.owner,
+        config: ntt.config(),
+        outbox_item: accounts.outbox_item,
+        peer: ntt.peer(accounts.recipient_chain),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct BeginDecommission {
+    pub owner: Pubkey,
+}
+
+pub fn begin_decommission(
+    ntt: &NTT,
+    accounts: BeginDecommission,
+    grace_period_seconds: u64,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::BeginDecommission {
+        grace_period_seconds,
+    };
+
+    let accounts = example_native_token_transfers::accounts::BeginDecommission {
+        owner: accounts.owner,
+        config: ntt.config(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct FinalizeDecommission {
+    pub owner: Pubkey,
+}
+
+pub fn finalize_decommission(ntt: &NTT, accounts: FinalizeDecommission) -> Instruction {
+    let data = example_native_token_transfers::instruction::FinalizeDecommission {};
+
+    let accounts = example_native_token_transfers::accounts::FinalizeDecommission {
+        owner: accounts.owner,
+        config: ntt.config(),
+        peer_index: ntt.peer_index(),
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct TransferOwnership {
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+pub fn transfer_ownership(ntt: &NTT, accounts: TransferOwnership) -> Instruction {
+    let data = example_native_token_transfers::instruction::TransferOwnership {};
+
+    let accounts = example_native_token_transfers::accounts::TransferOwnership {
+        config: ntt.config(),
+        owner: accounts.owner,
+        new_owner: accounts.new_owner,
+        upgrade_lock: ntt.upgrade_lock(),
+        program_data: ntt.program_data(),
+        bpf_loader_upgradeable_program: solana_sdk::bpf_loader_upgradeable::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ClaimOwnership {
+    pub new_owner: Pubkey,
+}
+
+pub fn claim_ownership(ntt: &NTT, accounts: ClaimOwnership) -> Instruction {
+    let data = example_native_token_transfers::instruction::ClaimOwnership {};
+
+    let accounts = example_native_token_transfers::accounts::ClaimOwnership {
+        config: ntt.config(),
+        upgrade_lock: ntt.upgrade_lock(),
+        new_owner: accounts.new_owner,
+        program_data: ntt.program_data(),
+        bpf_loader_upgradeable_program: solana_sdk::bpf_loader_upgradeable::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn get_pending_owner(ntt: &NTT) -> Instruction {
+    let data = example_native_token_transfers::instruction::GetPendingOwner {};
+
+    let accounts = example_native_token_transfers::accounts::GetPendingOwner {
+        config: ntt.config(),
     };
 
     Instruction {
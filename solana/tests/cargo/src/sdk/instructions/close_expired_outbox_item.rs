@@ -0,0 +1,61 @@
+use anchor_lang::{prelude::*, InstructionData};
+use anchor_spl::token::Token;
+use ntt_messages::mode::Mode;
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct CloseExpiredOutboxItem {
+    pub outbox_item: Pubkey,
+    pub rent_payer: Pubkey,
+    /// The chain the transfer was sent to.
+    pub recipient_chain: u16,
+    pub sender: Pubkey,
+    pub mint: Pubkey,
+    /// The sender's token account the tokens are returned to.
+    pub to: Pubkey,
+    /// Whether the transfer consumed from the recipient chain's own outbound
+    /// limit, rather than the global one.
+    pub consumed_chain_limit: bool,
+    /// Whether the transfer consumed from the sender's limit.
+    pub consumed_sender_limit: bool,
+}
+
+pub fn close_expired_outbox_item(
+    ntt: &NTT,
+    accounts: CloseExpiredOutboxItem,
+    mode: Mode,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::CloseExpiredOutboxItem {};
+
+    let accounts = example_native_token_transfers::accounts::CloseExpiredOutboxItem {
+        outbox_item: accounts.outbox_item,
+        rent_payer: accounts.rent_payer,
+        peer: ntt.peer(accounts.recipient_chain),
+        config: ntt.config(),
+        mint: accounts.mint,
+        to: accounts.to,
+        token_authority: ntt.token_authority(),
+        custody: ntt.custody(&accounts.mint),
+        token_program: Token::id(),
+        custody_ledger: match mode {
+            Mode::Locking => Some(ntt.custody_ledger()),
+            Mode::Burning => None,
+        },
+        multisig_token_authority: None,
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+        chain_outbox_rate_limit: accounts
+            .consumed_chain_limit
+            .then(|| ntt.chain_outbox_rate_limit(accounts.recipient_chain)),
+        sender_rate_limit: accounts
+            .consumed_sender_limit
+            .then(|| ntt.sender_rate_limit(&accounts.sender)),
+        inbox_rate_limit: ntt.inbox_rate_limit(accounts.recipient_chain),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -0,0 +1,24 @@
+use anchor_lang::{prelude::Pubkey, InstructionData, ToAccountMetas};
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct CloseInboxItem {
+    pub inbox_item: Pubkey,
+    pub rent_recipient: Pubkey,
+}
+
+pub fn close_inbox_item(ntt: &NTT, accounts: CloseInboxItem) -> Instruction {
+    let data = example_native_token_transfers::instruction::CloseInboxItem {};
+
+    let accounts = example_native_token_transfers::accounts::CloseInboxItem {
+        inbox_item: accounts.inbox_item,
+        rent_recipient: accounts.rent_recipient,
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -0,0 +1,23 @@
+use anchor_lang::{prelude::Pubkey, InstructionData, ToAccountMetas};
+use solana_sdk::instruction::{AccountMeta, Instruction};
+
+use crate::sdk::accounts::NTT;
+
+/// `transceivers` are the transceiver programs whose registrations are looked up.
+pub fn get_enabled_transceivers(ntt: &NTT, transceivers: &[Pubkey]) -> Instruction {
+    let data = example_native_token_transfers::instruction::GetEnabledTransceivers {};
+
+    let mut accounts = example_native_token_transfers::accounts::GetEnabledTransceivers {
+        config: ntt.config(),
+    }
+    .to_account_metas(None);
+    accounts.extend(transceivers.iter().map(|transceiver| {
+        AccountMeta::new_readonly(ntt.registered_transceiver(transceiver), false)
+    }));
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts,
+        data: data.data(),
+    }
+}
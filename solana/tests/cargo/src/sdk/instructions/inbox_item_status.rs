@@ -0,0 +1,25 @@
+use anchor_lang::{prelude::Pubkey, InstructionData, ToAccountMetas};
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct GetInboxItemStatus {
+    pub inbox_item: Pubkey,
+    /// The chain the transfer was received from.
+    pub chain_id: u16,
+}
+
+pub fn get_inbox_item_status(ntt: &NTT, accounts: GetInboxItemStatus) -> Instruction {
+    let data = example_native_token_transfers::instruction::GetInboxItemStatus {};
+
+    let accounts = example_native_token_transfers::accounts::GetInboxItemStatus {
+        inbox_item: accounts.inbox_item,
+        inbox_rate_limit: ntt.inbox_rate_limit(accounts.chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
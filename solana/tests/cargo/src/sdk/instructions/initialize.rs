@@ -1,7 +1,11 @@
 use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
 use anchor_spl::{associated_token::AssociatedToken, token::Token};
-use example_native_token_transfers::instructions::InitializeArgs;
-use solana_sdk::instruction::Instruction;
+use example_native_token_transfers::{
+    instructions::{InitialPeer, InitializeArgs},
+    transceivers::accounts::peer::{TransceiverPeer, TransceiverPeerIndex},
+};
+use ntt_messages::mode::Mode;
+use solana_sdk::instruction::{AccountMeta, Instruction};
 use wormhole_solana_utils::cpi::bpf_loader_upgradeable::BpfLoaderUpgradeable;
 
 use crate::sdk::accounts::NTT;
@@ -25,24 +29,105 @@ pub fn initialize_with_token_program_id(
 ) -> Instruction {
     let data = example_native_token_transfers::instruction::Initialize { args };
 
-    let bpf_loader_upgradeable_program = BpfLoaderUpgradeable::id();
-    let accounts = example_native_token_transfers::accounts::Initialize {
+    // fetch account
+    Instruction {
+        program_id: ntt.program(),
+        accounts: initialize_accounts(ntt, accounts, token_program_id).to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn initialize_accounts(
+    ntt: &NTT,
+    accounts: Initialize,
+    token_program_id: &Pubkey,
+) -> example_native_token_transfers::accounts::Initialize {
+    example_native_token_transfers::accounts::Initialize {
         payer: accounts.payer,
         deployer: accounts.deployer,
         program_data: ntt.program_data(),
         config: ntt.config(),
         mint: accounts.mint,
         rate_limit: ntt.outbox_rate_limit(),
+        custody_ledger: ntt.custody_ledger(),
         token_authority: ntt.token_authority(),
         multisig_token_authority: accounts.multisig_token_authority,
         custody: ntt.custody_with_token_program_id(&accounts.mint, token_program_id),
         token_program: *token_program_id,
         associated_token_program: AssociatedToken::id(),
-        bpf_loader_upgradeable_program,
+        bpf_loader_upgradeable_program: BpfLoaderUpgradeable::id(),
         system_program: System::id(),
+    }
+}
+
+/// [`initialize`], and sets up `peers` in the same transaction. The
+/// transceiver peers are those of the integrated transceiver.
+pub fn initialize_with_peers(
+    ntt: &NTT,
+    accounts: Initialize,
+    args: InitializeArgs,
+    peers: Vec<InitialPeer>,
+) -> Instruction {
+    let mut account_metas = initialize_accounts(ntt, accounts, &Token::id()).to_account_metas(None);
+
+    let mut remaining_accounts = vec![];
+    for peer in &peers {
+        let chain = peer.chain_id.id;
+        remaining_accounts.push(ntt.peer(chain));
+        remaining_accounts.push(ntt.inbox_rate_limit(chain));
+        if peer.transceiver_address.is_some() {
+            let (transceiver_peer, _) = Pubkey::find_program_address(
+                &[TransceiverPeer::SEED_PREFIX, &chain.to_be_bytes()],
+                &ntt.program(),
+            );
+            remaining_accounts.push(transceiver_peer);
+        }
+    }
+    if !peers.is_empty() {
+        remaining_accounts.push(ntt.peer_index());
+    }
+    if peers.iter().any(|peer| peer.transceiver_address.is_some()) {
+        let (transceiver_peer_index, _) =
+            Pubkey::find_program_address(&[TransceiverPeerIndex::SEED_PREFIX], &ntt.program());
+        remaining_accounts.push(transceiver_peer_index);
+    }
+    account_metas.extend(
+        remaining_accounts
+            .into_iter()
+            .map(|account| AccountMeta::new(account, false)),
+    );
+
+    let data = example_native_token_transfers::instruction::InitializeWithPeers { args, peers };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: account_metas,
+        data: data.data(),
+    }
+}
+
+pub struct PreflightInitialize {
+    pub mint: Pubkey,
+    pub multisig_token_authority: Option<Pubkey>,
+}
+
+pub fn preflight_initialize(
+    ntt: &NTT,
+    accounts: PreflightInitialize,
+    mode: Mode,
+    token_program_id: &Pubkey,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::PreflightInitialize { mode };
+
+    let accounts = example_native_token_transfers::accounts::PreflightInitialize {
+        config: ntt.config(),
+        mint: accounts.mint,
+        token_authority: ntt.token_authority(),
+        custody: ntt.custody_with_token_program_id(&accounts.mint, token_program_id),
+        token_program: *token_program_id,
+        multisig_token_authority: accounts.multisig_token_authority,
     };
 
-    // fetch account
     Instruction {
         program_id: ntt.program(),
         accounts: accounts.to_account_metas(None),
@@ -0,0 +1,25 @@
+use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct MigratePeer {
+    pub payer: Pubkey,
+}
+
+pub fn migrate_peer(ntt: &NTT, accounts: MigratePeer, chain_id: u16) -> Instruction {
+    let data = example_native_token_transfers::instruction::MigratePeer { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::MigratePeer {
+        payer: accounts.payer,
+        peer: ntt.peer(chain_id),
+        peer_index: ntt.peer_index(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
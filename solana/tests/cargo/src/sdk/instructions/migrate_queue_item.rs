@@ -0,0 +1,46 @@
+use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct MigrateOutboxItem {
+    pub payer: Pubkey,
+    pub outbox_item: Pubkey,
+}
+
+pub fn migrate_outbox_item(ntt: &NTT, accounts: MigrateOutboxItem) -> Instruction {
+    let data = example_native_token_transfers::instruction::MigrateOutboxItem {};
+
+    let accounts = example_native_token_transfers::accounts::MigrateOutboxItem {
+        payer: accounts.payer,
+        outbox_item: accounts.outbox_item,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct MigrateInboxItem {
+    pub payer: Pubkey,
+    pub inbox_item: Pubkey,
+}
+
+pub fn migrate_inbox_item(ntt: &NTT, accounts: MigrateInboxItem) -> Instruction {
+    let data = example_native_token_transfers::instruction::MigrateInboxItem {};
+
+    let accounts = example_native_token_transfers::accounts::MigrateInboxItem {
+        payer: accounts.payer,
+        inbox_item: accounts.inbox_item,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
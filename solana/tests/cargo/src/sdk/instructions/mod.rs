@@ -1,6 +1,21 @@
 pub mod admin;
+pub mod cancel_outbound_transfer;
+pub mod close_expired_outbox_item;
+pub mod close_inbox_item;
+pub mod enabled_transceivers;
+pub mod inbox_item_status;
 pub mod initialize;
+pub mod migrate_peer;
+pub mod migrate_queue_item;
+pub mod peer_decimals;
+pub mod peer_stats;
 pub mod post_vaa;
+pub mod preview_transfer;
+pub mod rate_limit_params;
+pub mod reconcile_custody;
 pub mod redeem;
 pub mod release_inbound;
+pub mod repair_custody;
 pub mod transfer;
+pub mod transfer_status;
+pub mod would_queue;
@@ -0,0 +1,27 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::sdk::accounts::NTT;
+
+pub struct GetPeerDecimals {
+    pub mint: Pubkey,
+    pub chain_id: u16,
+}
+
+pub fn get_peer_decimals(ntt: &NTT, accounts: GetPeerDecimals) -> Instruction {
+    let data = example_native_token_transfers::instruction::GetPeerDecimals {
+        chain_id: accounts.chain_id,
+    };
+
+    let accounts = example_native_token_transfers::accounts::GetPeerDecimals {
+        config: ntt.config(),
+        mint: accounts.mint,
+        peer: ntt.peer(accounts.chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -0,0 +1,35 @@
+use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub fn init_peer_stats(ntt: &NTT, payer: Pubkey, chain_id: u16) -> Instruction {
+    let data = example_native_token_transfers::instruction::InitPeerStats { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::InitPeerStats {
+        payer,
+        peer: ntt.peer(chain_id),
+        peer_stats: ntt.peer_stats(chain_id),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn get_stats(ntt: &NTT, chain_id: u16) -> Instruction {
+    let data = example_native_token_transfers::instruction::GetStats { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::GetStats {
+        peer_stats: ntt.peer_stats(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -75,10 +75,7 @@ fn verify_signatures<A: AnchorSerialize + Clone>(
 
     let (header, body): (Header, Body<A>) = vaa.into();
 
-    let serialized_body: Body<Box<RawMessage>> = Body {
-        payload: Box::<RawMessage>::from(body.payload.try_to_vec().unwrap()),
-        ..body
-    };
+    let serialized_body = serialize_body(body);
 
     let digest = serialized_body.digest().unwrap().hash;
 
@@ -146,15 +143,22 @@ pub fn get_guardian_signature<A: AnchorSerialize + Clone>(vaa: Vaa<A>, index: u8
     )
     .unwrap();
 
+    sign_vaa(vaa, index, &priv_key)
+}
+
+/// Signs `vaa` as the guardian at `index` of the guardian set, whose secret key
+/// is `priv_key`.
+pub fn sign_vaa<A: AnchorSerialize + Clone>(
+    vaa: Vaa<A>,
+    index: u8,
+    priv_key: &libsecp256k1::SecretKey,
+) -> Signature {
     let (_, body): (Header, Body<A>) = vaa.into();
-    let serialized_body: Body<Box<RawMessage>> = Body {
-        payload: Box::<RawMessage>::from(body.payload.try_to_vec().unwrap()),
-        ..body
-    };
+    let serialized_body = serialize_body(body);
     let digest = serialized_body.digest().unwrap().secp256k_hash;
     let msg = Message::parse(&digest);
 
-    let (sig, recovery_id) = sign(&msg, &priv_key);
+    let (sig, recovery_id) = sign(&msg, priv_key);
 
     let mut signature = [0u8; 65];
     signature[..64].copy_from_slice(&sig.serialize());
@@ -169,6 +173,7 @@ cfg_if! {
             CloseSignatures, CloseSignaturesAccounts, PostSignatures, PostSignaturesAccounts,
             PostSignaturesData,
         };
+        use solana_program_test::BanksClientError;
         use crate::sdk::transceivers::accounts::NTTTransceiver;
 
         pub async fn post_signatures<A: AnchorSerialize + Clone>(
@@ -177,6 +182,55 @@ cfg_if! {
             guardian_signatures: &Keypair,
             vaa: &Vaa<A>,
         ) {
+            post_signatures_in_batches(
+                ntt_transceiver,
+                ctx,
+                guardian_signatures,
+                vaa,
+                vaa.signatures.len(),
+            )
+            .await
+            .unwrap();
+        }
+
+        /// Posts the signatures of `vaa` into `guardian_signatures`,
+        /// `batch_size` signatures per transaction, for when they don't all
+        /// fit in a single one (e.g. a quorum of a large guardian set). The
+        /// account is created by the first batch, with room for all of the
+        /// signatures, and the shim appends every later batch to it.
+        pub async fn post_signatures_in_batches<A: AnchorSerialize + Clone>(
+            ntt_transceiver: &NTTTransceiver,
+            ctx: &mut ProgramTestContext,
+            guardian_signatures: &Keypair,
+            vaa: &Vaa<A>,
+            batch_size: usize,
+        ) -> Result<(), BanksClientError> {
+            let total_signatures = u8::try_from(vaa.signatures.len()).unwrap();
+            for batch in vaa.signatures.chunks(batch_size) {
+                post_signatures_batch(
+                    ntt_transceiver,
+                    ctx,
+                    guardian_signatures,
+                    vaa.guardian_set_index,
+                    total_signatures,
+                    batch,
+                )
+                .await?;
+            }
+            Ok(())
+        }
+
+        /// Posts a single batch of signatures into `guardian_signatures`.
+        /// `total_signatures` only determines the size of the account when
+        /// this is the first batch.
+        pub async fn post_signatures_batch(
+            ntt_transceiver: &NTTTransceiver,
+            ctx: &mut ProgramTestContext,
+            guardian_signatures: &Keypair,
+            guardian_set_index: u32,
+            total_signatures: u8,
+            signatures: &[Signature],
+        ) -> Result<(), BanksClientError> {
             PostSignatures {
                 program_id: &ntt_transceiver.verify_vaa_shim_shim(),
                 accounts: PostSignaturesAccounts {
@@ -184,9 +238,9 @@ cfg_if! {
                     guardian_signatures: &guardian_signatures.pubkey(),
                 },
                 data: PostSignaturesData::new(
-                    vaa.guardian_set_index,
-                    u8::try_from(vaa.signatures.len()).unwrap(),
-                    &vaa.signatures
+                    guardian_set_index,
+                    total_signatures,
+                    &signatures
                         .iter()
                         .map(|sig| {
                             let mut buf = [0u8; 66];
@@ -200,7 +254,6 @@ cfg_if! {
             .instruction()
             .submit_with_signers(&[guardian_signatures], ctx)
             .await
-            .unwrap();
         }
 
         pub async fn close_signatures(
@@ -222,3 +275,20 @@ cfg_if! {
         }
     }
 }
+
+/// `body` with its payload serialized, so that it can be hashed.
+///
+/// NOTE: the fields are copied one by one rather than with a struct update,
+/// as the payload type changes, which would need the nightly-only
+/// `type_changing_struct_update` feature.
+fn serialize_body<A: AnchorSerialize>(body: Body<A>) -> Body<Box<RawMessage>> {
+    Body {
+        timestamp: body.timestamp,
+        nonce: body.nonce,
+        emitter_chain: body.emitter_chain,
+        emitter_address: body.emitter_address,
+        sequence: body.sequence,
+        consistency_level: body.consistency_level,
+        payload: Box::<RawMessage>::from(body.payload.try_to_vec().unwrap()),
+    }
+}
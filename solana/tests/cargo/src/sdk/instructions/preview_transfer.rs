@@ -0,0 +1,33 @@
+use anchor_lang::{prelude::Pubkey, InstructionData, ToAccountMetas};
+use example_native_token_transfers::instructions::TransferArgs;
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct PreviewTransfer {
+    pub mint: Pubkey,
+    pub from: Pubkey,
+    pub from_authority: Pubkey,
+}
+
+pub fn preview_transfer(ntt: &NTT, accounts: PreviewTransfer, args: TransferArgs) -> Instruction {
+    let chain_id = args.recipient_chain.id;
+    let data = example_native_token_transfers::instruction::PreviewTransfer { args };
+
+    let accounts = example_native_token_transfers::accounts::PreviewTransfer {
+        config: ntt.config(),
+        mint: accounts.mint,
+        from: accounts.from,
+        peer: ntt.peer(chain_id),
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+        chain_outbox_rate_limit: ntt.chain_outbox_rate_limit(chain_id),
+        sender_rate_limit: ntt.sender_rate_limit(&accounts.from_authority),
+        wormhole_bridge: ntt.wormhole().bridge(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub fn get_rate_limit_params(ntt: &NTT, chain_id: u16) -> Instruction {
+    let data = example_native_token_transfers::instruction::GetRateLimitParams { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::GetRateLimitParams {
+        inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn get_outbound_capacity(ntt: &NTT) -> Instruction {
+    let data = example_native_token_transfers::instruction::GetOutboundCapacity {};
+
+    let accounts = example_native_token_transfers::accounts::GetOutboundCapacity {
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn get_inbound_capacity(ntt: &NTT, chain_id: u16) -> Instruction {
+    let data = example_native_token_transfers::instruction::GetInboundCapacity { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::GetInboundCapacity {
+        inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -0,0 +1,24 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::sdk::accounts::NTT;
+
+pub struct ReconcileCustody {
+    pub mint: Pubkey,
+}
+
+pub fn reconcile_custody(ntt: &NTT, accounts: ReconcileCustody) -> Instruction {
+    let data = example_native_token_transfers::instruction::ReconcileCustody {};
+
+    let accounts = example_native_token_transfers::accounts::ReconcileCustody {
+        config: ntt.config(),
+        custody: ntt.custody(&accounts.mint),
+        custody_ledger: ntt.custody_ledger(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
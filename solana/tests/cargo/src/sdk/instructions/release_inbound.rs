@@ -1,6 +1,8 @@
 use anchor_lang::{prelude::*, InstructionData};
 use anchor_spl::token::Token;
-use example_native_token_transfers::{accounts::NotPausedConfig, instructions::ReleaseInboundArgs};
+use example_native_token_transfers::{
+    accounts::NotPausedInboundConfig, instructions::ReleaseInboundArgs,
+};
 use solana_sdk::instruction::Instruction;
 
 use crate::sdk::accounts::NTT;
@@ -8,29 +10,89 @@ use crate::sdk::accounts::NTT;
 pub struct ReleaseInbound {
     pub payer: Pubkey,
     pub inbox_item: Pubkey,
+    /// The chain the transfer came from, i.e. the peer whose in-flight
+    /// inbound transfers are decremented. 0 for inbox items that predate
+    /// [`example_native_token_transfers::queue::inbox::InboxItem::from_chain`],
+    /// which are released without a peer.
+    pub from_chain: u16,
     pub mint: Pubkey,
     pub recipient: Pubkey,
 }
 
+pub fn release_inbound_mint(
+    ntt: &NTT,
+    accounts: ReleaseInbound,
+    args: ReleaseInboundArgs,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::ReleaseInboundMint { args };
+    let accounts = example_native_token_transfers::accounts::ReleaseInboundMint {
+        common: common(ntt, accounts, &Token::id(), None),
+        multisig_token_authority: None,
+    };
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
 pub fn release_inbound_unlock(
     ntt: &NTT,
     accounts: ReleaseInbound,
     args: ReleaseInboundArgs,
+) -> Instruction {
+    release_inbound_unlock_with_token_program_id(ntt, accounts, args, &Token::id())
+}
+
+/// The peer's fallback recipient, see [`release_inbound_unlock_with_fallback`].
+pub struct FallbackRecipient {
+    pub fallback_recipient: Pubkey,
+}
+
+pub fn release_inbound_unlock_with_token_program_id(
+    ntt: &NTT,
+    accounts: ReleaseInbound,
+    args: ReleaseInboundArgs,
+    token_program_id: &Pubkey,
+) -> Instruction {
+    release_inbound_unlock_with_fallback(ntt, accounts, args, token_program_id, None)
+}
+
+pub fn release_inbound_unlock_with_fallback(
+    ntt: &NTT,
+    accounts: ReleaseInbound,
+    args: ReleaseInboundArgs,
+    token_program_id: &Pubkey,
+    fallback: Option<FallbackRecipient>,
 ) -> Instruction {
+    let data = example_native_token_transfers::instruction::ReleaseInboundUnlock { args };
+    let accounts = example_native_token_transfers::accounts::ReleaseInboundUnlock {
+        common: common(ntt, accounts, token_program_id, fallback),
+        custody_ledger: Some(ntt.custody_ledger()),
+    };
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// [`release_inbound_unlock`], recording the delivery latency in the
+/// [`example_native_token_transfers::peer_stats::PeerStats`] of the chain the
+/// transfer came from.
+pub fn release_inbound_unlock_with_peer_stats(
+    ntt: &NTT,
+    accounts: ReleaseInbound,
+    args: ReleaseInboundArgs,
+) -> Instruction {
+    let chain_id = accounts.from_chain;
     let data = example_native_token_transfers::instruction::ReleaseInboundUnlock { args };
     let accounts = example_native_token_transfers::accounts::ReleaseInboundUnlock {
         common: example_native_token_transfers::accounts::ReleaseInbound {
-            payer: accounts.payer,
-            config: NotPausedConfig {
-                config: ntt.config(),
-            },
-            inbox_item: accounts.inbox_item,
-            recipient: accounts.recipient,
-            token_authority: ntt.token_authority(),
-            mint: accounts.mint,
-            token_program: Token::id(),
-            custody: ntt.custody(&accounts.mint),
+            peer_stats: Some(ntt.peer_stats(chain_id)),
+            ..common(ntt, accounts, &Token::id(), None)
         },
+        custody_ledger: Some(ntt.custody_ledger()),
     };
     Instruction {
         program_id: ntt.program(),
@@ -38,3 +100,26 @@ pub fn release_inbound_unlock(
         data: data.data(),
     }
 }
+
+fn common(
+    ntt: &NTT,
+    accounts: ReleaseInbound,
+    token_program_id: &Pubkey,
+    fallback: Option<FallbackRecipient>,
+) -> example_native_token_transfers::accounts::ReleaseInbound {
+    example_native_token_transfers::accounts::ReleaseInbound {
+        payer: accounts.payer,
+        config: NotPausedInboundConfig {
+            config: ntt.config(),
+        },
+        inbox_item: accounts.inbox_item,
+        recipient: accounts.recipient,
+        token_authority: ntt.token_authority(),
+        mint: accounts.mint,
+        token_program: *token_program_id,
+        custody: ntt.custody_with_token_program_id(&accounts.mint, token_program_id),
+        peer: (accounts.from_chain != 0).then(|| ntt.peer(accounts.from_chain)),
+        fallback_recipient: fallback.map(|fallback| fallback.fallback_recipient),
+        peer_stats: None,
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct RepairCustody {
+    pub payer: Pubkey,
+    pub mint: Pubkey,
+}
+
+pub fn repair_custody(ntt: &NTT, accounts: RepairCustody) -> Instruction {
+    repair_custody_with_token_program_id(ntt, accounts, &Token::id())
+}
+
+pub fn repair_custody_with_token_program_id(
+    ntt: &NTT,
+    accounts: RepairCustody,
+    token_program_id: &Pubkey,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::RepairCustody {};
+
+    let accounts = example_native_token_transfers::accounts::RepairCustody {
+        payer: accounts.payer,
+        config: ntt.config(),
+        mint: accounts.mint,
+        token_authority: ntt.token_authority(),
+        custody: ntt.custody_with_token_program_id(&accounts.mint, token_program_id),
+        token_program: *token_program_id,
+        associated_token_program: AssociatedToken::id(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -1,8 +1,17 @@
+use std::future::Future;
+
 use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
 use anchor_spl::{token::Token, token_2022::spl_token_2022};
-use example_native_token_transfers::{accounts::NotPausedConfig, instructions::TransferArgs};
+use example_native_token_transfers::{
+    accounts::NotPausedOutboundConfig,
+    instructions::{TransferArgs, TransferOptions},
+};
 use ntt_messages::mode::Mode;
 use solana_sdk::instruction::Instruction;
+use spl_token_2022::extension::{transfer_hook, StateWithExtensions};
+use spl_transfer_hook_interface::offchain::{
+    add_extra_account_metas_for_execute, AccountDataResult, AccountFetchError,
+};
 
 use crate::sdk::accounts::NTT;
 
@@ -14,6 +23,10 @@ pub struct Transfer {
     pub from_authority: Pubkey,
     pub peer: Pubkey,
     pub outbox_item: Pubkey,
+    pub dust_account: Option<Pubkey>,
+    /// Whether to pass the sender's rate limit, which is required once
+    /// senders are limited (see `Config::limits_senders`).
+    pub sender_limited: bool,
 }
 
 pub fn transfer(ntt: &NTT, accounts: Transfer, args: TransferArgs, mode: Mode) -> Instruction {
@@ -33,6 +46,78 @@ pub fn transfer_with_token_program_id(
     }
 }
 
+/// [`transfer`] through the `_with_options` instructions.
+pub fn transfer_with_options(
+    ntt: &NTT,
+    accounts: Transfer,
+    args: TransferArgs,
+    options: TransferOptions,
+    mode: Mode,
+) -> Instruction {
+    let chain_id = args.recipient_chain.id;
+    let session_authority =
+        ntt.session_authority_with_options(&accounts.from_authority, &args, &options);
+    let data = match mode {
+        Mode::Burning => {
+            example_native_token_transfers::instruction::TransferBurnWithOptions { args, options }
+                .data()
+        }
+        Mode::Locking => {
+            example_native_token_transfers::instruction::TransferLockWithOptions { args, options }
+                .data()
+        }
+    };
+
+    transfer_with_data(
+        ntt,
+        &accounts,
+        chain_id,
+        session_authority,
+        data,
+        mode,
+        &Token::id(),
+    )
+}
+
+/// [`transfer`] through the `_with_expiry` instructions.
+pub fn transfer_with_expiry(
+    ntt: &NTT,
+    accounts: Transfer,
+    args: TransferArgs,
+    expiry_timestamp: i64,
+    mode: Mode,
+) -> Instruction {
+    let chain_id = args.recipient_chain.id;
+    let options = TransferOptions {
+        expiry_timestamp: Some(expiry_timestamp),
+        ..TransferOptions::default()
+    };
+    let session_authority =
+        ntt.session_authority_with_options(&accounts.from_authority, &args, &options);
+    let data = match mode {
+        Mode::Burning => example_native_token_transfers::instruction::TransferBurnWithExpiry {
+            args,
+            expiry_timestamp,
+        }
+        .data(),
+        Mode::Locking => example_native_token_transfers::instruction::TransferLockWithExpiry {
+            args,
+            expiry_timestamp,
+        }
+        .data(),
+    };
+
+    transfer_with_data(
+        ntt,
+        &accounts,
+        chain_id,
+        session_authority,
+        data,
+        mode,
+        &Token::id(),
+    )
+}
+
 pub fn transfer_burn(ntt: &NTT, accounts: Transfer, args: TransferArgs) -> Instruction {
     transfer_burn_with_token_program_id(ntt, accounts, args, &Token::id())
 }
@@ -47,19 +132,15 @@ pub fn transfer_burn_with_token_program_id(
     let session_authority = ntt.session_authority(&accounts.from_authority, &args);
     let data = example_native_token_transfers::instruction::TransferBurn { args };
 
-    let accounts = example_native_token_transfers::accounts::TransferBurn {
-        common: common_with_token_program_id(ntt, &accounts, token_program_id),
-        inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
-        peer: accounts.peer,
+    transfer_with_data(
+        ntt,
+        &accounts,
+        chain_id,
         session_authority,
-        token_authority: ntt.token_authority(),
-    };
-
-    Instruction {
-        program_id: ntt.program(),
-        accounts: accounts.to_account_metas(None),
-        data: data.data(),
-    }
+        data.data(),
+        Mode::Burning,
+        token_program_id,
+    )
 }
 
 pub fn transfer_lock(ntt: &NTT, accounts: Transfer, args: TransferArgs) -> Instruction {
@@ -76,16 +157,56 @@ pub fn transfer_lock_with_token_program_id(
     let session_authority = ntt.session_authority(&accounts.from_authority, &args);
     let data = example_native_token_transfers::instruction::TransferLock { args };
 
-    let accounts = example_native_token_transfers::accounts::TransferLock {
-        common: common_with_token_program_id(ntt, &accounts, token_program_id),
-        inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
-        peer: accounts.peer,
+    transfer_with_data(
+        ntt,
+        &accounts,
+        chain_id,
         session_authority,
+        data.data(),
+        Mode::Locking,
+        token_program_id,
+    )
+}
+
+fn transfer_with_data(
+    ntt: &NTT,
+    accounts: &Transfer,
+    chain_id: u16,
+    session_authority: Pubkey,
+    data: Vec<u8>,
+    mode: Mode,
+    token_program_id: &Pubkey,
+) -> Instruction {
+    let accounts = match mode {
+        Mode::Burning => example_native_token_transfers::accounts::TransferBurn {
+            common: common_with_token_program_id(ntt, accounts, token_program_id),
+            inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+            peer: accounts.peer,
+            session_authority,
+            token_authority: ntt.token_authority(),
+            multisig_token_authority: None,
+            dust_account: accounts.dust_account,
+            sender_rate_limit: sender_rate_limit(ntt, accounts),
+            chain_outbox_rate_limit: Some(ntt.chain_outbox_rate_limit(chain_id)),
+        }
+        .to_account_metas(None),
+        Mode::Locking => example_native_token_transfers::accounts::TransferLock {
+            common: common_with_token_program_id(ntt, accounts, token_program_id),
+            inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+            peer: accounts.peer,
+            session_authority,
+            custody_ledger: Some(ntt.custody_ledger()),
+            dust_account: accounts.dust_account,
+            sender_rate_limit: sender_rate_limit(ntt, accounts),
+            chain_outbox_rate_limit: Some(ntt.chain_outbox_rate_limit(chain_id)),
+        }
+        .to_account_metas(None),
     };
+
     Instruction {
         program_id: ntt.program(),
-        accounts: accounts.to_account_metas(None),
-        data: data.data(),
+        accounts,
+        data,
     }
 }
 
@@ -98,6 +219,26 @@ pub fn approve_token_authority(
     approve_token_authority_with_token_program_id(ntt, user_token_account, user, args, &Token::id())
 }
 
+/// [`approve_token_authority`] for a transfer with `options`, see
+/// [`transfer_with_options`].
+pub fn approve_token_authority_with_options(
+    ntt: &NTT,
+    user_token_account: &Pubkey,
+    user: &Pubkey,
+    args: &TransferArgs,
+    options: &TransferOptions,
+) -> Instruction {
+    spl_token_2022::instruction::approve(
+        &Token::id(),
+        user_token_account,
+        &ntt.session_authority_with_options(user, args, options),
+        user,
+        &[user],
+        args.amount,
+    )
+    .unwrap()
+}
+
 pub fn approve_token_authority_with_token_program_id(
     ntt: &NTT,
     user_token_account: &Pubkey,
@@ -116,6 +257,49 @@ pub fn approve_token_authority_with_token_program_id(
     .unwrap()
 }
 
+/// Appends the extra accounts required by the transfer hook of `mint` (if it
+/// has one) to `instruction`, for a token transfer of `amount` from `source`
+/// to `destination` by `authority`.
+///
+/// The manager passes its remaining accounts on to the token program whenever
+/// it moves tokens, so this works for [`transfer`] (from the sender's account
+/// into custody, by the session authority) as well as for
+/// [`super::release_inbound::release_inbound_unlock`] (from custody to the
+/// recipient, by the token authority).
+pub async fn add_extra_account_metas_for_transfer<F, Fut>(
+    instruction: &mut Instruction,
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+    fetch_account_data_fn: F,
+) -> Result<(), AccountFetchError>
+where
+    F: Fn(Pubkey) -> Fut,
+    Fut: Future<Output = AccountDataResult>,
+{
+    let mint_data = fetch_account_data_fn(*mint)
+        .await?
+        .ok_or("mint not found")?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    let Some(hook_program_id) = transfer_hook::get_program_id(&mint_state) else {
+        return Ok(());
+    };
+
+    add_extra_account_metas_for_execute(
+        instruction,
+        &hook_program_id,
+        source,
+        mint,
+        destination,
+        authority,
+        amount,
+        fetch_account_data_fn,
+    )
+    .await
+}
+
 fn common_with_token_program_id(
     ntt: &NTT,
     accounts: &Transfer,
@@ -123,7 +307,7 @@ fn common_with_token_program_id(
 ) -> example_native_token_transfers::accounts::Transfer {
     example_native_token_transfers::accounts::Transfer {
         payer: accounts.payer,
-        config: NotPausedConfig {
+        config: NotPausedOutboundConfig {
             config: ntt.config(),
         },
         mint: accounts.mint,
@@ -135,3 +319,9 @@ fn common_with_token_program_id(
         custody: ntt.custody_with_token_program_id(&accounts.mint, token_program_id),
     }
 }
+
+fn sender_rate_limit(ntt: &NTT, accounts: &Transfer) -> Option<Pubkey> {
+    accounts
+        .sender_limited
+        .then(|| ntt.sender_rate_limit(&accounts.from_authority))
+}
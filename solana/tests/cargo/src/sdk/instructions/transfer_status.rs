@@ -0,0 +1,30 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::sdk::accounts::NTT;
+
+pub struct GetTransferStatus {
+    pub outbox_item: Pubkey,
+}
+
+pub fn get_transfer_status(ntt: &NTT, accounts: GetTransferStatus) -> Instruction {
+    let data = example_native_token_transfers::instruction::GetTransferStatus {};
+
+    // the message of the integrated transceiver, see
+    // [`crate::sdk::transceivers::legacy::accounts::NTTTransceiverAccounts::wormhole_message`]
+    let (wormhole_message, _) = Pubkey::find_program_address(
+        &[b"message".as_ref(), accounts.outbox_item.as_ref()],
+        &ntt.program(),
+    );
+
+    let accounts = example_native_token_transfers::accounts::GetTransferStatus {
+        outbox_item: accounts.outbox_item,
+        wormhole_message,
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
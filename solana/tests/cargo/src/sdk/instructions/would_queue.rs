@@ -0,0 +1,28 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct WouldQueue {
+    pub chain_id: u16,
+    pub amount: u64,
+}
+
+pub fn would_queue(ntt: &NTT, args: WouldQueue) -> Instruction {
+    let data = example_native_token_transfers::instruction::WouldQueue {
+        chain_id: args.chain_id,
+        amount: args.amount,
+    };
+
+    let accounts = example_native_token_transfers::accounts::WouldQueue {
+        config: ntt.config(),
+        peer: ntt.peer(args.chain_id),
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -28,6 +28,12 @@ pub trait NTTTransceiverAccounts {
         peer
     }
 
+    fn transceiver_peer_index(&self) -> Pubkey {
+        let (peer_index, _) =
+            Pubkey::find_program_address(&[b"transceiver_peer_index".as_ref()], &self.program());
+        peer_index
+    }
+
     fn transceiver_message(&self, chain: u16, id: [u8; 32]) -> Pubkey {
         let (transceiver_message, _) = Pubkey::find_program_address(
             &[b"transceiver_message".as_ref(), &chain.to_be_bytes(), &id],
@@ -7,13 +7,26 @@ use crate::sdk::accounts::ntt::NTT;
 use super::ntt_transceiver::NTTTransceiver;
 
 pub fn wormhole_accounts(ntt: &NTT, ntt_transceiver: &NTTTransceiver) -> WormholeAccounts {
+    wormhole_accounts_with_fee_payer(ntt, ntt_transceiver, None)
+}
+
+/// [`wormhole_accounts`], with the core bridge fee paid by `fee_payer` (if
+/// given) rather than the payer of the instruction.
+pub fn wormhole_accounts_with_fee_payer(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    fee_payer: Option<Pubkey>,
+) -> WormholeAccounts {
     WormholeAccounts {
         bridge: ntt.wormhole().bridge(),
         fee_collector: ntt.wormhole().fee_collector(),
-        sequence: ntt.wormhole_sequence(ntt_transceiver),
+        // NOTE: not [`crate::sdk::accounts::NTTAccounts::wormhole_sequence`], which
+        // takes the transceiver selected by the `shim` feature
+        sequence: ntt.wormhole().sequence(&ntt_transceiver.emitter()),
         program: ntt.wormhole().program,
         system_program: System::id(),
         clock: Clock::id(),
         rent: Rent::id(),
+        fee_payer,
     }
 }
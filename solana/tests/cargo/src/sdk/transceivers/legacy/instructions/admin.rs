@@ -1,8 +1,11 @@
 use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
-pub use example_native_token_transfers::transceivers::wormhole::SetTransceiverPeerArgs;
+pub use example_native_token_transfers::transceivers::{
+    accounts::peer::PeerAddressMatch,
+    wormhole::{SetTransceiverPeerArgs, SetTransceiverPeerOptions},
+};
 use solana_sdk::instruction::Instruction;
 
-use crate::sdk::{accounts::NTT, transceivers::accounts::NTTTransceiver};
+use crate::sdk::{accounts::NTT, transceivers::legacy::accounts::NTTTransceiver};
 
 pub struct SetTransceiverPeer {
     pub payer: Pubkey,
@@ -18,11 +21,101 @@ pub fn set_transceiver_peer(
     let chain_id = args.chain_id.id;
     let data = example_native_token_transfers::instruction::SetWormholePeer { args };
 
+    set_transceiver_peer_with_data(ntt, ntt_transceiver, accounts, chain_id, data.data())
+}
+
+pub fn set_transceiver_peer_with_options(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    accounts: SetTransceiverPeer,
+    args: SetTransceiverPeerArgs,
+    options: SetTransceiverPeerOptions,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data =
+        example_native_token_transfers::instruction::SetWormholePeerWithOptions { args, options };
+
+    set_transceiver_peer_with_data(ntt, ntt_transceiver, accounts, chain_id, data.data())
+}
+
+fn set_transceiver_peer_with_data(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    accounts: SetTransceiverPeer,
+    chain_id: u16,
+    data: Vec<u8>,
+) -> Instruction {
     let accounts = example_native_token_transfers::accounts::SetTransceiverPeer {
         config: ntt.config(),
         owner: accounts.owner,
         payer: accounts.payer,
         peer: ntt_transceiver.transceiver_peer(chain_id),
+        peer_index: ntt_transceiver.transceiver_peer_index(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt_transceiver.program(),
+        accounts: accounts.to_account_metas(None),
+        data,
+    }
+}
+
+pub struct DeleteTransceiverPeer {
+    pub owner: Pubkey,
+}
+
+pub fn delete_transceiver_peer(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    accounts: DeleteTransceiverPeer,
+    chain_id: u16,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::DeleteWormholePeer { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::DeleteTransceiverPeer {
+        config: ntt.config(),
+        owner: accounts.owner,
+        peer: ntt_transceiver.transceiver_peer(chain_id),
+        peer_index: ntt_transceiver.transceiver_peer_index(),
+    };
+
+    Instruction {
+        program_id: ntt_transceiver.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn get_transceiver_peer_index(ntt_transceiver: &NTTTransceiver) -> Instruction {
+    let data = example_native_token_transfers::instruction::GetWormholePeerIndex {};
+
+    let accounts = example_native_token_transfers::accounts::GetTransceiverPeerIndex {
+        peer_index: ntt_transceiver.transceiver_peer_index(),
+    };
+
+    Instruction {
+        program_id: ntt_transceiver.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct MigrateTransceiverPeer {
+    pub payer: Pubkey,
+}
+
+pub fn migrate_transceiver_peer(
+    ntt_transceiver: &NTTTransceiver,
+    accounts: MigrateTransceiverPeer,
+    chain_id: u16,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::MigrateWormholePeer { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::MigrateTransceiverPeer {
+        payer: accounts.payer,
+        peer: ntt_transceiver.transceiver_peer(chain_id),
+        peer_index: ntt_transceiver.transceiver_peer_index(),
         system_program: System::id(),
     };
 
@@ -1,9 +1,10 @@
 use anchor_lang::{prelude::*, InstructionData};
+use example_native_token_transfers::token_metadata::metadata_address;
 use solana_program::instruction::Instruction;
 
 use crate::sdk::{
     accounts::NTT,
-    transceivers::accounts::{wormhole_accounts, NTTTransceiver},
+    transceivers::legacy::accounts::{wormhole_accounts, NTTTransceiver},
 };
 
 pub struct BroadcastId {
@@ -22,6 +23,7 @@ pub fn broadcast_id(ntt: &NTT, ntt_transceiver: &NTTTransceiver, accs: Broadcast
         emitter: ntt_transceiver.emitter(),
         wormhole: wormhole_accounts(ntt, ntt_transceiver),
         mint: accs.mint,
+        token_metadata: Some(metadata_address(&accs.mint)),
     };
 
     Instruction {
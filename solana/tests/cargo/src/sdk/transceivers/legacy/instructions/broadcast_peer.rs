@@ -4,7 +4,7 @@ use solana_program::instruction::Instruction;
 
 use crate::sdk::{
     accounts::NTT,
-    transceivers::accounts::{wormhole_accounts, NTTTransceiver},
+    transceivers::legacy::accounts::{wormhole_accounts, NTTTransceiver},
 };
 
 pub struct BroadcastPeer {
@@ -1,7 +1,7 @@
 use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
 use solana_sdk::instruction::Instruction;
 
-use crate::sdk::{accounts::NTT, transceivers::accounts::NTTTransceiver};
+use crate::sdk::{accounts::NTT, transceivers::legacy::accounts::NTTTransceiver};
 
 #[derive(Debug, Clone)]
 pub struct ReceiveMessage {
@@ -21,10 +21,11 @@ pub fn receive_message(
 
     let accounts = example_native_token_transfers::accounts::ReceiveMessage {
         payer: accs.payer,
-        config: example_native_token_transfers::accounts::NotPausedConfig {
+        config: example_native_token_transfers::accounts::NotPausedInboundConfig {
             config: ntt.config(),
         },
         peer: accs.peer,
+        manager_peer: ntt.peer(accs.chain_id),
         vaa: accs.vaa,
         transceiver_message: ntt_transceiver.transceiver_message(accs.chain_id, accs.id),
         system_program: System::id(),
@@ -1,17 +1,20 @@
 use anchor_lang::{prelude::*, InstructionData};
 use example_native_token_transfers::{
-    accounts::NotPausedConfig, transceivers::wormhole::ReleaseOutboundArgs,
+    accounts::NotPausedOutboundConfig,
+    transceivers::wormhole::{ReleaseOutboundArgs, ReleaseOutboundOptions},
 };
 use solana_sdk::instruction::Instruction;
 
 use crate::sdk::{
     accounts::NTT,
-    transceivers::accounts::{wormhole_accounts, NTTTransceiver},
+    transceivers::legacy::accounts::{wormhole_accounts_with_fee_payer, NTTTransceiver},
 };
 
 pub struct ReleaseOutbound {
     pub payer: Pubkey,
     pub outbox_item: Pubkey,
+    /// The recipient chain of the outbox item, used to derive its peer.
+    pub recipient_chain: u16,
 }
 
 pub fn release_outbound(
@@ -19,22 +22,65 @@ pub fn release_outbound(
     ntt_transceiver: &NTTTransceiver,
     release_outbound: ReleaseOutbound,
     args: ReleaseOutboundArgs,
+) -> Instruction {
+    release_outbound_with_fee_payer(ntt, ntt_transceiver, release_outbound, args, None)
+}
+
+pub fn release_outbound_with_options(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    release_outbound: ReleaseOutbound,
+    args: ReleaseOutboundArgs,
+    options: ReleaseOutboundOptions,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::ReleaseWormholeOutboundWithOptions {
+        args,
+        options,
+    };
+    release_outbound_with_data(ntt, ntt_transceiver, release_outbound, data.data(), None)
+}
+
+/// [`release_outbound`], with the core bridge fee paid by `fee_payer` (if
+/// given) rather than the payer.
+pub fn release_outbound_with_fee_payer(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    release_outbound: ReleaseOutbound,
+    args: ReleaseOutboundArgs,
+    fee_payer: Option<Pubkey>,
 ) -> Instruction {
     let data = example_native_token_transfers::instruction::ReleaseWormholeOutbound { args };
+    release_outbound_with_data(
+        ntt,
+        ntt_transceiver,
+        release_outbound,
+        data.data(),
+        fee_payer,
+    )
+}
+
+fn release_outbound_with_data(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    release_outbound: ReleaseOutbound,
+    data: Vec<u8>,
+    fee_payer: Option<Pubkey>,
+) -> Instruction {
     let accounts = example_native_token_transfers::accounts::ReleaseOutbound {
         payer: release_outbound.payer,
-        config: NotPausedConfig {
+        config: NotPausedOutboundConfig {
             config: ntt.config(),
         },
         outbox_item: release_outbound.outbox_item,
+        peer: ntt.peer(release_outbound.recipient_chain),
         wormhole_message: ntt_transceiver.wormhole_message(&release_outbound.outbox_item),
         emitter: ntt_transceiver.emitter(),
         transceiver: ntt.registered_transceiver(&ntt.program()),
-        wormhole: wormhole_accounts(ntt, ntt_transceiver),
+        wormhole: wormhole_accounts_with_fee_payer(ntt, ntt_transceiver, fee_payer),
     };
     Instruction {
         program_id: ntt_transceiver.program(),
         accounts: accounts.to_account_metas(None),
-        data: data.data(),
+        data,
     }
 }
@@ -1,9 +1,12 @@
+// NOTE: the legacy transceiver is built into the manager, so it's available
+// alongside the shim transceiver, for deployments that run both.
+pub mod legacy;
+
 cfg_if! {
     if #[cfg(feature = "shim")] {
         pub mod shim;
         pub use shim::*;
     } else {
-        pub mod legacy;
         pub use legacy::*;
     }
 }
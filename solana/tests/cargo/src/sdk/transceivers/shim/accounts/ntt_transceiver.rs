@@ -31,11 +31,31 @@ pub trait NTTTransceiverAccounts {
         outbox_item_signer
     }
 
-    fn wormhole_message(&self) -> Pubkey {
-        let (wormhole_message, _) = Pubkey::find_program_address(
-            &[self.emitter().as_ref()],
-            &self.post_message_shim().program,
+    /// The emitter registered for the peer on `chain`, see
+    /// [`ntt_transceiver::peer_emitter::WormholePeerEmitter`].
+    fn emitter_for_peer(&self, chain: u16) -> Pubkey {
+        let (emitter, _) = Pubkey::find_program_address(
+            &[b"emitter".as_ref(), &chain.to_be_bytes()],
+            &self.program(),
+        );
+        emitter
+    }
+
+    fn peer_emitter(&self, chain: u16) -> Pubkey {
+        let (peer_emitter, _) = Pubkey::find_program_address(
+            &[b"wormhole_peer_emitter".as_ref(), &chain.to_be_bytes()],
+            &self.program(),
         );
+        peer_emitter
+    }
+
+    fn wormhole_message(&self) -> Pubkey {
+        self.wormhole_message_for_emitter(&self.emitter())
+    }
+
+    fn wormhole_message_for_emitter(&self, emitter: &Pubkey) -> Pubkey {
+        let (wormhole_message, _) =
+            Pubkey::find_program_address(&[emitter.as_ref()], &self.post_message_shim().program);
         wormhole_message
     }
 
@@ -47,6 +67,40 @@ pub trait NTTTransceiverAccounts {
         peer
     }
 
+    fn fee_stats(&self) -> Pubkey {
+        let (fee_stats, _) =
+            Pubkey::find_program_address(&[b"wormhole_fee_stats".as_ref()], &self.program());
+        fee_stats
+    }
+
+    fn heartbeat(&self) -> Pubkey {
+        let (heartbeat, _) =
+            Pubkey::find_program_address(&[b"wormhole_heartbeat".as_ref()], &self.program());
+        heartbeat
+    }
+
+    fn broadcast_suppression(&self) -> Pubkey {
+        let (broadcast_suppression, _) = Pubkey::find_program_address(
+            &[b"wormhole_broadcast_suppression".as_ref()],
+            &self.program(),
+        );
+        broadcast_suppression
+    }
+
+    fn peer_broadcast(&self, chain: u16) -> Pubkey {
+        let (peer_broadcast, _) = Pubkey::find_program_address(
+            &[b"wormhole_peer_broadcast".as_ref(), &chain.to_be_bytes()],
+            &self.program(),
+        );
+        peer_broadcast
+    }
+
+    fn transceiver_peer_index(&self) -> Pubkey {
+        let (peer_index, _) =
+            Pubkey::find_program_address(&[b"transceiver_peer_index".as_ref()], &self.program());
+        peer_index
+    }
+
     fn transceiver_message(&self, chain: u16, id: [u8; 32]) -> Pubkey {
         let (transceiver_message, _) = Pubkey::find_program_address(
             &[b"transceiver_message".as_ref(), &chain.to_be_bytes(), &id],
@@ -55,6 +109,18 @@ pub trait NTTTransceiverAccounts {
         transceiver_message
     }
 
+    fn pending_message(&self, chain: u16, id: [u8; 32]) -> Pubkey {
+        let (pending_message, _) = Pubkey::find_program_address(
+            &[
+                b"verified_pending_message".as_ref(),
+                &chain.to_be_bytes(),
+                &id,
+            ],
+            &self.program(),
+        );
+        pending_message
+    }
+
     fn unverified_message_account(&self, payer: &Pubkey, seed: u64) -> Pubkey {
         let (unverified_message_account, _) = Pubkey::find_program_address(
             &[b"vaa_body".as_ref(), payer.as_ref(), &seed.to_be_bytes()],
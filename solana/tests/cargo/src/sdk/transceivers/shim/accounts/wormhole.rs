@@ -7,6 +7,16 @@ use crate::sdk::accounts::NTT;
 use super::ntt_transceiver::NTTTransceiver;
 
 pub fn wormhole_accounts(ntt: &NTT, ntt_transceiver: &NTTTransceiver) -> WormholeAccounts {
+    wormhole_accounts_with_fee_payer(ntt, ntt_transceiver, None)
+}
+
+/// [`wormhole_accounts`], with the core bridge fee paid by `fee_payer` (if
+/// given) rather than the payer of the instruction.
+pub fn wormhole_accounts_with_fee_payer(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    fee_payer: Option<Pubkey>,
+) -> WormholeAccounts {
     WormholeAccounts {
         bridge: ntt.wormhole().bridge(),
         fee_collector: ntt.wormhole().fee_collector(),
@@ -15,7 +25,9 @@ pub fn wormhole_accounts(ntt: &NTT, ntt_transceiver: &NTTTransceiver) -> Wormhol
         system_program: System::id(),
         post_message_shim: ntt_transceiver.post_message_shim().program,
         wormhole_post_message_shim_ea: ntt_transceiver.post_message_shim().event_authority(),
+        fee_stats: Some(ntt_transceiver.fee_stats()),
         clock: Clock::id(),
         rent: Rent::id(),
+        fee_payer,
     }
 }
@@ -1,5 +1,8 @@
 use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
-pub use ntt_transceiver::wormhole::instructions::SetTransceiverPeerArgs;
+pub use example_native_token_transfers::transceivers::accounts::peer::PeerAddressMatch;
+pub use ntt_transceiver::wormhole::instructions::{
+    SetTransceiverPeerArgs, SetTransceiverPeerOptions,
+};
 use solana_sdk::instruction::Instruction;
 
 use crate::sdk::{accounts::NTT, transceivers::accounts::NTTTransceiver};
@@ -18,11 +21,159 @@ pub fn set_transceiver_peer(
     let chain_id = args.chain_id.id;
     let data = ntt_transceiver::instruction::SetWormholePeer { args };
 
+    set_transceiver_peer_with_data(ntt, ntt_transceiver, accounts, chain_id, data.data())
+}
+
+pub fn set_transceiver_peer_with_options(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    accounts: SetTransceiverPeer,
+    args: SetTransceiverPeerArgs,
+    options: SetTransceiverPeerOptions,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = ntt_transceiver::instruction::SetWormholePeerWithOptions { args, options };
+
+    set_transceiver_peer_with_data(ntt, ntt_transceiver, accounts, chain_id, data.data())
+}
+
+fn set_transceiver_peer_with_data(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    accounts: SetTransceiverPeer,
+    chain_id: u16,
+    data: Vec<u8>,
+) -> Instruction {
     let accounts = ntt_transceiver::accounts::SetTransceiverPeer {
+        config: ntt.config(),
+        owner: accounts.owner,
+        payer: accounts.payer,
+        registered_transceiver: ntt.registered_transceiver(&ntt_transceiver.program()),
+        peer: ntt_transceiver.transceiver_peer(chain_id),
+        peer_index: ntt_transceiver.transceiver_peer_index(),
+        fee_stats: ntt_transceiver.fee_stats(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt_transceiver.program(),
+        accounts: accounts.to_account_metas(None),
+        data,
+    }
+}
+
+pub struct DeleteTransceiverPeer {
+    pub owner: Pubkey,
+}
+
+pub fn delete_transceiver_peer(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    accounts: DeleteTransceiverPeer,
+    chain_id: u16,
+) -> Instruction {
+    let data = ntt_transceiver::instruction::DeleteWormholePeer { chain_id };
+
+    let accounts = ntt_transceiver::accounts::DeleteTransceiverPeer {
+        config: ntt.config(),
+        owner: accounts.owner,
+        peer: ntt_transceiver.transceiver_peer(chain_id),
+        peer_index: ntt_transceiver.transceiver_peer_index(),
+    };
+
+    Instruction {
+        program_id: ntt_transceiver.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn get_transceiver_peer_index(ntt_transceiver: &NTTTransceiver) -> Instruction {
+    let data = ntt_transceiver::instruction::GetWormholePeerIndex {};
+
+    let accounts = ntt_transceiver::accounts::GetTransceiverPeerIndex {
+        peer_index: ntt_transceiver.transceiver_peer_index(),
+    };
+
+    Instruction {
+        program_id: ntt_transceiver.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct MigrateTransceiverPeer {
+    pub payer: Pubkey,
+}
+
+pub fn migrate_transceiver_peer(
+    ntt_transceiver: &NTTTransceiver,
+    accounts: MigrateTransceiverPeer,
+    chain_id: u16,
+) -> Instruction {
+    let data = ntt_transceiver::instruction::MigrateWormholePeer { chain_id };
+
+    let accounts = ntt_transceiver::accounts::MigrateTransceiverPeer {
+        payer: accounts.payer,
+        peer: ntt_transceiver.transceiver_peer(chain_id),
+        peer_index: ntt_transceiver.transceiver_peer_index(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt_transceiver.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct RegisterPeerEmitter {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+}
+
+pub fn register_peer_emitter(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    accounts: RegisterPeerEmitter,
+    chain_id: u16,
+) -> Instruction {
+    let data = ntt_transceiver::instruction::RegisterWormholePeerEmitter { chain_id };
+
+    let accounts = ntt_transceiver::accounts::RegisterPeerEmitter {
         config: ntt.config(),
         owner: accounts.owner,
         payer: accounts.payer,
         peer: ntt_transceiver.transceiver_peer(chain_id),
+        peer_emitter: ntt_transceiver.peer_emitter(chain_id),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt_transceiver.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetBroadcastSuppressionWindow {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+}
+
+pub fn set_broadcast_suppression_window(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    accounts: SetBroadcastSuppressionWindow,
+    window: u32,
+) -> Instruction {
+    let data = ntt_transceiver::instruction::SetWormholeBroadcastSuppressionWindow { window };
+
+    let accounts = ntt_transceiver::accounts::SetBroadcastSuppressionWindow {
+        config: ntt.config(),
+        owner: accounts.owner,
+        payer: accounts.payer,
+        broadcast_suppression: ntt_transceiver.broadcast_suppression(),
         system_program: System::id(),
     };
 
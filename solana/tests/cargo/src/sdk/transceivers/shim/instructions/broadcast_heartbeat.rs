@@ -0,0 +1,35 @@
+use anchor_lang::{prelude::*, InstructionData};
+use solana_program::instruction::Instruction;
+
+use crate::sdk::{
+    accounts::NTT,
+    transceivers::accounts::{wormhole_accounts, NTTTransceiver},
+};
+
+pub struct BroadcastHeartbeat {
+    pub payer: Pubkey,
+}
+
+pub fn broadcast_heartbeat(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    accounts: BroadcastHeartbeat,
+) -> Instruction {
+    let data = ntt_transceiver::instruction::BroadcastWormholeHeartbeat {};
+
+    let accounts = ntt_transceiver::accounts::BroadcastHeartbeat {
+        payer: accounts.payer,
+        config: ntt.config(),
+        heartbeat: ntt_transceiver.heartbeat(),
+        wormhole_message: ntt_transceiver.wormhole_message(),
+        emitter: ntt_transceiver.emitter(),
+        wormhole: wormhole_accounts(ntt, ntt_transceiver),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt_transceiver.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
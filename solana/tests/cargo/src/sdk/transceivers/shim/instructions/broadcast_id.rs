@@ -1,4 +1,6 @@
 use anchor_lang::{prelude::*, InstructionData};
+use example_native_token_transfers::token_metadata::metadata_address;
+use ntt_transceiver::accounts::WormholeAccounts;
 use solana_program::instruction::Instruction;
 
 use crate::sdk::{
@@ -15,16 +17,36 @@ pub fn broadcast_id(
     ntt: &NTT,
     ntt_transceiver: &NTTTransceiver,
     accounts: BroadcastId,
+) -> Instruction {
+    broadcast_id_with_peer_emitter(ntt, ntt_transceiver, accounts, None)
+}
+
+/// [`broadcast_id`], posted from the emitter registered for the peer on
+/// `peer_emitter` (if given) rather than the default one.
+pub fn broadcast_id_with_peer_emitter(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    accounts: BroadcastId,
+    peer_emitter: Option<u16>,
 ) -> Instruction {
     let data = ntt_transceiver::instruction::BroadcastWormholeId {};
 
+    let emitter = match peer_emitter {
+        Some(chain) => ntt_transceiver.emitter_for_peer(chain),
+        None => ntt_transceiver.emitter(),
+    };
     let accounts = ntt_transceiver::accounts::BroadcastId {
         payer: accounts.payer,
         config: ntt.config(),
         mint: accounts.mint,
-        wormhole_message: ntt_transceiver.wormhole_message(),
-        emitter: ntt_transceiver.emitter(),
-        wormhole: wormhole_accounts(ntt, ntt_transceiver),
+        wormhole_message: ntt_transceiver.wormhole_message_for_emitter(&emitter),
+        emitter,
+        wormhole: WormholeAccounts {
+            sequence: ntt.wormhole().sequence(&emitter),
+            ..wormhole_accounts(ntt, ntt_transceiver)
+        },
+        token_metadata: Some(metadata_address(&accounts.mint)),
+        peer_emitter: peer_emitter.map(|chain| ntt_transceiver.peer_emitter(chain)),
     };
 
     Instruction {
@@ -1,5 +1,8 @@
 use anchor_lang::{prelude::*, InstructionData};
-use ntt_transceiver::wormhole::instructions::BroadcastPeerArgs;
+use ntt_transceiver::{
+    accounts::WormholeAccounts,
+    wormhole::{instructions::BroadcastPeerArgs, AddressEncoding},
+};
 use solana_program::instruction::Instruction;
 
 use crate::sdk::{
@@ -17,24 +20,66 @@ pub fn broadcast_peer(
     ntt_transceiver: &NTTTransceiver,
     accounts: BroadcastPeer,
 ) -> Instruction {
-    let data = ntt_transceiver::instruction::BroadcastWormholePeer {
-        args: BroadcastPeerArgs {
-            chain_id: accounts.chain_id,
-        },
+    broadcast_peer_with_address_encoding(ntt, ntt_transceiver, accounts, AddressEncoding::Universal)
+}
+
+pub fn broadcast_peer_with_address_encoding(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    accounts: BroadcastPeer,
+    address_encoding: AddressEncoding,
+) -> Instruction {
+    broadcast_peer_with_peer_emitter(ntt, ntt_transceiver, accounts, address_encoding, None)
+}
+
+/// [`broadcast_peer_with_address_encoding`], posted from the emitter registered
+/// for the peer on `peer_emitter` (if given) rather than the default one.
+pub fn broadcast_peer_with_peer_emitter(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    accounts: BroadcastPeer,
+    address_encoding: AddressEncoding,
+    peer_emitter: Option<u16>,
+) -> Instruction {
+    let args = BroadcastPeerArgs {
+        chain_id: accounts.chain_id,
+    };
+    // the universal encoding is the default, so it goes through the plain
+    // instruction
+    let data = match address_encoding {
+        AddressEncoding::Universal => {
+            ntt_transceiver::instruction::BroadcastWormholePeer { args }.data()
+        }
+        _ => ntt_transceiver::instruction::BroadcastWormholePeerWithAddressEncoding {
+            args,
+            address_encoding,
+        }
+        .data(),
     };
 
+    let emitter = match peer_emitter {
+        Some(chain) => ntt_transceiver.emitter_for_peer(chain),
+        None => ntt_transceiver.emitter(),
+    };
     let accounts = ntt_transceiver::accounts::BroadcastPeer {
         payer: accounts.payer,
         config: ntt.config(),
         peer: ntt_transceiver.transceiver_peer(accounts.chain_id),
-        wormhole_message: ntt_transceiver.wormhole_message(),
-        emitter: ntt_transceiver.emitter(),
-        wormhole: wormhole_accounts(ntt, ntt_transceiver),
+        wormhole_message: ntt_transceiver.wormhole_message_for_emitter(&emitter),
+        emitter,
+        wormhole: WormholeAccounts {
+            sequence: ntt.wormhole().sequence(&emitter),
+            ..wormhole_accounts(ntt, ntt_transceiver)
+        },
+        broadcast_suppression: ntt_transceiver.broadcast_suppression(),
+        peer_broadcast: ntt_transceiver.peer_broadcast(accounts.chain_id),
+        system_program: System::id(),
+        peer_emitter: peer_emitter.map(|chain| ntt_transceiver.peer_emitter(chain)),
     };
 
     Instruction {
         program_id: ntt_transceiver.program(),
         accounts: accounts.to_account_metas(None),
-        data: data.data(),
+        data,
     }
 }
@@ -0,0 +1,18 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::transceivers::accounts::NTTTransceiver;
+
+pub fn get_fee_stats(ntt_transceiver: &NTTTransceiver) -> Instruction {
+    let data = ntt_transceiver::instruction::GetWormholeFeeStats {};
+
+    let accounts = ntt_transceiver::accounts::GetFeeStats {
+        fee_stats: ntt_transceiver.fee_stats(),
+    };
+
+    Instruction {
+        program_id: ntt_transceiver.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
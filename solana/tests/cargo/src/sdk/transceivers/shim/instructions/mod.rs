@@ -1,6 +1,10 @@
 pub mod admin;
+pub mod broadcast_heartbeat;
 pub mod broadcast_id;
 pub mod broadcast_peer;
+pub mod get_fee_stats;
+pub mod pending_message;
+pub mod quote_delivery_cost;
 pub mod receive_message;
 pub mod release_outbound;
 pub mod unverified_message_account;
@@ -0,0 +1,74 @@
+use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::{
+    accounts::NTT,
+    transceivers::{accounts::NTTTransceiver, instructions::receive_message::ReceiveMessage},
+};
+
+pub fn verify_pending_message(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    receive_message: ReceiveMessage,
+    seed: u64,
+) -> Instruction {
+    let data = ntt_transceiver::instruction::VerifyPendingWormholeMessage {
+        guardian_set_bump: receive_message.guardian_set.1,
+        seed,
+    };
+
+    let accounts = ntt_transceiver::accounts::VerifyPendingMessage {
+        payer: receive_message.payer,
+        config: ntt.config(),
+        peer: receive_message.peer,
+        message: ntt_transceiver.unverified_message_account(&receive_message.payer, seed),
+        pending_message: ntt_transceiver
+            .pending_message(receive_message.chain_id, receive_message.id),
+        guardian_set: receive_message.guardian_set.0,
+        guardian_signatures: receive_message.guardian_signatures,
+        verify_vaa_shim: ntt_transceiver.verify_vaa_shim_shim(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt_transceiver.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PromotePendingMessage {
+    pub payer: Pubkey,
+    pub rent_payer: Pubkey,
+    pub peer: Pubkey,
+    pub chain_id: u16,
+    pub id: [u8; 32],
+}
+
+pub fn promote_pending_message(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    promote: PromotePendingMessage,
+) -> Instruction {
+    let data = ntt_transceiver::instruction::PromotePendingWormholeMessage {};
+
+    let accounts = ntt_transceiver::accounts::PromotePendingMessage {
+        payer: promote.payer,
+        config: ntt_transceiver::accounts::NotPausedInboundConfig {
+            config: ntt.config(),
+        },
+        peer: promote.peer,
+        manager_peer: ntt.peer(promote.chain_id),
+        pending_message: ntt_transceiver.pending_message(promote.chain_id, promote.id),
+        rent_payer: promote.rent_payer,
+        transceiver_message: ntt_transceiver.transceiver_message(promote.chain_id, promote.id),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt_transceiver.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -0,0 +1,23 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::transceivers::accounts::NTTTransceiver;
+
+pub fn quote_delivery_cost(
+    ntt_transceiver: &NTTTransceiver,
+    vaa_size: u32,
+    recipient_has_ata: bool,
+) -> Instruction {
+    let data = ntt_transceiver::instruction::QuoteDeliveryCost {
+        vaa_size,
+        recipient_has_ata,
+    };
+
+    let accounts = ntt_transceiver::accounts::QuoteDeliveryCost {};
+
+    Instruction {
+        program_id: ntt_transceiver.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
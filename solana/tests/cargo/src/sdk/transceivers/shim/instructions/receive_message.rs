@@ -12,6 +12,9 @@ pub struct ReceiveMessage {
     pub id: [u8; 32],
     pub guardian_set: (Pubkey, u8),
     pub guardian_signatures: Pubkey,
+    /// If set, the program checks that the VAA body hashes to this digest
+    /// (through the `*_with_digest` instructions).
+    pub expected_digest: Option<[u8; 32]>,
 }
 
 pub fn receive_message_instruction_data(
@@ -20,17 +23,30 @@ pub fn receive_message_instruction_data(
     receive_message: ReceiveMessage,
     vaa_body: VaaBodyData,
 ) -> Instruction {
-    let data = ntt_transceiver::instruction::ReceiveWormholeMessageInstructionData {
-        guardian_set_bump: receive_message.guardian_set.1,
-        vaa_body,
+    let guardian_set_bump = receive_message.guardian_set.1;
+    let data = match receive_message.expected_digest {
+        Some(expected_digest) => {
+            ntt_transceiver::instruction::ReceiveWormholeMessageInstructionDataWithDigest {
+                guardian_set_bump,
+                vaa_body,
+                expected_digest,
+            }
+            .data()
+        }
+        None => ntt_transceiver::instruction::ReceiveWormholeMessageInstructionData {
+            guardian_set_bump,
+            vaa_body,
+        }
+        .data(),
     };
 
     let accounts = ntt_transceiver::accounts::ReceiveMessageInstructionData {
         payer: receive_message.payer,
-        config: ntt_transceiver::accounts::NotPausedConfig {
+        config: ntt_transceiver::accounts::NotPausedInboundConfig {
             config: ntt.config(),
         },
         peer: receive_message.peer,
+        manager_peer: ntt.peer(receive_message.chain_id),
         transceiver_message: ntt_transceiver
             .transceiver_message(receive_message.chain_id, receive_message.id),
         guardian_set: receive_message.guardian_set.0,
@@ -42,7 +58,7 @@ pub fn receive_message_instruction_data(
     Instruction {
         program_id: ntt_transceiver.program(),
         accounts: accounts.to_account_metas(None),
-        data: data.data(),
+        data,
     }
 }
 
@@ -52,17 +68,30 @@ pub fn receive_message_account(
     receive_message: ReceiveMessage,
     seed: u64,
 ) -> Instruction {
-    let data = ntt_transceiver::instruction::ReceiveWormholeMessageAccount {
-        guardian_set_bump: receive_message.guardian_set.1,
-        seed,
+    let guardian_set_bump = receive_message.guardian_set.1;
+    let data = match receive_message.expected_digest {
+        Some(expected_digest) => {
+            ntt_transceiver::instruction::ReceiveWormholeMessageAccountWithDigest {
+                guardian_set_bump,
+                seed,
+                expected_digest,
+            }
+            .data()
+        }
+        None => ntt_transceiver::instruction::ReceiveWormholeMessageAccount {
+            guardian_set_bump,
+            seed,
+        }
+        .data(),
     };
 
     let accounts = ntt_transceiver::accounts::ReceiveMessageAccount {
         payer: receive_message.payer,
-        config: ntt_transceiver::accounts::NotPausedConfig {
+        config: ntt_transceiver::accounts::NotPausedInboundConfig {
             config: ntt.config(),
         },
         peer: receive_message.peer,
+        manager_peer: ntt.peer(receive_message.chain_id),
         transceiver_message: ntt_transceiver
             .transceiver_message(receive_message.chain_id, receive_message.id),
         guardian_set: receive_message.guardian_set.0,
@@ -75,6 +104,6 @@ pub fn receive_message_account(
     Instruction {
         program_id: ntt_transceiver.program(),
         accounts: accounts.to_account_metas(None),
-        data: data.data(),
+        data,
     }
 }
@@ -1,16 +1,18 @@
 use anchor_lang::{prelude::*, InstructionData, ToAccountMetas};
-use example_native_token_transfers::accounts::NotPausedConfig;
-use ntt_transceiver::wormhole::instructions::ReleaseOutboundArgs;
+use example_native_token_transfers::accounts::NotPausedOutboundConfig;
+use ntt_transceiver::wormhole::instructions::{ReleaseOutboundArgs, ReleaseOutboundOptions};
 use solana_sdk::instruction::Instruction;
 
 use crate::sdk::{
     accounts::NTT,
-    transceivers::accounts::{wormhole_accounts, NTTTransceiver},
+    transceivers::accounts::{wormhole_accounts_with_fee_payer, NTTTransceiver},
 };
 
 pub struct ReleaseOutbound {
     pub payer: Pubkey,
     pub outbox_item: Pubkey,
+    /// The recipient chain of the outbox item, used to derive its peer.
+    pub recipient_chain: u16,
 }
 
 pub fn release_outbound(
@@ -18,24 +20,58 @@ pub fn release_outbound(
     ntt_transceiver: &NTTTransceiver,
     accounts: ReleaseOutbound,
     args: ReleaseOutboundArgs,
+) -> Instruction {
+    release_outbound_with_fee_payer(ntt, ntt_transceiver, accounts, args, None)
+}
+
+pub fn release_outbound_with_options(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    accounts: ReleaseOutbound,
+    args: ReleaseOutboundArgs,
+    options: ReleaseOutboundOptions,
+) -> Instruction {
+    let data = ntt_transceiver::instruction::ReleaseWormholeOutboundWithOptions { args, options };
+    release_outbound_with_data(ntt, ntt_transceiver, accounts, data.data(), None)
+}
+
+/// [`release_outbound`], with the core bridge fee paid by `fee_payer` (if
+/// given) rather than the payer.
+pub fn release_outbound_with_fee_payer(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    accounts: ReleaseOutbound,
+    args: ReleaseOutboundArgs,
+    fee_payer: Option<Pubkey>,
 ) -> Instruction {
     let data = ntt_transceiver::instruction::ReleaseWormholeOutbound { args };
+    release_outbound_with_data(ntt, ntt_transceiver, accounts, data.data(), fee_payer)
+}
+
+fn release_outbound_with_data(
+    ntt: &NTT,
+    ntt_transceiver: &NTTTransceiver,
+    accounts: ReleaseOutbound,
+    data: Vec<u8>,
+    fee_payer: Option<Pubkey>,
+) -> Instruction {
     let accounts = ntt_transceiver::accounts::ReleaseOutbound {
         payer: accounts.payer,
-        config: NotPausedConfig {
+        config: NotPausedOutboundConfig {
             config: ntt.config(),
         },
         outbox_item: accounts.outbox_item,
+        peer: ntt.peer(accounts.recipient_chain),
         transceiver: ntt.registered_transceiver(&ntt_transceiver.program()),
         wormhole_message: ntt_transceiver.wormhole_message(),
         emitter: ntt_transceiver.emitter(),
-        wormhole: wormhole_accounts(ntt, ntt_transceiver),
+        wormhole: wormhole_accounts_with_fee_payer(ntt, ntt_transceiver, fee_payer),
         manager: ntt.program(),
         outbox_item_signer: ntt_transceiver.outbox_item_signer(),
     };
     Instruction {
         program_id: ntt_transceiver.program(),
         accounts: accounts.to_account_metas(None),
-        data: data.data(),
+        data,
     }
 }
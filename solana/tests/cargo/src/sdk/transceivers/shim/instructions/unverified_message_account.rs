@@ -38,7 +38,6 @@ pub fn post_unverified_message_account(
     }
 }
 
-// NOTE: this is currently unused but left for posterity
 pub fn close_unverified_message_account(
     ntt_transceiver: &NTTTransceiver,
     accounts: UnverifiedMessageAccount,
@@ -58,3 +57,22 @@ pub fn close_unverified_message_account(
         data: data.data(),
     }
 }
+
+pub fn cleanup_expired_vaa_body(
+    ntt_transceiver: &NTTTransceiver,
+    accounts: UnverifiedMessageAccount,
+    seed: u64,
+) -> Instruction {
+    let data = ntt_transceiver::instruction::CleanupExpiredVaaBody { seed };
+
+    let accounts = ntt_transceiver::accounts::CleanupExpiredVaaBody {
+        payer: accounts.payer,
+        message: ntt_transceiver.unverified_message_account(&accounts.payer, seed),
+    };
+
+    Instruction {
+        program_id: ntt_transceiver.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -35,6 +35,110 @@ export type ExampleNativeTokenTransfers = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "In any case, this function is used to set the Config and initialize the program so we",
+            "assume the caller of this function will have total control over the program.",
+            "",
+            "TODO: Using `UncheckedAccount` here leads to \"Access violation in stack frame ...\".",
+            "Could refactor code to use `Box<_>` to reduce stack size."
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The custody account that holds tokens in locking mode and temporarily",
+            "holds tokens in burning mode.",
+            "function if the token account has already been created."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "associated token account for the given mint."
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeWithPeers",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "deployer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "tokenAuthority",
           "isMut": false,
@@ -93,9 +197,62 @@ export type ExampleNativeTokenTransfers = {
           "type": {
             "defined": "InitializeArgs"
           }
+        },
+        {
+          "name": "peers",
+          "type": {
+            "vec": {
+              "defined": "InitialPeer"
+            }
+          }
         }
       ]
     },
+    {
+      "name": "preflightInitialize",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "mode",
+          "type": {
+            "defined": "Mode"
+          }
+        }
+      ],
+      "returns": "u32"
+    },
     {
       "name": "initializeLut",
       "accounts": [
@@ -204,6 +361,16 @@ export type ExampleNativeTokenTransfers = {
                   "name": "rent",
                   "isMut": false,
                   "isSigner": false
+                },
+                {
+                  "name": "feePayer",
+                  "isMut": true,
+                  "isSigner": true,
+                  "isOptional": true,
+                  "docs": [
+                    "Pays the core bridge fee if given, so that the payer of the instruction",
+                    "only covers rent."
+                  ]
                 }
               ]
             }
@@ -270,7 +437,11 @@ export type ExampleNativeTokenTransfers = {
             {
               "name": "outboxRateLimit",
               "isMut": true,
-              "isSigner": false
+              "isSigner": false,
+              "docs": [
+                "a missing rate limit fails with a specific error rather than Anchor's",
+                "generic one."
+              ]
             },
             {
               "name": "custody",
@@ -296,7 +467,7 @@ export type ExampleNativeTokenTransfers = {
         },
         {
           "name": "peer",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -304,6 +475,7 @@ export type ExampleNativeTokenTransfers = {
           "isMut": false,
           "isSigner": false,
           "docs": [
+            "in the handler, see [`session_authority_bump`].",
             "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
           ]
         },
@@ -311,6 +483,47 @@ export type ExampleNativeTokenTransfers = {
           "name": "tokenAuthority",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the mint authority is a multisig of the token authority.",
+            "Burning doesn't need the mint authority, but tokens burned while it",
+            "has been rotated away could not be minted back when they return."
+          ]
+        },
+        {
+          "name": "dustAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when dust collection is turned on (see [`Config::dust_account`])."
+          ]
+        },
+        {
+          "name": "senderRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "[`Config::limits_senders`]), in which case it's created on the sender's",
+            "first transfer. See [`insert_into_outbox`]."
+          ]
+        },
+        {
+          "name": "chainOutboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "required once per-chain limits are turned on (see",
+            "[`Config::per_chain_outbound_limits`]), and it only exists for chains",
+            "that have been given their own limit."
+          ]
         }
       ],
       "args": [
@@ -369,7 +582,11 @@ export type ExampleNativeTokenTransfers = {
             {
               "name": "outboxRateLimit",
               "isMut": true,
-              "isSigner": false
+              "isSigner": false,
+              "docs": [
+                "a missing rate limit fails with a specific error rather than Anchor's",
+                "generic one."
+              ]
             },
             {
               "name": "custody",
@@ -395,7 +612,7 @@ export type ExampleNativeTokenTransfers = {
         },
         {
           "name": "peer",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -403,100 +620,62 @@ export type ExampleNativeTokenTransfers = {
           "isMut": false,
           "isSigner": false,
           "docs": [
+            "in the handler, see [`session_authority_bump`].",
             "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
           ]
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "TransferArgs"
-          }
-        }
-      ]
-    },
-    {
-      "name": "redeem",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "config",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "peer",
-          "isMut": false,
-          "isSigner": false
         },
         {
-          "name": "transceiverMessage",
-          "isMut": false,
+          "name": "dustAccount",
+          "isMut": true,
           "isSigner": false,
+          "isOptional": true,
           "docs": [
-            "`Account<T>` and `owner` constraints are mutually-exclusive"
+            "Required when dust collection is turned on (see [`Config::dust_account`])."
           ]
         },
         {
-          "name": "transceiver",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "inboxItem",
+          "name": "senderRateLimit",
           "isMut": true,
           "isSigner": false,
+          "isOptional": true,
           "docs": [
-            "NOTE: This account is content-addressed (PDA seeded by the message hash).",
-            "This is because in a multi-transceiver configuration, the different",
-            "transceivers \"vote\" on messages (by delivering them). By making the inbox",
-            "items content-addressed, we can ensure that disagreeing votes don't",
-            "interfere with each other.",
-            "On the first call to [`redeem()`], [`InboxItem`] will be allocated and initialized with",
-            "default values.",
-            "On subsequent calls, we want to modify the `InboxItem` by \"voting\" on it. Therefore the",
-            "program should not fail which would occur when using the `init` constraint.",
-            "The [`InboxItem::init`] field is used to guard against malicious or accidental modification",
-            "InboxItem fields that should remain constant."
+            "[`Config::limits_senders`]), in which case it's created on the sender's",
+            "first transfer. See [`insert_into_outbox`]."
           ]
         },
         {
-          "name": "inboxRateLimit",
+          "name": "chainOutboxRateLimit",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "required once per-chain limits are turned on (see",
+            "[`Config::per_chain_outbound_limits`]), and it only exists for chains",
+            "that have been given their own limit."
+          ]
         },
         {
-          "name": "outboxRateLimit",
+          "name": "custodyLedger",
           "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required unless the deployment doesn't track custody, see",
+            "[`Config::tracks_custody`]."
+          ]
         }
       ],
       "args": [
         {
           "name": "args",
           "type": {
-            "defined": "RedeemArgs"
+            "defined": "TransferArgs"
           }
         }
       ]
     },
     {
-      "name": "releaseInboundMint",
+      "name": "transferBurnWithOptions",
       "accounts": [
         {
           "name": "common",
@@ -517,55 +696,137 @@ export type ExampleNativeTokenTransfers = {
               ]
             },
             {
-              "name": "inboxItem",
+              "name": "mint",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "recipient",
+              "name": "from",
               "isMut": true,
-              "isSigner": false
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
             },
             {
-              "name": "tokenAuthority",
+              "name": "tokenProgram",
               "isMut": false,
               "isSigner": false
             },
             {
-              "name": "mint",
+              "name": "outboxItem",
               "isMut": true,
-              "isSigner": false
+              "isSigner": true
             },
             {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "a missing rate limit fails with a specific error rather than Anchor's",
+                "generic one."
+              ]
             },
             {
               "name": "custody",
               "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
               "isSigner": false
             }
           ]
         },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "in the handler, see [`session_authority_bump`].",
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "multisigTokenAuthority",
           "isMut": false,
           "isSigner": false,
-          "isOptional": true
+          "isOptional": true,
+          "docs": [
+            "Required when the mint authority is a multisig of the token authority.",
+            "Burning doesn't need the mint authority, but tokens burned while it",
+            "has been rotated away could not be minted back when they return."
+          ]
+        },
+        {
+          "name": "dustAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when dust collection is turned on (see [`Config::dust_account`])."
+          ]
+        },
+        {
+          "name": "senderRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "[`Config::limits_senders`]), in which case it's created on the sender's",
+            "first transfer. See [`insert_into_outbox`]."
+          ]
+        },
+        {
+          "name": "chainOutboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "required once per-chain limits are turned on (see",
+            "[`Config::per_chain_outbound_limits`]), and it only exists for chains",
+            "that have been given their own limit."
+          ]
         }
       ],
       "args": [
         {
           "name": "args",
           "type": {
-            "defined": "ReleaseInboundArgs"
+            "defined": "TransferArgs"
+          }
+        },
+        {
+          "name": "options",
+          "type": {
+            "defined": "TransferOptions"
           }
         }
       ]
     },
     {
-      "name": "releaseInboundUnlock",
+      "name": "transferLockWithOptions",
       "accounts": [
         {
           "name": "common",
@@ -586,160 +847,149 @@ export type ExampleNativeTokenTransfers = {
               ]
             },
             {
-              "name": "inboxItem",
+              "name": "mint",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "recipient",
+              "name": "from",
               "isMut": true,
-              "isSigner": false
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
             },
             {
-              "name": "tokenAuthority",
+              "name": "tokenProgram",
               "isMut": false,
               "isSigner": false
             },
             {
-              "name": "mint",
+              "name": "outboxItem",
               "isMut": true,
-              "isSigner": false
+              "isSigner": true
             },
             {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "a missing rate limit fails with a specific error rather than Anchor's",
+                "generic one."
+              ]
             },
             {
               "name": "custody",
               "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
               "isSigner": false
             }
           ]
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "ReleaseInboundArgs"
-          }
-        }
-      ]
-    },
-    {
-      "name": "transferOwnership",
-      "accounts": [
-        {
-          "name": "config",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "newOwner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "upgradeLock",
-          "isMut": false,
-          "isSigner": false
         },
         {
-          "name": "programData",
+          "name": "inboxRateLimit",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bpfLoaderUpgradeableProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "transferOwnershipOneStepUnchecked",
-      "accounts": [
-        {
-          "name": "config",
+          "name": "peer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "sessionAuthority",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false,
+          "docs": [
+            "in the handler, see [`session_authority_bump`].",
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
         },
         {
-          "name": "newOwner",
-          "isMut": false,
-          "isSigner": false
+          "name": "dustAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when dust collection is turned on (see [`Config::dust_account`])."
+          ]
         },
         {
-          "name": "upgradeLock",
-          "isMut": false,
-          "isSigner": false
+          "name": "senderRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "[`Config::limits_senders`]), in which case it's created on the sender's",
+            "first transfer. See [`insert_into_outbox`]."
+          ]
         },
         {
-          "name": "programData",
+          "name": "chainOutboxRateLimit",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "required once per-chain limits are turned on (see",
+            "[`Config::per_chain_outbound_limits`]), and it only exists for chains",
+            "that have been given their own limit."
+          ]
         },
         {
-          "name": "bpfLoaderUpgradeableProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required unless the deployment doesn't track custody, see",
+            "[`Config::tracks_custody`]."
+          ]
         }
       ],
-      "args": []
-    },
-    {
-      "name": "claimOwnership",
-      "accounts": [
-        {
-          "name": "config",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "upgradeLock",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "newOwner",
-          "isMut": false,
-          "isSigner": true
-        },
+      "args": [
         {
-          "name": "programData",
-          "isMut": true,
-          "isSigner": false
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
         },
         {
-          "name": "bpfLoaderUpgradeableProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "options",
+          "type": {
+            "defined": "TransferOptions"
+          }
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "acceptTokenAuthority",
+      "name": "transferBurnWithExpiry",
       "accounts": [
         {
           "name": "common",
           "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
             {
               "name": "config",
-              "isMut": false,
-              "isSigner": false
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
             },
             {
               "name": "mint",
@@ -747,88 +997,148 @@ export type ExampleNativeTokenTransfers = {
               "isSigner": false
             },
             {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "multisigTokenAuthority",
-              "isMut": false,
+              "name": "from",
+              "isMut": true,
               "isSigner": false,
-              "isOptional": true
+              "docs": [
+                "account can spend these tokens."
+              ]
             },
             {
               "name": "tokenProgram",
               "isMut": false,
               "isSigner": false
-            }
-          ]
-        },
-        {
-          "name": "currentAuthority",
-          "isMut": false,
-          "isSigner": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "acceptTokenAuthorityFromMultisig",
-      "accounts": [
-        {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
             },
             {
-              "name": "mint",
+              "name": "outboxItem",
               "isMut": true,
-              "isSigner": false
+              "isSigner": true
             },
             {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "a missing rate limit fails with a specific error rather than Anchor's",
+                "generic one."
+              ]
             },
             {
-              "name": "multisigTokenAuthority",
-              "isMut": false,
+              "name": "custody",
+              "isMut": true,
               "isSigner": false,
-              "isOptional": true
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
             },
             {
-              "name": "tokenProgram",
+              "name": "systemProgram",
               "isMut": false,
               "isSigner": false
             }
           ]
         },
         {
-          "name": "currentMultisigAuthority",
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "in the handler, see [`session_authority_bump`].",
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the mint authority is a multisig of the token authority.",
+            "Burning doesn't need the mint authority, but tokens burned while it",
+            "has been rotated away could not be minted back when they return."
+          ]
+        },
+        {
+          "name": "dustAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when dust collection is turned on (see [`Config::dust_account`])."
+          ]
+        },
+        {
+          "name": "senderRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "[`Config::limits_senders`]), in which case it's created on the sender's",
+            "first transfer. See [`insert_into_outbox`]."
+          ]
+        },
+        {
+          "name": "chainOutboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "required once per-chain limits are turned on (see",
+            "[`Config::per_chain_outbound_limits`]), and it only exists for chains",
+            "that have been given their own limit."
+          ]
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        },
+        {
+          "name": "expiryTimestamp",
+          "type": "i64"
+        }
+      ]
     },
     {
-      "name": "setTokenAuthorityOneStepUnchecked",
+      "name": "transferLockWithExpiry",
       "accounts": [
         {
           "name": "common",
           "accounts": [
             {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
             },
             {
-              "name": "owner",
-              "isMut": false,
-              "isSigner": true
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
             },
             {
               "name": "mint",
@@ -836,532 +1146,357 @@ export type ExampleNativeTokenTransfers = {
               "isSigner": false
             },
             {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "multisigTokenAuthority",
-              "isMut": false,
+              "name": "from",
+              "isMut": true,
               "isSigner": false,
-              "isOptional": true
+              "docs": [
+                "account can spend these tokens."
+              ]
             },
             {
-              "name": "newAuthority",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "setTokenAuthority",
-      "accounts": [
-        {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "config",
+              "name": "tokenProgram",
               "isMut": false,
               "isSigner": false
             },
             {
-              "name": "owner",
-              "isMut": false,
+              "name": "outboxItem",
+              "isMut": true,
               "isSigner": true
             },
             {
-              "name": "mint",
+              "name": "outboxRateLimit",
               "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
+              "isSigner": false,
+              "docs": [
+                "a missing rate limit fails with a specific error rather than Anchor's",
+                "generic one."
+              ]
             },
             {
-              "name": "multisigTokenAuthority",
-              "isMut": false,
+              "name": "custody",
+              "isMut": true,
               "isSigner": false,
-              "isOptional": true
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
             },
             {
-              "name": "newAuthority",
+              "name": "systemProgram",
               "isMut": false,
               "isSigner": false
             }
           ]
         },
         {
-          "name": "rentPayer",
+          "name": "inboxRateLimit",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "pendingTokenAuthority",
+          "name": "peer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "sessionAuthority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "in the handler, see [`session_authority_bump`].",
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "dustAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when dust collection is turned on (see [`Config::dust_account`])."
+          ]
+        },
+        {
+          "name": "senderRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "[`Config::limits_senders`]), in which case it's created on the sender's",
+            "first transfer. See [`insert_into_outbox`]."
+          ]
+        },
+        {
+          "name": "chainOutboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "required once per-chain limits are turned on (see",
+            "[`Config::per_chain_outbound_limits`]), and it only exists for chains",
+            "that have been given their own limit."
+          ]
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required unless the deployment doesn't track custody, see",
+            "[`Config::tracks_custody`]."
+          ]
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        },
+        {
+          "name": "expiryTimestamp",
+          "type": "i64"
+        }
+      ]
     },
     {
-      "name": "revertTokenAuthority",
+      "name": "cancelOutboundTransfer",
       "accounts": [
         {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "mint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "multisigTokenAuthority",
-              "isMut": false,
-              "isSigner": false,
-              "isOptional": true
-            },
-            {
-              "name": "rentPayer",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "pendingTokenAuthority",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
-        },
-        {
-          "name": "owner",
+          "name": "sender",
           "isMut": false,
           "isSigner": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "claimTokenAuthority",
-      "accounts": [
-        {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "mint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "multisigTokenAuthority",
-              "isMut": false,
-              "isSigner": false,
-              "isOptional": true
-            },
-            {
-              "name": "rentPayer",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "pendingTokenAuthority",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
         },
         {
-          "name": "newAuthority",
+          "name": "config",
           "isMut": false,
-          "isSigner": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "claimTokenAuthorityToMultisig",
-      "accounts": [
-        {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "mint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "multisigTokenAuthority",
-              "isMut": false,
-              "isSigner": false,
-              "isOptional": true
-            },
-            {
-              "name": "rentPayer",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "pendingTokenAuthority",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            }
+          "isSigner": false,
+          "docs": [
+            "Not gated on [`Config::paused_outbound`], so that a pause doesn't keep",
+            "senders from taking back transfers it holds up."
           ]
         },
         {
-          "name": "newMultisigAuthority",
-          "isMut": false,
+          "name": "outboxItem",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "setPaused",
-      "accounts": [
+        },
         {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required unless the outbox item predates [`OutboxItem::rent_payer`]."
+          ]
         },
         {
-          "name": "config",
+          "name": "peer",
           "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "pause",
-          "type": "bool"
-        }
-      ]
-    },
-    {
-      "name": "setPeer",
-      "accounts": [
+        },
         {
-          "name": "payer",
+          "name": "mint",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
+          "name": "to",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The sender's token account the tokens are returned to."
+          ]
         },
         {
-          "name": "config",
+          "name": "tokenAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "peer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "inboxRateLimit",
+          "name": "custody",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SetPeerArgs"
-          }
-        }
-      ]
-    },
-    {
-      "name": "registerTransceiver",
-      "accounts": [
+        },
         {
-          "name": "config",
+          "name": "custodyLedger",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed in locking mode."
+          ]
         },
         {
-          "name": "owner",
+          "name": "multisigTokenAuthority",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed in burning mode, when the mint authority is a multisig."
+          ]
         },
         {
-          "name": "payer",
+          "name": "outboxRateLimit",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "transceiver",
-          "isMut": false,
+          "name": "chainOutboxRateLimit",
+          "isMut": true,
           "isSigner": false,
+          "isOptional": true,
           "docs": [
-            "used here that wraps the Transceiver account type."
+            "Only needed when the transfer consumed from the recipient chain's own",
+            "limit (see [`OutboxItem::consumed_chain_outbound_limit`])."
           ]
         },
         {
-          "name": "registeredTransceiver",
+          "name": "senderRateLimit",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed when the transfer consumed from the sender's limit (see",
+            "[`OutboxItem::consumed_sender_limit`])."
+          ]
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "inboxRateLimit",
+          "isMut": true,
           "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "deregisterTransceiver",
+      "name": "closeExpiredOutboxItem",
       "accounts": [
         {
-          "name": "config",
+          "name": "outboxItem",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "registeredTransceiver",
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "setOutboundLimit",
-      "accounts": [
+        },
         {
-          "name": "config",
-          "isMut": false,
+          "name": "mint",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
+          "name": "to",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The sender's token account the tokens are returned to."
+          ]
         },
         {
-          "name": "rateLimit",
-          "isMut": true,
+          "name": "tokenAuthority",
+          "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SetOutboundLimitArgs"
-          }
-        }
-      ]
-    },
-    {
-      "name": "setInboundLimit",
-      "accounts": [
+        },
         {
-          "name": "config",
-          "isMut": false,
+          "name": "custody",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "tokenProgram",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "rateLimit",
+          "name": "custodyLedger",
           "isMut": true,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SetInboundLimitArgs"
-          }
-        }
-      ]
-    },
-    {
-      "name": "markOutboxItemAsReleased",
-      "accounts": [
-        {
-          "name": "signer",
-          "isMut": false,
-          "isSigner": true
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed in locking mode."
+          ]
         },
         {
-          "name": "config",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
-            }
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed in burning mode, when the mint authority is a multisig."
           ]
         },
         {
-          "name": "outboxItem",
+          "name": "outboxRateLimit",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "transceiver",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [],
-      "returns": "bool"
-    },
-    {
-      "name": "setThreshold",
-      "accounts": [
+          "name": "chainOutboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed when the transfer consumed from the recipient chain's own",
+            "limit (see [`OutboxItem::consumed_chain_outbound_limit`])."
+          ]
+        },
         {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
+          "name": "senderRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed when the transfer consumed from the sender's limit (see",
+            "[`OutboxItem::consumed_sender_limit`])."
+          ]
         },
         {
-          "name": "config",
+          "name": "inboxRateLimit",
           "isMut": true,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "threshold",
-          "type": "u8"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "setWormholePeer",
+      "name": "closeInboxItem",
       "accounts": [
         {
-          "name": "config",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "peer",
+          "name": "inboxItem",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "rentRecipient",
+          "isMut": true,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SetTransceiverPeerArgs"
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "receiveWormholeMessage",
+      "name": "redeem",
       "accounts": [
         {
           "name": "payer",
@@ -1380,192 +1515,272 @@ export type ExampleNativeTokenTransfers = {
         },
         {
           "name": "peer",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "vaa",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "transceiverMessage",
-          "isMut": true,
-          "isSigner": false
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "`Account<T>` and `owner` constraints are mutually-exclusive"
+          ]
         },
         {
-          "name": "systemProgram",
+          "name": "transceiver",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "releaseWormholeOutbound",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
         },
         {
-          "name": "config",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
-            }
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NOTE: This account is content-addressed (PDA seeded by the message hash).",
+            "This is because in a multi-transceiver configuration, the different",
+            "transceivers \"vote\" on messages (by delivering them). By making the inbox",
+            "items content-addressed, we can ensure that disagreeing votes don't",
+            "interfere with each other.",
+            "On the first call to [`redeem()`], [`InboxItem`] will be allocated and initialized with",
+            "default values.",
+            "On subsequent calls, we want to modify the `InboxItem` by \"voting\" on it. Therefore the",
+            "program should not fail which would occur when using the `init` constraint.",
+            "The account discriminator is only written once the first call exits, so its absence is",
+            "used to guard against malicious or accidental modification InboxItem fields that should",
+            "remain constant.",
+            "NOTE: inbox items in the legacy layout fail the `space` check of `init_if_needed`, and",
+            "have to be converted with [`crate::instructions::migrate_inbox_item`] first."
           ]
         },
         {
-          "name": "outboxItem",
+          "name": "inboxRateLimit",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "transceiver",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "wormholeMessage",
+          "name": "outboxRateLimit",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "emitter",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "wormhole",
+          "name": "args",
+          "type": {
+            "defined": "RedeemArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundMint",
+      "accounts": [
+        {
+          "name": "common",
           "accounts": [
             {
-              "name": "bridge",
+              "name": "payer",
               "isMut": true,
-              "isSigner": false
+              "isSigner": true
             },
             {
-              "name": "feeCollector",
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "sequence",
+              "name": "recipient",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "program",
+              "name": "tokenAuthority",
               "isMut": false,
               "isSigner": false
             },
             {
-              "name": "systemProgram",
-              "isMut": false,
+              "name": "mint",
+              "isMut": true,
               "isSigner": false
             },
             {
-              "name": "clock",
+              "name": "tokenProgram",
               "isMut": false,
               "isSigner": false
             },
             {
-              "name": "rent",
-              "isMut": false,
+              "name": "custody",
+              "isMut": true,
               "isSigner": false
-            }
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "ReleaseOutboundArgs"
+            },
+            {
+              "name": "peer",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "The peer the transfer came from, whose",
+                "[`NttManagerPeer::inbound_in_flight`] is decremented. Only optional",
+                "for inbox items that predate [`InboxItem::from_chain`]."
+              ]
+            },
+            {
+              "name": "fallbackRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "The peer's [`NttManagerPeer::fallback_recipient`]. The tokens are",
+                "delivered here instead if [`ReleaseInbound::recipient`] is frozen."
+              ]
+            },
+            {
+              "name": "peerStats",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "When passed, the delivery latency of the transfer is recorded in it."
+              ]
+            }
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
           }
         }
       ]
     },
     {
-      "name": "broadcastWormholeId",
+      "name": "releaseInboundUnlock",
       "accounts": [
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "config",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "wormholeMessage",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "emitter",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "enforced by the [`CpiContext`] call in [`post_message`].",
-            "The seeds constraint ensures that this is the correct address"
-          ]
-        },
-        {
-          "name": "wormhole",
+          "name": "common",
           "accounts": [
             {
-              "name": "bridge",
+              "name": "payer",
               "isMut": true,
-              "isSigner": false
+              "isSigner": true
             },
             {
-              "name": "feeCollector",
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "sequence",
+              "name": "recipient",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "program",
+              "name": "tokenAuthority",
               "isMut": false,
               "isSigner": false
             },
             {
-              "name": "systemProgram",
-              "isMut": false,
+              "name": "mint",
+              "isMut": true,
               "isSigner": false
             },
             {
-              "name": "clock",
+              "name": "tokenProgram",
               "isMut": false,
               "isSigner": false
             },
             {
-              "name": "rent",
-              "isMut": false,
+              "name": "custody",
+              "isMut": true,
               "isSigner": false
+            },
+            {
+              "name": "peer",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "The peer the transfer came from, whose",
+                "[`NttManagerPeer::inbound_in_flight`] is decremented. Only optional",
+                "for inbox items that predate [`InboxItem::from_chain`]."
+              ]
+            },
+            {
+              "name": "fallbackRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "The peer's [`NttManagerPeer::fallback_recipient`]. The tokens are",
+                "delivered here instead if [`ReleaseInbound::recipient`] is frozen."
+              ]
+            },
+            {
+              "name": "peerStats",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "When passed, the delivery latency of the transfer is recorded in it."
+              ]
             }
           ]
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required unless the deployment doesn't track custody, see",
+            "[`Config::tracks_custody`]."
+          ]
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
     },
     {
-      "name": "broadcastWormholePeer",
+      "name": "repairCustody",
       "accounts": [
         {
           "name": "payer",
@@ -1578,997 +1793,961 @@ export type ExampleNativeTokenTransfers = {
           "isSigner": false
         },
         {
-          "name": "peer",
+          "name": "mint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "wormholeMessage",
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false,
+          "docs": [
+            "The custody account is the associated token account of the token",
+            "authority, so re-deriving it from the configured mint and token program",
+            "yields exactly [`Config::custody`]. `init_if_needed` makes repairing an",
+            "existing account a no-op."
+          ]
         },
         {
-          "name": "emitter",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "wormhole",
-          "accounts": [
-            {
-              "name": "bridge",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "feeCollector",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "sequence",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "program",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "clock",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "rent",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "reconcileCustody",
+      "accounts": [
         {
-          "name": "args",
-          "type": {
-            "defined": "BroadcastPeerArgs"
-          }
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
-    }
-  ],
-  "accounts": [
+      ],
+      "args": [],
+      "returns": {
+        "defined": "CustodyReconciliation"
+      }
+    },
     {
-      "name": "config",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "owner",
-            "docs": [
-              "Owner of the program."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "pendingOwner",
-            "docs": [
-              "Pending next owner (before claiming ownership)."
-            ],
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "mint",
-            "docs": [
-              "Mint address of the token managed by this program."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenProgram",
-            "docs": [
-              "Address of the token program (token or token22). This could always be queried",
-              "from the [`mint`] account's owner, but storing it here avoids an indirection",
-              "on the client side."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "mode",
-            "docs": [
-              "The mode that this program is running in. This is used to determine",
-              "whether the program is burning tokens or locking tokens."
-            ],
-            "type": {
-              "defined": "Mode"
-            }
-          },
-          {
-            "name": "chainId",
-            "docs": [
-              "The chain id of the chain that this program is running on. We don't",
-              "hardcode this so that the program is deployable on any potential SVM",
-              "forks."
-            ],
-            "type": {
-              "defined": "ChainId"
-            }
-          },
-          {
-            "name": "nextTransceiverId",
-            "docs": [
-              "The next transceiver id to use when registering an transceiver."
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "threshold",
-            "docs": [
-              "The number of transceivers that must attest to a transfer before it is",
-              "accepted."
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "enabledTransceivers",
-            "docs": [
-              "Bitmap of enabled transceivers.",
-              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
-            ],
-            "type": {
-              "defined": "Bitmap"
-            }
-          },
-          {
-            "name": "paused",
-            "docs": [
-              "Pause the program. This is useful for upgrades and other maintenance."
-            ],
-            "type": "bool"
-          },
-          {
-            "name": "custody",
-            "docs": [
-              "The custody account that holds tokens in locking mode."
-            ],
-            "type": "publicKey"
-          }
-        ]
+      "name": "getEnabledTransceivers",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "vec": "publicKey"
       }
     },
     {
-      "name": "LUT",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "address",
-            "type": "publicKey"
-          }
-        ]
+      "name": "getInboxItemStatus",
+      "accounts": [
+        {
+          "name": "inboxItem",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The inbound rate limit of the chain the transfer was received from.",
+            "Inbox items that predate [`InboxItem::from_chain`] don't record the",
+            "chain, so their status can't be looked up."
+          ]
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "InboxItemStatus"
       }
     },
     {
-      "name": "nttManagerPeer",
-      "docs": [
-        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      "name": "getPeerDecimals",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "address",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "tokenDecimals",
-            "type": "u8"
-          }
-        ]
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ],
+      "returns": {
+        "defined": "PeerDecimals"
       }
     },
     {
-      "name": "pendingTokenAuthority",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "pendingAuthority",
-            "type": "publicKey"
-          },
-          {
-            "name": "rentPayer",
-            "type": "publicKey"
-          }
-        ]
+      "name": "getPeerIndex",
+      "accounts": [
+        {
+          "name": "peerIndex",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "checked in [`get_peer_index`]."
+          ]
+        }
+      ],
+      "args": [],
+      "returns": {
+        "vec": "u16"
       }
     },
     {
-      "name": "inboxItem",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "init",
-            "type": "bool"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "amount",
-            "type": "u64"
-          },
-          {
-            "name": "recipientAddress",
-            "type": "publicKey"
-          },
-          {
-            "name": "votes",
-            "type": {
-              "defined": "Bitmap"
-            }
-          },
-          {
-            "name": "releaseStatus",
-            "type": {
-              "defined": "ReleaseStatus"
-            }
-          }
-        ]
+      "name": "getConfiguredChains",
+      "accounts": [
+        {
+          "name": "peerIndex",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "vec": "u16"
       }
     },
     {
-      "name": "inboxRateLimit",
-      "docs": [
-        "Inbound rate limit per chain.",
-        "SECURITY: must check the PDA (since there are multiple PDAs, namely one for each chain.)"
+      "name": "getRateLimitParams",
+      "accounts": [
+        {
+          "name": "inboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "rateLimit",
-            "type": {
-              "defined": "RateLimitState"
-            }
-          }
-        ]
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ],
+      "returns": {
+        "defined": "RateLimits"
       }
     },
     {
-      "name": "outboxItem",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "amount",
-            "type": {
-              "defined": "TrimmedAmount"
-            }
-          },
-          {
-            "name": "sender",
-            "type": "publicKey"
-          },
-          {
-            "name": "recipientChain",
-            "type": {
-              "defined": "ChainId"
-            }
-          },
-          {
-            "name": "recipientNttManager",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "recipientAddress",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "releaseTimestamp",
-            "type": "i64"
-          },
-          {
-            "name": "released",
-            "type": {
-              "defined": "Bitmap"
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "outboxRateLimit",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "rateLimit",
-            "type": {
-              "defined": "RateLimitState"
-            }
-          }
-        ]
-      }
+      "name": "getOutboundCapacity",
+      "accounts": [
+        {
+          "name": "outboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "u64"
     },
     {
-      "name": "registeredTransceiver",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "id",
-            "type": "u8"
-          },
-          {
-            "name": "transceiverAddress",
-            "type": "publicKey"
-          }
-        ]
-      }
+      "name": "getInboundCapacity",
+      "accounts": [
+        {
+          "name": "inboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ],
+      "returns": "u64"
     },
     {
-      "name": "transceiverPeer",
-      "docs": [
-        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      "name": "initPeerStats",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "address",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          }
-        ]
-      }
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
     },
     {
-      "name": "bridgeData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "guardianSetIndex",
-            "docs": [
-              "The current guardian set index, used to decide which signature sets to accept."
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "lastLamports",
-            "docs": [
-              "Lamports in the collection account"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "config",
-            "docs": [
-              "Bridge configuration, which is set once upon initialization."
-            ],
-            "type": {
-              "defined": "BridgeConfig"
-            }
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "Bitmap",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "map",
-            "type": "u128"
-          }
-        ]
+      "name": "getStats",
+      "accounts": [
+        {
+          "name": "peerStats",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ],
+      "returns": {
+        "defined": "LatencyStats"
       }
     },
     {
-      "name": "SetInboundLimitArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "limit",
-            "type": "u64"
-          },
-          {
-            "name": "chainId",
-            "type": {
-              "defined": "ChainId"
-            }
-          }
-        ]
+      "name": "getTransferStatus",
+      "accounts": [
+        {
+          "name": "outboxItem",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "it released the transfer. It doesn't exist until then, which is",
+            "checked in [`get_transfer_status`]."
+          ]
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "TransferStatus"
       }
     },
     {
-      "name": "SetOutboundLimitArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "limit",
-            "type": "u64"
-          }
-        ]
+      "name": "wouldQueue",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The peer the transfer would be sent to. Its decimals determine how much",
+            "of the amount is dust."
+          ]
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": "WouldQueueResult"
       }
     },
     {
-      "name": "SetPeerArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "chainId",
-            "type": {
-              "defined": "ChainId"
-            }
-          },
-          {
-            "name": "address",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "limit",
-            "type": "u64"
-          },
-          {
-            "name": "tokenDecimals",
-            "docs": [
-              "The token decimals on the peer chain."
-            ],
-            "type": "u8"
+      "name": "previewTransfer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "from",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token account the transfer would be sent from."
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "chainOutboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "senderRateLimit",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "limited, which is checked in [`preview_transfer`]."
+          ]
+        },
+        {
+          "name": "wormholeBridge",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
           }
-        ]
+        }
+      ],
+      "returns": {
+        "defined": "TransferPreview"
       }
     },
     {
-      "name": "InitializeArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "chainId",
-            "type": "u16"
-          },
-          {
-            "name": "limit",
-            "type": "u64"
-          },
-          {
-            "name": "mode",
-            "type": {
-              "defined": "Mode"
-            }
-          }
-        ]
-      }
+      "name": "transferOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "RedeemArgs",
-      "type": {
-        "kind": "struct",
-        "fields": []
-      }
+      "name": "transferOwnershipOneStepUnchecked",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "ReleaseInboundArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "revertWhenNotReady",
-            "type": "bool"
-          }
-        ]
-      }
+      "name": "claimOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "TransferArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "amount",
-            "type": "u64"
-          },
-          {
-            "name": "recipientChain",
-            "type": {
-              "defined": "ChainId"
-            }
-          },
-          {
-            "name": "recipientAddress",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "shouldQueue",
-            "type": "bool"
-          }
-        ]
+      "name": "getPendingOwner",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "option": "publicKey"
       }
     },
     {
-      "name": "ReleaseStatus",
-      "docs": [
-        "The status of an InboxItem. This determines whether the tokens are minted/unlocked to the recipient. As",
-        "such, this must be used as a state machine that moves forward in a linear manner. A state",
-        "should never \"move backward\" to a previous state (e.g. should never move from `Released` to",
-        "`ReleaseAfter`)."
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "NotApproved"
-          },
-          {
-            "name": "ReleaseAfter",
-            "fields": [
-              "i64"
-            ]
-          },
-          {
-            "name": "Released"
-          }
-        ]
-      }
+      "name": "acceptTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "currentAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
     },
     {
-      "name": "RateLimitState",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "limit",
-            "docs": [
-              "The maximum capacity of the rate limiter."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "capacityAtLastTx",
-            "docs": [
-              "The capacity of the rate limiter at `last_tx_timestamp`.",
-              "The actual current capacity is calculated in `capacity_at`, by",
-              "accounting for the time that has passed since `last_tx_timestamp` and",
-              "the refill rate."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "lastTxTimestamp",
-            "docs": [
-              "The timestamp of the last transaction that counted towards the current",
-              "capacity. Transactions that exceeded the capacity do not count, they are",
-              "just delayed."
-            ],
-            "type": "i64"
-          }
-        ]
-      }
+      "name": "acceptTokenAuthorityFromMultisig",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "currentMultisigAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "SetTransceiverPeerArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "chainId",
-            "type": {
-              "defined": "ChainId"
-            }
-          },
-          {
-            "name": "address",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
+      "name": "setTokenAuthorityOneStepUnchecked",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "owner",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "newAuthority",
+              "isMut": false,
+              "isSigner": false
             }
-          }
-        ]
-      }
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "BroadcastPeerArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "chainId",
-            "type": "u16"
-          }
-        ]
-      }
+      "name": "setTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "owner",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "newAuthority",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingTokenAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "ReleaseOutboundArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "revertOnDelay",
-            "type": "bool"
-          }
-        ]
-      }
-    },
-    {
-      "name": "ChainId",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "id",
-            "type": "u16"
-          }
-        ]
-      }
+      "name": "revertTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
     },
     {
-      "name": "Mode",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Locking"
-          },
-          {
-            "name": "Burning"
-          }
-        ]
-      }
+      "name": "claimTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
     },
     {
-      "name": "TrimmedAmount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "amount",
-            "type": "u64"
-          },
-          {
-            "name": "decimals",
-            "type": "u8"
-          }
-        ]
-      }
+      "name": "claimTokenAuthorityToMultisig",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "newMultisigAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "BridgeConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "guardianSetExpirationTime",
-            "docs": [
-              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
-              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
-              "this period we still trust the old guardian set."
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "fee",
-            "docs": [
-              "Amount of lamports that needs to be paid to the protocol to post a message"
-            ],
-            "type": "u64"
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "CantReleaseYet",
-      "msg": "CantReleaseYet"
+      "name": "setPaused",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "pause",
+          "type": "bool"
+        }
+      ]
     },
     {
-      "code": 6001,
-      "name": "InvalidPendingOwner",
-      "msg": "InvalidPendingOwner"
-    },
-    {
-      "code": 6002,
-      "name": "InvalidChainId",
-      "msg": "InvalidChainId"
-    },
-    {
-      "code": 6003,
-      "name": "InvalidRecipientAddress",
-      "msg": "InvalidRecipientAddress"
-    },
-    {
-      "code": 6004,
-      "name": "InvalidTransceiverPeer",
-      "msg": "InvalidTransceiverPeer"
-    },
-    {
-      "code": 6005,
-      "name": "InvalidNttManagerPeer",
-      "msg": "InvalidNttManagerPeer"
-    },
-    {
-      "code": 6006,
-      "name": "InvalidRecipientNttManager",
-      "msg": "InvalidRecipientNttManager"
-    },
-    {
-      "code": 6007,
-      "name": "TransferAlreadyRedeemed",
-      "msg": "TransferAlreadyRedeemed"
-    },
-    {
-      "code": 6008,
-      "name": "TransferCannotBeRedeemed",
-      "msg": "TransferCannotBeRedeemed"
-    },
-    {
-      "code": 6009,
-      "name": "TransferNotApproved",
-      "msg": "TransferNotApproved"
-    },
-    {
-      "code": 6010,
-      "name": "MessageAlreadySent",
-      "msg": "MessageAlreadySent"
-    },
-    {
-      "code": 6011,
-      "name": "InvalidMode",
-      "msg": "InvalidMode"
-    },
-    {
-      "code": 6012,
-      "name": "InvalidMintAuthority",
-      "msg": "InvalidMintAuthority"
-    },
-    {
-      "code": 6013,
-      "name": "TransferExceedsRateLimit",
-      "msg": "TransferExceedsRateLimit"
-    },
-    {
-      "code": 6014,
-      "name": "Paused",
-      "msg": "Paused"
-    },
-    {
-      "code": 6015,
-      "name": "DisabledTransceiver",
-      "msg": "DisabledTransceiver"
-    },
-    {
-      "code": 6016,
-      "name": "InvalidDeployer",
-      "msg": "InvalidDeployer"
-    },
-    {
-      "code": 6017,
-      "name": "BadAmountAfterTransfer",
-      "msg": "BadAmountAfterTransfer"
-    },
-    {
-      "code": 6018,
-      "name": "BadAmountAfterBurn",
-      "msg": "BadAmountAfterBurn"
-    },
-    {
-      "code": 6019,
-      "name": "ZeroThreshold",
-      "msg": "ZeroThreshold"
-    },
-    {
-      "code": 6020,
-      "name": "OverflowExponent",
-      "msg": "OverflowExponent"
-    },
-    {
-      "code": 6021,
-      "name": "OverflowScaledAmount",
-      "msg": "OverflowScaledAmount"
-    },
-    {
-      "code": 6022,
-      "name": "BitmapIndexOutOfBounds",
-      "msg": "BitmapIndexOutOfBounds"
-    },
-    {
-      "code": 6023,
-      "name": "NoRegisteredTransceivers",
-      "msg": "NoRegisteredTransceivers"
-    },
-    {
-      "code": 6024,
-      "name": "NotPaused",
-      "msg": "NotPaused"
-    },
-    {
-      "code": 6025,
-      "name": "InvalidPendingTokenAuthority",
-      "msg": "InvalidPendingTokenAuthority"
-    },
-    {
-      "code": 6026,
-      "name": "IncorrectRentPayer",
-      "msg": "IncorrectRentPayer"
-    },
-    {
-      "code": 6027,
-      "name": "InvalidMultisig",
-      "msg": "InvalidMultisig"
-    },
-    {
-      "code": 6028,
-      "name": "ThresholdTooHigh",
-      "msg": "ThresholdTooHigh"
-    },
-    {
-      "code": 6029,
-      "name": "InvalidTransceiverProgram",
-      "msg": "InvalidTransceiverProgram"
-    }
-  ]
-}
-export const IDL: ExampleNativeTokenTransfers = {
-  "version": "3.0.0",
-  "name": "example_native_token_transfers",
-  "instructions": [
-    {
-      "name": "initialize",
+      "name": "setOutboundPaused",
       "accounts": [
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "deployer",
+          "name": "owner",
           "isMut": false,
           "isSigner": true
         },
-        {
-          "name": "programData",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "config",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "mint",
+          "name": "pause",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setInboundPaused",
+      "accounts": [
+        {
+          "name": "owner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "rateLimit",
+          "name": "config",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "pause",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setPeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "tokenAuthority",
+          "name": "owner",
           "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "In any case, this function is used to set the Config and initialize the program so we",
-            "assume the caller of this function will have total control over the program.",
-            "",
-            "TODO: Using `UncheckedAccount` here leads to \"Access violation in stack frame ...\".",
-            "Could refactor code to use `Box<_>` to reduce stack size."
-          ]
+          "isSigner": true
         },
         {
-          "name": "multisigTokenAuthority",
+          "name": "config",
           "isMut": false,
-          "isSigner": false,
-          "isOptional": true
+          "isSigner": false
         },
         {
-          "name": "custody",
+          "name": "peer",
           "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "The custody account that holds tokens in locking mode and temporarily",
-            "holds tokens in burning mode.",
-            "function if the token account has already been created."
-          ]
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "associated token account for the given mint."
-          ]
+          "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
+          "name": "inboxRateLimit",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bpfLoaderUpgradeableProgram",
-          "isMut": false,
+          "name": "peerIndex",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -2581,13 +2760,13 @@ export const IDL: ExampleNativeTokenTransfers = {
         {
           "name": "args",
           "type": {
-            "defined": "InitializeArgs"
+            "defined": "SetPeerArgs"
           }
         }
       ]
     },
     {
-      "name": "initializeLut",
+      "name": "setPeerWithOptions",
       "accounts": [
         {
           "name": "payer",
@@ -2600,525 +2779,273 @@ export const IDL: ExampleNativeTokenTransfers = {
           "isSigner": true
         },
         {
-          "name": "authority",
+          "name": "config",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "lutAddress",
+          "name": "peer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lut",
+          "name": "inboxRateLimit",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lutProgram",
-          "isMut": false,
+          "name": "peerIndex",
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerArgs"
+          }
         },
         {
-          "name": "entries",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "custody",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "mint",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "outboxRateLimit",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "wormhole",
-              "accounts": [
-                {
-                  "name": "bridge",
-                  "isMut": true,
-                  "isSigner": false
-                },
-                {
-                  "name": "feeCollector",
-                  "isMut": true,
-                  "isSigner": false
-                },
-                {
-                  "name": "sequence",
-                  "isMut": true,
-                  "isSigner": false
-                },
-                {
-                  "name": "program",
-                  "isMut": false,
-                  "isSigner": false
-                },
-                {
-                  "name": "systemProgram",
-                  "isMut": false,
-                  "isSigner": false
-                },
-                {
-                  "name": "clock",
-                  "isMut": false,
-                  "isSigner": false
-                },
-                {
-                  "name": "rent",
-                  "isMut": false,
-                  "isSigner": false
-                }
-              ]
-            }
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "recentSlot",
-          "type": "u64"
+          "name": "options",
+          "type": {
+            "defined": "SetPeerOptions"
+          }
         }
       ]
     },
     {
-      "name": "version",
-      "accounts": [],
-      "args": [],
-      "returns": "string"
-    },
-    {
-      "name": "transferBurn",
+      "name": "deletePeer",
       "accounts": [
         {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "payer",
-              "isMut": true,
-              "isSigner": true
-            },
-            {
-              "name": "config",
-              "accounts": [
-                {
-                  "name": "config",
-                  "isMut": false,
-                  "isSigner": false
-                }
-              ]
-            },
-            {
-              "name": "mint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "from",
-              "isMut": true,
-              "isSigner": false,
-              "docs": [
-                "account can spend these tokens."
-              ]
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "outboxItem",
-              "isMut": true,
-              "isSigner": true
-            },
-            {
-              "name": "outboxRateLimit",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "custody",
-              "isMut": true,
-              "isSigner": false,
-              "docs": [
-                "Tokens are always transferred to the custody account first regardless of",
-                "the mode.",
-                "For an explanation, see the note in [`transfer_burn`]."
-              ]
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "inboxRateLimit",
-          "isMut": true,
+          "name": "config",
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "peer",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sessionAuthority",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
-          ]
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "tokenAuthority",
-          "isMut": false,
+          "name": "peerIndex",
+          "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "args",
-          "type": {
-            "defined": "TransferArgs"
-          }
+          "name": "chainId",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "transferLock",
+      "name": "registerTransceiver",
       "accounts": [
         {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "payer",
-              "isMut": true,
-              "isSigner": true
-            },
-            {
-              "name": "config",
-              "accounts": [
-                {
-                  "name": "config",
-                  "isMut": false,
-                  "isSigner": false
-                }
-              ]
-            },
-            {
-              "name": "mint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "from",
-              "isMut": true,
-              "isSigner": false,
-              "docs": [
-                "account can spend these tokens."
-              ]
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "outboxItem",
-              "isMut": true,
-              "isSigner": true
-            },
-            {
-              "name": "outboxRateLimit",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "custody",
-              "isMut": true,
-              "isSigner": false,
-              "docs": [
-                "Tokens are always transferred to the custody account first regardless of",
-                "the mode.",
-                "For an explanation, see the note in [`transfer_burn`]."
-              ]
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
-        },
-        {
-          "name": "inboxRateLimit",
+          "name": "config",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "peer",
+          "name": "owner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "sessionAuthority",
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transceiver",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+            "used here that wraps the Transceiver account type."
           ]
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "args",
-          "type": {
-            "defined": "TransferArgs"
-          }
+          "name": "registeredTransceiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "redeem",
+      "name": "deregisterTransceiver",
       "accounts": [
         {
-          "name": "payer",
+          "name": "config",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "config",
+          "name": "owner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "peer",
+          "name": "registeredTransceiver",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "swapTransceivers",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "transceiverMessage",
+          "name": "owner",
           "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "`Account<T>` and `owner` constraints are mutually-exclusive"
-          ]
+          "isSigner": true
         },
         {
-          "name": "transceiver",
-          "isMut": false,
-          "isSigner": false
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "mint",
+          "name": "oldRegisteredTransceiver",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "inboxItem",
-          "isMut": true,
+          "name": "newTransceiver",
+          "isMut": false,
           "isSigner": false,
           "docs": [
-            "NOTE: This account is content-addressed (PDA seeded by the message hash).",
-            "This is because in a multi-transceiver configuration, the different",
-            "transceivers \"vote\" on messages (by delivering them). By making the inbox",
-            "items content-addressed, we can ensure that disagreeing votes don't",
-            "interfere with each other.",
-            "On the first call to [`redeem()`], [`InboxItem`] will be allocated and initialized with",
-            "default values.",
-            "On subsequent calls, we want to modify the `InboxItem` by \"voting\" on it. Therefore the",
-            "program should not fail which would occur when using the `init` constraint.",
-            "The [`InboxItem::init`] field is used to guard against malicious or accidental modification",
-            "InboxItem fields that should remain constant."
+            "[`RegisterTransceiver::transceiver`])."
           ]
         },
         {
-          "name": "inboxRateLimit",
+          "name": "newRegisteredTransceiver",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "outboxRateLimit",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setLimitsManager",
+      "accounts": [
         {
-          "name": "systemProgram",
+          "name": "owner",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "args",
+          "name": "limitsManager",
           "type": {
-            "defined": "RedeemArgs"
+            "option": "publicKey"
           }
         }
       ]
     },
     {
-      "name": "releaseInboundMint",
+      "name": "setOutboundLimit",
       "accounts": [
         {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "payer",
-              "isMut": true,
-              "isSigner": true
-            },
-            {
-              "name": "config",
-              "accounts": [
-                {
-                  "name": "config",
-                  "isMut": false,
-                  "isSigner": false
-                }
-              ]
-            },
-            {
-              "name": "inboxItem",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "recipient",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "mint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "custody",
-              "isMut": true,
-              "isSigner": false
-            }
-          ]
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "multisigTokenAuthority",
+          "name": "authority",
           "isMut": false,
-          "isSigner": false,
-          "isOptional": true
+          "isSigner": true,
+          "docs": [
+            "The owner or the limits manager."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
         }
       ],
       "args": [
         {
           "name": "args",
           "type": {
-            "defined": "ReleaseInboundArgs"
+            "defined": "SetOutboundLimitArgs"
           }
         }
       ]
     },
     {
-      "name": "releaseInboundUnlock",
+      "name": "setInboundLimit",
       "accounts": [
         {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "payer",
-              "isMut": true,
-              "isSigner": true
-            },
-            {
-              "name": "config",
-              "accounts": [
-                {
-                  "name": "config",
-                  "isMut": false,
-                  "isSigner": false
-                }
-              ]
-            },
-            {
-              "name": "inboxItem",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "recipient",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "mint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "custody",
-              "isMut": true,
-              "isSigner": false
-            }
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner or the limits manager."
           ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
         }
       ],
       "args": [
         {
           "name": "args",
           "type": {
-            "defined": "ReleaseInboundArgs"
+            "defined": "SetInboundLimitArgs"
           }
         }
       ]
     },
     {
-      "name": "transferOwnership",
+      "name": "setSenderLimit",
       "accounts": [
         {
           "name": "config",
@@ -3126,35 +3053,66 @@ export const IDL: ExampleNativeTokenTransfers = {
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "authority",
           "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner or the limits manager."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "limit",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setSenderLimitOverride",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "newOwner",
-          "isMut": false,
+          "name": "config",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "upgradeLock",
+          "name": "authority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true,
+          "docs": [
+            "The owner or the limits manager."
+          ]
         },
         {
-          "name": "programData",
+          "name": "senderRateLimit",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bpfLoaderUpgradeableProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetSenderLimitOverrideArgs"
+          }
+        }
+      ]
     },
     {
-      "name": "transferOwnershipOneStepUnchecked",
+      "name": "setPerChainOutboundLimits",
       "accounts": [
         {
           "name": "config",
@@ -3162,425 +3120,257 @@ export const IDL: ExampleNativeTokenTransfers = {
           "isSigner": false
         },
         {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "newOwner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "upgradeLock",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "programData",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bpfLoaderUpgradeableProgram",
+          "name": "authority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true,
+          "docs": [
+            "The owner or the limits manager."
+          ]
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
     },
     {
-      "name": "claimOwnership",
+      "name": "setChainOutboundLimit",
       "accounts": [
         {
-          "name": "config",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "upgradeLock",
+          "name": "config",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newOwner",
+          "name": "authority",
           "isMut": false,
-          "isSigner": true
+          "isSigner": true,
+          "docs": [
+            "The owner or the limits manager."
+          ]
         },
         {
-          "name": "programData",
+          "name": "rateLimit",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bpfLoaderUpgradeableProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetChainOutboundLimitArgs"
+          }
+        }
+      ]
     },
     {
-      "name": "acceptTokenAuthority",
+      "name": "markOutboxItemAsReleased",
       "accounts": [
         {
-          "name": "common",
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
           "accounts": [
             {
               "name": "config",
               "isMut": false,
               "isSigner": false
-            },
-            {
-              "name": "mint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "multisigTokenAuthority",
-              "isMut": false,
-              "isSigner": false,
-              "isOptional": true
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
             }
           ]
         },
         {
-          "name": "currentAuthority",
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         }
       ],
-      "args": []
+      "args": [],
+      "returns": "bool"
     },
     {
-      "name": "acceptTokenAuthorityFromMultisig",
+      "name": "setThreshold",
       "accounts": [
         {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "mint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "multisigTokenAuthority",
-              "isMut": false,
-              "isSigner": false,
-              "isOptional": true
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "currentMultisigAuthority",
-          "isMut": false,
+          "name": "config",
+          "isMut": true,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "setTokenAuthorityOneStepUnchecked",
+      "name": "enableRoute",
       "accounts": [
         {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "owner",
-              "isMut": false,
-              "isSigner": true
-            },
-            {
-              "name": "mint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "multisigTokenAuthority",
-              "isMut": false,
-              "isSigner": false,
-              "isOptional": true
-            },
-            {
-              "name": "newAuthority",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "tokenProgram",
+          "name": "config",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
     },
     {
-      "name": "setTokenAuthority",
+      "name": "disableRoute",
       "accounts": [
         {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "owner",
-              "isMut": false,
-              "isSigner": true
-            },
-            {
-              "name": "mint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "multisigTokenAuthority",
-              "isMut": false,
-              "isSigner": false,
-              "isOptional": true
-            },
-            {
-              "name": "newAuthority",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
-        },
-        {
-          "name": "rentPayer",
-          "isMut": true,
+          "name": "owner",
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "pendingTokenAuthority",
-          "isMut": true,
+          "name": "config",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "peer",
+          "isMut": true,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
     },
     {
-      "name": "revertTokenAuthority",
+      "name": "setRoutesDefaultEnabled",
       "accounts": [
-        {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "mint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "multisigTokenAuthority",
-              "isMut": false,
-              "isSigner": false,
-              "isOptional": true
-            },
-            {
-              "name": "rentPayer",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "pendingTokenAuthority",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
-        },
         {
           "name": "owner",
           "isMut": false,
           "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
     },
     {
-      "name": "claimTokenAuthority",
+      "name": "setMaxOutboundInFlight",
       "accounts": [
         {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "mint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "multisigTokenAuthority",
-              "isMut": false,
-              "isSigner": false,
-              "isOptional": true
-            },
-            {
-              "name": "rentPayer",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "pendingTokenAuthority",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "newAuthority",
+          "name": "config",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        },
+        {
+          "name": "max",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
     },
     {
-      "name": "claimTokenAuthorityToMultisig",
+      "name": "setAllowQueue",
       "accounts": [
         {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "mint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "multisigTokenAuthority",
-              "isMut": false,
-              "isSigner": false,
-              "isOptional": true
-            },
-            {
-              "name": "rentPayer",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "pendingTokenAuthority",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "newMultisigAuthority",
+          "name": "config",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        },
+        {
+          "name": "allowQueue",
+          "type": "bool"
+        }
+      ]
     },
     {
-      "name": "setPaused",
+      "name": "setAllowInboundQueue",
       "accounts": [
         {
           "name": "owner",
@@ -3589,25 +3379,29 @@ export const IDL: ExampleNativeTokenTransfers = {
         },
         {
           "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
           "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "pause",
+          "name": "chainId",
+          "type": "u16"
+        },
+        {
+          "name": "allowInboundQueue",
           "type": "bool"
         }
       ]
     },
     {
-      "name": "setPeer",
+      "name": "setDestinationFeeBps",
       "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
         {
           "name": "owner",
           "isMut": false,
@@ -3622,57 +3416,366 @@ export const IDL: ExampleNativeTokenTransfers = {
           "name": "peer",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
         },
         {
-          "name": "inboxRateLimit",
+          "name": "feeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setFallbackRecipient",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "fallbackRecipient",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The token account to deliver to, or `None` to remove the fallback."
+          ]
         }
       ],
       "args": [
         {
-          "name": "args",
-          "type": {
-            "defined": "SetPeerArgs"
-          }
+          "name": "chainId",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "registerTransceiver",
+      "name": "setReceiveHook",
       "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
         {
           "name": "config",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
+        {
+          "name": "receiveHook",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setDustAccount",
+      "accounts": [
         {
           "name": "owner",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "payer",
+          "name": "config",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "transceiver",
+          "name": "dustAccount",
           "isMut": false,
           "isSigner": false,
+          "isOptional": true,
           "docs": [
-            "used here that wraps the Transceiver account type."
+            "The account to collect dust into. When omitted, dust collection is",
+            "turned off."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setMaxVaaAge",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxVaaAge",
+          "type": {
+            "option": "u32"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRejectUnconsumedPayload",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "reject",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setAllowedConsistencyLevels",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "consistencyLevels",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "setFeeCollector",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "feeCollector",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "beginMintMigration",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingMintMigration",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Created if needed, so that a migration that hasn't been completed can",
+            "be restarted with a different mint."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "completeMintMigration",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingMintMigration",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newCustody",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "time, same as in [`crate::instructions::initialize`]."
+          ]
+        },
+        {
+          "name": "newTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateConfig",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "be in the legacy layout."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The config's mint. Its decimals (and whether it has confidential",
+            "transfers) are cached in the config."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateOutboxItem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "in the legacy layout."
           ]
-        },
-        {
-          "name": "registeredTransceiver",
-          "isMut": true,
-          "isSigner": false
         },
         {
           "name": "systemProgram",
@@ -3683,459 +3786,8850 @@ export const IDL: ExampleNativeTokenTransfers = {
       "args": []
     },
     {
-      "name": "deregisterTransceiver",
-      "accounts": [
-        {
-          "name": "config",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "registeredTransceiver",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
+      "name": "migrateInboxItem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "in the legacy layout."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "legacy layout."
+          ]
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "purgeInboxItem",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The peer the transfer came from. Only needed when the transfer has",
+            "been approved, to decrement its [`NttManagerPeer::inbound_in_flight`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PurgeInboxItemArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "purgeInboxItemV2",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "owner",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "peer",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "The peer the transfer came from. Only needed when the transfer has",
+                "been approved, to decrement its [`NttManagerPeer::inbound_in_flight`]."
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PurgeInboxItemArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "forceMarkReleased",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ForceMarkReleasedArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "beginDecommission",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "gracePeriodSeconds",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "finalizeDecommission",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerIndex",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setWormholePeerWithOptions",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        },
+        {
+          "name": "options",
+          "type": {
+            "defined": "SetTransceiverPeerOptions"
+          }
+        }
+      ]
+    },
+    {
+      "name": "deleteWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "getWormholePeerIndex",
+      "accounts": [
+        {
+          "name": "peerIndex",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "checked in [`get_transceiver_peer_index`]."
+          ]
+        }
+      ],
+      "args": [],
+      "returns": {
+        "vec": "u16"
+      }
+    },
+    {
+      "name": "migrateWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "still in the legacy layout."
+          ]
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managerPeer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The manager peer is also checked in [`crate::instructions::redeem`],",
+            "but checking it here already rejects messages from the wrong manager",
+            "before they're stored."
+          ]
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "been received (see [`ValidatedTransceiverMessage::is_initialized`])."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseWormholeOutbound",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The registration of this program. Its id is the bit marked in the",
+            "outbox item's `released` bitmap."
+          ]
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseWormholeOutboundWithOptions",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The registration of this program. Its id is the bit marked in the",
+            "outbox item's `released` bitmap."
+          ]
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        },
+        {
+          "name": "options",
+          "type": {
+            "defined": "ReleaseOutboundOptions"
+          }
+        }
+      ]
+    },
+    {
+      "name": "broadcastWormholeId",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "enforced by the [`CpiContext`] call in [`post_message`].",
+            "The seeds constraint ensures that this is the correct address"
+          ]
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        },
+        {
+          "name": "tokenMetadata",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "in [`token_metadata_hash`]. It's optional, as not every token has",
+            "Metaplex metadata."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "docs": [
+              "Pending next owner (before claiming ownership)."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the token managed by this program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "Address of the token program (token or token22). This could always be queried",
+              "from the [`mint`] account's owner, but storing it here avoids an indirection",
+              "on the client side."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "The mode that this program is running in. This is used to determine",
+              "whether the program is burning tokens or locking tokens."
+            ],
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The chain id of the chain that this program is running on. We don't",
+              "hardcode this so that the program is deployable on any potential SVM",
+              "forks."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "docs": [
+              "The next transceiver id to use when registering an transceiver."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of transceivers that must attest to a transfer before it is",
+              "accepted."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "docs": [
+              "Bitmap of enabled transceivers.",
+              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "pausedOutbound",
+            "docs": [
+              "Pause outbound transfers (sending and releasing them). This is useful",
+              "for upgrades and other maintenance, or to halt outflows during an",
+              "incident without stranding inbound transfers (see [`paused_inbound`])."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "The custody account that holds tokens in locking mode."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "routesDefaultEnabled",
+            "docs": [
+              "Whether routes to newly registered peers are enabled straight away.",
+              "When false, the owner has to enable each route explicitly (via",
+              "`enable_route`) before any transfers to or from that chain are accepted."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "mintDecimals",
+            "docs": [
+              "Decimals of [`mint`], cached here so that inbound amounts can be",
+              "untrimmed without loading the mint account."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "receiveHook",
+            "docs": [
+              "Program notified whenever a transceiver receives a message (see",
+              "[`crate::receive_hook`])."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "feeCollector",
+            "docs": [
+              "Wormhole core bridge fee collector that message fees are paid into.",
+              "Defaults to the account derived from the core bridge program, and can",
+              "be updated (via `set_fee_collector`) if the core bridge changes it."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "confidentialTransfers",
+            "docs": [
+              "Whether [`mint`] has the Token-2022 confidential transfer extension, in",
+              "which case inbound transfers are only released to recipients that",
+              "accept non-confidential credits (see [`crate::confidential_transfer`])."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "dustAccount",
+            "docs": [
+              "Token account that dust trimmed off outbound transfers is collected",
+              "into (set via `set_dust_account`). When unset, the dust is left in the",
+              "sender's account."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "limitsManager",
+            "docs": [
+              "Account that can tune rate limits on behalf of the owner (see",
+              "[`Role::LimitsManager`]). Set and revoked via `set_limits_manager`."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "maxVaaAge",
+            "docs": [
+              "How old (in seconds) a VAA may be when it's received, measured from",
+              "the timestamp in its body. Older VAAs are rejected by the transceivers.",
+              "Set via `set_max_vaa_age`, unlimited when unset."
+            ],
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "rejectUnconsumedPayload",
+            "docs": [
+              "What happens to messages whose additional payload this program doesn't",
+              "decode (see [`crate::transfer::Payload`]) when there's no recipient",
+              "callback to pass it on to. When false, the payload is dropped and the",
+              "transfer goes through. When true, redeeming the message fails. Set via",
+              "`set_reject_unconsumed_payload`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "decommissionTimestamp",
+            "docs": [
+              "When the deployment is being decommissioned (see",
+              "`begin_decommission`), the time from which `finalize_decommission`",
+              "can be called. Outbound transfers are rejected in the meantime, while",
+              "inbound ones can still be redeemed and released."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "terminated",
+            "docs": [
+              "Whether the deployment has been decommissioned for good (see",
+              "`finalize_decommission`)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowedConsistencyLevels",
+            "docs": [
+              "Consistency levels outbound transfers may be released with, as a",
+              "bitmap indexed by the level's VAA encoding (see",
+              "[`CONSISTENCY_LEVEL_FINALIZED`] and [`CONSISTENCY_LEVEL_CONFIRMED`]).",
+              "Only finalized by default. Set via `set_allowed_consistency_levels`."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "pausedInbound",
+            "docs": [
+              "Pause inbound transfers (receiving, redeeming and releasing them).",
+              "Set together with [`paused_outbound`] via `set_paused`, or on its own",
+              "via `set_inbound_paused`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "senderLimit",
+            "docs": [
+              "How much a single sender may transfer out per day (see",
+              "[`crate::queue::outbox::SenderRateLimit`]), unless it has an override.",
+              "Set via `set_sender_limit`, unlimited when unset."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "perChainOutboundLimits",
+            "docs": [
+              "Whether transfers to a chain with its own",
+              "[`crate::queue::outbox::ChainOutboxRateLimit`] consume from it rather",
+              "than the global [`crate::queue::outbox::OutboxRateLimit`]. Set via",
+              "`set_per_chain_outbound_limits`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "senderLimitOverrides",
+            "docs": [
+              "Whether an override has been set for any sender with",
+              "`set_sender_limit_override`, which limits that sender even while",
+              "[`Self::sender_limit`] is unset. Never cleared, see",
+              "[`Self::limits_senders`]."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "tracksCustody",
+            "docs": [
+              "Whether the tokens locked in custody are tracked in the",
+              "[`crate::custody_ledger::CustodyLedger`], which is created along with",
+              "the deployment. Deployments migrated from before the ledger existed",
+              "don't have one, so transfers don't require it there."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LUT",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "nttManagerPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "enabled",
+            "docs": [
+              "Whether transfers to and from this chain are accepted."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "outboundInFlight",
+            "docs": [
+              "Number of outbound transfers to this chain that have not been released",
+              "by any transceiver yet."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxOutboundInFlight",
+            "docs": [
+              "Cap on [`Self::outbound_in_flight`]. `None` means no cap."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "allowQueue",
+            "docs": [
+              "Whether outbound transfers to this chain may be queued when they exceed",
+              "the rate limit. When false, such transfers fail even if the sender asked",
+              "for them to be queued."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "destinationFeeBps",
+            "docs": [
+              "The execution fee the peer deducts from delivered transfers, in basis",
+              "points. Informational only: nothing is deducted here, but transfers",
+              "with a `min_amount_out` are checked against it."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "fallbackRecipient",
+            "docs": [
+              "Token account that inbound transfers from this chain are delivered to",
+              "when the recipient's token account is frozen. `None` means such",
+              "transfers can't be released until it is thawed."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "inboundInFlight",
+            "docs": [
+              "Number of inbound transfers from this chain that have been approved,",
+              "but not released yet."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "allowInboundQueue",
+            "docs": [
+              "Whether inbound transfers from this chain that exceed the inbound rate",
+              "limit are queued (released once the capacity allows). When false, they",
+              "are rejected, and can only be redeemed again once there is capacity."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingTokenAuthority",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "releaseTimestamp",
+            "docs": [
+              "Only meaningful when the status is [`ReleaseStatus::ReleaseAfter`],",
+              "see [`InboxItem::release_status`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "recipientAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "votes",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "releaseStatus",
+            "type": "u8"
+          },
+          {
+            "name": "fromChain",
+            "docs": [
+              "The chain the transfer came from, or 0 for items redeemed before it",
+              "was recorded."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "vaaTimestamp",
+            "docs": [
+              "The timestamp of the VAA the transfer was first redeemed with, or 0",
+              "for items redeemed before it was recorded. Used for the delivery",
+              "latency in [`crate::peer_stats::PeerStats`]."
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxRateLimit",
+      "docs": [
+        "Inbound rate limit per chain.",
+        "SECURITY: must check the PDA (since there are multiple PDAs, namely one for each chain.)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "docs": [
+              "The trimmed amount, see [`OutboxItem::trimmed_amount`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "recipientChain",
+            "type": "u16"
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "The decimals of the trimmed amount."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "cancelled",
+            "docs": [
+              "Whether the sender cancelled the transfer, see",
+              "[`OutboxItem::is_cancelled`]. Taken out of the padding, so existing",
+              "items read as not cancelled."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "consumedRateLimits",
+            "docs": [
+              "Which outbound rate limits the transfer consumed from, see",
+              "[`OutboxItem::consumed_outbound_limit`]. Taken out of the padding, so",
+              "existing items read as having consumed none."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                3
+              ]
+            }
+          },
+          {
+            "name": "releasedBy",
+            "docs": [
+              "The ids of the transceivers that released the transfer, in the order",
+              "they did, alongside [`OutboxItem::released_at`]."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "releasedAt",
+            "docs": [
+              "When the transceivers in [`OutboxItem::released_by`] released the",
+              "transfer, or 0 for unused entries. [`OutboxItem::released`] stays the",
+              "source of truth for whether a transceiver has, see",
+              "[`OutboxItem::released_at`]."
+            ],
+            "type": {
+              "array": [
+                "i64",
+                8
+              ]
+            }
+          },
+          {
+            "name": "expiryTimestamp",
+            "docs": [
+              "The sender-specified deadline after which the transfer is no longer",
+              "released, or 0 if it has none. See [`OutboxItem::expiry`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "The account that paid the rent of the item, which gets it back if the",
+              "transfer expires (see [`crate::instructions::close_expired_outbox_item`]).",
+              "Zero for items created before it was recorded."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "inboundBackflow",
+            "docs": [
+              "How much sending the transfer refilled the inbound rate limit of the",
+              "recipient chain (the \"backflow\"), which is taken back if it's",
+              "cancelled or expires instead. Zero for queued transfers, and for items",
+              "created before it was recorded."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxRateLimit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "addressMatch",
+            "docs": [
+              "How [`Self::address`] is compared against the emitter of received",
+              "messages."
+            ],
+            "type": {
+              "defined": "PeerAddressMatch"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
+            "type": {
+              "defined": "BridgeConfig"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "custodyLedger",
+      "docs": [
+        "The amount of tokens the program believes are held in custody, so that it",
+        "can be reconciled against the actual balance of the custody account (see",
+        "[`crate::instructions::reconcile_custody`]).",
+        "Singleton PDA, created in [`crate::instructions::initialize`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "lockedAmount",
+            "docs": [
+              "Tokens locked by outbound transfers, minus the tokens unlocked by",
+              "inbound transfers. Only ever non-zero in locking mode."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "chainOutboxRateLimit",
+      "docs": [
+        "Outbound rate limit for transfers to a single chain, used in place of the",
+        "global [`OutboxRateLimit`] when",
+        "[`crate::config::Config::per_chain_outbound_limits`] is set. Chains",
+        "without one fall back to the global limit."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "senderRateLimit",
+      "docs": [
+        "Rate limit on the outbound transfers of a single sender (the owner of the",
+        "token account the tokens are sent from), on top of the global",
+        "[`OutboxRateLimit`]. Created on the sender's first transfer, or when an",
+        "override is set for it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "limitOverride",
+            "docs": [
+              "Replaces [`crate::config::Config::sender_limit`] for this sender when",
+              "set. Set via `set_sender_limit_override`."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "peerStats",
+      "docs": [
+        "Delivery statistics of a peer, for monitoring.",
+        "PDA seeded by the chain id, created permissionlessly with",
+        "[`crate::instructions::init_peer_stats`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "inboundLatency",
+            "docs": [
+              "The time from the VAA timestamp to the release of inbound transfers."
+            ],
+            "type": {
+              "defined": "LatencyStats"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "peerIndex",
+      "docs": [
+        "The chain ids of the configured peers, in the order they were added, so",
+        "that they can be listed without scanning the program's accounts (which many",
+        "RPC providers don't allow). Updated in the same instruction as the peers",
+        "themselves, see [`crate::instructions::set_peer`] and",
+        "[`crate::instructions::delete_peer`].",
+        "",
+        "NOTE: peers set before the index was introduced are only added when they're",
+        "migrated, see [`crate::instructions::migrate_peer`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "chainIds",
+            "type": {
+              "vec": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingMintMigration",
+      "docs": [
+        "A mint migration started by",
+        "[`crate::instructions::begin_mint_migration`], waiting for the custody to",
+        "be wound down before [`crate::instructions::complete_mint_migration`]",
+        "retargets the program to [`PendingMintMigration::mint`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint the program is migrated to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "The token program that owns [`PendingMintMigration::mint`]."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeerIndex",
+      "docs": [
+        "The chain ids of the configured [`TransceiverPeer`]s, see",
+        "[`crate::peer::PeerIndex`].",
+        "",
+        "NOTE: peers set before the index was introduced are only added when they're",
+        "migrated, see [`crate::transceivers::wormhole::instructions::migrate_transceiver_peer`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "chainIds",
+            "type": {
+              "vec": "u16"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bitmap",
+      "docs": [
+        "Stored as little-endian bytes rather than a `u128` so that it can be embedded",
+        "in zero-copy accounts without imposing an alignment requirement. The borsh",
+        "encoding is the same either way."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "map",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetInboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOutboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "tokenDecimals",
+            "docs": [
+              "The token decimals on the peer chain."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": "Mode"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RedeemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "ReleaseInboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertWhenNotReady",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "shouldQueue",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseStatus",
+      "docs": [
+        "The status of an InboxItem. This determines whether the tokens are minted/unlocked to the recipient. As",
+        "such, this must be used as a state machine that moves forward in a linear manner. A state",
+        "should never \"move backward\" to a previous state (e.g. should never move from `Released` to",
+        "`ReleaseAfter`)."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NotApproved"
+          },
+          {
+            "name": "ReleaseAfter",
+            "fields": [
+              "i64"
+            ]
+          },
+          {
+            "name": "Released"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "docs": [
+              "The maximum capacity of the rate limiter."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "capacityAtLastTx",
+            "docs": [
+              "The capacity of the rate limiter at `last_tx_timestamp`.",
+              "The actual current capacity is calculated in `capacity_at`, by",
+              "accounting for the time that has passed since `last_tx_timestamp` and",
+              "the refill rate."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastTxTimestamp",
+            "docs": [
+              "The timestamp of the last transaction that counted towards the current",
+              "capacity. Transactions that exceeded the capacity do not count, they are",
+              "just delayed."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BroadcastPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChainId",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Locking"
+          },
+          {
+            "name": "Burning"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TrimmedAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitialPeer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "docs": [
+              "The NTT manager on the peer chain."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "docs": [
+              "The token decimals on the peer chain."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "inboundLimit",
+            "type": "u64"
+          },
+          {
+            "name": "transceiverAddress",
+            "docs": [
+              "The peer of the integrated Wormhole transceiver, see",
+              "[`crate::transceivers::wormhole::set_transceiver_peer`]. Standalone",
+              "transceivers have to be set up separately."
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "allowUnknownChain",
+            "docs": [
+              "Skip the check that `chain_id` is a known Wormhole chain id."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowNonstandardAddress",
+            "docs": [
+              "Skip the check that `address` is well-formed for the chain, see",
+              "[`check_peer_address`]."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferOptions",
+      "docs": [
+        "Optional conditions on a transfer, taken by [`transfer_burn_with_options`]",
+        "and [`transfer_lock_with_options`]. They're not part of [`TransferArgs`], so",
+        "that its encoding stays the same for existing clients."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minAmountOut",
+            "docs": [
+              "The least the recipient must receive on the destination chain, after",
+              "the peer's [`NttManagerPeer::destination_fee_bps`] is deducted. This is",
+              "in the trimmed decimals of the transfer (see",
+              "[`crate::instructions::get_peer_decimals`])."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "expiryTimestamp",
+            "docs": [
+              "The time after which the transfer is discarded rather than released",
+              "(see [`OutboxItem::expiry_timestamp`])."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LatencyStats",
+      "docs": [
+        "A running aggregate of latencies, in seconds."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "count",
+            "docs": [
+              "The number of samples in [`Self::sum_seconds`] and [`Self::max_seconds`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sumSeconds",
+            "type": "u64"
+          },
+          {
+            "name": "maxSeconds",
+            "type": "u64"
+          },
+          {
+            "name": "outliers",
+            "docs": [
+              "The number of samples longer than [`Self::MAX_SAMPLE_SECONDS`]. These",
+              "are counted separately, so that a single stale (or misdated) message",
+              "doesn't skew the aggregate."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CustodyReconciliation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "custodyBalance",
+            "docs": [
+              "The token balance of the custody account."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lockedAmount",
+            "docs": [
+              "The amount the program believes is locked in custody, as tracked in",
+              "[`CustodyLedger`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "matches",
+            "docs": [
+              "Whether the two agree. A mismatch means tokens were transferred into",
+              "(or out of) custody outside of the program's transfers, or a bug."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxItemStatus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "votes",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "releaseStatus",
+            "type": {
+              "defined": "ReleaseStatus"
+            }
+          },
+          {
+            "name": "rateLimited",
+            "docs": [
+              "Whether the transfer is held back by the inbound rate limit.",
+              "",
+              "* For transfers that have not been approved yet, this is whether the",
+              "current inbound capacity is insufficient to release them immediately.",
+              "* For approved transfers, this is whether the release timestamp is",
+              "still in the future."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PeerDecimals",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mintDecimals",
+            "docs": [
+              "The decimals of the local mint."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "peerTokenDecimals",
+            "docs": [
+              "The decimals of the peer's token, as recorded in [`NttManagerPeer`]."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "trimmedDecimals",
+            "docs": [
+              "The decimals amounts are trimmed to in transfers to the peer."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimits",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "inbound",
+            "docs": [
+              "The inbound rate limit of the given chain."
+            ],
+            "type": {
+              "defined": "RateLimitParams"
+            }
+          },
+          {
+            "name": "outbound",
+            "docs": [
+              "The outbound rate limit, which is shared by all chains."
+            ],
+            "type": {
+              "defined": "RateLimitParams"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "docs": [
+              "See [`RateLimitState::limit`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "capacityAtLastTx",
+            "docs": [
+              "See [`RateLimitState::capacity_at_last_tx`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastTxTimestamp",
+            "docs": [
+              "See [`RateLimitState::last_tx_timestamp`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "capacity",
+            "docs": [
+              "The capacity right now, i.e. [`Self::capacity_at_last_tx`] plus what",
+              "has been refilled since."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "refillDuration",
+            "docs": [
+              "How many seconds it takes to refill from empty to [`Self::limit`]."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferStatus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "recipientChain",
+            "type": "u16"
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "rateLimited",
+            "docs": [
+              "Whether the transfer is held back by the outbound rate limit, i.e.",
+              "whether the release timestamp is still in the future."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "released",
+            "docs": [
+              "The transceivers that have released the transfer, by id."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "wormholeSequence",
+            "docs": [
+              "The sequence of the message the integrated Wormhole transceiver posted,",
+              "if it has released the transfer. Always `None` for standalone",
+              "transceivers, whose messages aren't tied to the outbox item."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "WouldQueueResult",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wouldQueue",
+            "docs": [
+              "Whether a transfer of the given amount would exceed the current",
+              "outbound capacity, and thus be queued (or rejected, if queueing is not",
+              "requested)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "releaseDelay",
+            "docs": [
+              "How many seconds the transfer would be queued for, if it would be."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferPreview",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "docs": [
+              "The amount taken from the sender, i.e. the requested amount without the",
+              "dust that can't be represented on the peer."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "dust",
+            "docs": [
+              "The dust left in the sender's account, or collected into the dust",
+              "account (see [`Config::dust_account`])."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "trimmedAmount",
+            "docs": [
+              "The amount carried by the transfer message."
+            ],
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "expectedAmountOut",
+            "docs": [
+              "What the recipient is expected to receive after the peer's destination",
+              "fee, see [`NttManagerPeer::expected_amount_out`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "peerDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "routeEnabled",
+            "docs": [
+              "Whether transfers to the peer are enabled at all."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "rateLimited",
+            "docs": [
+              "Whether the rate limits would hold the transfer back."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "exceedsSenderLimit",
+            "docs": [
+              "Whether the transfer would be held back by the sender's own limit (see",
+              "[`Config::sender_limit`]) rather than the outbound one."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "queueAllowed",
+            "docs": [
+              "Whether a rate limited transfer would be queued rather than rejected,",
+              "which takes both the sender asking for it and the peer allowing it."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "releaseTimestamp",
+            "docs": [
+              "When the transfer could be released by the transceivers."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "wormholeFee",
+            "docs": [
+              "The core bridge fee the Wormhole transceiver pays to release the",
+              "transfer."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "outboxItemRent",
+            "docs": [
+              "The rent of the outbox item, refunded once it's closed."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "senderRateLimitRent",
+            "docs": [
+              "The rent of the sender's rate limit account, if the transfer would",
+              "create it."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerOptions",
+      "docs": [
+        "Checks of [`set_peer`] that can be skipped, through",
+        "[`set_peer_with_options`]. They're not part of [`SetPeerArgs`], so that",
+        "its encoding stays the same for existing clients."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowUnknownChain",
+            "docs": [
+              "Skip the check that `chain_id` is a known Wormhole chain id.",
+              "Only needed for chains added to Wormhole after this program was built."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowNonstandardAddress",
+            "docs": [
+              "Skip the check that `address` is well-formed for the chain, see",
+              "[`check_peer_address`]."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetSenderLimitOverrideArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "limit",
+            "docs": [
+              "Falls back to [`Config::sender_limit`] when `None`."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetChainOutboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PurgeInboxItemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nttManagerMessage",
+            "type": {
+              "defined": "NttManagerMessage<NativeTokenTransfer<Payload>>"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ForceMarkReleasedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "transceiverId",
+            "type": "u8"
+          },
+          {
+            "name": "confirmOutboxItem",
+            "docs": [
+              "Must be the address of the outbox item."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PeerAddressMatch",
+      "docs": [
+        "How strictly a transceiver peer address is compared against the emitter",
+        "address of received messages."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Exact"
+          },
+          {
+            "name": "Normalized"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerOptions",
+      "docs": [
+        "Settings of [`set_transceiver_peer_with_options`] that",
+        "[`set_transceiver_peer`] leaves at their defaults. They're not part of",
+        "[`SetTransceiverPeerArgs`], so that its encoding stays the same for",
+        "existing clients."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowUnknownChain",
+            "docs": [
+              "Skip the check that `chain_id` is a known Wormhole chain id.",
+              "Only needed for chains added to Wormhole after this program was built."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "addressMatch",
+            "docs": [
+              "How `address` is compared against the emitter of received messages."
+            ],
+            "type": {
+              "defined": "PeerAddressMatch"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundOptions",
+      "docs": [
+        "Settings of [`release_outbound_with_options`] that [`release_outbound`]",
+        "leaves at their defaults. They're not part of [`ReleaseOutboundArgs`], so",
+        "that its encoding stays the same for existing clients."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxWormholeFee",
+            "docs": [
+              "The most the payer is willing to pay for the wormhole message fee.",
+              "Guards against the core bridge fee being raised between building and",
+              "landing the transaction. Unlimited when unset."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReceiveHookArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fromChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "id",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "sourceNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "sender",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "to",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "InboxItemPurged",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "recipientAddress",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "releaseStatus",
+          "type": {
+            "defined": "ReleaseStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReceiveHookNotified",
+      "fields": [
+        {
+          "name": "receiveHook",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transceiverMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReceiveHookArgs"
+          },
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "CantReleaseYet",
+      "msg": "CantReleaseYet"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidPendingOwner",
+      "msg": "InvalidPendingOwner"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidChainId",
+      "msg": "InvalidChainId"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidRecipientAddress",
+      "msg": "InvalidRecipientAddress"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidTransceiverPeer",
+      "msg": "InvalidTransceiverPeer"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidNttManagerPeer",
+      "msg": "InvalidNttManagerPeer"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidRecipientNttManager",
+      "msg": "InvalidRecipientNttManager"
+    },
+    {
+      "code": 6007,
+      "name": "TransferAlreadyRedeemed",
+      "msg": "TransferAlreadyRedeemed"
+    },
+    {
+      "code": 6008,
+      "name": "TransferCannotBeRedeemed",
+      "msg": "TransferCannotBeRedeemed"
+    },
+    {
+      "code": 6009,
+      "name": "TransferNotApproved",
+      "msg": "TransferNotApproved"
+    },
+    {
+      "code": 6010,
+      "name": "MessageAlreadySent",
+      "msg": "MessageAlreadySent"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidMode",
+      "msg": "InvalidMode"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidMintAuthority",
+      "msg": "InvalidMintAuthority"
+    },
+    {
+      "code": 6013,
+      "name": "TransferExceedsRateLimit",
+      "msg": "TransferExceedsRateLimit"
+    },
+    {
+      "code": 6014,
+      "name": "Paused",
+      "msg": "Paused"
+    },
+    {
+      "code": 6015,
+      "name": "DisabledTransceiver",
+      "msg": "DisabledTransceiver"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidDeployer",
+      "msg": "InvalidDeployer"
+    },
+    {
+      "code": 6017,
+      "name": "BadAmountAfterTransfer",
+      "msg": "BadAmountAfterTransfer"
+    },
+    {
+      "code": 6018,
+      "name": "BadAmountAfterBurn",
+      "msg": "BadAmountAfterBurn"
+    },
+    {
+      "code": 6019,
+      "name": "ZeroThreshold",
+      "msg": "ZeroThreshold"
+    },
+    {
+      "code": 6020,
+      "name": "OverflowExponent",
+      "msg": "OverflowExponent"
+    },
+    {
+      "code": 6021,
+      "name": "OverflowScaledAmount",
+      "msg": "OverflowScaledAmount"
+    },
+    {
+      "code": 6022,
+      "name": "BitmapIndexOutOfBounds",
+      "msg": "BitmapIndexOutOfBounds"
+    },
+    {
+      "code": 6023,
+      "name": "NoRegisteredTransceivers",
+      "msg": "NoRegisteredTransceivers"
+    },
+    {
+      "code": 6024,
+      "name": "NotPaused",
+      "msg": "NotPaused"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidPendingTokenAuthority",
+      "msg": "InvalidPendingTokenAuthority"
+    },
+    {
+      "code": 6026,
+      "name": "IncorrectRentPayer",
+      "msg": "IncorrectRentPayer"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidMultisig",
+      "msg": "InvalidMultisig"
+    },
+    {
+      "code": 6028,
+      "name": "ThresholdTooHigh",
+      "msg": "ThresholdTooHigh"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidTransceiverProgram",
+      "msg": "InvalidTransceiverProgram"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidAddressEncoding",
+      "msg": "InvalidAddressEncoding"
+    },
+    {
+      "code": 6031,
+      "name": "RouteDisabled",
+      "msg": "RouteDisabled"
+    },
+    {
+      "code": 6032,
+      "name": "TokenProgramMismatch",
+      "msg": "TokenProgramMismatch"
+    },
+    {
+      "code": 6033,
+      "name": "UnknownChainId",
+      "msg": "UnknownChainId"
+    },
+    {
+      "code": 6034,
+      "name": "SourceMintMismatch",
+      "msg": "SourceMintMismatch"
+    },
+    {
+      "code": 6035,
+      "name": "RecipientMintMismatch",
+      "msg": "RecipientMintMismatch"
+    },
+    {
+      "code": 6036,
+      "name": "UnsupportedPayloadVersion",
+      "msg": "UnsupportedPayloadVersion"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidFeeCollector",
+      "msg": "InvalidFeeCollector"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidGuardianSignaturesAccount",
+      "msg": "InvalidGuardianSignaturesAccount"
+    },
+    {
+      "code": 6039,
+      "name": "ConfidentialTransfersUnsupported",
+      "msg": "ConfidentialTransfersUnsupported"
+    },
+    {
+      "code": 6040,
+      "name": "TooManyOutboundInFlight",
+      "msg": "TooManyOutboundInFlight"
+    },
+    {
+      "code": 6041,
+      "name": "DigestMismatch",
+      "msg": "DigestMismatch"
+    },
+    {
+      "code": 6042,
+      "name": "HeartbeatTooSoon",
+      "msg": "HeartbeatTooSoon"
+    },
+    {
+      "code": 6043,
+      "name": "ForceReleaseNotConfirmed",
+      "msg": "ForceReleaseNotConfirmed"
+    },
+    {
+      "code": 6044,
+      "name": "UnregisteredTransceiver",
+      "msg": "UnregisteredTransceiver"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidDustAccount",
+      "msg": "InvalidDustAccount"
+    },
+    {
+      "code": 6046,
+      "name": "Unauthorized",
+      "msg": "Unauthorized"
+    },
+    {
+      "code": 6047,
+      "name": "InsufficientRentForMessage",
+      "msg": "InsufficientRentForMessage"
+    },
+    {
+      "code": 6048,
+      "name": "QueueItemNotMigrated",
+      "msg": "QueueItemNotMigrated"
+    },
+    {
+      "code": 6049,
+      "name": "QueueItemAlreadyMigrated",
+      "msg": "QueueItemAlreadyMigrated"
+    },
+    {
+      "code": 6050,
+      "name": "WormholeFeeTooHigh",
+      "msg": "WormholeFeeTooHigh"
+    },
+    {
+      "code": 6051,
+      "name": "MalformedMessage",
+      "msg": "MalformedMessage"
+    },
+    {
+      "code": 6052,
+      "name": "SlippageExceeded",
+      "msg": "SlippageExceeded"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidDestinationFeeBps",
+      "msg": "InvalidDestinationFeeBps"
+    },
+    {
+      "code": 6054,
+      "name": "TransceiverNotRegistered",
+      "msg": "TransceiverNotRegistered"
+    },
+    {
+      "code": 6055,
+      "name": "BroadcastSuppressed",
+      "msg": "BroadcastSuppressed"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidFallbackRecipient",
+      "msg": "InvalidFallbackRecipient"
+    },
+    {
+      "code": 6057,
+      "name": "TooManyInitialPeers",
+      "msg": "TooManyInitialPeers"
+    },
+    {
+      "code": 6058,
+      "name": "MintAuthorityRotatedAway",
+      "msg": "MintAuthorityRotatedAway"
+    },
+    {
+      "code": 6059,
+      "name": "VaaTooOld",
+      "msg": "VaaTooOld"
+    },
+    {
+      "code": 6060,
+      "name": "InvalidClock",
+      "msg": "InvalidClock"
+    },
+    {
+      "code": 6061,
+      "name": "PeerIndexFull",
+      "msg": "PeerIndexFull"
+    },
+    {
+      "code": 6062,
+      "name": "PeerHasOutboundInFlight",
+      "msg": "PeerHasOutboundInFlight"
+    },
+    {
+      "code": 6063,
+      "name": "RateLimitNotInitialized",
+      "msg": "RateLimitNotInitialized"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidPeerAddress",
+      "msg": "InvalidPeerAddress"
+    },
+    {
+      "code": 6065,
+      "name": "TooManyEnabledTransceivers",
+      "msg": "TooManyEnabledTransceivers"
+    },
+    {
+      "code": 6066,
+      "name": "UnconsumedPayload",
+      "msg": "UnconsumedPayload"
+    },
+    {
+      "code": 6067,
+      "name": "MintDecimalsMismatch",
+      "msg": "MintDecimalsMismatch"
+    },
+    {
+      "code": 6068,
+      "name": "CustodyNotEmpty",
+      "msg": "CustodyNotEmpty"
+    },
+    {
+      "code": 6069,
+      "name": "TransferExpired",
+      "msg": "TransferExpired"
+    },
+    {
+      "code": 6070,
+      "name": "InvalidExpiryTimestamp",
+      "msg": "InvalidExpiryTimestamp"
+    },
+    {
+      "code": 6071,
+      "name": "TransferNotExpired",
+      "msg": "TransferNotExpired"
+    },
+    {
+      "code": 6072,
+      "name": "InboxItemNotReleasable",
+      "msg": "InboxItemNotReleasable"
+    },
+    {
+      "code": 6073,
+      "name": "DeploymentDecommissioning",
+      "msg": "DeploymentDecommissioning"
+    },
+    {
+      "code": 6074,
+      "name": "DeploymentTerminated",
+      "msg": "DeploymentTerminated"
+    },
+    {
+      "code": 6075,
+      "name": "NotDecommissioning",
+      "msg": "NotDecommissioning"
+    },
+    {
+      "code": 6076,
+      "name": "DecommissionGracePeriodActive",
+      "msg": "DecommissionGracePeriodActive"
+    },
+    {
+      "code": 6077,
+      "name": "PeersRemaining",
+      "msg": "PeersRemaining"
+    },
+    {
+      "code": 6078,
+      "name": "PeerHasInboundInFlight",
+      "msg": "PeerHasInboundInFlight"
+    },
+    {
+      "code": 6079,
+      "name": "TransferAlreadyCancelled",
+      "msg": "TransferAlreadyCancelled"
+    },
+    {
+      "code": 6080,
+      "name": "ConsistencyLevelNotAllowed",
+      "msg": "ConsistencyLevelNotAllowed"
+    },
+    {
+      "code": 6081,
+      "name": "UnsupportedConsistencyLevel",
+      "msg": "UnsupportedConsistencyLevel"
+    },
+    {
+      "code": 6082,
+      "name": "VaaBodyNotExpired",
+      "msg": "VaaBodyNotExpired"
+    },
+    {
+      "code": 6083,
+      "name": "VaaTimestampInFuture",
+      "msg": "VaaTimestampInFuture"
+    },
+    {
+      "code": 6084,
+      "name": "TransferExceedsSenderRateLimit",
+      "msg": "TransferExceedsSenderRateLimit"
+    },
+    {
+      "code": 6085,
+      "name": "PeerAlreadyMigrated",
+      "msg": "PeerAlreadyMigrated"
+    }
+  ]
+}
+export const IDL: ExampleNativeTokenTransfers = {
+  "version": "3.0.0",
+  "name": "example_native_token_transfers",
+  "instructions": [
+    {
+      "name": "initialize",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "deployer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "In any case, this function is used to set the Config and initialize the program so we",
+            "assume the caller of this function will have total control over the program.",
+            "",
+            "TODO: Using `UncheckedAccount` here leads to \"Access violation in stack frame ...\".",
+            "Could refactor code to use `Box<_>` to reduce stack size."
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The custody account that holds tokens in locking mode and temporarily",
+            "holds tokens in burning mode.",
+            "function if the token account has already been created."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "associated token account for the given mint."
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeWithPeers",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "deployer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "In any case, this function is used to set the Config and initialize the program so we",
+            "assume the caller of this function will have total control over the program.",
+            "",
+            "TODO: Using `UncheckedAccount` here leads to \"Access violation in stack frame ...\".",
+            "Could refactor code to use `Box<_>` to reduce stack size."
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The custody account that holds tokens in locking mode and temporarily",
+            "holds tokens in burning mode.",
+            "function if the token account has already been created."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "associated token account for the given mint."
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeArgs"
+          }
+        },
+        {
+          "name": "peers",
+          "type": {
+            "vec": {
+              "defined": "InitialPeer"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "preflightInitialize",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "mode",
+          "type": {
+            "defined": "Mode"
+          }
+        }
+      ],
+      "returns": "u32"
+    },
+    {
+      "name": "initializeLut",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lutAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lut",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lutProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "entries",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormhole",
+              "accounts": [
+                {
+                  "name": "bridge",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "feeCollector",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "sequence",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "program",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "systemProgram",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "clock",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "rent",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "feePayer",
+                  "isMut": true,
+                  "isSigner": true,
+                  "isOptional": true,
+                  "docs": [
+                    "Pays the core bridge fee if given, so that the payer of the instruction",
+                    "only covers rent."
+                  ]
+                }
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "recentSlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "version",
+      "accounts": [],
+      "args": [],
+      "returns": "string"
+    },
+    {
+      "name": "transferBurn",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "a missing rate limit fails with a specific error rather than Anchor's",
+                "generic one."
+              ]
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "in the handler, see [`session_authority_bump`].",
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the mint authority is a multisig of the token authority.",
+            "Burning doesn't need the mint authority, but tokens burned while it",
+            "has been rotated away could not be minted back when they return."
+          ]
+        },
+        {
+          "name": "dustAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when dust collection is turned on (see [`Config::dust_account`])."
+          ]
+        },
+        {
+          "name": "senderRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "[`Config::limits_senders`]), in which case it's created on the sender's",
+            "first transfer. See [`insert_into_outbox`]."
+          ]
+        },
+        {
+          "name": "chainOutboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "required once per-chain limits are turned on (see",
+            "[`Config::per_chain_outbound_limits`]), and it only exists for chains",
+            "that have been given their own limit."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "a missing rate limit fails with a specific error rather than Anchor's",
+                "generic one."
+              ]
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "in the handler, see [`session_authority_bump`].",
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "dustAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when dust collection is turned on (see [`Config::dust_account`])."
+          ]
+        },
+        {
+          "name": "senderRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "[`Config::limits_senders`]), in which case it's created on the sender's",
+            "first transfer. See [`insert_into_outbox`]."
+          ]
+        },
+        {
+          "name": "chainOutboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "required once per-chain limits are turned on (see",
+            "[`Config::per_chain_outbound_limits`]), and it only exists for chains",
+            "that have been given their own limit."
+          ]
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required unless the deployment doesn't track custody, see",
+            "[`Config::tracks_custody`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferBurnWithOptions",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "a missing rate limit fails with a specific error rather than Anchor's",
+                "generic one."
+              ]
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "in the handler, see [`session_authority_bump`].",
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the mint authority is a multisig of the token authority.",
+            "Burning doesn't need the mint authority, but tokens burned while it",
+            "has been rotated away could not be minted back when they return."
+          ]
+        },
+        {
+          "name": "dustAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when dust collection is turned on (see [`Config::dust_account`])."
+          ]
+        },
+        {
+          "name": "senderRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "[`Config::limits_senders`]), in which case it's created on the sender's",
+            "first transfer. See [`insert_into_outbox`]."
+          ]
+        },
+        {
+          "name": "chainOutboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "required once per-chain limits are turned on (see",
+            "[`Config::per_chain_outbound_limits`]), and it only exists for chains",
+            "that have been given their own limit."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        },
+        {
+          "name": "options",
+          "type": {
+            "defined": "TransferOptions"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLockWithOptions",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "a missing rate limit fails with a specific error rather than Anchor's",
+                "generic one."
+              ]
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "in the handler, see [`session_authority_bump`].",
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "dustAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when dust collection is turned on (see [`Config::dust_account`])."
+          ]
+        },
+        {
+          "name": "senderRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "[`Config::limits_senders`]), in which case it's created on the sender's",
+            "first transfer. See [`insert_into_outbox`]."
+          ]
+        },
+        {
+          "name": "chainOutboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "required once per-chain limits are turned on (see",
+            "[`Config::per_chain_outbound_limits`]), and it only exists for chains",
+            "that have been given their own limit."
+          ]
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required unless the deployment doesn't track custody, see",
+            "[`Config::tracks_custody`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        },
+        {
+          "name": "options",
+          "type": {
+            "defined": "TransferOptions"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferBurnWithExpiry",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "a missing rate limit fails with a specific error rather than Anchor's",
+                "generic one."
+              ]
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "in the handler, see [`session_authority_bump`].",
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the mint authority is a multisig of the token authority.",
+            "Burning doesn't need the mint authority, but tokens burned while it",
+            "has been rotated away could not be minted back when they return."
+          ]
+        },
+        {
+          "name": "dustAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when dust collection is turned on (see [`Config::dust_account`])."
+          ]
+        },
+        {
+          "name": "senderRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "[`Config::limits_senders`]), in which case it's created on the sender's",
+            "first transfer. See [`insert_into_outbox`]."
+          ]
+        },
+        {
+          "name": "chainOutboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "required once per-chain limits are turned on (see",
+            "[`Config::per_chain_outbound_limits`]), and it only exists for chains",
+            "that have been given their own limit."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        },
+        {
+          "name": "expiryTimestamp",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "transferLockWithExpiry",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "a missing rate limit fails with a specific error rather than Anchor's",
+                "generic one."
+              ]
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "in the handler, see [`session_authority_bump`].",
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "dustAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when dust collection is turned on (see [`Config::dust_account`])."
+          ]
+        },
+        {
+          "name": "senderRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "[`Config::limits_senders`]), in which case it's created on the sender's",
+            "first transfer. See [`insert_into_outbox`]."
+          ]
+        },
+        {
+          "name": "chainOutboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "required once per-chain limits are turned on (see",
+            "[`Config::per_chain_outbound_limits`]), and it only exists for chains",
+            "that have been given their own limit."
+          ]
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required unless the deployment doesn't track custody, see",
+            "[`Config::tracks_custody`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        },
+        {
+          "name": "expiryTimestamp",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "cancelOutboundTransfer",
+      "accounts": [
+        {
+          "name": "sender",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Not gated on [`Config::paused_outbound`], so that a pause doesn't keep",
+            "senders from taking back transfers it holds up."
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required unless the outbox item predates [`OutboxItem::rent_payer`]."
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "to",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The sender's token account the tokens are returned to."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed in locking mode."
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed in burning mode, when the mint authority is a multisig."
+          ]
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "chainOutboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed when the transfer consumed from the recipient chain's own",
+            "limit (see [`OutboxItem::consumed_chain_outbound_limit`])."
+          ]
+        },
+        {
+          "name": "senderRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed when the transfer consumed from the sender's limit (see",
+            "[`OutboxItem::consumed_sender_limit`])."
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeExpiredOutboxItem",
+      "accounts": [
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "to",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The sender's token account the tokens are returned to."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed in locking mode."
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed in burning mode, when the mint authority is a multisig."
+          ]
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "chainOutboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed when the transfer consumed from the recipient chain's own",
+            "limit (see [`OutboxItem::consumed_chain_outbound_limit`])."
+          ]
+        },
+        {
+          "name": "senderRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed when the transfer consumed from the sender's limit (see",
+            "[`OutboxItem::consumed_sender_limit`])."
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeInboxItem",
+      "accounts": [
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentRecipient",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redeem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "`Account<T>` and `owner` constraints are mutually-exclusive"
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NOTE: This account is content-addressed (PDA seeded by the message hash).",
+            "This is because in a multi-transceiver configuration, the different",
+            "transceivers \"vote\" on messages (by delivering them). By making the inbox",
+            "items content-addressed, we can ensure that disagreeing votes don't",
+            "interfere with each other.",
+            "On the first call to [`redeem()`], [`InboxItem`] will be allocated and initialized with",
+            "default values.",
+            "On subsequent calls, we want to modify the `InboxItem` by \"voting\" on it. Therefore the",
+            "program should not fail which would occur when using the `init` constraint.",
+            "The account discriminator is only written once the first call exits, so its absence is",
+            "used to guard against malicious or accidental modification InboxItem fields that should",
+            "remain constant.",
+            "NOTE: inbox items in the legacy layout fail the `space` check of `init_if_needed`, and",
+            "have to be converted with [`crate::instructions::migrate_inbox_item`] first."
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RedeemArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundMint",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "peer",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "The peer the transfer came from, whose",
+                "[`NttManagerPeer::inbound_in_flight`] is decremented. Only optional",
+                "for inbox items that predate [`InboxItem::from_chain`]."
+              ]
+            },
+            {
+              "name": "fallbackRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "The peer's [`NttManagerPeer::fallback_recipient`]. The tokens are",
+                "delivered here instead if [`ReleaseInbound::recipient`] is frozen."
+              ]
+            },
+            {
+              "name": "peerStats",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "When passed, the delivery latency of the transfer is recorded in it."
+              ]
+            }
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundUnlock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "peer",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "The peer the transfer came from, whose",
+                "[`NttManagerPeer::inbound_in_flight`] is decremented. Only optional",
+                "for inbox items that predate [`InboxItem::from_chain`]."
+              ]
+            },
+            {
+              "name": "fallbackRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "The peer's [`NttManagerPeer::fallback_recipient`]. The tokens are",
+                "delivered here instead if [`ReleaseInbound::recipient`] is frozen."
+              ]
+            },
+            {
+              "name": "peerStats",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "When passed, the delivery latency of the transfer is recorded in it."
+              ]
+            }
+          ]
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required unless the deployment doesn't track custody, see",
+            "[`Config::tracks_custody`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "repairCustody",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The custody account is the associated token account of the token",
+            "authority, so re-deriving it from the configured mint and token program",
+            "yields exactly [`Config::custody`]. `init_if_needed` makes repairing an",
+            "existing account a no-op."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "reconcileCustody",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "CustodyReconciliation"
+      }
+    },
+    {
+      "name": "getEnabledTransceivers",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "vec": "publicKey"
+      }
+    },
+    {
+      "name": "getInboxItemStatus",
+      "accounts": [
+        {
+          "name": "inboxItem",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The inbound rate limit of the chain the transfer was received from.",
+            "Inbox items that predate [`InboxItem::from_chain`] don't record the",
+            "chain, so their status can't be looked up."
+          ]
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "InboxItemStatus"
+      }
+    },
+    {
+      "name": "getPeerDecimals",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ],
+      "returns": {
+        "defined": "PeerDecimals"
+      }
+    },
+    {
+      "name": "getPeerIndex",
+      "accounts": [
+        {
+          "name": "peerIndex",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "checked in [`get_peer_index`]."
+          ]
+        }
+      ],
+      "args": [],
+      "returns": {
+        "vec": "u16"
+      }
+    },
+    {
+      "name": "getConfiguredChains",
+      "accounts": [
+        {
+          "name": "peerIndex",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "vec": "u16"
+      }
+    },
+    {
+      "name": "getRateLimitParams",
+      "accounts": [
+        {
+          "name": "inboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ],
+      "returns": {
+        "defined": "RateLimits"
+      }
+    },
+    {
+      "name": "getOutboundCapacity",
+      "accounts": [
+        {
+          "name": "outboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "u64"
+    },
+    {
+      "name": "getInboundCapacity",
+      "accounts": [
+        {
+          "name": "inboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "initPeerStats",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "getStats",
+      "accounts": [
+        {
+          "name": "peerStats",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ],
+      "returns": {
+        "defined": "LatencyStats"
+      }
+    },
+    {
+      "name": "getTransferStatus",
+      "accounts": [
+        {
+          "name": "outboxItem",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "it released the transfer. It doesn't exist until then, which is",
+            "checked in [`get_transfer_status`]."
+          ]
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "TransferStatus"
+      }
+    },
+    {
+      "name": "wouldQueue",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The peer the transfer would be sent to. Its decimals determine how much",
+            "of the amount is dust."
+          ]
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": "WouldQueueResult"
+      }
+    },
+    {
+      "name": "previewTransfer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "from",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token account the transfer would be sent from."
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "chainOutboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "senderRateLimit",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "limited, which is checked in [`preview_transfer`]."
+          ]
+        },
+        {
+          "name": "wormholeBridge",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ],
+      "returns": {
+        "defined": "TransferPreview"
+      }
+    },
+    {
+      "name": "transferOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferOwnershipOneStepUnchecked",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "getPendingOwner",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "option": "publicKey"
+      }
+    },
+    {
+      "name": "acceptTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "currentAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptTokenAuthorityFromMultisig",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "currentMultisigAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTokenAuthorityOneStepUnchecked",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "owner",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "newAuthority",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "owner",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "newAuthority",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingTokenAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revertTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimTokenAuthorityToMultisig",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "newMultisigAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPaused",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "pause",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setOutboundPaused",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "pause",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setInboundPaused",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "pause",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setPeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPeerWithOptions",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerArgs"
+          }
+        },
+        {
+          "name": "options",
+          "type": {
+            "defined": "SetPeerOptions"
+          }
+        }
+      ]
+    },
+    {
+      "name": "deletePeer",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "registerTransceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "used here that wraps the Transceiver account type."
+          ]
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "deregisterTransceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "swapTransceivers",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "oldRegisteredTransceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newTransceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "[`RegisterTransceiver::transceiver`])."
+          ]
+        },
+        {
+          "name": "newRegisteredTransceiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setLimitsManager",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "limitsManager",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOutboundLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner or the limits manager."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setInboundLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner or the limits manager."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setSenderLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner or the limits manager."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "limit",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setSenderLimitOverride",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner or the limits manager."
+          ]
+        },
+        {
+          "name": "senderRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetSenderLimitOverrideArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPerChainOutboundLimits",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner or the limits manager."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setChainOutboundLimit",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner or the limits manager."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetChainOutboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "markOutboxItemAsReleased",
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "bool"
+    },
+    {
+      "name": "setThreshold",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "enableRoute",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "disableRoute",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setRoutesDefaultEnabled",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setMaxOutboundInFlight",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        },
+        {
+          "name": "max",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setAllowQueue",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        },
+        {
+          "name": "allowQueue",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setAllowInboundQueue",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        },
+        {
+          "name": "allowInboundQueue",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setDestinationFeeBps",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        },
+        {
+          "name": "feeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setFallbackRecipient",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "fallbackRecipient",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The token account to deliver to, or `None` to remove the fallback."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setReceiveHook",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "receiveHook",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setDustAccount",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "dustAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The account to collect dust into. When omitted, dust collection is",
+            "turned off."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setMaxVaaAge",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxVaaAge",
+          "type": {
+            "option": "u32"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRejectUnconsumedPayload",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "reject",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setAllowedConsistencyLevels",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "consistencyLevels",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "setFeeCollector",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "feeCollector",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "beginMintMigration",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingMintMigration",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Created if needed, so that a migration that hasn't been completed can",
+            "be restarted with a different mint."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "completeMintMigration",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingMintMigration",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newCustody",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "time, same as in [`crate::instructions::initialize`]."
+          ]
+        },
+        {
+          "name": "newTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateConfig",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "be in the legacy layout."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The config's mint. Its decimals (and whether it has confidential",
+            "transfers) are cached in the config."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateOutboxItem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "in the legacy layout."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateInboxItem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "in the legacy layout."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "legacy layout."
+          ]
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "purgeInboxItem",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The peer the transfer came from. Only needed when the transfer has",
+            "been approved, to decrement its [`NttManagerPeer::inbound_in_flight`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PurgeInboxItemArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "purgeInboxItemV2",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "owner",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "peer",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "The peer the transfer came from. Only needed when the transfer has",
+                "been approved, to decrement its [`NttManagerPeer::inbound_in_flight`]."
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PurgeInboxItemArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "forceMarkReleased",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ForceMarkReleasedArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "beginDecommission",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "gracePeriodSeconds",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "finalizeDecommission",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerIndex",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setWormholePeerWithOptions",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        },
+        {
+          "name": "options",
+          "type": {
+            "defined": "SetTransceiverPeerOptions"
+          }
+        }
+      ]
+    },
+    {
+      "name": "deleteWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "getWormholePeerIndex",
+      "accounts": [
+        {
+          "name": "peerIndex",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "checked in [`get_transceiver_peer_index`]."
+          ]
+        }
+      ],
+      "args": [],
+      "returns": {
+        "vec": "u16"
+      }
+    },
+    {
+      "name": "migrateWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "still in the legacy layout."
+          ]
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managerPeer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The manager peer is also checked in [`crate::instructions::redeem`],",
+            "but checking it here already rejects messages from the wrong manager",
+            "before they're stored."
+          ]
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "been received (see [`ValidatedTransceiverMessage::is_initialized`])."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseWormholeOutbound",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The registration of this program. Its id is the bit marked in the",
+            "outbox item's `released` bitmap."
+          ]
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseWormholeOutboundWithOptions",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The registration of this program. Its id is the bit marked in the",
+            "outbox item's `released` bitmap."
+          ]
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        },
+        {
+          "name": "options",
+          "type": {
+            "defined": "ReleaseOutboundOptions"
+          }
+        }
+      ]
+    },
+    {
+      "name": "broadcastWormholeId",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "enforced by the [`CpiContext`] call in [`post_message`].",
+            "The seeds constraint ensures that this is the correct address"
+          ]
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        },
+        {
+          "name": "tokenMetadata",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "in [`token_metadata_hash`]. It's optional, as not every token has",
+            "Metaplex metadata."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "docs": [
+              "Pending next owner (before claiming ownership)."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the token managed by this program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "Address of the token program (token or token22). This could always be queried",
+              "from the [`mint`] account's owner, but storing it here avoids an indirection",
+              "on the client side."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "The mode that this program is running in. This is used to determine",
+              "whether the program is burning tokens or locking tokens."
+            ],
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The chain id of the chain that this program is running on. We don't",
+              "hardcode this so that the program is deployable on any potential SVM",
+              "forks."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "docs": [
+              "The next transceiver id to use when registering an transceiver."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of transceivers that must attest to a transfer before it is",
+              "accepted."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "docs": [
+              "Bitmap of enabled transceivers.",
+              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "pausedOutbound",
+            "docs": [
+              "Pause outbound transfers (sending and releasing them). This is useful",
+              "for upgrades and other maintenance, or to halt outflows during an",
+              "incident without stranding inbound transfers (see [`paused_inbound`])."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "The custody account that holds tokens in locking mode."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "routesDefaultEnabled",
+            "docs": [
+              "Whether routes to newly registered peers are enabled straight away.",
+              "When false, the owner has to enable each route explicitly (via",
+              "`enable_route`) before any transfers to or from that chain are accepted."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "mintDecimals",
+            "docs": [
+              "Decimals of [`mint`], cached here so that inbound amounts can be",
+              "untrimmed without loading the mint account."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "receiveHook",
+            "docs": [
+              "Program notified whenever a transceiver receives a message (see",
+              "[`crate::receive_hook`])."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "feeCollector",
+            "docs": [
+              "Wormhole core bridge fee collector that message fees are paid into.",
+              "Defaults to the account derived from the core bridge program, and can",
+              "be updated (via `set_fee_collector`) if the core bridge changes it."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "confidentialTransfers",
+            "docs": [
+              "Whether [`mint`] has the Token-2022 confidential transfer extension, in",
+              "which case inbound transfers are only released to recipients that",
+              "accept non-confidential credits (see [`crate::confidential_transfer`])."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "dustAccount",
+            "docs": [
+              "Token account that dust trimmed off outbound transfers is collected",
+              "into (set via `set_dust_account`). When unset, the dust is left in the",
+              "sender's account."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "limitsManager",
+            "docs": [
+              "Account that can tune rate limits on behalf of the owner (see",
+              "[`Role::LimitsManager`]). Set and revoked via `set_limits_manager`."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "maxVaaAge",
+            "docs": [
+              "How old (in seconds) a VAA may be when it's received, measured from",
+              "the timestamp in its body. Older VAAs are rejected by the transceivers.",
+              "Set via `set_max_vaa_age`, unlimited when unset."
+            ],
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "rejectUnconsumedPayload",
+            "docs": [
+              "What happens to messages whose additional payload this program doesn't",
+              "decode (see [`crate::transfer::Payload`]) when there's no recipient",
+              "callback to pass it on to. When false, the payload is dropped and the",
+              "transfer goes through. When true, redeeming the message fails. Set via",
+              "`set_reject_unconsumed_payload`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "decommissionTimestamp",
+            "docs": [
+              "When the deployment is being decommissioned (see",
+              "`begin_decommission`), the time from which `finalize_decommission`",
+              "can be called. Outbound transfers are rejected in the meantime, while",
+              "inbound ones can still be redeemed and released."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "terminated",
+            "docs": [
+              "Whether the deployment has been decommissioned for good (see",
+              "`finalize_decommission`)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowedConsistencyLevels",
+            "docs": [
+              "Consistency levels outbound transfers may be released with, as a",
+              "bitmap indexed by the level's VAA encoding (see",
+              "[`CONSISTENCY_LEVEL_FINALIZED`] and [`CONSISTENCY_LEVEL_CONFIRMED`]).",
+              "Only finalized by default. Set via `set_allowed_consistency_levels`."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "pausedInbound",
+            "docs": [
+              "Pause inbound transfers (receiving, redeeming and releasing them).",
+              "Set together with [`paused_outbound`] via `set_paused`, or on its own",
+              "via `set_inbound_paused`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "senderLimit",
+            "docs": [
+              "How much a single sender may transfer out per day (see",
+              "[`crate::queue::outbox::SenderRateLimit`]), unless it has an override.",
+              "Set via `set_sender_limit`, unlimited when unset."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "perChainOutboundLimits",
+            "docs": [
+              "Whether transfers to a chain with its own",
+              "[`crate::queue::outbox::ChainOutboxRateLimit`] consume from it rather",
+              "than the global [`crate::queue::outbox::OutboxRateLimit`]. Set via",
+              "`set_per_chain_outbound_limits`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "senderLimitOverrides",
+            "docs": [
+              "Whether an override has been set for any sender with",
+              "`set_sender_limit_override`, which limits that sender even while",
+              "[`Self::sender_limit`] is unset. Never cleared, see",
+              "[`Self::limits_senders`]."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "tracksCustody",
+            "docs": [
+              "Whether the tokens locked in custody are tracked in the",
+              "[`crate::custody_ledger::CustodyLedger`], which is created along with",
+              "the deployment. Deployments migrated from before the ledger existed",
+              "don't have one, so transfers don't require it there."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LUT",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "nttManagerPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "enabled",
+            "docs": [
+              "Whether transfers to and from this chain are accepted."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "outboundInFlight",
+            "docs": [
+              "Number of outbound transfers to this chain that have not been released",
+              "by any transceiver yet."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxOutboundInFlight",
+            "docs": [
+              "Cap on [`Self::outbound_in_flight`]. `None` means no cap."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "allowQueue",
+            "docs": [
+              "Whether outbound transfers to this chain may be queued when they exceed",
+              "the rate limit. When false, such transfers fail even if the sender asked",
+              "for them to be queued."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "destinationFeeBps",
+            "docs": [
+              "The execution fee the peer deducts from delivered transfers, in basis",
+              "points. Informational only: nothing is deducted here, but transfers",
+              "with a `min_amount_out` are checked against it."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "fallbackRecipient",
+            "docs": [
+              "Token account that inbound transfers from this chain are delivered to",
+              "when the recipient's token account is frozen. `None` means such",
+              "transfers can't be released until it is thawed."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "inboundInFlight",
+            "docs": [
+              "Number of inbound transfers from this chain that have been approved,",
+              "but not released yet."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "allowInboundQueue",
+            "docs": [
+              "Whether inbound transfers from this chain that exceed the inbound rate",
+              "limit are queued (released once the capacity allows). When false, they",
+              "are rejected, and can only be redeemed again once there is capacity."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingTokenAuthority",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          }
+        ]
+      }
     },
     {
-      "name": "setOutboundLimit",
-      "accounts": [
-        {
-          "name": "config",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "rateLimit",
-          "isMut": true,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SetOutboundLimitArgs"
+      "name": "inboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "releaseTimestamp",
+            "docs": [
+              "Only meaningful when the status is [`ReleaseStatus::ReleaseAfter`],",
+              "see [`InboxItem::release_status`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "recipientAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "votes",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "releaseStatus",
+            "type": "u8"
+          },
+          {
+            "name": "fromChain",
+            "docs": [
+              "The chain the transfer came from, or 0 for items redeemed before it",
+              "was recorded."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "vaaTimestamp",
+            "docs": [
+              "The timestamp of the VAA the transfer was first redeemed with, or 0",
+              "for items redeemed before it was recorded. Used for the delivery",
+              "latency in [`crate::peer_stats::PeerStats`]."
+            ],
+            "type": "u32"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "setInboundLimit",
-      "accounts": [
-        {
-          "name": "config",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "rateLimit",
-          "isMut": true,
-          "isSigner": false
-        }
+      "name": "inboxRateLimit",
+      "docs": [
+        "Inbound rate limit per chain.",
+        "SECURITY: must check the PDA (since there are multiple PDAs, namely one for each chain.)"
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SetInboundLimitArgs"
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "docs": [
+              "The trimmed amount, see [`OutboxItem::trimmed_amount`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "recipientChain",
+            "type": "u16"
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "The decimals of the trimmed amount."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "cancelled",
+            "docs": [
+              "Whether the sender cancelled the transfer, see",
+              "[`OutboxItem::is_cancelled`]. Taken out of the padding, so existing",
+              "items read as not cancelled."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "consumedRateLimits",
+            "docs": [
+              "Which outbound rate limits the transfer consumed from, see",
+              "[`OutboxItem::consumed_outbound_limit`]. Taken out of the padding, so",
+              "existing items read as having consumed none."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                3
+              ]
+            }
+          },
+          {
+            "name": "releasedBy",
+            "docs": [
+              "The ids of the transceivers that released the transfer, in the order",
+              "they did, alongside [`OutboxItem::released_at`]."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "releasedAt",
+            "docs": [
+              "When the transceivers in [`OutboxItem::released_by`] released the",
+              "transfer, or 0 for unused entries. [`OutboxItem::released`] stays the",
+              "source of truth for whether a transceiver has, see",
+              "[`OutboxItem::released_at`]."
+            ],
+            "type": {
+              "array": [
+                "i64",
+                8
+              ]
+            }
+          },
+          {
+            "name": "expiryTimestamp",
+            "docs": [
+              "The sender-specified deadline after which the transfer is no longer",
+              "released, or 0 if it has none. See [`OutboxItem::expiry`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "The account that paid the rent of the item, which gets it back if the",
+              "transfer expires (see [`crate::instructions::close_expired_outbox_item`]).",
+              "Zero for items created before it was recorded."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "inboundBackflow",
+            "docs": [
+              "How much sending the transfer refilled the inbound rate limit of the",
+              "recipient chain (the \"backflow\"), which is taken back if it's",
+              "cancelled or expires instead. Zero for queued transfers, and for items",
+              "created before it was recorded."
+            ],
+            "type": "u64"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "markOutboxItemAsReleased",
-      "accounts": [
-        {
-          "name": "signer",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "config",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
+      "name": "outboxRateLimit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
             }
-          ]
-        },
-        {
-          "name": "outboxItem",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "transceiver",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [],
-      "returns": "bool"
-    },
-    {
-      "name": "setThreshold",
-      "accounts": [
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "config",
-          "isMut": true,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "threshold",
-          "type": "u8"
-        }
-      ]
+          }
+        ]
+      }
     },
     {
-      "name": "setWormholePeer",
-      "accounts": [
-        {
-          "name": "config",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "peer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SetTransceiverPeerArgs"
+      "name": "registeredTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "receiveWormholeMessage",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "config",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
-        },
-        {
-          "name": "peer",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "vaa",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "transceiverMessage",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "transceiverPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
       ],
-      "args": []
-    },
-    {
-      "name": "releaseWormholeOutbound",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "config",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
             }
-          ]
-        },
-        {
-          "name": "outboxItem",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "transceiver",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "wormholeMessage",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "emitter",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "wormhole",
-          "accounts": [
-            {
-              "name": "bridge",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "feeCollector",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "sequence",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "program",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "clock",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "rent",
-              "isMut": false,
-              "isSigner": false
+          },
+          {
+            "name": "addressMatch",
+            "docs": [
+              "How [`Self::address`] is compared against the emitter of received",
+              "messages."
+            ],
+            "type": {
+              "defined": "PeerAddressMatch"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
+            "type": {
+              "defined": "BridgeConfig"
             }
-          ]
-        }
+          }
+        ]
+      }
+    },
+    {
+      "name": "custodyLedger",
+      "docs": [
+        "The amount of tokens the program believes are held in custody, so that it",
+        "can be reconciled against the actual balance of the custody account (see",
+        "[`crate::instructions::reconcile_custody`]).",
+        "Singleton PDA, created in [`crate::instructions::initialize`]."
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "ReleaseOutboundArgs"
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "lockedAmount",
+            "docs": [
+              "Tokens locked by outbound transfers, minus the tokens unlocked by",
+              "inbound transfers. Only ever non-zero in locking mode."
+            ],
+            "type": "u64"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "broadcastWormholeId",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "config",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "wormholeMessage",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "emitter",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "enforced by the [`CpiContext`] call in [`post_message`].",
-            "The seeds constraint ensures that this is the correct address"
-          ]
-        },
-        {
-          "name": "wormhole",
-          "accounts": [
-            {
-              "name": "bridge",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "feeCollector",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "sequence",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "program",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "clock",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "rent",
-              "isMut": false,
-              "isSigner": false
+      "name": "chainOutboxRateLimit",
+      "docs": [
+        "Outbound rate limit for transfers to a single chain, used in place of the",
+        "global [`OutboxRateLimit`] when",
+        "[`crate::config::Config::per_chain_outbound_limits`] is set. Chains",
+        "without one fall back to the global limit."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
             }
-          ]
-        }
+          }
+        ]
+      }
+    },
+    {
+      "name": "senderRateLimit",
+      "docs": [
+        "Rate limit on the outbound transfers of a single sender (the owner of the",
+        "token account the tokens are sent from), on top of the global",
+        "[`OutboxRateLimit`]. Created on the sender's first transfer, or when an",
+        "override is set for it."
       ],
-      "args": []
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "limitOverride",
+            "docs": [
+              "Replaces [`crate::config::Config::sender_limit`] for this sender when",
+              "set. Set via `set_sender_limit_override`."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "broadcastWormholePeer",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "config",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "peer",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "wormholeMessage",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "emitter",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "wormhole",
-          "accounts": [
-            {
-              "name": "bridge",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "feeCollector",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "sequence",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "program",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "clock",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "rent",
-              "isMut": false,
-              "isSigner": false
+      "name": "peerStats",
+      "docs": [
+        "Delivery statistics of a peer, for monitoring.",
+        "PDA seeded by the chain id, created permissionlessly with",
+        "[`crate::instructions::init_peer_stats`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "inboundLatency",
+            "docs": [
+              "The time from the VAA timestamp to the release of inbound transfers."
+            ],
+            "type": {
+              "defined": "LatencyStats"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "peerIndex",
+      "docs": [
+        "The chain ids of the configured peers, in the order they were added, so",
+        "that they can be listed without scanning the program's accounts (which many",
+        "RPC providers don't allow). Updated in the same instruction as the peers",
+        "themselves, see [`crate::instructions::set_peer`] and",
+        "[`crate::instructions::delete_peer`].",
+        "",
+        "NOTE: peers set before the index was introduced are only added when they're",
+        "migrated, see [`crate::instructions::migrate_peer`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "chainIds",
+            "type": {
+              "vec": "u16"
             }
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "BroadcastPeerArgs"
           }
-        }
-      ]
-    }
-  ],
-  "accounts": [
+        ]
+      }
+    },
     {
-      "name": "config",
+      "name": "pendingMintMigration",
+      "docs": [
+        "A mint migration started by",
+        "[`crate::instructions::begin_mint_migration`], waiting for the custody to",
+        "be wound down before [`crate::instructions::complete_mint_migration`]",
+        "retargets the program to [`PendingMintMigration::mint`]."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
@@ -4144,245 +12638,400 @@ export const IDL: ExampleNativeTokenTransfers = {
             "type": "u8"
           },
           {
-            "name": "owner",
+            "name": "mint",
             "docs": [
-              "Owner of the program."
+              "The mint the program is migrated to."
             ],
             "type": "publicKey"
           },
           {
-            "name": "pendingOwner",
+            "name": "tokenProgram",
             "docs": [
-              "Pending next owner (before claiming ownership)."
+              "The token program that owns [`PendingMintMigration::mint`]."
             ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeerIndex",
+      "docs": [
+        "The chain ids of the configured [`TransceiverPeer`]s, see",
+        "[`crate::peer::PeerIndex`].",
+        "",
+        "NOTE: peers set before the index was introduced are only added when they're",
+        "migrated, see [`crate::transceivers::wormhole::instructions::migrate_transceiver_peer`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "chainIds",
             "type": {
-              "option": "publicKey"
+              "vec": "u16"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bitmap",
+      "docs": [
+        "Stored as little-endian bytes rather than a `u128` so that it can be embedded",
+        "in zero-copy accounts without imposing an alignment requirement. The borsh",
+        "encoding is the same either way."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "map",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetInboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
           },
           {
-            "name": "mint",
-            "docs": [
-              "Mint address of the token managed by this program."
-            ],
-            "type": "publicKey"
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOutboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
           },
           {
-            "name": "tokenProgram",
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "tokenDecimals",
             "docs": [
-              "Address of the token program (token or token22). This could always be queried",
-              "from the [`mint`] account's owner, but storing it here avoids an indirection",
-              "on the client side."
+              "The token decimals on the peer chain."
             ],
-            "type": "publicKey"
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          },
+          {
+            "name": "limit",
+            "type": "u64"
           },
           {
             "name": "mode",
-            "docs": [
-              "The mode that this program is running in. This is used to determine",
-              "whether the program is burning tokens or locking tokens."
-            ],
             "type": {
               "defined": "Mode"
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RedeemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "ReleaseInboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertWhenNotReady",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "chainId",
-            "docs": [
-              "The chain id of the chain that this program is running on. We don't",
-              "hardcode this so that the program is deployable on any potential SVM",
-              "forks."
-            ],
+            "name": "recipientChain",
             "type": {
               "defined": "ChainId"
             }
           },
           {
-            "name": "nextTransceiverId",
-            "docs": [
-              "The next transceiver id to use when registering an transceiver."
-            ],
-            "type": "u8"
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "threshold",
-            "docs": [
-              "The number of transceivers that must attest to a transfer before it is",
-              "accepted."
-            ],
-            "type": "u8"
+            "name": "shouldQueue",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseStatus",
+      "docs": [
+        "The status of an InboxItem. This determines whether the tokens are minted/unlocked to the recipient. As",
+        "such, this must be used as a state machine that moves forward in a linear manner. A state",
+        "should never \"move backward\" to a previous state (e.g. should never move from `Released` to",
+        "`ReleaseAfter`)."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NotApproved"
           },
           {
-            "name": "enabledTransceivers",
+            "name": "ReleaseAfter",
+            "fields": [
+              "i64"
+            ]
+          },
+          {
+            "name": "Released"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
             "docs": [
-              "Bitmap of enabled transceivers.",
-              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+              "The maximum capacity of the rate limiter."
             ],
-            "type": {
-              "defined": "Bitmap"
-            }
+            "type": "u64"
           },
           {
-            "name": "paused",
+            "name": "capacityAtLastTx",
             "docs": [
-              "Pause the program. This is useful for upgrades and other maintenance."
+              "The capacity of the rate limiter at `last_tx_timestamp`.",
+              "The actual current capacity is calculated in `capacity_at`, by",
+              "accounting for the time that has passed since `last_tx_timestamp` and",
+              "the refill rate."
             ],
-            "type": "bool"
+            "type": "u64"
           },
           {
-            "name": "custody",
+            "name": "lastTxTimestamp",
             "docs": [
-              "The custody account that holds tokens in locking mode."
+              "The timestamp of the last transaction that counted towards the current",
+              "capacity. Transactions that exceeded the capacity do not count, they are",
+              "just delayed."
             ],
-            "type": "publicKey"
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "LUT",
+      "name": "SetTransceiverPeerArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
           },
           {
             "name": "address",
-            "type": "publicKey"
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "nttManagerPeer",
-      "docs": [
-        "A peer on another chain. Stored in a PDA seeded by the chain id."
-      ],
+      "name": "BroadcastPeerArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "address",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "tokenDecimals",
-            "type": "u8"
+            "name": "revertOnDelay",
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "pendingTokenAuthority",
+      "name": "ChainId",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
-          },
+            "name": "id",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mode",
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "pendingAuthority",
-            "type": "publicKey"
+            "name": "Locking"
           },
           {
-            "name": "rentPayer",
-            "type": "publicKey"
+            "name": "Burning"
           }
         ]
       }
     },
     {
-      "name": "inboxItem",
+      "name": "TrimmedAmount",
       "type": {
         "kind": "struct",
         "fields": [
-          {
-            "name": "init",
-            "type": "bool"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
           {
             "name": "amount",
             "type": "u64"
           },
           {
-            "name": "recipientAddress",
-            "type": "publicKey"
-          },
-          {
-            "name": "votes",
-            "type": {
-              "defined": "Bitmap"
-            }
-          },
-          {
-            "name": "releaseStatus",
-            "type": {
-              "defined": "ReleaseStatus"
-            }
+            "name": "decimals",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "inboxRateLimit",
-      "docs": [
-        "Inbound rate limit per chain.",
-        "SECURITY: must check the PDA (since there are multiple PDAs, namely one for each chain.)"
-      ],
+      "name": "BridgeConfig",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
           },
           {
-            "name": "rateLimit",
-            "type": {
-              "defined": "RateLimitState"
-            }
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "outboxItem",
+      "name": "InitialPeer",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "amount",
-            "type": {
-              "defined": "TrimmedAmount"
-            }
-          },
-          {
-            "name": "sender",
-            "type": "publicKey"
-          },
-          {
-            "name": "recipientChain",
+            "name": "chainId",
             "type": {
               "defined": "ChainId"
             }
           },
           {
-            "name": "recipientNttManager",
+            "name": "address",
+            "docs": [
+              "The NTT manager on the peer chain."
+            ],
             "type": {
               "array": [
                 "u8",
@@ -4391,173 +13040,307 @@ export const IDL: ExampleNativeTokenTransfers = {
             }
           },
           {
-            "name": "recipientAddress",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "tokenDecimals",
+            "docs": [
+              "The token decimals on the peer chain."
+            ],
+            "type": "u8"
           },
           {
-            "name": "releaseTimestamp",
-            "type": "i64"
+            "name": "inboundLimit",
+            "type": "u64"
           },
           {
-            "name": "released",
+            "name": "transceiverAddress",
+            "docs": [
+              "The peer of the integrated Wormhole transceiver, see",
+              "[`crate::transceivers::wormhole::set_transceiver_peer`]. Standalone",
+              "transceivers have to be set up separately."
+            ],
             "type": {
-              "defined": "Bitmap"
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
             }
+          },
+          {
+            "name": "allowUnknownChain",
+            "docs": [
+              "Skip the check that `chain_id` is a known Wormhole chain id."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowNonstandardAddress",
+            "docs": [
+              "Skip the check that `address` is well-formed for the chain, see",
+              "[`check_peer_address`]."
+            ],
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "outboxRateLimit",
+      "name": "TransferOptions",
+      "docs": [
+        "Optional conditions on a transfer, taken by [`transfer_burn_with_options`]",
+        "and [`transfer_lock_with_options`]. They're not part of [`TransferArgs`], so",
+        "that its encoding stays the same for existing clients."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "rateLimit",
+            "name": "minAmountOut",
+            "docs": [
+              "The least the recipient must receive on the destination chain, after",
+              "the peer's [`NttManagerPeer::destination_fee_bps`] is deducted. This is",
+              "in the trimmed decimals of the transfer (see",
+              "[`crate::instructions::get_peer_decimals`])."
+            ],
             "type": {
-              "defined": "RateLimitState"
+              "option": "u64"
+            }
+          },
+          {
+            "name": "expiryTimestamp",
+            "docs": [
+              "The time after which the transfer is discarded rather than released",
+              "(see [`OutboxItem::expiry_timestamp`])."
+            ],
+            "type": {
+              "option": "i64"
             }
           }
         ]
       }
     },
     {
-      "name": "registeredTransceiver",
+      "name": "LatencyStats",
+      "docs": [
+        "A running aggregate of latencies, in seconds."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "count",
+            "docs": [
+              "The number of samples in [`Self::sum_seconds`] and [`Self::max_seconds`]."
+            ],
+            "type": "u64"
           },
           {
-            "name": "id",
-            "type": "u8"
+            "name": "sumSeconds",
+            "type": "u64"
           },
           {
-            "name": "transceiverAddress",
-            "type": "publicKey"
+            "name": "maxSeconds",
+            "type": "u64"
+          },
+          {
+            "name": "outliers",
+            "docs": [
+              "The number of samples longer than [`Self::MAX_SAMPLE_SECONDS`]. These",
+              "are counted separately, so that a single stale (or misdated) message",
+              "doesn't skew the aggregate."
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "transceiverPeer",
-      "docs": [
-        "A peer on another chain. Stored in a PDA seeded by the chain id."
-      ],
+      "name": "CustodyReconciliation",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "custodyBalance",
+            "docs": [
+              "The token balance of the custody account."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lockedAmount",
+            "docs": [
+              "The amount the program believes is locked in custody, as tracked in",
+              "[`CustodyLedger`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "matches",
+            "docs": [
+              "Whether the two agree. A mismatch means tokens were transferred into",
+              "(or out of) custody outside of the program's transfers, or a bug."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxItemStatus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "votes",
+            "type": {
+              "defined": "Bitmap"
+            }
           },
           {
-            "name": "address",
+            "name": "releaseStatus",
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "defined": "ReleaseStatus"
             }
+          },
+          {
+            "name": "rateLimited",
+            "docs": [
+              "Whether the transfer is held back by the inbound rate limit.",
+              "",
+              "* For transfers that have not been approved yet, this is whether the",
+              "current inbound capacity is insufficient to release them immediately.",
+              "* For approved transfers, this is whether the release timestamp is",
+              "still in the future."
+            ],
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "bridgeData",
+      "name": "PeerDecimals",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "guardianSetIndex",
+            "name": "mintDecimals",
             "docs": [
-              "The current guardian set index, used to decide which signature sets to accept."
+              "The decimals of the local mint."
             ],
-            "type": "u32"
+            "type": "u8"
           },
           {
-            "name": "lastLamports",
+            "name": "peerTokenDecimals",
             "docs": [
-              "Lamports in the collection account"
+              "The decimals of the peer's token, as recorded in [`NttManagerPeer`]."
             ],
-            "type": "u64"
+            "type": "u8"
           },
           {
-            "name": "config",
+            "name": "trimmedDecimals",
             "docs": [
-              "Bridge configuration, which is set once upon initialization."
+              "The decimals amounts are trimmed to in transfers to the peer."
             ],
-            "type": {
-              "defined": "BridgeConfig"
-            }
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "Bitmap",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "map",
-            "type": "u128"
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "SetInboundLimitArgs",
+      "name": "RateLimits",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "limit",
-            "type": "u64"
+            "name": "inbound",
+            "docs": [
+              "The inbound rate limit of the given chain."
+            ],
+            "type": {
+              "defined": "RateLimitParams"
+            }
           },
           {
-            "name": "chainId",
+            "name": "outbound",
+            "docs": [
+              "The outbound rate limit, which is shared by all chains."
+            ],
             "type": {
-              "defined": "ChainId"
+              "defined": "RateLimitParams"
             }
           }
         ]
       }
     },
     {
-      "name": "SetOutboundLimitArgs",
+      "name": "RateLimitParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "limit",
+            "docs": [
+              "See [`RateLimitState::limit`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "capacityAtLastTx",
+            "docs": [
+              "See [`RateLimitState::capacity_at_last_tx`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastTxTimestamp",
+            "docs": [
+              "See [`RateLimitState::last_tx_timestamp`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "capacity",
+            "docs": [
+              "The capacity right now, i.e. [`Self::capacity_at_last_tx`] plus what",
+              "has been refilled since."
+            ],
             "type": "u64"
+          },
+          {
+            "name": "refillDuration",
+            "docs": [
+              "How many seconds it takes to refill from empty to [`Self::limit`]."
+            ],
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "SetPeerArgs",
+      "name": "TransferStatus",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "chainId",
+            "name": "amount",
             "type": {
-              "defined": "ChainId"
+              "defined": "TrimmedAmount"
             }
           },
           {
-            "name": "address",
+            "name": "recipientChain",
+            "type": "u16"
+          },
+          {
+            "name": "recipientAddress",
             "type": {
               "array": [
                 "u8",
@@ -4566,265 +13349,464 @@ export const IDL: ExampleNativeTokenTransfers = {
             }
           },
           {
-            "name": "limit",
-            "type": "u64"
+            "name": "releaseTimestamp",
+            "type": "i64"
           },
           {
-            "name": "tokenDecimals",
+            "name": "rateLimited",
             "docs": [
-              "The token decimals on the peer chain."
+              "Whether the transfer is held back by the outbound rate limit, i.e.",
+              "whether the release timestamp is still in the future."
             ],
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "InitializeArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "chainId",
-            "type": "u16"
+            "type": "bool"
           },
           {
-            "name": "limit",
-            "type": "u64"
+            "name": "released",
+            "docs": [
+              "The transceivers that have released the transfer, by id."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
           },
           {
-            "name": "mode",
+            "name": "wormholeSequence",
+            "docs": [
+              "The sequence of the message the integrated Wormhole transceiver posted,",
+              "if it has released the transfer. Always `None` for standalone",
+              "transceivers, whose messages aren't tied to the outbox item."
+            ],
             "type": {
-              "defined": "Mode"
+              "option": "u64"
             }
           }
         ]
       }
     },
     {
-      "name": "RedeemArgs",
-      "type": {
-        "kind": "struct",
-        "fields": []
-      }
-    },
-    {
-      "name": "ReleaseInboundArgs",
+      "name": "WouldQueueResult",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "revertWhenNotReady",
+            "name": "wouldQueue",
+            "docs": [
+              "Whether a transfer of the given amount would exceed the current",
+              "outbound capacity, and thus be queued (or rejected, if queueing is not",
+              "requested)."
+            ],
             "type": "bool"
+          },
+          {
+            "name": "releaseDelay",
+            "docs": [
+              "How many seconds the transfer would be queued for, if it would be."
+            ],
+            "type": {
+              "option": "i64"
+            }
           }
         ]
       }
     },
     {
-      "name": "TransferArgs",
+      "name": "TransferPreview",
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "amount",
+            "docs": [
+              "The amount taken from the sender, i.e. the requested amount without the",
+              "dust that can't be represented on the peer."
+            ],
             "type": "u64"
           },
           {
-            "name": "recipientChain",
-            "type": {
-              "defined": "ChainId"
-            }
+            "name": "dust",
+            "docs": [
+              "The dust left in the sender's account, or collected into the dust",
+              "account (see [`Config::dust_account`])."
+            ],
+            "type": "u64"
           },
           {
-            "name": "recipientAddress",
+            "name": "trimmedAmount",
+            "docs": [
+              "The amount carried by the transfer message."
+            ],
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "defined": "TrimmedAmount"
             }
           },
           {
-            "name": "shouldQueue",
+            "name": "expectedAmountOut",
+            "docs": [
+              "What the recipient is expected to receive after the peer's destination",
+              "fee, see [`NttManagerPeer::expected_amount_out`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "peerDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "routeEnabled",
+            "docs": [
+              "Whether transfers to the peer are enabled at all."
+            ],
             "type": "bool"
-          }
-        ]
-      }
-    },
-    {
-      "name": "ReleaseStatus",
-      "docs": [
-        "The status of an InboxItem. This determines whether the tokens are minted/unlocked to the recipient. As",
-        "such, this must be used as a state machine that moves forward in a linear manner. A state",
-        "should never \"move backward\" to a previous state (e.g. should never move from `Released` to",
-        "`ReleaseAfter`)."
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
+          },
           {
-            "name": "NotApproved"
+            "name": "rateLimited",
+            "docs": [
+              "Whether the rate limits would hold the transfer back."
+            ],
+            "type": "bool"
           },
           {
-            "name": "ReleaseAfter",
-            "fields": [
-              "i64"
-            ]
+            "name": "exceedsSenderLimit",
+            "docs": [
+              "Whether the transfer would be held back by the sender's own limit (see",
+              "[`Config::sender_limit`]) rather than the outbound one."
+            ],
+            "type": "bool"
           },
           {
-            "name": "Released"
+            "name": "queueAllowed",
+            "docs": [
+              "Whether a rate limited transfer would be queued rather than rejected,",
+              "which takes both the sender asking for it and the peer allowing it."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "releaseTimestamp",
+            "docs": [
+              "When the transfer could be released by the transceivers."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "wormholeFee",
+            "docs": [
+              "The core bridge fee the Wormhole transceiver pays to release the",
+              "transfer."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "outboxItemRent",
+            "docs": [
+              "The rent of the outbox item, refunded once it's closed."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "senderRateLimitRent",
+            "docs": [
+              "The rent of the sender's rate limit account, if the transfer would",
+              "create it."
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "RateLimitState",
+      "name": "SetPeerOptions",
+      "docs": [
+        "Checks of [`set_peer`] that can be skipped, through",
+        "[`set_peer_with_options`]. They're not part of [`SetPeerArgs`], so that",
+        "its encoding stays the same for existing clients."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "limit",
-            "docs": [
-              "The maximum capacity of the rate limiter."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "capacityAtLastTx",
+            "name": "allowUnknownChain",
             "docs": [
-              "The capacity of the rate limiter at `last_tx_timestamp`.",
-              "The actual current capacity is calculated in `capacity_at`, by",
-              "accounting for the time that has passed since `last_tx_timestamp` and",
-              "the refill rate."
+              "Skip the check that `chain_id` is a known Wormhole chain id.",
+              "Only needed for chains added to Wormhole after this program was built."
             ],
-            "type": "u64"
+            "type": "bool"
           },
           {
-            "name": "lastTxTimestamp",
+            "name": "allowNonstandardAddress",
             "docs": [
-              "The timestamp of the last transaction that counted towards the current",
-              "capacity. Transactions that exceeded the capacity do not count, they are",
-              "just delayed."
+              "Skip the check that `address` is well-formed for the chain, see",
+              "[`check_peer_address`]."
             ],
-            "type": "i64"
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "SetTransceiverPeerArgs",
+      "name": "SetSenderLimitOverrideArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "chainId",
-            "type": {
-              "defined": "ChainId"
-            }
+            "name": "sender",
+            "type": "publicKey"
           },
           {
-            "name": "address",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
+            "name": "limit",
+            "docs": [
+              "Falls back to [`Config::sender_limit`] when `None`."
+            ],
+            "type": {
+              "option": "u64"
             }
           }
         ]
       }
     },
     {
-      "name": "BroadcastPeerArgs",
+      "name": "SetChainOutboundLimitArgs",
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
           {
             "name": "chainId",
-            "type": "u16"
+            "type": {
+              "defined": "ChainId"
+            }
           }
         ]
       }
     },
     {
-      "name": "ReleaseOutboundArgs",
+      "name": "PurgeInboxItemArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "revertOnDelay",
-            "type": "bool"
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nttManagerMessage",
+            "type": {
+              "defined": "NttManagerMessage<NativeTokenTransfer<Payload>>"
+            }
           }
         ]
       }
     },
     {
-      "name": "ChainId",
+      "name": "ForceMarkReleasedArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "id",
-            "type": "u16"
+            "name": "transceiverId",
+            "type": "u8"
+          },
+          {
+            "name": "confirmOutboxItem",
+            "docs": [
+              "Must be the address of the outbox item."
+            ],
+            "type": "publicKey"
           }
         ]
       }
     },
     {
-      "name": "Mode",
+      "name": "PeerAddressMatch",
+      "docs": [
+        "How strictly a transceiver peer address is compared against the emitter",
+        "address of received messages."
+      ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "Locking"
+            "name": "Exact"
           },
           {
-            "name": "Burning"
+            "name": "Normalized"
           }
         ]
       }
     },
     {
-      "name": "TrimmedAmount",
+      "name": "SetTransceiverPeerOptions",
+      "docs": [
+        "Settings of [`set_transceiver_peer_with_options`] that",
+        "[`set_transceiver_peer`] leaves at their defaults. They're not part of",
+        "[`SetTransceiverPeerArgs`], so that its encoding stays the same for",
+        "existing clients."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "allowUnknownChain",
+            "docs": [
+              "Skip the check that `chain_id` is a known Wormhole chain id.",
+              "Only needed for chains added to Wormhole after this program was built."
+            ],
+            "type": "bool"
           },
           {
-            "name": "decimals",
-            "type": "u8"
+            "name": "addressMatch",
+            "docs": [
+              "How `address` is compared against the emitter of received messages."
+            ],
+            "type": {
+              "defined": "PeerAddressMatch"
+            }
           }
         ]
       }
     },
     {
-      "name": "BridgeConfig",
+      "name": "ReleaseOutboundOptions",
+      "docs": [
+        "Settings of [`release_outbound_with_options`] that [`release_outbound`]",
+        "leaves at their defaults. They're not part of [`ReleaseOutboundArgs`], so",
+        "that its encoding stays the same for existing clients."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "guardianSetExpirationTime",
+            "name": "maxWormholeFee",
             "docs": [
-              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
-              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
-              "this period we still trust the old guardian set."
+              "The most the payer is willing to pay for the wormhole message fee.",
+              "Guards against the core bridge fee being raised between building and",
+              "landing the transaction. Unlimited when unset."
             ],
-            "type": "u32"
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReceiveHookArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fromChain",
+            "type": {
+              "defined": "ChainId"
+            }
           },
           {
-            "name": "fee",
-            "docs": [
-              "Amount of lamports that needs to be paid to the protocol to post a message"
-            ],
-            "type": "u64"
+            "name": "id",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "sourceNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "sender",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "to",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           }
         ]
       }
     }
   ],
+  "events": [
+    {
+      "name": "InboxItemPurged",
+      "fields": [
+        {
+          "name": "chainId",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "inboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "recipientAddress",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "releaseStatus",
+          "type": {
+            "defined": "ReleaseStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReceiveHookNotified",
+      "fields": [
+        {
+          "name": "receiveHook",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transceiverMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReceiveHookArgs"
+          },
+          "index": false
+        }
+      ]
+    }
+  ],
   "errors": [
     {
       "code": 6000,
@@ -4975,6 +13957,286 @@ export const IDL: ExampleNativeTokenTransfers = {
       "code": 6029,
       "name": "InvalidTransceiverProgram",
       "msg": "InvalidTransceiverProgram"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidAddressEncoding",
+      "msg": "InvalidAddressEncoding"
+    },
+    {
+      "code": 6031,
+      "name": "RouteDisabled",
+      "msg": "RouteDisabled"
+    },
+    {
+      "code": 6032,
+      "name": "TokenProgramMismatch",
+      "msg": "TokenProgramMismatch"
+    },
+    {
+      "code": 6033,
+      "name": "UnknownChainId",
+      "msg": "UnknownChainId"
+    },
+    {
+      "code": 6034,
+      "name": "SourceMintMismatch",
+      "msg": "SourceMintMismatch"
+    },
+    {
+      "code": 6035,
+      "name": "RecipientMintMismatch",
+      "msg": "RecipientMintMismatch"
+    },
+    {
+      "code": 6036,
+      "name": "UnsupportedPayloadVersion",
+      "msg": "UnsupportedPayloadVersion"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidFeeCollector",
+      "msg": "InvalidFeeCollector"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidGuardianSignaturesAccount",
+      "msg": "InvalidGuardianSignaturesAccount"
+    },
+    {
+      "code": 6039,
+      "name": "ConfidentialTransfersUnsupported",
+      "msg": "ConfidentialTransfersUnsupported"
+    },
+    {
+      "code": 6040,
+      "name": "TooManyOutboundInFlight",
+      "msg": "TooManyOutboundInFlight"
+    },
+    {
+      "code": 6041,
+      "name": "DigestMismatch",
+      "msg": "DigestMismatch"
+    },
+    {
+      "code": 6042,
+      "name": "HeartbeatTooSoon",
+      "msg": "HeartbeatTooSoon"
+    },
+    {
+      "code": 6043,
+      "name": "ForceReleaseNotConfirmed",
+      "msg": "ForceReleaseNotConfirmed"
+    },
+    {
+      "code": 6044,
+      "name": "UnregisteredTransceiver",
+      "msg": "UnregisteredTransceiver"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidDustAccount",
+      "msg": "InvalidDustAccount"
+    },
+    {
+      "code": 6046,
+      "name": "Unauthorized",
+      "msg": "Unauthorized"
+    },
+    {
+      "code": 6047,
+      "name": "InsufficientRentForMessage",
+      "msg": "InsufficientRentForMessage"
+    },
+    {
+      "code": 6048,
+      "name": "QueueItemNotMigrated",
+      "msg": "QueueItemNotMigrated"
+    },
+    {
+      "code": 6049,
+      "name": "QueueItemAlreadyMigrated",
+      "msg": "QueueItemAlreadyMigrated"
+    },
+    {
+      "code": 6050,
+      "name": "WormholeFeeTooHigh",
+      "msg": "WormholeFeeTooHigh"
+    },
+    {
+      "code": 6051,
+      "name": "MalformedMessage",
+      "msg": "MalformedMessage"
+    },
+    {
+      "code": 6052,
+      "name": "SlippageExceeded",
+      "msg": "SlippageExceeded"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidDestinationFeeBps",
+      "msg": "InvalidDestinationFeeBps"
+    },
+    {
+      "code": 6054,
+      "name": "TransceiverNotRegistered",
+      "msg": "TransceiverNotRegistered"
+    },
+    {
+      "code": 6055,
+      "name": "BroadcastSuppressed",
+      "msg": "BroadcastSuppressed"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidFallbackRecipient",
+      "msg": "InvalidFallbackRecipient"
+    },
+    {
+      "code": 6057,
+      "name": "TooManyInitialPeers",
+      "msg": "TooManyInitialPeers"
+    },
+    {
+      "code": 6058,
+      "name": "MintAuthorityRotatedAway",
+      "msg": "MintAuthorityRotatedAway"
+    },
+    {
+      "code": 6059,
+      "name": "VaaTooOld",
+      "msg": "VaaTooOld"
+    },
+    {
+      "code": 6060,
+      "name": "InvalidClock",
+      "msg": "InvalidClock"
+    },
+    {
+      "code": 6061,
+      "name": "PeerIndexFull",
+      "msg": "PeerIndexFull"
+    },
+    {
+      "code": 6062,
+      "name": "PeerHasOutboundInFlight",
+      "msg": "PeerHasOutboundInFlight"
+    },
+    {
+      "code": 6063,
+      "name": "RateLimitNotInitialized",
+      "msg": "RateLimitNotInitialized"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidPeerAddress",
+      "msg": "InvalidPeerAddress"
+    },
+    {
+      "code": 6065,
+      "name": "TooManyEnabledTransceivers",
+      "msg": "TooManyEnabledTransceivers"
+    },
+    {
+      "code": 6066,
+      "name": "UnconsumedPayload",
+      "msg": "UnconsumedPayload"
+    },
+    {
+      "code": 6067,
+      "name": "MintDecimalsMismatch",
+      "msg": "MintDecimalsMismatch"
+    },
+    {
+      "code": 6068,
+      "name": "CustodyNotEmpty",
+      "msg": "CustodyNotEmpty"
+    },
+    {
+      "code": 6069,
+      "name": "TransferExpired",
+      "msg": "TransferExpired"
+    },
+    {
+      "code": 6070,
+      "name": "InvalidExpiryTimestamp",
+      "msg": "InvalidExpiryTimestamp"
+    },
+    {
+      "code": 6071,
+      "name": "TransferNotExpired",
+      "msg": "TransferNotExpired"
+    },
+    {
+      "code": 6072,
+      "name": "InboxItemNotReleasable",
+      "msg": "InboxItemNotReleasable"
+    },
+    {
+      "code": 6073,
+      "name": "DeploymentDecommissioning",
+      "msg": "DeploymentDecommissioning"
+    },
+    {
+      "code": 6074,
+      "name": "DeploymentTerminated",
+      "msg": "DeploymentTerminated"
+    },
+    {
+      "code": 6075,
+      "name": "NotDecommissioning",
+      "msg": "NotDecommissioning"
+    },
+    {
+      "code": 6076,
+      "name": "DecommissionGracePeriodActive",
+      "msg": "DecommissionGracePeriodActive"
+    },
+    {
+      "code": 6077,
+      "name": "PeersRemaining",
+      "msg": "PeersRemaining"
+    },
+    {
+      "code": 6078,
+      "name": "PeerHasInboundInFlight",
+      "msg": "PeerHasInboundInFlight"
+    },
+    {
+      "code": 6079,
+      "name": "TransferAlreadyCancelled",
+      "msg": "TransferAlreadyCancelled"
+    },
+    {
+      "code": 6080,
+      "name": "ConsistencyLevelNotAllowed",
+      "msg": "ConsistencyLevelNotAllowed"
+    },
+    {
+      "code": 6081,
+      "name": "UnsupportedConsistencyLevel",
+      "msg": "UnsupportedConsistencyLevel"
+    },
+    {
+      "code": 6082,
+      "name": "VaaBodyNotExpired",
+      "msg": "VaaBodyNotExpired"
+    },
+    {
+      "code": 6083,
+      "name": "VaaTimestampInFuture",
+      "msg": "VaaTimestampInFuture"
+    },
+    {
+      "code": 6084,
+      "name": "TransferExceedsSenderRateLimit",
+      "msg": "TransferExceedsSenderRateLimit"
+    },
+    {
+      "code": 6085,
+      "name": "PeerAlreadyMigrated",
+      "msg": "PeerAlreadyMigrated"
     }
   ]
 }
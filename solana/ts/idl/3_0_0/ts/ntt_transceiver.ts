@@ -26,11 +26,30 @@ export type NttTransceiver = {
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "registeredTransceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "deserialized as an `Account`, as it doesn't exist until the transceiver",
+            "is registered, which is checked in [`is_enabled`]."
+          ]
+        },
         {
           "name": "peer",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeStats",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -47,52 +66,135 @@ export type NttTransceiver = {
       ]
     },
     {
-      "name": "receiveWormholeMessageInstructionData",
+      "name": "setWormholePeerWithOptions",
       "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
         {
           "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "config",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
-            }
+          "name": "registeredTransceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "deserialized as an `Account`, as it doesn't exist until the transceiver",
+            "is registered, which is checked in [`is_enabled`]."
           ]
         },
         {
           "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
         },
         {
-          "name": "transceiverMessage",
+          "name": "options",
+          "type": {
+            "defined": "SetTransceiverPeerOptions"
+          }
+        }
+      ]
+    },
+    {
+      "name": "deleteWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "guardianSet",
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "getWormholePeerIndex",
+      "accounts": [
+        {
+          "name": "peerIndex",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "Derivation is checked by the shim."
+            "checked in [`get_transceiver_peer_index`]."
           ]
+        }
+      ],
+      "args": [],
+      "returns": {
+        "vec": "u16"
+      }
+    },
+    {
+      "name": "migrateWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "guardianSignatures",
-          "isMut": false,
+          "name": "peer",
+          "isMut": true,
           "isSigner": false,
           "docs": [
-            "Ownership ownership and discriminator is checked by the shim."
+            "still in the legacy layout."
           ]
         },
         {
-          "name": "verifyVaaShim",
-          "isMut": false,
+          "name": "peerIndex",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -103,27 +205,36 @@ export type NttTransceiver = {
       ],
       "args": [
         {
-          "name": "guardianSetBump",
-          "type": "u8"
-        },
-        {
-          "name": "vaaBody",
-          "type": {
-            "defined": "VaaBodyData"
-          }
+          "name": "chainId",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "postUnverifiedWormholeMessageAccount",
+      "name": "registerWormholePeerEmitter",
       "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
         {
           "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "message",
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peerEmitter",
           "isMut": true,
           "isSigner": false
         },
@@ -135,23 +246,31 @@ export type NttTransceiver = {
       ],
       "args": [
         {
-          "name": "args",
-          "type": {
-            "defined": "PostUnverifiedMessageAccountArgs"
-          }
+          "name": "chainId",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "closeUnverifiedWormholeMessageAccount",
+      "name": "setWormholeBroadcastSuppressionWindow",
       "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
         {
           "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "message",
+          "name": "broadcastSuppression",
           "isMut": true,
           "isSigner": false
         },
@@ -163,13 +282,13 @@ export type NttTransceiver = {
       ],
       "args": [
         {
-          "name": "seed",
-          "type": "u64"
+          "name": "window",
+          "type": "u32"
         }
       ]
     },
     {
-      "name": "receiveWormholeMessageAccount",
+      "name": "receiveWormholeMessageInstructionData",
       "accounts": [
         {
           "name": "payer",
@@ -192,14 +311,21 @@ export type NttTransceiver = {
           "isSigner": false
         },
         {
-          "name": "message",
-          "isMut": true,
+          "name": "managerPeer",
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "transceiverMessage",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "exists, so that receiving the same message twice from this transceiver",
+            "is a no-op rather than an error.",
+            "The account is seeded by the message's (emitter chain, id), so if it",
+            "has already been initialized, the message has already been received",
+            "(see [`ValidatedTransceiverMessage::is_initialized`])."
+          ]
         },
         {
           "name": "guardianSet",
@@ -214,7 +340,8 @@ export type NttTransceiver = {
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "Ownership ownership and discriminator is checked by the shim."
+            "Ownership and discriminator are checked in [`verify_vaa_body`] before",
+            "invoking the shim."
           ]
         },
         {
@@ -234,13 +361,15 @@ export type NttTransceiver = {
           "type": "u8"
         },
         {
-          "name": "seed",
-          "type": "u64"
+          "name": "vaaBody",
+          "type": {
+            "defined": "VaaBodyData"
+          }
         }
       ]
     },
     {
-      "name": "releaseWormholeOutbound",
+      "name": "receiveWormholeMessageInstructionDataWithDigest",
       "accounts": [
         {
           "name": "payer",
@@ -258,97 +387,79 @@ export type NttTransceiver = {
           ]
         },
         {
-          "name": "outboxItem",
-          "isMut": true,
+          "name": "peer",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "transceiver",
+          "name": "managerPeer",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "wormholeMessage",
+          "name": "transceiverMessage",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "exists, so that receiving the same message twice from this transceiver",
+            "is a no-op rather than an error.",
+            "The account is seeded by the message's (emitter chain, id), so if it",
+            "has already been initialized, the message has already been received",
+            "(see [`ValidatedTransceiverMessage::is_initialized`])."
+          ]
         },
         {
-          "name": "emitter",
+          "name": "guardianSet",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "Derivation is checked by the shim."
+          ]
         },
         {
-          "name": "wormhole",
-          "accounts": [
-            {
-              "name": "bridge",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "feeCollector",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "sequence",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "program",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "postMessageShim",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "wormholePostMessageShimEa",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "clock",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "rent",
-              "isMut": false,
-              "isSigner": false
-            }
+          "name": "guardianSignatures",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Ownership and discriminator are checked in [`verify_vaa_body`] before",
+            "invoking the shim."
           ]
         },
         {
-          "name": "manager",
+          "name": "verifyVaaShim",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "outboxItemSigner",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "args",
+          "name": "guardianSetBump",
+          "type": "u8"
+        },
+        {
+          "name": "vaaBody",
           "type": {
-            "defined": "ReleaseOutboundArgs"
+            "defined": "VaaBodyData"
+          }
+        },
+        {
+          "name": "expectedDigest",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
           }
         }
       ]
     },
     {
-      "name": "broadcastWormholeId",
+      "name": "postUnverifiedWormholeMessageAccount",
       "accounts": [
         {
           "name": "payer",
@@ -356,84 +467,77 @@ export type NttTransceiver = {
           "isSigner": true
         },
         {
-          "name": "config",
-          "isMut": false,
+          "name": "message",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "mint",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PostUnverifiedMessageAccountArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeUnverifiedWormholeMessageAccount",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "wormholeMessage",
+          "name": "message",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "emitter",
+          "name": "systemProgram",
           "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "seed",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cleanupExpiredVaaBody",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
           "isSigner": false,
           "docs": [
-            "enforced by the [`CpiContext`] call in [`post_message`].",
-            "The seeds constraint ensures that this is the correct address"
+            "Only receives the rent."
           ]
         },
         {
-          "name": "wormhole",
-          "accounts": [
-            {
-              "name": "bridge",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "feeCollector",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "sequence",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "program",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "postMessageShim",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "wormholePostMessageShimEa",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "clock",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "rent",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
+          "name": "message",
+          "isMut": true,
+          "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "seed",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "broadcastWormholePeer",
+      "name": "receiveWormholeMessageAccount",
       "accounts": [
         {
           "name": "payer",
@@ -442,88 +546,1540 @@ export type NttTransceiver = {
         },
         {
           "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "peer",
+          "name": "managerPeer",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "wormholeMessage",
+          "name": "message",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "emitter",
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardianSet",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Derivation is checked by the shim."
+          ]
+        },
+        {
+          "name": "guardianSignatures",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Ownership and discriminator are checked in [`verify_vaa_body`] before",
+            "invoking the shim."
+          ]
+        },
+        {
+          "name": "verifyVaaShim",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "wormhole",
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "guardianSetBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeMessageAccountWithDigest",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
           "accounts": [
             {
-              "name": "bridge",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "feeCollector",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "sequence",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "program",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "postMessageShim",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "wormholePostMessageShimEa",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "clock",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "rent",
+              "name": "config",
               "isMut": false,
               "isSigner": false
             }
           ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managerPeer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "message",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardianSet",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Derivation is checked by the shim."
+          ]
+        },
+        {
+          "name": "guardianSignatures",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Ownership and discriminator are checked in [`verify_vaa_body`] before",
+            "invoking the shim."
+          ]
+        },
+        {
+          "name": "verifyVaaShim",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "args",
-          "type": {
-            "defined": "BroadcastPeerArgs"
-          }
-        }
-      ]
+          "name": "guardianSetBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "u64"
+        },
+        {
+          "name": "expectedDigest",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "verifyPendingWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "NOTE: unlike the receive instructions, this is allowed while the",
+            "program is paused. The message is only accepted once it's promoted",
+            "(see [`promote_pending_message`])."
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "message",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardianSet",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Derivation is checked by the shim."
+          ]
+        },
+        {
+          "name": "guardianSignatures",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Ownership and discriminator are checked in [`verify_vaa_body`] before",
+            "invoking the shim."
+          ]
+        },
+        {
+          "name": "verifyVaaShim",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "guardianSetBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "promotePendingWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managerPeer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Promoting a message that this transceiver has already received is a",
+            "no-op, other than closing the pending account."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseWormholeOutbound",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "recipient chain"
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The registration of this program. Its id is the bit marked in the",
+            "outbox item's `released` bitmap."
+          ]
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "postMessageShim",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormholePostMessageShimEa",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feeStats",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Records the fee when given. Optional, as it's only created when a peer",
+                "is registered."
+              ]
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItemSigner",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseWormholeOutboundWithOptions",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "recipient chain"
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The registration of this program. Its id is the bit marked in the",
+            "outbox item's `released` bitmap."
+          ]
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "postMessageShim",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormholePostMessageShimEa",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feeStats",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Records the fee when given. Optional, as it's only created when a peer",
+                "is registered."
+              ]
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItemSigner",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        },
+        {
+          "name": "options",
+          "type": {
+            "defined": "ReleaseOutboundOptions"
+          }
+        }
+      ]
+    },
+    {
+      "name": "broadcastWormholeId",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "enforced by the [`CpiContext`] call in [`post_message`].",
+            "The seeds constraint ensures that this is the correct address: the",
+            "emitter registered in [`Self::peer_emitter`] if given, and the default",
+            "one otherwise."
+          ]
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "postMessageShim",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormholePostMessageShimEa",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feeStats",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Records the fee when given. Optional, as it's only created when a peer",
+                "is registered."
+              ]
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        },
+        {
+          "name": "tokenMetadata",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "in [`token_metadata_hash`]. It's optional, as not every token has",
+            "Metaplex metadata."
+          ]
+        },
+        {
+          "name": "peerEmitter",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The emitter to broadcast from instead of the default one, see",
+            "[`WormholePeerEmitter`]."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "emitter registered in [`Self::peer_emitter`] if given, and the default",
+            "one otherwise."
+          ]
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "postMessageShim",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormholePostMessageShimEa",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feeStats",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Records the fee when given. Optional, as it's only created when a peer",
+                "is registered."
+              ]
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        },
+        {
+          "name": "broadcastSuppression",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerBroadcast",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peerEmitter",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The emitter to broadcast from instead of the default one, see",
+            "[`WormholePeerEmitter`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "broadcastWormholePeerWithAddressEncoding",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "emitter registered in [`Self::peer_emitter`] if given, and the default",
+            "one otherwise."
+          ]
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "postMessageShim",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormholePostMessageShimEa",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feeStats",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Records the fee when given. Optional, as it's only created when a peer",
+                "is registered."
+              ]
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        },
+        {
+          "name": "broadcastSuppression",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerBroadcast",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peerEmitter",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The emitter to broadcast from instead of the default one, see",
+            "[`WormholePeerEmitter`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
+        },
+        {
+          "name": "addressEncoding",
+          "type": {
+            "defined": "AddressEncoding"
+          }
+        }
+      ]
+    },
+    {
+      "name": "broadcastWormholeHeartbeat",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "heartbeat",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "enforced by the [`CpiContext`] call in [`post_message`].",
+            "The seeds constraint ensures that this is the correct address"
+          ]
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "postMessageShim",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormholePostMessageShimEa",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feeStats",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Records the fee when given. Optional, as it's only created when a peer",
+                "is registered."
+              ]
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "getWormholeFeeStats",
+      "accounts": [
+        {
+          "name": "feeStats",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "WormholeFeeStats"
+      }
+    },
+    {
+      "name": "quoteDeliveryCost",
+      "accounts": [],
+      "args": [
+        {
+          "name": "vaaSize",
+          "type": "u32"
+        },
+        {
+          "name": "recipientHasAta",
+          "type": "bool"
+        }
+      ],
+      "returns": {
+        "defined": "DeliveryCostQuote"
+      }
     }
   ],
   "accounts": [
     {
-      "name": "config",
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "docs": [
+              "Pending next owner (before claiming ownership)."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the token managed by this program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "Address of the token program (token or token22). This could always be queried",
+              "from the [`mint`] account's owner, but storing it here avoids an indirection",
+              "on the client side."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "The mode that this program is running in. This is used to determine",
+              "whether the program is burning tokens or locking tokens."
+            ],
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The chain id of the chain that this program is running on. We don't",
+              "hardcode this so that the program is deployable on any potential SVM",
+              "forks."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "docs": [
+              "The next transceiver id to use when registering an transceiver."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of transceivers that must attest to a transfer before it is",
+              "accepted."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "docs": [
+              "Bitmap of enabled transceivers.",
+              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "pausedOutbound",
+            "docs": [
+              "Pause outbound transfers (sending and releasing them). This is useful",
+              "for upgrades and other maintenance, or to halt outflows during an",
+              "incident without stranding inbound transfers (see [`paused_inbound`])."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "The custody account that holds tokens in locking mode."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "routesDefaultEnabled",
+            "docs": [
+              "Whether routes to newly registered peers are enabled straight away.",
+              "When false, the owner has to enable each route explicitly (via",
+              "`enable_route`) before any transfers to or from that chain are accepted."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "mintDecimals",
+            "docs": [
+              "Decimals of [`mint`], cached here so that inbound amounts can be",
+              "untrimmed without loading the mint account."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "receiveHook",
+            "docs": [
+              "Program notified whenever a transceiver receives a message (see",
+              "[`crate::receive_hook`])."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "feeCollector",
+            "docs": [
+              "Wormhole core bridge fee collector that message fees are paid into.",
+              "Defaults to the account derived from the core bridge program, and can",
+              "be updated (via `set_fee_collector`) if the core bridge changes it."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "confidentialTransfers",
+            "docs": [
+              "Whether [`mint`] has the Token-2022 confidential transfer extension, in",
+              "which case inbound transfers are only released to recipients that",
+              "accept non-confidential credits (see [`crate::confidential_transfer`])."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "dustAccount",
+            "docs": [
+              "Token account that dust trimmed off outbound transfers is collected",
+              "into (set via `set_dust_account`). When unset, the dust is left in the",
+              "sender's account."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "limitsManager",
+            "docs": [
+              "Account that can tune rate limits on behalf of the owner (see",
+              "[`Role::LimitsManager`]). Set and revoked via `set_limits_manager`."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "maxVaaAge",
+            "docs": [
+              "How old (in seconds) a VAA may be when it's received, measured from",
+              "the timestamp in its body. Older VAAs are rejected by the transceivers.",
+              "Set via `set_max_vaa_age`, unlimited when unset."
+            ],
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "rejectUnconsumedPayload",
+            "docs": [
+              "What happens to messages whose additional payload this program doesn't",
+              "decode (see [`crate::transfer::Payload`]) when there's no recipient",
+              "callback to pass it on to. When false, the payload is dropped and the",
+              "transfer goes through. When true, redeeming the message fails. Set via",
+              "`set_reject_unconsumed_payload`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "decommissionTimestamp",
+            "docs": [
+              "When the deployment is being decommissioned (see",
+              "`begin_decommission`), the time from which `finalize_decommission`",
+              "can be called. Outbound transfers are rejected in the meantime, while",
+              "inbound ones can still be redeemed and released."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "terminated",
+            "docs": [
+              "Whether the deployment has been decommissioned for good (see",
+              "`finalize_decommission`)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowedConsistencyLevels",
+            "docs": [
+              "Consistency levels outbound transfers may be released with, as a",
+              "bitmap indexed by the level's VAA encoding (see",
+              "[`CONSISTENCY_LEVEL_FINALIZED`] and [`CONSISTENCY_LEVEL_CONFIRMED`]).",
+              "Only finalized by default. Set via `set_allowed_consistency_levels`."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "pausedInbound",
+            "docs": [
+              "Pause inbound transfers (receiving, redeeming and releasing them).",
+              "Set together with [`paused_outbound`] via `set_paused`, or on its own",
+              "via `set_inbound_paused`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "senderLimit",
+            "docs": [
+              "How much a single sender may transfer out per day (see",
+              "[`crate::queue::outbox::SenderRateLimit`]), unless it has an override.",
+              "Set via `set_sender_limit`, unlimited when unset."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "perChainOutboundLimits",
+            "docs": [
+              "Whether transfers to a chain with its own",
+              "[`crate::queue::outbox::ChainOutboxRateLimit`] consume from it rather",
+              "than the global [`crate::queue::outbox::OutboxRateLimit`]. Set via",
+              "`set_per_chain_outbound_limits`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "senderLimitOverrides",
+            "docs": [
+              "Whether an override has been set for any sender with",
+              "`set_sender_limit_override`, which limits that sender even while",
+              "[`Self::sender_limit`] is unset. Never cleared, see",
+              "[`Self::limits_senders`]."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "tracksCustody",
+            "docs": [
+              "Whether the tokens locked in custody are tracked in the",
+              "[`crate::custody_ledger::CustodyLedger`], which is created along with",
+              "the deployment. Deployments migrated from before the ledger existed",
+              "don't have one, so transfers don't require it there."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "docs": [
+              "The trimmed amount, see [`OutboxItem::trimmed_amount`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "recipientChain",
+            "type": "u16"
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "The decimals of the trimmed amount."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "cancelled",
+            "docs": [
+              "Whether the sender cancelled the transfer, see",
+              "[`OutboxItem::is_cancelled`]. Taken out of the padding, so existing",
+              "items read as not cancelled."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "consumedRateLimits",
+            "docs": [
+              "Which outbound rate limits the transfer consumed from, see",
+              "[`OutboxItem::consumed_outbound_limit`]. Taken out of the padding, so",
+              "existing items read as having consumed none."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                3
+              ]
+            }
+          },
+          {
+            "name": "releasedBy",
+            "docs": [
+              "The ids of the transceivers that released the transfer, in the order",
+              "they did, alongside [`OutboxItem::released_at`]."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "releasedAt",
+            "docs": [
+              "When the transceivers in [`OutboxItem::released_by`] released the",
+              "transfer, or 0 for unused entries. [`OutboxItem::released`] stays the",
+              "source of truth for whether a transceiver has, see",
+              "[`OutboxItem::released_at`]."
+            ],
+            "type": {
+              "array": [
+                "i64",
+                8
+              ]
+            }
+          },
+          {
+            "name": "expiryTimestamp",
+            "docs": [
+              "The sender-specified deadline after which the transfer is no longer",
+              "released, or 0 if it has none. See [`OutboxItem::expiry`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "The account that paid the rent of the item, which gets it back if the",
+              "transfer expires (see [`crate::instructions::close_expired_outbox_item`]).",
+              "Zero for items created before it was recorded."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "inboundBackflow",
+            "docs": [
+              "How much sending the transfer refilled the inbound rate limit of the",
+              "recipient chain (the \"backflow\"), which is taken back if it's",
+              "cancelled or expires instead. Zero for queued transfers, and for items",
+              "created before it was recorded."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredTransceiver",
       "type": {
         "kind": "struct",
         "fields": [
@@ -532,454 +2088,1997 @@ export type NttTransceiver = {
             "type": "u8"
           },
           {
-            "name": "owner",
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "addressMatch",
             "docs": [
-              "Owner of the program."
+              "How [`Self::address`] is compared against the emitter of received",
+              "messages."
             ],
-            "type": "publicKey"
+            "type": {
+              "defined": "PeerAddressMatch"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaaBody",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "span",
+            "type": "bytes"
           },
           {
-            "name": "pendingOwner",
+            "name": "createdAt",
             "docs": [
-              "Pending next owner (before claiming ownership)."
+              "When the first chunk was staged. Once [`VaaBody::TTL`] has passed, the",
+              "account can be closed by anyone (see `cleanup_expired_vaa_body`)."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
             ],
             "type": {
-              "option": "publicKey"
+              "defined": "BridgeConfig"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeerIndex",
+      "docs": [
+        "The chain ids of the configured [`TransceiverPeer`]s, see",
+        "[`example_native_token_transfers::peer::PeerIndex`].",
+        "",
+        "NOTE: peers set before the index was introduced are only added when they're",
+        "migrated, see [`crate::wormhole::instructions::migrate_transceiver_peer`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "chainIds",
+            "type": {
+              "vec": "u16"
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "wormholeFeeStats",
+      "docs": [
+        "Cumulative accounting of the Wormhole core bridge fees paid by this transceiver.",
+        "Singleton PDA, initialized the first time a peer is registered. Deployments",
+        "that haven't registered a peer since it was added post messages without it,",
+        "see [`crate::wormhole::accounts::WormholeAccounts::fee_stats`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "mint",
+            "name": "totalWormholeFeesPaid",
             "docs": [
-              "Mint address of the token managed by this program."
+              "Total lamports transferred to the core bridge fee collector."
             ],
-            "type": "publicKey"
+            "type": "u64"
           },
           {
-            "name": "tokenProgram",
+            "name": "messagesPosted",
             "docs": [
-              "Address of the token program (token or token22). This could always be queried",
-              "from the [`mint`] account's owner, but storing it here avoids an indirection",
-              "on the client side."
+              "Number of messages posted through the core bridge."
             ],
-            "type": "publicKey"
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "wormholePeerEmitter",
+      "docs": [
+        "A Wormhole emitter registered for the peer on `chain_id`, for deployments",
+        "that run a distinct emitter per destination. Broadcasts opt into it by",
+        "passing this account. Stored in a PDA seeded by the chain id, created by",
+        "the owner with [`crate::wormhole::instructions::register_peer_emitter`].",
+        "",
+        "The emitter itself is the PDA seeded by `b\"emitter\"` and the chain id,",
+        "next to the default emitter seeded by just `b\"emitter\"`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "mode",
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "wormholeBroadcastSuppression",
+      "docs": [
+        "How long identical peer broadcasts are suppressed for. Set by the owner",
+        "with [`crate::wormhole::instructions::set_broadcast_suppression_window`].",
+        "Singleton PDA, initialized by the owner or by the first peer broadcast.",
+        "The window is zero (i.e. nothing is suppressed) until it's configured."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "window",
             "docs": [
-              "The mode that this program is running in. This is used to determine",
-              "whether the program is burning tokens or locking tokens."
+              "In seconds."
             ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "nttManagerPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
             "type": {
-              "defined": "Mode"
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "enabled",
+            "docs": [
+              "Whether transfers to and from this chain are accepted."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "outboundInFlight",
+            "docs": [
+              "Number of outbound transfers to this chain that have not been released",
+              "by any transceiver yet."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxOutboundInFlight",
+            "docs": [
+              "Cap on [`Self::outbound_in_flight`]. `None` means no cap."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "allowQueue",
+            "docs": [
+              "Whether outbound transfers to this chain may be queued when they exceed",
+              "the rate limit. When false, such transfers fail even if the sender asked",
+              "for them to be queued."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "destinationFeeBps",
+            "docs": [
+              "The execution fee the peer deducts from delivered transfers, in basis",
+              "points. Informational only: nothing is deducted here, but transfers",
+              "with a `min_amount_out` are checked against it."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "fallbackRecipient",
+            "docs": [
+              "Token account that inbound transfers from this chain are delivered to",
+              "when the recipient's token account is frozen. `None` means such",
+              "transfers can't be released until it is thawed."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "inboundInFlight",
+            "docs": [
+              "Number of inbound transfers from this chain that have been approved,",
+              "but not released yet."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "allowInboundQueue",
+            "docs": [
+              "Whether inbound transfers from this chain that exceed the inbound rate",
+              "limit are queued (released once the capacity allows). When false, they",
+              "are rejected, and can only be redeemed again once there is capacity."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "verifiedPendingMessage",
+      "docs": [
+        "A message whose VAA has been verified, but which has not been accepted yet.",
+        "This allows relayers to verify messages while the program is paused, and",
+        "then cheaply promote them into a [`ValidatedTransceiverMessage`] once",
+        "unpaused."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rentPayer",
+            "docs": [
+              "The account that paid for the rent of this account, refunded on promotion."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "fromChain",
+            "type": {
+              "defined": "ChainId"
             }
           },
           {
-            "name": "chainId",
+            "name": "emitterAddress",
             "docs": [
-              "The chain id of the chain that this program is running on. We don't",
-              "hardcode this so that the program is deployable on any potential SVM",
-              "forks."
+              "The emitter of the VAA. The peer is checked against this again on",
+              "promotion, in case it was changed while the program was paused."
             ],
             "type": {
-              "defined": "ChainId"
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
           {
-            "name": "nextTransceiverId",
+            "name": "message",
+            "type": {
+              "defined": "TransceiverMessageData<NativeTokenTransfer<Payload>>"
+            }
+          },
+          {
+            "name": "vaaTimestamp",
             "docs": [
-              "The next transceiver id to use when registering an transceiver."
+              "The timestamp of the VAA, carried over on promotion."
             ],
-            "type": "u8"
+            "type": "u32"
           },
           {
-            "name": "threshold",
+            "name": "unconsumedPayloadLen",
             "docs": [
-              "The number of transceivers that must attest to a transfer before it is",
-              "accepted."
+              "Carried over on promotion, see",
+              "[`ValidatedTransceiverMessage::unconsumed_payload_len`]."
             ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "wormholePeerBroadcast",
+      "docs": [
+        "The last broadcast of a peer, so that identical broadcasts can be",
+        "suppressed. Stored in a PDA seeded by the chain id, initialized by the",
+        "first broadcast."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
             "type": "u8"
           },
           {
-            "name": "enabledTransceivers",
+            "name": "payloadHash",
             "docs": [
-              "Bitmap of enabled transceivers.",
-              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+              "Keccak256 hash of the payload of the last broadcast."
             ],
             "type": {
-              "defined": "Bitmap"
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
           {
-            "name": "paused",
+            "name": "lastBroadcast",
             "docs": [
-              "Pause the program. This is useful for upgrades and other maintenance."
+              "Unix timestamp of the last broadcast. Zero if none has been sent yet."
             ],
-            "type": "bool"
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "wormholeHeartbeat",
+      "docs": [
+        "Tracks when the last heartbeat was broadcast, so that heartbeats can be",
+        "rate limited.",
+        "Singleton PDA, initialized by the first heartbeat."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "custody",
+            "name": "lastHeartbeat",
             "docs": [
-              "The custody account that holds tokens in locking mode."
+              "Unix timestamp of the last heartbeat. Zero if none has been sent yet."
             ],
-            "type": "publicKey"
+            "type": "i64"
           }
         ]
       }
-    },
+    }
+  ],
+  "types": [
     {
-      "name": "outboxItem",
+      "name": "Bitmap",
+      "docs": [
+        "Stored as little-endian bytes rather than a `u128` so that it can be embedded",
+        "in zero-copy accounts without imposing an alignment requirement. The borsh",
+        "encoding is the same either way."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "amount",
+            "name": "map",
             "type": {
-              "defined": "TrimmedAmount"
+              "array": [
+                "u8",
+                16
+              ]
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChainId",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "sender",
-            "type": "publicKey"
+            "name": "id",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Locking"
           },
           {
-            "name": "recipientChain",
+            "name": "Burning"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VaaBodyData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "span",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
             "type": {
               "defined": "ChainId"
             }
           },
           {
-            "name": "recipientNttManager",
+            "name": "address",
             "type": {
               "array": [
                 "u8",
                 32
               ]
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BroadcastPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PostUnverifiedMessageAccountArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seed",
+            "type": "u64"
           },
           {
-            "name": "recipientAddress",
+            "name": "offset",
+            "type": "u32"
+          },
+          {
+            "name": "chunk",
+            "type": "bytes"
+          },
+          {
+            "name": "messageSize",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PeerAddressMatch",
+      "docs": [
+        "How strictly a transceiver peer address is compared against the emitter",
+        "address of received messages."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Exact"
+          },
+          {
+            "name": "Normalized"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerOptions",
+      "docs": [
+        "Settings of [`set_transceiver_peer_with_options`] that",
+        "[`set_transceiver_peer`] leaves at their defaults. They're not part of",
+        "[`SetTransceiverPeerArgs`], so that its encoding stays the same for",
+        "existing clients."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowUnknownChain",
+            "docs": [
+              "Skip the check that `chain_id` is a known Wormhole chain id.",
+              "Only needed for chains added to Wormhole after this program was built."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "addressMatch",
+            "docs": [
+              "How `address` is compared against the emitter of received messages."
+            ],
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "defined": "PeerAddressMatch"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundOptions",
+      "docs": [
+        "Settings of [`release_outbound_with_options`] that [`release_outbound`]",
+        "leaves at their defaults. They're not part of [`ReleaseOutboundArgs`], so",
+        "that its encoding stays the same for existing clients."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxWormholeFee",
+            "docs": [
+              "The most the payer is willing to pay for the wormhole message fee.",
+              "Guards against the core bridge fee being raised between building and",
+              "landing the transaction. Unlimited when unset."
+            ],
+            "type": {
+              "option": "u64"
             }
           },
           {
-            "name": "releaseTimestamp",
-            "type": "i64"
+            "name": "consistencyLevel",
+            "docs": [
+              "VAA-encoded consistency level to post the message with. Finalized",
+              "when unset. Has to be one of the",
+              "[`Config::allowed_consistency_levels`]."
+            ],
+            "type": {
+              "option": "u8"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AddressEncoding",
+      "docs": [
+        "How a peer address is laid out in a broadcast payload.",
+        "",
+        "Wormhole universal addresses are 32 bytes, with shorter native addresses",
+        "right-aligned (left-padded with zeros). Some transceivers instead expect",
+        "shorter addresses to be left-aligned."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Universal"
           },
           {
-            "name": "released",
-            "type": {
-              "defined": "Bitmap"
-            }
+            "name": "RightPadded",
+            "fields": [
+              {
+                "name": "len",
+                "type": "u8"
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "registeredTransceiver",
+      "name": "DeliveryCostQuote",
+      "docs": [
+        "Itemized rent (in lamports) required to deliver an inbound transfer.",
+        "",
+        "NOTE: transaction fees and the guardian signatures account (which is",
+        "allocated by the verify VAA shim, and sized by the number of signatures)",
+        "are not included."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "vaaBody",
+            "docs": [
+              "The staged [`VaaBody`] account. Refunded when the message is received."
+            ],
+            "type": "u64"
           },
           {
-            "name": "id",
-            "type": "u8"
+            "name": "transceiverMessage",
+            "docs": [
+              "The [`crate::messages::ValidatedTransceiverMessage`] account."
+            ],
+            "type": "u64"
           },
           {
-            "name": "transceiverAddress",
-            "type": "publicKey"
+            "name": "inboxItem",
+            "docs": [
+              "The [`InboxItem`] account allocated by the manager on redeem."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "recipientTokenAccount",
+            "docs": [
+              "The recipient's associated token account, if it doesn't exist yet."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reclaimable",
+            "docs": [
+              "Total rent that is refunded to the payer by the end of the delivery."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "nonReclaimable",
+            "docs": [
+              "Total rent that remains locked in accounts after the delivery."
+            ],
+            "type": "u64"
           }
         ]
       }
+    }
+  ]
+}
+export const IDL: NttTransceiver = {
+  "version": "3.0.0",
+  "name": "ntt_transceiver",
+  "instructions": [
+    {
+      "name": "transceiverType",
+      "accounts": [],
+      "args": [],
+      "returns": "string"
+    },
+    {
+      "name": "setWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "deserialized as an `Account`, as it doesn't exist until the transceiver",
+            "is registered, which is checked in [`is_enabled`]."
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setWormholePeerWithOptions",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "deserialized as an `Account`, as it doesn't exist until the transceiver",
+            "is registered, which is checked in [`is_enabled`]."
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        },
+        {
+          "name": "options",
+          "type": {
+            "defined": "SetTransceiverPeerOptions"
+          }
+        }
+      ]
+    },
+    {
+      "name": "deleteWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "getWormholePeerIndex",
+      "accounts": [
+        {
+          "name": "peerIndex",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "checked in [`get_transceiver_peer_index`]."
+          ]
+        }
+      ],
+      "args": [],
+      "returns": {
+        "vec": "u16"
+      }
+    },
+    {
+      "name": "migrateWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "still in the legacy layout."
+          ]
+        },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "registerWormholePeerEmitter",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peerEmitter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
     },
     {
-      "name": "transceiverPeer",
-      "docs": [
-        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      "name": "setWormholeBroadcastSuppressionWindow",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "broadcastSuppression",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "address",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
+      "args": [
+        {
+          "name": "window",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeMessageInstructionData",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
             }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managerPeer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "exists, so that receiving the same message twice from this transceiver",
+            "is a no-op rather than an error.",
+            "The account is seeded by the message's (emitter chain, id), so if it",
+            "has already been initialized, the message has already been received",
+            "(see [`ValidatedTransceiverMessage::is_initialized`])."
+          ]
+        },
+        {
+          "name": "guardianSet",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Derivation is checked by the shim."
+          ]
+        },
+        {
+          "name": "guardianSignatures",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Ownership and discriminator are checked in [`verify_vaa_body`] before",
+            "invoking the shim."
+          ]
+        },
+        {
+          "name": "verifyVaaShim",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "guardianSetBump",
+          "type": "u8"
+        },
+        {
+          "name": "vaaBody",
+          "type": {
+            "defined": "VaaBodyData"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "vaaBody",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "span",
-            "type": "bytes"
+      "name": "receiveWormholeMessageInstructionDataWithDigest",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managerPeer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "exists, so that receiving the same message twice from this transceiver",
+            "is a no-op rather than an error.",
+            "The account is seeded by the message's (emitter chain, id), so if it",
+            "has already been initialized, the message has already been received",
+            "(see [`ValidatedTransceiverMessage::is_initialized`])."
+          ]
+        },
+        {
+          "name": "guardianSet",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Derivation is checked by the shim."
+          ]
+        },
+        {
+          "name": "guardianSignatures",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Ownership and discriminator are checked in [`verify_vaa_body`] before",
+            "invoking the shim."
+          ]
+        },
+        {
+          "name": "verifyVaaShim",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "guardianSetBump",
+          "type": "u8"
+        },
+        {
+          "name": "vaaBody",
+          "type": {
+            "defined": "VaaBodyData"
           }
-        ]
-      }
+        },
+        {
+          "name": "expectedDigest",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
     },
     {
-      "name": "bridgeData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "guardianSetIndex",
-            "docs": [
-              "The current guardian set index, used to decide which signature sets to accept."
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "lastLamports",
-            "docs": [
-              "Lamports in the collection account"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "config",
-            "docs": [
-              "Bridge configuration, which is set once upon initialization."
-            ],
-            "type": {
-              "defined": "BridgeConfig"
-            }
+      "name": "postUnverifiedWormholeMessageAccount",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "message",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PostUnverifiedMessageAccountArgs"
           }
-        ]
-      }
-    }
-  ],
-  "types": [
+        }
+      ]
+    },
     {
-      "name": "Bitmap",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "map",
-            "type": "u128"
-          }
-        ]
-      }
+      "name": "closeUnverifiedWormholeMessageAccount",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "message",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "seed",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "ChainId",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "id",
-            "type": "u16"
-          }
-        ]
-      }
+      "name": "cleanupExpiredVaaBody",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Only receives the rent."
+          ]
+        },
+        {
+          "name": "message",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "seed",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "Mode",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Locking"
-          },
-          {
-            "name": "Burning"
-          }
-        ]
-      }
+      "name": "receiveWormholeMessageAccount",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managerPeer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "message",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardianSet",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Derivation is checked by the shim."
+          ]
+        },
+        {
+          "name": "guardianSignatures",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Ownership and discriminator are checked in [`verify_vaa_body`] before",
+            "invoking the shim."
+          ]
+        },
+        {
+          "name": "verifyVaaShim",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "guardianSetBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "TrimmedAmount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "amount",
-            "type": "u64"
-          },
-          {
-            "name": "decimals",
-            "type": "u8"
+      "name": "receiveWormholeMessageAccountWithDigest",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managerPeer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "message",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardianSet",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Derivation is checked by the shim."
+          ]
+        },
+        {
+          "name": "guardianSignatures",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Ownership and discriminator are checked in [`verify_vaa_body`] before",
+            "invoking the shim."
+          ]
+        },
+        {
+          "name": "verifyVaaShim",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "guardianSetBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "u64"
+        },
+        {
+          "name": "expectedDigest",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
           }
-        ]
-      }
+        }
+      ]
+    },
+    {
+      "name": "verifyPendingWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "NOTE: unlike the receive instructions, this is allowed while the",
+            "program is paused. The message is only accepted once it's promoted",
+            "(see [`promote_pending_message`])."
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "message",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardianSet",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Derivation is checked by the shim."
+          ]
+        },
+        {
+          "name": "guardianSignatures",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Ownership and discriminator are checked in [`verify_vaa_body`] before",
+            "invoking the shim."
+          ]
+        },
+        {
+          "name": "verifyVaaShim",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "guardianSetBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "VaaBodyData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "span",
-            "type": "bytes"
-          }
-        ]
-      }
+      "name": "promotePendingWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managerPeer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Promoting a message that this transceiver has already received is a",
+            "no-op, other than closing the pending account."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "SetTransceiverPeerArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "chainId",
-            "type": {
-              "defined": "ChainId"
+      "name": "releaseWormholeOutbound",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
             }
-          },
-          {
-            "name": "address",
-            "type": {
-              "array": [
-                "u8",
-                32
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "recipient chain"
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The registration of this program. Its id is the bit marked in the",
+            "outbox item's `released` bitmap."
+          ]
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "postMessageShim",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormholePostMessageShimEa",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feeStats",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Records the fee when given. Optional, as it's only created when a peer",
+                "is registered."
+              ]
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
               ]
             }
+          ]
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItemSigner",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
           }
-        ]
-      }
-    },
-    {
-      "name": "BroadcastPeerArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "chainId",
-            "type": "u16"
-          }
-        ]
-      }
-    },
-    {
-      "name": "ReleaseOutboundArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "revertOnDelay",
-            "type": "bool"
-          }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "PostUnverifiedMessageAccountArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "seed",
-            "type": "u64"
-          },
-          {
-            "name": "offset",
-            "type": "u32"
-          },
-          {
-            "name": "chunk",
-            "type": "bytes"
-          },
-          {
-            "name": "messageSize",
-            "type": "u32"
+      "name": "releaseWormholeOutboundWithOptions",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "recipient chain"
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The registration of this program. Its id is the bit marked in the",
+            "outbox item's `released` bitmap."
+          ]
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "postMessageShim",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormholePostMessageShimEa",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feeStats",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Records the fee when given. Optional, as it's only created when a peer",
+                "is registered."
+              ]
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItemSigner",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
           }
-        ]
-      }
-    },
-    {
-      "name": "BridgeConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "guardianSetExpirationTime",
-            "docs": [
-              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
-              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
-              "this period we still trust the old guardian set."
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "fee",
-            "docs": [
-              "Amount of lamports that needs to be paid to the protocol to post a message"
-            ],
-            "type": "u64"
+        },
+        {
+          "name": "options",
+          "type": {
+            "defined": "ReleaseOutboundOptions"
           }
-        ]
-      }
-    }
-  ]
-}
-export const IDL: NttTransceiver = {
-  "version": "3.0.0",
-  "name": "ntt_transceiver",
-  "instructions": [
-    {
-      "name": "transceiverType",
-      "accounts": [],
-      "args": [],
-      "returns": "string"
+        }
+      ]
     },
     {
-      "name": "setWormholePeer",
+      "name": "broadcastWormholeId",
       "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
         {
           "name": "config",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "enforced by the [`CpiContext`] call in [`post_message`].",
+            "The seeds constraint ensures that this is the correct address: the",
+            "emitter registered in [`Self::peer_emitter`] if given, and the default",
+            "one otherwise."
+          ]
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "postMessageShim",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormholePostMessageShimEa",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feeStats",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Records the fee when given. Optional, as it's only created when a peer",
+                "is registered."
+              ]
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        },
+        {
+          "name": "tokenMetadata",
           "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "peer",
-          "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "in [`token_metadata_hash`]. It's optional, as not every token has",
+            "Metaplex metadata."
+          ]
         },
         {
-          "name": "systemProgram",
+          "name": "peerEmitter",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The emitter to broadcast from instead of the default one, see",
+            "[`WormholePeerEmitter`]."
+          ]
         }
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SetTransceiverPeerArgs"
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "receiveWormholeMessageInstructionData",
+      "name": "broadcastWormholePeer",
       "accounts": [
         {
           "name": "payer",
@@ -988,13 +4087,8 @@ export const IDL: NttTransceiver = {
         },
         {
           "name": "config",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
+          "isMut": false,
+          "isSigner": false
         },
         {
           "name": "peer",
@@ -1002,60 +4096,96 @@ export const IDL: NttTransceiver = {
           "isSigner": false
         },
         {
-          "name": "transceiverMessage",
+          "name": "wormholeMessage",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "guardianSet",
+          "name": "emitter",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "Derivation is checked by the shim."
+            "emitter registered in [`Self::peer_emitter`] if given, and the default",
+            "one otherwise."
           ]
         },
         {
-          "name": "guardianSignatures",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Ownership ownership and discriminator is checked by the shim."
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "postMessageShim",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormholePostMessageShimEa",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feeStats",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Records the fee when given. Optional, as it's only created when a peer",
+                "is registered."
+              ]
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
           ]
         },
         {
-          "name": "verifyVaaShim",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "guardianSetBump",
-          "type": "u8"
-        },
-        {
-          "name": "vaaBody",
-          "type": {
-            "defined": "VaaBodyData"
-          }
-        }
-      ]
-    },
-    {
-      "name": "postUnverifiedWormholeMessageAccount",
-      "accounts": [
-        {
-          "name": "payer",
+          "name": "broadcastSuppression",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "message",
+          "name": "peerBroadcast",
           "isMut": true,
           "isSigner": false
         },
@@ -1063,19 +4193,29 @@ export const IDL: NttTransceiver = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "peerEmitter",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The emitter to broadcast from instead of the default one, see",
+            "[`WormholePeerEmitter`]."
+          ]
         }
       ],
       "args": [
         {
           "name": "args",
           "type": {
-            "defined": "PostUnverifiedMessageAccountArgs"
+            "defined": "BroadcastPeerArgs"
           }
         }
       ]
     },
     {
-      "name": "closeUnverifiedWormholeMessageAccount",
+      "name": "broadcastWormholePeerWithAddressEncoding",
       "accounts": [
         {
           "name": "payer",
@@ -1083,96 +4223,142 @@ export const IDL: NttTransceiver = {
           "isSigner": true
         },
         {
-          "name": "message",
-          "isMut": true,
+          "name": "config",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "peer",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "seed",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "receiveWormholeMessageAccount",
-      "accounts": [
+        },
         {
-          "name": "payer",
+          "name": "wormholeMessage",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "config",
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "emitter registered in [`Self::peer_emitter`] if given, and the default",
+            "one otherwise."
+          ]
+        },
+        {
+          "name": "wormhole",
           "accounts": [
             {
-              "name": "config",
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
               "isMut": false,
               "isSigner": false
-            }
-          ]
-        },
-        {
-          "name": "peer",
-          "isMut": false,
-          "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "postMessageShim",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormholePostMessageShimEa",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feeStats",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Records the fee when given. Optional, as it's only created when a peer",
+                "is registered."
+              ]
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
         },
         {
-          "name": "message",
+          "name": "broadcastSuppression",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "transceiverMessage",
+          "name": "peerBroadcast",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "guardianSet",
+          "name": "systemProgram",
           "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Derivation is checked by the shim."
-          ]
+          "isSigner": false
         },
         {
-          "name": "guardianSignatures",
+          "name": "peerEmitter",
           "isMut": false,
           "isSigner": false,
+          "isOptional": true,
           "docs": [
-            "Ownership ownership and discriminator is checked by the shim."
+            "The emitter to broadcast from instead of the default one, see",
+            "[`WormholePeerEmitter`]."
           ]
-        },
-        {
-          "name": "verifyVaaShim",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "guardianSetBump",
-          "type": "u8"
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
         },
         {
-          "name": "seed",
-          "type": "u64"
+          "name": "addressEncoding",
+          "type": {
+            "defined": "AddressEncoding"
+          }
         }
       ]
     },
     {
-      "name": "releaseWormholeOutbound",
+      "name": "broadcastWormholeHeartbeat",
       "accounts": [
         {
           "name": "payer",
@@ -1181,22 +4367,12 @@ export const IDL: NttTransceiver = {
         },
         {
           "name": "config",
-          "accounts": [
-            {
-              "name": "config",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
-        },
-        {
-          "name": "outboxItem",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "transceiver",
-          "isMut": false,
+          "name": "heartbeat",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1207,7 +4383,11 @@ export const IDL: NttTransceiver = {
         {
           "name": "emitter",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "enforced by the [`CpiContext`] call in [`post_message`].",
+            "The seeds constraint ensures that this is the correct address"
+          ]
         },
         {
           "name": "wormhole",
@@ -1247,6 +4427,16 @@ export const IDL: NttTransceiver = {
               "isMut": false,
               "isSigner": false
             },
+            {
+              "name": "feeStats",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Records the fee when given. Optional, as it's only created when a peer",
+                "is registered."
+              ]
+            },
             {
               "name": "clock",
               "isMut": false,
@@ -1256,206 +4446,614 @@ export const IDL: NttTransceiver = {
               "name": "rent",
               "isMut": false,
               "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
+            }
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "getWormholeFeeStats",
+      "accounts": [
+        {
+          "name": "feeStats",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "WormholeFeeStats"
+      }
+    },
+    {
+      "name": "quoteDeliveryCost",
+      "accounts": [],
+      "args": [
+        {
+          "name": "vaaSize",
+          "type": "u32"
+        },
+        {
+          "name": "recipientHasAta",
+          "type": "bool"
+        }
+      ],
+      "returns": {
+        "defined": "DeliveryCostQuote"
+      }
+    }
+  ],
+  "accounts": [
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "docs": [
+              "Pending next owner (before claiming ownership)."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the token managed by this program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "Address of the token program (token or token22). This could always be queried",
+              "from the [`mint`] account's owner, but storing it here avoids an indirection",
+              "on the client side."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "The mode that this program is running in. This is used to determine",
+              "whether the program is burning tokens or locking tokens."
+            ],
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The chain id of the chain that this program is running on. We don't",
+              "hardcode this so that the program is deployable on any potential SVM",
+              "forks."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "docs": [
+              "The next transceiver id to use when registering an transceiver."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of transceivers that must attest to a transfer before it is",
+              "accepted."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "docs": [
+              "Bitmap of enabled transceivers.",
+              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "pausedOutbound",
+            "docs": [
+              "Pause outbound transfers (sending and releasing them). This is useful",
+              "for upgrades and other maintenance, or to halt outflows during an",
+              "incident without stranding inbound transfers (see [`paused_inbound`])."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "The custody account that holds tokens in locking mode."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "routesDefaultEnabled",
+            "docs": [
+              "Whether routes to newly registered peers are enabled straight away.",
+              "When false, the owner has to enable each route explicitly (via",
+              "`enable_route`) before any transfers to or from that chain are accepted."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "mintDecimals",
+            "docs": [
+              "Decimals of [`mint`], cached here so that inbound amounts can be",
+              "untrimmed without loading the mint account."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "receiveHook",
+            "docs": [
+              "Program notified whenever a transceiver receives a message (see",
+              "[`crate::receive_hook`])."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "feeCollector",
+            "docs": [
+              "Wormhole core bridge fee collector that message fees are paid into.",
+              "Defaults to the account derived from the core bridge program, and can",
+              "be updated (via `set_fee_collector`) if the core bridge changes it."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "confidentialTransfers",
+            "docs": [
+              "Whether [`mint`] has the Token-2022 confidential transfer extension, in",
+              "which case inbound transfers are only released to recipients that",
+              "accept non-confidential credits (see [`crate::confidential_transfer`])."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "dustAccount",
+            "docs": [
+              "Token account that dust trimmed off outbound transfers is collected",
+              "into (set via `set_dust_account`). When unset, the dust is left in the",
+              "sender's account."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "limitsManager",
+            "docs": [
+              "Account that can tune rate limits on behalf of the owner (see",
+              "[`Role::LimitsManager`]). Set and revoked via `set_limits_manager`."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "maxVaaAge",
+            "docs": [
+              "How old (in seconds) a VAA may be when it's received, measured from",
+              "the timestamp in its body. Older VAAs are rejected by the transceivers.",
+              "Set via `set_max_vaa_age`, unlimited when unset."
+            ],
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "rejectUnconsumedPayload",
+            "docs": [
+              "What happens to messages whose additional payload this program doesn't",
+              "decode (see [`crate::transfer::Payload`]) when there's no recipient",
+              "callback to pass it on to. When false, the payload is dropped and the",
+              "transfer goes through. When true, redeeming the message fails. Set via",
+              "`set_reject_unconsumed_payload`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "decommissionTimestamp",
+            "docs": [
+              "When the deployment is being decommissioned (see",
+              "`begin_decommission`), the time from which `finalize_decommission`",
+              "can be called. Outbound transfers are rejected in the meantime, while",
+              "inbound ones can still be redeemed and released."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "terminated",
+            "docs": [
+              "Whether the deployment has been decommissioned for good (see",
+              "`finalize_decommission`)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowedConsistencyLevels",
+            "docs": [
+              "Consistency levels outbound transfers may be released with, as a",
+              "bitmap indexed by the level's VAA encoding (see",
+              "[`CONSISTENCY_LEVEL_FINALIZED`] and [`CONSISTENCY_LEVEL_CONFIRMED`]).",
+              "Only finalized by default. Set via `set_allowed_consistency_levels`."
+            ],
+            "type": {
+              "defined": "Bitmap"
             }
-          ]
-        },
-        {
-          "name": "manager",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "outboxItemSigner",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "ReleaseOutboundArgs"
+          },
+          {
+            "name": "pausedInbound",
+            "docs": [
+              "Pause inbound transfers (receiving, redeeming and releasing them).",
+              "Set together with [`paused_outbound`] via `set_paused`, or on its own",
+              "via `set_inbound_paused`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "senderLimit",
+            "docs": [
+              "How much a single sender may transfer out per day (see",
+              "[`crate::queue::outbox::SenderRateLimit`]), unless it has an override.",
+              "Set via `set_sender_limit`, unlimited when unset."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "perChainOutboundLimits",
+            "docs": [
+              "Whether transfers to a chain with its own",
+              "[`crate::queue::outbox::ChainOutboxRateLimit`] consume from it rather",
+              "than the global [`crate::queue::outbox::OutboxRateLimit`]. Set via",
+              "`set_per_chain_outbound_limits`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "senderLimitOverrides",
+            "docs": [
+              "Whether an override has been set for any sender with",
+              "`set_sender_limit_override`, which limits that sender even while",
+              "[`Self::sender_limit`] is unset. Never cleared, see",
+              "[`Self::limits_senders`]."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "tracksCustody",
+            "docs": [
+              "Whether the tokens locked in custody are tracked in the",
+              "[`crate::custody_ledger::CustodyLedger`], which is created along with",
+              "the deployment. Deployments migrated from before the ledger existed",
+              "don't have one, so transfers don't require it there."
+            ],
+            "type": "bool"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "broadcastWormholeId",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "config",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "wormholeMessage",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "emitter",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "enforced by the [`CpiContext`] call in [`post_message`].",
-            "The seeds constraint ensures that this is the correct address"
-          ]
-        },
-        {
-          "name": "wormhole",
-          "accounts": [
-            {
-              "name": "bridge",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "feeCollector",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "sequence",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "program",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "postMessageShim",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "wormholePostMessageShimEa",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "clock",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "rent",
-              "isMut": false,
-              "isSigner": false
+      "name": "outboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "docs": [
+              "The trimmed amount, see [`OutboxItem::trimmed_amount`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
             }
-          ]
-        }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "recipientChain",
+            "type": "u16"
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "The decimals of the trimmed amount."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "cancelled",
+            "docs": [
+              "Whether the sender cancelled the transfer, see",
+              "[`OutboxItem::is_cancelled`]. Taken out of the padding, so existing",
+              "items read as not cancelled."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "consumedRateLimits",
+            "docs": [
+              "Which outbound rate limits the transfer consumed from, see",
+              "[`OutboxItem::consumed_outbound_limit`]. Taken out of the padding, so",
+              "existing items read as having consumed none."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                3
+              ]
+            }
+          },
+          {
+            "name": "releasedBy",
+            "docs": [
+              "The ids of the transceivers that released the transfer, in the order",
+              "they did, alongside [`OutboxItem::released_at`]."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "releasedAt",
+            "docs": [
+              "When the transceivers in [`OutboxItem::released_by`] released the",
+              "transfer, or 0 for unused entries. [`OutboxItem::released`] stays the",
+              "source of truth for whether a transceiver has, see",
+              "[`OutboxItem::released_at`]."
+            ],
+            "type": {
+              "array": [
+                "i64",
+                8
+              ]
+            }
+          },
+          {
+            "name": "expiryTimestamp",
+            "docs": [
+              "The sender-specified deadline after which the transfer is no longer",
+              "released, or 0 if it has none. See [`OutboxItem::expiry`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "The account that paid the rent of the item, which gets it back if the",
+              "transfer expires (see [`crate::instructions::close_expired_outbox_item`]).",
+              "Zero for items created before it was recorded."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "inboundBackflow",
+            "docs": [
+              "How much sending the transfer refilled the inbound rate limit of the",
+              "recipient chain (the \"backflow\"), which is taken back if it's",
+              "cancelled or expires instead. Zero for queued transfers, and for items",
+              "created before it was recorded."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
       ],
-      "args": []
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "addressMatch",
+            "docs": [
+              "How [`Self::address`] is compared against the emitter of received",
+              "messages."
+            ],
+            "type": {
+              "defined": "PeerAddressMatch"
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "broadcastWormholePeer",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "config",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "peer",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "wormholeMessage",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "emitter",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "wormhole",
-          "accounts": [
-            {
-              "name": "bridge",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "feeCollector",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "sequence",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "program",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "postMessageShim",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "wormholePostMessageShimEa",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "clock",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "rent",
-              "isMut": false,
-              "isSigner": false
+      "name": "vaaBody",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "span",
+            "type": "bytes"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "When the first chunk was staged. Once [`VaaBody::TTL`] has passed, the",
+              "account can be closed by anyone (see `cleanup_expired_vaa_body`)."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
+            "type": {
+              "defined": "BridgeConfig"
             }
-          ]
-        }
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeerIndex",
+      "docs": [
+        "The chain ids of the configured [`TransceiverPeer`]s, see",
+        "[`example_native_token_transfers::peer::PeerIndex`].",
+        "",
+        "NOTE: peers set before the index was introduced are only added when they're",
+        "migrated, see [`crate::wormhole::instructions::migrate_transceiver_peer`]."
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "BroadcastPeerArgs"
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "chainIds",
+            "type": {
+              "vec": "u16"
+            }
           }
-        }
-      ]
-    }
-  ],
-  "accounts": [
+        ]
+      }
+    },
     {
-      "name": "config",
+      "name": "wormholeFeeStats",
+      "docs": [
+        "Cumulative accounting of the Wormhole core bridge fees paid by this transceiver.",
+        "Singleton PDA, initialized the first time a peer is registered. Deployments",
+        "that haven't registered a peer since it was added post messages without it,",
+        "see [`crate::wormhole::accounts::WormholeAccounts::fee_stats`]."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
@@ -1464,132 +5062,200 @@ export const IDL: NttTransceiver = {
             "type": "u8"
           },
           {
-            "name": "owner",
+            "name": "totalWormholeFeesPaid",
             "docs": [
-              "Owner of the program."
+              "Total lamports transferred to the core bridge fee collector."
             ],
-            "type": "publicKey"
+            "type": "u64"
           },
           {
-            "name": "pendingOwner",
+            "name": "messagesPosted",
             "docs": [
-              "Pending next owner (before claiming ownership)."
+              "Number of messages posted through the core bridge."
             ],
-            "type": {
-              "option": "publicKey"
-            }
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "wormholePeerEmitter",
+      "docs": [
+        "A Wormhole emitter registered for the peer on `chain_id`, for deployments",
+        "that run a distinct emitter per destination. Broadcasts opt into it by",
+        "passing this account. Stored in a PDA seeded by the chain id, created by",
+        "the owner with [`crate::wormhole::instructions::register_peer_emitter`].",
+        "",
+        "The emitter itself is the PDA seeded by `b\"emitter\"` and the chain id,",
+        "next to the default emitter seeded by just `b\"emitter\"`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "mint",
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "wormholeBroadcastSuppression",
+      "docs": [
+        "How long identical peer broadcasts are suppressed for. Set by the owner",
+        "with [`crate::wormhole::instructions::set_broadcast_suppression_window`].",
+        "Singleton PDA, initialized by the owner or by the first peer broadcast.",
+        "The window is zero (i.e. nothing is suppressed) until it's configured."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "window",
             "docs": [
-              "Mint address of the token managed by this program."
+              "In seconds."
             ],
-            "type": "publicKey"
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "nttManagerPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "tokenProgram",
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "enabled",
             "docs": [
-              "Address of the token program (token or token22). This could always be queried",
-              "from the [`mint`] account's owner, but storing it here avoids an indirection",
-              "on the client side."
+              "Whether transfers to and from this chain are accepted."
             ],
-            "type": "publicKey"
+            "type": "bool"
           },
           {
-            "name": "mode",
+            "name": "outboundInFlight",
             "docs": [
-              "The mode that this program is running in. This is used to determine",
-              "whether the program is burning tokens or locking tokens."
+              "Number of outbound transfers to this chain that have not been released",
+              "by any transceiver yet."
             ],
-            "type": {
-              "defined": "Mode"
-            }
+            "type": "u64"
           },
           {
-            "name": "chainId",
+            "name": "maxOutboundInFlight",
             "docs": [
-              "The chain id of the chain that this program is running on. We don't",
-              "hardcode this so that the program is deployable on any potential SVM",
-              "forks."
+              "Cap on [`Self::outbound_in_flight`]. `None` means no cap."
             ],
             "type": {
-              "defined": "ChainId"
+              "option": "u64"
             }
           },
           {
-            "name": "nextTransceiverId",
+            "name": "allowQueue",
             "docs": [
-              "The next transceiver id to use when registering an transceiver."
+              "Whether outbound transfers to this chain may be queued when they exceed",
+              "the rate limit. When false, such transfers fail even if the sender asked",
+              "for them to be queued."
             ],
-            "type": "u8"
+            "type": "bool"
           },
           {
-            "name": "threshold",
+            "name": "destinationFeeBps",
             "docs": [
-              "The number of transceivers that must attest to a transfer before it is",
-              "accepted."
+              "The execution fee the peer deducts from delivered transfers, in basis",
+              "points. Informational only: nothing is deducted here, but transfers",
+              "with a `min_amount_out` are checked against it."
             ],
-            "type": "u8"
+            "type": "u16"
           },
           {
-            "name": "enabledTransceivers",
+            "name": "fallbackRecipient",
             "docs": [
-              "Bitmap of enabled transceivers.",
-              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+              "Token account that inbound transfers from this chain are delivered to",
+              "when the recipient's token account is frozen. `None` means such",
+              "transfers can't be released until it is thawed."
             ],
             "type": {
-              "defined": "Bitmap"
+              "option": "publicKey"
             }
           },
           {
-            "name": "paused",
+            "name": "inboundInFlight",
             "docs": [
-              "Pause the program. This is useful for upgrades and other maintenance."
+              "Number of inbound transfers from this chain that have been approved,",
+              "but not released yet."
             ],
-            "type": "bool"
+            "type": "u64"
           },
           {
-            "name": "custody",
+            "name": "allowInboundQueue",
             "docs": [
-              "The custody account that holds tokens in locking mode."
+              "Whether inbound transfers from this chain that exceed the inbound rate",
+              "limit are queued (released once the capacity allows). When false, they",
+              "are rejected, and can only be redeemed again once there is capacity."
             ],
-            "type": "publicKey"
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "outboxItem",
+      "name": "verifiedPendingMessage",
+      "docs": [
+        "A message whose VAA has been verified, but which has not been accepted yet.",
+        "This allows relayers to verify messages while the program is paused, and",
+        "then cheaply promote them into a [`ValidatedTransceiverMessage`] once",
+        "unpaused."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "amount",
-            "type": {
-              "defined": "TrimmedAmount"
-            }
-          },
-          {
-            "name": "sender",
+            "name": "rentPayer",
+            "docs": [
+              "The account that paid for the rent of this account, refunded on promotion."
+            ],
             "type": "publicKey"
           },
           {
-            "name": "recipientChain",
+            "name": "fromChain",
             "type": {
               "defined": "ChainId"
             }
           },
           {
-            "name": "recipientNttManager",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "recipientAddress",
+            "name": "emitterAddress",
+            "docs": [
+              "The emitter of the VAA. The peer is checked against this again on",
+              "promotion, in case it was changed while the program was paused."
+            ],
             "type": {
               "array": [
                 "u8",
@@ -1598,42 +5264,35 @@ export const IDL: NttTransceiver = {
             }
           },
           {
-            "name": "releaseTimestamp",
-            "type": "i64"
-          },
-          {
-            "name": "released",
+            "name": "message",
             "type": {
-              "defined": "Bitmap"
+              "defined": "TransceiverMessageData<NativeTokenTransfer<Payload>>"
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "registeredTransceiver",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
           },
           {
-            "name": "id",
-            "type": "u8"
+            "name": "vaaTimestamp",
+            "docs": [
+              "The timestamp of the VAA, carried over on promotion."
+            ],
+            "type": "u32"
           },
           {
-            "name": "transceiverAddress",
-            "type": "publicKey"
+            "name": "unconsumedPayloadLen",
+            "docs": [
+              "Carried over on promotion, see",
+              "[`ValidatedTransceiverMessage::unconsumed_payload_len`]."
+            ],
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "transceiverPeer",
+      "name": "wormholePeerBroadcast",
       "docs": [
-        "A peer on another chain. Stored in a PDA seeded by the chain id."
+        "The last broadcast of a peer, so that identical broadcasts can be",
+        "suppressed. Stored in a PDA seeded by the chain id, initialized by the",
+        "first broadcast."
       ],
       "type": {
         "kind": "struct",
@@ -1643,56 +5302,47 @@ export const IDL: NttTransceiver = {
             "type": "u8"
           },
           {
-            "name": "address",
+            "name": "payloadHash",
+            "docs": [
+              "Keccak256 hash of the payload of the last broadcast."
+            ],
             "type": {
               "array": [
                 "u8",
                 32
               ]
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "vaaBody",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "span",
-            "type": "bytes"
+            "name": "lastBroadcast",
+            "docs": [
+              "Unix timestamp of the last broadcast. Zero if none has been sent yet."
+            ],
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "bridgeData",
+      "name": "wormholeHeartbeat",
+      "docs": [
+        "Tracks when the last heartbeat was broadcast, so that heartbeats can be",
+        "rate limited.",
+        "Singleton PDA, initialized by the first heartbeat."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "guardianSetIndex",
-            "docs": [
-              "The current guardian set index, used to decide which signature sets to accept."
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "lastLamports",
-            "docs": [
-              "Lamports in the collection account"
-            ],
-            "type": "u64"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "config",
+            "name": "lastHeartbeat",
             "docs": [
-              "Bridge configuration, which is set once upon initialization."
+              "Unix timestamp of the last heartbeat. Zero if none has been sent yet."
             ],
-            "type": {
-              "defined": "BridgeConfig"
-            }
+            "type": "i64"
           }
         ]
       }
@@ -1701,12 +5351,22 @@ export const IDL: NttTransceiver = {
   "types": [
     {
       "name": "Bitmap",
+      "docs": [
+        "Stored as little-endian bytes rather than a `u128` so that it can be embedded",
+        "in zero-copy accounts without imposing an alignment requirement. The borsh",
+        "encoding is the same either way."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "map",
-            "type": "u128"
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
           }
         ]
       }
@@ -1737,22 +5397,6 @@ export const IDL: NttTransceiver = {
         ]
       }
     },
-    {
-      "name": "TrimmedAmount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "amount",
-            "type": "u64"
-          },
-          {
-            "name": "decimals",
-            "type": "u8"
-          }
-        ]
-      }
-    },
     {
       "name": "VaaBodyData",
       "type": {
@@ -1859,6 +5503,174 @@ export const IDL: NttTransceiver = {
           }
         ]
       }
+    },
+    {
+      "name": "PeerAddressMatch",
+      "docs": [
+        "How strictly a transceiver peer address is compared against the emitter",
+        "address of received messages."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Exact"
+          },
+          {
+            "name": "Normalized"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerOptions",
+      "docs": [
+        "Settings of [`set_transceiver_peer_with_options`] that",
+        "[`set_transceiver_peer`] leaves at their defaults. They're not part of",
+        "[`SetTransceiverPeerArgs`], so that its encoding stays the same for",
+        "existing clients."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowUnknownChain",
+            "docs": [
+              "Skip the check that `chain_id` is a known Wormhole chain id.",
+              "Only needed for chains added to Wormhole after this program was built."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "addressMatch",
+            "docs": [
+              "How `address` is compared against the emitter of received messages."
+            ],
+            "type": {
+              "defined": "PeerAddressMatch"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundOptions",
+      "docs": [
+        "Settings of [`release_outbound_with_options`] that [`release_outbound`]",
+        "leaves at their defaults. They're not part of [`ReleaseOutboundArgs`], so",
+        "that its encoding stays the same for existing clients."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxWormholeFee",
+            "docs": [
+              "The most the payer is willing to pay for the wormhole message fee.",
+              "Guards against the core bridge fee being raised between building and",
+              "landing the transaction. Unlimited when unset."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "consistencyLevel",
+            "docs": [
+              "VAA-encoded consistency level to post the message with. Finalized",
+              "when unset. Has to be one of the",
+              "[`Config::allowed_consistency_levels`]."
+            ],
+            "type": {
+              "option": "u8"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AddressEncoding",
+      "docs": [
+        "How a peer address is laid out in a broadcast payload.",
+        "",
+        "Wormhole universal addresses are 32 bytes, with shorter native addresses",
+        "right-aligned (left-padded with zeros). Some transceivers instead expect",
+        "shorter addresses to be left-aligned."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Universal"
+          },
+          {
+            "name": "RightPadded",
+            "fields": [
+              {
+                "name": "len",
+                "type": "u8"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "DeliveryCostQuote",
+      "docs": [
+        "Itemized rent (in lamports) required to deliver an inbound transfer.",
+        "",
+        "NOTE: transaction fees and the guardian signatures account (which is",
+        "allocated by the verify VAA shim, and sized by the number of signatures)",
+        "are not included."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vaaBody",
+            "docs": [
+              "The staged [`VaaBody`] account. Refunded when the message is received."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "transceiverMessage",
+            "docs": [
+              "The [`crate::messages::ValidatedTransceiverMessage`] account."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "inboxItem",
+            "docs": [
+              "The [`InboxItem`] account allocated by the manager on redeem."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "recipientTokenAccount",
+            "docs": [
+              "The recipient's associated token account, if it doesn't exist yet."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reclaimable",
+            "docs": [
+              "Total rent that is refunded to the payer by the end of the delivery."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "nonReclaimable",
+            "docs": [
+              "Total rent that remains locked in accounts after the delivery."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     }
   ]
 }
@@ -31,6 +31,11 @@ export type NttTransceiverLegacy = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -69,6 +74,16 @@ export type NttTransceiverLegacy = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "managerPeer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The manager peer is also checked in [`crate::instructions::redeem`],",
+            "but checking it here already rejects messages from the wrong manager",
+            "before they're stored."
+          ]
+        },
         {
           "name": "vaa",
           "isMut": false,
@@ -77,7 +92,10 @@ export type NttTransceiverLegacy = {
         {
           "name": "transceiverMessage",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "been received (see [`ValidatedTransceiverMessage::is_initialized`])."
+          ]
         },
         {
           "name": "systemProgram",
@@ -110,10 +128,19 @@ export type NttTransceiverLegacy = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "transceiver",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "The registration of this program. Its id is the bit marked in the",
+            "outbox item's `released` bitmap."
+          ]
         },
         {
           "name": "wormholeMessage",
@@ -162,6 +189,16 @@ export type NttTransceiverLegacy = {
               "name": "rent",
               "isMut": false,
               "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
             }
           ]
         },
@@ -254,8 +291,28 @@ export type NttTransceiverLegacy = {
               "name": "rent",
               "isMut": false,
               "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
             }
           ]
+        },
+        {
+          "name": "tokenMetadata",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "in [`token_metadata_hash`]. It's optional, as not every token has",
+            "Metaplex metadata."
+          ]
         }
       ],
       "args": []
@@ -325,6 +382,16 @@ export type NttTransceiverLegacy = {
               "name": "rent",
               "isMut": false,
               "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
             }
           ]
         }
@@ -428,9 +495,11 @@ export type NttTransceiverLegacy = {
             }
           },
           {
-            "name": "paused",
+            "name": "pausedOutbound",
             "docs": [
-              "Pause the program. This is useful for upgrades and other maintenance."
+              "Pause outbound transfers (sending and releasing them). This is useful",
+              "for upgrades and other maintenance, or to halt outflows during an",
+              "incident without stranding inbound transfers (see [`paused_inbound`])."
             ],
             "type": "bool"
           },
@@ -440,6 +509,176 @@ export type NttTransceiverLegacy = {
               "The custody account that holds tokens in locking mode."
             ],
             "type": "publicKey"
+          },
+          {
+            "name": "routesDefaultEnabled",
+            "docs": [
+              "Whether routes to newly registered peers are enabled straight away.",
+              "When false, the owner has to enable each route explicitly (via",
+              "`enable_route`) before any transfers to or from that chain are accepted."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "mintDecimals",
+            "docs": [
+              "Decimals of [`mint`], cached here so that inbound amounts can be",
+              "untrimmed without loading the mint account."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "receiveHook",
+            "docs": [
+              "Program notified whenever a transceiver receives a message (see",
+              "[`crate::receive_hook`])."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "feeCollector",
+            "docs": [
+              "Wormhole core bridge fee collector that message fees are paid into.",
+              "Defaults to the account derived from the core bridge program, and can",
+              "be updated (via `set_fee_collector`) if the core bridge changes it."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "confidentialTransfers",
+            "docs": [
+              "Whether [`mint`] has the Token-2022 confidential transfer extension, in",
+              "which case inbound transfers are only released to recipients that",
+              "accept non-confidential credits (see [`crate::confidential_transfer`])."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "dustAccount",
+            "docs": [
+              "Token account that dust trimmed off outbound transfers is collected",
+              "into (set via `set_dust_account`). When unset, the dust is left in the",
+              "sender's account."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "limitsManager",
+            "docs": [
+              "Account that can tune rate limits on behalf of the owner (see",
+              "[`Role::LimitsManager`]). Set and revoked via `set_limits_manager`."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "maxVaaAge",
+            "docs": [
+              "How old (in seconds) a VAA may be when it's received, measured from",
+              "the timestamp in its body. Older VAAs are rejected by the transceivers.",
+              "Set via `set_max_vaa_age`, unlimited when unset."
+            ],
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "rejectUnconsumedPayload",
+            "docs": [
+              "What happens to messages whose additional payload this program doesn't",
+              "decode (see [`crate::transfer::Payload`]) when there's no recipient",
+              "callback to pass it on to. When false, the payload is dropped and the",
+              "transfer goes through. When true, redeeming the message fails. Set via",
+              "`set_reject_unconsumed_payload`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "decommissionTimestamp",
+            "docs": [
+              "When the deployment is being decommissioned (see",
+              "`begin_decommission`), the time from which `finalize_decommission`",
+              "can be called. Outbound transfers are rejected in the meantime, while",
+              "inbound ones can still be redeemed and released."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "terminated",
+            "docs": [
+              "Whether the deployment has been decommissioned for good (see",
+              "`finalize_decommission`)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowedConsistencyLevels",
+            "docs": [
+              "Consistency levels outbound transfers may be released with, as a",
+              "bitmap indexed by the level's VAA encoding (see",
+              "[`CONSISTENCY_LEVEL_FINALIZED`] and [`CONSISTENCY_LEVEL_CONFIRMED`]).",
+              "Only finalized by default. Set via `set_allowed_consistency_levels`."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "pausedInbound",
+            "docs": [
+              "Pause inbound transfers (receiving, redeeming and releasing them).",
+              "Set together with [`paused_outbound`] via `set_paused`, or on its own",
+              "via `set_inbound_paused`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "senderLimit",
+            "docs": [
+              "How much a single sender may transfer out per day (see",
+              "[`crate::queue::outbox::SenderRateLimit`]), unless it has an override.",
+              "Set via `set_sender_limit`, unlimited when unset."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "perChainOutboundLimits",
+            "docs": [
+              "Whether transfers to a chain with its own",
+              "[`crate::queue::outbox::ChainOutboxRateLimit`] consume from it rather",
+              "than the global [`crate::queue::outbox::OutboxRateLimit`]. Set via",
+              "`set_per_chain_outbound_limits`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "senderLimitOverrides",
+            "docs": [
+              "Whether an override has been set for any sender with",
+              "`set_sender_limit_override`, which limits that sender even while",
+              "[`Self::sender_limit`] is unset. Never cleared, see",
+              "[`Self::limits_senders`]."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "tracksCustody",
+            "docs": [
+              "Whether the tokens locked in custody are tracked in the",
+              "[`crate::custody_ledger::CustodyLedger`], which is created along with",
+              "the deployment. Deployments migrated from before the ledger existed",
+              "don't have one, so transfers don't require it there."
+            ],
+            "type": "bool"
           }
         ]
       }
@@ -451,22 +690,30 @@ export type NttTransceiverLegacy = {
         "fields": [
           {
             "name": "amount",
-            "type": {
-              "defined": "TrimmedAmount"
-            }
+            "docs": [
+              "The trimmed amount, see [`OutboxItem::trimmed_amount`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
           },
           {
             "name": "sender",
             "type": "publicKey"
           },
           {
-            "name": "recipientChain",
+            "name": "recipientNttManager",
             "type": {
-              "defined": "ChainId"
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
           {
-            "name": "recipientNttManager",
+            "name": "recipientAddress",
             "type": {
               "array": [
                 "u8",
@@ -475,23 +722,103 @@ export type NttTransceiverLegacy = {
             }
           },
           {
-            "name": "recipientAddress",
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "recipientChain",
+            "type": "u16"
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "The decimals of the trimmed amount."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "cancelled",
+            "docs": [
+              "Whether the sender cancelled the transfer, see",
+              "[`OutboxItem::is_cancelled`]. Taken out of the padding, so existing",
+              "items read as not cancelled."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "consumedRateLimits",
+            "docs": [
+              "Which outbound rate limits the transfer consumed from, see",
+              "[`OutboxItem::consumed_outbound_limit`]. Taken out of the padding, so",
+              "existing items read as having consumed none."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
             "type": {
               "array": [
                 "u8",
-                32
+                3
               ]
             }
           },
           {
-            "name": "releaseTimestamp",
-            "type": "i64"
+            "name": "releasedBy",
+            "docs": [
+              "The ids of the transceivers that released the transfer, in the order",
+              "they did, alongside [`OutboxItem::released_at`]."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
           },
           {
-            "name": "released",
+            "name": "releasedAt",
+            "docs": [
+              "When the transceivers in [`OutboxItem::released_by`] released the",
+              "transfer, or 0 for unused entries. [`OutboxItem::released`] stays the",
+              "source of truth for whether a transceiver has, see",
+              "[`OutboxItem::released_at`]."
+            ],
             "type": {
-              "defined": "Bitmap"
+              "array": [
+                "i64",
+                8
+              ]
             }
+          },
+          {
+            "name": "expiryTimestamp",
+            "docs": [
+              "The sender-specified deadline after which the transfer is no longer",
+              "released, or 0 if it has none. See [`OutboxItem::expiry`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "The account that paid the rent of the item, which gets it back if the",
+              "transfer expires (see [`crate::instructions::close_expired_outbox_item`]).",
+              "Zero for items created before it was recorded."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "inboundBackflow",
+            "docs": [
+              "How much sending the transfer refilled the inbound rate limit of the",
+              "recipient chain (the \"backflow\"), which is taken back if it's",
+              "cancelled or expires instead. Zero for queued transfers, and for items",
+              "created before it was recorded."
+            ],
+            "type": "u64"
           }
         ]
       }
@@ -536,6 +863,16 @@ export type NttTransceiverLegacy = {
                 32
               ]
             }
+          },
+          {
+            "name": "addressMatch",
+            "docs": [
+              "How [`Self::address`] is compared against the emitter of received",
+              "messages."
+            ],
+            "type": {
+              "defined": "PeerAddressMatch"
+            }
           }
         ]
       }
@@ -570,59 +907,176 @@ export type NttTransceiverLegacy = {
           }
         ]
       }
-    }
-  ],
-  "types": [
-    {
-      "name": "Bitmap",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "map",
-            "type": "u128"
-          }
-        ]
-      }
     },
     {
-      "name": "ChainId",
+      "name": "transceiverPeerIndex",
+      "docs": [
+        "The chain ids of the configured [`TransceiverPeer`]s, see",
+        "[`crate::peer::PeerIndex`].",
+        "",
+        "NOTE: peers set before the index was introduced are only added when they're",
+        "migrated, see [`crate::transceivers::wormhole::instructions::migrate_transceiver_peer`]."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "id",
-            "type": "u16"
-          }
-        ]
-      }
-    },
-    {
-      "name": "Mode",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Locking"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "Burning"
+            "name": "chainIds",
+            "type": {
+              "vec": "u16"
+            }
           }
         ]
       }
     },
     {
-      "name": "TrimmedAmount",
+      "name": "nttManagerPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "decimals",
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
             "type": "u8"
+          },
+          {
+            "name": "enabled",
+            "docs": [
+              "Whether transfers to and from this chain are accepted."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "outboundInFlight",
+            "docs": [
+              "Number of outbound transfers to this chain that have not been released",
+              "by any transceiver yet."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxOutboundInFlight",
+            "docs": [
+              "Cap on [`Self::outbound_in_flight`]. `None` means no cap."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "allowQueue",
+            "docs": [
+              "Whether outbound transfers to this chain may be queued when they exceed",
+              "the rate limit. When false, such transfers fail even if the sender asked",
+              "for them to be queued."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "destinationFeeBps",
+            "docs": [
+              "The execution fee the peer deducts from delivered transfers, in basis",
+              "points. Informational only: nothing is deducted here, but transfers",
+              "with a `min_amount_out` are checked against it."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "fallbackRecipient",
+            "docs": [
+              "Token account that inbound transfers from this chain are delivered to",
+              "when the recipient's token account is frozen. `None` means such",
+              "transfers can't be released until it is thawed."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "inboundInFlight",
+            "docs": [
+              "Number of inbound transfers from this chain that have been approved,",
+              "but not released yet."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "allowInboundQueue",
+            "docs": [
+              "Whether inbound transfers from this chain that exceed the inbound rate",
+              "limit are queued (released once the capacity allows). When false, they",
+              "are rejected, and can only be redeemed again once there is capacity."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bitmap",
+      "docs": [
+        "Stored as little-endian bytes rather than a `u128` so that it can be embedded",
+        "in zero-copy accounts without imposing an alignment requirement. The borsh",
+        "encoding is the same either way."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "map",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChainId",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Locking"
+          },
+          {
+            "name": "Burning"
           }
         ]
       }
@@ -697,6 +1151,24 @@ export type NttTransceiverLegacy = {
           }
         ]
       }
+    },
+    {
+      "name": "PeerAddressMatch",
+      "docs": [
+        "How strictly a transceiver peer address is compared against the emitter",
+        "address of received messages."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Exact"
+          },
+          {
+            "name": "Normalized"
+          }
+        ]
+      }
     }
   ]
 }
@@ -733,6 +1205,11 @@ export const IDL: NttTransceiverLegacy = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "peerIndex",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -771,6 +1248,16 @@ export const IDL: NttTransceiverLegacy = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "managerPeer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The manager peer is also checked in [`crate::instructions::redeem`],",
+            "but checking it here already rejects messages from the wrong manager",
+            "before they're stored."
+          ]
+        },
         {
           "name": "vaa",
           "isMut": false,
@@ -779,7 +1266,10 @@ export const IDL: NttTransceiverLegacy = {
         {
           "name": "transceiverMessage",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "been received (see [`ValidatedTransceiverMessage::is_initialized`])."
+          ]
         },
         {
           "name": "systemProgram",
@@ -812,10 +1302,19 @@ export const IDL: NttTransceiverLegacy = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "transceiver",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "The registration of this program. Its id is the bit marked in the",
+            "outbox item's `released` bitmap."
+          ]
         },
         {
           "name": "wormholeMessage",
@@ -864,6 +1363,16 @@ export const IDL: NttTransceiverLegacy = {
               "name": "rent",
               "isMut": false,
               "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
             }
           ]
         },
@@ -956,8 +1465,28 @@ export const IDL: NttTransceiverLegacy = {
               "name": "rent",
               "isMut": false,
               "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
             }
           ]
+        },
+        {
+          "name": "tokenMetadata",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "in [`token_metadata_hash`]. It's optional, as not every token has",
+            "Metaplex metadata."
+          ]
         }
       ],
       "args": []
@@ -1027,6 +1556,16 @@ export const IDL: NttTransceiverLegacy = {
               "name": "rent",
               "isMut": false,
               "isSigner": false
+            },
+            {
+              "name": "feePayer",
+              "isMut": true,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Pays the core bridge fee if given, so that the payer of the instruction",
+                "only covers rent."
+              ]
             }
           ]
         }
@@ -1130,9 +1669,11 @@ export const IDL: NttTransceiverLegacy = {
             }
           },
           {
-            "name": "paused",
+            "name": "pausedOutbound",
             "docs": [
-              "Pause the program. This is useful for upgrades and other maintenance."
+              "Pause outbound transfers (sending and releasing them). This is useful",
+              "for upgrades and other maintenance, or to halt outflows during an",
+              "incident without stranding inbound transfers (see [`paused_inbound`])."
             ],
             "type": "bool"
           },
@@ -1142,6 +1683,176 @@ export const IDL: NttTransceiverLegacy = {
               "The custody account that holds tokens in locking mode."
             ],
             "type": "publicKey"
+          },
+          {
+            "name": "routesDefaultEnabled",
+            "docs": [
+              "Whether routes to newly registered peers are enabled straight away.",
+              "When false, the owner has to enable each route explicitly (via",
+              "`enable_route`) before any transfers to or from that chain are accepted."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "mintDecimals",
+            "docs": [
+              "Decimals of [`mint`], cached here so that inbound amounts can be",
+              "untrimmed without loading the mint account."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "receiveHook",
+            "docs": [
+              "Program notified whenever a transceiver receives a message (see",
+              "[`crate::receive_hook`])."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "feeCollector",
+            "docs": [
+              "Wormhole core bridge fee collector that message fees are paid into.",
+              "Defaults to the account derived from the core bridge program, and can",
+              "be updated (via `set_fee_collector`) if the core bridge changes it."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "confidentialTransfers",
+            "docs": [
+              "Whether [`mint`] has the Token-2022 confidential transfer extension, in",
+              "which case inbound transfers are only released to recipients that",
+              "accept non-confidential credits (see [`crate::confidential_transfer`])."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "dustAccount",
+            "docs": [
+              "Token account that dust trimmed off outbound transfers is collected",
+              "into (set via `set_dust_account`). When unset, the dust is left in the",
+              "sender's account."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "limitsManager",
+            "docs": [
+              "Account that can tune rate limits on behalf of the owner (see",
+              "[`Role::LimitsManager`]). Set and revoked via `set_limits_manager`."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "maxVaaAge",
+            "docs": [
+              "How old (in seconds) a VAA may be when it's received, measured from",
+              "the timestamp in its body. Older VAAs are rejected by the transceivers.",
+              "Set via `set_max_vaa_age`, unlimited when unset."
+            ],
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "rejectUnconsumedPayload",
+            "docs": [
+              "What happens to messages whose additional payload this program doesn't",
+              "decode (see [`crate::transfer::Payload`]) when there's no recipient",
+              "callback to pass it on to. When false, the payload is dropped and the",
+              "transfer goes through. When true, redeeming the message fails. Set via",
+              "`set_reject_unconsumed_payload`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "decommissionTimestamp",
+            "docs": [
+              "When the deployment is being decommissioned (see",
+              "`begin_decommission`), the time from which `finalize_decommission`",
+              "can be called. Outbound transfers are rejected in the meantime, while",
+              "inbound ones can still be redeemed and released."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "terminated",
+            "docs": [
+              "Whether the deployment has been decommissioned for good (see",
+              "`finalize_decommission`)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowedConsistencyLevels",
+            "docs": [
+              "Consistency levels outbound transfers may be released with, as a",
+              "bitmap indexed by the level's VAA encoding (see",
+              "[`CONSISTENCY_LEVEL_FINALIZED`] and [`CONSISTENCY_LEVEL_CONFIRMED`]).",
+              "Only finalized by default. Set via `set_allowed_consistency_levels`."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "pausedInbound",
+            "docs": [
+              "Pause inbound transfers (receiving, redeeming and releasing them).",
+              "Set together with [`paused_outbound`] via `set_paused`, or on its own",
+              "via `set_inbound_paused`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "senderLimit",
+            "docs": [
+              "How much a single sender may transfer out per day (see",
+              "[`crate::queue::outbox::SenderRateLimit`]), unless it has an override.",
+              "Set via `set_sender_limit`, unlimited when unset."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "perChainOutboundLimits",
+            "docs": [
+              "Whether transfers to a chain with its own",
+              "[`crate::queue::outbox::ChainOutboxRateLimit`] consume from it rather",
+              "than the global [`crate::queue::outbox::OutboxRateLimit`]. Set via",
+              "`set_per_chain_outbound_limits`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "senderLimitOverrides",
+            "docs": [
+              "Whether an override has been set for any sender with",
+              "`set_sender_limit_override`, which limits that sender even while",
+              "[`Self::sender_limit`] is unset. Never cleared, see",
+              "[`Self::limits_senders`]."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "tracksCustody",
+            "docs": [
+              "Whether the tokens locked in custody are tracked in the",
+              "[`crate::custody_ledger::CustodyLedger`], which is created along with",
+              "the deployment. Deployments migrated from before the ledger existed",
+              "don't have one, so transfers don't require it there."
+            ],
+            "type": "bool"
           }
         ]
       }
@@ -1153,22 +1864,30 @@ export const IDL: NttTransceiverLegacy = {
         "fields": [
           {
             "name": "amount",
-            "type": {
-              "defined": "TrimmedAmount"
-            }
+            "docs": [
+              "The trimmed amount, see [`OutboxItem::trimmed_amount`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
           },
           {
             "name": "sender",
             "type": "publicKey"
           },
           {
-            "name": "recipientChain",
+            "name": "recipientNttManager",
             "type": {
-              "defined": "ChainId"
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
           {
-            "name": "recipientNttManager",
+            "name": "recipientAddress",
             "type": {
               "array": [
                 "u8",
@@ -1177,23 +1896,103 @@ export const IDL: NttTransceiverLegacy = {
             }
           },
           {
-            "name": "recipientAddress",
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "recipientChain",
+            "type": "u16"
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "The decimals of the trimmed amount."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "cancelled",
+            "docs": [
+              "Whether the sender cancelled the transfer, see",
+              "[`OutboxItem::is_cancelled`]. Taken out of the padding, so existing",
+              "items read as not cancelled."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "consumedRateLimits",
+            "docs": [
+              "Which outbound rate limits the transfer consumed from, see",
+              "[`OutboxItem::consumed_outbound_limit`]. Taken out of the padding, so",
+              "existing items read as having consumed none."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
             "type": {
               "array": [
                 "u8",
-                32
+                3
               ]
             }
           },
           {
-            "name": "releaseTimestamp",
-            "type": "i64"
+            "name": "releasedBy",
+            "docs": [
+              "The ids of the transceivers that released the transfer, in the order",
+              "they did, alongside [`OutboxItem::released_at`]."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
           },
           {
-            "name": "released",
+            "name": "releasedAt",
+            "docs": [
+              "When the transceivers in [`OutboxItem::released_by`] released the",
+              "transfer, or 0 for unused entries. [`OutboxItem::released`] stays the",
+              "source of truth for whether a transceiver has, see",
+              "[`OutboxItem::released_at`]."
+            ],
             "type": {
-              "defined": "Bitmap"
+              "array": [
+                "i64",
+                8
+              ]
             }
+          },
+          {
+            "name": "expiryTimestamp",
+            "docs": [
+              "The sender-specified deadline after which the transfer is no longer",
+              "released, or 0 if it has none. See [`OutboxItem::expiry`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "The account that paid the rent of the item, which gets it back if the",
+              "transfer expires (see [`crate::instructions::close_expired_outbox_item`]).",
+              "Zero for items created before it was recorded."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "inboundBackflow",
+            "docs": [
+              "How much sending the transfer refilled the inbound rate limit of the",
+              "recipient chain (the \"backflow\"), which is taken back if it's",
+              "cancelled or expires instead. Zero for queued transfers, and for items",
+              "created before it was recorded."
+            ],
+            "type": "u64"
           }
         ]
       }
@@ -1238,6 +2037,16 @@ export const IDL: NttTransceiverLegacy = {
                 32
               ]
             }
+          },
+          {
+            "name": "addressMatch",
+            "docs": [
+              "How [`Self::address`] is compared against the emitter of received",
+              "messages."
+            ],
+            "type": {
+              "defined": "PeerAddressMatch"
+            }
           }
         ]
       }
@@ -1272,17 +2081,150 @@ export const IDL: NttTransceiverLegacy = {
           }
         ]
       }
+    },
+    {
+      "name": "transceiverPeerIndex",
+      "docs": [
+        "The chain ids of the configured [`TransceiverPeer`]s, see",
+        "[`crate::peer::PeerIndex`].",
+        "",
+        "NOTE: peers set before the index was introduced are only added when they're",
+        "migrated, see [`crate::transceivers::wormhole::instructions::migrate_transceiver_peer`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "chainIds",
+            "type": {
+              "vec": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "nttManagerPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "enabled",
+            "docs": [
+              "Whether transfers to and from this chain are accepted."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "outboundInFlight",
+            "docs": [
+              "Number of outbound transfers to this chain that have not been released",
+              "by any transceiver yet."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxOutboundInFlight",
+            "docs": [
+              "Cap on [`Self::outbound_in_flight`]. `None` means no cap."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "allowQueue",
+            "docs": [
+              "Whether outbound transfers to this chain may be queued when they exceed",
+              "the rate limit. When false, such transfers fail even if the sender asked",
+              "for them to be queued."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "destinationFeeBps",
+            "docs": [
+              "The execution fee the peer deducts from delivered transfers, in basis",
+              "points. Informational only: nothing is deducted here, but transfers",
+              "with a `min_amount_out` are checked against it."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "fallbackRecipient",
+            "docs": [
+              "Token account that inbound transfers from this chain are delivered to",
+              "when the recipient's token account is frozen. `None` means such",
+              "transfers can't be released until it is thawed."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "inboundInFlight",
+            "docs": [
+              "Number of inbound transfers from this chain that have been approved,",
+              "but not released yet."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "allowInboundQueue",
+            "docs": [
+              "Whether inbound transfers from this chain that exceed the inbound rate",
+              "limit are queued (released once the capacity allows). When false, they",
+              "are rejected, and can only be redeemed again once there is capacity."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     }
   ],
   "types": [
     {
       "name": "Bitmap",
+      "docs": [
+        "Stored as little-endian bytes rather than a `u128` so that it can be embedded",
+        "in zero-copy accounts without imposing an alignment requirement. The borsh",
+        "encoding is the same either way."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "map",
-            "type": "u128"
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
           }
         ]
       }
@@ -1313,22 +2255,6 @@ export const IDL: NttTransceiverLegacy = {
         ]
       }
     },
-    {
-      "name": "TrimmedAmount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "amount",
-            "type": "u64"
-          },
-          {
-            "name": "decimals",
-            "type": "u8"
-          }
-        ]
-      }
-    },
     {
       "name": "SetTransceiverPeerArgs",
       "type": {
@@ -1399,6 +2325,24 @@ export const IDL: NttTransceiverLegacy = {
           }
         ]
       }
+    },
+    {
+      "name": "PeerAddressMatch",
+      "docs": [
+        "How strictly a transceiver peer address is compared against the emitter",
+        "address of received messages."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Exact"
+          },
+          {
+            "name": "Normalized"
+          }
+        ]
+      }
     }
   ]
 }
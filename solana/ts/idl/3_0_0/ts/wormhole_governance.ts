@@ -40,6 +40,58 @@ export type WormholeGovernance = {
         }
       ],
       "args": []
+    },
+    {
+      "name": "setOutboundLimit",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "governance",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "config, which is checked in the handler."
+          ]
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nttManager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "are checked in the handler."
+          ]
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "replay",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     }
   ],
   "accounts": [
@@ -71,6 +123,26 @@ export type WormholeGovernance = {
       "code": 6002,
       "name": "InvalidGovernanceProgram",
       "msg": "InvalidGovernanceProgram"
+    },
+    {
+      "code": 6003,
+      "name": "MissingAccount",
+      "msg": "MissingAccount"
+    },
+    {
+      "code": 6004,
+      "name": "AccountFlagsMismatch",
+      "msg": "AccountFlagsMismatch"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidNttConfig",
+      "msg": "InvalidNttConfig"
+    },
+    {
+      "code": 6006,
+      "name": "NotConfigOwner",
+      "msg": "NotConfigOwner"
     }
   ]
 }
@@ -116,6 +188,58 @@ export const IDL: WormholeGovernance = {
         }
       ],
       "args": []
+    },
+    {
+      "name": "setOutboundLimit",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "governance",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "config, which is checked in the handler."
+          ]
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nttManager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "are checked in the handler."
+          ]
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "replay",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     }
   ],
   "accounts": [
@@ -147,6 +271,26 @@ export const IDL: WormholeGovernance = {
       "code": 6002,
       "name": "InvalidGovernanceProgram",
       "msg": "InvalidGovernanceProgram"
+    },
+    {
+      "code": 6003,
+      "name": "MissingAccount",
+      "msg": "MissingAccount"
+    },
+    {
+      "code": 6004,
+      "name": "AccountFlagsMismatch",
+      "msg": "AccountFlagsMismatch"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidNttConfig",
+      "msg": "InvalidNttConfig"
+    },
+    {
+      "code": 6006,
+      "name": "NotConfigOwner",
+      "msg": "NotConfigOwner"
     }
   ]
 }
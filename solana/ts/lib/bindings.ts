@@ -47,6 +47,7 @@ export namespace NttBindings {
 
   export type Config<V extends IdlVersion> = ProgramAccounts<V>["config"];
   export type InboxItem<V extends IdlVersion> = ProgramAccounts<V>["inboxItem"];
+  export type Peer<V extends IdlVersion> = ProgramAccounts<V>["nttManagerPeer"];
 }
 
 function loadIdlVersion<V extends IdlVersion>(targetVersion: V): IdlBinding<V> {
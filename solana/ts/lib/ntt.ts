@@ -1,4 +1,5 @@
 import {
+  BorshAccountsCoder,
   Program,
   parseIdlErrors,
   translateError,
@@ -38,6 +39,7 @@ import { Ntt } from "@wormhole-foundation/sdk-definitions-ntt";
 
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { utils } from "@wormhole-foundation/sdk-solana-core";
+import { _2_0_0 } from "./anchor-idl/index.js";
 import {
   IdlVersion,
   IdlVersions,
@@ -62,6 +64,28 @@ export namespace NTT {
     shouldQueue: boolean;
   }
 
+  /** Release status of an inbox item, see {@link getInboxItem} */
+  export type InboxItemReleaseStatus = {
+    notApproved?: {};
+    releaseAfter?: [BN];
+    released?: {};
+  };
+
+  /** An inbox item, in the same shape regardless of its on-chain layout */
+  export type InboxItem =
+    | {
+        closed: false;
+        amount: BN;
+        recipientAddress: PublicKey;
+        releaseStatus: InboxItemReleaseStatus;
+        /** 0 for items redeemed before the source chain was recorded */
+        fromChain: number;
+      }
+    | { closed: true; releaseStatus: InboxItemReleaseStatus };
+
+  /** Decodes accounts in the layout they have until they're migrated */
+  const legacyAccountsCoder = new BorshAccountsCoder(_2_0_0.idl.ntt);
+
   /** utility to create TransferArgs from SDK types */
   export function transferArgs(
     amount: bigint,
@@ -96,16 +120,26 @@ export namespace NTT {
     const upgradeLock = (): PublicKey => derivePda("upgrade_lock", programId);
     const outboxRateLimitAccount = (): PublicKey =>
       derivePda("outbox_rate_limit", programId);
+    const senderRateLimitAccount = (sender: PublicKey): PublicKey =>
+      derivePda(["sender_limit", sender.toBytes()], programId);
+    const chainOutboxRateLimitAccount = (chain: Chain): PublicKey =>
+      derivePda(["chain_outbox_rate_limit", chainToBytes(chain)], programId);
     const tokenAuthority = (): PublicKey =>
       derivePda("token_authority", programId);
     const pendingTokenAuthority = (): PublicKey =>
       derivePda("pending_token_authority", programId);
     const peerAccount = (chain: Chain): PublicKey =>
       derivePda(["peer", chainToBytes(chain)], programId);
+    const peerIndexAccount = (): PublicKey =>
+      derivePda("peer_index", programId);
+    const peerStatsAccount = (chain: Chain): PublicKey =>
+      derivePda(["peer_stats", chainToBytes(chain)], programId);
     const registeredTransceiver = (transceiver: PublicKey): PublicKey =>
       derivePda(["registered_transceiver", transceiver.toBytes()], programId);
     const lutAccount = (): PublicKey => derivePda("lut", programId);
     const lutAuthority = (): PublicKey => derivePda("lut_authority", programId);
+    const custodyLedgerAccount = (): PublicKey =>
+      derivePda("custody_ledger", programId);
     const sessionAuthority = (
       sender: PublicKey,
       args: TransferArgs
@@ -130,6 +164,8 @@ export namespace NTT {
     return {
       configAccount,
       outboxRateLimitAccount,
+      senderRateLimitAccount,
+      chainOutboxRateLimitAccount,
       inboxRateLimitAccount,
       inboxItemAccount,
       upgradeLock,
@@ -137,9 +173,12 @@ export namespace NTT {
       tokenAuthority,
       pendingTokenAuthority,
       peerAccount,
+      peerIndexAccount,
+      peerStatsAccount,
       registeredTransceiver,
       lutAccount,
       lutAuthority,
+      custodyLedgerAccount,
     };
   };
 
@@ -151,6 +190,8 @@ export namespace NTT {
     const outboxItemSigner = () => derivePda(["outbox_item_signer"], programId);
     const transceiverPeerAccount = (chain: Chain): PublicKey =>
       derivePda(["transceiver_peer", chainToBytes(chain)], programId);
+    const transceiverPeerIndexAccount = (): PublicKey =>
+      derivePda("transceiver_peer_index", programId);
     const transceiverMessageAccount = (
       chain: Chain,
       id: Uint8Array
@@ -166,16 +207,26 @@ export namespace NTT {
     const wormholeMessageWithShimAccount = (
       postMessageShim: PublicKey
     ): PublicKey => derivePda(emitterAccount().toBytes(), postMessageShim);
+    const feeStatsAccount = (): PublicKey =>
+      derivePda("wormhole_fee_stats", programId);
+    const broadcastSuppressionAccount = (): PublicKey =>
+      derivePda("wormhole_broadcast_suppression", programId);
+    const peerBroadcastAccount = (chain: Chain): PublicKey =>
+      derivePda(["wormhole_peer_broadcast", chainToBytes(chain)], programId);
 
     // TODO: memoize?
     return {
       emitterAccount,
       outboxItemSigner,
       transceiverPeerAccount,
+      transceiverPeerIndexAccount,
       transceiverMessageAccount,
       unverifiedMessageAccount,
       wormholeMessageAccount,
       wormholeMessageWithShimAccount,
+      feeStatsAccount,
+      broadcastSuppressionAccount,
+      peerBroadcastAccount,
     };
   };
 
@@ -282,6 +333,7 @@ export namespace NTT {
         ...(major >= 3 && {
           multisigTokenAuthority: args.multisigTokenAuthority ?? null,
         }),
+        custodyLedger: pdas.custodyLedgerAccount(),
         custody: await NTT.custodyAccountAddress(
           pdas,
           args.mint,
@@ -343,6 +395,9 @@ export namespace NTT {
         systemProgram: SystemProgram.programId,
         clock: web3.SYSVAR_CLOCK_PUBKEY,
         rent: web3.SYSVAR_RENT_PUBKEY,
+        // NOTE: the optional fee payer differs per transaction, so it's not
+        // added to the LUT
+        feePayer: null,
       },
     };
 
@@ -408,6 +463,17 @@ export namespace NTT {
   ): Promise<TransactionInstruction> {
     pdas = pdas ?? NTT.pdas(program.programId);
 
+    const mintInfo = await splToken.getMint(
+      program.provider.connection,
+      config.mint,
+      undefined,
+      config.tokenProgram
+    );
+    let multisigTokenAuthority: PublicKey | null = null;
+    if (!mintInfo.mintAuthority?.equals(pdas.tokenAuthority())) {
+      multisigTokenAuthority = mintInfo.mintAuthority;
+    }
+
     const custody = await custodyAccountAddress(pdas, config);
     const recipientChain = toChain(args.transferArgs.recipientChain.id);
     const transferIx = await program.methods
@@ -431,15 +497,17 @@ export namespace NTT {
           args.transferArgs
         ),
         tokenAuthority: pdas.tokenAuthority(),
+        multisigTokenAuthority,
+        dustAccount: dustAccount(config),
+        senderRateLimit: senderRateLimit(config, args.fromAuthority, pdas),
+        chainOutboxRateLimit: chainOutboxRateLimit(
+          config,
+          recipientChain,
+          pdas
+        ),
       })
       .instruction();
 
-    const mintInfo = await splToken.getMint(
-      program.provider.connection,
-      config.mint,
-      undefined,
-      config.tokenProgram
-    );
     const transferHook = splToken.getTransferHook(mintInfo);
 
     if (transferHook) {
@@ -486,7 +554,7 @@ export namespace NTT {
     },
     pdas?: Pdas
   ): Promise<TransactionInstruction> {
-    if (config.paused) throw new Error("Contract is paused");
+    if (isOutboundPaused(config)) throw new Error("Contract is paused");
 
     pdas = pdas ?? NTT.pdas(program.programId);
 
@@ -513,6 +581,10 @@ export namespace NTT {
           args.transferArgs
         ),
         custody,
+        dustAccount: dustAccount(config),
+        senderRateLimit: senderRateLimit(config, args.fromAuthority, pdas),
+        chainOutboxRateLimit: chainOutboxRateLimit(config, chain, pdas),
+        custodyLedger: custodyLedger(config, pdas),
       })
       .instruction();
 
@@ -580,10 +652,8 @@ export namespace NTT {
       multisigTokenAuthority = mintInfo.mintAuthority;
     }
 
-    const recipientAddress =
-      args.recipient ??
-      (await getInboxItem(program, args.chain, args.nttMessage))
-        .recipientAddress;
+    const { recipientAddress, peer, fallbackRecipient, peerStats } =
+      await releaseInboundAccounts(program, args, pdas);
 
     const transferIx = await program.methods
       .releaseInboundMint({
@@ -607,6 +677,9 @@ export namespace NTT {
           tokenAuthority: pdas.tokenAuthority(),
           tokenProgram: config.tokenProgram,
           custody: await custodyAccountAddress(pdas, config),
+          peer,
+          fallbackRecipient,
+          peerStats,
         },
         // NOTE: SPL Multisig token authority is only supported for versions >= 3.x.x
         ...(major >= 3 && {
@@ -658,12 +731,9 @@ export namespace NTT {
     },
     pdas?: Pdas
   ) {
-    const recipientAddress =
-      args.recipient ??
-      (await NTT.getInboxItem(program, args.chain, args.nttMessage))
-        .recipientAddress;
-
     pdas = pdas ?? NTT.pdas(program.programId);
+    const { recipientAddress, peer, fallbackRecipient, peerStats } =
+      await releaseInboundAccounts(program, args, pdas);
     const custody = await custodyAccountAddress(pdas, config);
 
     const transferIx = await program.methods
@@ -688,8 +758,12 @@ export namespace NTT {
           tokenAuthority: pdas.tokenAuthority(),
           tokenProgram: config.tokenProgram,
           custody,
+          peer,
+          fallbackRecipient,
+          peerStats,
         },
         custody,
+        custodyLedger: custodyLedger(config, pdas),
       })
       .instruction();
 
@@ -1031,6 +1105,7 @@ export namespace NTT {
         config: pdas.configAccount(),
         peer: pdas.peerAccount(args.chain),
         inboxRateLimit: pdas.inboxRateLimitAccount(args.chain),
+        peerIndex: pdas.peerIndexAccount(),
       })
       .instruction();
   }
@@ -1086,7 +1161,10 @@ export namespace NTT {
         limit: args.limit,
       })
       .accounts({
+        // NOTE: the rate limits can be set by the limits manager in versions
+        // >= 3.x.x, so the signer is `authority` rather than `owner`
         owner: args.owner,
+        authority: args.owner,
         config: pdas.configAccount(),
         rateLimit: pdas.outboxRateLimitAccount(),
       })
@@ -1109,7 +1187,10 @@ export namespace NTT {
         limit: args.limit,
       })
       .accounts({
+        // NOTE: the rate limits can be set by the limits manager in versions
+        // >= 3.x.x, so the signer is `authority` rather than `owner`
         owner: args.owner,
+        authority: args.owner,
         config: pdas.configAccount(),
         rateLimit: pdas.inboxRateLimitAccount(args.chain),
       })
@@ -1131,6 +1212,7 @@ export namespace NTT {
     transceiverPdas =
       transceiverPdas ?? NTT.transceiverPdas(transceiverProgramId);
 
+    const [major, , ,] = parseVersion(program.idl.version);
     const wormholeNTT = args.vaa;
     const nttMessage = wormholeNTT.payload.nttManagerPayload;
     const chain = wormholeNTT.emitterChain;
@@ -1139,7 +1221,10 @@ export namespace NTT {
       .redeem({})
       .accounts({
         payer: args.payer,
-        config: pdas.configAccount(),
+        // NOTE: the config is checked to not be paused for inbound transfers
+        // in versions >= 3.x.x, which nests it
+        config:
+          major >= 3 ? { config: pdas.configAccount() } : pdas.configAccount(),
         peer: pdas.peerAccount(chain),
         transceiverMessage: transceiverPdas.transceiverMessageAccount(
           chain,
@@ -1168,17 +1253,222 @@ export namespace NTT {
     program: Program<NttBindings.NativeTokenTransfer<IdlVersion>>,
     pdas: Pdas
   ): Promise<NttBindings.Config<IdlVersion>> {
-    return program.account.config.fetch(pdas.configAccount());
+    const address = pdas.configAccount();
+    const info = await program.provider.connection.getAccountInfo(address);
+    if (info === null) {
+      throw new Error(`Account does not exist or has no data ${address}`);
+    }
+    return decodeAccount(program, "config", info.data);
+  }
+
+  /**
+   * Whether outbound transfers are paused. Versions >= 3.x.x pause outbound
+   * and inbound transfers separately, earlier versions pause both at once.
+   */
+  export function isOutboundPaused(
+    config: NttBindings.Config<IdlVersion>
+  ): boolean {
+    return "pausedOutbound" in config ? config.pausedOutbound : config.paused;
+  }
+
+  /** Whether inbound transfers are paused, see {@link isOutboundPaused} */
+  export function isInboundPaused(
+    config: NttBindings.Config<IdlVersion>
+  ): boolean {
+    return "pausedInbound" in config ? config.pausedInbound : config.paused;
+  }
+
+  export async function getPeer(
+    program: Program<NttBindings.NativeTokenTransfer<IdlVersion>>,
+    chain: Chain,
+    pdas?: Pdas
+  ): Promise<NttBindings.Peer<IdlVersion> | null> {
+    pdas = pdas ?? NTT.pdas(program.programId);
+    const info = await program.provider.connection.getAccountInfo(
+      pdas.peerAccount(chain)
+    );
+    if (info === null) return null;
+    return decodeAccount(program, "nttManagerPeer", info.data);
   }
 
+  /**
+   * Fetches an inbox item. Versions >= 3.x.x store inbox items in a zero-copy
+   * layout, and items created before the upgrade stay in the previous layout
+   * until they're migrated, so both are decoded here. Items closed with
+   * `close_inbox_item` only keep their discriminator, and are reported as
+   * released.
+   */
   export async function getInboxItem(
     program: Program<NttBindings.NativeTokenTransfer<IdlVersion>>,
     fromChain: Chain,
     nttMessage: Ntt.Message
-  ): Promise<NttBindings.InboxItem<IdlVersion>> {
-    return program.account.inboxItem.fetch(
+  ): Promise<InboxItem> {
+    const inboxItem = await fetchInboxItem(program, fromChain, nttMessage);
+    if (inboxItem === null) {
+      const address = NTT.pdas(program.programId).inboxItemAccount(
+        fromChain,
+        nttMessage
+      );
+      throw new Error(`Account does not exist or has no data ${address}`);
+    }
+    return inboxItem;
+  }
+
+  async function fetchInboxItem(
+    program: Program<NttBindings.NativeTokenTransfer<IdlVersion>>,
+    fromChain: Chain,
+    nttMessage: Ntt.Message
+  ): Promise<InboxItem | null> {
+    const info = await program.provider.connection.getAccountInfo(
       NTT.pdas(program.programId).inboxItemAccount(fromChain, nttMessage)
     );
+    if (info === null) return null;
+
+    if (info.data.length === 8) {
+      return { closed: true, releaseStatus: { released: {} } };
+    }
+
+    const item = decodeAccount(program, "inboxItem", info.data);
+    return {
+      closed: false,
+      amount: item.amount,
+      recipientAddress: item.recipientAddress,
+      releaseStatus: !("releaseTimestamp" in item)
+        ? item.releaseStatus
+        : item.releaseStatus === 0
+          ? { notApproved: {} }
+          : item.releaseStatus === 1
+            ? { releaseAfter: [item.releaseTimestamp] }
+            : { released: {} },
+      fromChain: "fromChain" in item ? item.fromChain : 0,
+    };
+  }
+
+  /**
+   * Decodes an account that may still be in the layout of an earlier version.
+   * Accounts created before an upgrade are only brought up to the current
+   * layout when they're migrated, and are shorter until then.
+   */
+  function decodeAccount(
+    program: Program<NttBindings.NativeTokenTransfer<IdlVersion>>,
+    accountName: "config" | "inboxItem" | "nttManagerPeer",
+    data: Buffer
+  ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+    const idlAccount = program.idl.accounts!.find(
+      (account) => account.name === accountName
+    )!;
+    if (major >= 3 && data.length < program.coder.accounts.size(idlAccount)) {
+      return legacyAccountsCoder.decode(accountName, data);
+    }
+    return program.coder.accounts.decode(accountName, data);
+  }
+
+  /** The account that dust trimmed off outbound transfers is collected into */
+  function dustAccount(
+    config: NttBindings.Config<IdlVersion>
+  ): PublicKey | null {
+    return "dustAccount" in config ? config.dustAccount : null;
+  }
+
+  /**
+   * The rate limit of the sender, which is only required once senders are
+   * limited. `sender` owns the account the tokens are transferred from.
+   */
+  function senderRateLimit(
+    config: NttBindings.Config<IdlVersion>,
+    sender: PublicKey,
+    pdas: Pdas
+  ): PublicKey | null {
+    return "senderLimit" in config &&
+      (config.senderLimit !== null || config.senderLimitOverrides)
+      ? pdas.senderRateLimitAccount(sender)
+      : null;
+  }
+
+  /**
+   * The outbound rate limit of `chain`, which is only required once per-chain
+   * limits are turned on (and only exists for chains given their own limit).
+   */
+  function chainOutboxRateLimit(
+    config: NttBindings.Config<IdlVersion>,
+    chain: Chain,
+    pdas: Pdas
+  ): PublicKey | null {
+    return "perChainOutboundLimits" in config && config.perChainOutboundLimits
+      ? pdas.chainOutboxRateLimitAccount(chain)
+      : null;
+  }
+
+  /**
+   * The custody ledger, for the instructions that take it. Deployments
+   * migrated from before the ledger existed don't track custody, and have no
+   * ledger.
+   */
+  function custodyLedger(
+    config: NttBindings.Config<IdlVersion>,
+    pdas: Pdas
+  ): PublicKey | null {
+    return "tracksCustody" in config && config.tracksCustody
+      ? pdas.custodyLedgerAccount()
+      : null;
+  }
+
+  /**
+   * The accounts of the release_inbound instructions that depend on the inbox
+   * item. If the recipient is given, the inbox item doesn't have to exist yet,
+   * so that the transfer can be released in the same transaction that redeems
+   * it.
+   */
+  async function releaseInboundAccounts(
+    program: Program<NttBindings.NativeTokenTransfer<IdlVersion>>,
+    args: { chain: Chain; nttMessage: Ntt.Message; recipient?: PublicKey },
+    pdas: Pdas
+  ): Promise<{
+    recipientAddress: PublicKey;
+    peer: PublicKey | null;
+    fallbackRecipient: PublicKey | null;
+    peerStats: PublicKey | null;
+  }> {
+    const inboxItem = await fetchInboxItem(
+      program,
+      args.chain,
+      args.nttMessage
+    );
+    if (inboxItem !== null && inboxItem.closed) {
+      throw new Error("Inbox item has been closed");
+    }
+    const recipientAddress = args.recipient ?? inboxItem?.recipientAddress;
+    if (recipientAddress === undefined) {
+      const address = pdas.inboxItemAccount(args.chain, args.nttMessage);
+      throw new Error(`Account does not exist or has no data ${address}`);
+    }
+
+    // items redeemed before the source chain was recorded are released
+    // without the peer (and its stats)
+    if (inboxItem?.fromChain === 0) {
+      return {
+        recipientAddress,
+        peer: null,
+        fallbackRecipient: null,
+        peerStats: null,
+      };
+    }
+    const peer = await getPeer(program, args.chain, pdas);
+    // the delivery latency is only recorded once the peer's stats have been
+    // initialized (with `init_peer_stats`)
+    const peerStats = pdas.peerStatsAccount(args.chain);
+    const peerStatsInfo =
+      await program.provider.connection.getAccountInfo(peerStats);
+    return {
+      recipientAddress,
+      peer: pdas.peerAccount(args.chain),
+      fallbackRecipient:
+        peer !== null && "fallbackRecipient" in peer
+          ? peer.fallbackRecipient
+          : null,
+      peerStats: peerStatsInfo !== null ? peerStats : null,
+    };
   }
 
   export async function getAddressLookupTable(
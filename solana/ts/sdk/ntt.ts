@@ -165,6 +165,7 @@ export class SolanaNttWormholeTransceiver<
             payer,
             config: { config: this.manager.pdas.configAccount() },
             peer: this.pdas.transceiverPeerAccount(chain),
+            managerPeer: this.manager.pdas.peerAccount(chain),
             transceiverMessage: this.pdas.transceiverMessageAccount(
               chain,
               nttMessage.id
@@ -183,6 +184,7 @@ export class SolanaNttWormholeTransceiver<
             payer,
             config: { config: this.manager.pdas.configAccount() },
             peer: this.pdas.transceiverPeerAccount(chain),
+            managerPeer: this.manager.pdas.peerAccount(chain),
             message: this.pdas.unverifiedMessageAccount(payer, seed),
             transceiverMessage: this.pdas.transceiverMessageAccount(
               chain,
@@ -201,6 +203,7 @@ export class SolanaNttWormholeTransceiver<
           payer,
           config: { config: this.manager.pdas.configAccount() },
           peer: this.pdas.transceiverPeerAccount(chain),
+          managerPeer: this.manager.pdas.peerAccount(chain),
           vaa: utils.derivePostedVaaKey(
             this.manager.core.address,
             Buffer.from(attestation.hash)
@@ -375,7 +378,12 @@ export class SolanaNttWormholeTransceiver<
         payer: sender,
         owner: sender,
         config: this.manager.pdas.configAccount(),
+        registeredTransceiver: this.manager.pdas.registeredTransceiver(
+          this.program.programId
+        ),
         peer: this.pdas.transceiverPeerAccount(peer.chain),
+        peerIndex: this.pdas.transceiverPeerIndexAccount(),
+        feeStats: this.pdas.feeStatsAccount(),
       })
       .instruction();
 
@@ -493,6 +501,8 @@ export class SolanaNttWormholeTransceiver<
             ),
           }),
         },
+        broadcastSuppression: this.pdas.broadcastSuppressionAccount(),
+        peerBroadcast: this.pdas.peerBroadcastAccount(chain),
       })
       .instruction();
   }
@@ -500,6 +510,7 @@ export class SolanaNttWormholeTransceiver<
   async createReleaseWormholeOutboundIx(
     payer: PublicKey,
     outboxItem: PublicKey,
+    recipientChain: Chain,
     revertOnDelay: boolean
   ): Promise<web3.TransactionInstruction> {
     const [major, , ,] = parseVersion(this.version);
@@ -519,6 +530,7 @@ export class SolanaNttWormholeTransceiver<
         payer,
         config: { config: this.manager.pdas.configAccount() },
         outboxItem,
+        peer: this.manager.pdas.peerAccount(recipientChain),
         wormholeMessage,
         emitter: whAccs.wormholeEmitter,
         transceiver: this.manager.pdas.registeredTransceiver(
@@ -681,7 +693,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
 
   async isPaused(): Promise<boolean> {
     const config = await this.getConfig();
-    return config.paused;
+    return NTT.isOutboundPaused(config) || NTT.isInboundPaused(config);
   }
 
   async *pause(payer: AccountAddress<C>) {
@@ -816,9 +828,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
   }
 
   async getPeer<C extends Chain>(chain: C): Promise<Ntt.Peer<C> | null> {
-    const peer = await this.program.account.nttManagerPeer.fetchNullable(
-      this.pdas.peerAccount(chain)
-    );
+    const peer = await NTT.getPeer(this.program, chain, this.pdas);
 
     if (!peer) return null;
 
@@ -928,7 +938,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
     const owner = new SolanaAddress(args.owner).unwrap();
 
     const config = await this.getConfig();
-    if (config.paused) throw new Error("Contract is paused");
+    if (NTT.isOutboundPaused(config)) throw new Error("Contract is paused");
 
     const ix = await this.createRegisterTransceiverIx(0, payer, owner);
 
@@ -1048,7 +1058,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
     outboxItem?: Keypair
   ): AsyncGenerator<UnsignedTransaction<N, C>, any, unknown> {
     const config = await this.getConfig();
-    if (config.paused) throw new Error("Contract is paused");
+    if (NTT.isOutboundPaused(config)) throw new Error("Contract is paused");
 
     if (options.automatic) {
       throw new Error(
@@ -1158,6 +1168,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
         const releaseIx = whTransceiver.createReleaseWormholeOutboundIx(
           payerAddress,
           outboxItem.publicKey,
+          destination.chain,
           !options.queue
         );
         asyncIxs.push(releaseIx);
@@ -1223,7 +1234,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
 
   async *redeem(attestations: Ntt.Attestation[], payer: AccountAddress<C>) {
     const config = await this.getConfig();
-    if (config.paused) throw new Error("Contract is paused");
+    if (NTT.isInboundPaused(config)) throw new Error("Contract is paused");
 
     if (attestations.length !== this.transceivers.length) {
       throw new Error("Not enough attestations provided");
@@ -1439,8 +1450,10 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
     const payload = attestation.payload["nttManagerPayload"];
     let inboxItem;
     try {
-      inboxItem = await this.program.account.inboxItem.fetch(
-        this.pdas.inboxItemAccount(attestation.emitterChain, payload)
+      inboxItem = await NTT.getInboxItem(
+        this.program,
+        attestation.emitterChain,
+        payload
       );
     } catch (e: any) {
       if (e.message?.includes("Account does not exist")) {
@@ -1458,8 +1471,10 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
     const payload = attestation.payload["nttManagerPayload"];
     let inboxItem;
     try {
-      inboxItem = await this.program.account.inboxItem.fetch(
-        this.pdas.inboxItemAccount(attestation.emitterChain, payload)
+      inboxItem = await NTT.getInboxItem(
+        this.program,
+        attestation.emitterChain,
+        payload
       );
     } catch (e: any) {
       if (e.message?.includes("Account does not exist")) {
@@ -1477,10 +1492,8 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
     const payload = attestation.payload["nttManagerPayload"];
     try {
       // check that the inbox item was initialized
-      const inboxItem = await this.program.account.inboxItem.fetch(
-        this.pdas.inboxItemAccount(attestation.emitterChain, payload)
-      );
-      return inboxItem.init;
+      await NTT.getInboxItem(this.program, attestation.emitterChain, payload);
+      return true;
     } catch (e: any) {
       if (e.message?.includes("Account does not exist")) {
         return false;
@@ -1495,7 +1508,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
     payer: AccountAddress<C>
   ) {
     const config = await this.getConfig();
-    if (config.paused) throw new Error("Contract is paused");
+    if (NTT.isInboundPaused(config)) throw new Error("Contract is paused");
 
     const senderAddress = new SolanaAddress(payer).unwrap();
     const tx = new Transaction();
@@ -1538,9 +1551,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
   ): Promise<Ntt.InboundQueuedTransfer<C> | null> {
     let inboxItem;
     try {
-      inboxItem = await this.program.account.inboxItem.fetch(
-        this.pdas.inboxItemAccount(chain, nttMessage)
-      );
+      inboxItem = await NTT.getInboxItem(this.program, chain, nttMessage);
     } catch (e: any) {
       if (e.message?.includes("Account does not exist")) {
         return null;
@@ -1548,7 +1559,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
       throw e;
     }
 
-    if (inboxItem.releaseStatus.releaseAfter) {
+    if (!inboxItem.closed && inboxItem.releaseStatus.releaseAfter) {
       const { recipientAddress, amount, releaseStatus } = inboxItem;
       const rateLimitExpiry = releaseStatus.releaseAfter[0].toNumber();
       const xfer: Ntt.InboundQueuedTransfer<C> = {